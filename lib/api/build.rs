@@ -92,6 +92,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CreateCollection.optimizers_config", ""),
             ("CreateCollection.vectors_config", ""),
             ("CreateCollection.quantization_config", ""),
+            ("CreateCollection.strict_mode_config", ""),
             ("UpdateCollection.collection_name", "length(min = 1, max = 255)"),
             ("UpdateCollection.optimizers_config", ""),
             ("UpdateCollection.params", ""),
@@ -99,17 +100,25 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.hnsw_config", ""),
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
+            ("UpdateCollection.strict_mode_config", ""),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255)"),
             ("DeleteCollection.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CollectionConfig.params", ""),
             ("CollectionConfig.hnsw_config", ""),
             ("CollectionConfig.optimizers_config", ""),
             ("CollectionConfig.quantization_config", ""),
+            ("CollectionConfig.strict_mode_config", ""),
             ("CollectionParams.vectors_config", ""),
             ("ChangeAliases.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255)"),
             ("HnswConfigDiff.ef_construct", "custom = \"crate::grpc::validate::validate_u64_range_min_4\""),
             ("WalConfigDiff.wal_capacity_mb", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_query_limit", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
+            ("StrictModeConfig.max_timeout", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
+            ("StrictModeConfig.search_max_hnsw_ef", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
+            ("StrictModeConfig.search_max_batchsize", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
+            ("StrictModeConfig.upsert_max_batchsize", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
+            ("StrictModeConfig.write_rate_limit", "custom = \"crate::grpc::validate::validate_u32_range_min_1\""),
             ("OptimizersConfigDiff.deleted_threshold", "custom = \"crate::grpc::validate::validate_f64_range_1\""),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "custom = \"crate::grpc::validate::validate_u64_range_min_100\""),
             ("VectorsConfig.config", ""),
@@ -173,6 +182,14 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchBatchPoints.collection_name", "length(min = 1, max = 255)"),
             ("SearchBatchPoints.search_points", ""),
             ("SearchBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("SearchHybridPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SearchHybridPoints.prefetch", ""),
+            ("SearchHybridPoints.filter", ""),
+            ("SearchHybridPoints.limit", "range(min = 1)"),
+            ("SearchHybridPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("HybridPrefetch.filter", ""),
+            ("HybridPrefetch.params", ""),
+            ("HybridPrefetch.limit", "range(min = 1)"),
             // TODO(sparse) validate sparse vector for `SearchPointGroups`
             ("SearchPointGroups.collection_name", "length(min = 1, max = 255)"),
             ("SearchPointGroups.group_by", "length(min = 1)"),
@@ -214,11 +231,35 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DiscoverBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CountPoints.collection_name", "length(min = 1, max = 255)"),
             ("CountPoints.filter", ""),
+            ("FacetCounts.collection_name", "length(min = 1, max = 255)"),
+            ("FacetCounts.key", "length(min = 1)"),
+            ("FacetCounts.filter", ""),
+            ("FacetCounts.limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("SearchMatrixPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SearchMatrixPoints.filter", ""),
+            ("SearchMatrixPoints.sample", "custom = \"crate::grpc::validate::validate_u64_range_min_2\""),
+            ("SearchMatrixPoints.limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("SearchMatrixPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("QueryPoints.collection_name", "length(min = 1, max = 255)"),
+            ("QueryPoints.prefetch", ""),
+            ("QueryPoints.filter", ""),
+            ("QueryPoints.params", ""),
+            ("QueryPoints.limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("QueryPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("QueryBatchPoints.collection_name", "length(min = 1, max = 255)"),
+            ("QueryBatchPoints.query_points", ""),
+            ("QueryBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("PrefetchQuery.prefetch", ""),
+            ("PrefetchQuery.filter", ""),
+            ("PrefetchQuery.params", ""),
+            ("PrefetchQuery.limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("GeoPolygon.exterior", "custom = \"crate::grpc::validate::validate_geo_polygon_exterior\""),
             ("GeoPolygon.interiors", "custom = \"crate::grpc::validate::validate_geo_polygon_interiors\""),
             ("Filter.should", ""),
             ("Filter.must", ""),
             ("Filter.must_not", ""),
+            ("Filter.min_should", ""),
+            ("MinShould.conditions", ""),
             ("NestedCondition.filter", ""),
             ("Condition.condition_one_of", ""),
             ("Vectors.vectors_options", ""),
@@ -256,6 +297,13 @@ fn configure_validation(builder: Builder) -> Builder {
             ("ScrollPointsInternal.scroll_points", ""),
             ("GetPointsInternal.get_points", ""),
             ("CountPointsInternal.count_points", ""),
+            ("FacetCountsInternal.facet_counts", ""),
+            ("SamplePointsInternal.collection_name", "length(min = 1, max = 255)"),
+            ("SamplePointsInternal.filter", ""),
+            ("TextSearchInternal.collection_name", "length(min = 1, max = 255)"),
+            ("TextSearchInternal.key", "length(min = 1)"),
+            ("TextSearchInternal.filter", ""),
+            ("SparseVectorStatisticsInternal.collection_name", "length(min = 1, max = 255)"),
             ("SyncPointsInternal.sync_points", ""),
             ("SyncPoints.collection_name", "length(min = 1, max = 255)"),
         ], &[])
@@ -282,6 +330,10 @@ fn configure_validation(builder: Builder) -> Builder {
             "ListFullSnapshotsRequest",
         ])
         .field_attribute("SnapshotDescription.creation_time", "#[serde(skip)]")
+        .field_attribute("DatetimeRange.lt", "#[serde(serialize_with = \"crate::grpc::conversions::serialize_optional_timestamp\")]")
+        .field_attribute("DatetimeRange.gt", "#[serde(serialize_with = \"crate::grpc::conversions::serialize_optional_timestamp\")]")
+        .field_attribute("DatetimeRange.gte", "#[serde(serialize_with = \"crate::grpc::conversions::serialize_optional_timestamp\")]")
+        .field_attribute("DatetimeRange.lte", "#[serde(serialize_with = \"crate::grpc::conversions::serialize_optional_timestamp\")]")
 }
 
 fn append_to_file(path: &str, line: &str) {
@@ -2,6 +2,10 @@ use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use chrono::{NaiveDateTime, Timelike};
+use segment::data_types::index::{
+    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
+    KeywordIndexType, UuidIndexType,
+};
 use segment::data_types::text_index::TextIndexType;
 use segment::data_types::vectors::VectorElementType;
 use segment::types::default_quantization_ignore_value;
@@ -18,15 +22,20 @@ use crate::grpc::qdrant::value::Kind;
 use crate::grpc::qdrant::vectors::VectorsOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
-    shard_key, with_vectors_selector, CollectionDescription, CollectionOperationResponse,
-    Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
-    HasIdCondition, HealthCheckReply, HnswConfigDiff, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListValue, Match, NamedVectors, NestedCondition,
-    PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo,
-    PayloadSchemaType, PointId, ProductQuantization, QuantizationConfig, QuantizationSearchParams,
-    QuantizationType, Range, RepeatedIntegers, RepeatedStrings, ScalarQuantization, ScoredPoint,
-    SearchParams, ShardKey, Struct, TextIndexParams, TokenizerType, Value, ValuesCount, Vector,
-    Vectors, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    facet_value, geo_shape, order_value, shard_key, start_from, with_vectors_selector,
+    BoolIndexParams, CollectionDescription, CollectionOperationResponse, Condition, Datatype,
+    DatetimeIndexParams, DatetimeRange, Direction, Distance, FacetValue, FacetValueHit,
+    FieldCondition, Filter, FloatIndexParams, GeoBoundingBox, GeoIndexParams, GeoPoint, GeoPolygon,
+    GeoRadius, GeoShape, HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams,
+    IsEmptyCondition, IsNullCondition, KeywordIndexParams, KeywordRange, ListCollectionsResponse,
+    ListValue, Match, MinShould, MultiVectorComparator, MultiVectorConfig, NamedVectors,
+    NestedCondition, OrderBy, OrderValue, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId, ProductQuantization,
+    QuantizationConfig, QuantizationSearchParams, QuantizationType, Range, RepeatedIntegers,
+    RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey,
+    SparseVectorStatistics, StartFrom, StopwordsSet, Struct, TextIndexParams, TokenizerType,
+    UuidIndexParams, Value, ValuesCount, Vector, Vectors, VectorsSelector, WithPayloadSelector,
+    WithVectorsSelector,
 };
 
 pub fn payload_to_proto(payload: segment::types::Payload) -> HashMap<String, Value> {
@@ -185,6 +194,53 @@ impl From<segment::data_types::text_index::TokenizerType> for TokenizerType {
     }
 }
 
+fn language_to_grpc(language: segment::data_types::text_index::Language) -> String {
+    match serde_json::to_value(language) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => unreachable!("language is always serialized as a string"),
+    }
+}
+
+fn language_from_grpc(
+    language: String,
+) -> Result<segment::data_types::text_index::Language, Status> {
+    serde_json::from_value(serde_json::Value::String(language))
+        .map_err(|err| Status::invalid_argument(format!("unknown language: {err}")))
+}
+
+impl From<segment::data_types::text_index::StopwordsInterface> for StopwordsSet {
+    fn from(stopwords: segment::data_types::text_index::StopwordsInterface) -> Self {
+        match stopwords {
+            segment::data_types::text_index::StopwordsInterface::Language(language) => {
+                StopwordsSet {
+                    languages: vec![language_to_grpc(language)],
+                    custom: vec![],
+                }
+            }
+            segment::data_types::text_index::StopwordsInterface::Set(set) => StopwordsSet {
+                languages: set.languages.into_iter().map(language_to_grpc).collect(),
+                custom: set.custom.into_iter().collect(),
+            },
+        }
+    }
+}
+
+impl TryFrom<StopwordsSet> for segment::data_types::text_index::StopwordsInterface {
+    type Error = Status;
+    fn try_from(stopwords: StopwordsSet) -> Result<Self, Self::Error> {
+        Ok(segment::data_types::text_index::StopwordsInterface::Set(
+            segment::data_types::text_index::StopwordsSet {
+                languages: stopwords
+                    .languages
+                    .into_iter()
+                    .map(language_from_grpc)
+                    .collect::<Result<_, _>>()?,
+                custom: stopwords.custom.into_iter().collect(),
+            },
+        ))
+    }
+}
+
 impl From<segment::data_types::text_index::TextIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::text_index::TextIndexParams) -> Self {
         let tokenizer = TokenizerType::from(params.tokenizer);
@@ -194,11 +250,117 @@ impl From<segment::data_types::text_index::TextIndexParams> for PayloadIndexPara
                 lowercase: params.lowercase,
                 min_token_len: params.min_token_len.map(|x| x as u64),
                 max_token_len: params.max_token_len.map(|x| x as u64),
+                phrase_matching: params.phrase_matching,
+                stopwords: params.stopwords.map(StopwordsSet::from),
+                stemmer: params.stemmer.map(language_to_grpc),
+                ascii_folding: params.ascii_folding,
+                on_disk: params.on_disk,
             })),
         }
     }
 }
 
+impl From<segment::data_types::index::KeywordIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::KeywordIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::KeywordIndexParams(KeywordIndexParams {
+                is_tenant: params.is_tenant,
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::IntegerIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::IntegerIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::IntegerIndexParams(IntegerIndexParams {
+                is_tenant: params.is_tenant,
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::FloatIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::FloatIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::FloatIndexParams(FloatIndexParams {
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::DatetimeIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::DatetimeIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::DatetimeIndexParams(DatetimeIndexParams {
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::UuidIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::UuidIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::UuidIndexParams(UuidIndexParams {
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::GeoIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::GeoIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::GeoIndexParams(GeoIndexParams {
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::data_types::index::BoolIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::BoolIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::BoolIndexParams(BoolIndexParams {
+                on_disk: params.on_disk,
+            })),
+        }
+    }
+}
+
+impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
+    fn from(params: segment::types::PayloadSchemaParams) -> Self {
+        match params {
+            segment::types::PayloadSchemaParams::Text(text_index_params) => {
+                text_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Keyword(keyword_index_params) => {
+                keyword_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Integer(integer_index_params) => {
+                integer_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Float(float_index_params) => {
+                float_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Datetime(datetime_index_params) => {
+                datetime_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Uuid(uuid_index_params) => {
+                uuid_index_params.into()
+            }
+            segment::types::PayloadSchemaParams::Geo(geo_index_params) => geo_index_params.into(),
+            segment::types::PayloadSchemaParams::Bool(bool_index_params) => {
+                bool_index_params.into()
+            }
+        }
+    }
+}
+
 impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
     fn from(schema: segment::types::PayloadIndexInfo) -> Self {
         PayloadSchemaInfo {
@@ -209,13 +371,11 @@ impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
                 segment::types::PayloadSchemaType::Geo => PayloadSchemaType::Geo,
                 segment::types::PayloadSchemaType::Text => PayloadSchemaType::Text,
                 segment::types::PayloadSchemaType::Bool => PayloadSchemaType::Bool,
+                segment::types::PayloadSchemaType::Datetime => PayloadSchemaType::Datetime,
+                segment::types::PayloadSchemaType::Uuid => PayloadSchemaType::Uuid,
             }
             .into(),
-            params: schema.params.map(|params| match params {
-                segment::types::PayloadSchemaParams::Text(text_index_params) => {
-                    text_index_params.into()
-                }
-            }),
+            params: schema.params.map(PayloadIndexParams::from),
             points: Some(schema.points as u64),
         }
     }
@@ -249,10 +409,80 @@ impl TryFrom<TextIndexParams> for segment::data_types::text_index::TextIndexPara
             lowercase: params.lowercase,
             min_token_len: params.min_token_len.map(|x| x as usize),
             max_token_len: params.max_token_len.map(|x| x as usize),
+            phrase_matching: params.phrase_matching,
+            stopwords: params.stopwords.map(TryFrom::try_from).transpose()?,
+            stemmer: params.stemmer.map(language_from_grpc).transpose()?,
+            ascii_folding: params.ascii_folding,
+            on_disk: params.on_disk,
         })
     }
 }
 
+impl From<KeywordIndexParams> for segment::data_types::index::KeywordIndexParams {
+    fn from(params: KeywordIndexParams) -> Self {
+        segment::data_types::index::KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            is_tenant: params.is_tenant,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<IntegerIndexParams> for segment::data_types::index::IntegerIndexParams {
+    fn from(params: IntegerIndexParams) -> Self {
+        segment::data_types::index::IntegerIndexParams {
+            r#type: IntegerIndexType::Integer,
+            is_tenant: params.is_tenant,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<FloatIndexParams> for segment::data_types::index::FloatIndexParams {
+    fn from(params: FloatIndexParams) -> Self {
+        segment::data_types::index::FloatIndexParams {
+            r#type: FloatIndexType::Float,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<DatetimeIndexParams> for segment::data_types::index::DatetimeIndexParams {
+    fn from(params: DatetimeIndexParams) -> Self {
+        segment::data_types::index::DatetimeIndexParams {
+            r#type: DatetimeIndexType::Datetime,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<UuidIndexParams> for segment::data_types::index::UuidIndexParams {
+    fn from(params: UuidIndexParams) -> Self {
+        segment::data_types::index::UuidIndexParams {
+            r#type: UuidIndexType::Uuid,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<GeoIndexParams> for segment::data_types::index::GeoIndexParams {
+    fn from(params: GeoIndexParams) -> Self {
+        segment::data_types::index::GeoIndexParams {
+            r#type: GeoIndexType::Geo,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
+impl From<BoolIndexParams> for segment::data_types::index::BoolIndexParams {
+    fn from(params: BoolIndexParams) -> Self {
+        segment::data_types::index::BoolIndexParams {
+            r#type: BoolIndexType::Bool,
+            on_disk: params.on_disk,
+        }
+    }
+}
+
 impl TryFrom<PayloadIndexParams> for segment::data_types::text_index::TextIndexParams {
     type Error = Status;
     fn try_from(params: PayloadIndexParams) -> Result<Self, Self::Error> {
@@ -261,6 +491,15 @@ impl TryFrom<PayloadIndexParams> for segment::data_types::text_index::TextIndexP
             Some(IndexParams::TextIndexParams(text_index_params)) => {
                 Ok(text_index_params.try_into()?)
             }
+            Some(
+                IndexParams::KeywordIndexParams(_)
+                | IndexParams::IntegerIndexParams(_)
+                | IndexParams::FloatIndexParams(_)
+                | IndexParams::DatetimeIndexParams(_)
+                | IndexParams::UuidIndexParams(_)
+                | IndexParams::GeoIndexParams(_)
+                | IndexParams::BoolIndexParams(_),
+            ) => Err(Status::invalid_argument("expected text index params")),
         }
     }
 }
@@ -273,6 +512,27 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::TextIndexParams(text_index_params) => Ok(
                 segment::types::PayloadSchemaParams::Text(text_index_params.try_into()?),
             ),
+            IndexParams::KeywordIndexParams(keyword_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Keyword(keyword_index_params.into()),
+            ),
+            IndexParams::IntegerIndexParams(integer_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Integer(integer_index_params.into()),
+            ),
+            IndexParams::FloatIndexParams(float_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Float(float_index_params.into()),
+            ),
+            IndexParams::DatetimeIndexParams(datetime_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Datetime(datetime_index_params.into()),
+            ),
+            IndexParams::UuidIndexParams(uuid_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Uuid(uuid_index_params.into()),
+            ),
+            IndexParams::GeoIndexParams(geo_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Geo(geo_index_params.into()),
+            ),
+            IndexParams::BoolIndexParams(bool_index_params) => Ok(
+                segment::types::PayloadSchemaParams::Bool(bool_index_params.into()),
+            ),
         }
     }
 }
@@ -294,6 +554,8 @@ impl TryFrom<PayloadSchemaInfo> for segment::types::PayloadIndexInfo {
                 PayloadSchemaType::Geo => segment::types::PayloadSchemaType::Geo,
                 PayloadSchemaType::Text => segment::types::PayloadSchemaType::Text,
                 PayloadSchemaType::Bool => segment::types::PayloadSchemaType::Bool,
+                PayloadSchemaType::Datetime => segment::types::PayloadSchemaType::Datetime,
+                PayloadSchemaType::Uuid => segment::types::PayloadSchemaType::Uuid,
                 PayloadSchemaType::UnknownType => {
                     return Err(Status::invalid_argument(
                         "Malformed payload schema".to_string(),
@@ -404,6 +666,7 @@ impl From<SearchParams> for segment::types::SearchParams {
             exact: params.exact.unwrap_or(false),
             quantization: params.quantization.map(|q| q.into()),
             indexed_only: params.indexed_only.unwrap_or(false),
+            adaptive_ef: params.adaptive_ef.unwrap_or(false),
         }
     }
 }
@@ -415,6 +678,7 @@ impl From<segment::types::SearchParams> for SearchParams {
             exact: Some(params.exact),
             quantization: params.quantization.map(|q| q.into()),
             indexed_only: Some(params.indexed_only),
+            adaptive_ef: Some(params.adaptive_ef),
         }
     }
 }
@@ -436,31 +700,72 @@ impl From<segment::data_types::vectors::Vector> for Vector {
             segment::data_types::vectors::Vector::Dense(vector) => Self {
                 data: vector,
                 indices: None,
+                vectors_count: None,
             },
             segment::data_types::vectors::Vector::Sparse(vector) => Self {
                 data: vector.values,
                 indices: Some(SparseIndices {
                     data: vector.indices,
                 }),
+                vectors_count: None,
             },
+            segment::data_types::vectors::Vector::MultiDense(vector) => {
+                let vectors_count = vector.vectors_count() as u32;
+                Self {
+                    data: vector.flattened_vectors,
+                    indices: None,
+                    vectors_count: Some(vectors_count),
+                }
+            }
         }
     }
 }
 
-impl From<Vector> for segment::data_types::vectors::Vector {
-    fn from(vector: Vector) -> Self {
-        match vector.indices {
-            None => segment::data_types::vectors::Vector::Dense(vector.data),
-            Some(indices) => segment::data_types::vectors::Vector::Sparse(
+impl TryFrom<Vector> for segment::data_types::vectors::Vector {
+    type Error = Status;
+
+    fn try_from(vector: Vector) -> Result<Self, Self::Error> {
+        match (vector.indices, vector.vectors_count) {
+            (None, None) => Ok(segment::data_types::vectors::Vector::Dense(vector.data)),
+            (Some(indices), None) => Ok(segment::data_types::vectors::Vector::Sparse(
                 sparse::common::sparse_vector::SparseVector {
                     values: vector.data,
                     indices: indices.data,
                 },
-            ),
+            )),
+            (None, Some(vectors_count)) => Ok(segment::data_types::vectors::Vector::MultiDense(
+                into_multi_dense_vector(vector.data, vectors_count)?,
+            )),
+            (Some(_), Some(_)) => Err(Status::invalid_argument(
+                "Vector can not have both sparse indices and vectors count",
+            )),
         }
     }
 }
 
+/// Split the flattened data of a multi vector into `vectors_count` vectors of the same size
+fn into_multi_dense_vector(
+    data: Vec<VectorElementType>,
+    vectors_count: u32,
+) -> Result<segment::data_types::vectors::MultiDenseVector, Status> {
+    let vectors_count = vectors_count as usize;
+    if vectors_count == 0 || data.len() % vectors_count != 0 {
+        return Err(Status::invalid_argument(format!(
+            "Malformed multi vector: {} values can not be split into {vectors_count} vectors of the same size",
+            data.len(),
+        )));
+    }
+    let dim = data.len() / vectors_count;
+    if dim == 0 {
+        return Err(Status::invalid_argument(
+            "Malformed multi vector: vectors must not be empty",
+        ));
+    }
+    Ok(segment::data_types::vectors::MultiDenseVector::new(
+        data, dim,
+    ))
+}
+
 impl From<HashMap<String, segment::data_types::vectors::Vector>> for NamedVectors {
     fn from(vectors: HashMap<String, segment::data_types::vectors::Vector>) -> Self {
         Self {
@@ -523,12 +828,16 @@ impl From<segment::data_types::groups::GroupId> for GroupId {
     }
 }
 
-impl From<NamedVectors> for HashMap<String, segment::data_types::vectors::Vector> {
-    fn from(vectors: NamedVectors) -> Self {
+impl TryFrom<NamedVectors> for HashMap<String, segment::data_types::vectors::Vector> {
+    type Error = Status;
+
+    fn try_from(vectors: NamedVectors) -> Result<Self, Self::Error> {
         vectors
             .vectors
             .into_iter()
-            .map(|(name, vector)| (name, segment::data_types::vectors::Vector::from(vector)))
+            .map(|(name, vector)| {
+                segment::data_types::vectors::Vector::try_from(vector).map(|vector| (name, vector))
+            })
             .collect()
     }
 }
@@ -543,7 +852,7 @@ impl TryFrom<Vectors> for segment::data_types::vectors::VectorStruct {
                     segment::data_types::vectors::VectorStruct::Single(vector.data)
                 }
                 VectorsOptions::Vectors(vectors) => {
-                    segment::data_types::vectors::VectorStruct::Multi(vectors.into())
+                    segment::data_types::vectors::VectorStruct::Multi(vectors.try_into()?)
                 }
             }),
             None => Err(Status::invalid_argument("No Provided")),
@@ -768,6 +1077,7 @@ impl TryFrom<Filter> for segment::types::Filter {
     fn try_from(value: Filter) -> Result<Self, Self::Error> {
         Ok(Self {
             should: conditions_helper_from_grpc(value.should)?,
+            min_should: value.min_should.map(TryInto::try_into).transpose()?,
             must: conditions_helper_from_grpc(value.must)?,
             must_not: conditions_helper_from_grpc(value.must_not)?,
         })
@@ -778,12 +1088,37 @@ impl From<segment::types::Filter> for Filter {
     fn from(value: segment::types::Filter) -> Self {
         Self {
             should: conditions_helper_to_grpc(value.should),
+            min_should: value.min_should.map(Into::into),
             must: conditions_helper_to_grpc(value.must),
             must_not: conditions_helper_to_grpc(value.must_not),
         }
     }
 }
 
+impl TryFrom<MinShould> for segment::types::MinShould {
+    type Error = Status;
+
+    fn try_from(value: MinShould) -> Result<Self, Self::Error> {
+        Ok(Self {
+            conditions: value
+                .conditions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            min_count: value.min_count as usize,
+        })
+    }
+}
+
+impl From<segment::types::MinShould> for MinShould {
+    fn from(value: segment::types::MinShould) -> Self {
+        Self {
+            conditions: value.conditions.into_iter().map(Into::into).collect(),
+            min_count: value.min_count as u64,
+        }
+    }
+}
+
 impl TryFrom<Condition> for segment::types::Condition {
     type Error = Status;
 
@@ -924,19 +1259,42 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             geo_radius,
             values_count,
             geo_polygon,
+            datetime_range,
+            keyword_range,
+            geo_intersects,
+            geo_within,
         } = value;
 
+        let range = match (range, datetime_range, keyword_range) {
+            (Some(range), None, None) => Some(segment::types::RangeInterface::Float(range.into())),
+            (None, Some(datetime_range), None) => Some(segment::types::RangeInterface::DateTime(
+                datetime_range.try_into()?,
+            )),
+            (None, None, Some(keyword_range)) => Some(segment::types::RangeInterface::Keyword {
+                keyword: keyword_range.into(),
+            }),
+            (None, None, None) => None,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "Only one of range, datetime_range and keyword_range can be specified",
+                ))
+            }
+        };
         let geo_bounding_box =
             geo_bounding_box.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_radius = geo_radius.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_polygon = geo_polygon.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
+        let geo_intersects = geo_intersects.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
+        let geo_within = geo_within.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         Ok(Self {
             key,
             r#match: r#match.map_or_else(|| Ok(None), |m| m.try_into().map(Some))?,
-            range: range.map(Into::into),
+            range,
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_intersects,
+            geo_within,
             values_count: values_count.map(Into::into),
         })
     }
@@ -951,20 +1309,38 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_intersects,
+            geo_within,
             values_count,
         } = value;
 
+        let (range, datetime_range, keyword_range) = match range {
+            Some(segment::types::RangeInterface::Float(range)) => (Some(range.into()), None, None),
+            Some(segment::types::RangeInterface::DateTime(range)) => {
+                (None, Some(range.into()), None)
+            }
+            Some(segment::types::RangeInterface::Keyword { keyword: range }) => {
+                (None, None, Some(range.into()))
+            }
+            None => (None, None, None),
+        };
         let geo_bounding_box = geo_bounding_box.map(Into::into);
         let geo_radius = geo_radius.map(Into::into);
         let geo_polygon = geo_polygon.map(Into::into);
+        let geo_intersects = geo_intersects.map(Into::into);
+        let geo_within = geo_within.map(Into::into);
         Self {
             key,
             r#match: r#match.map(Into::into),
-            range: range.map(Into::into),
+            range,
             geo_bounding_box,
             geo_radius,
             geo_polygon,
             values_count: values_count.map(Into::into),
+            datetime_range,
+            keyword_range,
+            geo_intersects,
+            geo_within,
         }
     }
 }
@@ -1052,6 +1428,34 @@ impl From<segment::types::GeoPolygon> for GeoPolygon {
     }
 }
 
+impl TryFrom<GeoShape> for segment::types::GeoShape {
+    type Error = Status;
+
+    fn try_from(value: GeoShape) -> Result<Self, Self::Error> {
+        let shape = match value.shape {
+            Some(geo_shape::Shape::Point(point)) => Self::Point(point.into()),
+            Some(geo_shape::Shape::LineString(line)) => Self::LineString(line.into()),
+            Some(geo_shape::Shape::Polygon(polygon)) => Self::Polygon(polygon.try_into()?),
+            None => return Err(Status::invalid_argument("Malformed GeoShape type")),
+        };
+        shape
+            .validate()
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        Ok(shape)
+    }
+}
+
+impl From<segment::types::GeoShape> for GeoShape {
+    fn from(value: segment::types::GeoShape) -> Self {
+        let shape = match value {
+            segment::types::GeoShape::Point(point) => geo_shape::Shape::Point(point.into()),
+            segment::types::GeoShape::LineString(line) => geo_shape::Shape::LineString(line.into()),
+            segment::types::GeoShape::Polygon(polygon) => geo_shape::Shape::Polygon(polygon.into()),
+        };
+        Self { shape: Some(shape) }
+    }
+}
+
 impl From<GeoPoint> for segment::types::GeoPoint {
     fn from(value: GeoPoint) -> Self {
         Self {
@@ -1099,6 +1503,68 @@ impl From<segment::types::Range> for Range {
     }
 }
 
+impl TryFrom<DatetimeRange> for segment::types::DatetimeRange {
+    type Error = Status;
+
+    fn try_from(value: DatetimeRange) -> Result<Self, Self::Error> {
+        let convert = |timestamp: Option<prost_types::Timestamp>| {
+            timestamp
+                .map(|timestamp| {
+                    u32::try_from(timestamp.nanos)
+                        .ok()
+                        .and_then(|nanos| {
+                            chrono::DateTime::from_timestamp(timestamp.seconds, nanos)
+                        })
+                        .map(segment::types::DateTimePayloadType)
+                        .ok_or_else(|| Status::invalid_argument("Malformed timestamp"))
+                })
+                .transpose()
+        };
+        Ok(Self {
+            lt: convert(value.lt)?,
+            gt: convert(value.gt)?,
+            gte: convert(value.gte)?,
+            lte: convert(value.lte)?,
+        })
+    }
+}
+
+impl From<segment::types::DatetimeRange> for DatetimeRange {
+    fn from(value: segment::types::DatetimeRange) -> Self {
+        let convert = |datetime: segment::types::DateTimePayloadType| {
+            date_time_to_proto(datetime.0.naive_utc())
+        };
+        Self {
+            lt: value.lt.map(convert),
+            gt: value.gt.map(convert),
+            gte: value.gte.map(convert),
+            lte: value.lte.map(convert),
+        }
+    }
+}
+
+impl From<KeywordRange> for segment::types::KeywordRange {
+    fn from(value: KeywordRange) -> Self {
+        Self {
+            lt: value.lt,
+            gt: value.gt,
+            gte: value.gte,
+            lte: value.lte,
+        }
+    }
+}
+
+impl From<segment::types::KeywordRange> for KeywordRange {
+    fn from(value: segment::types::KeywordRange) -> Self {
+        Self {
+            lt: value.lt,
+            gt: value.gt,
+            gte: value.gte,
+            lte: value.lte,
+        }
+    }
+}
+
 impl From<ValuesCount> for segment::types::ValuesCount {
     fn from(value: ValuesCount) -> Self {
         Self {
@@ -1131,6 +1597,8 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::Integer(int) => int.into(),
                 MatchValue::Boolean(flag) => flag.into(),
                 MatchValue::Text(text) => segment::types::Match::Text(text.into()),
+                MatchValue::Phrase(phrase) => segment::types::Match::Phrase(phrase.into()),
+                MatchValue::Prefix(prefix) => segment::types::Match::Prefix(prefix.into()),
                 MatchValue::Keywords(kwds) => kwds.strings.into(),
                 MatchValue::Integers(ints) => ints.integers.into(),
                 MatchValue::ExceptIntegers(kwds) => {
@@ -1156,6 +1624,12 @@ impl From<segment::types::Match> for Match {
             segment::types::Match::Text(segment::types::MatchText { text }) => {
                 MatchValue::Text(text)
             }
+            segment::types::Match::Phrase(segment::types::MatchPhrase { phrase }) => {
+                MatchValue::Phrase(phrase)
+            }
+            segment::types::Match::Prefix(segment::types::MatchPrefix { prefix }) => {
+                MatchValue::Prefix(prefix)
+            }
             segment::types::Match::Any(any) => match any.any {
                 segment::types::AnyVariants::Keywords(strings) => {
                     MatchValue::Keywords(RepeatedStrings { strings })
@@ -1188,6 +1662,7 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             max_indexing_threads: hnsw_config.max_indexing_threads.unwrap_or_default() as usize,
             on_disk: hnsw_config.on_disk,
             payload_m: hnsw_config.payload_m.map(|x| x as usize),
+            range_links: hnsw_config.range_links,
         }
     }
 }
@@ -1199,6 +1674,17 @@ pub fn date_time_to_proto(date_time: NaiveDateTime) -> prost_types::Timestamp {
     }
 }
 
+/// Serialize protobuf timestamp in RFC 3339 format
+pub fn serialize_optional_timestamp<S: serde::Serializer>(
+    timestamp: &Option<prost_types::Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match timestamp {
+        Some(timestamp) => serializer.collect_str(timestamp),
+        None => serializer.serialize_none(),
+    }
+}
+
 impl TryFrom<Distance> for segment::types::Distance {
     type Error = Status;
 
@@ -1226,13 +1712,174 @@ pub fn from_grpc_dist(dist: i32) -> Result<segment::types::Distance, Status> {
     }
 }
 
+impl From<segment::types::MultiVectorComparator> for MultiVectorComparator {
+    fn from(value: segment::types::MultiVectorComparator) -> Self {
+        match value {
+            segment::types::MultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+        }
+    }
+}
+
+impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
+    fn from(value: MultiVectorComparator) -> Self {
+        match value {
+            MultiVectorComparator::MaxSim => segment::types::MultiVectorComparator::MaxSim,
+        }
+    }
+}
+
+impl From<segment::types::MultiVectorConfig> for MultiVectorConfig {
+    fn from(value: segment::types::MultiVectorConfig) -> Self {
+        Self {
+            comparator: MultiVectorComparator::from(value.comparator) as i32,
+        }
+    }
+}
+
+impl TryFrom<MultiVectorConfig> for segment::types::MultiVectorConfig {
+    type Error = Status;
+
+    fn try_from(value: MultiVectorConfig) -> Result<Self, Self::Error> {
+        let comparator = MultiVectorComparator::from_i32(value.comparator).ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "Unknown multi vector comparator: {}",
+                value.comparator
+            ))
+        })?;
+        Ok(Self {
+            comparator: comparator.into(),
+        })
+    }
+}
+
+impl From<segment::types::VectorStorageDatatype> for Datatype {
+    fn from(value: segment::types::VectorStorageDatatype) -> Self {
+        match value {
+            segment::types::VectorStorageDatatype::Float32 => Datatype::Float32,
+            segment::types::VectorStorageDatatype::Float16 => Datatype::Float16,
+            segment::types::VectorStorageDatatype::Uint8 => Datatype::Uint8,
+        }
+    }
+}
+
+impl From<segment::data_types::sparse_statistics::SparseVectorStatistics>
+    for SparseVectorStatistics
+{
+    fn from(value: segment::data_types::sparse_statistics::SparseVectorStatistics) -> Self {
+        Self {
+            vector_count: value.vector_count as u64,
+            document_frequencies: value
+                .document_frequencies
+                .into_iter()
+                .map(|(dim_id, frequency)| (dim_id, frequency as u64))
+                .collect(),
+        }
+    }
+}
+
+impl From<SparseVectorStatistics>
+    for segment::data_types::sparse_statistics::SparseVectorStatistics
+{
+    fn from(value: SparseVectorStatistics) -> Self {
+        Self {
+            vector_count: value.vector_count as usize,
+            document_frequencies: value
+                .document_frequencies
+                .into_iter()
+                .map(|(dim_id, frequency)| (dim_id, frequency as usize))
+                .collect(),
+        }
+    }
+}
+
+impl From<segment::data_types::facets::FacetValue> for FacetValue {
+    fn from(value: segment::data_types::facets::FacetValue) -> Self {
+        let variant = match value {
+            segment::data_types::facets::FacetValue::Keyword(value) => {
+                facet_value::Variant::StringValue(value)
+            }
+            segment::data_types::facets::FacetValue::Integer(value) => {
+                facet_value::Variant::IntegerValue(value)
+            }
+        };
+        Self {
+            variant: Some(variant),
+        }
+    }
+}
+
+impl TryFrom<FacetValue> for segment::data_types::facets::FacetValue {
+    type Error = Status;
+
+    fn try_from(value: FacetValue) -> Result<Self, Self::Error> {
+        match value.variant {
+            Some(facet_value::Variant::StringValue(value)) => Ok(Self::Keyword(value)),
+            Some(facet_value::Variant::IntegerValue(value)) => Ok(Self::Integer(value)),
+            None => Err(Status::invalid_argument("Unknown facet value")),
+        }
+    }
+}
+
+impl From<segment::data_types::facets::FacetValueHit> for FacetValueHit {
+    fn from(hit: segment::data_types::facets::FacetValueHit) -> Self {
+        Self {
+            value: Some(hit.value.into()),
+            count: hit.count as u64,
+        }
+    }
+}
+
+impl TryFrom<FacetValueHit> for segment::data_types::facets::FacetValueHit {
+    type Error = Status;
+
+    fn try_from(hit: FacetValueHit) -> Result<Self, Self::Error> {
+        let value = hit
+            .value
+            .ok_or_else(|| Status::invalid_argument("Facet value is missing"))?;
+        Ok(Self {
+            value: value.try_into()?,
+            count: hit.count as usize,
+        })
+    }
+}
+
+/// Converts gRPC datatype into storage datatype, `Default` means no datatype is specified
+pub fn from_grpc_datatype(
+    datatype: i32,
+) -> Result<Option<segment::types::VectorStorageDatatype>, Status> {
+    match Datatype::from_i32(datatype) {
+        None => Err(Status::invalid_argument(format!(
+            "Malformed datatype parameter, unexpected value: {datatype}"
+        ))),
+        Some(Datatype::Default) => Ok(None),
+        Some(Datatype::Float32) => Ok(Some(segment::types::VectorStorageDatatype::Float32)),
+        Some(Datatype::Float16) => Ok(Some(segment::types::VectorStorageDatatype::Float16)),
+        Some(Datatype::Uint8) => Ok(Some(segment::types::VectorStorageDatatype::Uint8)),
+    }
+}
+
 pub fn into_named_vector_struct(
     vector_name: Option<String>,
     vector: Vec<VectorElementType>,
     indices: Option<SparseIndices>,
+    vectors_count: Option<u32>,
 ) -> Result<segment::data_types::vectors::NamedVectorStruct, Status> {
-    use segment::data_types::vectors::{NamedSparseVector, NamedVector, NamedVectorStruct};
+    use segment::data_types::vectors::{
+        NamedMultiDenseVector, NamedSparseVector, NamedVector, NamedVectorStruct,
+        DEFAULT_VECTOR_NAME,
+    };
     use sparse::common::sparse_vector::SparseVector;
+    if let Some(vectors_count) = vectors_count {
+        if indices.is_some() {
+            return Err(Status::invalid_argument(
+                "Vector can not have both sparse indices and vectors count",
+            ));
+        }
+        return Ok(NamedVectorStruct::MultiDense(NamedMultiDenseVector {
+            name: vector_name.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            vector: into_multi_dense_vector(vector, vectors_count)?,
+        }));
+    }
     Ok(match indices {
         Some(indices) => NamedVectorStruct::Sparse(NamedSparseVector {
             name: vector_name
@@ -1254,3 +1901,113 @@ pub fn into_named_vector_struct(
         }
     })
 }
+
+impl From<segment::data_types::order_by::OrderBy> for OrderBy {
+    fn from(value: segment::data_types::order_by::OrderBy) -> Self {
+        Self {
+            key: value.key,
+            direction: value.direction.map(|d| Direction::from(d) as i32),
+            start_from: value.start_from.map(|start_from| start_from.into()),
+        }
+    }
+}
+
+impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
+    type Error = Status;
+
+    fn try_from(value: OrderBy) -> Result<Self, Self::Error> {
+        let direction = value
+            .direction
+            .map(|d| {
+                Direction::from_i32(d)
+                    .map(segment::data_types::order_by::Direction::from)
+                    .ok_or_else(|| {
+                        Status::invalid_argument(format!("Unknown order_by direction: {d}"))
+                    })
+            })
+            .transpose()?;
+
+        let start_from = value
+            .start_from
+            .map(|start_from| start_from.try_into())
+            .transpose()?;
+
+        Ok(Self {
+            key: value.key,
+            direction,
+            start_from,
+        })
+    }
+}
+
+impl From<segment::data_types::order_by::Direction> for Direction {
+    fn from(value: segment::data_types::order_by::Direction) -> Self {
+        match value {
+            segment::data_types::order_by::Direction::Asc => Self::Asc,
+            segment::data_types::order_by::Direction::Desc => Self::Desc,
+        }
+    }
+}
+
+impl From<Direction> for segment::data_types::order_by::Direction {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Asc => Self::Asc,
+            Direction::Desc => Self::Desc,
+        }
+    }
+}
+
+impl From<segment::data_types::order_by::StartFrom> for StartFrom {
+    fn from(value: segment::data_types::order_by::StartFrom) -> Self {
+        Self {
+            value: Some(match value {
+                segment::data_types::order_by::StartFrom::Integer(int) => {
+                    start_from::Value::Integer(int)
+                }
+                segment::data_types::order_by::StartFrom::Float(float) => {
+                    start_from::Value::Float(float)
+                }
+            }),
+        }
+    }
+}
+
+impl TryFrom<StartFrom> for segment::data_types::order_by::StartFrom {
+    type Error = Status;
+
+    fn try_from(value: StartFrom) -> Result<Self, Self::Error> {
+        match value.value {
+            Some(start_from::Value::Integer(int)) => Ok(Self::Integer(int)),
+            Some(start_from::Value::Float(float)) => Ok(Self::Float(float)),
+            None => Err(Status::invalid_argument("Unknown start_from value")),
+        }
+    }
+}
+
+impl From<segment::data_types::order_by::OrderValue> for OrderValue {
+    fn from(value: segment::data_types::order_by::OrderValue) -> Self {
+        Self {
+            variant: Some(match value {
+                segment::data_types::order_by::OrderValue::Int(int) => {
+                    order_value::Variant::Int(int)
+                }
+                segment::data_types::order_by::OrderValue::Float(float) => {
+                    order_value::Variant::Float(float)
+                }
+            }),
+        }
+    }
+}
+
+impl TryFrom<OrderValue> for segment::data_types::order_by::OrderValue {
+    type Error = Status;
+
+    fn try_from(value: OrderValue) -> Result<Self, Self::Error> {
+        match value.variant {
+            Some(order_value::Variant::Int(int)) => Ok(Self::Int(int)),
+            Some(order_value::Variant::Float(float)) => Ok(Self::Float(float)),
+            None => Err(Status::invalid_argument("Unknown order value")),
+        }
+    }
+}
@@ -177,7 +177,11 @@ impl Validate for crate::grpc::qdrant::FieldCondition {
             && self.geo_bounding_box.is_none()
             && self.geo_radius.is_none()
             && self.geo_polygon.is_none()
-            && self.values_count.is_none();
+            && self.values_count.is_none()
+            && self.datetime_range.is_none()
+            && self.keyword_range.is_none()
+            && self.geo_intersects.is_none()
+            && self.geo_within.is_none();
 
         if all_fields_none {
             let mut errors = ValidationErrors::new();
@@ -227,6 +231,11 @@ pub fn validate_u64_range_min_1(value: &Option<u64>) -> Result<(), ValidationErr
     value.map_or(Ok(()), |v| validate_range_generic(v, Some(1), None))
 }
 
+/// Validate the value is in `[2, ]` or `None`.
+pub fn validate_u64_range_min_2(value: &Option<u64>) -> Result<(), ValidationError> {
+    value.map_or(Ok(()), |v| validate_range_generic(v, Some(2), None))
+}
+
 /// Validate the value is in `[1, ]` or `None`.
 pub fn validate_u32_range_min_1(value: &Option<u32>) -> Result<(), ValidationError> {
     value.map_or(Ok(()), |v| validate_range_generic(v, Some(1), None))
@@ -5,6 +5,7 @@ use futures::{future, TryStreamExt as _};
 use segment::types::QuantizationConfig;
 
 use super::Collection;
+use crate::config::StrictModeConfig;
 use crate::operations::config_diff::*;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
@@ -117,6 +118,25 @@ impl Collection {
         Ok(())
     }
 
+    /// Updates strict mode config:
+    /// Saves new params on disk
+    ///
+    /// Limits which are not specified in the diff are left unchanged.
+    pub async fn update_strict_mode_config_from_diff(
+        &self,
+        strict_mode_diff: StrictModeConfig,
+    ) -> CollectionResult<()> {
+        {
+            let mut config = self.collection_config.write().await;
+            config.strict_mode_config = Some(match &config.strict_mode_config {
+                Some(strict_mode_config) => strict_mode_diff.update(strict_mode_config)?,
+                None => strict_mode_diff,
+            });
+        }
+        self.collection_config.read().await.save(&self.path)?;
+        Ok(())
+    }
+
     /// Updates quantization config:
     /// Saves new params on disk
     ///
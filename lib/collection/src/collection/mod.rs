@@ -6,6 +6,7 @@ mod shard_transfer;
 mod sharding_keys;
 mod snapshots;
 mod state_management;
+mod strict_mode;
 
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
@@ -22,6 +23,7 @@ use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::is_ready::IsReady;
+use crate::common::rate_limiting::RateLimiter;
 use crate::config::CollectionConfig;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{CollectionError, CollectionResult, NodeType};
@@ -66,6 +68,8 @@ pub struct Collection {
     update_runtime: Handle,
     // Search runtime handle.
     search_runtime: Handle,
+    // Limiter of the update rate set by the strict mode, created on the first limited update.
+    update_rate_limiter: parking_lot::Mutex<Option<RateLimiter>>,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -146,6 +150,7 @@ impl Collection {
             updates_lock: RwLock::new(()),
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
+            update_rate_limiter: parking_lot::Mutex::new(None),
         })
     }
 
@@ -240,6 +245,7 @@ impl Collection {
             updates_lock: RwLock::new(()),
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
+            update_rate_limiter: parking_lot::Mutex::new(None),
         }
     }
 
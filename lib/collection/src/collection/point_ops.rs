@@ -1,8 +1,12 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::{future, TryFutureExt, TryStreamExt as _};
 use itertools::Itertools as _;
-use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
+use rand::seq::IteratorRandom as _;
+use segment::data_types::facets::{merge_facet_counts, top_facet_hits};
+use segment::data_types::order_by::{OrderBy, StartFrom};
+use segment::types::{ExtendedPointId, Filter, ShardKey, WithPayload, WithPayloadInterface};
 use validator::Validate as _;
 
 use super::Collection;
@@ -153,6 +157,7 @@ impl Collection {
             .clone()
             .unwrap_or_else(|| default_request.with_payload.clone().unwrap());
         let with_vector = request.with_vector;
+        let order_by = request.order_by.map(OrderBy::from);
 
         if limit == 0 {
             return Err(CollectionError::BadRequest {
@@ -160,6 +165,15 @@ impl Collection {
             });
         }
 
+        if let Some(order_by) = &order_by {
+            if offset.is_some() && order_by.start_from.is_none() {
+                return Err(CollectionError::BadRequest {
+                    description: "`offset` can only be used together with `order_by.start_from`"
+                        .to_string(),
+                });
+            }
+        }
+
         // Needed to return next page offset.
         let limit = limit + 1;
         let retrieved_points: Vec<_> = {
@@ -176,6 +190,7 @@ impl Collection {
                         request.filter.as_ref(),
                         read_consistency,
                         shard_selection.is_shard_id(),
+                        order_by.as_ref(),
                     )
                     .and_then(move |mut records| async move {
                         if shard_key.is_none() {
@@ -190,23 +205,46 @@ impl Collection {
 
             future::try_join_all(scroll_futures).await?
         };
-        let mut points: Vec<_> = retrieved_points
-            .into_iter()
-            .flatten()
-            .sorted_by_key(|point| point.id)
-            .take(limit)
-            .collect();
 
-        let next_page_offset = if points.len() < limit {
+        let retrieved_iter = retrieved_points.into_iter().flatten();
+        let mut points: Vec<_> = match &order_by {
+            None => retrieved_iter
+                .sorted_by_key(|point| point.id)
+                .take(limit)
+                .collect(),
+            Some(order_by) => {
+                let direction = order_by.direction();
+                retrieved_iter
+                    .sorted_by(|a, b| {
+                        let value_ordering = match (&a.order_value, &b.order_value) {
+                            (Some(a_value), Some(b_value)) => {
+                                a_value.cmp_directed(b_value, direction)
+                            }
+                            // Records of an ordered scroll always have an order value
+                            (a_value, b_value) => a_value.cmp(b_value),
+                        };
+                        value_ordering.then_with(|| a.id.cmp(&b.id))
+                    })
+                    .take(limit)
+                    .collect()
+            }
+        };
+
+        let (next_page_offset, next_page_start_from) = if points.len() < limit {
             // This was the last page
-            None
+            (None, None)
         } else {
             // remove extra point, it would be a first point of the next page
-            Some(points.pop().unwrap().id)
+            let next_point = points.pop().unwrap();
+            (
+                Some(next_point.id),
+                next_point.order_value.map(StartFrom::from),
+            )
         };
         Ok(ScrollResult {
             points,
             next_page_offset,
+            next_page_start_from,
         })
     }
 
@@ -241,6 +279,107 @@ impl Collection {
         Ok(CountResult { count })
     }
 
+    /// Count points per value of the facet key over all selected shards
+    ///
+    /// In approximate mode each shard only reports its most frequent values.
+    pub async fn facet(
+        &self,
+        request: FacetRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<FacetResponse> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            // `facet` requests received through internal gRPC *always* have `shard_selection`
+            .map(|(shard, _shard_key)| {
+                shard.facet(
+                    request.clone(),
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                )
+            })
+            .collect();
+
+        let mut counts = HashMap::new();
+
+        while let Some(response) = requests.try_next().await? {
+            let shard_counts = response
+                .hits
+                .into_iter()
+                .map(|hit| (hit.value, hit.count))
+                .collect();
+            merge_facet_counts(&mut counts, shard_counts);
+        }
+
+        // Exact counts of internal requests are merged with other shards by the caller
+        let limit = if request.exact && shard_selection.is_shard_id() {
+            usize::MAX
+        } else {
+            request.limit
+        };
+
+        Ok(FacetResponse {
+            hits: top_facet_hits(counts, limit),
+        })
+    }
+
+    /// Select a random sample of at most `limit` points which satisfy the filter from all
+    /// selected shards
+    pub async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let requests = shards.into_iter().map(|(shard, _shard_key)| {
+            shard.sample_points(limit, filter, shard_selection.is_shard_id())
+        });
+        let all_points = future::try_join_all(requests).await?;
+
+        let point_ids = all_points
+            .into_iter()
+            .flatten()
+            .unique()
+            .choose_multiple(&mut rand::thread_rng(), limit);
+
+        Ok(point_ids)
+    }
+
+    /// Collect statistics of sparse vector dimensions from all selected shards
+    pub async fn sparse_vector_statistics(
+        &self,
+        request: SparseVectorStatisticsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: futures::stream::FuturesUnordered<_> = shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.sparse_vector_statistics(request.clone(), shard_selection.is_shard_id())
+            })
+            .collect();
+
+        let mut result = SparseVectorStatisticsResult::new();
+
+        while let Some(response) = requests.try_next().await? {
+            for (vector_name, statistics) in response {
+                result.entry(vector_name).or_default().merge(&statistics);
+            }
+        }
+
+        Ok(result)
+    }
+
     pub async fn retrieve(
         &self,
         request: PointRequestInternal,
@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
 use futures::{future, TryFutureExt};
+use segment::data_types::vectors::NamedVectorStruct;
 use segment::spaces::tools;
 use segment::types::{ExtendedPointId, Order, ScoredPoint, WithPayloadInterface, WithVector};
 
@@ -117,6 +118,49 @@ impl Collection {
         }
     }
 
+    /// Rank points of all selected shards by the BM25 score of the text in a full-text indexed
+    /// payload field
+    ///
+    /// Statistics used for scoring are collected per shard, so shards should be of similar
+    /// contents for the scores to be comparable.
+    pub async fn text_search(
+        &self,
+        request: TextSearchRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        if request.limit == 0 {
+            return Ok(vec![]);
+        }
+        let request = Arc::new(request);
+
+        let all_searches_res = {
+            let shard_holder = self.shards_holder.read().await;
+            let target_shards = shard_holder.select_shards(shard_selection)?;
+            let all_searches = target_shards.iter().map(|(shard, shard_key)| {
+                let shard_key = shard_key.cloned();
+                shard
+                    .text_search(
+                        Arc::clone(&request),
+                        read_consistency,
+                        shard_selection.is_shard_id(),
+                    )
+                    .map_ok(move |mut points| {
+                        for point in &mut points {
+                            point.shard_key = shard_key.clone();
+                        }
+                        points
+                    })
+            });
+            future::try_join_all(all_searches).await?
+        };
+
+        let mut points = all_searches_res.into_iter().flatten().collect::<Vec<_>>();
+        points.sort_unstable_by(|a, b| b.cmp(a));
+        points.truncate(request.limit);
+        Ok(points)
+    }
+
     async fn do_core_search_batch(
         &self,
         request: CoreSearchRequestBatch,
@@ -124,6 +168,12 @@ impl Collection {
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        // Internal requests are already weighted by the node which received the client request
+        let request = if shard_selection.is_shard_id() {
+            request
+        } else {
+            self.apply_sparse_idf(request, shard_selection).await?
+        };
         let request = Arc::new(request);
 
         // query all shards concurrently
@@ -158,6 +208,61 @@ impl Collection {
             .await
     }
 
+    /// Weight sparse queries by inverse document frequency, for vectors with the IDF modifier
+    ///
+    /// Statistics are collected from all target shards, so scores are comparable across shards.
+    async fn apply_sparse_idf(
+        &self,
+        mut request: CoreSearchRequestBatch,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<CoreSearchRequestBatch> {
+        let idf_vectors: HashSet<String> = {
+            let collection_params = &self.collection_config.read().await.params;
+            collection_params
+                .sparse_vectors
+                .iter()
+                .flatten()
+                .filter(|(_, params)| params.modifier == Some(Modifier::Idf))
+                .map(|(vector_name, _)| vector_name.clone())
+                .collect()
+        };
+        if idf_vectors.is_empty() {
+            return Ok(request);
+        }
+
+        let mut statistics_request = SparseVectorStatisticsRequest::default();
+        for search in &request.searches {
+            if let QueryEnum::Nearest(NamedVectorStruct::Sparse(query)) = &search.query {
+                if idf_vectors.contains(&query.name) {
+                    statistics_request
+                        .vectors
+                        .entry(query.name.clone())
+                        .or_default()
+                        .extend_from_slice(&query.vector.indices);
+                }
+            }
+        }
+        if statistics_request.vectors.is_empty() {
+            return Ok(request);
+        }
+        for indices in statistics_request.vectors.values_mut() {
+            indices.sort_unstable();
+            indices.dedup();
+        }
+
+        let statistics = self
+            .sparse_vector_statistics(statistics_request, shard_selection)
+            .await?;
+        for search in &mut request.searches {
+            if let QueryEnum::Nearest(NamedVectorStruct::Sparse(query)) = &mut search.query {
+                if let Some(statistics) = statistics.get(&query.name) {
+                    statistics.apply_idf(&mut query.vector);
+                }
+            }
+        }
+        Ok(request)
+    }
+
     pub(crate) async fn fill_search_result_with_payload(
         &self,
         search_result: Vec<ScoredPoint>,
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use super::Collection;
+use crate::common::rate_limiting::RateLimiter;
+use crate::config::StrictModeConfig;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::verification::{
+    check_batch_size, check_timeout, check_update_strict_mode, StrictModeVerification,
+};
+use crate::operations::CollectionUpdateOperations;
+
+impl Collection {
+    /// Strict mode config of the collection, if strict mode is enabled
+    pub async fn strict_mode_config(&self) -> Option<StrictModeConfig> {
+        self.collection_config
+            .read()
+            .await
+            .strict_mode_config
+            .clone()
+            .filter(StrictModeConfig::is_enabled)
+    }
+
+    /// Check a read request against the limits of the strict mode, if enabled
+    pub async fn check_strict_mode(
+        &self,
+        request: &impl StrictModeVerification,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<()> {
+        self.check_strict_mode_batch([request].into_iter(), timeout)
+            .await
+    }
+
+    /// Check a batch of read requests against the limits of the strict mode, if enabled
+    pub async fn check_strict_mode_batch<'a, R: StrictModeVerification + 'a>(
+        &self,
+        requests: impl ExactSizeIterator<Item = &'a R>,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<()> {
+        let Some(strict_mode_config) = self.strict_mode_config().await else {
+            return Ok(());
+        };
+
+        check_timeout(timeout, &strict_mode_config)?;
+        check_batch_size(requests.len(), &strict_mode_config)?;
+
+        let payload_index_schema = self.payload_index_schema.read();
+        requests.into_iter().try_for_each(|request| {
+            request.check_strict_mode(&strict_mode_config, &payload_index_schema.schema)
+        })
+    }
+
+    /// Check an update operation against the limits of the strict mode, if enabled
+    ///
+    /// Accepted operations count towards the update rate limit.
+    pub async fn check_strict_mode_update(
+        &self,
+        operation: &CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let Some(strict_mode_config) = self.strict_mode_config().await else {
+            return Ok(());
+        };
+
+        check_update_strict_mode(
+            operation,
+            &strict_mode_config,
+            &self.payload_index_schema.read().schema,
+        )?;
+
+        self.check_update_rate(strict_mode_config.write_rate_limit)
+    }
+
+    fn check_update_rate(&self, write_rate_limit: Option<usize>) -> CollectionResult<()> {
+        let mut rate_limiter = self.update_rate_limiter.lock();
+
+        let Some(rate) = write_rate_limit else {
+            *rate_limiter = None;
+            return Ok(());
+        };
+
+        // The limiter is recreated when the rate is changed
+        if !matches!(&*rate_limiter, Some(limiter) if limiter.rate_per_minute() == rate) {
+            *rate_limiter = Some(RateLimiter::new_per_minute(rate));
+        }
+
+        let allowed = rate_limiter
+            .as_mut()
+            .is_none_or(|limiter| limiter.try_consume());
+        if !allowed {
+            return Err(CollectionError::bad_request(format!(
+                "The rate limit of {rate} updates per minute is exceeded in strict mode",
+            )));
+        }
+        Ok(())
+    }
+}
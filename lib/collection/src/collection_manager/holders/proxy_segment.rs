@@ -4,9 +4,15 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
+use rand::seq::IteratorRandom;
 use segment::common::operation_error::{OperationResult, SegmentFailedState};
+use segment::data_types::facets::{merge_facet_counts, top_facet_hits, FacetParams, FacetValue};
 use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::order_by::{OrderBy, OrderValue};
+use segment::data_types::sparse_statistics::SparseVectorStatistics;
+use segment::data_types::text_statistics::TextStatistics;
 use segment::data_types::vectors::{QueryVector, Vector};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::field_index::CardinalityEstimation;
@@ -16,6 +22,7 @@ use segment::types::{
     ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType, WithPayload,
     WithVector,
 };
+use sparse::common::types::DimId;
 
 use crate::collection_manager::holders::segment_holder::LockedSegment;
 
@@ -506,6 +513,161 @@ impl SegmentEntry for ProxySegment {
         read_points
     }
 
+    fn read_random_filtered(&self, limit: usize, filter: Option<&Filter>) -> Vec<PointIdType> {
+        let deleted_points = self.deleted_points.read();
+        let wrapped_points = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_random_filtered(limit, filter)
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_random_filtered(limit, Some(&wrapped_filter))
+        };
+        let write_segment_points = self
+            .write_segment
+            .get()
+            .read()
+            .read_random_filtered(limit, filter);
+        // Points may be in both segments while they are being moved
+        wrapped_points
+            .into_iter()
+            .chain(write_segment_points)
+            .unique()
+            .choose_multiple(&mut rand::thread_rng(), limit)
+    }
+
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+        offset: Option<PointIdType>,
+    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+        let deleted_points = self.deleted_points.read();
+        let mut read_points = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_ordered_filtered(limit, filter, order_by, offset)?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().read_ordered_filtered(
+                limit,
+                Some(&wrapped_filter),
+                order_by,
+                offset,
+            )?
+        };
+        let mut write_segment_points = self
+            .write_segment
+            .get()
+            .read()
+            .read_ordered_filtered(limit, filter, order_by, offset)?;
+        read_points.append(&mut write_segment_points);
+
+        let direction = order_by.direction();
+        read_points.sort_unstable_by(|(value_a, id_a), (value_b, id_b)| {
+            value_a
+                .cmp_directed(value_b, direction)
+                .then_with(|| id_a.cmp(id_b))
+        });
+        if let Some(limit) = limit {
+            read_points.truncate(limit);
+        }
+        Ok(read_points)
+    }
+
+    /// Count values of the faceted key among points of both segments, except the deleted ones
+    fn facet(
+        &self,
+        request: &FacetParams,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<HashMap<FacetValue, usize>> {
+        let deleted_points = self.deleted_points.read();
+        let mut counts = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .facet(request, is_stopped)?
+        } else {
+            let wrapped_request = FacetParams {
+                filter: Some(self.add_deleted_points_condition_to_filter(
+                    request.filter.as_ref(),
+                    &deleted_points,
+                )),
+                ..request.clone()
+            };
+            self.wrapped_segment
+                .get()
+                .read()
+                .facet(&wrapped_request, is_stopped)?
+        };
+        let write_segment_counts = self.write_segment.get().read().facet(request, is_stopped)?;
+        merge_facet_counts(&mut counts, write_segment_counts);
+
+        if request.exact {
+            return Ok(counts);
+        }
+        Ok(top_facet_hits(counts, request.limit)
+            .into_iter()
+            .map(|hit| (hit.value, hit.count))
+            .collect())
+    }
+
+    fn text_statistics(&self, key: &PayloadKeyType, text: &str) -> OperationResult<TextStatistics> {
+        // Points updated during the optimization are counted in both segments. It only shifts
+        // document frequencies and average document length by the share of updated points,
+        // so BM25 ranking is barely affected.
+        let mut statistics = self
+            .wrapped_segment
+            .get()
+            .read()
+            .text_statistics(key, text)?;
+        statistics.merge(&self.write_segment.get().read().text_statistics(key, text)?);
+        Ok(statistics)
+    }
+
+    fn text_search(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        statistics: &TextStatistics,
+        filter: Option<&Filter>,
+        top: usize,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let deleted_points = self.deleted_points.read();
+        let mut wrapped_result = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .text_search(key, text, statistics, filter, top)?
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment.get().read().text_search(
+                key,
+                text,
+                statistics,
+                Some(&wrapped_filter),
+                top,
+            )?
+        };
+        let mut write_result = self
+            .write_segment
+            .get()
+            .read()
+            .text_search(key, text, statistics, filter, top)?;
+
+        wrapped_result.append(&mut write_result);
+        Ok(wrapped_result)
+    }
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let deleted_points = self.deleted_points.read();
@@ -763,6 +925,27 @@ impl SegmentEntry for ProxySegment {
         self.write_segment.get().read().vector_dims()
     }
 
+    fn sparse_vector_statistics(
+        &self,
+        vector_name: &str,
+        indices: &[DimId],
+    ) -> OperationResult<SparseVectorStatistics> {
+        // Points deleted from the wrapped segment are still counted, statistics are approximate anyway
+        let mut statistics = self
+            .wrapped_segment
+            .get()
+            .read()
+            .sparse_vector_statistics(vector_name, indices)?;
+        statistics.merge(
+            &self
+                .write_segment
+                .get()
+                .read()
+                .sparse_vector_statistics(vector_name, indices)?,
+        );
+        Ok(statistics)
+    }
+
     fn take_snapshot(
         &self,
         temp_path: &Path,
@@ -1331,6 +1514,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
                 (
@@ -1341,6 +1526,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
             ]),
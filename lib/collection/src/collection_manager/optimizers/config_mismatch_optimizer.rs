@@ -128,8 +128,9 @@ impl ConfigMismatchOptimizer {
                     return None; // Never optimize already optimized segment
                 }
 
-                if self.collection_params.on_disk_payload
-                    != segment_config.payload_storage_type.is_on_disk()
+                // On disk payload is moved between RocksDB and mmap storage only if `mmap_payload` is changed
+                if segment_config.payload_storage_type
+                    != self.collection_params.payload_storage_type()
                 {
                     return Some((*idx, vector_size)); // Skip segments with payload mismatch
                 }
@@ -161,7 +162,11 @@ impl ConfigMismatchOptimizer {
                             }
 
                             // Check quantization mismatch
-                            let target_quantization_collection = self.quantization_config.as_ref();
+                            // Collection quantization is not applied to multi vectors
+                            let target_quantization_collection = self
+                                .quantization_config
+                                .as_ref()
+                                .filter(|_| vector_data.multivector_config.is_none());
                             let target_quantization_vector = self
                                 .collection_params
                                 .vectors
@@ -272,11 +277,14 @@ mod tests {
     use std::sync::Arc;
 
     use parking_lot::RwLock;
+    use segment::data_types::vectors::only_default_vector;
     use segment::entry::entry_point::SegmentEntry;
+    use segment::segment_constructor::build_segment;
     use segment::types::{
-        CompressionRatio, Distance, ProductQuantization, ProductQuantizationConfig,
-        ScalarQuantizationConfig, ScalarType,
+        CompressionRatio, Distance, Payload, PayloadStorageType, ProductQuantization,
+        ProductQuantizationConfig, ScalarQuantizationConfig, ScalarType, SegmentConfig,
     };
+    use serde_json::json;
     use tempfile::Builder;
 
     use super::*;
@@ -316,6 +324,8 @@ mod tests {
                 hnsw_config: None,
                 quantization_config: None,
                 on_disk: None,
+                multivector_config: None,
+                datatype: None,
             }),
             ..CollectionParams::empty()
         };
@@ -337,6 +347,7 @@ mod tests {
             max_indexing_threads: 0,
             on_disk: None,
             payload_m: None,
+            range_links: None,
         };
 
         // Optimizers used in test
@@ -448,6 +459,8 @@ mod tests {
                         hnsw_config: Some(hnsw_config_vector1),
                         quantization_config: None,
                         on_disk: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
                 (
@@ -458,6 +471,8 @@ mod tests {
                         hnsw_config: None,
                         quantization_config: None,
                         on_disk: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
             ])),
@@ -487,6 +502,7 @@ mod tests {
             max_indexing_threads: 0,
             on_disk: None,
             payload_m: None,
+            range_links: None,
         };
 
         // Optimizers used in test
@@ -613,6 +629,8 @@ mod tests {
                         hnsw_config: None,
                         quantization_config: Some(quantization_config_vector1.clone()),
                         on_disk: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
                 (
@@ -623,6 +641,8 @@ mod tests {
                         hnsw_config: None,
                         quantization_config: None,
                         on_disk: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
             ])),
@@ -736,4 +756,113 @@ mod tests {
                 );
             });
     }
+
+    /// This tests the config mismatch optimizer migrating payload stored in RocksDB
+    ///
+    /// It tests whether:
+    /// - segments with on disk payload in RocksDB are kept, unless mmap payload storage is enabled
+    /// - segments with on disk payload in RocksDB are selected for optimization once it is enabled
+    /// - optimized segments use mmap payload storage and preserve the payload
+    #[test]
+    fn test_payload_storage_migration() {
+        // Collection configuration
+        let (point_count, dim) = (100, 4);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size: usize::MAX,
+            memmap_threshold: usize::MAX,
+            indexing_threshold: usize::MAX,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(VectorParams {
+                size: dim.try_into().unwrap(),
+                distance: Distance::Dot,
+                hnsw_config: None,
+                quantization_config: None,
+                on_disk: None,
+                multivector_config: None,
+                datatype: None,
+            }),
+            on_disk_payload: true,
+            ..CollectionParams::empty()
+        };
+
+        // Base segment with payload in RocksDB
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segment_config = SegmentConfig {
+            vector_data: collection_params.into_base_vector_data().unwrap(),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: PayloadStorageType::OnDisk,
+        };
+        let mut segment = build_segment(dir.path(), &segment_config, true).unwrap();
+        for point_id in 0..point_count {
+            let payload: Payload = json!({ "number": point_id }).into();
+            segment
+                .upsert_point(100, point_id.into(), only_default_vector(&[1.0; 4]))
+                .unwrap();
+            segment.set_payload(100, point_id.into(), &payload).unwrap();
+        }
+
+        let mut holder = SegmentHolder::default();
+        holder.add(segment);
+        let locked_holder: Arc<RwLock<_>> = Arc::new(RwLock::new(holder));
+
+        // RocksDB payload storage matches on disk payload without explicit opt-in
+        let config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config.clone(),
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            Default::default(),
+            Default::default(),
+        );
+        let suggested_to_optimize =
+            config_mismatch_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert!(suggested_to_optimize.is_empty());
+
+        let config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            CollectionParams {
+                mmap_payload: Some(true),
+                ..collection_params
+            },
+            Default::default(),
+            Default::default(),
+        );
+
+        let suggested_to_optimize =
+            config_mismatch_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert_eq!(suggested_to_optimize.len(), 1);
+        let changed = config_mismatch_optimizer
+            .optimize(locked_holder.clone(), suggested_to_optimize, &false.into())
+            .unwrap();
+        assert!(changed, "optimizer should have rebuilt this segment");
+
+        // Ensure new segment uses mmap payload storage with the same payload
+        locked_holder
+            .read()
+            .iter()
+            .map(|(_, segment)| match segment {
+                LockedSegment::Original(s) => s.read(),
+                LockedSegment::Proxy(_) => unreachable!(),
+            })
+            .filter(|segment| segment.total_point_count() > 0)
+            .for_each(|segment| {
+                assert_eq!(
+                    segment.config().payload_storage_type,
+                    PayloadStorageType::Mmap,
+                );
+                for point_id in 0..point_count {
+                    let expected: Payload = json!({ "number": point_id }).into();
+                    assert_eq!(segment.payload(point_id.into()).unwrap(), expected);
+                }
+            });
+
+        // Migrated segments have no mismatch anymore
+        let suggested_to_optimize =
+            config_mismatch_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert!(suggested_to_optimize.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::ops::Deref;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -16,8 +17,8 @@ use segment::segment::{Segment, SegmentVersion};
 use segment::segment_constructor::build_segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
 use segment::types::{
-    HnswConfig, Indexes, PayloadFieldSchema, PayloadKeyType, PayloadStorageType, PointIdType,
-    QuantizationConfig, SegmentConfig, VectorStorageType, VECTOR_ELEMENT_SIZE,
+    HnswConfig, Indexes, PayloadFieldSchema, PayloadKeyType, PointIdType, QuantizationConfig,
+    SegmentConfig, VectorStorageType, VECTOR_ELEMENT_SIZE,
 };
 
 use crate::collection_manager::holders::proxy_segment::ProxySegment;
@@ -84,11 +85,7 @@ pub trait SegmentOptimizer {
         let config = SegmentConfig {
             vector_data: collection_params.into_base_vector_data()?,
             sparse_vector_data: collection_params.into_sparse_vector_data()?,
-            payload_storage_type: if collection_params.on_disk_payload {
-                PayloadStorageType::OnDisk
-            } else {
-                PayloadStorageType::InMemory
-            },
+            payload_storage_type: collection_params.payload_storage_type(),
         };
         Ok(LockedSegment::new(build_segment(
             self.collection_path(),
@@ -178,8 +175,11 @@ pub trait SegmentOptimizer {
                     .vectors
                     .get_params(vector_name)
                     .and_then(|params| params.quantization_config.as_ref());
+                // Collection quantization is not applied to multi vectors
                 let vector_quantization = param_quantization
-                    .or(collection_quantization.as_ref())
+                    .or(collection_quantization
+                        .as_ref()
+                        .filter(|_| config.multivector_config.is_none()))
                     .cloned();
                 config.quantization_config = vector_quantization;
             });
@@ -238,11 +238,7 @@ pub trait SegmentOptimizer {
         let optimized_config = SegmentConfig {
             vector_data,
             sparse_vector_data,
-            payload_storage_type: if collection_params.on_disk_payload {
-                PayloadStorageType::OnDisk
-            } else {
-                PayloadStorageType::InMemory
-            },
+            payload_storage_type: collection_params.payload_storage_type(),
         };
 
         Ok(SegmentBuilder::new(
@@ -354,15 +350,17 @@ pub trait SegmentOptimizer {
 
         self.check_cancellation(stopped)?;
 
-        for segment in optimizing_segments {
-            match segment {
-                LockedSegment::Original(segment_arc) => {
-                    let segment_guard = segment_arc.read();
-                    segment_builder.update_from(&segment_guard, stopped)?;
-                }
+        let segment_guards: Vec<_> = optimizing_segments
+            .iter()
+            .map(|segment| match segment {
+                LockedSegment::Original(segment_arc) => segment_arc.read(),
                 LockedSegment::Proxy(_) => panic!("Attempt to optimize segment which is already currently under optimization. Should never happen"),
-            }
-        }
+            })
+            .collect();
+        // All segments are passed at once, so points of a tenant are grouped across all of them
+        let segments: Vec<&Segment> = segment_guards.iter().map(|guard| guard.deref()).collect();
+        segment_builder.update(&segments, stopped)?;
+        drop(segment_guards);
 
         for field in proxy_deleted_indexes.read().iter() {
             segment_builder.indexed_fields.remove(field);
@@ -332,6 +332,7 @@ impl SegmentsSearcher {
                             }
                         },
                         shard_key: None,
+                        order_value: None,
                     },
                 );
                 point_version.insert(id, version);
@@ -235,6 +235,7 @@ pub fn convert_to_vectors_owned(
         .filter_map(|example| match example {
             RecommendExample::Dense(vector) => Some(vector.into()),
             RecommendExample::Sparse(vector) => Some(vector.into()),
+            RecommendExample::MultiDense(vector) => Some(vector.into()),
             RecommendExample::PointId(vid) => {
                 let rec = all_vectors_records_map.get(&collection_name, vid).unwrap();
                 rec.get_vector_by_name(vector_name).map(|v| v.to_owned())
@@ -252,6 +253,7 @@ pub fn convert_to_vectors<'a>(
     examples.filter_map(move |example| match example {
         RecommendExample::Dense(vector) => Some(vector.into()),
         RecommendExample::Sparse(vector) => Some(vector.into()),
+        RecommendExample::MultiDense(vector) => Some(vector.into()),
         RecommendExample::PointId(vid) => {
             let rec = all_vectors_records_map.get(&collection_name, *vid).unwrap();
             rec.get_vector_by_name(vector_name)
@@ -0,0 +1,74 @@
+use std::time::Instant;
+
+/// Token bucket rate limiter, which is refilled continuously
+///
+/// The bucket starts full, so that a burst of up to the whole rate is allowed at once.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Capacity of the bucket, and number of tokens refilled per minute
+    rate_per_minute: usize,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new_per_minute(rate_per_minute: usize) -> Self {
+        Self {
+            rate_per_minute,
+            tokens: rate_per_minute as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    pub fn rate_per_minute(&self) -> usize {
+        self.rate_per_minute
+    }
+
+    /// Take a token from the bucket, returns false if there is none left
+    pub fn try_consume(&mut self) -> bool {
+        self.try_consume_at(Instant::now())
+    }
+
+    fn try_consume_at(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let capacity = self.rate_per_minute as f64;
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * capacity / 60.0).min(capacity);
+        self.last_refill = now;
+
+        if self.tokens < 1.0 {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new_per_minute(60);
+        let start = limiter.last_refill;
+
+        for _ in 0..60 {
+            assert!(limiter.try_consume_at(start));
+        }
+        assert!(!limiter.try_consume_at(start));
+
+        // A token is refilled every second
+        assert!(!limiter.try_consume_at(start + Duration::from_millis(500)));
+        assert!(limiter.try_consume_at(start + Duration::from_secs(1)));
+        assert!(!limiter.try_consume_at(start + Duration::from_secs(1)));
+
+        // The bucket is not refilled beyond its capacity
+        let later = start + Duration::from_secs(3600);
+        for _ in 0..60 {
+            assert!(limiter.try_consume_at(later));
+        }
+        assert!(!limiter.try_consume_at(later));
+    }
+}
@@ -6,13 +6,14 @@ use std::path::Path;
 
 use atomicwrites::AtomicFile;
 use atomicwrites::OverwriteBehavior::AllowOverwrite;
+use merge::Merge;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
-    Distance, HnswConfig, Indexes, QuantizationConfig, SparseVectorDataConfig, VectorDataConfig,
-    VectorStorageType,
+    Distance, HnswConfig, Indexes, PayloadStorageType, QuantizationConfig, SparseVectorDataConfig,
+    VectorDataConfig, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -100,6 +101,11 @@ pub struct CollectionParams {
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default = "default_on_disk_payload")]
     pub on_disk_payload: bool,
+    /// If true together with `on_disk_payload` - payload is stored in memory mapped pages instead of RocksDB.
+    /// Segments with payload in RocksDB are rebuilt by the optimizers after this option is enabled.
+    /// Default is false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mmap_payload: Option<bool>,
     /// Configuration of the sparse vector storage
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate]
@@ -116,6 +122,7 @@ impl Anonymize for CollectionParams {
             write_consistency_factor: self.write_consistency_factor,
             read_fan_out_factor: self.read_fan_out_factor,
             on_disk_payload: self.on_disk_payload,
+            mmap_payload: self.mmap_payload,
             sparse_vectors: self.sparse_vectors.anonymize(),
         }
     }
@@ -137,6 +144,60 @@ const fn default_on_disk_payload() -> bool {
     false
 }
 
+/// Limits enforced on the requests made to a collection, so that no single client can degrade the
+/// whole node with expensive requests.
+///
+/// Requests exceeding a limit are rejected rather than clamped. A limit which is not set is not
+/// enforced.
+#[derive(
+    Debug, Default, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash, Merge,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct StrictModeConfig {
+    /// Whether strict mode is enabled for the collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Max allowed `limit` of the read requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_query_limit: Option<usize>,
+    /// Max allowed `offset` of the read requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_offset: Option<usize>,
+    /// Max allowed `timeout` of the requests, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_timeout: Option<usize>,
+    /// Allow filtering reads by payload fields which are not indexed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unindexed_filtering_retrieve: Option<bool>,
+    /// Allow filtering updates by payload fields which are not indexed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unindexed_filtering_update: Option<bool>,
+    /// Max allowed `hnsw_ef` of the search params
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub search_max_hnsw_ef: Option<usize>,
+    /// Max number of requests in a batch of read requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub search_max_batchsize: Option<usize>,
+    /// Max number of points in a single upsert
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub upsert_max_batchsize: Option<usize>,
+    /// Max number of update operations per minute, on each node receiving them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub write_rate_limit: Option<usize>,
+}
+
+impl StrictModeConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
 pub struct CollectionConfig {
     #[validate]
@@ -149,6 +210,9 @@ pub struct CollectionConfig {
     pub wal_config: WalConfig,
     #[serde(default)]
     pub quantization_config: Option<QuantizationConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub strict_mode_config: Option<StrictModeConfig>,
 }
 
 impl CollectionConfig {
@@ -193,10 +257,20 @@ impl CollectionParams {
             write_consistency_factor: default_write_consistency_factor(),
             read_fan_out_factor: None,
             on_disk_payload: default_on_disk_payload(),
+            mmap_payload: None,
             sparse_vectors: None,
         }
     }
 
+    /// Payload storage type for new and optimized segments
+    pub fn payload_storage_type(&self) -> PayloadStorageType {
+        match (self.on_disk_payload, self.mmap_payload.unwrap_or_default()) {
+            (false, _) => PayloadStorageType::InMemory,
+            (true, false) => PayloadStorageType::OnDisk,
+            (true, true) => PayloadStorageType::Mmap,
+        }
+    }
+
     pub fn get_distance(&self, vector_name: &str) -> CollectionResult<Distance> {
         match self.vectors.get_params(vector_name) {
             Some(params) => Ok(params.distance),
@@ -272,6 +346,13 @@ impl CollectionParams {
             }
 
             if let Some(quantization_diff) = quantization_config {
+                if vector_params.multivector_config.is_some()
+                    && !matches!(quantization_diff, QuantizationConfigDiff::Disabled(_))
+                {
+                    return Err(CollectionError::bad_input(format!(
+                        "Multi vectors {vector_name} can't be quantized"
+                    )));
+                }
                 vector_params.quantization_config = match quantization_diff.clone() {
                     QuantizationConfigDiff::Scalar(scalar) => {
                         Some(QuantizationConfig::Scalar(scalar))
@@ -300,7 +381,7 @@ impl CollectionParams {
     ) -> CollectionResult<()> {
         for (vector_name, update_params) in update_vectors.0.iter() {
             let sparse_vector_params = self.get_sparse_vector_params_mut(vector_name)?;
-            let SparseVectorParams { index } = update_params.clone();
+            let SparseVectorParams { index, modifier } = update_params.clone();
 
             if let Some(index) = index {
                 if let Some(existing_index) = &mut sparse_vector_params.index {
@@ -309,6 +390,10 @@ impl CollectionParams {
                     sparse_vector_params.index = Some(index);
                 }
             }
+
+            if let Some(modifier) = modifier {
+                sparse_vector_params.modifier = Some(modifier);
+            }
         }
         Ok(())
     }
@@ -337,6 +422,8 @@ impl CollectionParams {
                         } else {
                             VectorStorageType::Memory
                         },
+                        multivector_config: params.multivector_config,
+                        datatype: params.datatype,
                     },
                 )
             })
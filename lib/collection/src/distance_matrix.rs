@@ -0,0 +1,252 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use segment::data_types::vectors::{NamedVectorStruct, DEFAULT_VECTOR_NAME};
+use segment::types::{Condition, ExtendedPointId, Filter, HasIdCondition, ScoredPoint, WithVector};
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    CollectionResult, CoreSearchRequest, CoreSearchRequestBatch, PointRequestInternal, QueryEnum,
+    SearchMatrixOffsetsResponse, SearchMatrixPair, SearchMatrixPairsResponse,
+    SearchMatrixRequestInternal, UsingVector,
+};
+
+/// Nearest neighbours of every sampled point within the sample
+#[derive(Debug, Default)]
+pub struct CollectionSearchMatrixResponse {
+    /// Sampled points, which have the requested vector
+    pub sample_ids: Vec<ExtendedPointId>,
+    /// Neighbours of each sampled point, in the order of `sample_ids`
+    pub nearests: Vec<Vec<ScoredPoint>>,
+}
+
+impl From<CollectionSearchMatrixResponse> for SearchMatrixPairsResponse {
+    fn from(response: CollectionSearchMatrixResponse) -> Self {
+        let CollectionSearchMatrixResponse {
+            sample_ids,
+            nearests,
+        } = response;
+
+        let pairs = sample_ids
+            .into_iter()
+            .zip(nearests)
+            .flat_map(|(id, nearest)| {
+                nearest.into_iter().map(move |point| SearchMatrixPair {
+                    a: id,
+                    b: point.id,
+                    score: point.score,
+                })
+            })
+            .collect();
+
+        SearchMatrixPairsResponse { pairs }
+    }
+}
+
+impl From<CollectionSearchMatrixResponse> for SearchMatrixOffsetsResponse {
+    fn from(response: CollectionSearchMatrixResponse) -> Self {
+        let CollectionSearchMatrixResponse {
+            sample_ids,
+            nearests,
+        } = response;
+
+        let offsets: HashMap<_, _> = sample_ids
+            .iter()
+            .enumerate()
+            .map(|(offset, id)| (*id, offset as u64))
+            .collect();
+
+        let mut offsets_row = Vec::new();
+        let mut offsets_col = Vec::new();
+        let mut scores = Vec::new();
+        for (row, nearest) in nearests.into_iter().enumerate() {
+            for point in nearest {
+                // Neighbours are always searched within the sample
+                let Some(&col) = offsets.get(&point.id) else {
+                    continue;
+                };
+                offsets_row.push(row as u64);
+                offsets_col.push(col);
+                scores.push(point.score);
+            }
+        }
+
+        SearchMatrixOffsetsResponse {
+            offsets_row,
+            offsets_col,
+            scores,
+            ids: sample_ids,
+        }
+    }
+}
+
+/// Search the neighbours of a sampled point among the other sampled points
+fn sample_search_request(
+    id: ExtendedPointId,
+    vector: NamedVectorStruct,
+    sample: &HashSet<ExtendedPointId>,
+    limit: usize,
+) -> CoreSearchRequest {
+    let filter = Filter {
+        should: None,
+        min_should: None,
+        must: Some(vec![Condition::HasId(HasIdCondition::from(sample.clone()))]),
+        must_not: Some(vec![Condition::HasId(HasIdCondition::from(HashSet::from(
+            [id],
+        )))]),
+    };
+
+    CoreSearchRequest {
+        query: QueryEnum::Nearest(vector),
+        filter: Some(filter),
+        params: None,
+        limit,
+        offset: 0,
+        with_payload: None,
+        with_vector: None,
+        score_threshold: None,
+    }
+}
+
+/// Sample random points which satisfy the filter and search the nearest neighbours of each of
+/// them within the sample.
+pub async fn search_points_matrix(
+    request: SearchMatrixRequestInternal,
+    collection: &Collection,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> CollectionResult<CollectionSearchMatrixResponse> {
+    let SearchMatrixRequestInternal {
+        filter,
+        sample,
+        limit,
+        using,
+    } = request;
+
+    let vector_name = match using {
+        None => DEFAULT_VECTOR_NAME.to_string(),
+        Some(UsingVector::Name(name)) => name,
+    };
+
+    // Fail early if the vector does not exist
+    collection
+        .collection_config
+        .read()
+        .await
+        .params
+        .get_distance(&vector_name)?;
+
+    let sample_ids = collection
+        .sample_points(sample, filter.as_ref(), &shard_selector)
+        .await?;
+    if sample_ids.len() < 2 {
+        return Ok(CollectionSearchMatrixResponse::default());
+    }
+
+    let records = collection
+        .retrieve(
+            PointRequestInternal {
+                ids: sample_ids,
+                with_payload: None,
+                with_vector: WithVector::Selector(vec![vector_name.clone()]),
+            },
+            read_consistency,
+            &shard_selector,
+        )
+        .await?;
+
+    // Points without the vector can't be searched with, nor be found
+    let sample_vectors: Vec<_> = records
+        .into_iter()
+        .filter_map(|record| {
+            let vector = record.vector?.get(&vector_name)?.to_vec();
+            Some((record.id, vector))
+        })
+        .collect();
+    let sample: HashSet<_> = sample_vectors.iter().map(|(id, _)| *id).collect();
+
+    let searches = sample_vectors
+        .iter()
+        .map(|(id, vector)| {
+            let vector = NamedVectorStruct::new_from_vector(vector.clone(), vector_name.clone());
+            sample_search_request(*id, vector, &sample, limit)
+        })
+        .collect();
+
+    let nearests = collection
+        .core_search_batch(
+            CoreSearchRequestBatch { searches },
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await?;
+
+    Ok(CollectionSearchMatrixResponse {
+        sample_ids: sample_vectors.into_iter().map(|(id, _)| id).collect(),
+        nearests,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+        }
+    }
+
+    fn matrix_response() -> CollectionSearchMatrixResponse {
+        CollectionSearchMatrixResponse {
+            sample_ids: vec![10.into(), 20.into(), 30.into()],
+            nearests: vec![
+                vec![scored(30, 0.9), scored(20, 0.5)],
+                vec![scored(10, 0.5)],
+                vec![scored(10, 0.9)],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_search_matrix_pairs() {
+        let response = SearchMatrixPairsResponse::from(matrix_response());
+        let pairs: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|pair| (pair.a, pair.b, pair.score))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (10.into(), 30.into(), 0.9),
+                (10.into(), 20.into(), 0.5),
+                (20.into(), 10.into(), 0.5),
+                (30.into(), 10.into(), 0.9),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_search_matrix_offsets() {
+        let response = SearchMatrixOffsetsResponse::from(matrix_response());
+        assert_eq!(
+            response,
+            SearchMatrixOffsetsResponse {
+                offsets_row: vec![0, 0, 1, 2],
+                offsets_col: vec![2, 1, 0, 0],
+                scores: vec![0.9, 0.5, 0.5, 0.9],
+                ids: vec![10.into(), 20.into(), 30.into()],
+            },
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use segment::data_types::vectors::Named;
+use segment::types::{ExtendedPointId, Order, ScoredPoint, WithPayloadInterface};
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    CollectionResult, CoreSearchRequest, CoreSearchRequestBatch, Fusion,
+    HybridSearchRequestInternal, QueryEnum,
+};
+
+/// Constant of the reciprocal rank fusion, dampens the impact of the top ranks
+const RRF_K: f32 = 60.0;
+
+pub(crate) const DEFAULT_PREFETCH_WEIGHT: f32 = 1.0;
+
+fn hybrid_into_core_search_batch(request: &HybridSearchRequestInternal) -> CoreSearchRequestBatch {
+    let searches = request
+        .prefetch
+        .iter()
+        .map(|prefetch| {
+            let filter = match (&request.filter, &prefetch.filter) {
+                (None, None) => None,
+                (Some(filter), None) | (None, Some(filter)) => Some(filter.clone()),
+                (Some(filter), Some(prefetch_filter)) => Some(filter.merge(prefetch_filter)),
+            };
+
+            CoreSearchRequest {
+                query: QueryEnum::Nearest(prefetch.vector.clone()),
+                filter,
+                params: prefetch.params,
+                limit: prefetch.limit,
+                offset: 0,
+                // Payload and vectors are only fetched for the fused results
+                with_payload: None,
+                with_vector: None,
+                score_threshold: prefetch.score_threshold,
+            }
+        })
+        .collect();
+
+    CoreSearchRequestBatch { searches }
+}
+
+/// Reciprocal rank fusion: every prefetch contributes `weight / (k + rank)` to the score of each
+/// point it found, ranks are 1-based.
+fn rrf_scores(
+    prefetch_results: &[Vec<ScoredPoint>],
+    weights: &[f32],
+) -> HashMap<ExtendedPointId, f32> {
+    let mut scores: HashMap<ExtendedPointId, f32> = HashMap::new();
+    for (points, weight) in prefetch_results.iter().zip(weights) {
+        for (rank, point) in points.iter().enumerate() {
+            *scores.entry(point.id).or_default() += weight / (RRF_K + rank as f32 + 1.0);
+        }
+    }
+    scores
+}
+
+/// Weighted sum of the min-max normalized scores of every prefetch.
+/// Scores of the `SmallBetter` distances are inverted, so that `1.0` is always the best match.
+fn normalized_scores(
+    prefetch_results: &[Vec<ScoredPoint>],
+    weights: &[f32],
+    orders: &[Order],
+) -> HashMap<ExtendedPointId, f32> {
+    let mut scores: HashMap<ExtendedPointId, f32> = HashMap::new();
+    for ((points, weight), order) in prefetch_results.iter().zip(weights).zip(orders) {
+        let Some((min, max)) = points
+            .iter()
+            .map(|point| point.score)
+            .minmax()
+            .into_option()
+        else {
+            continue;
+        };
+        let range = max - min;
+
+        for point in points {
+            let normalized = if range > 0.0 {
+                match order {
+                    Order::LargeBetter => (point.score - min) / range,
+                    Order::SmallBetter => (max - point.score) / range,
+                }
+            } else {
+                // All candidates of this prefetch are equally good
+                1.0
+            };
+            *scores.entry(point.id).or_default() += weight * normalized;
+        }
+    }
+    scores
+}
+
+/// Fuse the results of several searches into a single ranking, best fused scores first.
+///
+/// `orders` holds the distance order of the vector used by each search.
+pub(crate) fn fuse_results(
+    results: Vec<Vec<ScoredPoint>>,
+    fusion: Fusion,
+    weights: &[f32],
+    orders: &[Order],
+) -> Vec<ScoredPoint> {
+    let scores = match fusion {
+        Fusion::Rrf => rrf_scores(&results, weights),
+        Fusion::Normalized => normalized_scores(&results, weights, orders),
+    };
+
+    results
+        .into_iter()
+        .flatten()
+        .unique_by(|point| point.id)
+        .map(|point| ScoredPoint {
+            score: scores[&point.id],
+            ..point
+        })
+        .sorted_by_key(|point| (Reverse(OrderedFloat(point.score)), point.id))
+        .collect()
+}
+
+/// Fuse the results of the prefetches into a single ranking and apply the threshold, offset
+/// and limit of the hybrid request.
+///
+/// `orders` holds the distance order of the vector used by each prefetch.
+fn fuse(
+    prefetch_results: Vec<Vec<ScoredPoint>>,
+    request: &HybridSearchRequestInternal,
+    orders: &[Order],
+) -> Vec<ScoredPoint> {
+    let weights = request
+        .prefetch
+        .iter()
+        .map(|prefetch| prefetch.weight.unwrap_or(DEFAULT_PREFETCH_WEIGHT))
+        .collect_vec();
+
+    fuse_results(
+        prefetch_results,
+        request.fusion.unwrap_or_default(),
+        &weights,
+        orders,
+    )
+    .into_iter()
+    .filter(|point| {
+        request
+            .score_threshold
+            .map_or(true, |threshold| point.score >= threshold)
+    })
+    .skip(request.offset.unwrap_or_default())
+    .take(request.limit)
+    .collect()
+}
+
+pub async fn search_hybrid(
+    request: HybridSearchRequestInternal,
+    collection: &Collection,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> CollectionResult<Vec<ScoredPoint>> {
+    if request.limit == 0 {
+        return Ok(vec![]);
+    }
+
+    let orders = {
+        let collection_params = &collection.collection_config.read().await.params;
+        request
+            .prefetch
+            .iter()
+            .map(|prefetch| {
+                collection_params
+                    .get_distance(prefetch.vector.get_name())
+                    .map(|distance| distance.distance_order())
+            })
+            .collect::<CollectionResult<Vec<_>>>()?
+    };
+
+    let prefetch_results = collection
+        .core_search_batch(
+            hybrid_into_core_search_batch(&request),
+            read_consistency,
+            shard_selector.clone(),
+            timeout,
+        )
+        .await?;
+
+    let fused = fuse(prefetch_results, &request, &orders);
+
+    collection
+        .fill_search_result_with_payload(
+            fused,
+            Some(
+                request
+                    .with_payload
+                    .unwrap_or(WithPayloadInterface::Bool(false)),
+            ),
+            request.with_vector.unwrap_or_default(),
+            read_consistency,
+            &shard_selector,
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::data_types::vectors::NamedVectorStruct;
+
+    use super::*;
+    use crate::operations::types::HybridPrefetch;
+
+    fn scored(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+        }
+    }
+
+    fn request(fusion: Fusion, weights: &[f32], limit: usize) -> HybridSearchRequestInternal {
+        HybridSearchRequestInternal {
+            prefetch: weights
+                .iter()
+                .map(|&weight| HybridPrefetch {
+                    vector: NamedVectorStruct::Default(vec![1.0, 0.0]),
+                    filter: None,
+                    params: None,
+                    limit: 10,
+                    score_threshold: None,
+                    weight: Some(weight),
+                })
+                .collect(),
+            fusion: Some(fusion),
+            filter: None,
+            limit,
+            offset: None,
+            with_payload: None,
+            with_vector: None,
+            score_threshold: None,
+        }
+    }
+
+    fn ids(points: &[ScoredPoint]) -> Vec<ExtendedPointId> {
+        points.iter().map(|point| point.id).collect()
+    }
+
+    #[test]
+    fn test_rrf_fusion() {
+        let dense = vec![scored(1, 0.9), scored(2, 0.8), scored(3, 0.7)];
+        let sparse = vec![scored(3, 25.0), scored(4, 12.0), scored(1, 3.0)];
+
+        let request = request(Fusion::Rrf, &[1.0, 1.0], 10);
+        let orders = [Order::LargeBetter, Order::LargeBetter];
+        let fused = fuse(vec![dense, sparse], &request, &orders);
+
+        // 1: 1/61 + 1/63, 3: 1/63 + 1/61, 2: 1/62, 4: 1/62
+        assert_eq!(ids(&fused), vec![1.into(), 3.into(), 2.into(), 4.into()]);
+        assert_eq!(fused[0].score, fused[1].score);
+        assert!((fused[0].score - (1.0 / 61.0 + 1.0 / 63.0)).abs() < 1e-6);
+        assert!((fused[2].score - 1.0 / 62.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rrf_fusion_weights_and_pagination() {
+        let dense = vec![scored(1, 0.9), scored(2, 0.8)];
+        let sparse = vec![scored(2, 25.0), scored(3, 12.0)];
+
+        let mut request = request(Fusion::Rrf, &[1.0, 3.0], 1);
+        request.offset = Some(1);
+        let orders = [Order::LargeBetter, Order::LargeBetter];
+        let fused = fuse(vec![dense, sparse], &request, &orders);
+
+        // 2: 1/62 + 3/61, 3: 3/62, 1: 1/61
+        assert_eq!(ids(&fused), vec![3.into()]);
+    }
+
+    #[test]
+    fn test_normalized_fusion() {
+        let dense = vec![scored(1, 0.9), scored(2, 0.5), scored(3, 0.1)];
+        // Euclid distances, the smaller the better
+        let euclid = vec![scored(3, 1.0), scored(2, 2.0), scored(1, 5.0)];
+
+        let request = request(Fusion::Normalized, &[1.0, 2.0], 10);
+        let orders = [Order::LargeBetter, Order::SmallBetter];
+        let fused = fuse(vec![dense, euclid], &request, &orders);
+
+        // 3: 0 + 2 * 1, 2: 0.5 + 2 * 0.75, 1: 1 + 0
+        assert_eq!(ids(&fused), vec![2.into(), 3.into(), 1.into()]);
+        assert!((fused[0].score - 2.0).abs() < 1e-6);
+        assert!((fused[1].score - 2.0).abs() < 1e-6);
+        assert!((fused[2].score - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fusion_score_threshold() {
+        let dense = vec![scored(1, 0.9), scored(2, 0.5)];
+        let sparse = vec![scored(1, 7.0)];
+
+        let mut request = request(Fusion::Normalized, &[1.0, 1.0], 10);
+        request.score_threshold = Some(1.5);
+        let orders = [Order::LargeBetter, Order::LargeBetter];
+        let fused = fuse(vec![dense, sparse], &request, &orders);
+
+        assert_eq!(ids(&fused), vec![1.into()]);
+    }
+}
@@ -4,8 +4,10 @@ pub mod collection_state;
 pub mod common;
 pub mod config;
 pub mod discovery;
+pub mod distance_matrix;
 pub mod grouping;
 pub mod hash_ring;
+pub mod hybrid;
 pub mod lookup;
 pub mod operations;
 pub mod optimizers_builder;
@@ -13,6 +15,7 @@ pub mod recommendations;
 pub mod save_on_disk;
 pub mod shards;
 pub mod telemetry;
+pub mod universal_query;
 mod update_handler;
 pub mod wal;
 
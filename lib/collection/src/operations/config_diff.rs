@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use validator::{Validate, ValidationErrors};
 
-use crate::config::{CollectionParams, WalConfig};
+use crate::config::{CollectionParams, StrictModeConfig, WalConfig};
 use crate::operations::types::CollectionResult;
 use crate::optimizers_builder::OptimizersConfig;
 
@@ -80,6 +80,10 @@ pub struct HnswConfigDiff {
     /// Custom M param for additional payload-aware HNSW links. If not set, default M will be used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload_m: Option<usize>,
+    /// Build additional links within wide ranges of indexed numeric payload fields.
+    /// Improves accuracy of range filters of medium selectivity at the cost of longer indexing. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_links: Option<bool>,
 }
 
 #[derive(
@@ -107,6 +111,11 @@ pub struct CollectionParamsDiff {
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default)]
     pub on_disk_payload: Option<bool>,
+    /// If true together with `on_disk_payload` - payload is stored in memory mapped pages instead of RocksDB.
+    /// Segments with payload in RocksDB are rebuilt by the optimizers after this option is enabled.
+    /// Default is false
+    #[serde(default)]
+    pub mmap_payload: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Merge)]
@@ -197,6 +206,8 @@ impl DiffConfig<WalConfig> for WalConfigDiff {}
 
 impl DiffConfig<CollectionParams> for CollectionParamsDiff {}
 
+impl DiffConfig<StrictModeConfig> for StrictModeConfig {}
+
 impl From<HnswConfig> for HnswConfigDiff {
     fn from(config: HnswConfig) -> Self {
         HnswConfigDiff::from_full(&config).unwrap()
@@ -339,6 +350,8 @@ mod tests {
                 hnsw_config: None,
                 quantization_config: None,
                 on_disk: None,
+                multivector_config: None,
+                datatype: None,
             }
             .into(),
             ..CollectionParams::empty()
@@ -349,6 +362,7 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             on_disk_payload: None,
+            mmap_payload: None,
         };
 
         let new_params = diff.update(&params).unwrap();
@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use api::grpc::conversions::{
     convert_shard_key_from_grpc, convert_shard_key_from_grpc_opt, convert_shard_key_to_grpc,
-    from_grpc_dist, payload_to_proto, proto_to_payloads,
+    from_grpc_datatype, from_grpc_dist, payload_to_proto, proto_to_payloads,
 };
 use api::grpc::qdrant::quantization_config_diff::Quantization;
 use api::grpc::qdrant::update_collection_cluster_setup_request::{
@@ -22,14 +22,18 @@ use tonic::Status;
 
 use super::consistency_params::ReadConsistency;
 use super::types::{
-    BaseGroupRequest, ContextExamplePair, CoreSearchRequest, DiscoverRequestInternal, GroupsResult,
-    PointGroup, QueryEnum, RecommendExample, RecommendGroupsRequestInternal, RecommendStrategy,
-    SearchGroupsRequestInternal, SparseIndexParams, SparseVectorParams, VectorParamsDiff,
-    VectorsConfigDiff,
+    default_search_matrix_limit, default_search_matrix_sample, BaseGroupRequest,
+    ContextExamplePair, CoreSearchRequest, DiscoverInput, DiscoverRequestInternal, Fusion,
+    GroupsResult, HybridPrefetch, HybridSearchRequestInternal, Modifier, PointGroup, Prefetch,
+    Query, QueryEnum, QueryInterface, QueryRequestInternal, RecommendExample,
+    RecommendGroupsRequestInternal, RecommendInput, RecommendStrategy, SearchGroupsRequestInternal,
+    SearchMatrixOffsetsResponse, SearchMatrixPair, SearchMatrixPairsResponse,
+    SearchMatrixRequestInternal, SparseIndexParams, SparseVectorParams, TextQueryInput,
+    VectorParamsDiff, VectorsConfigDiff,
 };
 use crate::config::{
     default_replication_factor, default_write_consistency_factor, CollectionConfig,
-    CollectionParams, ShardingMethod, WalConfig,
+    CollectionParams, ShardingMethod, StrictModeConfig, WalConfig,
 };
 use crate::lookup::types::WithLookupInterface;
 use crate::lookup::WithLookup;
@@ -133,11 +137,14 @@ pub fn try_record_from_grpc(
         .map(|vectors| vectors.try_into())
         .transpose()?;
 
+    let order_value = point.order_value.map(TryFrom::try_from).transpose()?;
+
     Ok(Record {
         id,
         payload,
         vector,
         shard_key: convert_shard_key_from_grpc_opt(point.shard_key),
+        order_value,
     })
 }
 
@@ -173,23 +180,7 @@ pub fn try_discover_request_from_grpc(
 
     let target = target.map(TryInto::try_into).transpose()?;
 
-    let context = context
-        .into_iter()
-        .map(|pair| {
-            match (
-                pair.positive.map(|p| p.try_into()),
-                pair.negative.map(|n| n.try_into()),
-            ) {
-                (Some(Ok(positive)), Some(Ok(negative))) => {
-                    Ok(ContextExamplePair { positive, negative })
-                }
-                (Some(Err(e)), _) | (_, Some(Err(e))) => Err(e),
-                (None, _) | (_, None) => Err(Status::invalid_argument(
-                    "Both positive and negative are required in a context pair",
-                )),
-            }
-        })
-        .try_collect()?;
+    let context = context.into_iter().map(TryInto::try_into).try_collect()?;
 
     let request = DiscoverRequestInternal {
         target,
@@ -230,6 +221,7 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             max_indexing_threads: value.max_indexing_threads.map(|v| v as usize),
             on_disk: value.on_disk,
             payload_m: value.payload_m.map(|v| v as usize),
+            range_links: value.range_links,
         }
     }
 }
@@ -243,6 +235,7 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             max_indexing_threads: value.max_indexing_threads.map(|v| v as u64),
             on_disk: value.on_disk,
             payload_m: value.payload_m.map(|v| v as u64),
+            range_links: value.range_links,
         }
     }
 }
@@ -256,6 +249,40 @@ impl From<api::grpc::qdrant::WalConfigDiff> for WalConfigDiff {
     }
 }
 
+impl From<api::grpc::qdrant::StrictModeConfig> for StrictModeConfig {
+    fn from(value: api::grpc::qdrant::StrictModeConfig) -> Self {
+        Self {
+            enabled: value.enabled,
+            max_query_limit: value.max_query_limit.map(|v| v as usize),
+            max_query_offset: value.max_query_offset.map(|v| v as usize),
+            max_timeout: value.max_timeout.map(|v| v as usize),
+            unindexed_filtering_retrieve: value.unindexed_filtering_retrieve,
+            unindexed_filtering_update: value.unindexed_filtering_update,
+            search_max_hnsw_ef: value.search_max_hnsw_ef.map(|v| v as usize),
+            search_max_batchsize: value.search_max_batchsize.map(|v| v as usize),
+            upsert_max_batchsize: value.upsert_max_batchsize.map(|v| v as usize),
+            write_rate_limit: value.write_rate_limit.map(|v| v as usize),
+        }
+    }
+}
+
+impl From<StrictModeConfig> for api::grpc::qdrant::StrictModeConfig {
+    fn from(value: StrictModeConfig) -> Self {
+        Self {
+            enabled: value.enabled,
+            max_query_limit: value.max_query_limit.map(|v| v as u32),
+            max_query_offset: value.max_query_offset.map(|v| v as u32),
+            max_timeout: value.max_timeout.map(|v| v as u32),
+            unindexed_filtering_retrieve: value.unindexed_filtering_retrieve,
+            unindexed_filtering_update: value.unindexed_filtering_update,
+            search_max_hnsw_ef: value.search_max_hnsw_ef.map(|v| v as u32),
+            search_max_batchsize: value.search_max_batchsize.map(|v| v as u32),
+            upsert_max_batchsize: value.upsert_max_batchsize.map(|v| v as u32),
+            write_rate_limit: value.write_rate_limit.map(|v| v as u32),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
     type Error = Status;
 
@@ -278,6 +305,7 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor: value.read_fan_out_factor,
             on_disk_payload: value.on_disk_payload,
+            mmap_payload: value.mmap_payload,
         })
     }
 }
@@ -375,6 +403,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     shard_number: config.params.shard_number.get(),
                     replication_factor: Some(config.params.replication_factor.get()),
                     on_disk_payload: config.params.on_disk_payload,
+                    mmap_payload: config.params.mmap_payload,
                     write_consistency_factor: Some(config.params.write_consistency_factor.get()),
                     read_fan_out_factor: config.params.read_fan_out_factor,
                     sharding_method: config.params.sharding_method.map(sharding_method_to_proto),
@@ -396,6 +425,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     max_indexing_threads: Some(config.hnsw_config.max_indexing_threads as u64),
                     on_disk: config.hnsw_config.on_disk,
                     payload_m: config.hnsw_config.payload_m.map(|v| v as u64),
+                    range_links: config.hnsw_config.range_links,
                 }),
                 optimizer_config: Some(api::grpc::qdrant::OptimizersConfigDiff {
                     deleted_threshold: Some(config.optimizer_config.deleted_threshold),
@@ -421,6 +451,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     wal_segments_ahead: Some(config.wal_config.wal_segments_ahead as u64),
                 }),
                 quantization_config: config.quantization_config.map(|x| x.into()),
+                strict_mode_config: config.strict_mode_config.map(|x| x.into()),
             }),
             payload_schema: payload_schema
                 .into_iter()
@@ -439,6 +470,7 @@ impl From<Record> for api::grpc::qdrant::RetrievedPoint {
             payload: record.payload.map(payload_to_proto).unwrap_or_default(),
             vectors,
             shard_key: record.shard_key.map(convert_shard_key_to_grpc),
+            order_value: record.order_value.map(From::from),
         }
     }
 }
@@ -530,6 +562,12 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
     type Error = Status;
 
     fn try_from(vector_params: api::grpc::qdrant::VectorParams) -> Result<Self, Self::Error> {
+        if vector_params.multivector_config.is_some() && vector_params.quantization_config.is_some()
+        {
+            return Err(Status::invalid_argument(
+                "quantization_config is not supported for multi vectors",
+            ));
+        }
         Ok(Self {
             size: NonZeroU64::new(vector_params.size).ok_or_else(|| {
                 Status::invalid_argument("VectorParams size must be greater than zero")
@@ -541,6 +579,15 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
                 .map(grpc_to_segment_quantization_config)
                 .transpose()?,
             on_disk: vector_params.on_disk,
+            multivector_config: vector_params
+                .multivector_config
+                .map(TryInto::try_into)
+                .transpose()?,
+            datatype: vector_params
+                .datatype
+                .map(from_grpc_datatype)
+                .transpose()?
+                .flatten(),
         })
     }
 }
@@ -560,16 +607,24 @@ impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
     }
 }
 
-impl From<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
-    fn from(sparse_vector_params: api::grpc::qdrant::SparseVectorParams) -> Self {
-        Self {
+impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
+    type Error = Status;
+
+    fn try_from(
+        sparse_vector_params: api::grpc::qdrant::SparseVectorParams,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
             index: sparse_vector_params
                 .index
                 .map(|index_config| SparseIndexParams {
                     full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
                     on_disk: index_config.on_disk,
                 }),
-        }
+            modifier: sparse_vector_params
+                .modifier
+                .map(grpc_to_modifier)
+                .transpose()?,
+        })
     }
 }
 
@@ -582,6 +637,28 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                     on_disk: index_config.on_disk,
                 }
             }),
+            modifier: sparse_vector_params
+                .modifier
+                .map(|modifier| api::grpc::qdrant::Modifier::from(modifier) as i32),
+        }
+    }
+}
+
+fn grpc_to_modifier(modifier: i32) -> Result<Modifier, Status> {
+    match api::grpc::qdrant::Modifier::from_i32(modifier) {
+        None => Err(Status::invalid_argument(format!(
+            "Malformed modifier parameter, unexpected value: {modifier}"
+        ))),
+        Some(api::grpc::qdrant::Modifier::None) => Ok(Modifier::None),
+        Some(api::grpc::qdrant::Modifier::Idf) => Ok(Modifier::Idf),
+    }
+}
+
+impl From<Modifier> for api::grpc::qdrant::Modifier {
+    fn from(value: Modifier) -> Self {
+        match value {
+            Modifier::None => api::grpc::qdrant::Modifier::None,
+            Modifier::Idf => api::grpc::qdrant::Modifier::Idf,
         }
     }
 }
@@ -639,16 +716,22 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             ),
                         },
                     },
-                    sparse_vectors: params.sparse_vectors_config.map(|sparse_vectors| {
-                        sparse_vectors
-                            .map
-                            .into_iter()
-                            .map(|(name, sparse_vector_params)| (name, sparse_vector_params.into()))
-                            .collect()
-                    }),
+                    sparse_vectors: params
+                        .sparse_vectors_config
+                        .map(|sparse_vectors| {
+                            sparse_vectors
+                                .map
+                                .into_iter()
+                                .map(|(name, sparse_vector_params)| {
+                                    Ok((name, sparse_vector_params.try_into()?))
+                                })
+                                .collect::<Result<_, Status>>()
+                        })
+                        .transpose()?,
                     shard_number: NonZeroU32::new(params.shard_number)
                         .ok_or_else(|| Status::invalid_argument("`shard_number` cannot be zero"))?,
                     on_disk_payload: params.on_disk_payload,
+                    mmap_payload: params.mmap_payload,
                     replication_factor: NonZeroU32::new(
                         params
                             .replication_factor
@@ -692,6 +775,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                     None
                 }
             },
+            strict_mode_config: config.strict_mode_config.map(StrictModeConfig::from),
         })
     }
 }
@@ -910,10 +994,15 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            vectors_count,
         } = value;
 
-        let vector_struct =
-            api::grpc::conversions::into_named_vector_struct(vector_name, vector, sparse_indices)?;
+        let vector_struct = api::grpc::conversions::into_named_vector_struct(
+            vector_name,
+            vector,
+            sparse_indices,
+            vectors_count,
+        )?;
 
         Ok(Self {
             query: QueryEnum::Nearest(vector_struct),
@@ -932,14 +1021,19 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
 impl<'a> From<CollectionSearchRequest<'a>> for api::grpc::qdrant::SearchPoints {
     fn from(value: CollectionSearchRequest<'a>) -> Self {
         let (collection_id, request) = value.0;
-        let (vector, sparse_indices) = match request.vector.get_vector().to_owned() {
-            Vector::Dense(vector) => (vector, None),
+        let (vector, sparse_indices, vectors_count) = match request.vector.get_vector().to_owned() {
+            Vector::Dense(vector) => (vector, None, None),
             Vector::Sparse(vector) => (
                 vector.values,
                 Some(api::grpc::qdrant::SparseIndices {
                     data: vector.indices,
                 }),
+                None,
             ),
+            Vector::MultiDense(vector) => {
+                let vectors_count = vector.vectors_count() as u32;
+                (vector.flattened_vectors, None, Some(vectors_count))
+            }
         };
         Self {
             collection_name: collection_id,
@@ -959,6 +1053,7 @@ impl<'a> From<CollectionSearchRequest<'a>> for api::grpc::qdrant::SearchPoints {
             timeout: None,
             shard_key_selector: None,
             sparse_indices,
+            vectors_count,
         }
     }
 }
@@ -1080,8 +1175,8 @@ fn try_context_pair_from_grpc(
 ) -> Result<ContextPair<Vector>, Status> {
     match (pair.positive, pair.negative) {
         (Some(positive), Some(negative)) => Ok(ContextPair {
-            positive: positive.into(),
-            negative: negative.into(),
+            positive: positive.try_into()?,
+            negative: negative.try_into()?,
         }),
         _ => Err(Status::invalid_argument(
             "All context pairs must have both positive and negative parts",
@@ -1103,13 +1198,22 @@ impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
                             value.vector_name,
                             vector.data,
                             vector.indices.clone(),
+                            vector.vectors_count,
                         )?)
                     }
                     api::grpc::qdrant::query_enum::Query::RecommendBestScore(query) => {
                         QueryEnum::RecommendBestScore(NamedQuery {
                             query: RecoQuery::new(
-                                query.positives.into_iter().map(|v| v.into()).collect(),
-                                query.negatives.into_iter().map(|v| v.into()).collect(),
+                                query
+                                    .positives
+                                    .into_iter()
+                                    .map(TryInto::try_into)
+                                    .try_collect()?,
+                                query
+                                    .negatives
+                                    .into_iter()
+                                    .map(TryInto::try_into)
+                                    .try_collect()?,
                             ),
                             using: value.vector_name,
                         })
@@ -1129,7 +1233,7 @@ impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
                             .try_collect()?;
 
                         QueryEnum::Discover(NamedQuery {
-                            query: DiscoveryQuery::new(target.into(), pairs),
+                            query: DiscoveryQuery::new(target.try_into()?, pairs),
                             using: value.vector_name,
                         })
                     }
@@ -1177,6 +1281,7 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for SearchRequestInternal {
                 value.vector_name,
                 value.vector,
                 value.sparse_indices,
+                value.vectors_count,
             )?,
             filter: value.filter.map(|f| f.try_into()).transpose()?,
             params: value.params.map(|p| p.into()),
@@ -1194,6 +1299,297 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for SearchRequestInternal {
     }
 }
 
+impl From<api::grpc::qdrant::Fusion> for Fusion {
+    fn from(value: api::grpc::qdrant::Fusion) -> Self {
+        match value {
+            api::grpc::qdrant::Fusion::Rrf => Fusion::Rrf,
+            api::grpc::qdrant::Fusion::Normalized => Fusion::Normalized,
+        }
+    }
+}
+
+impl TryFrom<i32> for Fusion {
+    type Error = Status;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        let fusion = api::grpc::qdrant::Fusion::from_i32(value)
+            .ok_or_else(|| Status::invalid_argument(format!("Unknown fusion: {}", value)))?;
+        Ok(fusion.into())
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::HybridPrefetch> for HybridPrefetch {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::HybridPrefetch) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::HybridPrefetch {
+            vector,
+            vector_name,
+            sparse_indices,
+            filter,
+            params,
+            limit,
+            score_threshold,
+            weight,
+            vectors_count,
+        } = value;
+
+        Ok(Self {
+            vector: api::grpc::conversions::into_named_vector_struct(
+                vector_name,
+                vector,
+                sparse_indices,
+                vectors_count,
+            )?,
+            filter: filter.map(TryInto::try_into).transpose()?,
+            params: params.map(Into::into),
+            limit: limit as usize,
+            score_threshold,
+            weight,
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::SearchHybridPoints> for HybridSearchRequestInternal {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::SearchHybridPoints) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::SearchHybridPoints {
+            collection_name: _,
+            prefetch,
+            fusion,
+            filter,
+            limit,
+            offset,
+            with_payload,
+            with_vectors,
+            score_threshold,
+            read_consistency: _,
+            timeout: _,
+            shard_key_selector: _,
+        } = value;
+
+        if prefetch.is_empty() {
+            return Err(Status::invalid_argument(
+                "must specify at least one prefetch",
+            ));
+        }
+
+        Ok(Self {
+            prefetch: prefetch
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            fusion: fusion.map(TryInto::try_into).transpose()?,
+            filter: filter.map(TryInto::try_into).transpose()?,
+            limit: limit as usize,
+            offset: offset.map(|x| x as usize),
+            with_payload: with_payload.map(TryInto::try_into).transpose()?,
+            with_vector: with_vectors.map(Into::into),
+            score_threshold,
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::SearchMatrixPoints> for SearchMatrixRequestInternal {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::SearchMatrixPoints) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::SearchMatrixPoints {
+            collection_name: _,
+            filter,
+            sample,
+            limit,
+            using,
+            timeout: _,
+            read_consistency: _,
+            shard_key_selector: _,
+        } = value;
+
+        Ok(Self {
+            filter: filter.map(TryInto::try_into).transpose()?,
+            sample: sample.map_or_else(default_search_matrix_sample, |sample| sample as usize),
+            limit: limit.map_or_else(default_search_matrix_limit, |limit| limit as usize),
+            using: using.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::RecommendInput> for RecommendInput {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::RecommendInput) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::RecommendInput {
+            positive,
+            negative,
+            strategy,
+        } = value;
+
+        Ok(Self {
+            positive: positive.into_iter().map(TryInto::try_into).try_collect()?,
+            negative: negative.into_iter().map(TryInto::try_into).try_collect()?,
+            strategy: strategy.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::DiscoverInput> for DiscoverInput {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::DiscoverInput) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::DiscoverInput { target, context } = value;
+
+        let target = target.ok_or_else(|| Status::invalid_argument("Target is not specified"))?;
+
+        Ok(Self {
+            target: target.try_into()?,
+            context: context.into_iter().map(TryInto::try_into).try_collect()?,
+        })
+    }
+}
+
+impl From<api::grpc::qdrant::TextQueryInput> for TextQueryInput {
+    fn from(value: api::grpc::qdrant::TextQueryInput) -> Self {
+        let api::grpc::qdrant::TextQueryInput { key, text } = value;
+        Self { key, text }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::Query> for Query {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::Query) -> Result<Self, Self::Error> {
+        use api::grpc::qdrant::query::Variant;
+
+        let variant = value
+            .variant
+            .ok_or_else(|| Status::invalid_argument("Query is not specified"))?;
+
+        Ok(match variant {
+            Variant::Nearest(example) => Query::Nearest(example.try_into()?),
+            Variant::Recommend(recommend) => Query::Recommend(recommend.try_into()?),
+            Variant::Discover(discover) => Query::Discover(discover.try_into()?),
+            Variant::Context(context) => Query::Context(
+                context
+                    .pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .try_collect()?,
+            ),
+            Variant::Fusion(fusion) => Query::Fusion(fusion.try_into()?),
+            Variant::Text(text) => Query::Text(text.into()),
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PrefetchQuery> for Prefetch {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::PrefetchQuery) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::PrefetchQuery {
+            prefetch,
+            query,
+            using,
+            filter,
+            params,
+            score_threshold,
+            limit,
+        } = value;
+
+        Ok(Self {
+            prefetch: (!prefetch.is_empty())
+                .then(|| prefetch.into_iter().map(TryInto::try_into).try_collect())
+                .transpose()?,
+            query: query
+                .map(|query| Query::try_from(query).map(QueryInterface::Query))
+                .transpose()?,
+            using: using.map(Into::into),
+            filter: filter.map(TryInto::try_into).transpose()?,
+            params: params.map(Into::into),
+            score_threshold,
+            limit: limit.map(|limit| limit as usize),
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::QueryPoints> for QueryRequestInternal {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::QueryPoints) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::QueryPoints {
+            collection_name: _,
+            prefetch,
+            query,
+            using,
+            filter,
+            params,
+            score_threshold,
+            limit,
+            offset,
+            with_vectors,
+            with_payload,
+            read_consistency: _,
+            shard_key_selector: _,
+            lookup_from,
+            timeout: _,
+        } = value;
+
+        Ok(Self {
+            prefetch: (!prefetch.is_empty())
+                .then(|| prefetch.into_iter().map(TryInto::try_into).try_collect())
+                .transpose()?,
+            query: query
+                .map(|query| Query::try_from(query).map(QueryInterface::Query))
+                .transpose()?,
+            using: using.map(Into::into),
+            filter: filter.map(TryInto::try_into).transpose()?,
+            params: params.map(Into::into),
+            score_threshold,
+            limit: limit.map(|limit| limit as usize),
+            offset: offset.map(|offset| offset as usize),
+            with_payload: with_payload.map(TryInto::try_into).transpose()?,
+            with_vector: with_vectors.map(Into::into),
+            lookup_from: lookup_from.map(Into::into),
+        })
+    }
+}
+
+impl From<SearchMatrixPair> for api::grpc::qdrant::SearchMatrixPair {
+    fn from(pair: SearchMatrixPair) -> Self {
+        let SearchMatrixPair { a, b, score } = pair;
+        Self {
+            a: Some(a.into()),
+            b: Some(b.into()),
+            score,
+        }
+    }
+}
+
+impl From<SearchMatrixPairsResponse> for api::grpc::qdrant::SearchMatrixPairs {
+    fn from(response: SearchMatrixPairsResponse) -> Self {
+        Self {
+            pairs: response.pairs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SearchMatrixOffsetsResponse> for api::grpc::qdrant::SearchMatrixOffsets {
+    fn from(response: SearchMatrixOffsetsResponse) -> Self {
+        let SearchMatrixOffsetsResponse {
+            offsets_row,
+            offsets_col,
+            scores,
+            ids,
+        } = response;
+        Self {
+            offsets_row,
+            offsets_col,
+            scores,
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::SearchPointGroups> for SearchGroupsRequestInternal {
     type Error = Status;
 
@@ -1213,6 +1609,7 @@ impl TryFrom<api::grpc::qdrant::SearchPointGroups> for SearchGroupsRequestIntern
             timeout: None,
             shard_key_selector: None,
             sparse_indices: value.sparse_indices,
+            vectors_count: None,
         };
 
         let SearchRequestInternal {
@@ -1327,13 +1724,16 @@ impl TryFrom<api::grpc::qdrant::PointId> for RecommendExample {
     }
 }
 
-impl From<api::grpc::qdrant::Vector> for RecommendExample {
-    fn from(value: api::grpc::qdrant::Vector) -> Self {
-        let vector: Vector = value.into();
-        match vector {
+impl TryFrom<api::grpc::qdrant::Vector> for RecommendExample {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::Vector) -> Result<Self, Self::Error> {
+        let vector: Vector = value.try_into()?;
+        Ok(match vector {
             Vector::Dense(vector) => Self::Dense(vector),
             Vector::Sparse(vector) => Self::Sparse(vector),
-        }
+            Vector::MultiDense(vector) => Self::MultiDense(vector),
+        })
     }
 }
 
@@ -1350,13 +1750,28 @@ impl TryFrom<api::grpc::qdrant::VectorExample> for RecommendExample {
                 api::grpc::qdrant::vector_example::Example::Id(id) => {
                     Ok(Self::PointId(id.try_into()?))
                 }
-                api::grpc::qdrant::vector_example::Example::Vector(vector) => {
-                    Ok(Self::Dense(vector.data))
-                }
+                api::grpc::qdrant::vector_example::Example::Vector(vector) => vector.try_into(),
             })
     }
 }
 
+impl TryFrom<api::grpc::qdrant::ContextExamplePair> for ContextExamplePair {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::ContextExamplePair) -> Result<Self, Self::Error> {
+        match (
+            value.positive.map(|p| p.try_into()),
+            value.negative.map(|n| n.try_into()),
+        ) {
+            (Some(Ok(positive)), Some(Ok(negative))) => Ok(Self { positive, negative }),
+            (Some(Err(e)), _) | (_, Some(Err(e))) => Err(e),
+            (None, _) | (_, None) => Err(Status::invalid_argument(
+                "Both positive and negative are required in a context pair",
+            )),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
     type Error = Status;
 
@@ -1367,7 +1782,11 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
             .map(TryInto::try_into)
             .collect::<Result<Vec<RecommendExample>, Self::Error>>()?;
 
-        let positive_vectors = value.positive_vectors.into_iter().map(Into::into).collect();
+        let positive_vectors = value
+            .positive_vectors
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<RecommendExample>, Self::Error>>()?;
         let positive = [positive_ids, positive_vectors].concat();
 
         let negative_ids = value
@@ -1376,7 +1795,11 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
             .map(TryInto::try_into)
             .collect::<Result<Vec<RecommendExample>, Self::Error>>()?;
 
-        let negative_vectors = value.negative_vectors.into_iter().map(Into::into).collect();
+        let negative_vectors = value
+            .negative_vectors
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<RecommendExample>, Self::Error>>()?;
         let negative = [negative_ids, negative_vectors].concat();
 
         Ok(RecommendRequestInternal {
@@ -1484,6 +1907,10 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             hnsw_config: value.hnsw_config.map(Into::into),
             quantization_config: value.quantization_config.map(Into::into),
             on_disk: value.on_disk,
+            multivector_config: value.multivector_config.map(Into::into),
+            datatype: value
+                .datatype
+                .map(|datatype| api::grpc::qdrant::Datatype::from(datatype) as i32),
         }
     }
 }
@@ -12,6 +12,7 @@ pub mod snapshot_ops;
 pub mod types;
 pub mod validation;
 pub mod vector_ops;
+pub mod verification;
 
 use std::collections::HashMap;
 
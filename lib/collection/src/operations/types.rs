@@ -14,14 +14,18 @@ use merge::Merge;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::common::operation_error::OperationError;
+use segment::data_types::facets::{FacetParams, FacetValueHit};
 use segment::data_types::groups::GroupId;
+use segment::data_types::order_by::{OrderBy, OrderValue, StartFrom};
+use segment::data_types::sparse_statistics::SparseVectorStatistics;
 use segment::data_types::vectors::{
-    DenseVector, Named, NamedQuery, NamedVectorStruct, QueryVector, Vector, VectorElementType,
-    VectorRef, VectorStruct, DEFAULT_VECTOR_NAME,
+    DenseVector, MultiDenseVector, Named, NamedQuery, NamedVectorStruct, QueryVector, Vector,
+    VectorElementType, VectorRef, VectorStruct, DEFAULT_VECTOR_NAME,
 };
 use segment::types::{
-    Distance, Filter, Payload, PayloadIndexInfo, PayloadKeyType, PointIdType, QuantizationConfig,
-    ScoredPoint, SearchParams, SeqNumberType, ShardKey, WithPayloadInterface, WithVector,
+    Distance, Filter, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType, PointIdType,
+    QuantizationConfig, ScoredPoint, SearchParams, SeqNumberType, ShardKey, VectorStorageDatatype,
+    WithPayloadInterface, WithVector,
 };
 use segment::vector_storage::query::context_query::ContextQuery;
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
@@ -30,6 +34,7 @@ use serde;
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimId;
 use thiserror::Error;
 use tokio::sync::mpsc::error::SendError;
 use tokio::sync::oneshot::error::RecvError as OneshotRecvError;
@@ -90,6 +95,9 @@ pub struct Record {
     /// Shard Key
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKey>,
+    /// Value of the `order_by` key, if the point was read with ordering
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_value: Option<OrderValue>,
 }
 
 /// Current statistics and configuration of the collection
@@ -281,6 +289,11 @@ pub struct ScrollRequestInternal {
     /// Whether to return the point vector with the result?
     #[serde(default, alias = "with_vectors")]
     pub with_vector: WithVector,
+    /// Order the records by a payload field.
+    /// Ordered scroll requires a range index on the field.
+    /// To paginate, use `next_page_start_from` together with `next_page_offset` from the previous page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderByInterface>,
 }
 
 impl Default for ScrollRequestInternal {
@@ -291,6 +304,27 @@ impl Default for ScrollRequestInternal {
             filter: None,
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(false),
+            order_by: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum OrderByInterface {
+    Key(PayloadKeyType),
+    Struct(OrderBy),
+}
+
+impl From<OrderByInterface> for OrderBy {
+    fn from(order_by: OrderByInterface) -> Self {
+        match order_by {
+            OrderByInterface::Key(key) => OrderBy {
+                key,
+                direction: None,
+                start_from: None,
+            },
+            OrderByInterface::Struct(order_by) => order_by,
         }
     }
 }
@@ -303,6 +337,10 @@ pub struct ScrollResult {
     pub points: Vec<Record>,
     /// Offset which should be used to retrieve a next page result
     pub next_page_offset: Option<PointIdType>,
+    /// Value of the `order_by` key, which should be used as `start_from` to retrieve a next page result.
+    /// Only present for scroll requests with `order_by`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_start_from: Option<StartFrom>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -491,6 +529,7 @@ pub enum RecommendExample {
     PointId(PointIdType),
     Dense(DenseVector),
     Sparse(SparseVector),
+    MultiDense(MultiDenseVector),
 }
 
 impl RecommendExample {
@@ -508,6 +547,7 @@ impl Validate for RecommendExample {
             RecommendExample::PointId(_) => Ok(()),
             RecommendExample::Dense(_) => Ok(()),
             RecommendExample::Sparse(sparse) => sparse.validate(),
+            RecommendExample::MultiDense(_) => Ok(()),
         }
     }
 }
@@ -796,6 +836,275 @@ pub struct DiscoverRequestBatch {
     pub searches: Vec<DiscoverRequest>,
 }
 
+/// How to combine the results of the prefetch searches into a single ranking, default is `rrf`:
+///
+/// * `rrf` - Reciprocal rank fusion. Each point gets `weight / (k + rank)` from every prefetch
+///   it was found in, where `rank` starts from 1 and `k = 60`. Raw scores are ignored, so
+///   prefetches with incomparable scores (e.g. dense and sparse) can be mixed safely.
+///
+/// * `normalized` - Scores of each prefetch are min-max normalized into `[0, 1]`, taking the
+///   distance order into account, and the weighted sum of the normalized scores is used.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Default, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Fusion {
+    #[default]
+    Rrf,
+    Normalized,
+}
+
+/// A single search to run as a part of the hybrid search
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct HybridPrefetch {
+    /// Look for vectors closest to this
+    #[validate]
+    pub vector: NamedVectorStruct,
+    /// Look only for points which satisfies this conditions.
+    /// Applied in addition to the filter of the hybrid request
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Additional search params
+    #[validate]
+    pub params: Option<SearchParams>,
+    /// Max number of candidates to take from this search
+    #[validate(range(min = 1))]
+    pub limit: usize,
+    /// Minimal score of the candidates taken from this search
+    pub score_threshold: Option<ScoreType>,
+    /// Weight of this search in the fusion. Default: 1.0
+    pub weight: Option<f32>,
+}
+
+/// Hybrid search request.
+/// Runs several searches, e.g. over dense and sparse vectors, and fuses their results into a
+/// single ranking.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct HybridSearchRequestInternal {
+    /// Searches to run, their results are fused into the final ranking
+    #[validate]
+    #[validate(length(min = 1, message = "must specify at least one prefetch"))]
+    pub prefetch: Vec<HybridPrefetch>,
+    /// How to fuse the results of the prefetches. Default: `rrf`
+    pub fusion: Option<Fusion>,
+    /// Look only for points which satisfies this conditions. Applied to every prefetch
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Max number of result to return
+    #[validate(range(min = 1))]
+    pub limit: usize,
+    /// Offset of the first result to return.
+    /// May be used to paginate results.
+    pub offset: Option<usize>,
+    /// Select which payload to return with the response. Default: None
+    pub with_payload: Option<WithPayloadInterface>,
+    /// Whether to return the point vector with the result?
+    #[serde(default, alias = "with_vectors")]
+    pub with_vector: Option<WithVector>,
+    /// Define a minimal fused score for the result.
+    /// If defined, results with lower fused scores will not be returned.
+    pub score_threshold: Option<ScoreType>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct HybridSearchRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub search_request: HybridSearchRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Recommend points by positive and negative examples
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Default, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RecommendInput {
+    /// Look for vectors closest to those
+    #[serde(default)]
+    #[validate]
+    pub positive: Vec<RecommendExample>,
+    /// Try to avoid vectors like this
+    #[serde(default)]
+    #[validate]
+    pub negative: Vec<RecommendExample>,
+    /// How to use positive and negative examples to find the results. Default: `average_vector`
+    pub strategy: Option<RecommendStrategy>,
+}
+
+/// Look for points closer to the target, constrained by the context pairs
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct DiscoverInput {
+    /// Use this as the primary search objective
+    #[validate]
+    pub target: RecommendExample,
+    /// Search will be constrained by these pairs of examples
+    #[serde(default)]
+    #[validate]
+    pub context: Vec<ContextExamplePair>,
+}
+
+/// Rank points by the BM25 score of the query text in a payload field with a full-text index
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct TextQueryInput {
+    /// Payload key of the field with a full-text index
+    #[validate(length(min = 1))]
+    pub key: String,
+    /// Text to look for, it is tokenized the same way as the indexed documents
+    #[validate(length(min = 1))]
+    pub text: String,
+}
+
+/// Query of a single stage of the universal query
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Query {
+    /// Look for the nearest neighbours of the vector, or of the vector of the point with this ID
+    Nearest(RecommendExample),
+    /// Recommend points by positive and negative examples
+    Recommend(RecommendInput),
+    /// Look for points closer to the target, constrained by the context pairs
+    Discover(DiscoverInput),
+    /// Look for points within the space constrained by the context pairs
+    Context(Vec<ContextExamplePair>),
+    /// Fuse the results of the prefetches into a single ranking
+    Fusion(Fusion),
+    /// Rank points by the BM25 score of the text in a full-text indexed payload field
+    Text(TextQueryInput),
+}
+
+impl Validate for Query {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Query::Nearest(example) => example.validate(),
+            Query::Recommend(recommend) => recommend.validate(),
+            Query::Discover(discover) => discover.validate(),
+            Query::Context(pairs) => pairs.iter().try_for_each(Validate::validate),
+            Query::Fusion(_) => Ok(()),
+            Query::Text(text) => text.validate(),
+        }
+    }
+}
+
+/// Query of the universal query, a bare vector or point ID stands for the `nearest` query
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
+#[serde(untagged)]
+pub enum QueryInterface {
+    Nearest(RecommendExample),
+    Query(Query),
+}
+
+impl Validate for QueryInterface {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            QueryInterface::Nearest(example) => example.validate(),
+            QueryInterface::Query(query) => query.validate(),
+        }
+    }
+}
+
+impl From<QueryInterface> for Query {
+    fn from(query: QueryInterface) -> Self {
+        match query {
+            QueryInterface::Nearest(example) => Query::Nearest(example),
+            QueryInterface::Query(query) => query,
+        }
+    }
+}
+
+/// A stage of the universal query, which produces candidates for the stage above it
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct Prefetch {
+    /// Sub-requests to run first, their results are the candidates of this stage
+    #[validate]
+    pub prefetch: Option<Vec<Prefetch>>,
+    /// Query to search or rescore the candidates with.
+    /// If not specified, the candidates of the sub-requests are taken as they are
+    #[validate]
+    pub query: Option<QueryInterface>,
+    /// Define which vector to use for the query, if not specified - try to use default vector
+    pub using: Option<UsingVector>,
+    /// Look only for points which satisfies this conditions.
+    /// Applied in addition to the filters of the stages above
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Additional search params
+    #[validate]
+    pub params: Option<SearchParams>,
+    /// Minimal score of the candidates taken from this stage
+    pub score_threshold: Option<ScoreType>,
+    /// Max number of candidates to take from this stage. Default: 10
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+}
+
+/// Universal query request.
+/// Runs any kind of search - nearest, recommend, discover, context or fusion of several searches.
+/// The query of every stage rescores the candidates of its prefetches, or searches the whole
+/// collection if there are none.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryRequestInternal {
+    /// Sub-requests to run first, their results are the candidates of the main query
+    #[validate]
+    pub prefetch: Option<Vec<Prefetch>>,
+    /// Query to search or rescore the candidates with.
+    /// If neither query nor prefetch is specified, points which satisfy the filter are returned
+    #[validate]
+    pub query: Option<QueryInterface>,
+    /// Define which vector to use for the query, if not specified - try to use default vector
+    pub using: Option<UsingVector>,
+    /// Look only for points which satisfies this conditions. Applied to every prefetch as well
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Additional search params
+    #[validate]
+    pub params: Option<SearchParams>,
+    /// Define a minimal score threshold for the result.
+    /// If defined, less similar results will not be returned.
+    pub score_threshold: Option<ScoreType>,
+    /// Max number of result to return. Default: 10
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+    /// Offset of the first result to return.
+    /// May be used to paginate results.
+    pub offset: Option<usize>,
+    /// Select which payload to return with the response. Default: None
+    pub with_payload: Option<WithPayloadInterface>,
+    /// Whether to return the point vector with the result?
+    #[serde(default, alias = "with_vectors")]
+    pub with_vector: Option<WithVector>,
+    /// The location used to lookup the vectors of the point IDs in the queries.
+    /// If not specified - use current collection
+    #[serde(default)]
+    pub lookup_from: Option<LookupLocation>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub query_request: QueryRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+pub struct QueryRequestBatch {
+    #[validate]
+    pub searches: Vec<QueryRequest>,
+}
+
+pub const fn default_query_limit() -> usize {
+    10
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
 pub struct PointGroup {
     /// Scored points that have the same value of the group_by key
@@ -849,6 +1158,156 @@ pub struct CountResult {
     pub count: usize,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct FacetRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub facet_request: FacetRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Facet Request
+/// Counts the number of points for each value of the given payload key.
+/// The key must have a keyword or integer index.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct FacetRequestInternal {
+    /// Payload key to count values of
+    #[validate(length(min = 1))]
+    pub key: String,
+    /// Max number of values to return. Default is 10.
+    #[validate(range(min = 1))]
+    #[serde(default = "default_facet_limit")]
+    pub limit: usize,
+    /// Count only points which satisfy these conditions
+    #[validate]
+    pub filter: Option<Filter>,
+    /// If true, count exact number of points for each value.
+    /// If false, only the most frequent values of each segment are counted, which is faster
+    /// but may underestimate counts of less frequent values. Default: false
+    #[serde(default)]
+    pub exact: bool,
+}
+
+pub const fn default_facet_limit() -> usize {
+    10
+}
+
+impl From<&FacetRequestInternal> for FacetParams {
+    fn from(request: &FacetRequestInternal) -> Self {
+        FacetParams {
+            key: request.key.clone(),
+            limit: request.limit,
+            filter: request.filter.clone(),
+            exact: request.exact,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct FacetResponse {
+    /// Values of the key with the number of points having them, the most frequent first
+    pub hits: Vec<FacetValueHit>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchMatrixRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub search_matrix_request: SearchMatrixRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Search Matrix Request
+/// Samples random points which satisfy the filter and searches the nearest neighbours of each
+/// of them within the sample.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchMatrixRequestInternal {
+    /// Look only for points which satisfies this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+    /// How many points to select and search within. Default is 10.
+    #[validate(range(min = 2))]
+    #[serde(default = "default_search_matrix_sample")]
+    pub sample: usize,
+    /// How many neighbours per sample to find. Default is 3.
+    #[validate(range(min = 1))]
+    #[serde(default = "default_search_matrix_limit")]
+    pub limit: usize,
+    /// Define which vector to use for searching, if not specified - try to use default vector
+    #[serde(default)]
+    pub using: Option<UsingVector>,
+}
+
+pub const fn default_search_matrix_sample() -> usize {
+    10
+}
+
+pub const fn default_search_matrix_limit() -> usize {
+    3
+}
+
+/// Pair of points with the score between them
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchMatrixPair {
+    pub a: PointIdType,
+    pub b: PointIdType,
+    pub score: ScoreType,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchMatrixPairsResponse {
+    /// List of pairs of points with scores
+    pub pairs: Vec<SearchMatrixPair>,
+}
+
+/// Sparse matrix in coordinate format, rows and columns are indices into `ids`
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchMatrixOffsetsResponse {
+    /// Row indices of the matrix
+    pub offsets_row: Vec<u64>,
+    /// Column indices of the matrix
+    pub offsets_col: Vec<u64>,
+    /// Scores associated with matrix coordinates
+    pub scores: Vec<ScoreType>,
+    /// Ids of the points in order
+    pub ids: Vec<PointIdType>,
+}
+
+/// Internal request for statistics of sparse vector dimensions, used to apply the IDF modifier
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVectorStatisticsRequest {
+    /// Dimensions to collect statistics for, per vector name
+    pub vectors: HashMap<String, Vec<DimId>>,
+}
+
+/// Statistics of sparse vector dimensions, per vector name
+pub type SparseVectorStatisticsResult = HashMap<String, SparseVectorStatistics>;
+
+/// Internal request to rank points by the BM25 score of the text in a full-text indexed field
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSearchRequest {
+    /// Payload key of the field with a full-text index
+    pub key: PayloadKeyType,
+    /// Text to look for
+    pub text: String,
+    /// Look only for points which satisfy this conditions
+    pub filter: Option<Filter>,
+    /// Max number of points to return
+    pub limit: usize,
+}
+
 #[derive(Error, Debug, Clone)]
 #[error("{0}")]
 pub enum CollectionError {
@@ -1020,6 +1479,18 @@ impl From<OperationError> for CollectionError {
             OperationError::WrongSparse => Self::BadInput {
                 description: "Conversion between sparse and regular vectors failed".to_string(),
             },
+            OperationError::WrongMulti => Self::BadInput {
+                description: "Conversion between multi and regular vectors failed".to_string(),
+            },
+            OperationError::MissingRangeIndexForOrderBy { .. } => Self::BadInput {
+                description: format!("{err}"),
+            },
+            OperationError::MissingMapIndexForFacet { .. } => Self::BadInput {
+                description: format!("{err}"),
+            },
+            OperationError::MissingFullTextIndexForTextQuery { .. } => Self::BadInput {
+                description: format!("{err}"),
+            },
         }
     }
 }
@@ -1221,6 +1692,7 @@ impl Record {
 /// Params of single vector data storage
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[validate(schema(function = "validate_vector_params"))]
 pub struct VectorParams {
     /// Size of a vectors used
     #[validate(custom = "validate_nonzerou64_range_min_1_max_65536")]
@@ -1243,6 +1715,25 @@ pub struct VectorParams {
     /// Default: false
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
+    /// If set, each point holds multiple vectors of this size instead of a single one,
+    /// e.g. token embeddings of late interaction models. Multi vectors can't be quantized,
+    /// collection quantization config is not applied to them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multivector_config: Option<MultiVectorConfig>,
+    /// Type of the vector elements in the storage. Smaller types reduce memory usage at the cost of precision.
+    /// Default: float32
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<VectorStorageDatatype>,
+}
+
+pub fn validate_vector_params(vector_params: &VectorParams) -> Result<(), ValidationError> {
+    if vector_params.multivector_config.is_some() && vector_params.quantization_config.is_some() {
+        Err(ValidationError::new(
+            "quantization_config is not supported for multi vectors",
+        ))
+    } else {
+        Ok(())
+    }
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1273,16 +1764,32 @@ pub struct SparseVectorParams {
     /// Custom params for index. If none - values from collection configuration are used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub index: Option<SparseIndexParams>,
+    /// Modification of vector weights, applied at query time.
+    /// Default: none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modifier: Option<Modifier>,
 }
 
 impl Anonymize for SparseVectorParams {
     fn anonymize(&self) -> Self {
         Self {
             index: self.index.anonymize(),
+            modifier: self.modifier,
         }
     }
 }
 
+/// If used, include weight modification, which will be applied to sparse vectors at query time:
+/// None - no modification (default)
+/// Idf - inverse document frequency, based on statistics of the collection
+#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Modifier {
+    #[default]
+    None,
+    Idf,
+}
+
 /// Configuration for sparse inverted index.
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
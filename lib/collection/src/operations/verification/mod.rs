@@ -0,0 +1,525 @@
+mod update;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use segment::types::{Condition, Filter, PayloadFieldSchema, PayloadKeyType, SearchParams};
+pub use update::check_update_strict_mode;
+
+use crate::config::StrictModeConfig;
+use crate::grouping::group_by::{GroupRequest, SourceRequest};
+use crate::operations::types::{
+    CollectionError, CollectionResult, CoreSearchRequest, CountRequestInternal,
+    DiscoverRequestInternal, FacetRequestInternal, HybridPrefetch, HybridSearchRequestInternal,
+    Prefetch, QueryRequestInternal, RecommendRequestInternal, ScrollRequestInternal,
+    SearchMatrixRequestInternal, SearchRequestInternal,
+};
+
+/// Payload fields of a collection which have an index
+pub type IndexedFields = HashMap<PayloadKeyType, PayloadFieldSchema>;
+
+/// Read requests which can be checked against the limits of the strict mode
+pub trait StrictModeVerification {
+    /// Max number of points the request returns
+    fn query_limit(&self) -> Option<usize>;
+
+    /// Number of points the request skips before the returned ones
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+
+    /// Filter the points are read with
+    fn filter(&self) -> Option<&Filter>;
+
+    /// Check the requests nested in this one, if any
+    fn check_nested(
+        &self,
+        _strict_mode_config: &StrictModeConfig,
+        _indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        Ok(())
+    }
+
+    fn check_strict_mode(
+        &self,
+        strict_mode_config: &StrictModeConfig,
+        indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        check_max(
+            "limit",
+            self.query_limit(),
+            strict_mode_config.max_query_limit,
+        )?;
+        check_max(
+            "offset",
+            self.query_offset(),
+            strict_mode_config.max_query_offset,
+        )?;
+        check_max(
+            "hnsw_ef",
+            self.search_params().and_then(|params| params.hnsw_ef),
+            strict_mode_config.search_max_hnsw_ef,
+        )?;
+
+        if strict_mode_config.unindexed_filtering_retrieve == Some(false) {
+            if let Some(filter) = self.filter() {
+                check_filter_indexed(filter, indexed_fields)?;
+            }
+        }
+
+        self.check_nested(strict_mode_config, indexed_fields)
+    }
+}
+
+/// Check the timeout of a request does not exceed the one allowed by the strict mode
+pub fn check_timeout(
+    timeout: Option<Duration>,
+    strict_mode_config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    check_max(
+        "timeout",
+        timeout.map(|timeout| timeout.as_secs() as usize),
+        strict_mode_config.max_timeout,
+    )
+}
+
+/// Check the size of a batch of read requests does not exceed the one allowed by the strict mode
+pub fn check_batch_size(
+    batch_size: usize,
+    strict_mode_config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    check_max(
+        "batch size",
+        Some(batch_size),
+        strict_mode_config.search_max_batchsize,
+    )
+}
+
+fn check_max(parameter: &str, value: Option<usize>, max: Option<usize>) -> CollectionResult<()> {
+    match (value, max) {
+        (Some(value), Some(max)) if value > max => Err(CollectionError::bad_request(format!(
+            "The {parameter} of {value} exceeds the max allowed {max} in strict mode",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check all payload fields the filter conditions on have an index
+fn check_filter_indexed(filter: &Filter, indexed_fields: &IndexedFields) -> CollectionResult<()> {
+    match unindexed_key(filter, indexed_fields, None) {
+        None => Ok(()),
+        Some(key) => Err(CollectionError::bad_request(format!(
+            "Filtering by the unindexed field {key} is not allowed in strict mode, \
+             create a payload index for it",
+        ))),
+    }
+}
+
+/// Find a payload key the filter conditions on which has no index
+///
+/// Keys of nested conditions are prefixed with the path of the array they are nested in.
+fn unindexed_key(
+    filter: &Filter,
+    indexed_fields: &IndexedFields,
+    prefix: Option<&str>,
+) -> Option<String> {
+    let full_key = |key: &str| match prefix {
+        Some(prefix) => format!("{prefix}.{key}"),
+        None => key.to_string(),
+    };
+
+    let min_should = filter
+        .min_should
+        .as_ref()
+        .map(|min_should| &min_should.conditions);
+
+    [
+        filter.must.as_ref(),
+        filter.should.as_ref(),
+        min_should,
+        filter.must_not.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+    .find_map(|condition| {
+        match condition {
+            Condition::Field(field) => Some(full_key(&field.key)),
+            Condition::IsEmpty(is_empty) => Some(full_key(&is_empty.is_empty.key)),
+            Condition::IsNull(is_null) => Some(full_key(&is_null.is_null.key)),
+            Condition::HasId(_) => None,
+            Condition::Nested(nested) => {
+                let nested_prefix = full_key(&nested.array_key());
+                unindexed_key(nested.filter(), indexed_fields, Some(&nested_prefix))
+            }
+            Condition::Filter(filter) => unindexed_key(filter, indexed_fields, prefix),
+        }
+        .filter(|key| !indexed_fields.contains_key(key))
+    })
+}
+
+impl StrictModeVerification for CoreSearchRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for SearchRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for RecommendRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for DiscoverRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for GroupRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn check_nested(
+        &self,
+        strict_mode_config: &StrictModeConfig,
+        indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        match &self.source {
+            SourceRequest::Search(request) => {
+                request.check_strict_mode(strict_mode_config, indexed_fields)
+            }
+            SourceRequest::Recommend(request) => {
+                request.check_strict_mode(strict_mode_config, indexed_fields)
+            }
+        }
+    }
+}
+
+impl StrictModeVerification for HybridPrefetch {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for HybridSearchRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn check_nested(
+        &self,
+        strict_mode_config: &StrictModeConfig,
+        indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        self.prefetch
+            .iter()
+            .try_for_each(|prefetch| prefetch.check_strict_mode(strict_mode_config, indexed_fields))
+    }
+}
+
+impl StrictModeVerification for Prefetch {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn check_nested(
+        &self,
+        strict_mode_config: &StrictModeConfig,
+        indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        self.prefetch
+            .iter()
+            .flatten()
+            .try_for_each(|prefetch| prefetch.check_strict_mode(strict_mode_config, indexed_fields))
+    }
+}
+
+impl StrictModeVerification for QueryRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn check_nested(
+        &self,
+        strict_mode_config: &StrictModeConfig,
+        indexed_fields: &IndexedFields,
+    ) -> CollectionResult<()> {
+        self.prefetch
+            .iter()
+            .flatten()
+            .try_for_each(|prefetch| prefetch.check_strict_mode(strict_mode_config, indexed_fields))
+    }
+}
+
+impl StrictModeVerification for SearchMatrixRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.sample)
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for CountRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for FacetRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit)
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+impl StrictModeVerification for ScrollRequestInternal {
+    fn query_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{FieldCondition, Match, PayloadSchemaType};
+
+    use super::*;
+
+    fn match_condition(key: &str) -> Condition {
+        Condition::Field(FieldCondition::new_match(
+            key,
+            Match::from("value".to_string()),
+        ))
+    }
+
+    fn indexed_fields(keys: &[&str]) -> IndexedFields {
+        keys.iter()
+            .map(|key| (key.to_string(), PayloadSchemaType::Keyword.into()))
+            .collect()
+    }
+
+    fn scroll_request(limit: usize, filter: Option<Filter>) -> ScrollRequestInternal {
+        ScrollRequestInternal {
+            limit: Some(limit),
+            filter,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_limits() {
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            max_query_limit: Some(100),
+            max_timeout: Some(10),
+            search_max_batchsize: Some(4),
+            ..Default::default()
+        };
+        let indexed = IndexedFields::new();
+
+        assert!(scroll_request(100, None)
+            .check_strict_mode(&config, &indexed)
+            .is_ok());
+        assert!(scroll_request(101, None)
+            .check_strict_mode(&config, &indexed)
+            .is_err());
+
+        assert!(check_timeout(None, &config).is_ok());
+        assert!(check_timeout(Some(Duration::from_secs(10)), &config).is_ok());
+        assert!(check_timeout(Some(Duration::from_secs(11)), &config).is_err());
+
+        assert!(check_batch_size(4, &config).is_ok());
+        assert!(check_batch_size(5, &config).is_err());
+
+        // Limits which are not set are not enforced
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            ..Default::default()
+        };
+        assert!(scroll_request(100_000, None)
+            .check_strict_mode(&config, &indexed)
+            .is_ok());
+        assert!(check_timeout(Some(Duration::from_secs(3600)), &config).is_ok());
+    }
+
+    #[test]
+    fn test_check_nested_limits() {
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            max_query_limit: Some(100),
+            ..Default::default()
+        };
+        let indexed = IndexedFields::new();
+
+        let request = QueryRequestInternal {
+            prefetch: Some(vec![Prefetch {
+                prefetch: Some(vec![Prefetch {
+                    limit: Some(1000),
+                    ..Default::default()
+                }]),
+                limit: Some(10),
+                ..Default::default()
+            }]),
+            limit: Some(10),
+            ..Default::default()
+        };
+        assert!(request.check_strict_mode(&config, &indexed).is_err());
+    }
+
+    #[test]
+    fn test_check_unindexed_filter() {
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            unindexed_filtering_retrieve: Some(false),
+            ..Default::default()
+        };
+        let indexed = indexed_fields(&["city", "country.cities[].name"]);
+
+        let check =
+            |filter: Filter| scroll_request(10, Some(filter)).check_strict_mode(&config, &indexed);
+
+        assert!(check(Filter::new_must(match_condition("city"))).is_ok());
+        assert!(check(Filter::new_should(match_condition("color"))).is_err());
+        assert!(
+            check(Filter::new_must(Condition::Filter(Filter::new_must_not(
+                match_condition("color")
+            ))))
+            .is_err()
+        );
+
+        // Keys of nested conditions are checked with the path of their array
+        assert!(check(Filter::new_must(Condition::new_nested(
+            "country.cities",
+            Filter::new_must(match_condition("name")),
+        )))
+        .is_ok());
+        assert!(check(Filter::new_must(Condition::new_nested(
+            "country.cities",
+            Filter::new_must(match_condition("population")),
+        )))
+        .is_err());
+
+        // Unindexed fields are allowed unless forbidden explicitly
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            ..Default::default()
+        };
+        assert!(
+            scroll_request(10, Some(Filter::new_must(match_condition("color"))))
+                .check_strict_mode(&config, &indexed)
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use segment::types::Filter;
+
+use super::{check_filter_indexed, check_max, IndexedFields};
+use crate::config::StrictModeConfig;
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::types::CollectionResult;
+use crate::operations::vector_ops::VectorOperations;
+use crate::operations::CollectionUpdateOperations;
+
+/// Check an update operation against the limits of the strict mode
+///
+/// The rate of the updates is limited by the collection itself.
+pub fn check_update_strict_mode(
+    operation: &CollectionUpdateOperations,
+    strict_mode_config: &StrictModeConfig,
+    indexed_fields: &IndexedFields,
+) -> CollectionResult<()> {
+    check_max(
+        "number of updated points",
+        upserted_points_count(operation),
+        strict_mode_config.upsert_max_batchsize,
+    )?;
+
+    if strict_mode_config.unindexed_filtering_update == Some(false) {
+        if let Some(filter) = update_filter(operation) {
+            check_filter_indexed(filter, indexed_fields)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of points the operation inserts or updates the vectors of
+fn upserted_points_count(operation: &CollectionUpdateOperations) -> Option<usize> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(points)) => {
+            match points {
+                PointInsertOperationsInternal::PointsBatch(batch) => Some(batch.ids.len()),
+                PointInsertOperationsInternal::PointsList(points) => Some(points.len()),
+            }
+        }
+        CollectionUpdateOperations::VectorOperation(VectorOperations::UpdateVectors(update)) => {
+            Some(update.points.len())
+        }
+        _ => None,
+    }
+}
+
+/// Filter selecting the points the operation updates, if any
+fn update_filter(operation: &CollectionUpdateOperations) -> Option<&Filter> {
+    match operation {
+        CollectionUpdateOperations::PointOperation(operation) => match operation {
+            PointOperations::DeletePointsByFilter(filter) => Some(filter),
+            PointOperations::UpsertPoints(_)
+            | PointOperations::DeletePoints { .. }
+            | PointOperations::SyncPoints(_) => None,
+        },
+        CollectionUpdateOperations::VectorOperation(operation) => match operation {
+            VectorOperations::DeleteVectorsByFilter(filter, _) => Some(filter),
+            VectorOperations::UpdateVectors(_) | VectorOperations::DeleteVectors(..) => None,
+        },
+        CollectionUpdateOperations::PayloadOperation(operation) => match operation {
+            PayloadOps::SetPayload(set_payload) | PayloadOps::OverwritePayload(set_payload) => {
+                set_payload.filter.as_ref()
+            }
+            PayloadOps::DeletePayload(delete_payload) => delete_payload.filter.as_ref(),
+            PayloadOps::ClearPayloadByFilter(filter) => Some(filter),
+            PayloadOps::ClearPayload { .. } => None,
+        },
+        CollectionUpdateOperations::FieldIndexOperation(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::data_types::vectors::only_default_vector;
+    use segment::types::{Condition, FieldCondition, Match};
+
+    use super::*;
+    use crate::operations::point_ops::PointStruct;
+
+    #[test]
+    fn test_check_update() {
+        let config = StrictModeConfig {
+            enabled: Some(true),
+            upsert_max_batchsize: Some(2),
+            unindexed_filtering_update: Some(false),
+            ..Default::default()
+        };
+        let indexed = IndexedFields::new();
+
+        let upsert = |count: u64| {
+            let points = (0..count)
+                .map(|id| PointStruct {
+                    id: id.into(),
+                    vector: only_default_vector(&[0.0, 1.0]).into(),
+                    payload: None,
+                })
+                .collect::<Vec<_>>();
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            ))
+        };
+        assert!(check_update_strict_mode(&upsert(2), &config, &indexed).is_ok());
+        assert!(check_update_strict_mode(&upsert(3), &config, &indexed).is_err());
+
+        let delete = CollectionUpdateOperations::PointOperation(
+            PointOperations::DeletePointsByFilter(Filter::new_must(Condition::Field(
+                FieldCondition::new_match("color", Match::from("red".to_string())),
+            ))),
+        );
+        assert!(check_update_strict_mode(&delete, &config, &indexed).is_err());
+    }
+}
@@ -27,7 +27,9 @@ use crate::operations::types::{
     RecommendRequestInternal, RecommendStrategy, UsingVector,
 };
 
-fn avg_vectors<'a>(vectors: impl Iterator<Item = VectorRef<'a>>) -> CollectionResult<Vector> {
+pub(crate) fn avg_vectors<'a>(
+    vectors: impl Iterator<Item = VectorRef<'a>>,
+) -> CollectionResult<Vector> {
     let mut avg_dense = DenseVector::default();
     let mut avg_sparse = SparseVector::default();
     let mut dense_count = 0;
@@ -48,6 +50,11 @@ fn avg_vectors<'a>(vectors: impl Iterator<Item = VectorRef<'a>>) -> CollectionRe
                 sparse_count += 1;
                 avg_sparse = vector.combine_aggregate(&avg_sparse, |v1, v2| v1 + v2);
             }
+            VectorRef::MultiDense(_) => {
+                return Err(CollectionError::bad_input(
+                    "Multi vectors are not supported with `average` strategy, use `best_score` instead".to_owned(),
+                ));
+            }
         }
     }
 
@@ -76,7 +83,10 @@ fn avg_vectors<'a>(vectors: impl Iterator<Item = VectorRef<'a>>) -> CollectionRe
     }
 }
 
-fn merge_positive_and_negative_avg(positive: Vector, negative: Vector) -> CollectionResult<Vector> {
+pub(crate) fn merge_positive_and_negative_avg(
+    positive: Vector,
+    negative: Vector,
+) -> CollectionResult<Vector> {
     match (positive, negative) {
         (Vector::Dense(positive), Vector::Dense(negative)) => {
             let vector: DenseVector = positive
@@ -320,6 +330,7 @@ fn recommend_by_avg_vector(
         )),
         filter: Some(Filter {
             should: None,
+            min_should: None,
             must: filter.clone().map(|filter| vec![Condition::Filter(filter)]),
             must_not: Some(vec![Condition::HasId(HasIdCondition {
                 has_id: reference_vectors_ids.iter().cloned().collect(),
@@ -383,6 +394,7 @@ fn recommend_by_best_score(
         query,
         filter: Some(Filter {
             should: None,
+            min_should: None,
             must: filter.map(|filter| vec![Condition::Filter(filter)]),
             must_not: Some(vec![Condition::HasId(HasIdCondition {
                 has_id: reference_vectors_ids.into_iter().collect(),
@@ -331,6 +331,12 @@ pub fn internal_create_index(
                     segment::types::PayloadSchemaType::Bool => {
                         api::grpc::qdrant::FieldType::Bool as i32
                     }
+                    segment::types::PayloadSchemaType::Datetime => {
+                        api::grpc::qdrant::FieldType::Datetime as i32
+                    }
+                    segment::types::PayloadSchemaType::Uuid => {
+                        api::grpc::qdrant::FieldType::Uuid as i32
+                    }
                 },
                 None,
             ),
@@ -339,6 +345,34 @@ pub fn internal_create_index(
                     api::grpc::qdrant::FieldType::Text as i32,
                     Some(text_index_params.into()),
                 ),
+                PayloadSchemaParams::Keyword(keyword_index_params) => (
+                    api::grpc::qdrant::FieldType::Keyword as i32,
+                    Some(keyword_index_params.into()),
+                ),
+                PayloadSchemaParams::Integer(integer_index_params) => (
+                    api::grpc::qdrant::FieldType::Integer as i32,
+                    Some(integer_index_params.into()),
+                ),
+                PayloadSchemaParams::Float(float_index_params) => (
+                    api::grpc::qdrant::FieldType::Float as i32,
+                    Some(float_index_params.into()),
+                ),
+                PayloadSchemaParams::Datetime(datetime_index_params) => (
+                    api::grpc::qdrant::FieldType::Datetime as i32,
+                    Some(datetime_index_params.into()),
+                ),
+                PayloadSchemaParams::Uuid(uuid_index_params) => (
+                    api::grpc::qdrant::FieldType::Uuid as i32,
+                    Some(uuid_index_params.into()),
+                ),
+                PayloadSchemaParams::Geo(geo_index_params) => (
+                    api::grpc::qdrant::FieldType::Geo as i32,
+                    Some(geo_index_params.into()),
+                ),
+                PayloadSchemaParams::Bool(bool_index_params) => (
+                    api::grpc::qdrant::FieldType::Bool as i32,
+                    Some(bool_index_params.into()),
+                ),
             },
         })
         .map(|(field_type, field_params)| (Some(field_type), field_params))
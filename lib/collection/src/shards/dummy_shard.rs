@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::order_by::OrderBy;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -10,7 +11,9 @@ use tokio::runtime::Handle;
 
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    CountRequestInternal, CountResult, FacetRequestInternal, FacetResponse, PointRequestInternal,
+    Record, SparseVectorStatisticsRequest, SparseVectorStatisticsResult, TextSearchRequest,
+    UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
 use crate::shards::shard_trait::ShardOperation;
@@ -72,11 +75,21 @@ impl ShardOperation for DummyShard {
         _: &WithPayloadInterface,
         _: &WithVector,
         _: Option<&Filter>,
+        _: Option<&OrderBy>,
         _: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         self.dummy()
     }
 
+    async fn sample_points(
+        &self,
+        _: usize,
+        _: Option<&Filter>,
+        _: &Handle,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
+        self.dummy()
+    }
+
     async fn info(&self) -> CollectionResult<CollectionInfo> {
         self.dummy()
     }
@@ -94,6 +107,29 @@ impl ShardOperation for DummyShard {
         self.dummy()
     }
 
+    async fn facet(
+        &self,
+        _: Arc<FacetRequestInternal>,
+        _: &Handle,
+    ) -> CollectionResult<FacetResponse> {
+        self.dummy()
+    }
+
+    async fn text_search(
+        &self,
+        _: Arc<TextSearchRequest>,
+        _: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.dummy()
+    }
+
+    async fn sparse_vector_statistics(
+        &self,
+        _: Arc<SparseVectorStatisticsRequest>,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        self.dummy()
+    }
+
     async fn retrieve(
         &self,
         _: Arc<PointRequestInternal>,
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::order_by::OrderBy;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
     WithVector,
@@ -14,7 +15,9 @@ use super::update_tracker::UpdateTracker;
 use crate::operations::point_ops::{PointOperations, PointStruct, PointSyncOperation};
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    CountRequestInternal, CountResult, FacetRequestInternal, FacetResponse, PointRequestInternal,
+    Record, SparseVectorStatisticsRequest, SparseVectorStatisticsResult, TextSearchRequest,
+    UpdateResult,
 };
 use crate::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use crate::shards::local_shard::LocalShard;
@@ -92,6 +95,7 @@ impl ForwardProxyShard {
                 &WithPayloadInterface::Bool(true),
                 &true.into(),
                 None,
+                None,
                 runtime_handle,
             )
             .await?;
@@ -187,6 +191,7 @@ impl ShardOperation for ForwardProxyShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         let local_shard = &self.wrapped_shard;
@@ -197,11 +202,24 @@ impl ShardOperation for ForwardProxyShard {
                 with_payload_interface,
                 with_vector,
                 filter,
+                order_by,
                 search_runtime_handle,
             )
             .await
     }
 
+    async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .sample_points(limit, filter, search_runtime_handle)
+            .await
+    }
+
     async fn info(&self) -> CollectionResult<CollectionInfo> {
         let local_shard = &self.wrapped_shard;
         local_shard.info().await
@@ -223,6 +241,35 @@ impl ShardOperation for ForwardProxyShard {
         local_shard.count(request).await
     }
 
+    async fn facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<FacetResponse> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.facet(request, search_runtime_handle).await
+    }
+
+    /// Forward read-only `text_search` to `wrapped_shard`
+    async fn text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .text_search(request, search_runtime_handle)
+            .await
+    }
+
+    async fn sparse_vector_statistics(
+        &self,
+        request: Arc<SparseVectorStatisticsRequest>,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.sparse_vector_statistics(request).await
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
@@ -16,8 +16,8 @@ use segment::index::field_index::CardinalityEstimation;
 use segment::segment::Segment;
 use segment::segment_constructor::{build_segment, load_segment};
 use segment::types::{
-    CompressionRatio, Filter, PayloadIndexInfo, PayloadKeyType, PayloadStorageType, PointIdType,
-    QuantizationConfig, SegmentConfig, SegmentType,
+    CompressionRatio, Filter, PayloadIndexInfo, PayloadKeyType, PointIdType, QuantizationConfig,
+    SegmentConfig, SegmentType,
 };
 use segment::utils::mem::Mem;
 use tokio::fs::{copy, create_dir_all, remove_dir_all};
@@ -35,7 +35,8 @@ use crate::config::CollectionConfig;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{
     check_sparse_compatible_with_segment_config, CollectionError, CollectionInfoInternal,
-    CollectionResult, CollectionStatus, OptimizersStatus,
+    CollectionResult, CollectionStatus, OptimizersStatus, SparseVectorStatisticsRequest,
+    SparseVectorStatisticsResult,
 };
 use crate::operations::CollectionUpdateOperations;
 use crate::optimizers_builder::{build_optimizers, clear_temp_segments};
@@ -371,11 +372,7 @@ impl LocalShard {
             let segment_config = SegmentConfig {
                 vector_data: vector_params.clone(),
                 sparse_vector_data: sparse_vector_params.clone(),
-                payload_storage_type: if config.params.on_disk_payload {
-                    PayloadStorageType::OnDisk
-                } else {
-                    PayloadStorageType::InMemory
-                },
+                payload_storage_type: config.params.payload_storage_type(),
             };
             let segment = thread::Builder::new()
                 .name(format!("shard-build-{collection_id}-{id}"))
@@ -687,6 +684,26 @@ impl LocalShard {
         Ok(cardinality)
     }
 
+    /// Collect statistics of the requested sparse vector dimensions over all segments
+    pub fn collect_sparse_vector_statistics(
+        &self,
+        request: &SparseVectorStatisticsRequest,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        let segments = self.segments().read();
+        let mut result = SparseVectorStatisticsResult::new();
+        for (vector_name, indices) in &request.vectors {
+            let statistics = result.entry(vector_name.clone()).or_default();
+            for (_id, segment) in segments.iter() {
+                let segment_statistics = segment
+                    .get()
+                    .read()
+                    .sparse_vector_statistics(vector_name, indices)?;
+                statistics.merge(&segment_statistics);
+            }
+        }
+        Ok(result)
+    }
+
     pub fn read_filtered<'a>(
         &'a self,
         filter: Option<&'a Filter>,
@@ -1,9 +1,14 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use futures::future::try_join_all;
 use itertools::Itertools;
+use rand::seq::IteratorRandom;
+use segment::data_types::facets::{merge_facet_counts, top_facet_hits, FacetParams};
+use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_statistics::TextStatistics;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -14,8 +19,9 @@ use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::common::stopping_guard::StoppingGuard;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, QueryEnum, Record, UpdateResult,
-    UpdateStatus,
+    CountRequestInternal, CountResult, FacetRequestInternal, FacetResponse, PointRequestInternal,
+    QueryEnum, Record, SparseVectorStatisticsRequest, SparseVectorStatisticsResult,
+    TextSearchRequest, UpdateResult, UpdateStatus,
 };
 use crate::operations::CollectionUpdateOperations;
 use crate::optimizers_builder::DEFAULT_INDEXING_THRESHOLD_KB;
@@ -100,6 +106,223 @@ impl LocalShard {
             .collect();
         Ok(top_results)
     }
+
+    async fn scroll_by_id(
+        &self,
+        offset: Option<ExtendedPointId>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<Record>> {
+        // ToDo: Make faster points selection with a set
+        let segments = self.segments();
+        let read_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let filter = filter.cloned();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment
+                            .get()
+                            .read()
+                            .read_filtered(offset, Some(limit), filter.as_ref())
+                    })
+                })
+                .collect()
+        };
+        let all_points = try_join_all(read_handles).await?;
+
+        let point_ids = all_points
+            .into_iter()
+            .flatten()
+            .sorted()
+            .dedup()
+            .take(limit)
+            .collect_vec();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        let mut points =
+            SegmentsSearcher::retrieve(segments, &point_ids, &with_payload, with_vector)?;
+        points.sort_by_key(|point| point.id);
+
+        Ok(points)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn scroll_by_field(
+        &self,
+        offset: Option<ExtendedPointId>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        order_by: &OrderBy,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<Record>> {
+        let segments = self.segments();
+        let read_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let filter = filter.cloned();
+                    let order_by = order_by.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment.get().read().read_ordered_filtered(
+                            Some(limit),
+                            filter.as_ref(),
+                            &order_by,
+                            offset,
+                        )
+                    })
+                })
+                .collect()
+        };
+        let all_reads = try_join_all(read_handles).await?;
+
+        let mut all_points = Vec::new();
+        for segment_points in all_reads {
+            all_points.extend(segment_points?);
+        }
+
+        let direction = order_by.direction();
+        let top_points = all_points
+            .into_iter()
+            .sorted_unstable_by(|(value_a, id_a), (value_b, id_b)| {
+                value_a
+                    .cmp_directed(value_b, direction)
+                    .then_with(|| id_a.cmp(id_b))
+            })
+            .unique_by(|(_, id)| *id)
+            .take(limit)
+            .collect_vec();
+
+        let point_ids = top_points.iter().map(|(_, id)| *id).collect_vec();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        let mut records: HashMap<_, _> =
+            SegmentsSearcher::retrieve(segments, &point_ids, &with_payload, with_vector)?
+                .into_iter()
+                .map(|record| (record.id, record))
+                .collect();
+
+        let ordered_records = top_points
+            .into_iter()
+            .filter_map(|(value, id)| {
+                records.remove(&id).map(|mut record| {
+                    record.order_value = Some(value);
+                    record
+                })
+            })
+            .collect();
+
+        Ok(ordered_records)
+    }
+
+    async fn do_facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<FacetResponse> {
+        let params = FacetParams::from(request.as_ref());
+        let stopping_guard = StoppingGuard::new();
+        let segments = self.segments();
+        let read_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let params = params.clone();
+                    let is_stopped = stopping_guard.get_is_stopped();
+                    search_runtime_handle
+                        .spawn_blocking(move || segment.get().read().facet(&params, &is_stopped))
+                })
+                .collect()
+        };
+        let all_reads = try_join_all(read_handles).await?;
+
+        let mut counts = HashMap::new();
+        for segment_counts in all_reads {
+            merge_facet_counts(&mut counts, segment_counts?);
+        }
+
+        // Exact counts of all values are required to merge results of different shards
+        let hits = if request.exact {
+            top_facet_hits(counts, usize::MAX)
+        } else {
+            top_facet_hits(counts, request.limit)
+        };
+        Ok(FacetResponse { hits })
+    }
+
+    async fn do_text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let segments = self.segments();
+
+        // Statistics of all segments are combined, so scores of different segments are comparable
+        let statistics_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let request = request.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment
+                            .get()
+                            .read()
+                            .text_statistics(&request.key, &request.text)
+                    })
+                })
+                .collect()
+        };
+        let mut statistics = TextStatistics::default();
+        for segment_statistics in try_join_all(statistics_handles).await? {
+            statistics.merge(&segment_statistics?);
+        }
+        let statistics = Arc::new(statistics);
+
+        let search_handles: Vec<_> = {
+            let segments_guard = segments.read();
+            segments_guard
+                .iter()
+                .map(|(_, segment)| {
+                    let segment = segment.clone();
+                    let request = request.clone();
+                    let statistics = statistics.clone();
+                    search_runtime_handle.spawn_blocking(move || {
+                        segment.get().read().text_search(
+                            &request.key,
+                            &request.text,
+                            &statistics,
+                            request.filter.as_ref(),
+                            request.limit,
+                        )
+                    })
+                })
+                .collect()
+        };
+        let mut points = Vec::new();
+        for segment_points in try_join_all(search_handles).await? {
+            points.extend(segment_points?);
+        }
+
+        // A point may be found in several segments while it is being moved, keep the latest version
+        points.sort_unstable_by_key(|point| std::cmp::Reverse(point.version));
+        let mut points = points.into_iter().unique_by(|point| point.id).collect_vec();
+        points.sort_unstable_by(|a, b| b.cmp(a));
+        points.truncate(request.limit);
+        Ok(points)
+    }
 }
 #[async_trait]
 impl ShardOperation for LocalShard {
@@ -153,9 +376,42 @@ impl ShardOperation for LocalShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
-        // ToDo: Make faster points selection with a set
+        match order_by {
+            None => {
+                self.scroll_by_id(
+                    offset,
+                    limit,
+                    with_payload_interface,
+                    with_vector,
+                    filter,
+                    search_runtime_handle,
+                )
+                .await
+            }
+            Some(order_by) => {
+                self.scroll_by_field(
+                    offset,
+                    limit,
+                    with_payload_interface,
+                    with_vector,
+                    filter,
+                    order_by,
+                    search_runtime_handle,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
         let segments = self.segments();
         let read_handles: Vec<_> = {
             let segments_guard = segments.read();
@@ -168,7 +424,7 @@ impl ShardOperation for LocalShard {
                         segment
                             .get()
                             .read()
-                            .read_filtered(offset, Some(limit), filter.as_ref())
+                            .read_random_filtered(limit, filter.as_ref())
                     })
                 })
                 .collect()
@@ -178,17 +434,10 @@ impl ShardOperation for LocalShard {
         let point_ids = all_points
             .into_iter()
             .flatten()
-            .sorted()
-            .dedup()
-            .take(limit)
-            .collect_vec();
-
-        let with_payload = WithPayload::from(with_payload_interface);
-        let mut points =
-            SegmentsSearcher::retrieve(segments, &point_ids, &with_payload, with_vector)?;
-        points.sort_by_key(|point| point.id);
+            .unique()
+            .choose_multiple(&mut rand::thread_rng(), limit);
 
-        Ok(points)
+        Ok(point_ids)
     }
 
     /// Collect overview information about the shard
@@ -216,6 +465,29 @@ impl ShardOperation for LocalShard {
         Ok(CountResult { count: total_count })
     }
 
+    async fn facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<FacetResponse> {
+        self.do_facet(request, search_runtime_handle).await
+    }
+
+    async fn text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.do_text_search(request, search_runtime_handle).await
+    }
+
+    async fn sparse_vector_statistics(
+        &self,
+        request: Arc<SparseVectorStatisticsRequest>,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        self.collect_sparse_vector_statistics(&request)
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
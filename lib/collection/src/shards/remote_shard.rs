@@ -10,10 +10,11 @@ use api::grpc::qdrant::shard_snapshot_location::Location;
 use api::grpc::qdrant::shard_snapshots_client::ShardSnapshotsClient;
 use api::grpc::qdrant::{
     CollectionOperationResponse, CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal,
-    GetCollectionInfoRequest, GetCollectionInfoRequestInternal, GetPoints, GetPointsInternal,
-    HealthCheckRequest, InitiateShardTransferRequest, RecoverShardSnapshotRequest,
-    RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal, ShardSnapshotLocation,
-    WaitForShardStateRequest,
+    FacetCounts, FacetCountsInternal, GetCollectionInfoRequest, GetCollectionInfoRequestInternal,
+    GetPoints, GetPointsInternal, HealthCheckRequest, InitiateShardTransferRequest,
+    RecoverShardSnapshotRequest, RecoverSnapshotResponse, SamplePointsInternal, ScrollPoints,
+    ScrollPointsInternal, ShardSnapshotLocation, SparseIndices, SparseVectorStatisticsInternal,
+    TextSearchInternal, WaitForShardStateRequest,
 };
 use api::grpc::transport_channel_pool::{AddTimeout, MAX_GRPC_CHANNEL_TIMEOUT};
 use async_trait::async_trait;
@@ -21,6 +22,7 @@ use parking_lot::Mutex;
 use segment::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
+use segment::data_types::order_by::OrderBy;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -40,8 +42,9 @@ use crate::operations::point_ops::{PointOperations, WriteOrdering};
 use crate::operations::snapshot_ops::SnapshotPriority;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequest, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, SearchRequestInternal,
-    UpdateResult,
+    CountRequestInternal, CountResult, FacetRequestInternal, FacetResponse, PointRequestInternal,
+    Record, SearchRequestInternal, SparseVectorStatisticsRequest, SparseVectorStatisticsResult,
+    TextSearchRequest, UpdateResult,
 };
 use crate::operations::vector_ops::VectorOperations;
 use crate::operations::{CollectionUpdateOperations, FieldIndexOperations};
@@ -556,6 +559,7 @@ impl ShardOperation for RemoteShard {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>> {
         let scroll_points = ScrollPoints {
@@ -567,6 +571,7 @@ impl ShardOperation for RemoteShard {
             with_vectors: Some(with_vector.clone().into()),
             read_consistency: None,
             shard_key_selector: None,
+            order_by: order_by.map(|order_by| order_by.clone().into()),
         };
         let request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
@@ -589,6 +594,37 @@ impl ShardOperation for RemoteShard {
         result.map_err(|e| e.into())
     }
 
+    async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
+        let request = &SamplePointsInternal {
+            collection_name: self.collection_id.clone(),
+            filter: filter.map(|f| f.clone().into()),
+            limit: limit as u64,
+            shard_id: Some(self.id),
+        };
+
+        let sample_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .sample_points(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+
+        let result: Result<Vec<ExtendedPointId>, Status> = sample_response
+            .result
+            .into_iter()
+            .map(|point_id| point_id.try_into())
+            .collect();
+
+        result.map_err(|e| e.into())
+    }
+
     async fn info(&self) -> CollectionResult<CollectionInfo> {
         let get_collection_info_request = GetCollectionInfoRequest {
             collection_name: self.collection_id.clone(),
@@ -694,6 +730,103 @@ impl ShardOperation for RemoteShard {
         )
     }
 
+    async fn facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<FacetResponse> {
+        let facet_counts = FacetCounts {
+            collection_name: self.collection_id.clone(),
+            key: request.key.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            limit: Some(request.limit as u64),
+            exact: Some(request.exact),
+            read_consistency: None,
+            shard_key_selector: None,
+        };
+
+        let request = &FacetCountsInternal {
+            facet_counts: Some(facet_counts),
+            shard_id: Some(self.id),
+        };
+        let facet_response = self
+            .with_points_client(|mut client| async move {
+                client.facet(tonic::Request::new(request.clone())).await
+            })
+            .await?
+            .into_inner();
+        let hits = facet_response
+            .hits
+            .into_iter()
+            .map(|hit| hit.try_into())
+            .collect::<Result<_, Status>>()?;
+        Ok(FacetResponse { hits })
+    }
+
+    async fn text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        _search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let request = &TextSearchInternal {
+            collection_name: self.collection_id.clone(),
+            key: request.key.clone(),
+            text: request.text.clone(),
+            filter: request.filter.clone().map(|f| f.into()),
+            limit: request.limit as u64,
+            shard_id: Some(self.id),
+        };
+        let search_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .text_search(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+
+        let result: Result<Vec<ScoredPoint>, Status> = search_response
+            .result
+            .into_iter()
+            .map(|point| try_scored_point_from_grpc(point, false))
+            .collect();
+
+        result.map_err(|e| e.into())
+    }
+
+    async fn sparse_vector_statistics(
+        &self,
+        request: Arc<SparseVectorStatisticsRequest>,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        let request = &SparseVectorStatisticsInternal {
+            collection_name: self.collection_id.clone(),
+            vectors: request
+                .vectors
+                .iter()
+                .map(|(vector_name, indices)| {
+                    let indices = SparseIndices {
+                        data: indices.clone(),
+                    };
+                    (vector_name.clone(), indices)
+                })
+                .collect(),
+            shard_id: Some(self.id),
+        };
+        let statistics_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .sparse_vector_statistics(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        Ok(statistics_response
+            .result
+            .into_iter()
+            .map(|(vector_name, statistics)| (vector_name, statistics.into()))
+            .collect())
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
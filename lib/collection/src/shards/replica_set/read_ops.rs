@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures::FutureExt as _;
+use segment::data_types::order_by::OrderBy;
 use segment::types::*;
 
 use super::ShardReplicaSet;
@@ -19,16 +20,19 @@ impl ShardReplicaSet {
         filter: Option<&Filter>,
         read_consistency: Option<ReadConsistency>,
         local_only: bool,
+        order_by: Option<&OrderBy>,
     ) -> CollectionResult<Vec<Record>> {
         let with_payload_interface = Arc::new(with_payload_interface.clone());
         let with_vector = Arc::new(with_vector.clone());
         let filter = filter.map(|filter| Arc::new(filter.clone()));
+        let order_by = order_by.map(|order_by| Arc::new(order_by.clone()));
 
         self.execute_and_resolve_read_operation(
             |shard| {
                 let with_payload_interface = with_payload_interface.clone();
                 let with_vector = with_vector.clone();
                 let filter = filter.clone();
+                let order_by = order_by.clone();
                 let search_runtime = self.search_runtime.clone();
 
                 async move {
@@ -39,6 +43,7 @@ impl ShardReplicaSet {
                             &with_payload_interface,
                             &with_vector,
                             filter.as_deref(),
+                            order_by.as_deref(),
                             &search_runtime,
                         )
                         .await
@@ -50,6 +55,33 @@ impl ShardReplicaSet {
         )
         .await
     }
+
+    /// Samples of different replicas are not comparable, so they are not resolved
+    pub async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        local_only: bool,
+    ) -> CollectionResult<Vec<ExtendedPointId>> {
+        let filter = filter.map(|filter| Arc::new(filter.clone()));
+
+        self.execute_read_operation(
+            |shard| {
+                let filter = filter.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                async move {
+                    shard
+                        .sample_points(limit, filter.as_deref(), &search_runtime)
+                        .await
+                }
+                .boxed()
+            },
+            local_only,
+        )
+        .await
+    }
+
     pub async fn core_search(
         &self,
         request: Arc<CoreSearchRequestBatch>,
@@ -87,6 +119,44 @@ impl ShardReplicaSet {
         .await
     }
 
+    pub async fn facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<FacetResponse> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                async move { shard.facet(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let request = request.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                async move { shard.text_search(request, &search_runtime).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
     pub async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
@@ -120,6 +190,21 @@ impl ShardReplicaSet {
         .await
     }
 
+    pub async fn sparse_vector_statistics(
+        &self,
+        request: Arc<SparseVectorStatisticsRequest>,
+        local_only: bool,
+    ) -> CollectionResult<SparseVectorStatisticsResult> {
+        self.execute_read_operation(
+            |shard| {
+                let request = request.clone();
+                async move { shard.sparse_vector_statistics(request).await }.boxed()
+            },
+            local_only,
+        )
+        .await
+    }
+
     pub async fn count_local(
         &self,
         request: Arc<CountRequestInternal>,
@@ -1,10 +1,11 @@
 use std::collections::{HashMap, HashSet};
 use std::hash;
 
+use segment::data_types::facets::FacetValueHit;
 use segment::types::{Payload, ScoredPoint};
 use tinyvec::TinyVec;
 
-use crate::operations::types::{CountResult, Record};
+use crate::operations::types::{CountResult, FacetResponse, Record};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ResolveCondition {
@@ -41,6 +42,41 @@ impl Resolve for CountResult {
     }
 }
 
+impl Resolve for FacetResponse {
+    /// Resolve the count of each value separately, a value missing in a response has no points
+    fn resolve(responses: Vec<Self>, condition: ResolveCondition) -> Self {
+        let counts: Vec<HashMap<_, _>> = responses
+            .into_iter()
+            .map(|response| {
+                response
+                    .hits
+                    .into_iter()
+                    .map(|hit| (hit.value, hit.count))
+                    .collect()
+            })
+            .collect();
+
+        let values: HashSet<_> = counts.iter().flat_map(HashMap::keys).cloned().collect();
+
+        let mut hits: Vec<_> = values
+            .into_iter()
+            .filter_map(|value| {
+                let replica_counts = counts
+                    .iter()
+                    .map(|counts| CountResult {
+                        count: counts.get(&value).copied().unwrap_or_default(),
+                    })
+                    .collect();
+                let count = CountResult::resolve(replica_counts, condition).count;
+                (count > 0).then_some(FacetValueHit { value, count })
+            })
+            .collect();
+
+        hits.sort_unstable();
+        Self { hits }
+    }
+}
+
 impl Resolve for Vec<Record> {
     fn resolve(records: Vec<Self>, condition: ResolveCondition) -> Self {
         let mut resolved = Resolver::resolve(records, |record| record.id, record_eq, condition);
@@ -49,6 +85,15 @@ impl Resolve for Vec<Record> {
     }
 }
 
+impl Resolve for Vec<ScoredPoint> {
+    fn resolve(points: Vec<Self>, condition: ResolveCondition) -> Self {
+        let mut resolved = Resolver::resolve(points, |point| point.id, scored_point_eq, condition);
+        // Best points first
+        resolved.sort_unstable_by(|a, b| b.cmp(a));
+        resolved
+    }
+}
+
 impl Resolve for Vec<Vec<ScoredPoint>> {
     fn resolve(batches: Vec<Self>, condition: ResolveCondition) -> Self {
         // batches: <replica_id, <batch_id, ScoredPoint>>
@@ -247,6 +292,7 @@ mod test {
     use std::fmt;
 
     use common::types::ScoreType;
+    use segment::data_types::facets::FacetValue;
 
     use super::*;
 
@@ -458,6 +504,45 @@ mod test {
         [3, 6, 9, 12, 13, 16, 19, 22, 26, 27]
     }
 
+    fn facet_response(hits: &[(&str, usize)]) -> FacetResponse {
+        FacetResponse {
+            hits: hits
+                .iter()
+                .map(|(value, count)| FacetValueHit {
+                    value: FacetValue::Keyword(value.to_string()),
+                    count: *count,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn resolve_facet_response() {
+        let responses = vec![
+            facet_response(&[("a", 3), ("b", 2)]),
+            facet_response(&[("a", 4), ("c", 1)]),
+            facet_response(&[("b", 2), ("a", 2)]),
+        ];
+
+        let all = FacetResponse::resolve(responses.clone(), ResolveCondition::All);
+        assert_eq!(all, facet_response(&[("a", 2)]));
+
+        let majority = FacetResponse::resolve(responses, ResolveCondition::Majority);
+        assert_eq!(majority, facet_response(&[("a", 3), ("b", 2)]));
+    }
+
+    #[test]
+    fn resolve_scored_points_best_first() {
+        let replicas = vec![
+            vec![point(1, 0.5), point(2, 0.9), point(3, 0.1)],
+            vec![point(2, 0.9), point(1, 0.5)],
+        ];
+
+        let resolved = Vec::<ScoredPoint>::resolve(replicas, ResolveCondition::All);
+        let ids: Vec<_> = resolved.iter().map(|point| point.id).collect();
+        assert_eq!(ids, vec![2.into(), 1.into()]);
+    }
+
     #[test]
     fn resolve_0_all() {
         resolve_0(ResolveCondition::All);
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use segment::data_types::order_by::OrderBy;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -9,7 +10,8 @@ use tokio::runtime::Handle;
 
 use crate::operations::types::{
     CollectionInfo, CollectionResult, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    PointRequestInternal, Record, UpdateResult,
+    FacetRequestInternal, FacetResponse, PointRequestInternal, Record,
+    SparseVectorStatisticsRequest, SparseVectorStatisticsResult, TextSearchRequest, UpdateResult,
 };
 use crate::operations::CollectionUpdateOperations;
 
@@ -29,9 +31,18 @@ pub trait ShardOperation {
         with_payload_interface: &WithPayloadInterface,
         with_vector: &WithVector,
         filter: Option<&Filter>,
+        order_by: Option<&OrderBy>,
         search_runtime_handle: &Handle,
     ) -> CollectionResult<Vec<Record>>;
 
+    /// Select a random sample of at most `limit` points which satisfy the filter
+    async fn sample_points(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ExtendedPointId>>;
+
     async fn info(&self) -> CollectionResult<CollectionInfo>;
 
     async fn core_search(
@@ -43,6 +54,26 @@ pub trait ShardOperation {
 
     async fn count(&self, request: Arc<CountRequestInternal>) -> CollectionResult<CountResult>;
 
+    async fn facet(
+        &self,
+        request: Arc<FacetRequestInternal>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<FacetResponse>;
+
+    /// Rank points which satisfy the filter by the BM25 score of the text in a full-text indexed
+    /// payload field
+    async fn text_search(
+        &self,
+        request: Arc<TextSearchRequest>,
+        search_runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>>;
+
+    /// Collect statistics of sparse vector dimensions, used to apply the IDF modifier
+    async fn sparse_vector_statistics(
+        &self,
+        request: Arc<SparseVectorStatisticsRequest>,
+    ) -> CollectionResult<SparseVectorStatisticsResult>;
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
@@ -0,0 +1,895 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::time::Duration;
+
+use futures::future::try_join_all;
+use itertools::Itertools;
+use segment::data_types::vectors::{NamedQuery, NamedVectorStruct, Vector, DEFAULT_VECTOR_NAME};
+use segment::types::{
+    Condition, Filter, HasIdCondition, Order, PointIdType, ScoredPoint, SearchParams,
+    WithPayloadInterface, WithVector,
+};
+use segment::vector_storage::query::context_query::{ContextPair, ContextQuery};
+use segment::vector_storage::query::discovery_query::DiscoveryQuery;
+use segment::vector_storage::query::reco_query::RecoQuery;
+use tokio::sync::RwLockReadGuard;
+
+use crate::collection::Collection;
+use crate::common::batching::batch_requests;
+use crate::common::fetch_vectors::{resolve_referenced_vectors_batch, ReferencedVectors};
+use crate::common::retrieve_request_trait::RetrieveRequest;
+use crate::config::CollectionParams;
+use crate::hybrid::{fuse_results, DEFAULT_PREFETCH_WEIGHT};
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_key_selector::ShardKeySelector;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    default_query_limit, CollectionError, CollectionResult, ContextExamplePair, CoreSearchRequest,
+    CoreSearchRequestBatch, DiscoverInput, LookupLocation, Prefetch, Query, QueryEnum,
+    QueryInterface, QueryRequestInternal, RecommendExample, RecommendInput, RecommendStrategy,
+    ScrollRequestInternal, TextQueryInput, TextSearchRequest, UsingVector,
+};
+use crate::recommendations::{avg_vectors, merge_positive_and_negative_avg};
+
+const EMPTY_SHARD_KEY_SELECTOR: Option<ShardKeySelector> = None;
+
+/// Point IDs referenced by the query of a single stage, their vectors are looked up
+struct StageReferences {
+    ids: Vec<PointIdType>,
+    vector_name: String,
+    lookup_from: Option<LookupLocation>,
+}
+
+impl RetrieveRequest for StageReferences {
+    fn get_lookup_collection(&self) -> Option<&String> {
+        self.lookup_from.as_ref().map(|x| &x.collection)
+    }
+
+    fn get_referenced_point_ids(&self) -> Vec<PointIdType> {
+        self.ids.clone()
+    }
+
+    fn get_search_vector_name(&self) -> String {
+        self.vector_name.clone()
+    }
+
+    fn get_lookup_shard_key(&self) -> &Option<ShardKeySelector> {
+        self.lookup_from
+            .as_ref()
+            .map(|x| &x.shard_key)
+            .unwrap_or(&EMPTY_SHARD_KEY_SELECTOR)
+    }
+}
+
+fn vector_name(using: &Option<UsingVector>) -> String {
+    match using {
+        None => DEFAULT_VECTOR_NAME.to_string(),
+        Some(UsingVector::Name(name)) => name.clone(),
+    }
+}
+
+/// Name of the vector to take from the referenced points
+fn lookup_vector_name(using: &Option<UsingVector>, lookup_from: Option<&LookupLocation>) -> String {
+    match lookup_from {
+        None => vector_name(using),
+        Some(lookup_from) => lookup_from
+            .vector
+            .clone()
+            .unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_string()),
+    }
+}
+
+fn referenced_point_ids(query: &QueryInterface) -> Vec<PointIdType> {
+    let examples: Vec<&RecommendExample> = match query {
+        QueryInterface::Nearest(example) | QueryInterface::Query(Query::Nearest(example)) => {
+            vec![example]
+        }
+        QueryInterface::Query(Query::Recommend(recommend)) => recommend
+            .positive
+            .iter()
+            .chain(&recommend.negative)
+            .collect(),
+        QueryInterface::Query(Query::Discover(discover)) => std::iter::once(&discover.target)
+            .chain(discover.context.iter().flat_map(ContextExamplePair::iter))
+            .collect(),
+        QueryInterface::Query(Query::Context(pairs)) => {
+            pairs.iter().flat_map(ContextExamplePair::iter).collect()
+        }
+        QueryInterface::Query(Query::Fusion(_) | Query::Text(_)) => vec![],
+    };
+
+    examples
+        .into_iter()
+        .filter_map(RecommendExample::as_point_id)
+        .collect()
+}
+
+fn collect_stage_references(
+    prefetches: &[Prefetch],
+    query: Option<&QueryInterface>,
+    using: &Option<UsingVector>,
+    lookup_from: Option<&LookupLocation>,
+    references: &mut Vec<StageReferences>,
+) {
+    if let Some(query) = query {
+        let ids = referenced_point_ids(query);
+        if !ids.is_empty() {
+            references.push(StageReferences {
+                ids,
+                vector_name: lookup_vector_name(using, lookup_from),
+                lookup_from: lookup_from.cloned(),
+            });
+        }
+    }
+
+    for prefetch in prefetches {
+        collect_stage_references(
+            prefetch.prefetch.as_deref().unwrap_or_default(),
+            prefetch.query.as_ref(),
+            &prefetch.using,
+            lookup_from,
+            references,
+        );
+    }
+}
+
+/// Fetch the vectors of all points referenced in the queries of the batch
+async fn resolve_referenced_vectors<'a, F, Fut>(
+    request_batch: &[(QueryRequestInternal, ShardSelectorInternal)],
+    collection: &Collection,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+) -> CollectionResult<ReferencedVectors>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    let mut references = Vec::new();
+    for (request, shard_selector) in request_batch {
+        let mut request_references = Vec::new();
+        collect_stage_references(
+            request.prefetch.as_deref().unwrap_or_default(),
+            request.query.as_ref(),
+            &request.using,
+            request.lookup_from.as_ref(),
+            &mut request_references,
+        );
+        references.extend(
+            request_references
+                .into_iter()
+                .map(|stage_references| (stage_references, shard_selector.clone())),
+        );
+    }
+
+    if references.is_empty() {
+        return Ok(ReferencedVectors::default());
+    }
+
+    resolve_referenced_vectors_batch(
+        &references,
+        collection,
+        collection_by_name,
+        read_consistency,
+    )
+    .await
+}
+
+/// What a stage does with the candidates of its prefetches
+enum StageQuery {
+    /// Search the candidates, or the whole collection if there are no prefetches
+    Search(QueryEnum),
+    /// Fuse the results of the prefetches
+    Fusion(crate::operations::types::Fusion),
+    /// Rank the candidates, or the whole collection if there are no prefetches, by text
+    Text(TextQueryInput),
+}
+
+/// Stage of the query with point IDs resolved into vectors and filters propagated
+struct PlannedStage {
+    /// Indices of the prefetch stages in the plan, they always precede this stage
+    prefetches: Vec<usize>,
+    query: Option<StageQuery>,
+    filter: Option<Filter>,
+    params: Option<SearchParams>,
+    score_threshold: Option<f32>,
+    limit: usize,
+    offset: usize,
+    /// Points referenced by the query are not returned as its results
+    referenced_ids: Vec<PointIdType>,
+    /// Order of the scores produced by this stage
+    order: Order,
+    /// Length of the longest chain of prefetches below this stage
+    depth: usize,
+}
+
+impl PlannedStage {
+    fn passes_threshold(&self, score: f32) -> bool {
+        match (self.score_threshold, self.order) {
+            (None, _) => true,
+            (Some(threshold), Order::LargeBetter) => score >= threshold,
+            (Some(threshold), Order::SmallBetter) => score <= threshold,
+        }
+    }
+
+    /// Apply threshold, offset and limit to the candidates, which are already ranked
+    fn cut(&self, points: impl IntoIterator<Item = ScoredPoint>) -> Vec<ScoredPoint> {
+        points
+            .into_iter()
+            .filter(|point| self.passes_threshold(point.score))
+            .skip(self.offset)
+            .take(self.limit)
+            .collect()
+    }
+
+    /// Points found by the prefetches, `None` if the stage has no prefetches
+    fn candidates(&self, prefetch_results: &[Vec<ScoredPoint>]) -> Option<HashSet<PointIdType>> {
+        (!self.prefetches.is_empty()).then(|| {
+            prefetch_results
+                .iter()
+                .flatten()
+                .map(|point| point.id)
+                .collect()
+        })
+    }
+
+    fn search_filter(&self, candidates: Option<HashSet<PointIdType>>) -> Option<Filter> {
+        let restriction = Filter {
+            should: None,
+            min_should: None,
+            must: candidates.map(|ids| vec![Condition::HasId(HasIdCondition::from(ids))]),
+            must_not: (!self.referenced_ids.is_empty()).then(|| {
+                vec![Condition::HasId(HasIdCondition::from(
+                    self.referenced_ids.iter().copied().collect::<HashSet<_>>(),
+                ))]
+            }),
+        };
+
+        match (
+            &self.filter,
+            restriction.must.is_none() && restriction.must_not.is_none(),
+        ) {
+            (None, true) => None,
+            (None, false) => Some(restriction),
+            (Some(filter), true) => Some(filter.clone()),
+            (Some(filter), false) => Some(restriction.merge(filter)),
+        }
+    }
+
+    fn core_search(
+        &self,
+        query: &QueryEnum,
+        candidates: Option<HashSet<PointIdType>>,
+    ) -> CoreSearchRequest {
+        CoreSearchRequest {
+            query: query.clone(),
+            filter: self.search_filter(candidates),
+            params: self.params,
+            limit: self.limit,
+            offset: self.offset,
+            // Payload and vectors are only fetched for the final results
+            with_payload: None,
+            with_vector: None,
+            score_threshold: self.score_threshold,
+        }
+    }
+
+    fn text_search(
+        &self,
+        input: &TextQueryInput,
+        candidates: Option<HashSet<PointIdType>>,
+    ) -> TextSearchRequest {
+        TextSearchRequest {
+            key: input.key.clone(),
+            text: input.text.clone(),
+            filter: self.search_filter(candidates),
+            // Threshold and offset are applied to the merged results of all shards
+            limit: self.offset + self.limit,
+        }
+    }
+}
+
+/// Stages of a single query request, the last one is the root
+struct QueryPlan {
+    stages: Vec<PlannedStage>,
+    shard_selector: ShardSelectorInternal,
+    with_payload: Option<WithPayloadInterface>,
+    with_vector: Option<WithVector>,
+}
+
+struct Planner<'a> {
+    stages: Vec<PlannedStage>,
+    referenced_vectors: &'a ReferencedVectors,
+    lookup_from: Option<&'a LookupLocation>,
+    collection_params: &'a CollectionParams,
+}
+
+impl<'a> Planner<'a> {
+    fn example_vector(
+        &self,
+        example: RecommendExample,
+        lookup_vector_name: &str,
+    ) -> CollectionResult<Vector> {
+        match example {
+            RecommendExample::Dense(vector) => Ok(vector.into()),
+            RecommendExample::Sparse(vector) => Ok(vector.into()),
+            RecommendExample::MultiDense(vector) => Ok(vector.into()),
+            RecommendExample::PointId(point_id) => {
+                let lookup_collection = self.lookup_from.map(|x| &x.collection);
+                let record = self
+                    .referenced_vectors
+                    .get(&lookup_collection, point_id)
+                    .ok_or(CollectionError::PointNotFound {
+                        missed_point_id: point_id,
+                    })?;
+                record
+                    .get_vector_by_name(lookup_vector_name)
+                    .map(|vector| vector.to_owned())
+                    .ok_or_else(|| {
+                        CollectionError::bad_input(format!(
+                            "Point {point_id} has no vector `{lookup_vector_name}`"
+                        ))
+                    })
+            }
+        }
+    }
+
+    fn example_vectors(
+        &self,
+        examples: Vec<RecommendExample>,
+        lookup_vector_name: &str,
+    ) -> CollectionResult<Vec<Vector>> {
+        examples
+            .into_iter()
+            .map(|example| self.example_vector(example, lookup_vector_name))
+            .collect()
+    }
+
+    fn context_pairs(
+        &self,
+        pairs: Vec<ContextExamplePair>,
+        lookup_vector_name: &str,
+    ) -> CollectionResult<Vec<ContextPair<Vector>>> {
+        pairs
+            .into_iter()
+            .map(|pair| {
+                Ok(ContextPair {
+                    positive: self.example_vector(pair.positive, lookup_vector_name)?,
+                    negative: self.example_vector(pair.negative, lookup_vector_name)?,
+                })
+            })
+            .collect()
+    }
+
+    fn plan_query(
+        &self,
+        query: Query,
+        using: Option<UsingVector>,
+        has_prefetches: bool,
+    ) -> CollectionResult<StageQuery> {
+        let lookup_vector_name = lookup_vector_name(&using, self.lookup_from);
+        let vector_name = vector_name(&using);
+        let using = using.map(|UsingVector::Name(name)| name);
+
+        let query = match query {
+            Query::Nearest(example) => {
+                let vector = self.example_vector(example, &lookup_vector_name)?;
+                QueryEnum::Nearest(NamedVectorStruct::new_from_vector(vector, vector_name))
+            }
+            Query::Recommend(RecommendInput {
+                positive,
+                negative,
+                strategy,
+            }) => match strategy.unwrap_or_default() {
+                RecommendStrategy::AverageVector => {
+                    if positive.is_empty() {
+                        return Err(CollectionError::bad_request(
+                            "At least one positive example required with `average_vector` strategy"
+                                .to_string(),
+                        ));
+                    }
+                    let positive = self.example_vectors(positive, &lookup_vector_name)?;
+                    let negative = self.example_vectors(negative, &lookup_vector_name)?;
+                    let avg_positive = avg_vectors(positive.iter().map(Vector::to_vec_ref))?;
+                    let vector = if negative.is_empty() {
+                        avg_positive
+                    } else {
+                        let avg_negative = avg_vectors(negative.iter().map(Vector::to_vec_ref))?;
+                        merge_positive_and_negative_avg(avg_positive, avg_negative)?
+                    };
+                    QueryEnum::Nearest(NamedVectorStruct::new_from_vector(vector, vector_name))
+                }
+                RecommendStrategy::BestScore => {
+                    if positive.is_empty() && negative.is_empty() {
+                        return Err(CollectionError::bad_request(
+                            "At least one positive or negative example required with `best_score` strategy"
+                                .to_string(),
+                        ));
+                    }
+                    QueryEnum::RecommendBestScore(NamedQuery {
+                        query: RecoQuery::new(
+                            self.example_vectors(positive, &lookup_vector_name)?,
+                            self.example_vectors(negative, &lookup_vector_name)?,
+                        ),
+                        using,
+                    })
+                }
+            },
+            Query::Discover(DiscoverInput { target, context }) => QueryEnum::Discover(NamedQuery {
+                query: DiscoveryQuery::new(
+                    self.example_vector(target, &lookup_vector_name)?,
+                    self.context_pairs(context, &lookup_vector_name)?,
+                ),
+                using,
+            }),
+            Query::Context(pairs) => {
+                if pairs.is_empty() {
+                    return Err(CollectionError::bad_request(
+                        "At least one context pair required".to_string(),
+                    ));
+                }
+                QueryEnum::Context(NamedQuery {
+                    query: ContextQuery::new(self.context_pairs(pairs, &lookup_vector_name)?),
+                    using,
+                })
+            }
+            Query::Fusion(fusion) => {
+                if !has_prefetches {
+                    return Err(CollectionError::bad_request(
+                        "Fusion requires at least one prefetch".to_string(),
+                    ));
+                }
+                return Ok(StageQuery::Fusion(fusion));
+            }
+            Query::Text(input) => return Ok(StageQuery::Text(input)),
+        };
+
+        Ok(StageQuery::Search(query))
+    }
+
+    /// Plan the stage after all of its prefetches, returns the index of the stage
+    fn plan_stage(
+        &mut self,
+        stage: Prefetch,
+        parent_filter: Option<&Filter>,
+        offset: usize,
+    ) -> CollectionResult<usize> {
+        let Prefetch {
+            prefetch,
+            query,
+            using,
+            filter,
+            params,
+            score_threshold,
+            limit,
+        } = stage;
+
+        let filter = match (parent_filter, filter) {
+            (None, filter) => filter,
+            (Some(parent_filter), None) => Some(parent_filter.clone()),
+            (Some(parent_filter), Some(filter)) => Some(parent_filter.merge(&filter)),
+        };
+
+        let prefetches = prefetch
+            .unwrap_or_default()
+            .into_iter()
+            .map(|prefetch| self.plan_stage(prefetch, filter.as_ref(), 0))
+            .collect::<CollectionResult<Vec<_>>>()?;
+
+        let referenced_ids = query.as_ref().map(referenced_point_ids).unwrap_or_default();
+        let query = query
+            .map(|query| self.plan_query(query.into(), using, !prefetches.is_empty()))
+            .transpose()?;
+
+        let order = match &query {
+            Some(StageQuery::Search(query)) => self
+                .collection_params
+                .get_distance(query.get_vector_name())?
+                .distance_order(),
+            Some(StageQuery::Fusion(_) | StageQuery::Text(_)) => Order::LargeBetter,
+            None => prefetches
+                .first()
+                .map_or(Order::LargeBetter, |&prefetch| self.stages[prefetch].order),
+        };
+
+        let depth = prefetches
+            .iter()
+            .map(|&prefetch| self.stages[prefetch].depth + 1)
+            .max()
+            .unwrap_or_default();
+
+        self.stages.push(PlannedStage {
+            prefetches,
+            query,
+            filter,
+            params,
+            score_threshold,
+            limit: limit.unwrap_or_else(default_query_limit),
+            offset,
+            referenced_ids,
+            order,
+            depth,
+        });
+        Ok(self.stages.len() - 1)
+    }
+}
+
+fn plan_query_request(
+    request: QueryRequestInternal,
+    shard_selector: ShardSelectorInternal,
+    referenced_vectors: &ReferencedVectors,
+    collection_params: &CollectionParams,
+) -> CollectionResult<QueryPlan> {
+    let QueryRequestInternal {
+        prefetch,
+        query,
+        using,
+        filter,
+        params,
+        score_threshold,
+        limit,
+        offset,
+        with_payload,
+        with_vector,
+        lookup_from,
+    } = request;
+
+    let mut planner = Planner {
+        stages: Vec::new(),
+        referenced_vectors,
+        lookup_from: lookup_from.as_ref(),
+        collection_params,
+    };
+
+    let root = Prefetch {
+        prefetch,
+        query,
+        using,
+        filter,
+        params,
+        score_threshold,
+        limit,
+    };
+    planner.plan_stage(root, None, offset.unwrap_or_default())?;
+
+    Ok(QueryPlan {
+        stages: planner.stages,
+        shard_selector,
+        with_payload,
+        with_vector,
+    })
+}
+
+pub async fn query<'a, F, Fut>(
+    request: QueryRequestInternal,
+    collection: &Collection,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> CollectionResult<Vec<ScoredPoint>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    // `query` is a special case of query_batch with a single batch
+    let request_batch = vec![(request, shard_selector)];
+    let results = query_batch(
+        request_batch,
+        collection,
+        collection_by_name,
+        read_consistency,
+        timeout,
+    )
+    .await?;
+    Ok(results.into_iter().next().unwrap())
+}
+
+/// Run a batch of universal queries.
+///
+/// Function works in following stages:
+///
+/// - Fetches the vectors of all points referenced in the queries
+/// - Plans every request as a list of stages, prefetches first
+/// - Evaluates the stages of all requests level by level, starting from the deepest prefetches.
+///   All searches of a level are executed as a single core search batch per shard selector,
+///   text searches are executed concurrently, while fusion is computed locally
+/// - Fills payload and vectors of the final results
+///
+/// # Arguments
+///
+/// * `request_batch` - batch of query requests
+/// * `collection` - collection to search in
+/// * `collection_by_name` - function to retrieve collection by name, used to retrieve points from other collections
+/// * `timeout` - timeout of every search level
+///
+pub async fn query_batch<'a, F, Fut>(
+    request_batch: Vec<(QueryRequestInternal, ShardSelectorInternal)>,
+    collection: &Collection,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+) -> CollectionResult<Vec<Vec<ScoredPoint>>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    let referenced_vectors = resolve_referenced_vectors(
+        &request_batch,
+        collection,
+        collection_by_name,
+        read_consistency,
+    )
+    .await?;
+
+    let plans = {
+        let collection_params = &collection.collection_config.read().await.params;
+        request_batch
+            .into_iter()
+            .map(|(request, shard_selector)| {
+                plan_query_request(
+                    request,
+                    shard_selector,
+                    &referenced_vectors,
+                    collection_params,
+                )
+            })
+            .collect::<CollectionResult<Vec<_>>>()?
+    };
+
+    let mut results: Vec<Vec<Option<Vec<ScoredPoint>>>> = plans
+        .iter()
+        .map(|plan| plan.stages.iter().map(|_| None).collect())
+        .collect();
+
+    let max_depth = plans
+        .iter()
+        .flat_map(|plan| &plan.stages)
+        .map(|stage| stage.depth)
+        .max()
+        .unwrap_or_default();
+
+    for depth in 0..=max_depth {
+        let mut searches = Vec::new();
+        let mut text_searches = Vec::new();
+        let mut scrolls = Vec::new();
+
+        for (plan_idx, plan) in plans.iter().enumerate() {
+            for (stage_idx, stage) in plan.stages.iter().enumerate() {
+                if stage.depth != depth {
+                    continue;
+                }
+
+                let prefetch_results = stage
+                    .prefetches
+                    .iter()
+                    .map(|&prefetch| {
+                        results[plan_idx][prefetch]
+                            .take()
+                            .expect("prefetches are evaluated before the stage")
+                    })
+                    .collect_vec();
+
+                let stage_result = match &stage.query {
+                    Some(StageQuery::Search(query)) => {
+                        let candidates = stage.candidates(&prefetch_results);
+                        if candidates.as_ref().is_some_and(HashSet::is_empty) {
+                            Some(Vec::new())
+                        } else {
+                            searches.push((
+                                (plan_idx, stage_idx),
+                                stage.core_search(query, candidates),
+                                plan.shard_selector.clone(),
+                            ));
+                            None
+                        }
+                    }
+                    Some(StageQuery::Text(input)) => {
+                        let candidates = stage.candidates(&prefetch_results);
+                        if candidates.as_ref().is_some_and(HashSet::is_empty) {
+                            Some(Vec::new())
+                        } else {
+                            text_searches.push((
+                                (plan_idx, stage_idx),
+                                collection.text_search(
+                                    stage.text_search(input, candidates),
+                                    read_consistency,
+                                    &plan.shard_selector,
+                                ),
+                            ));
+                            None
+                        }
+                    }
+                    Some(StageQuery::Fusion(fusion)) => {
+                        let weights = vec![DEFAULT_PREFETCH_WEIGHT; prefetch_results.len()];
+                        let orders = stage
+                            .prefetches
+                            .iter()
+                            .map(|&prefetch| plan.stages[prefetch].order)
+                            .collect_vec();
+                        let fused = fuse_results(prefetch_results, *fusion, &weights, &orders);
+                        Some(stage.cut(fused))
+                    }
+                    None if stage.prefetches.is_empty() => {
+                        // Neither query nor prefetch: take the points which satisfy the filter
+                        let scroll = ScrollRequestInternal {
+                            offset: None,
+                            limit: Some(stage.offset + stage.limit),
+                            filter: stage.filter.clone(),
+                            with_payload: Some(WithPayloadInterface::Bool(false)),
+                            with_vector: WithVector::Bool(false),
+                            order_by: None,
+                        };
+                        scrolls.push((
+                            (plan_idx, stage_idx),
+                            collection.scroll_by(scroll, read_consistency, &plan.shard_selector),
+                        ));
+                        None
+                    }
+                    None => Some(
+                        stage.cut(
+                            prefetch_results
+                                .into_iter()
+                                .flatten()
+                                .unique_by(|point| point.id),
+                        ),
+                    ),
+                };
+
+                results[plan_idx][stage_idx] = stage_result;
+            }
+        }
+
+        let mut search_positions = Vec::new();
+        let search_futures = if searches.is_empty() {
+            Vec::new()
+        } else {
+            batch_requests::<_, ShardSelectorInternal, Vec<_>, Vec<_>>(
+                searches,
+                |(_, _, shard_selector)| shard_selector,
+                |(position, search, _), acc| {
+                    acc.push((position, search));
+                    Ok(())
+                },
+                |shard_selector, acc, futures| {
+                    let (positions, searches): (Vec<_>, Vec<_>) = acc.into_iter().unzip();
+                    search_positions.extend(positions);
+                    futures.push(collection.core_search_batch(
+                        CoreSearchRequestBatch { searches },
+                        read_consistency,
+                        shard_selector,
+                        timeout,
+                    ));
+                    Ok(())
+                },
+            )?
+        };
+
+        let (text_search_positions, text_search_futures): (Vec<_>, Vec<_>) =
+            text_searches.into_iter().unzip();
+        let (scroll_positions, scroll_futures): (Vec<_>, Vec<_>) = scrolls.into_iter().unzip();
+
+        let (search_results, text_search_results, scroll_results) = futures::try_join!(
+            try_join_all(search_futures),
+            try_join_all(text_search_futures),
+            try_join_all(scroll_futures),
+        )?;
+
+        for ((plan_idx, stage_idx), points) in search_positions
+            .into_iter()
+            .zip(search_results.into_iter().flatten())
+        {
+            results[plan_idx][stage_idx] = Some(points);
+        }
+
+        for ((plan_idx, stage_idx), points) in
+            text_search_positions.into_iter().zip(text_search_results)
+        {
+            let stage = &plans[plan_idx].stages[stage_idx];
+            results[plan_idx][stage_idx] = Some(stage.cut(points));
+        }
+
+        for ((plan_idx, stage_idx), scroll_result) in
+            scroll_positions.into_iter().zip(scroll_results)
+        {
+            let stage = &plans[plan_idx].stages[stage_idx];
+            let points = scroll_result.points.into_iter().map(|record| ScoredPoint {
+                id: record.id,
+                version: 0,
+                score: 0.0,
+                payload: None,
+                vector: None,
+                shard_key: record.shard_key,
+            });
+            results[plan_idx][stage_idx] = Some(stage.cut(points));
+        }
+    }
+
+    let fill_payload = plans.into_iter().zip(results).map(|(plan, mut results)| {
+        let points = results
+            .pop()
+            .flatten()
+            .expect("root stage is evaluated last");
+        async move {
+            collection
+                .fill_search_result_with_payload(
+                    points,
+                    Some(
+                        plan.with_payload
+                            .unwrap_or(WithPayloadInterface::Bool(false)),
+                    ),
+                    plan.with_vector.unwrap_or_default(),
+                    read_consistency,
+                    &plan.shard_selector,
+                )
+                .await
+        }
+    });
+
+    try_join_all(fill_payload).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+        }
+    }
+
+    fn stage(
+        order: Order,
+        score_threshold: Option<f32>,
+        offset: usize,
+        limit: usize,
+    ) -> PlannedStage {
+        PlannedStage {
+            prefetches: vec![],
+            query: None,
+            filter: None,
+            params: None,
+            score_threshold,
+            limit,
+            offset,
+            referenced_ids: vec![],
+            order,
+            depth: 0,
+        }
+    }
+
+    #[test]
+    fn test_cut_respects_distance_order() {
+        let points = || vec![scored(1, 0.9), scored(2, 0.5), scored(3, 0.1)];
+
+        let large_better = stage(Order::LargeBetter, Some(0.5), 0, 10);
+        let ids: Vec<_> = large_better.cut(points()).iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![1.into(), 2.into()]);
+
+        let small_better = stage(Order::SmallBetter, Some(0.5), 0, 10);
+        let ids: Vec<_> = small_better.cut(points()).iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![2.into(), 3.into()]);
+
+        let paginated = stage(Order::LargeBetter, None, 1, 1);
+        let ids: Vec<_> = paginated.cut(points()).iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![2.into()]);
+    }
+
+    #[test]
+    fn test_search_filter() {
+        let mut stage = stage(Order::LargeBetter, None, 0, 10);
+        assert!(stage.search_filter(None).is_none());
+
+        stage.referenced_ids = vec![7.into()];
+        let filter = stage
+            .search_filter(Some(HashSet::from([1.into(), 2.into()])))
+            .unwrap();
+        assert_eq!(filter.must.map(|must| must.len()), Some(1));
+        assert_eq!(filter.must_not.map(|must_not| must_not.len()), Some(1));
+    }
+}
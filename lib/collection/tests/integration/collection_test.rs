@@ -5,17 +5,20 @@ use collection::operations::payload_ops::{PayloadOps, SetPayloadOp};
 use collection::operations::point_ops::{Batch, PointOperations, PointStruct, WriteOrdering};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CountRequestInternal, PointRequestInternal, RecommendRequestInternal, ScrollRequestInternal,
-    SearchRequestInternal, UpdateStatus,
+    CountRequestInternal, OrderByInterface, PointRequestInternal, RecommendRequestInternal,
+    ScrollRequestInternal, SearchRequestInternal, UpdateStatus,
 };
-use collection::operations::CollectionUpdateOperations;
+use collection::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use collection::recommendations::recommend_by;
 use collection::shards::replica_set::{ReplicaSetState, ReplicaState};
 use itertools::Itertools;
+use segment::data_types::order_by::{Direction, OrderBy, OrderValue};
 use segment::data_types::vectors::VectorStruct;
 use segment::types::{
-    Condition, FieldCondition, Filter, HasIdCondition, Payload, PointIdType, WithPayloadInterface,
+    Condition, FieldCondition, Filter, HasIdCondition, Payload, PayloadFieldSchema,
+    PayloadSchemaType, PointIdType, WithPayloadInterface,
 };
+use serde_json::json;
 use tempfile::Builder;
 
 use crate::common::{load_local_collection, simple_collection_fixture, N_SHARDS};
@@ -168,6 +171,8 @@ async fn test_collection_search_with_payload_and_vector_with_shards(shard_number
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
         }))),
         exact: true,
     };
@@ -410,6 +415,7 @@ async fn test_read_api_with_shards(shard_number: u32) {
                 filter: None,
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
+                order_by: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -421,6 +427,102 @@ async fn test_read_api_with_shards(shard_number: u32) {
     assert_eq!(result.points.len(), 2);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ordered_read_api() {
+    test_ordered_read_api_with_shards(1).await;
+    test_ordered_read_api_with_shards(N_SHARDS).await;
+}
+
+async fn test_ordered_read_api_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+
+    let create_index = CollectionUpdateOperations::FieldIndexOperation(
+        FieldIndexOperations::CreateIndex(CreateIndex {
+            field_name: "price".to_string(),
+            field_schema: Some(PayloadFieldSchema::FieldType(PayloadSchemaType::Float)),
+        }),
+    );
+    collection
+        .update_from_client_simple(create_index, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    let prices = [5.0, 1.5, 3.0, 3.0, 8.0, 1.5, 3.0, 0.5, 9.0, 3.0];
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: (0..prices.len() as u64).map(|x| x.into()).collect_vec(),
+            vectors: vec![vec![1.0, 0.0, 1.0, 1.0]; prices.len()].into(),
+            payloads: Some(
+                prices
+                    .iter()
+                    .map(|price| Some(serde_json::from_value(json!({ "price": price })).unwrap()))
+                    .collect(),
+            ),
+        }
+        .into(),
+    );
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    for direction in [Direction::Asc, Direction::Desc] {
+        let mut expected = prices
+            .iter()
+            .enumerate()
+            .map(|(id, price)| (OrderValue::Float(*price), PointIdType::from(id as u64)))
+            .collect_vec();
+        expected.sort_by(|(value_a, id_a), (value_b, id_b)| {
+            value_a
+                .cmp_directed(value_b, direction)
+                .then_with(|| id_a.cmp(id_b))
+        });
+
+        let mut start_from = None;
+        let mut offset = None;
+        let mut scrolled = Vec::new();
+        loop {
+            let result = collection
+                .scroll_by(
+                    ScrollRequestInternal {
+                        offset,
+                        limit: Some(3),
+                        filter: None,
+                        with_payload: Some(WithPayloadInterface::Bool(false)),
+                        with_vector: false.into(),
+                        order_by: Some(OrderByInterface::Struct(OrderBy {
+                            key: "price".to_string(),
+                            direction: Some(direction),
+                            start_from,
+                        })),
+                    },
+                    None,
+                    &ShardSelectorInternal::All,
+                )
+                .await
+                .unwrap();
+
+            scrolled.extend(
+                result
+                    .points
+                    .into_iter()
+                    .map(|point| (point.order_value.unwrap(), point.id)),
+            );
+
+            if result.next_page_offset.is_none() {
+                assert!(result.next_page_start_from.is_none());
+                break;
+            }
+            offset = result.next_page_offset;
+            start_from = result.next_page_start_from;
+        }
+
+        assert_eq!(scrolled, expected, "direction: {direction:?}");
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_collection_delete_points_by_filter() {
     test_collection_delete_points_by_filter_with_shards(1).await;
@@ -466,6 +568,7 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
     let to_be_deleted: HashSet<PointIdType> = vec![0.into(), 3.into()].into_iter().collect();
     let delete_filter = segment::types::Filter {
         should: None,
+        min_should: None,
         must: Some(vec![Condition::HasId(HasIdCondition::from(to_be_deleted))]),
         must_not: None,
     };
@@ -493,6 +596,7 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
                 filter: None,
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: false.into(),
+                order_by: None,
             },
             None,
             &ShardSelectorInternal::All,
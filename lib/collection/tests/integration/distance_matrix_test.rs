@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet};
+
+use collection::collection::Collection;
+use collection::distance_matrix::{search_points_matrix, CollectionSearchMatrixResponse};
+use collection::operations::point_ops::{Batch, WriteOrdering};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{
+    CollectionError, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
+    SearchMatrixRequestInternal,
+};
+use collection::operations::CollectionUpdateOperations;
+use itertools::Itertools;
+use segment::types::{
+    Condition, ExtendedPointId, FieldCondition, Filter, IntPayloadType, Match, PointIdType,
+};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::common::{simple_collection_fixture, N_SHARDS};
+
+const NUM_POINTS: u64 = 100;
+
+fn point_vector(id: u64) -> Vec<f32> {
+    vec![id as f32 / NUM_POINTS as f32, 1.0, 0.5, 0.0]
+}
+
+async fn matrix_collection_fixture(collection: &Collection) {
+    let payloads = (0..NUM_POINTS)
+        .map(|id| Some(serde_json::from_value(json!({ "parity": id % 2 })).unwrap()))
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: (0..NUM_POINTS).map(|id| id.into()).collect_vec(),
+            vectors: (0..NUM_POINTS).map(point_vector).collect_vec().into(),
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+}
+
+async fn search_matrix(
+    collection: &Collection,
+    sample: usize,
+    limit: usize,
+    filter: Option<Filter>,
+) -> Result<CollectionSearchMatrixResponse, CollectionError> {
+    let request = SearchMatrixRequestInternal {
+        filter,
+        sample,
+        limit,
+        using: None,
+    };
+    search_points_matrix(request, collection, None, ShardSelectorInternal::All, None).await
+}
+
+fn point_num(id: ExtendedPointId) -> u64 {
+    match id {
+        PointIdType::NumId(num) => num,
+        PointIdType::Uuid(_) => panic!("unexpected uuid"),
+    }
+}
+
+fn dot(a: u64, b: u64) -> f32 {
+    point_vector(a)
+        .iter()
+        .zip(point_vector(b))
+        .map(|(x, y)| x * y)
+        .sum()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_distance_matrix() {
+    test_distance_matrix_with_shards(1).await;
+    test_distance_matrix_with_shards(N_SHARDS).await;
+}
+
+async fn test_distance_matrix_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+    matrix_collection_fixture(&collection).await;
+
+    let even_filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "parity",
+        Match::from(0 as IntPayloadType),
+    )));
+
+    let response = search_matrix(&collection, 20, 3, Some(even_filter.clone()))
+        .await
+        .unwrap();
+    assert_eq!(response.sample_ids.len(), 20);
+    assert_eq!(response.nearests.len(), 20);
+
+    let sample: HashSet<_> = response.sample_ids.iter().copied().collect();
+    assert_eq!(sample.len(), 20, "sampled points are unique");
+
+    for (id, nearest) in response.sample_ids.iter().zip(&response.nearests) {
+        assert_eq!(point_num(*id) % 2, 0, "sampled points satisfy the filter");
+        assert_eq!(nearest.len(), 3);
+        for point in nearest {
+            assert_ne!(point.id, *id, "a point is not its own neighbour");
+            assert!(
+                sample.contains(&point.id),
+                "neighbours are within the sample"
+            );
+            let expected = dot(point_num(*id), point_num(point.id));
+            assert!((point.score - expected).abs() < 1e-5);
+        }
+        assert!(nearest.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    // Both formats describe the same matrix
+    let pairs = SearchMatrixPairsResponse::from(CollectionSearchMatrixResponse {
+        sample_ids: response.sample_ids.clone(),
+        nearests: response.nearests.clone(),
+    });
+    let offsets = SearchMatrixOffsetsResponse::from(response);
+    assert_eq!(pairs.pairs.len(), 20 * 3);
+    assert_eq!(offsets.scores.len(), 20 * 3);
+    let from_pairs: HashMap<_, _> = pairs
+        .pairs
+        .iter()
+        .map(|pair| ((pair.a, pair.b), pair.score))
+        .collect();
+    for ((row, col), score) in offsets
+        .offsets_row
+        .iter()
+        .zip(&offsets.offsets_col)
+        .zip(&offsets.scores)
+    {
+        let key = (offsets.ids[*row as usize], offsets.ids[*col as usize]);
+        assert_eq!(from_pairs[&key], *score);
+    }
+
+    // Sample is limited by the number of matching points
+    let response = search_matrix(&collection, 1000, 2, Some(even_filter))
+        .await
+        .unwrap();
+    assert_eq!(response.sample_ids.len(), NUM_POINTS as usize / 2);
+
+    // Nothing to compare with a single point
+    let single_filter = Filter::new_must(Condition::HasId(
+        HashSet::from([ExtendedPointId::from(1)]).into(),
+    ));
+    let response = search_matrix(&collection, 10, 2, Some(single_filter))
+        .await
+        .unwrap();
+    assert!(response.sample_ids.is_empty());
+    assert!(response.nearests.is_empty());
+
+    let request = SearchMatrixRequestInternal {
+        filter: None,
+        sample: 10,
+        limit: 2,
+        using: Some("missing".to_string().into()),
+    };
+    let result =
+        search_points_matrix(request, &collection, None, ShardSelectorInternal::All, None).await;
+    assert!(
+        matches!(result, Err(CollectionError::BadInput { .. })),
+        "{result:?}",
+    );
+}
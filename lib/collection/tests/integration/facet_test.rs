@@ -0,0 +1,140 @@
+use collection::collection::Collection;
+use collection::operations::point_ops::{Batch, WriteOrdering};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{CollectionError, FacetRequestInternal, FacetResponse};
+use collection::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
+use itertools::Itertools;
+use segment::data_types::facets::{FacetValue, FacetValueHit};
+use segment::types::{
+    Condition, FieldCondition, Filter, IntPayloadType, Match, PayloadFieldSchema, PayloadSchemaType,
+};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::common::{simple_collection_fixture, N_SHARDS};
+
+const NUM_POINTS: u64 = 100;
+const BRANDS: [&str; 3] = ["a", "b", "c"];
+
+async fn facet_collection_fixture(collection: &Collection) {
+    for (field_name, schema) in [
+        ("brand", PayloadSchemaType::Keyword),
+        ("color", PayloadSchemaType::Integer),
+    ] {
+        let create_index = CollectionUpdateOperations::FieldIndexOperation(
+            FieldIndexOperations::CreateIndex(CreateIndex {
+                field_name: field_name.to_string(),
+                field_schema: Some(PayloadFieldSchema::FieldType(schema)),
+            }),
+        );
+        collection
+            .update_from_client_simple(create_index, true, WriteOrdering::default())
+            .await
+            .unwrap();
+    }
+
+    // Every tenth point has an additional brand "x"
+    let payloads = (0..NUM_POINTS)
+        .map(|id| {
+            let brand = BRANDS[id as usize % BRANDS.len()];
+            let brand = if id % 10 == 0 {
+                json!([brand, "x"])
+            } else {
+                json!(brand)
+            };
+            let payload = json!({ "brand": brand, "color": id % 4, "unindexed": "value" });
+            Some(serde_json::from_value(payload).unwrap())
+        })
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: (0..NUM_POINTS).map(|id| id.into()).collect_vec(),
+            vectors: vec![vec![1.0, 0.0, 1.0, 1.0]; NUM_POINTS as usize].into(),
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+}
+
+fn keyword_hits(hits: &[(&str, usize)]) -> Vec<FacetValueHit> {
+    hits.iter()
+        .map(|(value, count)| FacetValueHit {
+            value: FacetValue::Keyword(value.to_string()),
+            count: *count,
+        })
+        .collect()
+}
+
+async fn facet(
+    collection: &Collection,
+    key: &str,
+    limit: usize,
+    filter: Option<Filter>,
+    exact: bool,
+) -> Result<FacetResponse, CollectionError> {
+    let request = FacetRequestInternal {
+        key: key.to_string(),
+        limit,
+        filter,
+        exact,
+    };
+    collection
+        .facet(request, None, &ShardSelectorInternal::All)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_facet() {
+    test_facet_with_shards(1).await;
+    test_facet_with_shards(N_SHARDS).await;
+}
+
+async fn test_facet_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+    facet_collection_fixture(&collection).await;
+
+    // With a limit above the number of values, approximate counts are exact as well
+    let expected = keyword_hits(&[("a", 34), ("b", 33), ("c", 33), ("x", 10)]);
+    for exact in [true, false] {
+        let response = facet(&collection, "brand", 10, None, exact).await.unwrap();
+        assert_eq!(response.hits, expected);
+    }
+
+    // Ties are ordered by value
+    let response = facet(&collection, "brand", 2, None, true).await.unwrap();
+    assert_eq!(response.hits, expected[..2]);
+
+    let response = facet(&collection, "color", 10, None, true).await.unwrap();
+    let expected = (0..4)
+        .map(|color| FacetValueHit {
+            value: FacetValue::Integer(color),
+            count: 25,
+        })
+        .collect_vec();
+    assert_eq!(response.hits, expected);
+
+    // Only points with color 0, i.e. every fourth point, are counted
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "color",
+        Match::from(0 as IntPayloadType),
+    )));
+    let response = facet(&collection, "brand", 10, Some(filter), true)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.hits,
+        keyword_hits(&[("a", 9), ("b", 8), ("c", 8), ("x", 5)]),
+    );
+
+    let result = facet(&collection, "unindexed", 10, None, true).await;
+    assert!(
+        matches!(result, Err(CollectionError::BadInput { .. })),
+        "{result:?}",
+    );
+}
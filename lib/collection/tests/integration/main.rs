@@ -5,6 +5,10 @@ pub mod collection_test;
 #[cfg(test)]
 pub mod common;
 #[cfg(test)]
+pub mod distance_matrix_test;
+#[cfg(test)]
+pub mod facet_test;
+#[cfg(test)]
 pub mod grouping_test;
 #[cfg(test)]
 pub mod lookup_test;
@@ -13,4 +17,8 @@ pub mod multi_vec_test;
 #[cfg(test)]
 pub mod pagination_test;
 #[cfg(test)]
+pub mod query_test;
+#[cfg(test)]
 pub mod snapshot_recovery_test;
+#[cfg(test)]
+pub mod sparse_idf_test;
@@ -0,0 +1,469 @@
+use std::collections::HashSet;
+
+use collection::collection::Collection;
+use collection::operations::point_ops::{Batch, WriteOrdering};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{
+    CollectionError, Fusion, Prefetch, Query, QueryInterface, QueryRequestInternal,
+    RecommendExample, RecommendInput, RecommendStrategy, TextQueryInput,
+};
+use collection::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
+use collection::universal_query::{query, query_batch};
+use itertools::Itertools;
+use segment::types::{
+    Condition, FieldCondition, Filter, IntPayloadType, Match, PayloadFieldSchema,
+    PayloadSchemaType, PointIdType, ScoredPoint, WithPayloadInterface,
+};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::common::{simple_collection_fixture, N_SHARDS};
+
+const NUM_POINTS: u64 = 40;
+
+/// Points are spread over the quarter circle, so their order by `Dot` is predictable
+fn point_vector(id: u64) -> Vec<f32> {
+    let angle = id as f32 / NUM_POINTS as f32 * std::f32::consts::FRAC_PI_2;
+    vec![angle.cos(), angle.sin(), 0.0, 0.0]
+}
+
+async fn query_collection_fixture(collection: &Collection) {
+    let payloads = (0..NUM_POINTS)
+        .map(|id| Some(serde_json::from_value(json!({ "parity": id % 2 })).unwrap()))
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: (0..NUM_POINTS).map(|id| id.into()).collect_vec(),
+            vectors: (0..NUM_POINTS).map(point_vector).collect_vec().into(),
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+}
+
+async fn run_query(
+    collection: &Collection,
+    request: QueryRequestInternal,
+) -> Result<Vec<ScoredPoint>, CollectionError> {
+    query(
+        request,
+        collection,
+        |_| async { unreachable!("Should not be called in this test") },
+        None,
+        ShardSelectorInternal::All,
+        None,
+    )
+    .await
+}
+
+fn ids(points: &[ScoredPoint]) -> Vec<u64> {
+    points
+        .iter()
+        .map(|point| match point.id {
+            PointIdType::NumId(num) => num,
+            PointIdType::Uuid(_) => panic!("unexpected uuid"),
+        })
+        .collect()
+}
+
+fn nearest_to_vector(id: u64) -> Option<QueryInterface> {
+    Some(QueryInterface::Nearest(RecommendExample::Dense(
+        point_vector(id),
+    )))
+}
+
+fn nearest_to_point(id: u64) -> Option<QueryInterface> {
+    Some(QueryInterface::Nearest(RecommendExample::from(id)))
+}
+
+fn parity_filter(parity: IntPayloadType) -> Filter {
+    Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "parity",
+        Match::from(parity),
+    )))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_universal_query() {
+    test_universal_query_with_shards(1).await;
+    test_universal_query_with_shards(N_SHARDS).await;
+}
+
+async fn test_universal_query_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+    query_collection_fixture(&collection).await;
+
+    // Nearest by vector, with payload
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: nearest_to_vector(10),
+            limit: Some(3),
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&result)[0], 10);
+    assert_eq!(result.len(), 3);
+    assert!(result.iter().all(|point| point.payload.is_some()));
+
+    // Nearest by point id excludes the point itself
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: nearest_to_point(10),
+            limit: Some(2),
+            offset: Some(1),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(!ids(&result).contains(&10));
+
+    // Recommend with both strategies
+    for strategy in [
+        RecommendStrategy::AverageVector,
+        RecommendStrategy::BestScore,
+    ] {
+        let result = run_query(
+            &collection,
+            QueryRequestInternal {
+                query: Some(QueryInterface::Query(Query::Recommend(RecommendInput {
+                    positive: vec![RecommendExample::from(NUM_POINTS - 1)],
+                    negative: vec![RecommendExample::from(0)],
+                    strategy: Some(strategy),
+                }))),
+                limit: Some(5),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.len(), 5);
+        assert!(
+            ids(&result).iter().all(|id| *id >= NUM_POINTS - 6),
+            "{strategy:?}: {:?}",
+            ids(&result),
+        );
+    }
+
+    // Prefetch limits the candidates, the main query rescores them
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            prefetch: Some(vec![Prefetch {
+                query: nearest_to_vector(0),
+                filter: Some(parity_filter(0)),
+                limit: Some(5),
+                ..Default::default()
+            }]),
+            query: nearest_to_vector(NUM_POINTS - 1),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&result), vec![8, 6, 4, 2, 0]);
+
+    // Filter of the request applies to the prefetches as well
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            prefetch: Some(vec![Prefetch {
+                query: nearest_to_vector(0),
+                limit: Some(4),
+                ..Default::default()
+            }]),
+            filter: Some(parity_filter(1)),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&result), vec![1, 3, 5, 7]);
+
+    // Fusion of two opposite searches
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            prefetch: Some(vec![
+                Prefetch {
+                    query: nearest_to_vector(0),
+                    limit: Some(3),
+                    ..Default::default()
+                },
+                Prefetch {
+                    query: nearest_to_vector(NUM_POINTS - 1),
+                    limit: Some(3),
+                    ..Default::default()
+                },
+            ]),
+            query: Some(QueryInterface::Query(Query::Fusion(Fusion::Rrf))),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    let fused: HashSet<_> = ids(&result).into_iter().collect();
+    assert_eq!(
+        fused,
+        HashSet::from([0, 1, 2, NUM_POINTS - 1, NUM_POINTS - 2, NUM_POINTS - 3]),
+    );
+    assert!(result.windows(2).all(|w| w[0].score >= w[1].score));
+
+    // Fusion without prefetches is rejected
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: Some(QueryInterface::Query(Query::Fusion(Fusion::Rrf))),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(
+        matches!(result, Err(CollectionError::BadRequest { .. })),
+        "{result:?}",
+    );
+
+    // Missing referenced point
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: nearest_to_point(1000),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(
+        matches!(result, Err(CollectionError::PointNotFound { .. })),
+        "{result:?}",
+    );
+
+    // Without query, points are taken by filter
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            filter: Some(parity_filter(0)),
+            limit: Some(100),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.len(), NUM_POINTS as usize / 2);
+    assert!(ids(&result).iter().all(|id| id % 2 == 0));
+
+    // Batch, including requests of different depth
+    let batch = vec![
+        (
+            QueryRequestInternal {
+                query: nearest_to_vector(20),
+                limit: Some(1),
+                ..Default::default()
+            },
+            ShardSelectorInternal::All,
+        ),
+        (
+            QueryRequestInternal {
+                prefetch: Some(vec![Prefetch {
+                    prefetch: Some(vec![Prefetch {
+                        query: nearest_to_vector(0),
+                        limit: Some(10),
+                        ..Default::default()
+                    }]),
+                    query: nearest_to_point(NUM_POINTS - 1),
+                    limit: Some(3),
+                    ..Default::default()
+                }]),
+                query: nearest_to_vector(0),
+                limit: Some(3),
+                ..Default::default()
+            },
+            ShardSelectorInternal::All,
+        ),
+    ];
+    let results = query_batch(
+        batch,
+        &collection,
+        |_| async { unreachable!("Should not be called in this test") },
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(ids(&results[0]), vec![20]);
+    assert_eq!(ids(&results[1]), vec![7, 8, 9]);
+}
+
+/// First points mention "cat" less and less often, all of them mention "dog"
+fn point_text(id: u64) -> String {
+    let mut text = "cat ".repeat(5usize.saturating_sub(id as usize));
+    text.push_str("dog");
+    text
+}
+
+async fn text_collection_fixture(collection: &Collection) {
+    let create_index = CollectionUpdateOperations::FieldIndexOperation(
+        FieldIndexOperations::CreateIndex(CreateIndex {
+            field_name: "text".to_string(),
+            field_schema: Some(PayloadFieldSchema::FieldType(PayloadSchemaType::Text)),
+        }),
+    );
+    collection
+        .update_from_client_simple(create_index, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    let payloads = (0..NUM_POINTS)
+        .map(|id| {
+            let payload = json!({ "parity": id % 2, "text": point_text(id) });
+            Some(serde_json::from_value(payload).unwrap())
+        })
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: (0..NUM_POINTS).map(|id| id.into()).collect_vec(),
+            vectors: (0..NUM_POINTS).map(point_vector).collect_vec().into(),
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+}
+
+fn text_query(key: &str, text: &str) -> Option<QueryInterface> {
+    Some(QueryInterface::Query(Query::Text(TextQueryInput {
+        key: key.to_string(),
+        text: text.to_string(),
+    })))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_universal_query_text() {
+    test_universal_query_text_with_shards(1).await;
+    test_universal_query_text_with_shards(N_SHARDS).await;
+}
+
+async fn test_universal_query_text_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+    text_collection_fixture(&collection).await;
+
+    // Only points mentioning the text are found, more relevant first
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: text_query("text", "cat"),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(result.windows(2).all(|w| w[0].score >= w[1].score));
+    // Scores are comparable between points of a single shard only
+    if shard_number == 1 {
+        assert_eq!(ids(&result), vec![0, 1, 2, 3, 4]);
+    } else {
+        assert_eq!(
+            ids(&result).into_iter().collect::<HashSet<_>>(),
+            HashSet::from([0, 1, 2, 3, 4]),
+        );
+    }
+
+    // Filter is applied
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: text_query("text", "cat"),
+            filter: Some(parity_filter(1)),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        ids(&result).into_iter().collect::<HashSet<_>>(),
+        HashSet::from([1, 3]),
+    );
+
+    // Rank candidates of a vector search by text
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            prefetch: Some(vec![Prefetch {
+                query: nearest_to_vector(4),
+                limit: Some(3),
+                ..Default::default()
+            }]),
+            query: text_query("text", "cat"),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        ids(&result).into_iter().collect::<HashSet<_>>(),
+        HashSet::from([3, 4]),
+    );
+
+    // Fusion of text and vector searches
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            prefetch: Some(vec![
+                Prefetch {
+                    query: text_query("text", "cat"),
+                    limit: Some(2),
+                    ..Default::default()
+                },
+                Prefetch {
+                    query: nearest_to_vector(NUM_POINTS - 1),
+                    limit: Some(2),
+                    ..Default::default()
+                },
+            ]),
+            query: Some(QueryInterface::Query(Query::Fusion(Fusion::Rrf))),
+            limit: Some(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.len(), 4);
+    assert!(ids(&result).contains(&(NUM_POINTS - 1)));
+    assert!(ids(&result).contains(&(NUM_POINTS - 2)));
+
+    // Field without full-text index
+    let result = run_query(
+        &collection,
+        QueryRequestInternal {
+            query: text_query("parity", "cat"),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(
+        matches!(result, Err(CollectionError::BadInput { .. })),
+        "{result:?}",
+    );
+}
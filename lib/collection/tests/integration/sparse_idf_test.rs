@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use collection::collection::Collection;
+use collection::config::{CollectionConfig, CollectionParams, WalConfig};
+use collection::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStruct, WriteOrdering,
+};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{
+    CoreSearchRequest, Modifier, QueryEnum, SparseVectorParams, SparseVectorStatisticsRequest,
+};
+use collection::operations::CollectionUpdateOperations;
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::sparse_statistics::SparseVectorStatistics;
+use segment::data_types::vectors::{NamedSparseVector, NamedVectorStruct, Vector};
+use segment::types::PointIdType;
+use sparse::common::sparse_vector::SparseVector;
+use tempfile::Builder;
+
+use crate::common::{new_local_collection, N_SHARDS, TEST_OPTIMIZERS_CONFIG};
+
+const SPARSE_VECTOR_NAME: &str = "sparse";
+const NUM_POINTS: u64 = 100;
+
+/// Dimension 0 is in every vector, dimension 1 in every tenth and dimension 2 in none
+fn sparse_vector(id: u64) -> SparseVector {
+    if id % 10 == 0 {
+        SparseVector::new(vec![0, 1], vec![1.0, 2.0]).unwrap()
+    } else {
+        SparseVector::new(vec![0, 3], vec![1.0, 1.0]).unwrap()
+    }
+}
+
+async fn sparse_collection_fixture(
+    collection_path: &Path,
+    shard_number: u32,
+    modifier: Option<Modifier>,
+) -> Collection {
+    let wal_config = WalConfig {
+        wal_capacity_mb: 1,
+        wal_segments_ahead: 0,
+    };
+
+    let collection_params = CollectionParams {
+        sparse_vectors: Some(BTreeMap::from([(
+            SPARSE_VECTOR_NAME.to_string(),
+            SparseVectorParams {
+                index: None,
+                modifier,
+            },
+        )])),
+        shard_number: NonZeroU32::new(shard_number).expect("Shard number can not be zero"),
+        ..CollectionParams::empty()
+    };
+
+    let collection_config = CollectionConfig {
+        params: collection_params,
+        optimizer_config: TEST_OPTIMIZERS_CONFIG.clone(),
+        wal_config,
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+    };
+
+    let snapshot_path = collection_path.join("snapshots");
+
+    let collection = new_local_collection(
+        "test".to_string(),
+        collection_path,
+        &snapshot_path,
+        &collection_config,
+    )
+    .await
+    .unwrap();
+
+    let points = (0..NUM_POINTS)
+        .map(|id| {
+            let mut vectors = NamedVectors::default();
+            vectors.insert(
+                SPARSE_VECTOR_NAME.to_string(),
+                Vector::Sparse(sparse_vector(id)),
+            );
+            PointStruct {
+                id: id.into(),
+                vector: vectors.into(),
+                payload: None,
+            }
+        })
+        .collect();
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    collection
+}
+
+async fn search_sparse(collection: &Collection, query: SparseVector) -> Vec<(PointIdType, f32)> {
+    let request = CoreSearchRequest {
+        query: QueryEnum::Nearest(NamedVectorStruct::Sparse(NamedSparseVector {
+            name: SPARSE_VECTOR_NAME.to_string(),
+            vector: query,
+        })),
+        filter: None,
+        params: None,
+        limit: NUM_POINTS as usize,
+        offset: 0,
+        with_payload: None,
+        with_vector: None,
+        score_threshold: None,
+    };
+    collection
+        .search(request, None, &ShardSelectorInternal::All, None)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|point| (point.id, point.score))
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sparse_idf_statistics() {
+    for shard_number in [1, N_SHARDS] {
+        let collection_dir = Builder::new().prefix("test_sparse_idf").tempdir().unwrap();
+        let collection =
+            sparse_collection_fixture(collection_dir.path(), shard_number, Some(Modifier::Idf))
+                .await;
+
+        let request = SparseVectorStatisticsRequest {
+            vectors: [(SPARSE_VECTOR_NAME.to_string(), vec![0, 1, 2])].into(),
+        };
+        let statistics = collection
+            .sparse_vector_statistics(request, &ShardSelectorInternal::All)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statistics[SPARSE_VECTOR_NAME],
+            SparseVectorStatistics {
+                vector_count: NUM_POINTS as usize,
+                document_frequencies: [(0, 100), (1, 10), (2, 0)].into(),
+            },
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sparse_idf_search() {
+    let statistics = SparseVectorStatistics {
+        vector_count: NUM_POINTS as usize,
+        document_frequencies: [(0, 100), (1, 10)].into(),
+    };
+    let query = SparseVector::new(vec![0, 1], vec![1.0, 1.0]).unwrap();
+
+    // Scores don't depend on the distribution of points across shards
+    for shard_number in [1, N_SHARDS] {
+        let collection_dir = Builder::new().prefix("test_sparse_idf").tempdir().unwrap();
+        let collection =
+            sparse_collection_fixture(collection_dir.path(), shard_number, Some(Modifier::Idf))
+                .await;
+
+        let result = search_sparse(&collection, query.clone()).await;
+        assert_eq!(result.len(), NUM_POINTS as usize);
+        for (id, score) in result {
+            let PointIdType::NumId(id) = id else {
+                panic!("unexpected point id {id}");
+            };
+            let expected = if id % 10 == 0 {
+                statistics.idf(0) + 2.0 * statistics.idf(1)
+            } else {
+                statistics.idf(0)
+            };
+            assert!(
+                (score - expected).abs() < 1e-5,
+                "point {id}: {score} != {expected}",
+            );
+        }
+    }
+
+    // Without modifier, raw dot product is used
+    let collection_dir = Builder::new().prefix("test_sparse_idf").tempdir().unwrap();
+    let collection = sparse_collection_fixture(collection_dir.path(), N_SHARDS, None).await;
+    let result = search_sparse(&collection, query).await;
+    assert_eq!(result.first().map(|(_, score)| *score), Some(3.0));
+    assert_eq!(result.last().map(|(_, score)| *score), Some(1.0));
+}
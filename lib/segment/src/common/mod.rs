@@ -175,6 +175,9 @@ fn check_vector_against_config(
 ) -> OperationResult<()> {
     match vector {
         VectorRef::Dense(vector) => {
+            if vector_config.multivector_config.is_some() {
+                return Err(OperationError::WrongMulti);
+            }
             // Check dimensionality
             let dim = vector_config.size;
             if vector.len() != dim {
@@ -185,6 +188,20 @@ fn check_vector_against_config(
             }
             Ok(())
         }
+        VectorRef::MultiDense(multi_vector) => {
+            if vector_config.multivector_config.is_none() {
+                return Err(OperationError::WrongMulti);
+            }
+            // Check dimensionality of every vector in the matrix
+            let dim = vector_config.size;
+            if multi_vector.dim != dim {
+                return Err(OperationError::WrongVector {
+                    expected_dim: dim,
+                    received_dim: multi_vector.dim,
+                });
+            }
+            Ok(())
+        }
         VectorRef::Sparse(_) => Err(OperationError::WrongSparse),
     }
 }
@@ -194,7 +211,7 @@ fn check_sparse_vector_against_config(
     _vector_config: &SparseVectorDataConfig,
 ) -> OperationResult<()> {
     match vector {
-        VectorRef::Dense(_) => Err(OperationError::WrongSparse),
+        VectorRef::Dense(_) | VectorRef::MultiDense(_) => Err(OperationError::WrongSparse),
         VectorRef::Sparse(_vector) => Ok(()), // TODO(sparse) check vector by config
     }
 }
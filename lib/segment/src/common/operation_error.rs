@@ -52,6 +52,14 @@ pub enum OperationError {
     ValidationError { description: String },
     #[error("Wrong usage of sparse vectors")]
     WrongSparse,
+    #[error("Wrong usage of multi vectors")]
+    WrongMulti,
+    #[error("No range index for `order_by` key: `{key}`. Please create one to use `order_by`. Integer and float payloads can have range indexes, see https://qdrant.tech/documentation/concepts/indexing/#payload-index.")]
+    MissingRangeIndexForOrderBy { key: String },
+    #[error("No keyword or integer index for facet key: `{key}`. Please create one to count values of the key, see https://qdrant.tech/documentation/concepts/indexing/#payload-index.")]
+    MissingMapIndexForFacet { key: String },
+    #[error("No full-text index for text query key: `{key}`. Please create one to rank points by text, see https://qdrant.tech/documentation/concepts/indexing/#full-text-index.")]
+    MissingFullTextIndexForTextQuery { key: String },
 }
 
 impl OperationError {
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use itertools::Either;
 use serde_json::Value;
 
 use crate::data_types::named_vectors::NamedVectors;
@@ -9,6 +10,9 @@ use crate::types::PayloadKeyType;
 
 pub type IndexesMap = HashMap<PayloadKeyType, Vec<FieldIndex>>;
 
+/// JSON path element, which matches any key of an object
+pub const JSON_PATH_WILDCARD: &str = "*";
+
 /// Avoids allocating Vec with a single element
 #[derive(Debug)]
 pub enum MultiValue<T> {
@@ -146,6 +150,30 @@ fn parse_array_path(path: &str) -> Option<(&str, Option<u32>)> {
     }
 }
 
+/// Values of the object addressed by the path element, all of them for the wildcard
+fn focus_object_values<'a>(
+    element: &str,
+    json_map: &'a serde_json::Map<String, Value>,
+) -> impl Iterator<Item = &'a Value> {
+    if element == JSON_PATH_WILDCARD {
+        Either::Left(json_map.values())
+    } else {
+        Either::Right(json_map.get(element).into_iter())
+    }
+}
+
+/// Mutable values of the object addressed by the path element, all of them for the wildcard
+fn focus_object_values_mut<'a>(
+    element: &str,
+    json_map: &'a mut serde_json::Map<String, Value>,
+) -> impl Iterator<Item = &'a mut Value> {
+    if element == JSON_PATH_WILDCARD {
+        Either::Left(json_map.values_mut())
+    } else {
+        Either::Right(json_map.get_mut(element).into_iter())
+    }
+}
+
 /// Focus on array values references according to array path
 ///
 /// Expects to be called with a path that is a path to an Array
@@ -155,38 +183,32 @@ fn focus_array_path<'a>(
     rest_of_path: Option<&str>,
     json_map: &'a serde_json::Map<String, Value>,
 ) -> Option<MultiValue<&'a Value>> {
-    match json_map.get(array_path) {
-        Some(Value::Array(array)) => {
-            let mut values: MultiValue<_> = MultiValue::default();
-            for (i, value) in array.iter().enumerate() {
-                if let Some(array_index) = array_index {
-                    if i == array_index as usize {
-                        match rest_of_path {
-                            Some(rest_path) => {
-                                // expect an Object if there is a rest path
-                                if let Value::Object(map) = value {
-                                    values.extend(get_value_from_json_map(rest_path, map))
-                                }
-                            }
-                            None => values.push(value),
-                        }
-                    }
-                } else {
-                    match rest_of_path {
-                        Some(rest_path) => {
-                            // expect an Object if there is a rest path
-                            if let Value::Object(map) = value {
-                                values.extend(get_value_from_json_map(rest_path, map))
-                            }
-                        }
-                        None => values.push(value),
+    let mut arrays = focus_object_values(array_path, json_map)
+        .filter_map(Value::as_array)
+        .peekable();
+    // the wildcard matches any amount of arrays, including none
+    if arrays.peek().is_none() && array_path != JSON_PATH_WILDCARD {
+        return None;
+    }
+    let mut values: MultiValue<_> = MultiValue::default();
+    for array in arrays {
+        let elements = match array_index {
+            Some(array_index) => Either::Left(array.get(array_index as usize).into_iter()),
+            None => Either::Right(array.iter()),
+        };
+        for value in elements {
+            match rest_of_path {
+                Some(rest_path) => {
+                    // expect an Object if there is a rest path
+                    if let Value::Object(map) = value {
+                        values.extend(get_value_from_json_map(rest_path, map))
                     }
                 }
+                None => values.push(value),
             }
-            Some(values)
         }
-        _ => None,
     }
+    Some(values)
 }
 
 pub fn get_value_from_json_map_opt<'a>(
@@ -201,6 +223,16 @@ pub fn get_value_from_json_map_opt<'a>(
                 Some((array_element_path, array_index)) => {
                     focus_array_path(array_element_path, array_index, Some(rest_path), json_map)
                 }
+                None if element == JSON_PATH_WILDCARD => {
+                    // any key of the object
+                    let mut values = MultiValue::default();
+                    for value in json_map.values() {
+                        if let Value::Object(map) = value {
+                            values.extend(get_value_from_json_map(rest_path, map))
+                        }
+                    }
+                    Some(values)
+                }
                 None => {
                     // no array notation
                     match json_map.get(element) {
@@ -215,6 +247,11 @@ pub fn get_value_from_json_map_opt<'a>(
             Some((array_element_path, array_index)) => {
                 focus_array_path(array_element_path, array_index, None, json_map)
             }
+            None if path == JSON_PATH_WILDCARD => {
+                let mut values = MultiValue::default();
+                values.extend(json_map.values());
+                Some(values)
+            }
             None => json_map.get(path).map(MultiValue::one),
         },
     }
@@ -233,6 +270,9 @@ pub fn get_value_from_json_map_opt<'a>(
 ///
 /// path: "arr[].a"   => Vec![Value::Array[ 1, 2, 3], 4]
 /// path: "arr[].a[]" => Vec![ 1, 2, 3, 4]
+/// path: "arr[0].a"  => Vec![Value::Array[ 1, 2, 3]]
+///
+/// The `*` path element matches any key of an object, e.g. "prices.*.amount"
 ///
 /// performance: the function could be improved by using the Entry API instead of BTreeMap.get
 pub fn get_value_from_json_map<'a>(
@@ -251,21 +291,24 @@ fn delete_array_path(
     rest_of_path: Option<&str>,
     json_map: &mut serde_json::Map<String, Value>,
 ) -> MultiValue<Value> {
-    if let Some(Value::Array(array)) = json_map.get_mut(array_path) {
+    let mut values = MultiValue::default();
+    for value in focus_object_values_mut(array_path, json_map) {
+        let Value::Array(array) = value else {
+            continue;
+        };
         match rest_of_path {
             None => {
                 // end of path - delete and collect
                 if let Some(array_index) = array_index {
                     if array.len() > array_index as usize {
-                        return MultiValue::one(array.remove(array_index as usize));
+                        values.push(array.remove(array_index as usize));
                     }
                 } else {
-                    return MultiValue::one(Value::Array(std::mem::take(array)));
+                    values.push(Value::Array(std::mem::take(array)));
                 }
             }
             Some(rest_path) => {
                 // dig deeper
-                let mut values = MultiValue::default();
                 for (i, value) in array.iter_mut().enumerate() {
                     if let Value::Object(map) = value {
                         if let Some(array_index) = array_index {
@@ -277,12 +320,10 @@ fn delete_array_path(
                         }
                     }
                 }
-                return values;
             }
         }
     }
-    // no array found
-    MultiValue::default()
+    values
 }
 
 /// Remove value at a given JSON path from JSON map
@@ -300,6 +341,16 @@ pub fn remove_value_from_json_map(
                 Some((array_element_path, array_index)) => {
                     delete_array_path(array_element_path, array_index, Some(rest_path), json_map)
                 }
+                None if element == JSON_PATH_WILDCARD => {
+                    // any key of the object
+                    let mut values = MultiValue::default();
+                    for value in json_map.values_mut() {
+                        if let Value::Object(map) = value {
+                            values.extend(remove_value_from_json_map(rest_path, map))
+                        }
+                    }
+                    values
+                }
                 None => {
                     // no array notation
                     if rest_path.is_empty() {
@@ -318,11 +369,35 @@ pub fn remove_value_from_json_map(
             Some((array_element_path, array_index)) => {
                 delete_array_path(array_element_path, array_index, None, json_map)
             }
+            None if path == JSON_PATH_WILDCARD => {
+                let mut values = MultiValue::default();
+                values.extend(
+                    std::mem::take(json_map)
+                        .into_iter()
+                        .map(|(_key, value)| value),
+                );
+                values
+            }
             None => MultiValue::option(json_map.remove(path)),
         },
     }
 }
 
+/// Split a JSON path into its elements, array indices are kept as `"0]"` and `"]"`
+fn path_elements(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['.', '['])
+}
+
+/// Check if an element of a pattern matches an element of a concrete path
+///
+/// `[]` in the pattern matches any array index and `*` matches any object key.
+fn check_element_pattern(pattern: &str, element: &str) -> bool {
+    let is_index = element.ends_with(']');
+    pattern == element
+        || (pattern == "]" && is_index)
+        || (pattern == JSON_PATH_WILDCARD && !is_index)
+}
+
 /// Check if a path is included in a list of patterns
 ///
 /// Basically, it checks if either the pattern or path is a prefix of the other.
@@ -333,12 +408,14 @@ pub fn remove_value_from_json_map(
 /// assert!(!segment::common::utils::check_include_pattern("a.b.c", "a.b.d"));
 /// assert!(segment::common::utils::check_include_pattern("a.b.c", "a"));
 /// assert!(segment::common::utils::check_include_pattern("a", "a.d"));
+/// assert!(segment::common::utils::check_include_pattern("a[].b", "a[1].b"));
+/// assert!(!segment::common::utils::check_include_pattern("a[0].b", "a[1].b"));
+/// assert!(segment::common::utils::check_include_pattern("a.*.c", "a.b.c"));
 /// ```
 pub fn check_include_pattern(pattern: &str, path: &str) -> bool {
-    pattern
-        .split(['.', '['])
-        .zip(path.split(['.', '[']))
-        .all(|(p, v)| p == v)
+    path_elements(pattern)
+        .zip(path_elements(path))
+        .all(|(p, v)| check_element_pattern(p, v))
 }
 
 /// Check if a path should be excluded by a pattern
@@ -351,16 +428,15 @@ pub fn check_include_pattern(pattern: &str, path: &str) -> bool {
 /// assert!(!segment::common::utils::check_exclude_pattern("a.b.c", "a.b.d"));
 /// assert!(!segment::common::utils::check_exclude_pattern("a.b.c", "a"));
 /// assert!(segment::common::utils::check_exclude_pattern("a", "a.d"));
+/// assert!(segment::common::utils::check_exclude_pattern("a[0]", "a[0].b"));
+/// assert!(!segment::common::utils::check_exclude_pattern("a[0]", "a[1].b"));
+/// assert!(segment::common::utils::check_exclude_pattern("*.c", "a.c"));
 /// ```
-
 pub fn check_exclude_pattern(pattern: &str, path: &str) -> bool {
-    if pattern.len() > path.len() {
+    if path_elements(pattern).count() > path_elements(path).count() {
         return false;
     }
-    pattern
-        .split(['.', '['])
-        .zip(path.split(['.', '[']))
-        .all(|(p, v)| p == v)
+    check_include_pattern(pattern, path)
 }
 
 fn _filter_json_values<'a>(
@@ -375,15 +451,16 @@ fn _filter_json_values<'a>(
         Value::String(_) => value.clone(),
         Value::Array(array) => {
             let mut new_array = Vec::new();
-            path.push_str("[]");
-            for value in array.iter() {
+            for (i, value) in array.iter().enumerate() {
+                let index = format!("[{i}]");
+                path.push_str(&index);
                 if filter(&path, value) {
                     let (path_, value) = _filter_json_values(path, value, filter);
                     path = path_;
                     new_array.push(value);
                 }
+                path.truncate(path.len() - index.len());
             }
-            path.truncate(path.len() - 2);
             Value::Array(new_array)
         }
         Value::Object(object) => {
@@ -411,7 +488,8 @@ fn _filter_json_values<'a>(
 
 /// Filter json map based on external filter function
 ///
-/// Filter function takes path and value as input and returns true if the value should be kept
+/// Filter function takes path and value as input and returns true if the value should be kept.
+/// Array elements are addressed by their index in the path, e.g. `a.b[1].c`.
 pub fn filter_json_values(
     json_map: &serde_json::Map<String, Value>,
     filter: impl Fn(&str, &Value) -> bool,
@@ -739,6 +817,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_wildcard_value_from_json_map() {
+        let map = serde_json::from_str::<serde_json::Map<String, Value>>(
+            r#"
+            {
+                "prices": {
+                    "eur": { "amount": 1 },
+                    "usd": { "amount": 2 },
+                    "gbp": 3
+                },
+                "tags": {
+                    "a": ["t1", "t2"],
+                    "b": ["t3"]
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_value_from_json_map("prices.*.amount", &map).values(),
+            vec![&Value::Number(1.into()), &Value::Number(2.into())]
+        );
+
+        // leaf wildcard selects all values of the object
+        assert_eq!(get_value_from_json_map("prices.*", &map).values().len(), 3);
+
+        // wildcard combined with array index
+        assert_eq!(
+            get_value_from_json_map("tags.*[0]", &map).values(),
+            vec![
+                &Value::String("t1".to_string()),
+                &Value::String("t3".to_string())
+            ]
+        );
+
+        // no match for the wildcard is not an error
+        assert!(get_value_from_json_map("prices.*.missing", &map).check_is_empty());
+        assert_eq!(
+            get_value_from_json_map("*.eur.amount", &map).values(),
+            vec![&Value::Number(1.into())]
+        );
+    }
+
+    #[test]
+    fn test_remove_wildcard_value_from_json_map() {
+        let mut map = serde_json::from_str::<serde_json::Map<String, Value>>(
+            r#"
+            {
+                "prices": {
+                    "eur": { "amount": 1, "tax": 0 },
+                    "usd": { "amount": 2 }
+                },
+                "tags": {
+                    "a": ["t1", "t2"],
+                    "b": ["t3"]
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let removed = remove_value_from_json_map("prices.*.amount", &mut map).values();
+        assert_eq!(
+            removed,
+            vec![Value::Number(1.into()), Value::Number(2.into())]
+        );
+
+        let removed = remove_value_from_json_map("tags.*[0]", &mut map).values();
+        assert_eq!(
+            removed,
+            vec![
+                Value::String("t1".to_string()),
+                Value::String("t3".to_string())
+            ]
+        );
+
+        assert_eq!(
+            map,
+            serde_json::from_str::<serde_json::Map<String, Value>>(
+                r#"
+                {
+                    "prices": {
+                        "eur": { "tax": 0 },
+                        "usd": {}
+                    },
+                    "tags": {
+                        "a": ["t2"],
+                        "b": []
+                    }
+                }
+                "#,
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_filter_json_by_index_and_wildcard() {
+        let map = serde_json::from_str::<serde_json::Map<String, Value>>(
+            r#"
+            {
+                "a": [
+                    { "b": 1, "c": 2 },
+                    { "b": 3, "c": 4 }
+                ],
+                "prices": {
+                    "eur": { "amount": 1, "tax": 0 },
+                    "usd": { "amount": 2 }
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let res = filter_json_values(&map, |path, _value| {
+            check_include_pattern("a[1].b", path) || check_include_pattern("prices.*.amount", path)
+        });
+        assert_eq!(
+            res,
+            serde_json::from_str::<serde_json::Map<String, Value>>(
+                r#"
+                {
+                    "a": [ { "b": 3 } ],
+                    "prices": {
+                        "eur": { "amount": 1 },
+                        "usd": { "amount": 2 }
+                    }
+                }
+                "#,
+            )
+            .unwrap()
+        );
+
+        let res = filter_json_values(&map, |path, _value| {
+            !check_exclude_pattern("a[0]", path) && !check_exclude_pattern("prices.*.tax", path)
+        });
+        assert_eq!(
+            res,
+            serde_json::from_str::<serde_json::Map<String, Value>>(
+                r#"
+                {
+                    "a": [ { "b": 3, "c": 4 } ],
+                    "prices": {
+                        "eur": { "amount": 1 },
+                        "usd": { "amount": 2 }
+                    }
+                }
+                "#,
+            )
+            .unwrap()
+        );
+    }
+
     #[test]
     fn test_filter_json() {
         let map = serde_json::from_str::<serde_json::Map<String, Value>>(
@@ -758,9 +990,7 @@ mod tests {
         )
         .unwrap();
 
-        let res = filter_json_values(&map, |path, _value| {
-            path.starts_with("a.b[].c") || "a.b[].c".starts_with(path)
-        });
+        let res = filter_json_values(&map, |path, _value| check_include_pattern("a.b[].c", path));
 
         assert_eq!(
             res,
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Filter, IntPayloadType, PayloadKeyType};
+
+/// Parameters of counting points per value of a payload key
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacetParams {
+    /// Payload key to count values of
+    pub key: PayloadKeyType,
+    /// Maximum number of values to return
+    pub limit: usize,
+    /// Count only points which satisfy this filter
+    pub filter: Option<Filter>,
+    /// If false, only the top `limit` values of each segment are counted
+    pub exact: bool,
+}
+
+/// Value of a payload key, which points are counted for
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash, PartialOrd, Ord,
+)]
+#[serde(untagged)]
+pub enum FacetValue {
+    Keyword(String),
+    Integer(IntPayloadType),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+pub struct FacetValueHit {
+    pub value: FacetValue,
+    pub count: usize,
+}
+
+impl Ord for FacetValueHit {
+    /// The most frequent values go first, ties are sorted by value
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .count
+            .cmp(&self.count)
+            .then_with(|| self.value.cmp(&other.value))
+    }
+}
+
+impl PartialOrd for FacetValueHit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Add counts of `other` into `counts`, e.g. to aggregate counts of different segments
+pub fn merge_facet_counts(
+    counts: &mut HashMap<FacetValue, usize>,
+    other: HashMap<FacetValue, usize>,
+) {
+    for (value, count) in other {
+        *counts.entry(value).or_default() += count;
+    }
+}
+
+/// Select `limit` most frequent values
+pub fn top_facet_hits(counts: HashMap<FacetValue, usize>, limit: usize) -> Vec<FacetValueHit> {
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(value, count)| FacetValueHit { value, count })
+        .k_smallest(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_facet_hits() {
+        let mut counts = HashMap::from([
+            (FacetValue::Keyword("a".to_string()), 1),
+            (FacetValue::Keyword("b".to_string()), 5),
+            (FacetValue::Integer(3), 5),
+            (FacetValue::Integer(4), 0),
+        ]);
+        merge_facet_counts(
+            &mut counts,
+            HashMap::from([(FacetValue::Keyword("a".to_string()), 10)]),
+        );
+
+        assert_eq!(
+            top_facet_hits(counts.clone(), 2),
+            vec![
+                FacetValueHit {
+                    value: FacetValue::Keyword("a".to_string()),
+                    count: 11,
+                },
+                FacetValueHit {
+                    value: FacetValue::Keyword("b".to_string()),
+                    count: 5,
+                },
+            ],
+        );
+        // Values without points are never returned
+        assert_eq!(top_facet_hits(counts, 10).len(), 3);
+    }
+}
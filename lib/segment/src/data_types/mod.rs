@@ -1,5 +1,11 @@
+pub mod facets;
 pub mod groups;
+pub mod index;
 pub mod named_vectors;
+pub mod order_by;
+pub mod primitive;
+pub mod sparse_statistics;
 pub mod text_index;
+pub mod text_statistics;
 pub mod tiny_map;
 pub mod vectors;
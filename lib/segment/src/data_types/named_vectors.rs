@@ -4,9 +4,12 @@ use std::collections::HashMap;
 use sparse::common::sparse_vector::SparseVector;
 
 use super::tiny_map;
-use super::vectors::{DenseVector, Vector, VectorElementType, VectorRef};
+use super::vectors::{
+    DenseVector, MultiDenseVector, Vector, VectorElementType, VectorElementTypeByte,
+    VectorElementTypeHalf, VectorRef,
+};
 use crate::common::operation_error::OperationError;
-use crate::types::Distance;
+use crate::types::{VectorDataConfig, VectorStorageDatatype};
 
 type CowKey<'a> = Cow<'a, str>;
 
@@ -14,6 +17,7 @@ type CowKey<'a> = Cow<'a, str>;
 pub enum CowVector<'a> {
     Dense(Cow<'a, [VectorElementType]>),
     Sparse(Cow<'a, SparseVector>),
+    MultiDense(Cow<'a, MultiDenseVector>),
 }
 
 impl<'a> Default for CowVector<'a> {
@@ -34,6 +38,7 @@ impl<'a> CowVector<'a> {
         match self {
             CowVector::Dense(v) => Vector::Dense(v.into_owned()),
             CowVector::Sparse(v) => Vector::Sparse(v.into_owned()),
+            CowVector::MultiDense(v) => Vector::MultiDense(v.into_owned()),
         }
     }
 
@@ -41,6 +46,7 @@ impl<'a> CowVector<'a> {
         match self {
             CowVector::Dense(v) => VectorRef::Dense(v.as_ref()),
             CowVector::Sparse(v) => VectorRef::Sparse(v.as_ref()),
+            CowVector::MultiDense(v) => VectorRef::MultiDense(v.as_ref().into()),
         }
     }
 }
@@ -50,6 +56,7 @@ impl<'a> From<Vector> for CowVector<'a> {
         match v {
             Vector::Dense(v) => CowVector::Dense(Cow::Owned(v)),
             Vector::Sparse(v) => CowVector::Sparse(Cow::Owned(v)),
+            Vector::MultiDense(v) => CowVector::MultiDense(Cow::Owned(v)),
         }
     }
 }
@@ -66,12 +73,24 @@ impl<'a> From<DenseVector> for CowVector<'a> {
     }
 }
 
+impl<'a> From<MultiDenseVector> for CowVector<'a> {
+    fn from(v: MultiDenseVector) -> Self {
+        CowVector::MultiDense(Cow::Owned(v))
+    }
+}
+
 impl<'a> From<&'a SparseVector> for CowVector<'a> {
     fn from(v: &'a SparseVector) -> Self {
         CowVector::Sparse(Cow::Borrowed(v))
     }
 }
 
+impl<'a> From<&'a MultiDenseVector> for CowVector<'a> {
+    fn from(v: &'a MultiDenseVector) -> Self {
+        CowVector::MultiDense(Cow::Borrowed(v))
+    }
+}
+
 impl<'a> From<&'a [VectorElementType]> for CowVector<'a> {
     fn from(v: &'a [VectorElementType]) -> Self {
         CowVector::Dense(Cow::Owned(v.into()))
@@ -85,6 +104,7 @@ impl<'a> TryFrom<CowVector<'a>> for SparseVector {
         match value {
             CowVector::Dense(_) => Err(OperationError::WrongSparse),
             CowVector::Sparse(v) => Ok(v.into_owned()),
+            CowVector::MultiDense(_) => Err(OperationError::WrongSparse),
         }
     }
 }
@@ -96,6 +116,18 @@ impl<'a> TryFrom<CowVector<'a>> for DenseVector {
         match value {
             CowVector::Dense(v) => Ok(v.into_owned()),
             CowVector::Sparse(_) => Err(OperationError::WrongSparse),
+            CowVector::MultiDense(_) => Err(OperationError::WrongMulti),
+        }
+    }
+}
+
+impl<'a> TryFrom<CowVector<'a>> for MultiDenseVector {
+    type Error = OperationError;
+
+    fn try_from(value: CowVector<'a>) -> Result<Self, Self::Error> {
+        match value {
+            CowVector::Dense(_) | CowVector::Sparse(_) => Err(OperationError::WrongMulti),
+            CowVector::MultiDense(v) => Ok(v.into_owned()),
         }
     }
 }
@@ -105,6 +137,7 @@ impl<'a> From<VectorRef<'a>> for CowVector<'a> {
         match v {
             VectorRef::Dense(v) => CowVector::Dense(Cow::Borrowed(v)),
             VectorRef::Sparse(v) => CowVector::Sparse(Cow::Borrowed(v)),
+            VectorRef::MultiDense(v) => CowVector::MultiDense(Cow::Owned(v.to_owned())),
         }
     }
 }
@@ -117,6 +150,7 @@ impl<'a> NamedVectors<'a> {
             match value {
                 VectorRef::Dense(v) => CowVector::Dense(Cow::Borrowed(v)),
                 VectorRef::Sparse(v) => CowVector::Sparse(Cow::Borrowed(v)),
+                VectorRef::MultiDense(v) => CowVector::MultiDense(Cow::Owned(v.to_owned())),
             },
         );
         Self { map }
@@ -155,6 +189,7 @@ impl<'a> NamedVectors<'a> {
             match vector {
                 Vector::Dense(v) => CowVector::Dense(Cow::Owned(v)),
                 Vector::Sparse(v) => CowVector::Sparse(Cow::Owned(v)),
+                Vector::MultiDense(v) => CowVector::MultiDense(Cow::Owned(v)),
             },
         );
     }
@@ -165,6 +200,7 @@ impl<'a> NamedVectors<'a> {
             match vector {
                 VectorRef::Dense(v) => CowVector::Dense(Cow::Borrowed(v)),
                 VectorRef::Sparse(v) => CowVector::Sparse(Cow::Borrowed(v)),
+                VectorRef::MultiDense(v) => CowVector::MultiDense(Cow::Owned(v.to_owned())),
             },
         );
     }
@@ -200,24 +236,52 @@ impl<'a> NamedVectors<'a> {
         self.map.get(key).map(|v| v.as_vec_ref())
     }
 
-    pub fn preprocess<F>(&mut self, distance_map: F)
-    where
-        F: Fn(&str) -> Distance,
-    {
+    pub fn preprocess<'b>(&mut self, get_vector_data: impl Fn(&str) -> &'b VectorDataConfig) {
         for (name, vector) in self.map.iter_mut() {
-            let distance = distance_map(name);
             match vector {
                 CowVector::Dense(v) => {
-                    let preprocessed_vector = distance.preprocess_vector(v.to_vec());
+                    let config = get_vector_data(name);
+                    let preprocessed_vector = Self::preprocess_dense_vector(v.to_vec(), config);
                     *vector = CowVector::Dense(Cow::Owned(preprocessed_vector))
                 }
                 CowVector::Sparse(v) => {
                     // sort by indices to enable faster dot product and overlap checks
                     v.to_mut().sort_by_indices();
                 }
+                CowVector::MultiDense(v) => {
+                    // preprocess every vector of the matrix separately
+                    let distance = get_vector_data(name).distance;
+                    let multi_vector = v.to_mut();
+                    let dim = multi_vector.dim;
+                    multi_vector.flattened_vectors = multi_vector
+                        .flattened_vectors
+                        .chunks_exact(dim)
+                        .flat_map(|vector| {
+                            distance.preprocess_vector::<VectorElementType>(vector.to_vec())
+                        })
+                        .collect();
+                }
             }
         }
     }
+
+    /// Preprocess a dense vector for the datatype it is going to be stored with
+    pub fn preprocess_dense_vector(
+        dense_vector: DenseVector,
+        config: &VectorDataConfig,
+    ) -> DenseVector {
+        match config.datatype.unwrap_or_default() {
+            VectorStorageDatatype::Float32 => config
+                .distance
+                .preprocess_vector::<VectorElementType>(dense_vector),
+            VectorStorageDatatype::Float16 => config
+                .distance
+                .preprocess_vector::<VectorElementTypeHalf>(dense_vector),
+            VectorStorageDatatype::Uint8 => config
+                .distance
+                .preprocess_vector::<VectorElementTypeByte>(dense_vector),
+        }
+    }
 }
 
 impl<'a> IntoIterator for NamedVectors<'a> {
@@ -0,0 +1,192 @@
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{FloatPayloadType, IntPayloadType, PayloadKeyType, Range};
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl Direction {
+    pub fn as_range_from(&self, from: FloatPayloadType) -> Range {
+        match self {
+            Direction::Asc => Range {
+                gte: Some(from),
+                gt: None,
+                lte: None,
+                lt: None,
+            },
+            Direction::Desc => Range {
+                lte: Some(from),
+                gt: None,
+                gte: None,
+                lt: None,
+            },
+        }
+    }
+
+    /// Bounds of values from `from` in this direction
+    pub fn as_bounds_from<T>(&self, from: T) -> (Bound<T>, Bound<T>) {
+        match self {
+            Direction::Asc => (Bound::Included(from), Bound::Unbounded),
+            Direction::Desc => (Bound::Unbounded, Bound::Included(from)),
+        }
+    }
+}
+
+/// Range of values of the `order_by` key, which should be read from the index
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderByRange {
+    /// Integer bounds are kept as is, large integers are not exactly representable as floats
+    Integer(Bound<IntPayloadType>, Bound<IntPayloadType>),
+    Float(Range),
+}
+
+/// Value to start ordering from
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum StartFrom {
+    Integer(IntPayloadType),
+    Float(FloatPayloadType),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct OrderBy {
+    /// Payload key to order by
+    pub key: PayloadKeyType,
+
+    /// Direction of ordering: `asc` or `desc`. Default is ascending.
+    pub direction: Option<Direction>,
+
+    /// Which payload value to start scrolling from. Default is the lowest value for `asc` and the highest for `desc`
+    pub start_from: Option<StartFrom>,
+}
+
+impl OrderBy {
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_default()
+    }
+
+    /// Range of values, which should be read from the index, to respect `start_from`
+    pub fn as_range(&self) -> OrderByRange {
+        match self.start_from {
+            Some(StartFrom::Integer(start_from)) => {
+                let (start, end) = self.direction().as_bounds_from(start_from);
+                OrderByRange::Integer(start, end)
+            }
+            Some(StartFrom::Float(start_from)) => {
+                OrderByRange::Float(self.direction().as_range_from(start_from))
+            }
+            None => OrderByRange::Float(Range {
+                lt: None,
+                gt: None,
+                gte: None,
+                lte: None,
+            }),
+        }
+    }
+}
+
+/// Value of the `order_by` key for a point
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone)]
+#[serde(untagged)]
+pub enum OrderValue {
+    Int(IntPayloadType),
+    Float(FloatPayloadType),
+}
+
+impl OrderValue {
+    fn as_float(&self) -> FloatPayloadType {
+        match self {
+            OrderValue::Int(i) => *i as FloatPayloadType,
+            OrderValue::Float(f) => *f,
+        }
+    }
+
+    /// Compare two values according to the given direction
+    pub fn cmp_directed(&self, other: &Self, direction: Direction) -> Ordering {
+        match direction {
+            Direction::Asc => self.cmp(other),
+            Direction::Desc => other.cmp(self),
+        }
+    }
+}
+
+impl PartialEq for OrderValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderValue {}
+
+impl PartialOrd for OrderValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (OrderValue::Int(a), OrderValue::Int(b)) => a.cmp(b),
+            (OrderValue::Float(a), OrderValue::Float(b)) => a.total_cmp(b),
+            _ => self.as_float().total_cmp(&other.as_float()),
+        }
+    }
+}
+
+impl From<OrderValue> for StartFrom {
+    fn from(value: OrderValue) -> Self {
+        match value {
+            OrderValue::Int(i) => StartFrom::Integer(i),
+            OrderValue::Float(f) => StartFrom::Float(f),
+        }
+    }
+}
+
+impl From<StartFrom> for OrderValue {
+    fn from(value: StartFrom) -> Self {
+        match value {
+            StartFrom::Integer(i) => OrderValue::Int(i),
+            StartFrom::Float(f) => OrderValue::Float(f),
+        }
+    }
+}
+
+impl From<IntPayloadType> for OrderValue {
+    fn from(value: IntPayloadType) -> Self {
+        OrderValue::Int(value)
+    }
+}
+
+impl From<FloatPayloadType> for OrderValue {
+    fn from(value: FloatPayloadType) -> Self {
+        OrderValue::Float(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_value_ordering() {
+        assert!(OrderValue::Int(1) < OrderValue::Int(2));
+        assert!(OrderValue::Float(1.5) < OrderValue::Int(2));
+        assert!(OrderValue::Int(2) > OrderValue::Float(1.5));
+        assert_eq!(OrderValue::Int(2), OrderValue::Float(2.0));
+        assert_eq!(
+            OrderValue::Int(1).cmp_directed(&OrderValue::Int(2), Direction::Desc),
+            Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+
+use half::slice::HalfFloatSliceExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
+use crate::types::VectorStorageDatatype;
+
+/// Element type of dense vector storages
+///
+/// Vectors are always received and returned as `f32`, storages convert them into the element
+/// type they hold.
+pub trait PrimitiveVectorElement:
+    Copy + Clone + Default + Serialize + DeserializeOwned + Send + Sync + 'static
+{
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]>;
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]>;
+
+    fn datatype() -> VectorStorageDatatype;
+}
+
+impl PrimitiveVectorElement for VectorElementType {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        vector
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        vector
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Float32
+    }
+}
+
+impl PrimitiveVectorElement for VectorElementTypeHalf {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(
+            vector
+                .iter()
+                .map(|&x| VectorElementTypeHalf::from_f32(x))
+                .collect(),
+        )
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(vector.to_f32_vec())
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Float16
+    }
+}
+
+impl PrimitiveVectorElement for VectorElementTypeByte {
+    /// Values are truncated towards zero and saturated to the `0..=255` range
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(vector.iter().map(|&x| x as VectorElementTypeByte).collect())
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(vector.iter().map(|&x| VectorElementType::from(x)).collect())
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Uint8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_conversion_saturates() {
+        let vector = [-1.0, 0.0, 1.7, 254.9, 300.0];
+        let bytes = VectorElementTypeByte::slice_from_float_cow(Cow::Borrowed(&vector));
+        assert_eq!(bytes.as_ref(), &[0, 0, 1, 254, 255]);
+        let floats = VectorElementTypeByte::slice_to_float_cow(bytes);
+        assert_eq!(floats.as_ref(), &[0.0, 0.0, 1.0, 254.0, 255.0]);
+    }
+
+    #[test]
+    fn test_half_conversion_round_trip() {
+        let vector = [-2.5, 0.0, 0.125, 1024.0];
+        let halves = VectorElementTypeHalf::slice_from_float_cow(Cow::Borrowed(&vector));
+        let floats = VectorElementTypeHalf::slice_to_float_cow(halves);
+        assert_eq!(floats.as_ref(), &vector);
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::{DimId, DimWeight};
+
+/// Statistics of sparse vector dimensions, used to weight queries by inverse document frequency
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVectorStatistics {
+    /// Number of vectors the statistics are collected from
+    pub vector_count: usize,
+    /// Number of vectors containing each of the requested dimensions
+    pub document_frequencies: HashMap<DimId, usize>,
+}
+
+impl SparseVectorStatistics {
+    /// Combine statistics collected from disjoint sets of vectors, e.g. from different segments
+    pub fn merge(&mut self, other: &SparseVectorStatistics) {
+        self.vector_count += other.vector_count;
+        for (dim_id, frequency) in &other.document_frequencies {
+            *self.document_frequencies.entry(*dim_id).or_default() += frequency;
+        }
+    }
+
+    /// Inverse document frequency of the dimension, as defined in BM25
+    ///
+    /// Dimensions which are not present in any vector get the highest weight.
+    pub fn idf(&self, dim_id: DimId) -> DimWeight {
+        let frequency = self
+            .document_frequencies
+            .get(&dim_id)
+            .copied()
+            .unwrap_or_default();
+        // Frequency may exceed the number of vectors, if deleted vectors are not yet removed from the index
+        let other_count = self.vector_count.saturating_sub(frequency);
+        ((other_count as DimWeight + 0.5) / (frequency as DimWeight + 0.5)).ln_1p()
+    }
+
+    /// Multiply every weight of the query by the inverse document frequency of its dimension
+    ///
+    /// Scoring is a dot product, so it is equivalent to applying IDF to the matched dimensions.
+    pub fn apply_idf(&self, query: &mut SparseVector) {
+        for (dim_id, weight) in query.indices.iter().zip(query.values.iter_mut()) {
+            *weight *= self.idf(*dim_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idf_statistics_merge() {
+        let mut statistics = SparseVectorStatistics {
+            vector_count: 10,
+            document_frequencies: HashMap::from([(1, 2), (2, 10)]),
+        };
+        statistics.merge(&SparseVectorStatistics {
+            vector_count: 5,
+            document_frequencies: HashMap::from([(1, 3), (3, 1)]),
+        });
+
+        assert_eq!(statistics.vector_count, 15);
+        assert_eq!(
+            statistics.document_frequencies,
+            HashMap::from([(1, 5), (2, 10), (3, 1)])
+        );
+    }
+
+    #[test]
+    fn test_idf_weights() {
+        let statistics = SparseVectorStatistics {
+            vector_count: 100,
+            document_frequencies: HashMap::from([(1, 1), (2, 50), (3, 100), (4, 120)]),
+        };
+
+        // ln(1 + (100 - 50 + 0.5) / (50 + 0.5)) = ln(2)
+        assert!((statistics.idf(2) - 2f32.ln()).abs() < 1e-6);
+
+        // Rare dimensions weight more than frequent ones
+        assert!(statistics.idf(0) > statistics.idf(1));
+        assert!(statistics.idf(1) > statistics.idf(2));
+        assert!(statistics.idf(2) > statistics.idf(3));
+        // Weights stay positive, even if frequency is overestimated
+        assert!(statistics.idf(4) > 0.0);
+        assert!(statistics.idf(3) >= statistics.idf(4));
+
+        let mut query = SparseVector::new(vec![1, 2], vec![1.0, 2.0]).unwrap();
+        statistics.apply_idf(&mut query);
+        assert_eq!(query.indices, vec![1, 2]);
+        assert_eq!(
+            query.values,
+            vec![statistics.idf(1), 2.0 * statistics.idf(2)]
+        );
+    }
+}
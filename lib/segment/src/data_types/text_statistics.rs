@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use common::types::ScoreType;
+
+/// Term frequency saturation of BM25
+pub const BM25_K1: ScoreType = 1.2;
+/// Document length normalization of BM25
+pub const BM25_B: ScoreType = 0.75;
+
+/// Statistics of a full-text indexed field, used to rank documents with BM25
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextStatistics {
+    /// Number of documents the statistics are collected from
+    pub documents_count: usize,
+    /// Total number of tokens in these documents
+    pub total_length: usize,
+    /// Number of documents containing each of the query tokens
+    pub document_frequencies: HashMap<String, usize>,
+}
+
+impl TextStatistics {
+    /// Combine statistics collected from disjoint sets of documents, e.g. from different segments
+    pub fn merge(&mut self, other: &TextStatistics) {
+        self.documents_count += other.documents_count;
+        self.total_length += other.total_length;
+        for (token, frequency) in &other.document_frequencies {
+            *self.document_frequencies.entry(token.clone()).or_default() += frequency;
+        }
+    }
+
+    /// Inverse document frequency of the token, as defined in BM25
+    pub fn idf(&self, token: &str) -> ScoreType {
+        let frequency = self
+            .document_frequencies
+            .get(token)
+            .copied()
+            .unwrap_or_default();
+        // Frequency may exceed the number of documents, if they are collected while being updated
+        let other_count = self.documents_count.saturating_sub(frequency);
+        ((other_count as ScoreType + 0.5) / (frequency as ScoreType + 0.5)).ln_1p()
+    }
+
+    pub fn average_length(&self) -> ScoreType {
+        if self.documents_count == 0 {
+            return 0.0;
+        }
+        self.total_length as ScoreType / self.documents_count as ScoreType
+    }
+
+    /// Contribution of a single query token to the BM25 score of a document
+    ///
+    /// * `idf` - inverse document frequency of the token
+    /// * `frequency` - number of occurrences of the token in the document
+    /// * `document_length` - number of tokens in the document
+    pub fn term_score(&self, idf: ScoreType, frequency: u32, document_length: u32) -> ScoreType {
+        let average_length = self.average_length();
+        let relative_length = if average_length > 0.0 {
+            document_length as ScoreType / average_length
+        } else {
+            1.0
+        };
+        let frequency = frequency as ScoreType;
+        let normalization = BM25_K1 * (1.0 - BM25_B + BM25_B * relative_length);
+        idf * frequency * (BM25_K1 + 1.0) / (frequency + normalization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_statistics_merge() {
+        let mut statistics = TextStatistics {
+            documents_count: 10,
+            total_length: 100,
+            document_frequencies: HashMap::from([("cat".to_string(), 2)]),
+        };
+        statistics.merge(&TextStatistics {
+            documents_count: 5,
+            total_length: 20,
+            document_frequencies: HashMap::from([("cat".to_string(), 1), ("dog".to_string(), 5)]),
+        });
+
+        assert_eq!(statistics.documents_count, 15);
+        assert_eq!(statistics.total_length, 120);
+        assert_eq!(statistics.average_length(), 8.0);
+        assert_eq!(
+            statistics.document_frequencies,
+            HashMap::from([("cat".to_string(), 3), ("dog".to_string(), 5)])
+        );
+    }
+
+    #[test]
+    fn test_bm25_term_score() {
+        let statistics = TextStatistics {
+            documents_count: 100,
+            total_length: 1000,
+            document_frequencies: HashMap::from([
+                ("rare".to_string(), 1),
+                ("common".to_string(), 50),
+            ]),
+        };
+
+        // Rare tokens weight more than common ones
+        assert!(statistics.idf("rare") > statistics.idf("common"));
+
+        let idf = statistics.idf("rare");
+        // A document of average length with a single occurrence gets exactly the IDF
+        assert!((statistics.term_score(idf, 1, 10) - idf).abs() < 1e-6);
+        // More occurrences score higher, but saturate
+        let double = statistics.term_score(idf, 2, 10);
+        assert!(double > idf);
+        assert!(double < 2.0 * idf);
+        assert!(statistics.term_score(idf, 1000, 10) < idf * (BM25_K1 + 1.0));
+        // Shorter documents score higher
+        assert!(statistics.term_score(idf, 1, 5) > statistics.term_score(idf, 1, 20));
+    }
+}
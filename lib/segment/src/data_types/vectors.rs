@@ -17,12 +17,14 @@ use crate::vector_storage::query::reco_query::RecoQuery;
 pub enum Vector {
     Dense(DenseVector),
     Sparse(SparseVector),
+    MultiDense(MultiDenseVector),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum VectorRef<'a> {
     Dense(&'a [VectorElementType]),
     Sparse(&'a SparseVector),
+    MultiDense(MultiDenseVectorRef<'a>),
 }
 
 impl Vector {
@@ -30,6 +32,7 @@ impl Vector {
         match self {
             Vector::Dense(v) => VectorRef::Dense(v.as_slice()),
             Vector::Sparse(v) => VectorRef::Sparse(v),
+            Vector::MultiDense(v) => VectorRef::MultiDense(v.into()),
         }
     }
 }
@@ -39,6 +42,7 @@ impl Validate for Vector {
         match self {
             Vector::Dense(_) => Ok(()),
             Vector::Sparse(v) => v.validate(),
+            Vector::MultiDense(_) => Ok(()),
         }
     }
 }
@@ -48,6 +52,7 @@ impl<'a> VectorRef<'a> {
         match self {
             VectorRef::Dense(v) => Vector::Dense(v.to_vec()),
             VectorRef::Sparse(v) => Vector::Sparse(v.clone()),
+            VectorRef::MultiDense(v) => Vector::MultiDense(v.to_owned()),
         }
     }
 }
@@ -59,6 +64,7 @@ impl<'a> TryFrom<VectorRef<'a>> for &'a [VectorElementType] {
         match value {
             VectorRef::Dense(v) => Ok(v),
             VectorRef::Sparse(_) => Err(OperationError::WrongSparse),
+            VectorRef::MultiDense(_) => Err(OperationError::WrongMulti),
         }
     }
 }
@@ -70,6 +76,18 @@ impl<'a> TryFrom<VectorRef<'a>> for &'a SparseVector {
         match value {
             VectorRef::Dense(_) => Err(OperationError::WrongSparse),
             VectorRef::Sparse(v) => Ok(v),
+            VectorRef::MultiDense(_) => Err(OperationError::WrongSparse),
+        }
+    }
+}
+
+impl<'a> TryFrom<VectorRef<'a>> for MultiDenseVectorRef<'a> {
+    type Error = OperationError;
+
+    fn try_from(value: VectorRef<'a>) -> Result<Self, Self::Error> {
+        match value {
+            VectorRef::Dense(_) | VectorRef::Sparse(_) => Err(OperationError::WrongMulti),
+            VectorRef::MultiDense(v) => Ok(v),
         }
     }
 }
@@ -80,6 +98,7 @@ impl From<NamedVectorStruct> for Vector {
             NamedVectorStruct::Default(v) => Vector::Dense(v),
             NamedVectorStruct::Dense(v) => Vector::Dense(v.vector),
             NamedVectorStruct::Sparse(v) => Vector::Sparse(v.vector),
+            NamedVectorStruct::MultiDense(v) => Vector::MultiDense(v.vector),
         }
     }
 }
@@ -91,6 +110,7 @@ impl TryFrom<Vector> for DenseVector {
         match value {
             Vector::Dense(v) => Ok(v),
             Vector::Sparse(_) => Err(OperationError::WrongSparse),
+            Vector::MultiDense(_) => Err(OperationError::WrongMulti),
         }
     }
 }
@@ -102,6 +122,18 @@ impl TryFrom<Vector> for SparseVector {
         match value {
             Vector::Dense(_) => Err(OperationError::WrongSparse),
             Vector::Sparse(v) => Ok(v),
+            Vector::MultiDense(_) => Err(OperationError::WrongSparse),
+        }
+    }
+}
+
+impl TryFrom<Vector> for MultiDenseVector {
+    type Error = OperationError;
+
+    fn try_from(value: Vector) -> Result<Self, Self::Error> {
+        match value {
+            Vector::Dense(_) | Vector::Sparse(_) => Err(OperationError::WrongMulti),
+            Vector::MultiDense(v) => Ok(v),
         }
     }
 }
@@ -124,6 +156,18 @@ impl<'a> From<&'a SparseVector> for VectorRef<'a> {
     }
 }
 
+impl<'a> From<&'a MultiDenseVector> for VectorRef<'a> {
+    fn from(val: &'a MultiDenseVector) -> Self {
+        VectorRef::MultiDense(val.into())
+    }
+}
+
+impl<'a> From<MultiDenseVectorRef<'a>> for VectorRef<'a> {
+    fn from(val: MultiDenseVectorRef<'a>) -> Self {
+        VectorRef::MultiDense(val)
+    }
+}
+
 impl From<DenseVector> for Vector {
     fn from(val: DenseVector) -> Self {
         Vector::Dense(val)
@@ -136,11 +180,18 @@ impl From<SparseVector> for Vector {
     }
 }
 
+impl From<MultiDenseVector> for Vector {
+    fn from(val: MultiDenseVector) -> Self {
+        Vector::MultiDense(val)
+    }
+}
+
 impl<'a> From<&'a Vector> for VectorRef<'a> {
     fn from(val: &'a Vector) -> Self {
         match val {
             Vector::Dense(v) => VectorRef::Dense(v.as_slice()),
             Vector::Sparse(v) => VectorRef::Sparse(v),
+            Vector::MultiDense(v) => VectorRef::MultiDense(v.into()),
         }
     }
 }
@@ -148,17 +199,129 @@ impl<'a> From<&'a Vector> for VectorRef<'a> {
 /// Type of vector element.
 pub type VectorElementType = f32;
 
+/// Type of vector element in half-precision storages.
+pub type VectorElementTypeHalf = half::f16;
+
+/// Type of vector element in byte storages.
+pub type VectorElementTypeByte = u8;
+
 pub const DEFAULT_VECTOR_NAME: &str = "";
 
 /// Type for dense vector
 pub type DenseVector = Vec<VectorElementType>;
 
+/// Type for dense vector with elements of a storage datatype
+pub type TypedDenseVector<T> = Vec<T>;
+
+/// Multiple dense vectors of the same dimensionality attached to a single point,
+/// e.g. token embeddings of a late interaction model.
+///
+/// Stored as a flattened row-major matrix, serialized as a list of vectors.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<DenseVector>", into = "Vec<DenseVector>")]
+pub struct MultiDenseVector {
+    /// Concatenated vectors, `dim` elements each
+    pub flattened_vectors: DenseVector,
+    /// Dimensionality of each vector
+    pub dim: usize,
+}
+
+impl MultiDenseVector {
+    pub fn new(flattened_vectors: DenseVector, dim: usize) -> Self {
+        debug_assert!(dim > 0 && flattened_vectors.len() % dim == 0);
+        Self {
+            flattened_vectors,
+            dim,
+        }
+    }
+
+    /// Number of vectors in the matrix
+    pub fn vectors_count(&self) -> usize {
+        self.flattened_vectors.len() / self.dim
+    }
+
+    pub fn multi_vectors(&self) -> impl Iterator<Item = &[VectorElementType]> {
+        self.flattened_vectors.chunks_exact(self.dim)
+    }
+}
+
+impl TryFrom<Vec<DenseVector>> for MultiDenseVector {
+    type Error = OperationError;
+
+    fn try_from(vectors: Vec<DenseVector>) -> Result<Self, Self::Error> {
+        let Some(dim) = vectors.first().map(|v| v.len()) else {
+            return Err(OperationError::ValidationError {
+                description: "multi vector must contain at least one vector".to_string(),
+            });
+        };
+        if dim == 0 {
+            return Err(OperationError::ValidationError {
+                description: "vectors of a multi vector must not be empty".to_string(),
+            });
+        }
+        if let Some(vector) = vectors.iter().find(|v| v.len() != dim) {
+            return Err(OperationError::WrongVector {
+                expected_dim: dim,
+                received_dim: vector.len(),
+            });
+        }
+        Ok(Self::new(vectors.into_iter().flatten().collect(), dim))
+    }
+}
+
+impl From<MultiDenseVector> for Vec<DenseVector> {
+    fn from(value: MultiDenseVector) -> Self {
+        value.multi_vectors().map(|v| v.to_vec()).collect()
+    }
+}
+
+impl JsonSchema for MultiDenseVector {
+    fn schema_name() -> String {
+        "MultiDenseVector".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        gen.subschema_for::<Vec<DenseVector>>()
+    }
+}
+
+/// Borrowed flattened matrix of a [`MultiDenseVector`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MultiDenseVectorRef<'a> {
+    pub flattened_vectors: &'a [VectorElementType],
+    pub dim: usize,
+}
+
+impl<'a> MultiDenseVectorRef<'a> {
+    pub fn to_owned(self) -> MultiDenseVector {
+        MultiDenseVector::new(self.flattened_vectors.to_vec(), self.dim)
+    }
+
+    pub fn vectors_count(&self) -> usize {
+        self.flattened_vectors.len() / self.dim
+    }
+
+    pub fn multi_vectors(self) -> impl Iterator<Item = &'a [VectorElementType]> {
+        self.flattened_vectors.chunks_exact(self.dim)
+    }
+}
+
+impl<'a> From<&'a MultiDenseVector> for MultiDenseVectorRef<'a> {
+    fn from(value: &'a MultiDenseVector) -> Self {
+        Self {
+            flattened_vectors: &value.flattened_vectors,
+            dim: value.dim,
+        }
+    }
+}
+
 impl<'a> VectorRef<'a> {
     // Cannot use `ToOwned` trait because of `Borrow` implementation for `Vector`
     pub fn to_owned(self) -> Vector {
         match self {
             VectorRef::Dense(v) => Vector::Dense(v.to_vec()),
             VectorRef::Sparse(v) => Vector::Sparse(v.clone()),
+            VectorRef::MultiDense(v) => Vector::MultiDense(v.to_owned()),
         }
     }
 
@@ -166,6 +329,7 @@ impl<'a> VectorRef<'a> {
         match self {
             VectorRef::Dense(v) => v.len(),
             VectorRef::Sparse(v) => v.indices.len(),
+            VectorRef::MultiDense(v) => v.flattened_vectors.len(),
         }
     }
 
@@ -181,6 +345,7 @@ impl<'a> TryInto<&'a [VectorElementType]> for &'a Vector {
         match self {
             Vector::Dense(v) => Ok(v),
             Vector::Sparse(_) => Err(OperationError::WrongSparse),
+            Vector::MultiDense(_) => Err(OperationError::WrongMulti),
         }
     }
 }
@@ -192,6 +357,18 @@ impl<'a> TryInto<&'a SparseVector> for &'a Vector {
         match self {
             Vector::Dense(_) => Err(OperationError::WrongSparse),
             Vector::Sparse(v) => Ok(v),
+            Vector::MultiDense(_) => Err(OperationError::WrongSparse),
+        }
+    }
+}
+
+impl<'a> TryInto<&'a MultiDenseVector> for &'a Vector {
+    type Error = OperationError;
+
+    fn try_into(self) -> Result<&'a MultiDenseVector, Self::Error> {
+        match self {
+            Vector::Dense(_) | Vector::Sparse(_) => Err(OperationError::WrongMulti),
+            Vector::MultiDense(v) => Ok(v),
         }
     }
 }
@@ -220,6 +397,7 @@ impl VectorStruct {
             VectorStruct::Multi(vectors) => vectors.values().all(|v| match v {
                 Vector::Dense(vector) => vector.is_empty(),
                 Vector::Sparse(vector) => vector.indices.is_empty(),
+                Vector::MultiDense(vector) => vector.flattened_vectors.is_empty(),
             }),
         }
     }
@@ -294,6 +472,16 @@ pub struct NamedSparseVector {
     pub vector: SparseVector,
 }
 
+/// Multi vector data with name
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct NamedMultiDenseVector {
+    /// Name of vector data
+    pub name: String,
+    /// Vector data
+    pub vector: MultiDenseVector,
+}
+
 /// Vector data separator for named and unnamed modes
 /// Unnamed mode:
 ///
@@ -316,6 +504,7 @@ pub enum NamedVectorStruct {
     Default(DenseVector),
     Dense(NamedVector),
     Sparse(NamedSparseVector),
+    MultiDense(NamedMultiDenseVector),
 }
 
 impl From<DenseVector> for NamedVectorStruct {
@@ -336,6 +525,12 @@ impl From<NamedSparseVector> for NamedVectorStruct {
     }
 }
 
+impl From<NamedMultiDenseVector> for NamedVectorStruct {
+    fn from(v: NamedMultiDenseVector) -> Self {
+        NamedVectorStruct::MultiDense(v)
+    }
+}
+
 pub trait Named {
     fn get_name(&self) -> &str;
 }
@@ -346,6 +541,7 @@ impl Named for NamedVectorStruct {
             NamedVectorStruct::Default(_) => DEFAULT_VECTOR_NAME,
             NamedVectorStruct::Dense(v) => &v.name,
             NamedVectorStruct::Sparse(v) => &v.name,
+            NamedVectorStruct::MultiDense(v) => &v.name,
         }
     }
 }
@@ -355,6 +551,9 @@ impl NamedVectorStruct {
         match vector {
             Vector::Dense(vector) => NamedVectorStruct::Dense(NamedVector { name, vector }),
             Vector::Sparse(vector) => NamedVectorStruct::Sparse(NamedSparseVector { name, vector }),
+            Vector::MultiDense(vector) => {
+                NamedVectorStruct::MultiDense(NamedMultiDenseVector { name, vector })
+            }
         }
     }
 
@@ -363,6 +562,7 @@ impl NamedVectorStruct {
             NamedVectorStruct::Default(v) => v.as_slice().into(),
             NamedVectorStruct::Dense(v) => v.vector.as_slice().into(),
             NamedVectorStruct::Sparse(v) => (&v.vector).into(),
+            NamedVectorStruct::MultiDense(v) => (&v.vector).into(),
         }
     }
 
@@ -371,6 +571,7 @@ impl NamedVectorStruct {
             NamedVectorStruct::Default(v) => v.into(),
             NamedVectorStruct::Dense(v) => v.vector.into(),
             NamedVectorStruct::Sparse(v) => v.vector.into(),
+            NamedVectorStruct::MultiDense(v) => v.vector.into(),
         }
     }
 }
@@ -381,6 +582,7 @@ impl Validate for NamedVectorStruct {
             NamedVectorStruct::Default(_) => Ok(()),
             NamedVectorStruct::Dense(_) => Ok(()),
             NamedVectorStruct::Sparse(v) => v.validate(),
+            NamedVectorStruct::MultiDense(_) => Ok(()),
         }
     }
 }
@@ -487,3 +689,9 @@ impl From<SparseVector> for QueryVector {
         Self::Nearest(Vector::Sparse(vec))
     }
 }
+
+impl From<MultiDenseVector> for QueryVector {
+    fn from(vec: MultiDenseVector) -> Self {
+        Self::Nearest(Vector::MultiDense(vec))
+    }
+}
@@ -2,8 +2,14 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 
+use sparse::common::types::DimId;
+
 use crate::common::operation_error::{OperationResult, SegmentFailedState};
+use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
+use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::sparse_statistics::SparseVectorStatistics;
+use crate::data_types::text_statistics::TextStatistics;
 use crate::data_types::vectors::{QueryVector, Vector};
 use crate::index::field_index::CardinalityEstimation;
 use crate::telemetry::SegmentTelemetry;
@@ -121,6 +127,47 @@ pub trait SegmentEntry {
         filter: Option<&'a Filter>,
     ) -> Vec<PointIdType>;
 
+    /// Read a uniform random sample of at most `limit` points which satisfy filtering condition.
+    fn read_random_filtered(&self, limit: usize, filter: Option<&Filter>) -> Vec<PointIdType>;
+
+    /// Read points which satisfy filtering condition, ordered by the `order_by` payload key.
+    ///
+    /// `offset` is used as a tiebreaker: points with a value equal to `order_by.start_from` and id
+    /// lower than `offset` are skipped.
+    /// Result is sorted by value in the requested direction, then by id.
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+        offset: Option<PointIdType>,
+    ) -> OperationResult<Vec<(OrderValue, PointIdType)>>;
+
+    /// Count points per value of the payload key, which satisfy filtering condition
+    ///
+    /// If the request is not exact, only the top `limit` values are returned.
+    fn facet(
+        &self,
+        request: &FacetParams,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<HashMap<FacetValue, usize>>;
+
+    /// Collect statistics of the query tokens in the full-text index of the payload key
+    fn text_statistics(&self, key: &PayloadKeyType, text: &str) -> OperationResult<TextStatistics>;
+
+    /// Rank points which satisfy filtering condition by the BM25 score of the query text in the
+    /// full-text index of the payload key
+    ///
+    /// `statistics` are usually collected from several segments, so their scores are comparable.
+    fn text_search(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        statistics: &TextStatistics,
+        filter: Option<&Filter>,
+        top: usize,
+    ) -> OperationResult<Vec<ScoredPoint>>;
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
 
@@ -134,6 +181,13 @@ pub trait SegmentEntry {
 
     fn vector_dims(&self) -> HashMap<String, usize>;
 
+    /// Collect document frequencies of the given dimensions of a sparse vector
+    fn sparse_vector_statistics(
+        &self,
+        vector_name: &str,
+        indices: &[DimId],
+    ) -> OperationResult<SparseVectorStatistics>;
+
     /// Number of available points
     ///
     /// - excludes soft deleted points
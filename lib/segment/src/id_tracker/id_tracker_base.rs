@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use bitvec::prelude::BitSlice;
 use common::types::PointOffsetType;
 use rand::rngs::StdRng;
@@ -87,6 +89,11 @@ pub trait IdTracker {
     /// Flush points versions to disk
     fn versions_flusher(&self) -> Flusher;
 
+    /// Files, which store the tracker outside of the segment database
+    fn files(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
     /// Number of total points
     ///
     /// - includes soft deleted points
@@ -0,0 +1,436 @@
+use std::path::{Path, PathBuf};
+
+use bitvec::prelude::{BitSlice, BitVec};
+use common::types::PointOffsetType;
+use uuid::Uuid;
+
+use crate::common::mmap_type::{MmapBitSlice, MmapSlice};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::id_tracker::{IdTracker, IdTrackerSS};
+use crate::index::field_index::mmap_lists::{
+    create_mmap_bitslice, create_mmap_slice, open_mmap_bitslice, open_mmap_slice,
+};
+use crate::types::{PointIdType, SeqNumberType};
+
+const EXTERNAL_NUM_IDS_FILE: &str = "external_num_ids.dat";
+const EXTERNAL_UUIDS_FILE: &str = "external_uuids.dat";
+const EXTERNAL_TO_INTERNAL_FILE: &str = "external_to_internal.dat";
+const INTERNAL_TO_EXTERNAL_FILE: &str = "internal_to_external.dat";
+const VERSIONS_FILE: &str = "versions.dat";
+const DELETED_FILE: &str = "deleted.dat";
+
+/// Position of an internal id, which has no external id
+const NO_POSITION: u32 = u32::MAX;
+
+/// Id tracker of immutable segments, which keeps the mappings in memory mapped files
+///
+/// External ids are stored sorted, numeric ids followed by UUIDs, so lookups and iteration from
+/// an offset are binary searches over the mapped files instead of in-memory maps.
+///
+/// Mappings are written once by [`ImmutableIdTracker::create`] and can't be extended afterwards.
+/// Points can only be removed, and versions of the existing points can be updated.
+pub struct ImmutableIdTracker {
+    path: PathBuf,
+    /// Sorted numeric external ids
+    external_num_ids: MmapSlice<u64>,
+    /// Sorted UUID external ids, as `u128` which keeps the ordering of [`Uuid`]
+    external_uuids: MmapSlice<u128>,
+    /// Internal id for each position in the sorted external ids, numeric ids first
+    external_to_internal: MmapSlice<PointOffsetType>,
+    /// Position in the sorted external ids for each internal id
+    internal_to_external: MmapSlice<u32>,
+    internal_to_version: MmapSlice<SeqNumberType>,
+    deleted: MmapBitSlice,
+    deleted_count: usize,
+}
+
+impl ImmutableIdTracker {
+    /// Whether there is a tracker stored in the `path` directory
+    pub fn exists(path: &Path) -> bool {
+        path.join(DELETED_FILE).exists()
+    }
+
+    /// Persist all mappings and versions of the `id_tracker` into the `path` directory
+    pub fn create(path: &Path, id_tracker: &IdTrackerSS) -> OperationResult<()> {
+        std::fs::create_dir_all(path)?;
+
+        let total_point_count = id_tracker.total_point_count();
+
+        let mut external_num_ids = vec![];
+        let mut external_uuids = vec![];
+        let mut external_to_internal = vec![];
+        let mut internal_to_external = vec![NO_POSITION; total_point_count];
+        // `iter_from` yields numeric ids first, in order, so positions match the stored layout
+        for (external_id, internal_id) in id_tracker.iter_from(None) {
+            match external_id {
+                PointIdType::NumId(idx) => external_num_ids.push(idx),
+                PointIdType::Uuid(uuid) => external_uuids.push(uuid.as_u128()),
+            }
+            let position = external_to_internal.len() as u32;
+            internal_to_external[internal_id as usize] = position;
+            external_to_internal.push(internal_id);
+        }
+
+        let internal_to_version: Vec<SeqNumberType> = (0..total_point_count as PointOffsetType)
+            .map(|internal_id| id_tracker.internal_version(internal_id).unwrap_or(0))
+            .collect();
+        let deleted: BitVec = internal_to_external
+            .iter()
+            .map(|&position| position == NO_POSITION)
+            .collect();
+
+        create_mmap_slice(&path.join(EXTERNAL_NUM_IDS_FILE), &external_num_ids)?;
+        create_mmap_slice(&path.join(EXTERNAL_UUIDS_FILE), &external_uuids)?;
+        create_mmap_slice(&path.join(EXTERNAL_TO_INTERNAL_FILE), &external_to_internal)?;
+        create_mmap_slice(&path.join(INTERNAL_TO_EXTERNAL_FILE), &internal_to_external)?;
+        create_mmap_slice(&path.join(VERSIONS_FILE), &internal_to_version)?;
+        // Written last, its presence marks a complete tracker
+        create_mmap_bitslice(&path.join(DELETED_FILE), &deleted)
+    }
+
+    pub fn open(path: &Path) -> OperationResult<Self> {
+        let internal_to_external: MmapSlice<u32> =
+            open_mmap_slice(&path.join(INTERNAL_TO_EXTERNAL_FILE))?;
+        let deleted = open_mmap_bitslice(&path.join(DELETED_FILE))?;
+
+        let total_point_count = internal_to_external.len();
+        if deleted.len() < total_point_count {
+            return Err(OperationError::service_error(format!(
+                "Id tracker {} is corrupted: {} deletion flags for {total_point_count} points",
+                path.display(),
+                deleted.len(),
+            )));
+        }
+        let deleted_count = deleted[..total_point_count].count_ones();
+
+        Ok(Self {
+            path: path.to_owned(),
+            external_num_ids: open_mmap_slice(&path.join(EXTERNAL_NUM_IDS_FILE))?,
+            external_uuids: open_mmap_slice(&path.join(EXTERNAL_UUIDS_FILE))?,
+            external_to_internal: open_mmap_slice(&path.join(EXTERNAL_TO_INTERNAL_FILE))?,
+            internal_to_external,
+            internal_to_version: open_mmap_slice(&path.join(VERSIONS_FILE))?,
+            deleted,
+            deleted_count,
+        })
+    }
+
+    /// Position of the external id in the sorted external ids, if it is stored
+    fn position(&self, external_id: PointIdType) -> Option<usize> {
+        match external_id {
+            PointIdType::NumId(idx) => self.external_num_ids.binary_search(&idx).ok(),
+            PointIdType::Uuid(uuid) => self
+                .external_uuids
+                .binary_search(&uuid.as_u128())
+                .ok()
+                .map(|position| self.external_num_ids.len() + position),
+        }
+    }
+
+    /// First position with an external id, which is not less than the given one
+    fn lower_bound(&self, external_id: PointIdType) -> usize {
+        match external_id {
+            PointIdType::NumId(idx) => self.external_num_ids.partition_point(|&id| id < idx),
+            PointIdType::Uuid(uuid) => {
+                let uuid = uuid.as_u128();
+                self.external_num_ids.len() + self.external_uuids.partition_point(|&id| id < uuid)
+            }
+        }
+    }
+
+    fn external_id_at(&self, position: usize) -> PointIdType {
+        let num_ids_count = self.external_num_ids.len();
+        if position < num_ids_count {
+            PointIdType::NumId(self.external_num_ids[position])
+        } else {
+            PointIdType::Uuid(Uuid::from_u128(
+                self.external_uuids[position - num_ids_count],
+            ))
+        }
+    }
+
+    /// Iterate over the not deleted points, starting from the `start` position of external ids
+    fn iter_positions_from(
+        &self,
+        start: usize,
+    ) -> impl Iterator<Item = (PointIdType, PointOffsetType)> + '_ {
+        self.external_to_internal[start.min(self.external_to_internal.len())..]
+            .iter()
+            .enumerate()
+            .filter(|(_, internal_id)| !self.is_deleted_point(**internal_id))
+            .map(move |(offset, &internal_id)| (self.external_id_at(start + offset), internal_id))
+    }
+}
+
+impl IdTracker for ImmutableIdTracker {
+    fn internal_version(&self, internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        self.internal_to_version.get(internal_id as usize).copied()
+    }
+
+    fn set_internal_version(
+        &mut self,
+        internal_id: PointOffsetType,
+        version: SeqNumberType,
+    ) -> OperationResult<()> {
+        if !self.is_deleted_point(internal_id) {
+            self.internal_to_version[internal_id as usize] = version;
+        }
+        Ok(())
+    }
+
+    fn internal_id(&self, external_id: PointIdType) -> Option<PointOffsetType> {
+        let internal_id = self.external_to_internal[self.position(external_id)?];
+        (!self.is_deleted_point(internal_id)).then_some(internal_id)
+    }
+
+    fn external_id(&self, internal_id: PointOffsetType) -> Option<PointIdType> {
+        if self.is_deleted_point(internal_id) {
+            return None;
+        }
+        let position = self.internal_to_external[internal_id as usize];
+        Some(self.external_id_at(position as usize))
+    }
+
+    fn set_link(
+        &mut self,
+        external_id: PointIdType,
+        internal_id: PointOffsetType,
+    ) -> OperationResult<()> {
+        if self.internal_id(external_id) == Some(internal_id) {
+            return Ok(());
+        }
+        Err(OperationError::service_error(format!(
+            "Can't link point {external_id} to internal id {internal_id}: id tracker is immutable",
+        )))
+    }
+
+    fn drop(&mut self, external_id: PointIdType) -> OperationResult<()> {
+        if let Some(internal_id) = self.internal_id(external_id) {
+            self.deleted.set(internal_id as usize, true);
+            self.deleted_count += 1;
+        }
+        Ok(())
+    }
+
+    fn iter_external(&self) -> Box<dyn Iterator<Item = PointIdType> + '_> {
+        Box::new(
+            self.iter_positions_from(0)
+                .map(|(external_id, _)| external_id),
+        )
+    }
+
+    fn iter_internal(&self) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        Box::new(
+            (0..self.total_point_count() as PointOffsetType)
+                .filter(move |&internal_id| !self.is_deleted_point(internal_id)),
+        )
+    }
+
+    fn iter_from(
+        &self,
+        external_id: Option<PointIdType>,
+    ) -> Box<dyn Iterator<Item = (PointIdType, PointOffsetType)> + '_> {
+        let start = external_id.map_or(0, |external_id| self.lower_bound(external_id));
+        Box::new(self.iter_positions_from(start))
+    }
+
+    fn iter_ids(&self) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        self.iter_internal()
+    }
+
+    /// Creates a flusher function, that persists the removed points.
+    fn mapping_flusher(&self) -> Flusher {
+        self.deleted.flusher()
+    }
+
+    /// Creates a flusher function, that persists the updated versions.
+    fn versions_flusher(&self) -> Flusher {
+        self.internal_to_version.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        [
+            EXTERNAL_NUM_IDS_FILE,
+            EXTERNAL_UUIDS_FILE,
+            EXTERNAL_TO_INTERNAL_FILE,
+            INTERNAL_TO_EXTERNAL_FILE,
+            VERSIONS_FILE,
+            DELETED_FILE,
+        ]
+        .into_iter()
+        .map(|file| self.path.join(file))
+        .collect()
+    }
+
+    fn total_point_count(&self) -> usize {
+        self.internal_to_external.len()
+    }
+
+    fn deleted_point_count(&self) -> usize {
+        self.deleted_count
+    }
+
+    fn deleted_point_bitslice(&self) -> &BitSlice {
+        &self.deleted[..self.total_point_count()]
+    }
+
+    fn is_deleted_point(&self, internal_id: PointOffsetType) -> bool {
+        let internal_id = internal_id as usize;
+        if internal_id >= self.total_point_count() {
+            return true;
+        }
+        self.deleted[internal_id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+    use crate::id_tracker::simple_id_tracker::SimpleIdTracker;
+
+    fn make_trackers(dir: &Path, ids: &[PointIdType]) -> (SimpleIdTracker, ImmutableIdTracker) {
+        let db = open_db(dir, &[DB_VECTOR_CF]).unwrap();
+        let mut simple = SimpleIdTracker::open(db).unwrap();
+        for (internal_id, &external_id) in ids.iter().enumerate() {
+            let internal_id = internal_id as PointOffsetType;
+            simple.set_link(external_id, internal_id).unwrap();
+            simple
+                .set_internal_version(internal_id, internal_id as SeqNumberType * 10)
+                .unwrap();
+        }
+        // Leave a hole in the internal ids
+        simple.drop(ids[3]).unwrap();
+
+        let path = dir.join("id_tracker");
+        ImmutableIdTracker::create(&path, &simple).unwrap();
+        assert!(ImmutableIdTracker::exists(&path));
+        let immutable = ImmutableIdTracker::open(&path).unwrap();
+        (simple, immutable)
+    }
+
+    fn test_ids() -> Vec<PointIdType> {
+        vec![
+            200.into(),
+            PointIdType::Uuid(Uuid::from_u128(123_u128)),
+            100.into(),
+            150.into(),
+            PointIdType::Uuid(Uuid::from_u128(u128::MAX - 5)),
+            120.into(),
+            PointIdType::Uuid(Uuid::from_u128(12_u128)),
+            180.into(),
+        ]
+    }
+
+    #[test]
+    fn test_same_as_simple_tracker() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let ids = test_ids();
+        let (simple, immutable) = make_trackers(dir.path(), &ids);
+
+        assert_eq!(immutable.total_point_count(), simple.total_point_count());
+        assert_eq!(
+            immutable.available_point_count(),
+            simple.available_point_count()
+        );
+        assert_eq!(immutable.deleted_point_count(), 1);
+
+        for &external_id in &ids {
+            assert_eq!(
+                immutable.internal_id(external_id),
+                simple.internal_id(external_id)
+            );
+        }
+        assert_eq!(immutable.internal_id(999.into()), None);
+
+        for internal_id in 0..ids.len() as PointOffsetType + 2 {
+            assert_eq!(
+                immutable.external_id(internal_id),
+                simple.external_id(internal_id)
+            );
+            assert_eq!(
+                immutable.is_deleted_point(internal_id),
+                simple.is_deleted_point(internal_id),
+            );
+            if !simple.is_deleted_point(internal_id) {
+                assert_eq!(
+                    immutable.internal_version(internal_id),
+                    simple.internal_version(internal_id),
+                );
+            }
+        }
+
+        assert_eq!(
+            immutable.iter_external().collect_vec(),
+            simple.iter_external().collect_vec(),
+        );
+        assert_eq!(
+            immutable.iter_ids().collect_vec(),
+            simple.iter_ids().collect_vec(),
+        );
+        for offset in [
+            None,
+            Some(0.into()),
+            Some(130.into()),
+            Some(150.into()),
+            Some(u64::MAX.into()),
+            Some(PointIdType::Uuid(Uuid::from_u128(100_u128))),
+            Some(PointIdType::Uuid(Uuid::from_u128(u128::MAX))),
+        ] {
+            assert_eq!(
+                immutable.iter_from(offset).collect_vec(),
+                simple.iter_from(offset).collect_vec(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_delete_and_reopen() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let ids = test_ids();
+        let (_, mut immutable) = make_trackers(dir.path(), &ids);
+
+        assert!(immutable.set_link(ids[0], 0).is_ok());
+        assert!(immutable.set_link(1000.into(), 8).is_err());
+
+        immutable.drop(ids[0]).unwrap();
+        immutable.drop(ids[4]).unwrap();
+        immutable.set_internal_version(1, 1000).unwrap();
+        assert_eq!(immutable.internal_id(ids[0]), None);
+        assert_eq!(immutable.external_id(4), None);
+        assert_eq!(immutable.deleted_point_count(), 3);
+
+        immutable.mapping_flusher()().unwrap();
+        immutable.versions_flusher()().unwrap();
+        drop(immutable);
+
+        let immutable = ImmutableIdTracker::open(&dir.path().join("id_tracker")).unwrap();
+        assert_eq!(immutable.deleted_point_count(), 3);
+        assert_eq!(immutable.available_point_count(), ids.len() - 3);
+        assert_eq!(immutable.internal_id(ids[4]), None);
+        assert_eq!(immutable.internal_id(ids[1]), Some(1));
+        assert_eq!(immutable.internal_version(1), Some(1000));
+        assert!(!immutable.iter_external().contains(&ids[0]));
+        assert_eq!(immutable.iter_ids().count(), ids.len() - 3);
+    }
+
+    #[test]
+    fn test_empty_tracker() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let simple = SimpleIdTracker::open(db).unwrap();
+
+        let path = dir.path().join("id_tracker");
+        ImmutableIdTracker::create(&path, &simple).unwrap();
+        let immutable = ImmutableIdTracker::open(&path).unwrap();
+
+        assert_eq!(immutable.total_point_count(), 0);
+        assert_eq!(immutable.iter_from(None).count(), 0);
+        assert_eq!(immutable.internal_id(1.into()), None);
+        assert!(immutable.is_deleted_point(0));
+    }
+}
@@ -1,10 +1,13 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
+use itertools::Either;
 use parking_lot::RwLock;
 use rocksdb::DB;
 
 use self::memory::{BinaryItem, BinaryMemory};
+use self::mmap::BinaryMmap;
 use super::{CardinalityEstimation, PayloadFieldIndex, PrimaryCondition, ValueIndexer};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
@@ -12,6 +15,7 @@ use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{FieldCondition, Match, MatchValue, PayloadKeyType, ValueVariants};
 
 mod memory {
+    use bitvec::slice::BitSlice;
     use bitvec::vec::BitVec;
     use common::types::PointOffsetType;
 
@@ -156,22 +160,157 @@ mod memory {
         pub fn iter_has_false(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
             self.falses.iter_ones().map(|v| v as PointOffsetType)
         }
+
+        pub fn trues(&self) -> &BitSlice {
+            &self.trues
+        }
+
+        pub fn falses(&self) -> &BitSlice {
+            &self.falses
+        }
+    }
+}
+
+mod mmap {
+    use std::fs::create_dir_all;
+    use std::path::{Path, PathBuf};
+
+    use common::types::PointOffsetType;
+
+    use super::memory::{BinaryItem, BinaryMemory};
+    use crate::common::mmap_type::MmapBitSlice;
+    use crate::common::operation_error::OperationResult;
+    use crate::common::Flusher;
+    use crate::index::field_index::mmap_lists::{create_mmap_bitslice, open_mmap_bitslice};
+
+    const TRUES_PATH: &str = "trues.dat";
+    const FALSES_PATH: &str = "falses.dat";
+
+    /// Same flags as [`BinaryMemory`], stored in memory mapped files
+    pub struct BinaryMmap {
+        dir: PathBuf,
+        trues: MmapBitSlice,
+        falses: MmapBitSlice,
+        trues_count: usize,
+        falses_count: usize,
+        indexed_count: usize,
+    }
+
+    impl BinaryMmap {
+        pub fn create(dir: &Path, memory: &BinaryMemory) -> OperationResult<()> {
+            create_dir_all(dir)?;
+            create_mmap_bitslice(&dir.join(TRUES_PATH), memory.trues())?;
+            create_mmap_bitslice(&dir.join(FALSES_PATH), memory.falses())
+        }
+
+        pub fn open(dir: &Path) -> OperationResult<Self> {
+            let trues = open_mmap_bitslice(&dir.join(TRUES_PATH))?;
+            let falses = open_mmap_bitslice(&dir.join(FALSES_PATH))?;
+            let trues_count = trues.count_ones();
+            let falses_count = falses.count_ones();
+            let indexed_count = trues
+                .iter()
+                .zip(falses.iter())
+                .filter(|(has_true, has_false)| **has_true || **has_false)
+                .count();
+            Ok(Self {
+                dir: dir.to_owned(),
+                trues,
+                falses,
+                trues_count,
+                falses_count,
+                indexed_count,
+            })
+        }
+
+        pub fn get(&self, id: PointOffsetType) -> BinaryItem {
+            let has_true = self.trues.get(id as usize).map(|v| *v).unwrap_or(false);
+            let has_false = self.falses.get(id as usize).map(|v| *v).unwrap_or(false);
+
+            BinaryItem::from_bools(has_true, has_false)
+        }
+
+        /// Removes the point from the index. If the index is not within bounds, does nothing
+        pub fn remove(&mut self, id: PointOffsetType) {
+            if (id as usize) >= self.trues.len() {
+                return;
+            }
+
+            let had_true = self.trues.replace(id as usize, false);
+            let had_false = self.falses.replace(id as usize, false);
+
+            if had_true {
+                self.trues_count -= 1;
+            }
+            if had_false {
+                self.falses_count -= 1;
+            }
+
+            if had_false || had_true {
+                self.indexed_count -= 1;
+            }
+        }
+
+        pub fn trues_count(&self) -> usize {
+            self.trues_count
+        }
+
+        pub fn falses_count(&self) -> usize {
+            self.falses_count
+        }
+
+        pub fn indexed_count(&self) -> usize {
+            self.indexed_count
+        }
+
+        pub fn iter_has_true(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+            self.trues.iter_ones().map(|v| v as PointOffsetType)
+        }
+
+        pub fn iter_has_false(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+            self.falses.iter_ones().map(|v| v as PointOffsetType)
+        }
+
+        pub fn flusher(&self) -> Flusher {
+            let trues_flusher = self.trues.flusher();
+            let falses_flusher = self.falses.flusher();
+            Box::new(move || {
+                trues_flusher()?;
+                falses_flusher()
+            })
+        }
+
+        pub fn files(&self) -> Vec<PathBuf> {
+            vec![self.dir.join(TRUES_PATH), self.dir.join(FALSES_PATH)]
+        }
+
+        pub fn clear(self) -> OperationResult<()> {
+            let dir = self.dir.clone();
+            drop(self);
+            std::fs::remove_dir_all(dir)?;
+            Ok(())
+        }
     }
 }
 
-pub struct BinaryIndex {
+pub struct MutableBinaryIndex {
     memory: BinaryMemory,
     db_wrapper: DatabaseColumnWrapper,
 }
 
+pub enum BinaryIndex {
+    Mutable(MutableBinaryIndex),
+    Mmap(BinaryMmap),
+}
+
 impl BinaryIndex {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str) -> BinaryIndex {
         let store_cf_name = Self::storage_cf_name(field_name);
         let db_wrapper = DatabaseColumnWrapper::new(db, &store_cf_name);
-        Self {
+        BinaryIndex::Mutable(MutableBinaryIndex {
             memory: BinaryMemory::new(),
             db_wrapper,
-        }
+        })
     }
 
     fn storage_cf_name(field: &str) -> String {
@@ -179,20 +318,90 @@ impl BinaryIndex {
     }
 
     pub fn recreate(&self) -> OperationResult<()> {
-        self.db_wrapper.recreate_column_family()
+        match self {
+            BinaryIndex::Mutable(index) => index.db_wrapper.recreate_column_family(),
+            BinaryIndex::Mmap(_) => Ok(()),
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        match self {
+            BinaryIndex::Mutable(index) => BinaryMmap::create(dir, &index.memory),
+            BinaryIndex::Mmap(_) => Err(OperationError::service_error(
+                "Only mutable binary index can be saved on disk",
+            )),
+        }
+    }
+
+    /// Replace the index with the memory mapped one, saved in the directory with [`Self::save_mmap`]
+    pub fn into_mmap(self, dir: &Path) -> OperationResult<Self> {
+        if let BinaryIndex::Mutable(index) = &self {
+            index.db_wrapper.remove_column_family()?;
+        }
+        Ok(BinaryIndex::Mmap(BinaryMmap::open(dir)?))
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            BinaryIndex::Mutable(_) => vec![],
+            BinaryIndex::Mmap(index) => index.files(),
+        }
+    }
+
+    fn get(&self, point_id: PointOffsetType) -> BinaryItem {
+        match self {
+            BinaryIndex::Mutable(index) => index.memory.get(point_id),
+            BinaryIndex::Mmap(index) => index.get(point_id),
+        }
+    }
+
+    fn trues_count(&self) -> usize {
+        match self {
+            BinaryIndex::Mutable(index) => index.memory.trues_count(),
+            BinaryIndex::Mmap(index) => index.trues_count(),
+        }
+    }
+
+    fn falses_count(&self) -> usize {
+        match self {
+            BinaryIndex::Mutable(index) => index.memory.falses_count(),
+            BinaryIndex::Mmap(index) => index.falses_count(),
+        }
+    }
+
+    fn indexed_count(&self) -> usize {
+        match self {
+            BinaryIndex::Mutable(index) => index.memory.indexed_count(),
+            BinaryIndex::Mmap(index) => index.indexed_count(),
+        }
+    }
+
+    fn iter_has_true(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+        match self {
+            BinaryIndex::Mutable(index) => Either::Left(index.memory.iter_has_true()),
+            BinaryIndex::Mmap(index) => Either::Right(index.iter_has_true()),
+        }
+    }
+
+    fn iter_has_false(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+        match self {
+            BinaryIndex::Mutable(index) => Either::Left(index.memory.iter_has_false()),
+            BinaryIndex::Mmap(index) => Either::Right(index.iter_has_false()),
+        }
     }
 
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
-            points_count: self.memory.indexed_count(),
-            points_values_count: self.memory.trues_count() + self.memory.falses_count(),
+            points_count: self.indexed_count(),
+            points_values_count: self.trues_count() + self.falses_count(),
             histogram_bucket_size: None,
         }
     }
 
     pub fn values_count(&self, point_id: PointOffsetType) -> usize {
-        let binary_item = self.memory.get(point_id);
+        let binary_item = self.get(point_id);
         binary_item.has_true() as usize + binary_item.has_false() as usize
     }
 
@@ -202,38 +411,49 @@ impl BinaryIndex {
 
     /// Check if the point has a true value
     pub fn values_has_true(&self, point_id: PointOffsetType) -> bool {
-        self.memory.get(point_id).has_true()
+        self.get(point_id).has_true()
     }
 
     /// Check if the point has a false value
     pub fn values_has_false(&self, point_id: PointOffsetType) -> bool {
-        self.memory.get(point_id).has_false()
+        self.get(point_id).has_false()
     }
 }
 
 impl PayloadFieldIndex for BinaryIndex {
     fn load(&mut self) -> OperationResult<bool> {
-        if !self.db_wrapper.has_column_family()? {
+        let index = match self {
+            BinaryIndex::Mutable(index) => index,
+            BinaryIndex::Mmap(_) => return Ok(true),
+        };
+
+        if !index.db_wrapper.has_column_family()? {
             return Ok(false);
         }
 
-        for (key, value) in self.db_wrapper.lock_db().iter()? {
+        for (key, value) in index.db_wrapper.lock_db().iter()? {
             let idx = PointOffsetType::from_be_bytes(key.as_ref().try_into().unwrap());
 
             debug_assert_eq!(value.len(), 1);
 
             let item = BinaryItem::from(value[0]);
-            self.memory.set_or_insert(idx, &item);
+            index.memory.set_or_insert(idx, &item);
         }
         Ok(true)
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.db_wrapper.remove_column_family()
+        match self {
+            BinaryIndex::Mutable(index) => index.db_wrapper.remove_column_family(),
+            BinaryIndex::Mmap(index) => index.clear(),
+        }
     }
 
     fn flusher(&self) -> crate::common::Flusher {
-        self.db_wrapper.flusher()
+        match self {
+            BinaryIndex::Mutable(index) => index.db_wrapper.flusher(),
+            BinaryIndex::Mmap(index) => index.flusher(),
+        }
     }
 
     fn filter<'a>(
@@ -245,9 +465,9 @@ impl PayloadFieldIndex for BinaryIndex {
                 value: ValueVariants::Bool(value),
             })) => {
                 if *value {
-                    Ok(Box::new(self.memory.iter_has_true()))
+                    Ok(Box::new(self.iter_has_true()))
                 } else {
-                    Ok(Box::new(self.memory.iter_has_false()))
+                    Ok(Box::new(self.iter_has_false()))
                 }
             }
             _ => Err(OperationError::service_error("failed to filter")),
@@ -263,9 +483,9 @@ impl PayloadFieldIndex for BinaryIndex {
                 value: ValueVariants::Bool(value),
             })) => {
                 let count = if *value {
-                    self.memory.trues_count()
+                    self.trues_count()
                 } else {
-                    self.memory.falses_count()
+                    self.falses_count()
                 };
 
                 let estimation = CardinalityEstimation::exact(count)
@@ -302,8 +522,8 @@ impl PayloadFieldIndex for BinaryIndex {
 
         // just two possible blocks: true and false
         let iter = [
-            make_block(self.memory.trues_count(), true, key.clone()),
-            make_block(self.memory.falses_count(), false, key),
+            make_block(self.trues_count(), true, key.clone()),
+            make_block(self.falses_count(), false, key),
         ]
         .into_iter()
         .flatten();
@@ -312,7 +532,7 @@ impl PayloadFieldIndex for BinaryIndex {
     }
 
     fn count_indexed_points(&self) -> usize {
-        self.memory.indexed_count()
+        self.indexed_count()
     }
 }
 
@@ -327,9 +547,15 @@ impl ValueIndexer<bool> for BinaryIndex {
 
         let item = BinaryItem::from_bools(has_true, has_false);
 
-        self.memory.set_or_insert(id, &item);
+        let BinaryIndex::Mutable(index) = self else {
+            return Err(OperationError::service_error(
+                "Can't add values to immutable binary index",
+            ));
+        };
+
+        index.memory.set_or_insert(id, &item);
 
-        self.db_wrapper.put(id.to_be_bytes(), item.as_bytes())?;
+        index.db_wrapper.put(id.to_be_bytes(), item.as_bytes())?;
 
         Ok(())
     }
@@ -339,8 +565,13 @@ impl ValueIndexer<bool> for BinaryIndex {
     }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
-        self.memory.remove(id);
-        self.db_wrapper.remove(id.to_be_bytes())?;
+        match self {
+            BinaryIndex::Mutable(index) => {
+                index.memory.remove(id);
+                index.db_wrapper.remove(id.to_be_bytes())?;
+            }
+            BinaryIndex::Mmap(index) => index.remove(id),
+        }
         Ok(())
     }
 }
@@ -443,6 +674,9 @@ mod tests {
             });
 
         index.flusher()().unwrap();
+        let BinaryIndex::Mutable(index) = index else {
+            panic!("index is mutable");
+        };
         let db = index.db_wrapper.database;
 
         let mut new_index = BinaryIndex::new(db, FIELD_NAME);
@@ -529,4 +763,43 @@ mod tests {
         let cardinality = index.estimate_cardinality(&match_bool(false)).unwrap();
         assert_eq!(cardinality.exp, 6);
     }
+
+    #[test]
+    fn test_mmap_binary_index() {
+        let (tmp_dir, mut index) = new_binary_index();
+
+        bools_fixture()
+            .into_iter()
+            .enumerate()
+            .for_each(|(i, value)| {
+                let payload = MultiValue::one(&value);
+                index.add_point(i as u32, &payload).unwrap();
+            });
+
+        let mmap_dir = tmp_dir.path().join("mmap");
+        index.save_mmap(&mmap_dir).unwrap();
+        let mut mmap_index = index.into_mmap(&mmap_dir).unwrap();
+        assert!(matches!(mmap_index, BinaryIndex::Mmap(_)));
+        assert_eq!(mmap_index.count_indexed_points(), 9);
+
+        let point_offsets = mmap_index.filter(&match_bool(false)).unwrap().collect_vec();
+        assert_eq!(point_offsets, vec![1, 2, 3, 5, 6, 10]);
+        let cardinality = mmap_index.estimate_cardinality(&match_bool(true)).unwrap();
+        assert_eq!(cardinality.exp, 6);
+
+        mmap_index.remove_point(2).unwrap();
+        mmap_index.flusher()().unwrap();
+        assert_eq!(mmap_index.count_indexed_points(), 8);
+        assert!(mmap_index.values_is_empty(2));
+
+        // Removal is persisted
+        let mmap_index = BinaryIndex::new(
+            open_db_with_existing_cf(tmp_dir.path()).unwrap(),
+            FIELD_NAME,
+        )
+        .into_mmap(&mmap_dir)
+        .unwrap();
+        let point_offsets = mmap_index.filter(&match_bool(true)).unwrap().collect_vec();
+        assert_eq!(point_offsets, vec![0, 3, 4, 6, 11]);
+    }
 }
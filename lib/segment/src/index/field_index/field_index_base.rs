@@ -1,11 +1,17 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
 use common::types::PointOffsetType;
+use itertools::Itertools;
 use serde_json::Value;
 use smol_str::SmolStr;
 
-use super::map_index::MapIndex;
+use super::map_index::{uuid_to_string, MapIndex};
 use crate::common::operation_error::OperationResult;
 use crate::common::utils::MultiValue;
 use crate::common::Flusher;
+use crate::data_types::facets::FacetValue;
+use crate::data_types::order_by::{OrderByRange, OrderValue};
 use crate::index::field_index::binary_index::BinaryIndex;
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::geo_index::GeoMapIndex;
@@ -13,7 +19,8 @@ use crate::index::field_index::numeric_index::NumericIndex;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchText, PayloadKeyType,
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchPhrase,
+    MatchText, PayloadKeyType, UuidIntType,
 };
 
 pub trait PayloadFieldIndex {
@@ -119,6 +126,8 @@ pub enum FieldIndex {
     IntMapIndex(MapIndex<IntPayloadType>),
     KeywordIndex(MapIndex<SmolStr>),
     FloatIndex(NumericIndex<FloatPayloadType>),
+    DatetimeIndex(NumericIndex<IntPayloadType>),
+    UuidMapIndex(MapIndex<UuidIntType>),
     GeoIndex(GeoMapIndex),
     FullTextIndex(FullTextIndex),
     BinaryIndex(BinaryIndex),
@@ -142,6 +151,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(_) => None,
             FieldIndex::KeywordIndex(_) => None,
             FieldIndex::FloatIndex(_) => None,
+            FieldIndex::DatetimeIndex(_) => None,
+            FieldIndex::UuidMapIndex(_) => None,
             FieldIndex::GeoIndex(_) => None,
             FieldIndex::BinaryIndex(_) => None,
             FieldIndex::FullTextIndex(full_text_index) => match &condition.r#match {
@@ -155,6 +166,14 @@ impl FieldIndex {
                     }
                     Some(false)
                 }
+                Some(Match::Phrase(MatchPhrase { phrase })) => {
+                    let parsed_phrase = full_text_index.parse_phrase(phrase)?;
+                    let is_match = full_text_index
+                        .get_values(payload_value)
+                        .iter()
+                        .any(|value| full_text_index.check_phrase_in_text(&parsed_phrase, value));
+                    Some(is_match)
+                }
                 _ => None,
             },
         }
@@ -166,6 +185,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(payload_field_index) => payload_field_index,
             FieldIndex::KeywordIndex(payload_field_index) => payload_field_index,
             FieldIndex::FloatIndex(payload_field_index) => payload_field_index,
+            FieldIndex::DatetimeIndex(payload_field_index) => payload_field_index,
+            FieldIndex::UuidMapIndex(payload_field_index) => payload_field_index,
             FieldIndex::GeoIndex(payload_field_index) => payload_field_index,
             FieldIndex::BinaryIndex(payload_field_index) => payload_field_index,
             FieldIndex::FullTextIndex(payload_field_index) => payload_field_index,
@@ -179,6 +200,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(ref mut payload_field_index) => payload_field_index,
             FieldIndex::KeywordIndex(ref mut payload_field_index) => payload_field_index,
             FieldIndex::FloatIndex(ref mut payload_field_index) => payload_field_index,
+            FieldIndex::DatetimeIndex(ref mut payload_field_index) => payload_field_index,
+            FieldIndex::UuidMapIndex(ref mut payload_field_index) => payload_field_index,
             FieldIndex::GeoIndex(ref mut payload_field_index) => payload_field_index,
             FieldIndex::BinaryIndex(ref mut payload_field_index) => payload_field_index,
             FieldIndex::FullTextIndex(ref mut payload_field_index) => payload_field_index,
@@ -191,6 +214,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(ref mut payload_field_index) => payload_field_index.load(),
             FieldIndex::KeywordIndex(ref mut payload_field_index) => payload_field_index.load(),
             FieldIndex::FloatIndex(ref mut payload_field_index) => payload_field_index.load(),
+            FieldIndex::DatetimeIndex(ref mut payload_field_index) => payload_field_index.load(),
+            FieldIndex::UuidMapIndex(ref mut payload_field_index) => payload_field_index.load(),
             FieldIndex::GeoIndex(ref mut payload_field_index) => payload_field_index.load(),
             FieldIndex::BinaryIndex(ref mut payload_field_index) => payload_field_index.load(),
             FieldIndex::FullTextIndex(ref mut payload_field_index) => payload_field_index.load(),
@@ -203,6 +228,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.clear(),
             FieldIndex::KeywordIndex(index) => index.clear(),
             FieldIndex::FloatIndex(index) => index.clear(),
+            FieldIndex::DatetimeIndex(index) => index.clear(),
+            FieldIndex::UuidMapIndex(index) => index.clear(),
             FieldIndex::GeoIndex(index) => index.clear(),
             FieldIndex::BinaryIndex(index) => index.clear(),
             FieldIndex::FullTextIndex(index) => index.clear(),
@@ -215,12 +242,80 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.recreate(),
             FieldIndex::KeywordIndex(index) => index.recreate(),
             FieldIndex::FloatIndex(index) => index.recreate(),
+            FieldIndex::DatetimeIndex(index) => index.recreate(),
+            FieldIndex::UuidMapIndex(index) => index.recreate(),
             FieldIndex::GeoIndex(index) => index.recreate(),
             FieldIndex::BinaryIndex(index) => index.recreate(),
             FieldIndex::FullTextIndex(index) => index.recreate(),
         }
     }
 
+    /// Name of the directory of the on-disk index, within the directory of the field
+    fn mmap_dir_name(&self) -> &'static str {
+        match self {
+            FieldIndex::IntIndex(_) => "int",
+            FieldIndex::IntMapIndex(_) => "int_map",
+            FieldIndex::KeywordIndex(_) => "keyword",
+            FieldIndex::FloatIndex(_) => "float",
+            FieldIndex::DatetimeIndex(_) => "datetime",
+            FieldIndex::UuidMapIndex(_) => "uuid_map",
+            FieldIndex::GeoIndex(_) => "geo",
+            FieldIndex::FullTextIndex(_) => "full_text",
+            FieldIndex::BinaryIndex(_) => "bool",
+        }
+    }
+
+    /// Write an on-disk copy of the index into the directory of the field
+    ///
+    /// Only indexes, which are loaded from RocksDB as mutable ones, can be written.
+    pub fn save_mmap(&self, field_dir: &Path) -> OperationResult<()> {
+        let dir = field_dir.join(self.mmap_dir_name());
+        match self {
+            FieldIndex::IntIndex(index) => index.save_mmap(&dir),
+            FieldIndex::IntMapIndex(index) => index.save_mmap(&dir),
+            FieldIndex::KeywordIndex(index) => index.save_mmap(&dir),
+            FieldIndex::FloatIndex(index) => index.save_mmap(&dir),
+            FieldIndex::DatetimeIndex(index) => index.save_mmap(&dir),
+            FieldIndex::UuidMapIndex(index) => index.save_mmap(&dir),
+            FieldIndex::GeoIndex(index) => index.save_mmap(&dir),
+            FieldIndex::FullTextIndex(index) => index.save_mmap(&dir),
+            FieldIndex::BinaryIndex(index) => index.save_mmap(&dir),
+        }
+    }
+
+    /// Replace the index with the on-disk one from the directory of the field
+    ///
+    /// RocksDB data of the index is removed, as it is not needed anymore.
+    pub fn into_mmap(self, field_dir: &Path) -> OperationResult<FieldIndex> {
+        let dir = field_dir.join(self.mmap_dir_name());
+        Ok(match self {
+            FieldIndex::IntIndex(index) => FieldIndex::IntIndex(index.into_mmap(&dir)?),
+            FieldIndex::IntMapIndex(index) => FieldIndex::IntMapIndex(index.into_mmap(&dir)?),
+            FieldIndex::KeywordIndex(index) => FieldIndex::KeywordIndex(index.into_mmap(&dir)?),
+            FieldIndex::FloatIndex(index) => FieldIndex::FloatIndex(index.into_mmap(&dir)?),
+            FieldIndex::DatetimeIndex(index) => FieldIndex::DatetimeIndex(index.into_mmap(&dir)?),
+            FieldIndex::UuidMapIndex(index) => FieldIndex::UuidMapIndex(index.into_mmap(&dir)?),
+            FieldIndex::GeoIndex(index) => FieldIndex::GeoIndex(index.into_mmap(&dir)?),
+            FieldIndex::FullTextIndex(index) => FieldIndex::FullTextIndex(index.into_mmap(&dir)?),
+            FieldIndex::BinaryIndex(index) => FieldIndex::BinaryIndex(index.into_mmap(&dir)?),
+        })
+    }
+
+    /// Files of the on-disk index, empty for indexes stored in RocksDB
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            FieldIndex::IntIndex(index) => index.files(),
+            FieldIndex::IntMapIndex(index) => index.files(),
+            FieldIndex::KeywordIndex(index) => index.files(),
+            FieldIndex::FloatIndex(index) => index.files(),
+            FieldIndex::DatetimeIndex(index) => index.files(),
+            FieldIndex::UuidMapIndex(index) => index.files(),
+            FieldIndex::GeoIndex(index) => index.files(),
+            FieldIndex::FullTextIndex(index) => index.files(),
+            FieldIndex::BinaryIndex(index) => index.files(),
+        }
+    }
+
     pub fn count_indexed_points(&self) -> usize {
         self.get_payload_field_index().count_indexed_points()
     }
@@ -260,7 +355,7 @@ impl FieldIndex {
     ) -> OperationResult<()> {
         match self {
             FieldIndex::IntIndex(ref mut payload_field_index) => {
-                payload_field_index.add_point(id, payload)
+                ValueIndexer::<IntPayloadType>::add_point(payload_field_index, id, payload)
             }
             FieldIndex::IntMapIndex(ref mut payload_field_index) => {
                 payload_field_index.add_point(id, payload)
@@ -271,6 +366,12 @@ impl FieldIndex {
             FieldIndex::FloatIndex(ref mut payload_field_index) => {
                 payload_field_index.add_point(id, payload)
             }
+            FieldIndex::DatetimeIndex(ref mut payload_field_index) => {
+                ValueIndexer::<DateTimePayloadType>::add_point(payload_field_index, id, payload)
+            }
+            FieldIndex::UuidMapIndex(ref mut payload_field_index) => {
+                payload_field_index.add_point(id, payload)
+            }
             FieldIndex::GeoIndex(ref mut payload_field_index) => {
                 payload_field_index.add_point(id, payload)
             }
@@ -289,6 +390,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.remove_point(point_id),
             FieldIndex::KeywordIndex(index) => index.remove_point(point_id),
             FieldIndex::FloatIndex(index) => index.remove_point(point_id),
+            FieldIndex::DatetimeIndex(index) => index.remove_point(point_id),
+            FieldIndex::UuidMapIndex(index) => index.remove_point(point_id),
             FieldIndex::GeoIndex(index) => index.remove_point(point_id),
             FieldIndex::BinaryIndex(index) => index.remove_point(point_id),
             FieldIndex::FullTextIndex(index) => index.remove_point(point_id),
@@ -301,6 +404,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::KeywordIndex(index) => index.get_telemetry_data(),
             FieldIndex::FloatIndex(index) => index.get_telemetry_data(),
+            FieldIndex::DatetimeIndex(index) => index.get_telemetry_data(),
+            FieldIndex::UuidMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::GeoIndex(index) => index.get_telemetry_data(),
             FieldIndex::BinaryIndex(index) => index.get_telemetry_data(),
             FieldIndex::FullTextIndex(index) => index.get_telemetry_data(),
@@ -313,6 +418,8 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.values_count(point_id),
             FieldIndex::KeywordIndex(index) => index.values_count(point_id),
             FieldIndex::FloatIndex(index) => index.values_count(point_id),
+            FieldIndex::DatetimeIndex(index) => index.values_count(point_id),
+            FieldIndex::UuidMapIndex(index) => index.values_count(point_id),
             FieldIndex::GeoIndex(index) => index.values_count(point_id),
             FieldIndex::BinaryIndex(index) => index.values_count(point_id),
             FieldIndex::FullTextIndex(index) => index.values_count(point_id),
@@ -325,9 +432,149 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.values_is_empty(point_id),
             FieldIndex::KeywordIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FloatIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::DatetimeIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::UuidMapIndex(index) => index.values_is_empty(point_id),
             FieldIndex::GeoIndex(index) => index.values_is_empty(point_id),
             FieldIndex::BinaryIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FullTextIndex(index) => index.values_is_empty(point_id),
         }
     }
+
+    /// Index, which can be used to count points per value, if available
+    pub fn as_facet_index(&self) -> Option<FacetIndex<'_>> {
+        match self {
+            FieldIndex::KeywordIndex(index) => Some(FacetIndex::KeywordIndex(index)),
+            FieldIndex::IntMapIndex(index) => Some(FacetIndex::IntMapIndex(index)),
+            FieldIndex::UuidMapIndex(index) => Some(FacetIndex::UuidMapIndex(index)),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::BinaryIndex(_) => None,
+        }
+    }
+
+    /// Index, which can be used to rank points by text, if available
+    pub fn as_full_text(&self) -> Option<&FullTextIndex> {
+        match self {
+            FieldIndex::FullTextIndex(index) => Some(index),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::IntMapIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::UuidMapIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::BinaryIndex(_) => None,
+        }
+    }
+
+    /// Index, which can be used to read points ordered by value, if available
+    pub fn as_numeric(&self) -> Option<NumericFieldIndex<'_>> {
+        match self {
+            FieldIndex::IntIndex(index) => Some(NumericFieldIndex::IntIndex(index)),
+            FieldIndex::FloatIndex(index) => Some(NumericFieldIndex::FloatIndex(index)),
+            FieldIndex::IntMapIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::UuidMapIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::FullTextIndex(_)
+            | FieldIndex::BinaryIndex(_) => None,
+        }
+    }
+}
+
+pub enum FacetIndex<'a> {
+    KeywordIndex(&'a MapIndex<SmolStr>),
+    IntMapIndex(&'a MapIndex<IntPayloadType>),
+    UuidMapIndex(&'a MapIndex<UuidIntType>),
+}
+
+impl<'a> FacetIndex<'a> {
+    /// Number of points per each value of the index
+    pub fn counts_per_value(&self) -> HashMap<FacetValue, usize> {
+        match self {
+            FacetIndex::KeywordIndex(index) => index
+                .iter_counts_per_value()
+                .map(|(value, count)| (FacetValue::Keyword(value.to_string()), count))
+                .collect(),
+            FacetIndex::IntMapIndex(index) => index
+                .iter_counts_per_value()
+                .map(|(value, count)| (FacetValue::Integer(value), count))
+                .collect(),
+            FacetIndex::UuidMapIndex(index) => index
+                .iter_counts_per_value()
+                .map(|(value, count)| (FacetValue::Keyword(uuid_to_string(value)), count))
+                .collect(),
+        }
+    }
+
+    /// Values of the point, each value is counted once
+    pub fn get_values(&self, point_id: PointOffsetType) -> Vec<FacetValue> {
+        match self {
+            FacetIndex::KeywordIndex(index) => index
+                .get_values(point_id)
+                .into_iter()
+                .flatten()
+                .unique()
+                .map(|value| FacetValue::Keyword(value.to_string()))
+                .collect(),
+            FacetIndex::IntMapIndex(index) => index
+                .get_values(point_id)
+                .into_iter()
+                .flatten()
+                .unique()
+                .map(FacetValue::Integer)
+                .collect(),
+            FacetIndex::UuidMapIndex(index) => index
+                .get_values(point_id)
+                .into_iter()
+                .flatten()
+                .unique()
+                .map(|value| FacetValue::Keyword(uuid_to_string(value)))
+                .collect(),
+        }
+    }
+}
+
+pub enum NumericFieldIndex<'a> {
+    IntIndex(&'a NumericIndex<IntPayloadType>),
+    FloatIndex(&'a NumericIndex<FloatPayloadType>),
+}
+
+impl<'a> NumericFieldIndex<'a> {
+    /// Iterate over `(value, point)` pairs within the range, ordered by value
+    pub fn stream_range(
+        &self,
+        range: &OrderByRange,
+    ) -> Box<dyn DoubleEndedIterator<Item = (OrderValue, PointOffsetType)> + 'a> {
+        match (self, range) {
+            (NumericFieldIndex::IntIndex(index), OrderByRange::Integer(start, end)) => Box::new(
+                index
+                    .stream_values_range(*start, *end)
+                    .map(|(value, idx)| (OrderValue::from(value), idx)),
+            ),
+            (NumericFieldIndex::IntIndex(index), OrderByRange::Float(range)) => Box::new(
+                index
+                    .stream_range(range)
+                    .map(|(value, idx)| (OrderValue::from(value), idx)),
+            ),
+            (NumericFieldIndex::FloatIndex(index), OrderByRange::Integer(start, end)) => {
+                let start = start.map(|v| v as FloatPayloadType);
+                let end = end.map(|v| v as FloatPayloadType);
+                Box::new(
+                    index
+                        .stream_values_range(start, end)
+                        .map(|(value, idx)| (OrderValue::from(value), idx)),
+                )
+            }
+            (NumericFieldIndex::FloatIndex(index), OrderByRange::Float(range)) => Box::new(
+                index
+                    .stream_range(range)
+                    .map(|(value, idx)| (OrderValue::from(value), idx)),
+            ),
+        }
+    }
 }
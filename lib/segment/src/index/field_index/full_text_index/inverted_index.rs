@@ -1,10 +1,15 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use serde::{Deserialize, Serialize};
 
-use super::posting_list::PostingList;
+use super::mmap_inverted_index::MmapInvertedIndex;
+use super::posting_list::{PostingList, TokenPositions};
 use super::postings_iterator::intersect_postings_iterator;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::data_types::text_statistics::TextStatistics;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition, PrimaryCondition};
 use crate::types::{FieldCondition, Match, MatchText, PayloadKeyType};
 
@@ -45,55 +50,141 @@ pub struct ParsedQuery {
 
 impl ParsedQuery {
     pub fn check_match(&self, document: &Document) -> bool {
+        self.check_tokens(document.tokens())
+    }
+
+    /// Check that all tokens of the query are among the sorted tokens of a document
+    pub fn check_tokens(&self, document_tokens: &[TokenId]) -> bool {
         if self.tokens.contains(&None) {
             return false;
         }
         // Check that all tokens are in document
-        self.tokens
-            .iter()
+        self.tokens.iter().all(|query_token| {
             // unwrap crash safety: all tokens exist in the vocabulary if it passes the above check
-            .all(|query_token| document.check(query_token.unwrap()))
+            document_tokens.binary_search(&query_token.unwrap()).is_ok()
+        })
+    }
+}
+
+/// Tokens of a phrase query, in the order they appear in the phrase
+#[derive(Debug)]
+pub struct ParsedPhrase {
+    pub tokens: Vec<Option<TokenId>>,
+}
+
+impl ParsedPhrase {
+    /// Query for the documents containing all tokens of the phrase, regardless of their positions
+    pub fn to_query(&self) -> ParsedQuery {
+        let mut tokens = self.tokens.clone();
+        tokens.sort_unstable();
+        tokens.dedup();
+        ParsedQuery { tokens }
+    }
+
+    /// Check that the tokens of the phrase are next to each other, given the positions of each token
+    pub fn check_positions<'a>(
+        &self,
+        get_positions: impl Fn(TokenId) -> Option<&'a [u32]>,
+    ) -> bool {
+        let positions_opt: Option<Vec<_>> = self
+            .tokens
+            .iter()
+            .map(|&vocab_idx| get_positions(vocab_idx?))
+            .collect();
+        let Some(positions) = positions_opt else {
+            // Unseen tokens, or tokens missing in the document
+            return false;
+        };
+        let Some((first_positions, next_positions)) = positions.split_first() else {
+            // Empty phrase -> no match
+            return false;
+        };
+        first_positions.iter().any(|&start| {
+            next_positions
+                .iter()
+                .zip(1..)
+                .all(|(token_positions, offset)| {
+                    token_positions.binary_search(&(start + offset)).is_ok()
+                })
+        })
     }
 }
 
 #[derive(Default)]
-pub struct InvertedIndex {
-    postings: Vec<Option<PostingList>>,
+pub struct MutableInvertedIndex {
+    pub(super) postings: Vec<Option<PostingList>>,
     pub vocab: HashMap<String, TokenId>,
     pub point_to_docs: Vec<Option<Document>>,
+    /// Number of tokens in each document, including repeated ones
+    pub(super) document_lengths: Vec<u32>,
     pub points_count: usize,
+    /// Sum of the lengths of all documents
+    pub total_length: usize,
 }
 
-impl InvertedIndex {
-    pub fn new() -> InvertedIndex {
+impl MutableInvertedIndex {
+    pub fn new() -> MutableInvertedIndex {
         Default::default()
     }
 
-    pub fn document_from_tokens(&mut self, tokens: &BTreeSet<String>) -> Document {
-        let mut document_tokens = vec![];
-        for token in tokens {
-            // check if in vocab
-            let vocab_idx = match self.vocab.get(token) {
-                Some(&idx) => idx,
-                None => {
-                    let next_token_id = self.vocab.len() as TokenId;
-                    self.vocab.insert(token.to_string(), next_token_id);
-                    next_token_id
-                }
-            };
-            document_tokens.push(vocab_idx);
+    fn register_token(&mut self, token: &str) -> TokenId {
+        // check if in vocab
+        match self.vocab.get(token) {
+            Some(&idx) => idx,
+            None => {
+                let next_token_id = self.vocab.len() as TokenId;
+                self.vocab.insert(token.to_string(), next_token_id);
+                next_token_id
+            }
         }
+    }
 
+    pub fn document_from_tokens(&mut self, tokens: &BTreeSet<String>) -> Document {
+        let document_tokens = tokens
+            .iter()
+            .map(|token| self.register_token(token))
+            .collect();
         Document::new(document_tokens)
     }
 
-    pub fn index_document(&mut self, idx: PointOffsetType, document: Document) {
+    pub fn token_positions_from_tokens(
+        &mut self,
+        positions: &BTreeMap<String, TokenPositions>,
+    ) -> HashMap<TokenId, TokenPositions> {
+        positions
+            .iter()
+            .map(|(token, token_positions)| (self.register_token(token), token_positions.clone()))
+            .collect()
+    }
+
+    pub fn token_frequencies_from_tokens(
+        &mut self,
+        frequencies: &BTreeMap<String, u32>,
+    ) -> HashMap<TokenId, u32> {
+        frequencies
+            .iter()
+            .map(|(token, &frequency)| (self.register_token(token), frequency))
+            .collect()
+    }
+
+    /// Index the document. Tokens missing in `frequencies` occur in the document once.
+    /// Token positions are only given if the index supports phrase matching.
+    pub fn index_document(
+        &mut self,
+        idx: PointOffsetType,
+        document: Document,
+        frequencies: HashMap<TokenId, u32>,
+        mut positions: Option<HashMap<TokenId, TokenPositions>>,
+    ) {
         self.points_count += 1;
         if self.point_to_docs.len() <= idx as usize {
             self.point_to_docs
                 .resize_with(idx as usize + 1, Default::default);
+            self.document_lengths.resize(idx as usize + 1, 0);
         }
 
+        let mut document_length = 0;
+
         for token_idx in document.tokens() {
             let token_idx_usize = *token_idx as usize;
             if self.postings.len() <= token_idx_usize {
@@ -104,12 +195,19 @@ impl InvertedIndex {
                 .postings
                 .get_mut(token_idx_usize)
                 .expect("posting must exist even if with None");
+            let frequency = frequencies.get(token_idx).copied().unwrap_or(1);
+            document_length += frequency;
+            let token_positions = positions
+                .as_mut()
+                .map(|positions| positions.remove(token_idx).unwrap_or_default());
             match posting {
-                None => *posting = Some(PostingList::new(idx)),
-                Some(vec) => vec.insert(idx),
+                None => *posting = Some(PostingList::new(idx, frequency, token_positions)),
+                Some(vec) => vec.insert(idx, frequency, token_positions),
             }
         }
         self.point_to_docs[idx as usize] = Some(document);
+        self.document_lengths[idx as usize] = document_length;
+        self.total_length += document_length as usize;
     }
 
     pub fn remove_document(&mut self, idx: PointOffsetType) -> Option<Document> {
@@ -123,6 +221,8 @@ impl InvertedIndex {
         };
 
         self.points_count -= 1;
+        let document_length = std::mem::take(&mut self.document_lengths[idx as usize]);
+        self.total_length -= document_length as usize;
 
         for removed_token in removed_doc.tokens() {
             // unwrap safety: posting list exists and contains the document id
@@ -134,6 +234,130 @@ impl InvertedIndex {
         Some(removed_doc)
     }
 
+    fn posting(&self, token: TokenId) -> Option<&PostingList> {
+        self.postings.get(token as usize)?.as_ref()
+    }
+}
+
+pub enum InvertedIndex {
+    Mutable(Box<MutableInvertedIndex>),
+    Mmap(Box<MmapInvertedIndex>),
+}
+
+impl InvertedIndex {
+    pub fn new() -> InvertedIndex {
+        InvertedIndex::Mutable(Box::new(MutableInvertedIndex::new()))
+    }
+
+    pub fn get_token_id(&self, token: &str) -> Option<TokenId> {
+        match self {
+            InvertedIndex::Mutable(index) => index.vocab.get(token).copied(),
+            InvertedIndex::Mmap(index) => index.get_token_id(token),
+        }
+    }
+
+    /// Sorted documents containing the token
+    fn posting_ids(&self, token: TokenId) -> Option<&[PointOffsetType]> {
+        match self {
+            InvertedIndex::Mutable(index) => index.posting(token).map(PostingList::ids),
+            InvertedIndex::Mmap(index) => index.posting_ids(token),
+        }
+    }
+
+    /// Number of occurrences of the token in each document of [`Self::posting_ids`]
+    fn posting_frequencies(&self, token: TokenId) -> Option<&[u32]> {
+        match self {
+            InvertedIndex::Mutable(index) => index.posting(token).map(PostingList::frequencies),
+            InvertedIndex::Mmap(index) => index.posting_frequencies(token),
+        }
+    }
+
+    /// Positions of the token in the given document, if they are stored
+    fn positions(&self, token: TokenId, idx: PointOffsetType) -> Option<&[u32]> {
+        match self {
+            InvertedIndex::Mutable(index) => index.posting(token)?.positions(idx),
+            InvertedIndex::Mmap(index) => index.positions(token, idx),
+        }
+    }
+
+    fn document_length(&self, idx: PointOffsetType) -> u32 {
+        match self {
+            InvertedIndex::Mutable(index) => index.document_lengths[idx as usize],
+            InvertedIndex::Mmap(index) => index.document_length(idx),
+        }
+    }
+
+    /// Sorted distinct tokens of the document
+    pub fn document_tokens(&self, idx: PointOffsetType) -> Option<&[TokenId]> {
+        match self {
+            InvertedIndex::Mutable(index) => match index.point_to_docs.get(idx as usize) {
+                Some(Some(doc)) => Some(doc.tokens()),
+                _ => None,
+            },
+            InvertedIndex::Mmap(index) => index.document_tokens(idx),
+        }
+    }
+
+    /// Tokens of the vocabulary with their ids
+    fn iter_vocab(&self) -> Box<dyn Iterator<Item = (&str, TokenId)> + '_> {
+        match self {
+            InvertedIndex::Mutable(index) => Box::new(
+                index
+                    .vocab
+                    .iter()
+                    .map(|(token, &token_id)| (token.as_str(), token_id)),
+            ),
+            InvertedIndex::Mmap(index) => Box::new(index.iter_vocab()),
+        }
+    }
+
+    pub fn points_count(&self) -> usize {
+        match self {
+            InvertedIndex::Mutable(index) => index.points_count,
+            InvertedIndex::Mmap(index) => index.points_count,
+        }
+    }
+
+    /// Sum of the lengths of all documents
+    pub fn total_length(&self) -> usize {
+        match self {
+            InvertedIndex::Mutable(index) => index.total_length,
+            InvertedIndex::Mmap(index) => index.total_length,
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        match self {
+            InvertedIndex::Mutable(index) => MmapInvertedIndex::create(dir, index),
+            InvertedIndex::Mmap(_) => Err(OperationError::service_error(
+                "Only mutable full text index can be saved on disk",
+            )),
+        }
+    }
+
+    pub fn flusher(&self) -> Option<Flusher> {
+        match self {
+            InvertedIndex::Mutable(_) => None,
+            InvertedIndex::Mmap(index) => Some(index.flusher()),
+        }
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            InvertedIndex::Mutable(_) => vec![],
+            InvertedIndex::Mmap(index) => index.files(),
+        }
+    }
+
+    /// Returns `false` if the document was not indexed
+    pub fn remove_document(&mut self, idx: PointOffsetType) -> bool {
+        match self {
+            InvertedIndex::Mutable(index) => index.remove_document(idx).is_some(),
+            InvertedIndex::Mmap(index) => index.remove_document(idx),
+        }
+    }
+
     pub fn filter(&self, query: &ParsedQuery) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         let postings_opt: Option<Vec<_>> = query
             .tokens
@@ -142,7 +366,7 @@ impl InvertedIndex {
                 None => None,
                 // if a ParsedQuery token was given an index, then it must exist in the vocabulary
                 // dictionary. Posting list entry can be None but it exists.
-                Some(idx) => self.postings.get(idx as usize).unwrap().as_ref(),
+                Some(idx) => self.posting_ids(idx),
             })
             .collect();
         if postings_opt.is_none() {
@@ -157,6 +381,48 @@ impl InvertedIndex {
         intersect_postings_iterator(postings)
     }
 
+    pub fn filter_phrase(
+        &self,
+        phrase: ParsedPhrase,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        let candidates = self.filter(&phrase.to_query());
+        Box::new(candidates.filter(move |&idx| self.check_phrase(&phrase, idx)))
+    }
+
+    /// Check that the tokens of the phrase are next to each other in the document
+    pub fn check_phrase(&self, phrase: &ParsedPhrase, idx: PointOffsetType) -> bool {
+        phrase.check_positions(|vocab_idx| self.positions(vocab_idx, idx))
+    }
+
+    /// Number of documents containing the token
+    pub fn document_frequency(&self, token: TokenId) -> usize {
+        self.posting_ids(token).map_or(0, <[_]>::len)
+    }
+
+    /// BM25 scores of all documents containing at least one of the tokens
+    ///
+    /// Every token comes with its inverse document frequency, which is computed from `statistics`.
+    pub fn score_bm25(
+        &self,
+        tokens: &[(TokenId, ScoreType)],
+        statistics: &TextStatistics,
+    ) -> HashMap<PointOffsetType, ScoreType> {
+        let mut scores: HashMap<PointOffsetType, ScoreType> = HashMap::new();
+        for &(token, idf) in tokens {
+            let (Some(ids), Some(frequencies)) =
+                (self.posting_ids(token), self.posting_frequencies(token))
+            else {
+                continue;
+            };
+            for (&idx, &frequency) in ids.iter().zip(frequencies) {
+                let document_length = self.document_length(idx);
+                *scores.entry(idx).or_default() +=
+                    statistics.term_score(idf, frequency, document_length);
+            }
+        }
+        scores
+    }
+
     pub fn estimate_cardinality(
         &self,
         query: &ParsedQuery,
@@ -167,8 +433,7 @@ impl InvertedIndex {
             .iter()
             .map(|&vocab_idx| match vocab_idx {
                 None => None,
-                // unwrap safety: same as in filter()
-                Some(idx) => self.postings.get(idx as usize).unwrap().as_ref(),
+                Some(idx) => self.posting_ids(idx),
             })
             .collect();
         if postings_opt.is_none() {
@@ -203,9 +468,9 @@ impl InvertedIndex {
         } else {
             let expected_frac: f64 = postings
                 .iter()
-                .map(|posting| posting.len() as f64 / self.points_count as f64)
+                .map(|posting| posting.len() as f64 / self.points_count() as f64)
                 .product();
-            let exp = (expected_frac * self.points_count as f64) as usize;
+            let exp = (expected_frac * self.points_count() as f64) as usize;
             CardinalityEstimation {
                 primary_clauses: vec![PrimaryCondition::Condition(condition.clone())],
                 min: 0, // ToDo: make better estimation
@@ -215,6 +480,23 @@ impl InvertedIndex {
         };
     }
 
+    pub fn estimate_phrase_cardinality(
+        &self,
+        phrase: &ParsedPhrase,
+        condition: &FieldCondition,
+    ) -> CardinalityEstimation {
+        let estimation = self.estimate_cardinality(&phrase.to_query(), condition);
+        if phrase.tokens.len() > 1 {
+            // All tokens might be in the document, but not next to each other
+            CardinalityEstimation {
+                min: 0,
+                ..estimation
+            }
+        } else {
+            estimation
+        }
+    }
+
     pub fn payload_blocks(
         &self,
         threshold: usize,
@@ -223,30 +505,21 @@ impl InvertedIndex {
         // It might be very hard to predict possible combinations of conditions,
         // so we only build it for individual tokens
         Box::new(
-            self.vocab
-                .iter()
-                .filter(|(_token, &posting_idx)| self.postings[posting_idx as usize].is_some())
-                .filter(move |(_token, &posting_idx)| {
-                    // unwrap crash safety: all tokens that passes the first filter should have postings
-                    self.postings[posting_idx as usize].as_ref().unwrap().len() >= threshold
-                })
-                .map(|(token, &posting_idx)| {
-                    (
-                        token,
-                        // same as the above case
-                        self.postings[posting_idx as usize].as_ref().unwrap(),
-                    )
-                })
+            self.iter_vocab()
+                .filter_map(|(token, token_id)| Some((token, self.posting_ids(token_id)?)))
+                .filter(move |(_token, posting)| posting.len() >= threshold)
                 .map(move |(token, posting)| PayloadBlockCondition {
                     condition: FieldCondition {
                         key: key.clone(),
                         r#match: Some(Match::Text(MatchText {
-                            text: token.clone(),
+                            text: token.to_owned(),
                         })),
                         range: None,
                         geo_bounding_box: None,
                         geo_radius: None,
                         geo_polygon: None,
+                        geo_intersects: None,
+                        geo_within: None,
                         values_count: None,
                     },
                     cardinality: posting.len(),
@@ -0,0 +1,244 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use itertools::Itertools;
+
+use super::inverted_index::{MutableInvertedIndex, TokenId};
+use super::posting_list::PostingList;
+use crate::common::mmap_type::MmapSlice;
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::index::field_index::mmap_lists::{create_mmap_slice, open_mmap_slice, MmapLists};
+
+const VOCAB: &str = "vocab";
+const POSTINGS: &str = "postings";
+const FREQUENCIES: &str = "frequencies";
+const POSITION_IDS: &str = "position_ids";
+const POSITIONS: &str = "positions";
+const POINT_TO_TOKENS: &str = "point_to_tokens";
+const DOCUMENT_LENGTHS_PATH: &str = "document_lengths.dat";
+
+/// Inverted index stored in memory mapped files
+///
+/// Token ids are positions of the tokens in the sorted vocabulary, so they differ from the ids of
+/// the mutable index it was created from.
+pub struct MmapInvertedIndex {
+    dir: PathBuf,
+    /// Sorted tokens
+    vocab: MmapLists<u8>,
+    /// Sorted documents of each token
+    postings: MmapLists<PointOffsetType>,
+    /// Number of occurrences of the token in each document of `postings`
+    frequencies: MmapLists<u32>,
+    /// Index in `positions` for each document of `postings`, empty if phrase matching is disabled
+    position_ids: MmapLists<u32>,
+    /// Sorted positions of a token within a document
+    positions: MmapLists<u32>,
+    /// Sorted distinct tokens of each document
+    point_to_tokens: MmapLists<TokenId>,
+    /// Number of tokens in each document, including repeated ones
+    document_lengths: MmapSlice<u32>,
+    pub(super) points_count: usize,
+    /// Sum of the lengths of all documents
+    pub(super) total_length: usize,
+}
+
+impl MmapInvertedIndex {
+    pub fn create(dir: &Path, index: &MutableInvertedIndex) -> OperationResult<()> {
+        create_dir_all(dir)?;
+
+        let vocab = index
+            .vocab
+            .iter()
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+            .collect_vec();
+        let mut token_ids = vec![0; index.vocab.len()];
+        for (token_id, (_token, &mutable_token_id)) in vocab.iter().enumerate() {
+            token_ids[mutable_token_id as usize] = token_id as TokenId;
+        }
+
+        let empty_posting = PostingList::default();
+        let postings = vocab
+            .iter()
+            .map(|(_token, &mutable_token_id)| {
+                index
+                    .postings
+                    .get(mutable_token_id as usize)
+                    .and_then(Option::as_ref)
+                    .unwrap_or(&empty_posting)
+            })
+            .collect_vec();
+
+        MmapLists::<u8>::create(dir, VOCAB, vocab.iter().map(|(token, _)| token.as_bytes()))?;
+        MmapLists::<PointOffsetType>::create(dir, POSTINGS, postings.iter().map(|p| p.ids()))?;
+        MmapLists::<u32>::create(dir, FREQUENCIES, postings.iter().map(|p| p.frequencies()))?;
+
+        let mut position_id = 0;
+        MmapLists::<u32>::create(
+            dir,
+            POSITION_IDS,
+            postings.iter().map(|posting| {
+                let start = position_id;
+                position_id += posting.all_positions().len() as u32;
+                (start..position_id).collect_vec()
+            }),
+        )?;
+        MmapLists::<u32>::create(
+            dir,
+            POSITIONS,
+            postings.iter().flat_map(|posting| posting.all_positions()),
+        )?;
+
+        MmapLists::<TokenId>::create(
+            dir,
+            POINT_TO_TOKENS,
+            index.point_to_docs.iter().map(|document| {
+                document
+                    .iter()
+                    .flat_map(|document| document.tokens())
+                    .map(|&token| token_ids[token as usize])
+                    .sorted_unstable()
+                    .collect_vec()
+            }),
+        )?;
+        create_mmap_slice(&dir.join(DOCUMENT_LENGTHS_PATH), &index.document_lengths)
+    }
+
+    pub fn open(dir: &Path) -> OperationResult<Self> {
+        let point_to_tokens = MmapLists::<TokenId>::open(dir, POINT_TO_TOKENS)?;
+        let document_lengths: MmapSlice<u32> = open_mmap_slice(&dir.join(DOCUMENT_LENGTHS_PATH))?;
+
+        let points_count = point_to_tokens
+            .iter()
+            .filter(|tokens| !tokens.is_empty())
+            .count();
+        let total_length = document_lengths.iter().map(|&length| length as usize).sum();
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            vocab: MmapLists::open(dir, VOCAB)?,
+            postings: MmapLists::open(dir, POSTINGS)?,
+            frequencies: MmapLists::open(dir, FREQUENCIES)?,
+            position_ids: MmapLists::open(dir, POSITION_IDS)?,
+            positions: MmapLists::open(dir, POSITIONS)?,
+            point_to_tokens,
+            document_lengths,
+            points_count,
+            total_length,
+        })
+    }
+
+    pub fn get_token_id(&self, token: &str) -> Option<TokenId> {
+        let position = self.vocab.partition_point(|other| other < token.as_bytes());
+        (self.vocab.get(position)? == token.as_bytes()).then_some(position as TokenId)
+    }
+
+    pub fn iter_vocab(&self) -> impl Iterator<Item = (&str, TokenId)> + '_ {
+        self.vocab.iter().enumerate().map(|(token_id, token)| {
+            let token = std::str::from_utf8(token).unwrap_or_default();
+            (token, token_id as TokenId)
+        })
+    }
+
+    pub fn posting_ids(&self, token: TokenId) -> Option<&[PointOffsetType]> {
+        self.postings.get(token as usize)
+    }
+
+    pub fn posting_frequencies(&self, token: TokenId) -> Option<&[u32]> {
+        self.frequencies.get(token as usize)
+    }
+
+    pub fn positions(&self, token: TokenId, idx: PointOffsetType) -> Option<&[u32]> {
+        let posting_idx = self.posting_ids(token)?.binary_search(&idx).ok()?;
+        let position_id = *self.position_ids.get(token as usize)?.get(posting_idx)?;
+        self.positions.get(position_id as usize)
+    }
+
+    pub fn document_length(&self, idx: PointOffsetType) -> u32 {
+        self.document_lengths
+            .get(idx as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sorted distinct tokens of the document, `None` if the document is not indexed
+    pub fn document_tokens(&self, idx: PointOffsetType) -> Option<&[TokenId]> {
+        self.point_to_tokens
+            .get(idx as usize)
+            .filter(|tokens| !tokens.is_empty())
+    }
+
+    /// Returns `false` if the document was not indexed
+    pub fn remove_document(&mut self, idx: PointOffsetType) -> bool {
+        let Some(removed_tokens) = self.point_to_tokens.take(idx as usize) else {
+            return false; // Never actually existed
+        };
+        if removed_tokens.is_empty() {
+            return false;
+        }
+        let removed_tokens = removed_tokens.to_vec();
+
+        self.points_count -= 1;
+        let document_length = std::mem::take(&mut self.document_lengths[idx as usize]);
+        self.total_length -= document_length as usize;
+
+        for token in removed_tokens {
+            let token = token as usize;
+            let Some(posting) = self.postings.get(token) else {
+                continue;
+            };
+            if let Ok(posting_idx) = posting.binary_search(&idx) {
+                self.postings.remove_at(token, posting_idx);
+                self.frequencies.remove_at(token, posting_idx);
+                if self
+                    .position_ids
+                    .get(token)
+                    .map_or(false, |ids| !ids.is_empty())
+                {
+                    self.position_ids.remove_at(token, posting_idx);
+                }
+            }
+        }
+        true
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        let flushers = [
+            self.postings.flusher(),
+            self.frequencies.flusher(),
+            self.position_ids.flusher(),
+            self.point_to_tokens.flusher(),
+            self.document_lengths.flusher(),
+        ];
+        Box::new(move || {
+            for flusher in flushers {
+                flusher()?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files = vec![];
+        for lists_files in [
+            self.vocab.files(),
+            self.postings.files(),
+            self.frequencies.files(),
+            self.position_ids.files(),
+            self.positions.files(),
+            self.point_to_tokens.files(),
+        ] {
+            files.extend(lists_files);
+        }
+        files.push(self.dir.join(DOCUMENT_LENGTHS_PATH));
+        files
+    }
+
+    pub fn clear(self) -> OperationResult<()> {
+        let dir = self.dir.clone();
+        drop(self);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod inverted_index;
+mod mmap_inverted_index;
 mod posting_list;
 mod postings_iterator;
 pub mod text_index;
@@ -1,16 +1,30 @@
 use common::types::PointOffsetType;
 
+/// Sorted positions of a token within a document
+pub type TokenPositions = Vec<u32>;
+
 #[derive(Clone, Debug, Default)]
 pub struct PostingList {
     list: Vec<PointOffsetType>,
+    /// Number of occurrences of the token in each document of the `list`
+    frequencies: Vec<u32>,
+    /// Token positions for each document of the `list`, only filled if phrase matching is enabled
+    positions: Vec<TokenPositions>,
 }
 
 impl PostingList {
-    pub fn new(idx: PointOffsetType) -> Self {
-        Self { list: vec![idx] }
+    pub fn new(idx: PointOffsetType, frequency: u32, positions: Option<TokenPositions>) -> Self {
+        let mut posting = Self::default();
+        posting.insert(idx, frequency, positions);
+        posting
     }
 
-    pub fn insert(&mut self, idx: PointOffsetType) {
+    pub fn insert(
+        &mut self,
+        idx: PointOffsetType,
+        frequency: u32,
+        positions: Option<TokenPositions>,
+    ) {
         if let Err(insertion_idx) = self.list.binary_search(&idx) {
             // Yes, this is O(n) but:
             // 1. That would give us maximal search performance with minimal memory usage
@@ -18,25 +32,43 @@ impl PostingList {
             // 3. Vector indexing is more expensive anyway
             // 4. We can separate updatable and remove-only indexes later
             self.list.insert(insertion_idx, idx);
+            self.frequencies.insert(insertion_idx, frequency);
+            if let Some(positions) = positions {
+                debug_assert_eq!(self.positions.len() + 1, self.list.len());
+                self.positions.insert(insertion_idx, positions);
+            }
         }
     }
 
     pub fn remove(&mut self, idx: PointOffsetType) {
         if let Ok(removal_idx) = self.list.binary_search(&idx) {
             self.list.remove(removal_idx);
+            self.frequencies.remove(removal_idx);
+            if !self.positions.is_empty() {
+                self.positions.remove(removal_idx);
+            }
         }
     }
 
-    pub fn len(&self) -> usize {
-        self.list.len()
+    /// Positions of the token in the given document, if they are stored
+    pub fn positions(&self, idx: PointOffsetType) -> Option<&[u32]> {
+        let position_idx = self.list.binary_search(&idx).ok()?;
+        self.positions.get(position_idx).map(Vec::as_slice)
+    }
+
+    /// Sorted documents of the posting
+    pub fn ids(&self) -> &[PointOffsetType] {
+        &self.list
     }
 
-    pub fn contains(&self, val: &PointOffsetType) -> bool {
-        self.list.binary_search(val).is_ok()
+    /// Number of occurrences of the token in each document of [`Self::ids`]
+    pub fn frequencies(&self) -> &[u32] {
+        &self.frequencies
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
-        self.list.iter().copied()
+    /// Token positions for each document of [`Self::ids`], empty if phrase matching is disabled
+    pub fn all_positions(&self) -> &[TokenPositions] {
+        &self.positions
     }
 }
 
@@ -1,9 +1,8 @@
 use common::types::PointOffsetType;
 
-use super::posting_list::PostingList;
-
+/// Iterate over the documents present in all of the sorted postings
 pub fn intersect_postings_iterator<'a>(
-    mut postings: Vec<&'a PostingList>,
+    mut postings: Vec<&'a [PointOffsetType]>,
 ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
     let smallest_posting_idx = postings
         .iter()
@@ -13,9 +12,11 @@ pub fn intersect_postings_iterator<'a>(
         .unwrap();
     let smallest_posting = postings.remove(smallest_posting_idx);
 
-    let and_iter = smallest_posting
-        .iter()
-        .filter(move |doc_id| postings.iter().all(|posting| posting.contains(doc_id)));
+    let and_iter = smallest_posting.iter().copied().filter(move |doc_id| {
+        postings
+            .iter()
+            .all(|posting| posting.binary_search(doc_id).is_ok())
+    });
 
     Box::new(and_iter)
 }
@@ -23,28 +24,29 @@ pub fn intersect_postings_iterator<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::index::field_index::full_text_index::posting_list::PostingList;
 
     #[test]
     fn test_postings_iterator() {
         let mut p1 = PostingList::default();
-        p1.insert(1);
-        p1.insert(2);
-        p1.insert(3);
-        p1.insert(4);
-        p1.insert(5);
+        p1.insert(1, 1, None);
+        p1.insert(2, 1, None);
+        p1.insert(3, 1, None);
+        p1.insert(4, 1, None);
+        p1.insert(5, 1, None);
         let mut p2 = PostingList::default();
-        p2.insert(2);
-        p2.insert(4);
-        p2.insert(5);
-        p2.insert(5);
+        p2.insert(2, 1, None);
+        p2.insert(4, 1, None);
+        p2.insert(5, 1, None);
+        p2.insert(5, 1, None);
         let mut p3 = PostingList::default();
-        p3.insert(1);
-        p3.insert(2);
-        p3.insert(5);
-        p3.insert(6);
-        p3.insert(7);
+        p3.insert(1, 1, None);
+        p3.insert(2, 1, None);
+        p3.insert(5, 1, None);
+        p3.insert(6, 1, None);
+        p3.insert(7, 1, None);
 
-        let postings = vec![&p1, &p2, &p3];
+        let postings = vec![p1.ids(), p2.ids(), p3.ids()];
         let merged = intersect_postings_iterator(postings);
 
         let res = merged.collect::<Vec<_>>();
@@ -2,7 +2,9 @@ use common::types::PointOffsetType;
 use tempfile::Builder;
 
 use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
-use crate::data_types::text_index::{TextIndexParams, TextIndexType, TokenizerType};
+use crate::data_types::text_index::{
+    Language, StopwordsInterface, TextIndexParams, TextIndexType, TokenizerType,
+};
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::ValueIndexer;
 
@@ -158,6 +160,11 @@ fn test_prefix_search() {
         min_token_len: None,
         max_token_len: None,
         lowercase: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        ascii_folding: None,
+        on_disk: None,
     };
 
     let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
@@ -177,8 +184,7 @@ fn test_prefix_search() {
     let query = index.parse_query("ROBO");
 
     for idx in res.iter() {
-        let doc = index.get_doc(*idx).unwrap();
-        assert!(query.check_match(doc));
+        assert!(index.check_match(&query, *idx));
     }
 
     assert_eq!(res.len(), 3);
@@ -188,9 +194,101 @@ fn test_prefix_search() {
     let query = index.parse_query("q231");
 
     for idx in [1, 2, 3] {
-        let doc = index.get_doc(idx).unwrap();
-        assert!(!query.check_match(doc));
+        assert!(index.values_count(idx) > 0);
+        assert!(!index.check_match(&query, idx));
     }
 
     assert_eq!(res.len(), 0);
 }
+
+#[test]
+fn test_prefix_phrase_search() {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Prefix,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        phrase_matching: Some(true),
+        stopwords: None,
+        stemmer: None,
+        ascii_folding: None,
+        on_disk: None,
+    };
+
+    let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+    let mut index = FullTextIndex::new(db, config, "text");
+    index.recreate().unwrap();
+
+    let texts = get_texts();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.to_string()])
+            .unwrap();
+    }
+
+    let res: Vec<_> = index
+        .query_phrase("the weapon")
+        .map(|idx| texts[idx as usize].as_str())
+        .collect();
+    assert_eq!(res, vec!["The Weapon", "The Weapon Too Dreadful to Use"]);
+
+    // Tokens are matched by prefix, but must still be next to each other
+    let res: Vec<_> = index
+        .query_phrase("weap too dread")
+        .map(|idx| texts[idx as usize].as_str())
+        .collect();
+    assert_eq!(res, vec!["The Weapon Too Dreadful to Use"]);
+
+    let res: Vec<_> = index.query_phrase("weapon the").collect();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_normalized_search() {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        phrase_matching: None,
+        stopwords: Some(StopwordsInterface::Language(Language::English)),
+        stemmer: Some(Language::English),
+        ascii_folding: Some(true),
+        on_disk: None,
+    };
+
+    let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+    let mut index = FullTextIndex::new(db, config, "text");
+    index.recreate().unwrap();
+
+    let texts = get_texts();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.to_string()])
+            .unwrap();
+    }
+
+    // Stemming matches other forms of the word
+    let res: Vec<_> = index
+        .query("hunter")
+        .map(|idx| texts[idx as usize].as_str())
+        .collect();
+    assert_eq!(res, vec!["Day of the Hunters"]);
+
+    // ASCII folding matches words regardless of the diacritics
+    let res: Vec<_> = index
+        .query("Ganymède")
+        .map(|idx| texts[idx as usize].as_str())
+        .collect();
+    assert_eq!(res, vec!["Christmas on Ganymede"]);
+
+    // Stopwords are excluded from both documents and queries
+    let res: Vec<_> = index.query("the").collect();
+    assert!(res.is_empty());
+}
@@ -1,7 +1,9 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use common::types::PointOffsetType;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoredPointOffset};
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde::{Deserialize, Serialize};
@@ -11,9 +13,12 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::data_types::text_index::TextIndexParams;
+use crate::data_types::text_statistics::TextStatistics;
 use crate::index::field_index::full_text_index::inverted_index::{
-    Document, InvertedIndex, ParsedQuery,
+    Document, InvertedIndex, MutableInvertedIndex, ParsedPhrase, ParsedQuery, TokenId,
 };
+use crate::index::field_index::full_text_index::mmap_inverted_index::MmapInvertedIndex;
+use crate::index::field_index::full_text_index::posting_list::TokenPositions;
 use crate::index::field_index::full_text_index::tokenizers::Tokenizer;
 use crate::index::field_index::{
     CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, ValueIndexer,
@@ -21,10 +26,18 @@ use crate::index::field_index::{
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{FieldCondition, Match, PayloadKeyType};
 
+/// Document restored from the storage, with token frequencies and optional token positions
+type IndexedDocument = (
+    Document,
+    HashMap<TokenId, u32>,
+    Option<HashMap<TokenId, TokenPositions>>,
+);
+
 pub struct FullTextIndex {
     inverted_index: InvertedIndex,
     db_wrapper: DatabaseColumnWrapper,
     config: TextIndexParams,
+    tokenizer: Tokenizer,
 }
 
 impl FullTextIndex {
@@ -36,27 +49,55 @@ impl FullTextIndex {
         bincode::deserialize(data).unwrap()
     }
 
-    fn serialize_document_tokens(&self, tokens: BTreeSet<String>) -> OperationResult<Vec<u8>> {
+    fn serialize_document_tokens(
+        &self,
+        tokens: BTreeSet<String>,
+        frequencies: BTreeMap<String, u32>,
+        positions: Option<BTreeMap<String, TokenPositions>>,
+    ) -> OperationResult<Vec<u8>> {
         #[derive(Serialize)]
         struct StoredDocument {
             tokens: BTreeSet<String>,
+            /// Only tokens which occur more than once
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            frequencies: BTreeMap<String, u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            positions: Option<BTreeMap<String, TokenPositions>>,
         }
-        let doc = StoredDocument { tokens };
+        let doc = StoredDocument {
+            tokens,
+            frequencies,
+            positions,
+        };
         serde_cbor::to_vec(&doc).map_err(|e| {
             OperationError::service_error(format!("Failed to serialize document: {e}"))
         })
     }
 
-    fn deserialize_document(data: &[u8], index: &mut InvertedIndex) -> OperationResult<Document> {
+    fn deserialize_document(
+        data: &[u8],
+        index: &mut MutableInvertedIndex,
+    ) -> OperationResult<IndexedDocument> {
         #[derive(Deserialize)]
         struct StoredDocument {
             tokens: BTreeSet<String>,
+            #[serde(default)]
+            frequencies: BTreeMap<String, u32>,
+            #[serde(default)]
+            positions: Option<BTreeMap<String, TokenPositions>>,
         }
         serde_cbor::from_slice::<StoredDocument>(data)
             .map_err(|e| {
                 OperationError::service_error(format!("Failed to deserialize document: {e}"))
             })
-            .map(|doc| index.document_from_tokens(&doc.tokens))
+            .map(|doc| {
+                let document = index.document_from_tokens(&doc.tokens);
+                let frequencies = index.token_frequencies_from_tokens(&doc.frequencies);
+                let positions = doc
+                    .positions
+                    .map(|positions| index.token_positions_from_tokens(&positions));
+                (document, frequencies, positions)
+            })
     }
 
     fn storage_cf_name(field: &str) -> String {
@@ -69,45 +110,182 @@ impl FullTextIndex {
         FullTextIndex {
             inverted_index: InvertedIndex::new(),
             db_wrapper,
+            tokenizer: Tokenizer::new(&config),
             config,
         }
     }
 
-    pub fn get_doc(&self, idx: PointOffsetType) -> Option<&Document> {
-        match self.inverted_index.point_to_docs.get(idx as usize) {
-            Some(Some(doc)) => Some(doc),
-            _ => None,
-        }
+    /// Check that the document of the point contains all tokens of the query
+    pub fn check_match(&self, query: &ParsedQuery, idx: PointOffsetType) -> bool {
+        self.inverted_index
+            .document_tokens(idx)
+            .map_or(false, |tokens| query.check_tokens(tokens))
     }
 
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
-            points_values_count: self.inverted_index.points_count,
-            points_count: self.inverted_index.points_count,
+            points_values_count: self.inverted_index.points_count(),
+            points_count: self.inverted_index.points_count(),
             histogram_bucket_size: None,
         }
     }
 
     pub fn recreate(&self) -> OperationResult<()> {
-        self.db_wrapper.recreate_column_family()
+        match self.inverted_index {
+            InvertedIndex::Mutable(_) => self.db_wrapper.recreate_column_family(),
+            InvertedIndex::Mmap(_) => Ok(()),
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        self.inverted_index.save_mmap(dir)
+    }
+
+    /// Replace the index with the memory mapped one, saved in the directory with [`Self::save_mmap`]
+    pub fn into_mmap(self, dir: &Path) -> OperationResult<Self> {
+        self.db_wrapper.remove_column_family()?;
+        Ok(FullTextIndex {
+            inverted_index: InvertedIndex::Mmap(Box::new(MmapInvertedIndex::open(dir)?)),
+            ..self
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.inverted_index.files()
     }
 
     pub fn parse_query(&self, text: &str) -> ParsedQuery {
         let mut tokens = HashSet::new();
-        Tokenizer::tokenize_query(text, &self.config, |token| {
-            tokens.insert(self.inverted_index.vocab.get(token).copied());
+        self.tokenizer.tokenize_query(text, |token| {
+            tokens.insert(self.inverted_index.get_token_id(token));
         });
         ParsedQuery {
             tokens: tokens.into_iter().collect(),
         }
     }
 
+    /// Distinct tokens of the query text
+    fn query_tokens(&self, text: &str) -> BTreeSet<String> {
+        let mut tokens = BTreeSet::new();
+        self.tokenizer.tokenize_query(text, |token| {
+            tokens.insert(token.to_owned());
+        });
+        tokens
+    }
+
+    /// Statistics of the query tokens in this index, required to compute BM25 scores
+    pub fn text_statistics(&self, text: &str) -> TextStatistics {
+        let document_frequencies = self
+            .query_tokens(text)
+            .into_iter()
+            .map(|token| {
+                let frequency = self
+                    .inverted_index
+                    .get_token_id(&token)
+                    .map_or(0, |token_id| {
+                        self.inverted_index.document_frequency(token_id)
+                    });
+                (token, frequency)
+            })
+            .collect();
+        TextStatistics {
+            documents_count: self.inverted_index.points_count(),
+            total_length: self.inverted_index.total_length(),
+            document_frequencies,
+        }
+    }
+
+    /// Rank the documents containing any token of the query text with BM25
+    ///
+    /// `statistics` may be collected from several indexes, so scores of these indexes are
+    /// comparable. Returns at most `top` documents which pass the `check`, best first.
+    pub fn search_bm25(
+        &self,
+        text: &str,
+        statistics: &TextStatistics,
+        check: impl Fn(PointOffsetType) -> bool,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        if top == 0 {
+            return vec![];
+        }
+        let tokens: Vec<_> = self
+            .query_tokens(text)
+            .iter()
+            .filter_map(|token| {
+                let token_id = self.inverted_index.get_token_id(token)?;
+                Some((token_id, statistics.idf(token)))
+            })
+            .collect();
+
+        let mut top_documents = FixedLengthPriorityQueue::new(top);
+        for (idx, score) in self.inverted_index.score_bm25(&tokens, statistics) {
+            if check(idx) {
+                top_documents.push(ScoredPointOffset { idx, score });
+            }
+        }
+        top_documents.into_vec()
+    }
+
+    fn phrase_matching(&self) -> bool {
+        self.config.phrase_matching.unwrap_or(false)
+    }
+
+    /// Returns `None` if the index does not store token positions, so phrases can't be matched
+    pub fn parse_phrase(&self, text: &str) -> Option<ParsedPhrase> {
+        if !self.phrase_matching() {
+            return None;
+        }
+        let mut tokens = vec![];
+        self.tokenizer.tokenize_query(text, |token| {
+            tokens.push(self.inverted_index.get_token_id(token));
+        });
+        Some(ParsedPhrase { tokens })
+    }
+
+    pub fn check_phrase(&self, phrase: &ParsedPhrase, idx: PointOffsetType) -> bool {
+        self.inverted_index.check_phrase(phrase, idx)
+    }
+
+    /// Check that the phrase is contained in the text, tokenized the same way as the documents
+    pub fn check_phrase_in_text(&self, phrase: &ParsedPhrase, text: &str) -> bool {
+        let mut positions: HashMap<TokenId, TokenPositions> = HashMap::new();
+        self.tokenizer
+            .tokenize_doc_with_positions(text, |token, position| {
+                if let Some(token_id) = self.inverted_index.get_token_id(token) {
+                    positions.entry(token_id).or_default().push(position);
+                }
+            });
+        phrase.check_positions(|token_id| positions.get(&token_id).map(Vec::as_slice))
+    }
+
+    /// Positions of the tokens in the values, used for phrase matching
+    fn tokenize_positions(&self, values: &[String]) -> BTreeMap<String, TokenPositions> {
+        let mut positions: BTreeMap<String, TokenPositions> = BTreeMap::new();
+        let mut offset = 0;
+        for value in values {
+            let mut value_len = 0;
+            self.tokenizer
+                .tokenize_doc_with_positions(value, |token, position| {
+                    positions
+                        .entry(token.to_owned())
+                        .or_default()
+                        .push(offset + position);
+                    value_len = position + 1;
+                });
+            // Leave a gap, so that phrases don't match across the values
+            offset += value_len + 1;
+        }
+        positions
+    }
+
     pub fn parse_document(&self, text: &str) -> Document {
         let mut document_tokens = vec![];
-        Tokenizer::tokenize_doc(text, &self.config, |token| {
-            if let Some(token_id) = self.inverted_index.vocab.get(token) {
-                document_tokens.push(*token_id);
+        self.tokenizer.tokenize_doc(text, |token| {
+            if let Some(token_id) = self.inverted_index.get_token_id(token) {
+                document_tokens.push(token_id);
             }
         });
         Document::new(document_tokens)
@@ -119,13 +297,21 @@ impl FullTextIndex {
         self.inverted_index.filter(&parsed_query)
     }
 
+    #[cfg(test)]
+    pub fn query_phrase(&self, phrase: &str) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        let parsed_phrase = self.parse_phrase(phrase).unwrap();
+        self.inverted_index.filter_phrase(parsed_phrase)
+    }
+
     pub fn values_count(&self, point_id: PointOffsetType) -> usize {
         // Maybe we want number of documents in the future?
-        self.get_doc(point_id).map(|x| x.len()).unwrap_or(0)
+        self.inverted_index
+            .document_tokens(point_id)
+            .map_or(0, <[_]>::len)
     }
 
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
-        self.get_doc(point_id).map(|x| x.is_empty()).unwrap_or(true)
+        self.values_count(point_id) == 0
     }
 }
 
@@ -135,19 +321,40 @@ impl ValueIndexer<String> for FullTextIndex {
             return Ok(());
         }
 
-        let mut tokens: BTreeSet<String> = BTreeSet::new();
+        let mut token_counts: BTreeMap<String, u32> = BTreeMap::new();
 
-        for value in values {
-            Tokenizer::tokenize_doc(&value, &self.config, |token| {
-                tokens.insert(token.to_owned());
+        for value in &values {
+            self.tokenizer.tokenize_doc(value, |token| {
+                *token_counts.entry(token.to_owned()).or_default() += 1;
             });
         }
 
-        let document = self.inverted_index.document_from_tokens(&tokens);
-        self.inverted_index.index_document(idx, document);
+        let tokens: BTreeSet<String> = token_counts.keys().cloned().collect();
+        // Tokens which occur once are implied, to keep the storage small
+        let frequencies: BTreeMap<String, u32> = token_counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .collect();
+
+        let positions = self
+            .phrase_matching()
+            .then(|| self.tokenize_positions(&values));
+
+        let InvertedIndex::Mutable(inverted_index) = &mut self.inverted_index else {
+            return Err(OperationError::service_error(
+                "Can't add values to immutable full text index",
+            ));
+        };
+
+        let document = inverted_index.document_from_tokens(&tokens);
+        let token_frequencies = inverted_index.token_frequencies_from_tokens(&frequencies);
+        let token_positions = positions
+            .as_ref()
+            .map(|positions| inverted_index.token_positions_from_tokens(positions));
+        inverted_index.index_document(idx, document, token_frequencies, token_positions);
 
         let db_idx = Self::store_key(&idx);
-        let db_document = self.serialize_document_tokens(tokens)?;
+        let db_document = self.serialize_document_tokens(tokens, frequencies, positions)?;
 
         self.db_wrapper.put(db_idx, db_document)?;
 
@@ -162,9 +369,9 @@ impl ValueIndexer<String> for FullTextIndex {
     }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
-        let removed_doc = self.inverted_index.remove_document(id);
+        let is_removed = self.inverted_index.remove_document(id);
 
-        if removed_doc.is_none() {
+        if !is_removed || matches!(self.inverted_index, InvertedIndex::Mmap(_)) {
             return Ok(());
         }
 
@@ -177,37 +384,55 @@ impl ValueIndexer<String> for FullTextIndex {
 
 impl PayloadFieldIndex for FullTextIndex {
     fn count_indexed_points(&self) -> usize {
-        self.inverted_index.points_count
+        self.inverted_index.points_count()
     }
 
     fn load(&mut self) -> OperationResult<bool> {
+        let InvertedIndex::Mutable(inverted_index) = &mut self.inverted_index else {
+            return Ok(true);
+        };
+
         if !self.db_wrapper.has_column_family()? {
             return Ok(false);
         };
 
         for (key, value) in self.db_wrapper.lock_db().iter()? {
             let idx = Self::restore_key(&key);
-            let document = Self::deserialize_document(&value, &mut self.inverted_index)?;
-            self.inverted_index.index_document(idx, document);
+            let (document, frequencies, positions) =
+                Self::deserialize_document(&value, inverted_index)?;
+            inverted_index.index_document(idx, document, frequencies, positions);
         }
         Ok(true)
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.db_wrapper.remove_column_family()
+        match self.inverted_index {
+            InvertedIndex::Mutable(_) => self.db_wrapper.remove_column_family(),
+            InvertedIndex::Mmap(index) => index.clear(),
+        }
     }
 
     fn flusher(&self) -> Flusher {
-        self.db_wrapper.flusher()
+        self.inverted_index
+            .flusher()
+            .unwrap_or_else(|| self.db_wrapper.flusher())
     }
 
     fn filter(
         &self,
         condition: &FieldCondition,
     ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
-        if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
-            return Ok(self.inverted_index.filter(&parsed_query));
+        match &condition.r#match {
+            Some(Match::Text(text_match)) => {
+                let parsed_query = self.parse_query(&text_match.text);
+                return Ok(self.inverted_index.filter(&parsed_query));
+            }
+            Some(Match::Phrase(phrase_match)) => {
+                if let Some(parsed_phrase) = self.parse_phrase(&phrase_match.phrase) {
+                    return Ok(self.inverted_index.filter_phrase(parsed_phrase));
+                }
+            }
+            _ => {}
         }
         Err(OperationError::service_error("failed to filter"))
     }
@@ -216,11 +441,21 @@ impl PayloadFieldIndex for FullTextIndex {
         &self,
         condition: &FieldCondition,
     ) -> OperationResult<CardinalityEstimation> {
-        if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
-            return Ok(self
-                .inverted_index
-                .estimate_cardinality(&parsed_query, condition));
+        match &condition.r#match {
+            Some(Match::Text(text_match)) => {
+                let parsed_query = self.parse_query(&text_match.text);
+                return Ok(self
+                    .inverted_index
+                    .estimate_cardinality(&parsed_query, condition));
+            }
+            Some(Match::Phrase(phrase_match)) => {
+                if let Some(parsed_phrase) = self.parse_phrase(&phrase_match.phrase) {
+                    return Ok(self
+                        .inverted_index
+                        .estimate_phrase_cardinality(&parsed_phrase, condition));
+                }
+            }
+            _ => {}
         }
         Err(OperationError::service_error(
             "failed to estimate cardinality",
@@ -244,7 +479,7 @@ mod tests {
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
     use crate::common::utils::MultiValue;
     use crate::data_types::text_index::{TextIndexType, TokenizerType};
-    use crate::types::MatchText;
+    use crate::types::{MatchPhrase, MatchText};
 
     fn filter_request(text: &str) -> FieldCondition {
         FieldCondition {
@@ -257,9 +492,20 @@ mod tests {
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
         }
     }
 
+    fn phrase_request(phrase: &str) -> FieldCondition {
+        FieldCondition::new_match(
+            "text",
+            Match::Phrase(MatchPhrase {
+                phrase: phrase.to_owned(),
+            }),
+        )
+    }
+
     #[test]
     fn test_full_text_indexing() {
         let payloads: Vec<_> = vec![
@@ -280,6 +526,11 @@ mod tests {
             min_token_len: None,
             max_token_len: None,
             lowercase: None,
+            phrase_matching: None,
+            stopwords: None,
+            stemmer: None,
+            ascii_folding: None,
+            on_disk: None,
         };
 
         {
@@ -350,4 +601,276 @@ mod tests {
             assert_eq!(search_res, vec![0, 1, 3, 4]);
         }
     }
+
+    #[test]
+    fn test_phrase_matching() {
+        let payloads: Vec<_> = vec![
+            serde_json::json!("New York is a big city"),
+            serde_json::json!("York is new, the city is big"),
+            serde_json::json!(["It is a new", "York"]),
+            serde_json::json!("The New York Times, new york"),
+        ];
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            min_token_len: None,
+            max_token_len: None,
+            lowercase: None,
+            phrase_matching: Some(true),
+            stopwords: None,
+            stemmer: None,
+            ascii_folding: None,
+            on_disk: None,
+        };
+
+        {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config.clone(), "text");
+            index.recreate().unwrap();
+
+            for (idx, payload) in payloads.iter().enumerate() {
+                index
+                    .add_point(idx as PointOffsetType, &MultiValue::one(payload))
+                    .unwrap();
+            }
+
+            // Text match ignores token positions
+            let search_res: Vec<_> = index.filter(&filter_request("new york")).unwrap().collect();
+            assert_eq!(search_res, vec![0, 1, 2, 3]);
+
+            let search_res: Vec<_> = index.filter(&phrase_request("new york")).unwrap().collect();
+            assert_eq!(search_res, vec![0, 3]);
+
+            let search_res: Vec<_> = index.filter(&phrase_request("york new")).unwrap().collect();
+            assert!(search_res.is_empty());
+
+            let search_res: Vec<_> = index
+                .filter(&phrase_request("city is big"))
+                .unwrap()
+                .collect();
+            assert_eq!(search_res, vec![1]);
+
+            let search_res: Vec<_> = index.filter(&phrase_request("boston")).unwrap().collect();
+            assert!(search_res.is_empty());
+
+            let estimation = index
+                .estimate_cardinality(&phrase_request("new york"))
+                .unwrap();
+            assert_eq!(estimation.min, 0);
+            assert_eq!(estimation.max, 4);
+
+            let phrase = index.parse_phrase("new york").unwrap();
+            assert!(index.check_phrase(&phrase, 0));
+            assert!(!index.check_phrase(&phrase, 1));
+            assert!(!index.check_phrase(&phrase, 2));
+            assert!(index.check_phrase_in_text(&phrase, "I love New York"));
+            assert!(!index.check_phrase_in_text(&phrase, "I love New Jersey and York"));
+
+            index.remove_point(0).unwrap();
+            let search_res: Vec<_> = index.filter(&phrase_request("new york")).unwrap().collect();
+            assert_eq!(search_res, vec![3]);
+
+            index.flusher()().unwrap();
+        }
+
+        {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config, "text");
+            assert!(index.load().unwrap());
+
+            let search_res: Vec<_> = index.filter(&phrase_request("new york")).unwrap().collect();
+            assert_eq!(search_res, vec![3]);
+
+            let search_res: Vec<_> = index.filter(&phrase_request("is new")).unwrap().collect();
+            assert_eq!(search_res, vec![1]);
+
+            let search_res: Vec<_> = index.filter(&phrase_request("a new")).unwrap().collect();
+            assert_eq!(search_res, vec![2]);
+        }
+    }
+
+    #[test]
+    fn test_bm25_search() {
+        let payloads: Vec<_> = vec![
+            serde_json::json!("Apple pie with apple sauce"),
+            serde_json::json!(["A pie", "with cherries"]),
+            serde_json::json!("Apple"),
+            serde_json::json!("Banana bread"),
+        ];
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let config = TextIndexParams::default();
+
+        let search = |index: &FullTextIndex, text: &str| -> Vec<ScoredPointOffset> {
+            let statistics = index.text_statistics(text);
+            index.search_bm25(text, &statistics, |_| true, 10)
+        };
+
+        let scores_before_reload = {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config.clone(), "text");
+            index.recreate().unwrap();
+
+            for (idx, payload) in payloads.iter().enumerate() {
+                index
+                    .add_point(idx as PointOffsetType, &MultiValue::one(payload))
+                    .unwrap();
+            }
+
+            let statistics = index.text_statistics("apple pie");
+            assert_eq!(statistics.documents_count, 4);
+            assert_eq!(statistics.total_length, 5 + 4 + 1 + 2);
+            assert_eq!(statistics.document_frequencies["apple"], 2);
+            assert_eq!(statistics.document_frequencies["pie"], 2);
+
+            let result = search(&index, "apple pie");
+            let ids: Vec<_> = result.iter().map(|point| point.idx).collect();
+            assert_eq!(ids, vec![0, 2, 1]);
+
+            // Unknown tokens don't contribute to the score
+            let result = search(&index, "banana smoothie");
+            let ids: Vec<_> = result.iter().map(|point| point.idx).collect();
+            assert_eq!(ids, vec![3]);
+
+            let result = index.search_bm25("apple", &statistics, |idx| idx != 0, 10);
+            let ids: Vec<_> = result.iter().map(|point| point.idx).collect();
+            assert_eq!(ids, vec![2]);
+            assert!(search(&index, "").is_empty());
+
+            index.remove_point(3).unwrap();
+            assert!(search(&index, "banana").is_empty());
+
+            index.flusher()().unwrap();
+            search(&index, "apple pie")
+        };
+
+        {
+            let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+            let mut index = FullTextIndex::new(db, config, "text");
+            assert!(index.load().unwrap());
+
+            // Term frequencies and document lengths are restored
+            assert_eq!(search(&index, "apple pie"), scores_before_reload);
+        }
+    }
+
+    #[test]
+    fn test_phrase_matching_disabled() {
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut index = FullTextIndex::new(db, TextIndexParams::default(), "text");
+        index.recreate().unwrap();
+
+        let payload = serde_json::json!("New York is a big city");
+        index.add_point(0, &MultiValue::one(&payload)).unwrap();
+
+        // Without token positions, the index can't be used for phrases
+        assert!(index.parse_phrase("new york").is_none());
+        assert!(index.filter(&phrase_request("new york")).is_err());
+        assert!(index
+            .estimate_cardinality(&phrase_request("new york"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_mmap_full_text_index() {
+        let payloads: Vec<_> = vec![
+            serde_json::json!("New York is a big city"),
+            serde_json::json!("York is new, the city is big"),
+            serde_json::json!(["It is a new", "York"]),
+            serde_json::json!("The New York Times, new york"),
+            serde_json::json!("Apple pie with apple sauce"),
+        ];
+
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let config = TextIndexParams {
+            phrase_matching: Some(true),
+            ..Default::default()
+        };
+
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut index = FullTextIndex::new(db, config.clone(), "text");
+        index.recreate().unwrap();
+        for (idx, payload) in payloads.iter().enumerate() {
+            index
+                .add_point(idx as PointOffsetType, &MultiValue::one(payload))
+                .unwrap();
+        }
+
+        let mmap_dir = temp_dir.path().join("mmap");
+        index.save_mmap(&mmap_dir).unwrap();
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut mmap_index = FullTextIndex::new(db, config.clone(), "text")
+            .into_mmap(&mmap_dir)
+            .unwrap();
+        assert!(mmap_index.load().unwrap());
+
+        assert_eq!(
+            mmap_index.count_indexed_points(),
+            index.count_indexed_points()
+        );
+        for idx in 0..payloads.len() as PointOffsetType {
+            assert_eq!(mmap_index.values_count(idx), index.values_count(idx));
+        }
+
+        let conditions = [
+            filter_request("new york"),
+            filter_request("apple"),
+            filter_request("boston"),
+            phrase_request("new york"),
+            phrase_request("is new"),
+            phrase_request("york new"),
+        ];
+        for condition in &conditions {
+            assert_eq!(
+                mmap_index.filter(condition).unwrap().collect::<Vec<_>>(),
+                index.filter(condition).unwrap().collect::<Vec<_>>(),
+            );
+            let mmap_estimation = mmap_index.estimate_cardinality(condition).unwrap();
+            let estimation = index.estimate_cardinality(condition).unwrap();
+            assert_eq!(
+                (
+                    mmap_estimation.min,
+                    mmap_estimation.exp,
+                    mmap_estimation.max
+                ),
+                (estimation.min, estimation.exp, estimation.max),
+            );
+        }
+
+        let statistics = index.text_statistics("apple city");
+        assert_eq!(mmap_index.text_statistics("apple city"), statistics);
+        assert_eq!(
+            mmap_index.search_bm25("apple city", &statistics, |_| true, 10),
+            index.search_bm25("apple city", &statistics, |_| true, 10),
+        );
+
+        // Immutable index can't be extended
+        assert!(mmap_index
+            .add_point(5, &MultiValue::one(&serde_json::json!("Boston")))
+            .is_err());
+
+        mmap_index.remove_point(0).unwrap();
+        mmap_index.flusher()().unwrap();
+        let search_res: Vec<_> = mmap_index
+            .filter(&phrase_request("new york"))
+            .unwrap()
+            .collect();
+        assert_eq!(search_res, vec![3]);
+        assert_eq!(mmap_index.count_indexed_points(), payloads.len() - 1);
+
+        // Removal is persisted
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mmap_index = FullTextIndex::new(db, config, "text")
+            .into_mmap(&mmap_dir)
+            .unwrap();
+        assert!(mmap_index.values_is_empty(0));
+        let search_res: Vec<_> = mmap_index
+            .filter(&filter_request("new york"))
+            .unwrap()
+            .collect();
+        assert_eq!(search_res, vec![1, 2, 3]);
+    }
 }
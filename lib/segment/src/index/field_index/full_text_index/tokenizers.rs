@@ -1,6 +1,15 @@
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
+
 use charabia::Tokenize;
+use rust_stemmers::{Algorithm, Stemmer};
+use stop_words::LANGUAGE;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
-use crate::data_types::text_index::{TextIndexParams, TokenizerType};
+use crate::data_types::text_index::{
+    Language, StopwordsInterface, StopwordsSet, TextIndexParams, TokenizerType,
+};
 
 struct WhiteSpaceTokenizer;
 
@@ -74,64 +83,229 @@ impl MultilingualTokenizer {
     }
 }
 
-pub struct Tokenizer;
+pub struct Tokenizer {
+    config: TextIndexParams,
+    stopwords: HashSet<String>,
+    stemmer: Option<Stemmer>,
+}
 
 impl Tokenizer {
-    fn doc_token_filter<'a, C: FnMut(&str) + 'a>(
-        config: &'a TextIndexParams,
-        mut callback: C,
-    ) -> impl FnMut(&str) + 'a {
-        move |token: &str| {
-            if config
-                .min_token_len
-                .map(|min_len| token.len() < min_len && token.chars().count() < min_len)
-                .unwrap_or(false)
-            {
-                return;
+    pub fn new(config: &TextIndexParams) -> Self {
+        let mut tokenizer = Tokenizer {
+            config: config.clone(),
+            stopwords: HashSet::new(),
+            stemmer: config
+                .stemmer
+                .map(|language| Stemmer::create(stemmer_algorithm(language))),
+        };
+
+        let (languages, custom) = match &config.stopwords {
+            None => (Default::default(), Default::default()),
+            Some(StopwordsInterface::Language(language)) => {
+                (BTreeSet::from([*language]), Default::default())
             }
-            if config
-                .max_token_len
-                .map(|max_len| token.len() > max_len && token.chars().count() > max_len)
-                .unwrap_or(false)
-            {
-                return;
+            Some(StopwordsInterface::Set(StopwordsSet { languages, custom })) => {
+                (languages.clone(), custom.clone())
             }
-            if config.lowercase.unwrap_or(true) {
-                callback(&token.to_lowercase());
-            } else {
-                callback(token);
+        };
+        let builtin_stopwords = languages.into_iter().flat_map(|language| {
+            stop_words::get(stopwords_language(language))
+                .iter()
+                .copied()
+        });
+        // Stopwords are compared with normalized words, so they are normalized the same way
+        let stopwords = builtin_stopwords
+            .chain(custom.iter().map(String::as_str))
+            .map(|stopword| tokenizer.normalize_case(stopword).into_owned())
+            .collect();
+        tokenizer.stopwords = stopwords;
+
+        tokenizer
+    }
+
+    fn token_len_filter(&self, token: &str) -> bool {
+        if self
+            .config
+            .min_token_len
+            .map(|min_len| token.len() < min_len && token.chars().count() < min_len)
+            .unwrap_or(false)
+        {
+            return false;
+        }
+        if self
+            .config
+            .max_token_len
+            .map(|max_len| token.len() > max_len && token.chars().count() > max_len)
+            .unwrap_or(false)
+        {
+            return false;
+        }
+        true
+    }
+
+    /// Lowercase and fold to ASCII, if configured
+    fn normalize_case<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        let mut word = Cow::Borrowed(word);
+        if self.config.lowercase.unwrap_or(true) {
+            word = Cow::Owned(word.to_lowercase());
+        }
+        if self.config.ascii_folding.unwrap_or(false) && !word.is_ascii() {
+            word = Cow::Owned(fold_to_ascii(&word));
+        }
+        word
+    }
+
+    /// Normalize the word as configured, returns `None` for stopwords
+    fn normalize_word<'a>(&self, word: &'a str) -> Option<Cow<'a, str>> {
+        let word = self.normalize_case(word);
+        if self.stopwords.contains(word.as_ref()) {
+            return None;
+        }
+        match &self.stemmer {
+            Some(stemmer) => Some(Cow::Owned(stemmer.stem(&word).into_owned())),
+            None => Some(word),
+        }
+    }
+
+    /// Split the text into normalized words, without stopwords
+    fn tokenize_words<C: FnMut(&str)>(&self, text: &str, mut callback: C) {
+        let word_callback = |word: &str| {
+            if let Some(word) = self.normalize_word(word) {
+                callback(&word);
+            }
+        };
+        match self.config.tokenizer {
+            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, word_callback),
+            TokenizerType::Word | TokenizerType::Prefix => {
+                WordTokenizer::tokenize(text, word_callback)
             }
+            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, word_callback),
         }
     }
 
-    pub fn tokenize_doc<C: FnMut(&str)>(text: &str, config: &TextIndexParams, mut callback: C) {
-        let token_filter = Self::doc_token_filter(config, &mut callback);
-        match config.tokenizer {
-            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, token_filter),
-            TokenizerType::Word => WordTokenizer::tokenize(text, token_filter),
-            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, token_filter),
+    /// Tokens of a normalized word: the word itself, or its prefixes for the prefix tokenizer
+    fn tokenize_word<C: FnMut(&str)>(&self, word: &str, is_query: bool, mut callback: C) {
+        let mut token_filter = |token: &str| {
+            if self.token_len_filter(token) {
+                callback(token);
+            }
+        };
+        match self.config.tokenizer {
+            TokenizerType::Prefix if is_query => PrefixTokenizer::tokenize_query(
+                word,
+                self.config.max_token_len.unwrap_or(usize::MAX),
+                token_filter,
+            ),
             TokenizerType::Prefix => PrefixTokenizer::tokenize(
-                text,
-                config.min_token_len.unwrap_or(1),
-                config.max_token_len.unwrap_or(usize::MAX),
+                word,
+                self.config.min_token_len.unwrap_or(1),
+                self.config.max_token_len.unwrap_or(usize::MAX),
                 token_filter,
             ),
+            TokenizerType::Whitespace | TokenizerType::Word | TokenizerType::Multilingual => {
+                token_filter(word)
+            }
         }
     }
 
-    pub fn tokenize_query<C: FnMut(&str)>(text: &str, config: &TextIndexParams, mut callback: C) {
-        let token_filter = Self::doc_token_filter(config, &mut callback);
-        match config.tokenizer {
-            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, token_filter),
-            TokenizerType::Word => WordTokenizer::tokenize(text, token_filter),
-            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, token_filter),
-            TokenizerType::Prefix => PrefixTokenizer::tokenize_query(
-                text,
-                config.max_token_len.unwrap_or(usize::MAX),
-                token_filter,
-            ),
+    pub fn tokenize_doc<C: FnMut(&str)>(&self, text: &str, mut callback: C) {
+        self.tokenize_words(text, |word| self.tokenize_word(word, false, &mut callback));
+    }
+
+    /// Tokenize the document, along with the position of the word each token comes from.
+    ///
+    /// Prefix tokenizer produces several tokens per word, all of them share the word position.
+    pub fn tokenize_doc_with_positions<C: FnMut(&str, u32)>(&self, text: &str, mut callback: C) {
+        let mut position = 0;
+        self.tokenize_words(text, |word| {
+            let mut has_tokens = false;
+            self.tokenize_word(word, false, |token| {
+                has_tokens = true;
+                callback(token, position);
+            });
+            // Skipped words don't take a position, the same way they are skipped in queries
+            if has_tokens {
+                position += 1;
+            }
+        });
+    }
+
+    pub fn tokenize_query<C: FnMut(&str)>(&self, text: &str, mut callback: C) {
+        self.tokenize_words(text, |word| self.tokenize_word(word, true, &mut callback));
+    }
+}
+
+fn stemmer_algorithm(language: Language) -> Algorithm {
+    match language {
+        Language::Arabic => Algorithm::Arabic,
+        Language::Danish => Algorithm::Danish,
+        Language::Dutch => Algorithm::Dutch,
+        Language::English => Algorithm::English,
+        Language::Finnish => Algorithm::Finnish,
+        Language::French => Algorithm::French,
+        Language::German => Algorithm::German,
+        Language::Greek => Algorithm::Greek,
+        Language::Hungarian => Algorithm::Hungarian,
+        Language::Italian => Algorithm::Italian,
+        Language::Norwegian => Algorithm::Norwegian,
+        Language::Portuguese => Algorithm::Portuguese,
+        Language::Romanian => Algorithm::Romanian,
+        Language::Russian => Algorithm::Russian,
+        Language::Spanish => Algorithm::Spanish,
+        Language::Swedish => Algorithm::Swedish,
+        Language::Turkish => Algorithm::Turkish,
+    }
+}
+
+fn stopwords_language(language: Language) -> LANGUAGE {
+    match language {
+        Language::Arabic => LANGUAGE::Arabic,
+        Language::Danish => LANGUAGE::Danish,
+        Language::Dutch => LANGUAGE::Dutch,
+        Language::English => LANGUAGE::English,
+        Language::Finnish => LANGUAGE::Finnish,
+        Language::French => LANGUAGE::French,
+        Language::German => LANGUAGE::German,
+        Language::Greek => LANGUAGE::Greek,
+        Language::Hungarian => LANGUAGE::Hungarian,
+        Language::Italian => LANGUAGE::Italian,
+        Language::Norwegian => LANGUAGE::Norwegian,
+        Language::Portuguese => LANGUAGE::Portuguese,
+        Language::Romanian => LANGUAGE::Romanian,
+        Language::Russian => LANGUAGE::Russian,
+        Language::Spanish => LANGUAGE::Spanish,
+        Language::Swedish => LANGUAGE::Swedish,
+        Language::Turkish => LANGUAGE::Turkish,
+    }
+}
+
+/// Replace accented and other non-ASCII letters with their closest ASCII equivalents.
+///
+/// Characters without an equivalent are kept as is.
+fn fold_to_ascii(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    // Decomposition splits letters from their diacritics, e.g. "é" into "e" and "\u{301}"
+    for c in text.nfkd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'ß' => folded.push_str("ss"),
+            'ẞ' => folded.push_str("SS"),
+            'æ' => folded.push_str("ae"),
+            'Æ' => folded.push_str("AE"),
+            'œ' => folded.push_str("oe"),
+            'Œ' => folded.push_str("OE"),
+            'ø' => folded.push('o'),
+            'Ø' => folded.push('O'),
+            'đ' | 'ð' => folded.push('d'),
+            'Đ' | 'Ð' => folded.push('D'),
+            'ł' => folded.push('l'),
+            'Ł' => folded.push('L'),
+            'ı' => folded.push('i'),
+            'þ' => folded.push_str("th"),
+            'Þ' => folded.push_str("TH"),
+            c => folded.push(c),
         }
     }
+    folded
 }
 
 #[cfg(test)]
@@ -246,17 +420,19 @@ mod tests {
     fn test_tokenizer() {
         let text = "Hello, Мир!";
         let mut tokens = Vec::new();
-        Tokenizer::tokenize_doc(
-            text,
-            &TextIndexParams {
-                r#type: TextIndexType::Text,
-                tokenizer: TokenizerType::Prefix,
-                min_token_len: Some(1),
-                max_token_len: Some(4),
-                lowercase: Some(true),
-            },
-            |token| tokens.push(token.to_owned()),
-        );
+        Tokenizer::new(&TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Prefix,
+            min_token_len: Some(1),
+            max_token_len: Some(4),
+            lowercase: Some(true),
+            phrase_matching: None,
+            stopwords: None,
+            stemmer: None,
+            ascii_folding: None,
+            on_disk: None,
+        })
+        .tokenize_doc(text, |token| tokens.push(token.to_owned()));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 7);
         assert_eq!(tokens.first(), Some(&"h".to_owned()));
@@ -267,4 +443,60 @@ mod tests {
         assert_eq!(tokens.get(5), Some(&"ми".to_owned()));
         assert_eq!(tokens.get(6), Some(&"мир".to_owned()));
     }
+
+    #[test]
+    fn test_tokenizer_normalization() {
+        let config: TextIndexParams = serde_json::from_value(serde_json::json!({
+            "type": "text",
+            "tokenizer": "word",
+            "stopwords": "english",
+            "stemmer": "english",
+            "ascii_folding": true,
+        }))
+        .unwrap();
+        assert_eq!(
+            config.stopwords,
+            Some(StopwordsInterface::Language(Language::English))
+        );
+        let tokenizer = Tokenizer::new(&config);
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc("The Cafés were running, Mañana", |token| {
+            tokens.push(token.to_owned())
+        });
+        assert_eq!(tokens, vec!["cafe", "run", "manana"]);
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_query("café runs", |token| tokens.push(token.to_owned()));
+        assert_eq!(tokens, vec!["cafe", "run"]);
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc_with_positions("the straße of the cafés", |token, position| {
+            tokens.push((token.to_owned(), position))
+        });
+        assert_eq!(
+            tokens,
+            vec![("strass".to_owned(), 0), ("cafe".to_owned(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_tokenizer_custom_stopwords() {
+        let config: TextIndexParams = serde_json::from_value(serde_json::json!({
+            "type": "text",
+            "tokenizer": "prefix",
+            "min_token_len": 2,
+            "max_token_len": 3,
+            "stopwords": {
+                "languages": ["english"],
+                "custom": ["Hello"]
+            },
+        }))
+        .unwrap();
+        let tokenizer = Tokenizer::new(&config);
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc("Hello and goodbye", |token| tokens.push(token.to_owned()));
+        assert_eq!(tokens, vec!["go", "goo"]);
+    }
 }
@@ -1,13 +1,13 @@
 use std::ops::Range;
 
 use geo::algorithm::haversine_distance::HaversineDistance;
-use geo::{Coord, Intersects, LineString, Point, Polygon};
+use geo::{BoundingRect, Coord, Geometry, Intersects, LineString, Point, Polygon};
 use geohash::{decode, decode_bbox, encode, Direction, GeohashError};
 use itertools::Itertools;
 use smol_str::SmolStr;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius};
+use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, GeoShape};
 
 pub type GeoHash = SmolStr;
 
@@ -208,6 +208,17 @@ fn check_polygon_intersection(geohash: &str, polygon: &Polygon) -> bool {
     rect.intersects(polygon)
 }
 
+/// Check if geohash tile intersects the geometry
+fn check_geometry_intersection(geohash: &str, geometry: &Geometry) -> bool {
+    let precision = geohash.len();
+    if precision == 0 {
+        return true;
+    }
+    let rect = decode_bbox(geohash).unwrap();
+
+    geometry.intersects(&rect)
+}
+
 fn create_hashes(
     mapping_fn: impl Fn(usize) -> Option<Vec<GeoHash>>,
 ) -> OperationResult<Vec<GeoHash>> {
@@ -327,6 +338,45 @@ pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResu
     create_hashes(mapping_fn)
 }
 
+/// Return as-high-as-possible with maximum of `max_regions`
+/// number of geo-hash guaranteed to cover the whole shape.
+/// Only regions intersecting the shape itself are kept: the ones crossed by a line,
+/// or the ones overlapping the area of a polygon, excluding the polygon holes.
+pub fn shape_hashes(shape: &GeoShape, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
+    if max_regions == 0 {
+        return Err(OperationError::service_error(
+            "max_regions cannot be equal to zero",
+        ));
+    }
+    let geometry = shape.convert().geometry;
+    let rect = geometry
+        .bounding_rect()
+        .ok_or_else(|| OperationError::service_error("geo shape has no bounding rectangle"))?;
+    let geo_bounding_box = GeoBoundingBox {
+        top_left: GeoPoint {
+            lon: rect.min().x,
+            lat: rect.max().y,
+        },
+        bottom_right: GeoPoint {
+            lon: rect.max().x,
+            lat: rect.min().y,
+        },
+    };
+    let full_geohash_bounding_box: GeohashBoundingBox = geo_bounding_box.into();
+
+    let mapping_fn = |precision| {
+        full_geohash_bounding_box
+            .geohash_regions(precision, max_regions)
+            .map(|hashes| {
+                hashes
+                    .into_iter()
+                    .filter(|hash| check_geometry_intersection(hash, &geometry))
+                    .collect_vec()
+            })
+    };
+    create_hashes(mapping_fn)
+}
+
 /// A globally-average value is usually considered to be 6,371 kilometres (3,959 mi) with a 0.3% variability (±10 km).
 /// <https://en.wikipedia.org/wiki/Earth_radius>.
 const EARTH_RADIUS_METERS: f64 = 6371.0 * 1000.;
@@ -420,6 +470,7 @@ mod tests {
 
     use super::*;
     use crate::types::test_utils::{build_polygon, build_polygon_with_interiors};
+    use crate::types::GeoLineString;
 
     const BERLIN: GeoPoint = GeoPoint {
         lat: 52.52437,
@@ -606,6 +657,50 @@ mod tests {
         assert_eq!(nyc_hashes_result.unwrap(), ["dr5ru"]);
     }
 
+    #[test]
+    fn shape_hashes_nyc() {
+        let near_nyc_polygon = build_polygon(vec![
+            (-74.00101399, 40.76517460),
+            (-73.98201792, 40.76517460),
+            (-73.98201792, 40.75078539),
+            (-74.00101399, 40.75078539),
+            (-74.00101399, 40.76517460),
+        ]);
+
+        // Same coverage as for the polygon filter
+        let mut nyc_hashes =
+            shape_hashes(&GeoShape::Polygon(near_nyc_polygon.clone()), 10).unwrap();
+        nyc_hashes.sort_unstable();
+        let mut expected = polygon_hashes(&near_nyc_polygon, 10).unwrap();
+        expected.sort_unstable();
+        assert_eq!(nyc_hashes, expected);
+
+        // A point is covered by a single max precision hash
+        let point_hashes = shape_hashes(&GeoShape::Point(NYC), 10).unwrap();
+        assert_eq!(
+            point_hashes,
+            [encode_max_precision(NYC.lon, NYC.lat).unwrap()]
+        );
+
+        // A diagonal line does not need the corner tiles of its bounding box
+        let line = GeoShape::LineString(GeoLineString {
+            points: vec![
+                GeoPoint {
+                    lon: -74.00101399,
+                    lat: 40.75078539,
+                },
+                GeoPoint {
+                    lon: -73.98201792,
+                    lat: 40.76517460,
+                },
+            ],
+        });
+        let line_hashes = shape_hashes(&line, 10).unwrap();
+        assert!(!line_hashes.is_empty());
+        assert!(line_hashes.len() < expected.len());
+        assert!(line_hashes.iter().all(|hash| expected.contains(hash)));
+    }
+
     #[test]
     fn random_circles() {
         let mut rnd = StdRng::seed_from_u64(42);
@@ -0,0 +1,348 @@
+use std::collections::HashSet;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use itertools::Itertools;
+
+use super::{GeoMapIndex, MutableGeoMapIndex, GEO_SHAPE_MAX_REGION};
+use crate::common::mmap_type::MmapSlice;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::index::field_index::geo_hash::{encode_max_precision, shape_hashes, GeoHash};
+use crate::index::field_index::mmap_lists::{create_mmap_slice, open_mmap_slice, MmapLists};
+use crate::types::{GeoPoint, GeoShape};
+
+const POINT_TO_VALUES: &str = "point_to_values";
+const HASHES: &str = "hashes";
+const HASH_TO_POINTS: &str = "hash_to_points";
+const COUNTS_HASHES: &str = "counts_hashes";
+const COUNTS_PATH: &str = "counts.dat";
+const POINT_TO_SHAPES: &str = "point_to_shapes";
+const SHAPE_HASHES: &str = "shape_hashes";
+const SHAPE_HASH_TO_POINTS: &str = "shape_hash_to_points";
+
+pub struct MmapGeoMapIndex {
+    dir: PathBuf,
+    /// Values of each point, as `[lon, lat]`
+    point_to_values: MmapLists<[f64; 2]>,
+    /// Sorted unique max precision hashes of the values
+    hashes: MmapLists<u8>,
+    /// Sorted points of each of the `hashes`
+    hash_to_points: MmapLists<PointOffsetType>,
+    /// Sorted hashes of all precisions, including the empty one
+    counts_hashes: MmapLists<u8>,
+    /// Amount of points and values in each of the `counts_hashes` regions
+    counts: MmapSlice<[u32; 2]>,
+    /// Geo shapes of each point, as GeoJSON
+    point_to_shapes: MmapLists<u8>,
+    /// Sorted unique hashes covering the geo shapes
+    shape_hashes: MmapLists<u8>,
+    /// Sorted points of each of the `shape_hashes`
+    shape_hash_to_points: MmapLists<PointOffsetType>,
+    pub(super) points_count: usize,
+    pub(super) points_values_count: usize,
+    pub(super) max_values_per_point: usize,
+}
+
+impl MmapGeoMapIndex {
+    pub fn create(dir: &Path, index: &MutableGeoMapIndex) -> OperationResult<()> {
+        create_dir_all(dir)?;
+
+        MmapLists::<[f64; 2]>::create(
+            dir,
+            POINT_TO_VALUES,
+            index.point_to_values.iter().map(|values| {
+                values
+                    .iter()
+                    .map(|value| [value.lon, value.lat])
+                    .collect_vec()
+            }),
+        )?;
+
+        // Keys of the maps are already sorted
+        MmapLists::<u8>::create(
+            dir,
+            HASHES,
+            index.points_map.keys().map(|hash| hash.as_bytes()),
+        )?;
+        MmapLists::<PointOffsetType>::create(
+            dir,
+            HASH_TO_POINTS,
+            index
+                .points_map
+                .values()
+                .map(|points| points.iter().copied().sorted().collect_vec()),
+        )?;
+
+        MmapLists::<u8>::create(
+            dir,
+            COUNTS_HASHES,
+            index.points_per_hash.keys().map(|hash| hash.as_bytes()),
+        )?;
+        let counts = index
+            .points_per_hash
+            .iter()
+            .map(|(hash, &points)| [points as u32, index.get_values_of_hash(hash) as u32])
+            .collect_vec();
+        create_mmap_slice(&dir.join(COUNTS_PATH), &counts)?;
+
+        let point_to_shapes: Vec<_> = index
+            .point_to_shapes
+            .iter()
+            .map(|shapes| {
+                if shapes.is_empty() {
+                    Ok(vec![])
+                } else {
+                    GeoMapIndex::encode_db_shapes(shapes)
+                }
+            })
+            .try_collect()?;
+        MmapLists::<u8>::create(dir, POINT_TO_SHAPES, point_to_shapes)?;
+        MmapLists::<u8>::create(
+            dir,
+            SHAPE_HASHES,
+            index.shapes_map.keys().map(|hash| hash.as_bytes()),
+        )?;
+        MmapLists::<PointOffsetType>::create(
+            dir,
+            SHAPE_HASH_TO_POINTS,
+            index
+                .shapes_map
+                .values()
+                .map(|points| points.iter().copied().sorted().collect_vec()),
+        )
+    }
+
+    pub fn open(dir: &Path) -> OperationResult<Self> {
+        let point_to_values = MmapLists::<[f64; 2]>::open(dir, POINT_TO_VALUES)?;
+        let point_to_shapes = MmapLists::<u8>::open(dir, POINT_TO_SHAPES)?;
+
+        let mut points_count = 0;
+        let mut points_values_count = 0;
+        for idx in 0..point_to_values.len().max(point_to_shapes.len()) {
+            let values_count = point_to_values.get(idx).map_or(0, <[_]>::len);
+            let shapes_count = match point_to_shapes.get(idx) {
+                Some(shapes) if !shapes.is_empty() => GeoMapIndex::decode_db_shapes(shapes)?.len(),
+                _ => 0,
+            };
+            if values_count + shapes_count > 0 {
+                points_count += 1;
+            }
+            points_values_count += values_count + shapes_count;
+        }
+        let max_values_per_point = point_to_values.iter().map(<[_]>::len).max();
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            point_to_values,
+            hashes: MmapLists::open(dir, HASHES)?,
+            hash_to_points: MmapLists::open(dir, HASH_TO_POINTS)?,
+            counts_hashes: MmapLists::open(dir, COUNTS_HASHES)?,
+            counts: open_mmap_slice(&dir.join(COUNTS_PATH))?,
+            point_to_shapes,
+            shape_hashes: MmapLists::open(dir, SHAPE_HASHES)?,
+            shape_hash_to_points: MmapLists::open(dir, SHAPE_HASH_TO_POINTS)?,
+            points_count,
+            points_values_count,
+            max_values_per_point: max_values_per_point.unwrap_or_default(),
+        })
+    }
+
+    /// Position of the hash in the sorted `hashes`
+    fn find_hash(hashes: &MmapLists<u8>, hash: &str) -> Option<usize> {
+        let position = hashes.partition_point(|other| other < hash.as_bytes());
+        (hashes.get(position)? == hash.as_bytes()).then_some(position)
+    }
+
+    pub(super) fn get_values(
+        &self,
+        idx: PointOffsetType,
+    ) -> Option<impl Iterator<Item = GeoPoint> + '_> {
+        let values = self.point_to_values.get(idx as usize)?;
+        Some(values.iter().map(|&[lon, lat]| GeoPoint { lon, lat }))
+    }
+
+    pub(super) fn get_shapes(&self, idx: PointOffsetType) -> Option<Vec<GeoShape>> {
+        let shapes = self.point_to_shapes.get(idx as usize)?;
+        if shapes.is_empty() {
+            return None;
+        }
+        GeoMapIndex::decode_db_shapes(shapes).ok()
+    }
+
+    pub(super) fn values_count(&self, idx: PointOffsetType) -> usize {
+        self.point_to_values.get(idx as usize).map_or(0, <[_]>::len)
+            + self.get_shapes(idx).map_or(0, |shapes| shapes.len())
+    }
+
+    pub(super) fn get_points_per_hash(&self) -> impl Iterator<Item = (GeoHash, usize)> + '_ {
+        self.counts_hashes
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(hash, &[points, _values])| {
+                let hash = std::str::from_utf8(hash).unwrap_or_default();
+                (GeoHash::new(hash), points as usize)
+            })
+    }
+
+    pub(super) fn get_points_of_hash(&self, hash: &GeoHash) -> usize {
+        Self::find_hash(&self.counts_hashes, hash).map_or(0, |position| {
+            let [points, _values] = self.counts[position];
+            points as usize
+        })
+    }
+
+    pub(super) fn get_values_of_hash(&self, hash: &GeoHash) -> usize {
+        Self::find_hash(&self.counts_hashes, hash).map_or(0, |position| {
+            let [_points, values] = self.counts[position];
+            values as usize
+        })
+    }
+
+    /// Points of all stored hashes, which start with the given one
+    fn sub_regions_points<'a>(
+        hashes: &'a MmapLists<u8>,
+        hash_to_points: &'a MmapLists<PointOffsetType>,
+        geo: &GeoHash,
+    ) -> impl Iterator<Item = &'a [PointOffsetType]> + 'a {
+        let geo = geo.clone();
+        let start = hashes.partition_point(|hash| hash < geo.as_bytes());
+        hashes
+            .iter_from(start)
+            .zip(hash_to_points.iter_from(start))
+            .take_while(move |(hash, _points)| hash.starts_with(geo.as_bytes()))
+            .map(|(_hash, points)| points)
+    }
+
+    /// Points of all stored hashes, which start with the given one
+    pub(super) fn get_stored_points(
+        &self,
+        geo: &GeoHash,
+    ) -> impl Iterator<Item = PointOffsetType> + '_ {
+        Self::sub_regions_points(&self.hashes, &self.hash_to_points, geo)
+            .flat_map(|points| points.iter().copied())
+    }
+
+    /// Points with geo shapes, covering any sub-region of the given one or a region containing it
+    pub(super) fn get_shape_points(
+        &self,
+        geo: &GeoHash,
+    ) -> impl Iterator<Item = PointOffsetType> + '_ {
+        let super_regions = (0..geo.len())
+            .filter_map(|i| Self::find_hash(&self.shape_hashes, &geo[0..i]))
+            .filter_map(|position| self.shape_hash_to_points.get(position))
+            .collect_vec();
+        let sub_regions =
+            Self::sub_regions_points(&self.shape_hashes, &self.shape_hash_to_points, geo);
+        super_regions
+            .into_iter()
+            .chain(sub_regions)
+            .flat_map(|points| points.iter().copied())
+    }
+
+    pub(super) fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
+        // Empty if already removed or never actually existed
+        let removed_values = self
+            .point_to_values
+            .take(idx as usize)
+            .map(<[_]>::to_vec)
+            .unwrap_or_default();
+        let removed_shapes = match self.point_to_shapes.take(idx as usize) {
+            Some(shapes) if !shapes.is_empty() => GeoMapIndex::decode_db_shapes(shapes)?,
+            _ => vec![],
+        };
+
+        if removed_values.is_empty() && removed_shapes.is_empty() {
+            return Ok(());
+        }
+
+        self.points_count -= 1;
+        self.points_values_count -= removed_values.len() + removed_shapes.len();
+
+        for removed_shape in &removed_shapes {
+            for removed_geo_hash in shape_hashes(removed_shape, GEO_SHAPE_MAX_REGION)? {
+                if let Some(position) = Self::find_hash(&self.shape_hashes, &removed_geo_hash) {
+                    let points = self.shape_hash_to_points.get(position).unwrap_or_default();
+                    if let Ok(point_position) = points.binary_search(&idx) {
+                        self.shape_hash_to_points
+                            .remove_at(position, point_position);
+                    }
+                }
+            }
+        }
+        let mut removed_geo_hashes = Vec::with_capacity(removed_values.len());
+
+        for [lon, lat] in removed_values {
+            let removed_geo_hash = encode_max_precision(lon, lat)
+                .map_err(|e| OperationError::service_error(format!("Malformed geo points: {e}")))?;
+
+            if let Some(position) = Self::find_hash(&self.hashes, &removed_geo_hash) {
+                let points = self.hash_to_points.get(position).unwrap_or_default();
+                // Same hash may occur several times, the point is removed on the first occurrence
+                if let Ok(point_position) = points.binary_search(&idx) {
+                    self.hash_to_points.remove_at(position, point_position);
+                }
+            }
+
+            for i in 0..=removed_geo_hash.len() {
+                self.decrement_count(&removed_geo_hash[0..i], |[_points, values]| values);
+            }
+            removed_geo_hashes.push(removed_geo_hash);
+        }
+
+        let removed_sub_hashes: HashSet<&str> = removed_geo_hashes
+            .iter()
+            .flat_map(|geo_hash| (0..=geo_hash.len()).map(|i| &geo_hash[0..i]))
+            .collect();
+        for sub_geo_hash in removed_sub_hashes {
+            self.decrement_count(sub_geo_hash, |[points, _values]| points);
+        }
+
+        Ok(())
+    }
+
+    fn decrement_count(&mut self, hash: &str, count: impl Fn(&mut [u32; 2]) -> &mut u32) {
+        match Self::find_hash(&self.counts_hashes, hash) {
+            Some(position) => {
+                let count = count(&mut self.counts[position]);
+                *count = count.saturating_sub(1);
+            }
+            None => debug_assert!(false, "Hash count is not found for hash: {hash}"),
+        }
+    }
+
+    pub(super) fn flusher(&self) -> Flusher {
+        let point_to_values_flusher = self.point_to_values.flusher();
+        let hash_to_points_flusher = self.hash_to_points.flusher();
+        let counts_flusher = self.counts.flusher();
+        let point_to_shapes_flusher = self.point_to_shapes.flusher();
+        let shape_hash_to_points_flusher = self.shape_hash_to_points.flusher();
+        Box::new(move || {
+            point_to_values_flusher()?;
+            hash_to_points_flusher()?;
+            counts_flusher()?;
+            point_to_shapes_flusher()?;
+            shape_hash_to_points_flusher()
+        })
+    }
+
+    pub(super) fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.point_to_values.files();
+        files.extend(self.hashes.files());
+        files.extend(self.hash_to_points.files());
+        files.extend(self.counts_hashes.files());
+        files.push(self.dir.join(COUNTS_PATH));
+        files.extend(self.point_to_shapes.files());
+        files.extend(self.shape_hashes.files());
+        files.extend(self.shape_hash_to_points.files());
+        files
+    }
+
+    pub(super) fn clear(self) -> OperationResult<()> {
+        let dir = self.dir.clone();
+        drop(self);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 use std::cmp::{max, min};
 use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
-use itertools::Itertools;
+use itertools::{Either, Itertools};
+use mmap_geo_index::MmapGeoMapIndex;
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde_json::Value;
@@ -14,7 +16,7 @@ use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::index::field_index::geo_hash::{
     circle_hashes, common_hash_prefix, encode_max_precision, geo_hash_to_box, polygon_hashes,
-    polygon_hashes_estimation, rectangle_hashes, GeoHash,
+    polygon_hashes_estimation, rectangle_hashes, shape_hashes, GeoHash,
 };
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
 use crate::index::field_index::{
@@ -22,13 +24,21 @@ use crate::index::field_index::{
 };
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    FieldCondition, GeoBoundingBox, GeoPoint, GeoRadius, PayloadKeyType, PolygonWrapper,
+    FieldCondition, GeoBoundingBox, GeoPoint, GeoRadius, GeoShape, PayloadKeyType, PolygonWrapper,
 };
 
+pub mod mmap_geo_index;
+
 /// Max number of sub-regions computed for an input geo query
 // TODO discuss value, should it be dynamically computed?
 const GEO_QUERY_MAX_REGION: usize = 12;
 
+/// Max number of sub-regions used to index a geo shape
+const GEO_SHAPE_MAX_REGION: usize = 16;
+
+/// Pseudo geo-hash of the DB key, under which all geo shapes of a point are stored
+const SHAPES_DB_KEY: &str = "~shapes";
+
 pub struct MutableGeoMapIndex {
     /*
     {
@@ -52,14 +62,28 @@ pub struct MutableGeoMapIndex {
      */
     points_map: BTreeMap<GeoHash, HashSet<PointOffsetType>>,
     point_to_values: Vec<Vec<GeoPoint>>,
+    /*
+    Geo shapes are stored by all geo-hashes covering them
+    {
+        "dr5r": {4},
+        "dr5ru": {4, 5},
+        ...
+    }
+     */
+    shapes_map: BTreeMap<GeoHash, HashSet<PointOffsetType>>,
+    point_to_shapes: Vec<Vec<GeoShape>>,
+    /// Number of points with at least one geo point or geo shape
     points_count: usize,
+    /// Number of geo points and geo shapes
     points_values_count: usize,
+    /// Max number of geo points of a single point, geo shapes are not included
     max_values_per_point: usize,
     db_wrapper: DatabaseColumnWrapper,
 }
 
 pub enum GeoMapIndex {
-    Mutable(MutableGeoMapIndex),
+    Mutable(Box<MutableGeoMapIndex>),
+    Mmap(Box<MmapGeoMapIndex>),
 }
 
 impl MutableGeoMapIndex {
@@ -71,6 +95,8 @@ impl MutableGeoMapIndex {
             values_per_hash: Default::default(),
             points_map: Default::default(),
             point_to_values: vec![],
+            shapes_map: Default::default(),
+            point_to_shapes: vec![],
             points_count: 0,
             points_values_count: 0,
             max_values_per_point: 0,
@@ -86,6 +112,18 @@ impl MutableGeoMapIndex {
         self.point_to_values.get(idx as usize).map(Vec::as_slice)
     }
 
+    fn get_shapes(&self, idx: PointOffsetType) -> Option<&[GeoShape]> {
+        self.point_to_shapes.get(idx as usize).map(Vec::as_slice)
+    }
+
+    fn has_values(&self, idx: PointOffsetType) -> bool {
+        self.get_values(idx)
+            .map_or(false, |values| !values.is_empty())
+            || self
+                .get_shapes(idx)
+                .map_or(false, |shapes| !shapes.is_empty())
+    }
+
     fn get_points_per_hash(&self) -> impl Iterator<Item = (&GeoHash, usize)> {
         self.points_per_hash
             .iter()
@@ -106,6 +144,7 @@ impl MutableGeoMapIndex {
         };
 
         let mut points_to_hashes: BTreeMap<PointOffsetType, Vec<GeoHash>> = Default::default();
+        let mut points_to_shapes: Vec<(PointOffsetType, Vec<GeoShape>)> = Default::default();
 
         for (key, value) in self.db_wrapper.lock_db().iter()? {
             let key_str = std::str::from_utf8(&key).map_err(|_| {
@@ -113,6 +152,12 @@ impl MutableGeoMapIndex {
             })?;
 
             let (geo_hash, idx) = GeoMapIndex::decode_db_key(key_str)?;
+
+            if geo_hash == SHAPES_DB_KEY {
+                points_to_shapes.push((idx, GeoMapIndex::decode_db_shapes(value)?));
+                continue;
+            }
+
             let geo_point = GeoMapIndex::decode_db_value(value)?;
 
             if self.point_to_values.len() <= idx as usize {
@@ -144,22 +189,36 @@ impl MutableGeoMapIndex {
                 self.increment_hash_value_counts(&geo_hash);
             }
         }
+
+        for (idx, shapes) in points_to_shapes {
+            if !self.has_values(idx) {
+                self.points_count += 1;
+            }
+            self.insert_geo_shapes(idx, shapes)?;
+        }
         Ok(true)
     }
 
     fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
-        if self.point_to_values.len() <= idx as usize {
-            return Ok(()); // Already removed or never actually existed
-        }
-
-        let removed_geo_points = std::mem::take(&mut self.point_to_values[idx as usize]);
-
-        if removed_geo_points.is_empty() {
+        // Empty if already removed or never actually existed
+        let removed_geo_points = self
+            .point_to_values
+            .get_mut(idx as usize)
+            .map(std::mem::take)
+            .unwrap_or_default();
+        let removed_geo_shapes = self
+            .point_to_shapes
+            .get_mut(idx as usize)
+            .map(std::mem::take)
+            .unwrap_or_default();
+
+        if removed_geo_points.is_empty() && removed_geo_shapes.is_empty() {
             return Ok(());
         }
 
         self.points_count -= 1;
-        self.points_values_count -= removed_geo_points.len();
+        self.points_values_count -= removed_geo_points.len() + removed_geo_shapes.len();
+        self.remove_geo_shapes(idx, &removed_geo_shapes)?;
         let mut removed_geo_hashes = Vec::with_capacity(removed_geo_points.len());
 
         for removed_geo_point in removed_geo_points {
@@ -192,6 +251,88 @@ impl MutableGeoMapIndex {
         Ok(())
     }
 
+    fn remove_geo_shapes(
+        &mut self,
+        idx: PointOffsetType,
+        removed_geo_shapes: &[GeoShape],
+    ) -> OperationResult<()> {
+        if removed_geo_shapes.is_empty() {
+            return Ok(());
+        }
+
+        let key = GeoMapIndex::encode_db_key(SHAPES_DB_KEY, idx);
+        self.db_wrapper.remove(key)?;
+
+        for removed_geo_shape in removed_geo_shapes {
+            for removed_geo_hash in shape_hashes(removed_geo_shape, GEO_SHAPE_MAX_REGION)? {
+                let is_last = if let Some(hash_ids) = self.shapes_map.get_mut(&removed_geo_hash) {
+                    hash_ids.remove(&idx);
+                    hash_ids.is_empty()
+                } else {
+                    false
+                };
+
+                if is_last {
+                    self.shapes_map.remove(&removed_geo_hash);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn add_many_geo_values(
+        &mut self,
+        idx: PointOffsetType,
+        points: &[GeoPoint],
+        shapes: Vec<GeoShape>,
+    ) -> OperationResult<()> {
+        if points.is_empty() && shapes.is_empty() {
+            return Ok(());
+        }
+
+        self.add_many_geo_points(idx, points)?;
+        self.add_many_geo_shapes(idx, shapes)?;
+
+        self.points_count += 1;
+        Ok(())
+    }
+
+    fn add_many_geo_shapes(
+        &mut self,
+        idx: PointOffsetType,
+        shapes: Vec<GeoShape>,
+    ) -> OperationResult<()> {
+        if shapes.is_empty() {
+            return Ok(());
+        }
+
+        let key = GeoMapIndex::encode_db_key(SHAPES_DB_KEY, idx);
+        let value = GeoMapIndex::encode_db_shapes(&shapes)?;
+        self.db_wrapper.put(key, value)?;
+
+        self.insert_geo_shapes(idx, shapes)
+    }
+
+    fn insert_geo_shapes(
+        &mut self,
+        idx: PointOffsetType,
+        shapes: Vec<GeoShape>,
+    ) -> OperationResult<()> {
+        for shape in &shapes {
+            for geo_hash in shape_hashes(shape, GEO_SHAPE_MAX_REGION)? {
+                self.shapes_map.entry(geo_hash).or_default().insert(idx);
+            }
+        }
+
+        if self.point_to_shapes.len() <= idx as usize {
+            self.point_to_shapes.resize_with(idx as usize + 1, Vec::new);
+        }
+
+        self.points_values_count += shapes.len();
+        self.point_to_shapes[idx as usize] = shapes;
+        Ok(())
+    }
+
     fn add_many_geo_points(
         &mut self,
         idx: PointOffsetType,
@@ -234,7 +375,6 @@ impl MutableGeoMapIndex {
         self.increment_hash_point_counts(&geo_hashes);
 
         self.points_values_count += values.len();
-        self.points_count += 1;
         self.max_values_per_point = self.max_values_per_point.max(values.len());
         Ok(())
     }
@@ -251,6 +391,23 @@ impl MutableGeoMapIndex {
         )
     }
 
+    /// Points with geo shapes, covering any sub-region of the given one or a region containing it
+    fn get_shape_points(&self, geo: &GeoHash) -> impl Iterator<Item = PointOffsetType> + '_ {
+        let super_regions = (0..geo.len())
+            .filter_map(|i| self.shapes_map.get(&geo[0..i]))
+            .collect_vec();
+        let geo_clone = geo.clone();
+        let sub_regions = self
+            .shapes_map
+            .range(geo.clone()..)
+            .take_while(move |(p, _h)| p.starts_with(geo_clone.as_str()))
+            .map(|(_geohash, points)| points);
+        super_regions
+            .into_iter()
+            .chain(sub_regions)
+            .flat_map(|points| points.iter().copied())
+    }
+
     fn increment_hash_value_counts(&mut self, geo_hash: &GeoHash) {
         for i in 0..=geo_hash.len() {
             let sub_geo_hash = &geo_hash[0..i];
@@ -335,24 +492,20 @@ impl MutableGeoMapIndex {
 
 impl GeoMapIndex {
     pub fn new(db: Arc<RwLock<DB>>, field: &str) -> Self {
-        GeoMapIndex::Mutable(MutableGeoMapIndex::new(db, field))
-    }
-
-    fn db_wrapper(&self) -> &DatabaseColumnWrapper {
-        match self {
-            GeoMapIndex::Mutable(index) => index.db_wrapper(),
-        }
+        GeoMapIndex::Mutable(Box::new(MutableGeoMapIndex::new(db, field)))
     }
 
     fn points_count(&self) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.points_count,
+            GeoMapIndex::Mmap(index) => index.points_count,
         }
     }
 
     fn points_values_count(&self) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.points_values_count,
+            GeoMapIndex::Mmap(index) => index.points_values_count,
         }
     }
 
@@ -364,33 +517,52 @@ impl GeoMapIndex {
     fn max_values_per_point(&self) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.max_values_per_point,
+            GeoMapIndex::Mmap(index) => index.max_values_per_point,
         }
     }
 
-    fn get_points_per_hash(&self) -> impl Iterator<Item = (&GeoHash, usize)> {
+    fn get_points_per_hash(&self) -> Box<dyn Iterator<Item = (GeoHash, usize)> + '_> {
         match self {
-            GeoMapIndex::Mutable(index) => index.get_points_per_hash(),
+            GeoMapIndex::Mutable(index) => Box::new(
+                index
+                    .get_points_per_hash()
+                    .map(|(hash, count)| (hash.clone(), count)),
+            ),
+            GeoMapIndex::Mmap(index) => Box::new(index.get_points_per_hash()),
         }
     }
 
     fn get_points_of_hash(&self, hash: &GeoHash) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.get_points_of_hash(hash),
+            GeoMapIndex::Mmap(index) => index.get_points_of_hash(hash),
         }
     }
 
     fn get_values_of_hash(&self, hash: &GeoHash) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.get_values_of_hash(hash),
+            GeoMapIndex::Mmap(index) => index.get_values_of_hash(hash),
         }
     }
 
-    fn get_stored_sub_regions(
-        &self,
-        geo: &GeoHash,
-    ) -> Box<dyn Iterator<Item = (&GeoHash, &HashSet<PointOffsetType>)> + '_> {
+    /// Points of all stored hashes, which start with the given one
+    fn get_stored_points(&self, geo: &GeoHash) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        match self {
+            GeoMapIndex::Mutable(index) => Box::new(
+                index
+                    .get_stored_sub_regions(geo)
+                    .flat_map(|(_geohash, points)| points.iter().copied()),
+            ),
+            GeoMapIndex::Mmap(index) => Box::new(index.get_stored_points(geo)),
+        }
+    }
+
+    /// Points with geo shapes, covering any sub-region of the given one or a region containing it
+    fn get_shape_points(&self, geo: &GeoHash) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         match self {
-            GeoMapIndex::Mutable(index) => index.get_stored_sub_regions(geo),
+            GeoMapIndex::Mutable(index) => Box::new(index.get_shape_points(geo)),
+            GeoMapIndex::Mmap(index) => Box::new(index.get_shape_points(geo)),
         }
     }
 
@@ -399,7 +571,10 @@ impl GeoMapIndex {
     }
 
     pub fn recreate(&self) -> OperationResult<()> {
-        self.db_wrapper().recreate_column_family()
+        match self {
+            GeoMapIndex::Mutable(index) => index.db_wrapper().recreate_column_family(),
+            GeoMapIndex::Mmap(_) => Ok(()),
+        }
     }
 
     fn encode_db_key(value: &str, idx: PointOffsetType) -> String {
@@ -443,34 +618,96 @@ impl GeoMapIndex {
         result
     }
 
+    fn decode_db_shapes<T: AsRef<[u8]>>(value: T) -> OperationResult<Vec<GeoShape>> {
+        serde_json::from_slice(value.as_ref()).map_err(|e| {
+            OperationError::service_error(format!(
+                "Index db parsing error: malformed geo shapes: {e}"
+            ))
+        })
+    }
+
+    fn encode_db_shapes(shapes: &[GeoShape]) -> OperationResult<Vec<u8>> {
+        serde_json::to_vec(shapes)
+            .map_err(|e| OperationError::service_error(format!("Malformed geo shapes: {e}")))
+    }
+
     pub fn flusher(&self) -> Flusher {
-        self.db_wrapper().flusher()
+        match self {
+            GeoMapIndex::Mutable(index) => index.db_wrapper().flusher(),
+            GeoMapIndex::Mmap(index) => index.flusher(),
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        match self {
+            GeoMapIndex::Mutable(index) => MmapGeoMapIndex::create(dir, index),
+            GeoMapIndex::Mmap(_) => Err(OperationError::service_error(
+                "Only mutable geo index can be saved on disk",
+            )),
+        }
+    }
+
+    /// Replace the index with the memory mapped one, saved in the directory with [`Self::save_mmap`]
+    pub fn into_mmap(self, dir: &Path) -> OperationResult<Self> {
+        match &self {
+            GeoMapIndex::Mutable(index) => index.db_wrapper().remove_column_family()?,
+            GeoMapIndex::Mmap(_) => {}
+        }
+        Ok(GeoMapIndex::Mmap(Box::new(MmapGeoMapIndex::open(dir)?)))
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            GeoMapIndex::Mutable(_) => vec![],
+            GeoMapIndex::Mmap(index) => index.files(),
+        }
     }
 
-    pub fn get_values(&self, idx: PointOffsetType) -> Option<&[GeoPoint]> {
+    pub fn get_values(&self, idx: PointOffsetType) -> Option<impl Iterator<Item = GeoPoint> + '_> {
         match self {
-            GeoMapIndex::Mutable(index) => index.get_values(idx),
+            GeoMapIndex::Mutable(index) => index
+                .get_values(idx)
+                .map(|values| Either::Left(values.iter().cloned())),
+            GeoMapIndex::Mmap(index) => index.get_values(idx).map(Either::Right),
         }
     }
 
     pub fn check_radius(&self, idx: PointOffsetType, radius: &GeoRadius) -> bool {
         self.get_values(idx)
-            .map(|values| values.iter().any(|x| radius.check_point(x)))
+            .map(|mut values| values.any(|x| radius.check_point(&x)))
             .unwrap_or(false)
     }
 
     pub fn check_box(&self, idx: PointOffsetType, bbox: &GeoBoundingBox) -> bool {
         self.get_values(idx)
-            .map(|values| values.iter().any(|x| bbox.check_point(x)))
+            .map(|mut values| values.any(|x| bbox.check_point(&x)))
             .unwrap_or(false)
     }
 
     pub fn check_polygon(&self, idx: PointOffsetType, polygon: &PolygonWrapper) -> bool {
         self.get_values(idx)
-            .map(|values| values.iter().any(|x| polygon.check_point(x)))
+            .map(|mut values| values.any(|x| polygon.check_point(&x)))
             .unwrap_or(false)
     }
 
+    /// Check geo points and geo shapes of the point
+    pub fn check_geo_shape(&self, idx: PointOffsetType, check: impl Fn(&GeoShape) -> bool) -> bool {
+        let points_match = self
+            .get_values(idx)
+            .map(|mut values| values.any(|x| check(&GeoShape::Point(x))))
+            .unwrap_or(false);
+        points_match
+            || match self {
+                GeoMapIndex::Mutable(index) => index
+                    .get_shapes(idx)
+                    .map_or(false, |shapes| shapes.iter().any(&check)),
+                GeoMapIndex::Mmap(index) => index
+                    .get_shapes(idx)
+                    .map_or(false, |shapes| shapes.iter().any(&check)),
+            }
+    }
+
     pub fn match_cardinality(&self, values: &[GeoHash]) -> CardinalityEstimation {
         let max_values_per_point = self.max_values_per_point();
         if max_values_per_point == 0 {
@@ -518,6 +755,26 @@ impl GeoMapIndex {
         }
     }
 
+    /// Estimation for geo points and geo shapes in the regions
+    ///
+    /// Hash statistics are collected for geo points only, so points with geo shapes are counted directly.
+    pub fn match_shape_cardinality(&self, values: &[GeoHash]) -> CardinalityEstimation {
+        let points_estimation = self.match_cardinality(values);
+        let shape_points = values
+            .iter()
+            .flat_map(|region| self.get_shape_points(region))
+            .unique()
+            .count();
+
+        let estimation_max = min(points_estimation.max + shape_points, self.points_count());
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min: points_estimation.min,
+            exp: min(estimation_max, points_estimation.exp + shape_points),
+            max: estimation_max,
+        }
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
@@ -528,12 +785,25 @@ impl GeoMapIndex {
     }
 
     fn get_iterator(&self, values: Vec<GeoHash>) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        Box::new(
+            values
+                .into_iter()
+                .flat_map(|top_geo_hash| self.get_stored_points(&top_geo_hash))
+                .unique(),
+        )
+    }
+
+    /// Points with geo points or geo shapes in the regions
+    fn get_shapes_iterator(
+        &self,
+        values: Vec<GeoHash>,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         Box::new(
             values
                 .into_iter()
                 .flat_map(|top_geo_hash| {
-                    self.get_stored_sub_regions(&top_geo_hash)
-                        .flat_map(|(_geohash, points)| points.iter().copied())
+                    self.get_stored_points(&top_geo_hash)
+                        .chain(self.get_shape_points(&top_geo_hash))
                 })
                 .unique(),
         )
@@ -543,7 +813,7 @@ impl GeoMapIndex {
     fn get_large_hashes(
         &self,
         threshold: usize,
-    ) -> Box<dyn Iterator<Item = (&GeoHash, usize)> + '_> {
+    ) -> Box<dyn Iterator<Item = (GeoHash, usize)> + '_> {
         let mut large_regions = self
             .get_points_per_hash()
             .filter(|(hash, size)| *size > threshold && !hash.is_empty())
@@ -569,41 +839,45 @@ impl GeoMapIndex {
     }
 
     pub fn values_count(&self, point_id: PointOffsetType) -> usize {
-        self.get_values(point_id).map(|x| x.len()).unwrap_or(0)
+        match self {
+            GeoMapIndex::Mutable(index) => {
+                index.get_values(point_id).map_or(0, <[_]>::len)
+                    + index.get_shapes(point_id).map_or(0, <[_]>::len)
+            }
+            GeoMapIndex::Mmap(index) => index.values_count(point_id),
+        }
     }
 
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
-        self.get_values(point_id)
-            .map(|x| x.is_empty())
-            .unwrap_or(true)
+        self.values_count(point_id) == 0
     }
 }
 
-impl ValueIndexer<GeoPoint> for GeoMapIndex {
-    fn add_many(&mut self, id: PointOffsetType, values: Vec<GeoPoint>) -> OperationResult<()> {
+impl ValueIndexer<GeoShape> for GeoMapIndex {
+    fn add_many(&mut self, id: PointOffsetType, values: Vec<GeoShape>) -> OperationResult<()> {
         match self {
-            GeoMapIndex::Mutable(index) => index.add_many_geo_points(id, &values),
+            GeoMapIndex::Mutable(index) => {
+                let (points, shapes): (Vec<_>, Vec<_>) =
+                    values.into_iter().partition_map(|value| match value {
+                        GeoShape::Point(point) => Either::Left(point),
+                        shape => Either::Right(shape),
+                    });
+                index.add_many_geo_values(id, &points, shapes)
+            }
+            GeoMapIndex::Mmap(_) => Err(OperationError::service_error(
+                "Can't add values to immutable geo index",
+            )),
         }
     }
 
-    fn get_value(&self, value: &Value) -> Option<GeoPoint> {
-        match value {
-            Value::Object(obj) => {
-                let lon_op = obj.get("lon").and_then(|x| x.as_f64());
-                let lat_op = obj.get("lat").and_then(|x| x.as_f64());
-
-                if let (Some(lon), Some(lat)) = (lon_op, lat_op) {
-                    return GeoPoint::new(lon, lat).ok();
-                }
-                None
-            }
-            _ => None,
-        }
+    fn get_value(&self, value: &Value) -> Option<GeoShape> {
+        GeoShape::from_payload(value)
     }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
         match self {
             GeoMapIndex::Mutable(index) => index.remove_point(id),
+            GeoMapIndex::Mmap(index) => index.remove_point(id),
         }
     }
 }
@@ -616,11 +890,15 @@ impl PayloadFieldIndex for GeoMapIndex {
     fn load(&mut self) -> OperationResult<bool> {
         match self {
             GeoMapIndex::Mutable(index) => index.load(),
+            GeoMapIndex::Mmap(_) => Ok(true),
         }
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.db_wrapper().remove_column_family()
+        match self {
+            GeoMapIndex::Mutable(index) => index.db_wrapper().remove_column_family(),
+            GeoMapIndex::Mmap(index) => index.clear(),
+        }
     }
 
     fn flusher(&self) -> Flusher {
@@ -638,8 +916,7 @@ impl PayloadFieldIndex for GeoMapIndex {
                 move |point| {
                     self.get_values(*point)
                         .unwrap()
-                        .iter()
-                        .any(|point| geo_condition_copy.check_point(point))
+                        .any(|point| geo_condition_copy.check_point(&point))
                 },
             )));
         }
@@ -651,8 +928,7 @@ impl PayloadFieldIndex for GeoMapIndex {
                 move |point| {
                     self.get_values(*point)
                         .unwrap()
-                        .iter()
-                        .any(|point| geo_condition_copy.check_point(point))
+                        .any(|point| geo_condition_copy.check_point(&point))
                 },
             )));
         }
@@ -664,8 +940,27 @@ impl PayloadFieldIndex for GeoMapIndex {
                 move |point| {
                     self.get_values(*point)
                         .unwrap()
-                        .iter()
-                        .any(|point| geo_condition_copy.check_point(point))
+                        .any(|point| geo_condition_copy.check_point(&point))
+                },
+            )));
+        }
+
+        if let Some(geo_shape) = &condition.geo_intersects {
+            let geo_hashes = shape_hashes(geo_shape, GEO_QUERY_MAX_REGION)?;
+            let geo_condition_copy = geo_shape.convert();
+            return Ok(Box::new(self.get_shapes_iterator(geo_hashes).filter(
+                move |point| {
+                    self.check_geo_shape(*point, |shape| geo_condition_copy.check_intersects(shape))
+                },
+            )));
+        }
+
+        if let Some(geo_shape) = &condition.geo_within {
+            let geo_hashes = shape_hashes(geo_shape, GEO_QUERY_MAX_REGION)?;
+            let geo_condition_copy = geo_shape.convert();
+            return Ok(Box::new(self.get_shapes_iterator(geo_hashes).filter(
+                move |point| {
+                    self.check_geo_shape(*point, |shape| geo_condition_copy.check_within(shape))
                 },
             )));
         }
@@ -721,6 +1016,19 @@ impl PayloadFieldIndex for GeoMapIndex {
             return Ok(exterior_estimation);
         }
 
+        if let Some(geo_shape) = condition
+            .geo_intersects
+            .as_ref()
+            .or(condition.geo_within.as_ref())
+        {
+            let geo_hashes = shape_hashes(geo_shape, GEO_QUERY_MAX_REGION)?;
+            let mut estimation = self.match_shape_cardinality(&geo_hashes);
+            estimation
+                .primary_clauses
+                .push(PrimaryCondition::Condition(condition.clone()));
+            return Ok(estimation);
+        }
+
         Err(OperationError::service_error(
             "failed to estimate cardinality",
         ))
@@ -736,7 +1044,7 @@ impl PayloadFieldIndex for GeoMapIndex {
                 .map(move |(geo_hash, size)| PayloadBlockCondition {
                     condition: FieldCondition::new_geo_bounding_box(
                         key.clone(),
-                        geo_hash_to_box(geo_hash),
+                        geo_hash_to_box(&geo_hash),
                     ),
                     cardinality: size,
                 }),
@@ -1035,7 +1343,7 @@ mod tests {
             let field_index = build_random_index(1000, 5);
 
             let mut matched_points = (0..field_index.count_indexed_points() as PointOffsetType)
-                .map(|idx| (idx, field_index.get_values(idx).unwrap()))
+                .map(|idx| (idx, field_index.get_values(idx).unwrap().collect_vec()))
                 .filter(|(_idx, geo_points)| geo_points.iter().any(&check_fn))
                 .map(|(idx, _geo_points)| idx as PointOffsetType)
                 .collect_vec();
@@ -1390,4 +1698,176 @@ mod tests {
             .match_cardinality(&hashes_with_interior)
             .equals_min_exp_max(&CardinalityEstimation::exact(0)),);
     }
+
+    #[test]
+    fn test_mmap_geo_index() {
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let index = build_random_index(1000, 5);
+
+        let mmap_dir = temp_dir.path().join("mmap");
+        index.save_mmap(&mmap_dir).unwrap();
+        let mut mmap_index = GeoMapIndex::Mmap(Box::new(MmapGeoMapIndex::open(&mmap_dir).unwrap()));
+
+        assert_eq!(mmap_index.points_count(), index.points_count());
+        assert_eq!(
+            mmap_index.points_values_count(),
+            index.points_values_count()
+        );
+        assert_eq!(
+            mmap_index.max_values_per_point(),
+            index.max_values_per_point()
+        );
+        assert_eq!(
+            mmap_index.get_points_per_hash().collect_vec(),
+            index.get_points_per_hash().collect_vec(),
+        );
+        for idx in 0..1000 {
+            assert_eq!(
+                mmap_index.get_values(idx).unwrap().collect_vec(),
+                index.get_values(idx).unwrap().collect_vec(),
+            );
+        }
+
+        let geo_radius = GeoRadius {
+            center: NYC,
+            radius: 1_000_000.0,
+        };
+        let conditions = [
+            condition_for_geo_radius("test".to_string(), geo_radius.clone()),
+            condition_for_geo_polygon("test".to_string(), radius_to_polygon(&geo_radius)),
+        ];
+        for condition in &conditions {
+            assert_eq!(
+                mmap_index.filter(condition).unwrap().sorted().collect_vec(),
+                index.filter(condition).unwrap().sorted().collect_vec(),
+            );
+            let mmap_estimation = mmap_index.estimate_cardinality(condition).unwrap();
+            let estimation = index.estimate_cardinality(condition).unwrap();
+            assert_eq!(
+                (
+                    mmap_estimation.min,
+                    mmap_estimation.exp,
+                    mmap_estimation.max
+                ),
+                (estimation.min, estimation.exp, estimation.max),
+            );
+        }
+        assert_eq!(
+            mmap_index.get_large_hashes(100).collect_vec(),
+            index.get_large_hashes(100).collect_vec(),
+        );
+
+        let matched = mmap_index.filter(&conditions[0]).unwrap().collect_vec();
+        let removed = matched[0];
+        mmap_index.remove_point(removed).unwrap();
+        mmap_index.flusher()().unwrap();
+        let matched_after_removal = mmap_index.filter(&conditions[0]).unwrap().collect_vec();
+        assert_eq!(matched_after_removal.len(), matched.len() - 1);
+        assert!(!matched_after_removal.contains(&removed));
+        assert_eq!(mmap_index.points_count(), 999);
+        assert_eq!(mmap_index.get_points_of_hash(&Default::default()), 999);
+        assert_eq!(mmap_index.get_values_of_hash(&Default::default()), 999 * 5);
+
+        // Removal is persisted
+        let mmap_index = GeoMapIndex::Mmap(Box::new(MmapGeoMapIndex::open(&mmap_dir).unwrap()));
+        assert!(mmap_index.values_is_empty(removed));
+        assert_eq!(mmap_index.get_points_of_hash(&Default::default()), 999);
+    }
+
+    #[test]
+    fn test_geo_shapes() {
+        let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut index = GeoMapIndex::new(db, FIELD_NAME);
+        index.recreate().unwrap();
+
+        let payloads = [
+            // Delivery zone around Potsdam, with a hole in Berlin
+            json!({
+                "type": "Polygon",
+                "coordinates": [
+                    [[12.9, 52.3], [13.7, 52.3], [13.7, 52.7], [12.9, 52.7], [12.9, 52.3]],
+                    [[13.3, 52.45], [13.5, 52.45], [13.5, 52.6], [13.3, 52.6], [13.3, 52.45]]
+                ]
+            }),
+            json!({
+                "type": "LineString",
+                "coordinates": [[POTSDAM.lon, POTSDAM.lat], [BERLIN.lon, BERLIN.lat]]
+            }),
+            json!({ "lon": TOKYO.lon, "lat": TOKYO.lat }),
+            json!({ "type": "Point", "coordinates": [NYC.lon, NYC.lat] }),
+        ];
+        for (idx, payload) in payloads.iter().enumerate() {
+            index
+                .add_point(idx as PointOffsetType + 1, &MultiValue::one(payload))
+                .unwrap();
+        }
+        index.flusher()().unwrap();
+
+        assert_eq!(index.points_count(), 4);
+        assert_eq!(index.points_values_count(), 4);
+        assert_eq!(index.values_count(1), 1);
+
+        let point = |point: GeoPoint| GeoShape::Point(point);
+        let region: GeoShape = serde_json::from_value(json!({
+            "type": "Polygon",
+            "coordinates": [[[12.5, 52.0], [14.5, 52.0], [14.5, 53.0], [12.5, 53.0], [12.5, 52.0]]]
+        }))
+        .unwrap();
+        let conditions = [
+            (
+                FieldCondition::new_geo_intersects(FIELD_NAME, point(POTSDAM)),
+                vec![1, 2],
+            ),
+            (
+                FieldCondition::new_geo_intersects(FIELD_NAME, point(BERLIN)),
+                vec![2],
+            ),
+            (
+                FieldCondition::new_geo_intersects(FIELD_NAME, point(TOKYO)),
+                vec![3],
+            ),
+            (
+                FieldCondition::new_geo_intersects(FIELD_NAME, point(NYC)),
+                vec![4],
+            ),
+            (
+                FieldCondition::new_geo_within(FIELD_NAME, region),
+                vec![1, 2],
+            ),
+        ];
+
+        let check_index = |index: &GeoMapIndex| {
+            for (condition, expected) in &conditions {
+                let points = index.filter(condition).unwrap().sorted().collect_vec();
+                assert_eq!(&points, expected);
+
+                let estimation = index.estimate_cardinality(condition).unwrap();
+                assert!(estimation.min <= expected.len());
+                assert!(estimation.max >= expected.len());
+            }
+        };
+        check_index(&index);
+
+        let mmap_dir = temp_dir.path().join("mmap");
+        index.save_mmap(&mmap_dir).unwrap();
+        let mut mmap_index = GeoMapIndex::Mmap(Box::new(MmapGeoMapIndex::open(&mmap_dir).unwrap()));
+        assert_eq!(mmap_index.points_count(), 4);
+        assert_eq!(mmap_index.points_values_count(), 4);
+        check_index(&mmap_index);
+
+        let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+        let mut loaded_index = GeoMapIndex::new(db, FIELD_NAME);
+        loaded_index.load().unwrap();
+        assert_eq!(loaded_index.points_count(), 4);
+        check_index(&loaded_index);
+
+        for index in [&mut loaded_index, &mut mmap_index] {
+            index.remove_point(1).unwrap();
+            assert_eq!(index.points_count(), 3);
+            assert!(index.values_is_empty(1));
+            let condition = &conditions[0].0;
+            assert_eq!(index.filter(condition).unwrap().collect_vec(), vec![2]);
+        }
+    }
 }
@@ -11,6 +11,7 @@ use crate::index::field_index::numeric_index::NumericIndex;
 use crate::index::field_index::FieldIndex;
 use crate::types::{
     FloatPayloadType, IntPayloadType, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
+    UuidIntType,
 };
 
 /// Selects index types based on field type
@@ -49,11 +50,37 @@ pub fn index_selector(
                 field,
             ))],
             PayloadSchemaType::Bool => vec![FieldIndex::BinaryIndex(BinaryIndex::new(db, field))],
+            PayloadSchemaType::Datetime => {
+                vec![FieldIndex::DatetimeIndex(
+                    NumericIndex::<IntPayloadType>::new(db, field, is_appendable),
+                )]
+            }
+            PayloadSchemaType::Uuid => {
+                vec![FieldIndex::UuidMapIndex(MapIndex::<UuidIntType>::new(
+                    db,
+                    field,
+                    is_appendable,
+                ))]
+            }
         },
         PayloadFieldSchema::FieldParams(payload_params) => match payload_params {
             PayloadSchemaParams::Text(text_index_params) => vec![FieldIndex::FullTextIndex(
                 FullTextIndex::new(db, text_index_params.clone(), field),
             )],
+            // Tenant and on-disk parameters only affect the placement of points and the storage
+            // of the index, index structures are the same
+            PayloadSchemaParams::Keyword(_)
+            | PayloadSchemaParams::Integer(_)
+            | PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::Uuid(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Bool(_) => index_selector(
+                field,
+                &PayloadFieldSchema::FieldType(payload_params.kind()),
+                db,
+                is_appendable,
+            ),
         },
     }
 }
@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::hash::Hash;
 use std::iter;
-use std::ops::Range;
+use std::ops::{Bound, Range};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -11,12 +11,12 @@ use parking_lot::RwLock;
 use rocksdb::DB;
 
 use super::mutable_map_index::MutableMapIndex;
-use super::MapIndex;
+use super::{MapIndex, MapIndexKey};
 use crate::common::operation_error::OperationResult;
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 
 pub struct ImmutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
-    value_to_points: HashMap<N, Range<u32>>,
+    value_to_points: BTreeMap<N, Range<u32>>,
     value_to_points_container: Vec<PointOffsetType>,
     point_to_values: Vec<Range<u32>>,
     point_to_values_container: Vec<N>,
@@ -26,7 +26,7 @@ pub struct ImmutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
     db_wrapper: DatabaseColumnWrapper,
 }
 
-impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
+impl<N: MapIndexKey> ImmutableMapIndex<N> {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str) -> Self {
         let store_cf_name = MapIndex::<N>::storage_cf_name(field_name);
         let db_wrapper = DatabaseColumnWrapper::new(db, &store_cf_name);
@@ -43,7 +43,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
 
     /// Return mutable slice of a container which holds point_ids for given value.
     fn get_mut_point_ids_slice(&mut self, value: &N) -> Option<&mut [PointOffsetType]> {
-        match self.value_to_points.get(value) {
+        match self.value_to_points.get(value.borrow()) {
             Some(vals_range) if vals_range.start < vals_range.end => {
                 let range = vals_range.start as usize..vals_range.end as usize;
                 let vals = &mut self.value_to_points_container[range];
@@ -56,7 +56,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
     /// Shrinks the range of values-to-points by one.
     /// Returns true if the last element was removed.
     fn shrink_value_range(&mut self, value: &N) -> bool {
-        if let Some(range) = self.value_to_points.get_mut(value) {
+        if let Some(range) = self.value_to_points.get_mut(value.borrow()) {
             range.end -= 1;
             return range.start == range.end; // true if the last element was removed
         }
@@ -106,7 +106,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
         }
 
         if self.shrink_value_range(value) {
-            self.value_to_points.remove(value);
+            self.value_to_points.remove(value.borrow());
         }
     }
 
@@ -215,7 +215,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
     where
         Q: ?Sized,
         N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Ord,
     {
         self.value_to_points.get(value).map(|p| p.len())
     }
@@ -224,7 +224,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
     where
         Q: ?Sized,
         N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Ord,
     {
         if let Some(range) = self.value_to_points.get(value) {
             let range = range.start as usize..range.end as usize;
@@ -237,4 +237,14 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> ImmutableMapIndex<N> {
     pub fn get_values_iterator(&self) -> Box<dyn Iterator<Item = &N> + '_> {
         Box::new(self.value_to_points.keys())
     }
+
+    /// Values within the bounds, in ascending order
+    pub fn get_values_range<'a>(
+        &'a self,
+        range: (Bound<&'a N::Referenced>, Bound<&'a N::Referenced>),
+    ) -> impl Iterator<Item = &'a N> + 'a {
+        self.value_to_points
+            .range::<N::Referenced, _>(range)
+            .map(|(value, _points)| value)
+    }
 }
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fs::create_dir_all;
+use std::marker::PhantomData;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+
+use super::mutable_map_index::MutableMapIndex;
+use super::MapIndexKey;
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::index::field_index::mmap_lists::MmapLists;
+
+const VALUES: &str = "values";
+const VALUE_TO_POINTS: &str = "value_to_points";
+const POINT_TO_VALUES: &str = "point_to_values";
+
+pub struct MmapMapIndex<N: MapIndexKey> {
+    dir: PathBuf,
+    /// Unique values, in their binary representation, sorted
+    values: MmapLists<u8>,
+    /// Sorted points of each of the `values`
+    value_to_points: MmapLists<PointOffsetType>,
+    /// Positions of the point values in `values`
+    point_to_values: MmapLists<u32>,
+    /// Amount of point which have at least one indexed payload value
+    indexed_points: usize,
+    values_count: usize,
+    /// Amount of values which have at least one point
+    unique_values_count: usize,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: MapIndexKey> MmapMapIndex<N> {
+    pub fn create(dir: &Path, index: &MutableMapIndex<N>) -> OperationResult<()> {
+        create_dir_all(dir)?;
+
+        let mut values: Vec<_> = index
+            .map
+            .iter()
+            .filter(|(_value, points)| !points.is_empty())
+            .map(|(value, points)| (N::encode_referenced(value.borrow()), points))
+            .collect();
+        values.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let value_ids: HashMap<&[u8], u32> = values
+            .iter()
+            .enumerate()
+            .map(|(value_id, (bytes, _points))| (bytes.as_ref(), value_id as u32))
+            .collect();
+
+        MmapLists::<u8>::create(dir, VALUES, values.iter().map(|(bytes, _points)| bytes))?;
+        MmapLists::<PointOffsetType>::create(
+            dir,
+            VALUE_TO_POINTS,
+            values
+                .iter()
+                .map(|(_bytes, points)| points.iter().copied().collect::<Vec<_>>()),
+        )?;
+        MmapLists::<u32>::create(
+            dir,
+            POINT_TO_VALUES,
+            index.point_to_values.iter().map(|point_values| {
+                point_values
+                    .iter()
+                    .map(|value| value_ids[&N::encode_referenced(value.borrow()).as_ref()])
+                    .collect::<Vec<_>>()
+            }),
+        )
+    }
+
+    pub fn open(dir: &Path) -> OperationResult<Self> {
+        let values = MmapLists::open(dir, VALUES)?;
+        let value_to_points = MmapLists::<PointOffsetType>::open(dir, VALUE_TO_POINTS)?;
+        let point_to_values = MmapLists::<u32>::open(dir, POINT_TO_VALUES)?;
+
+        let indexed_points = point_to_values
+            .iter()
+            .filter(|point_values| !point_values.is_empty())
+            .count();
+        let values_count = point_to_values.iter().map(<[u32]>::len).sum();
+        let unique_values_count = value_to_points
+            .iter()
+            .filter(|points| !points.is_empty())
+            .count();
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            values,
+            value_to_points,
+            point_to_values,
+            indexed_points,
+            values_count,
+            unique_values_count,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Position of the value in the sorted `values`
+    fn find_value(&self, value: &N::Referenced) -> Option<usize> {
+        let bytes = N::encode_referenced(value);
+        let position = self.values.partition_point(|other| other < bytes.as_ref());
+        (self.values.get(position)? == bytes.as_ref()).then_some(position)
+    }
+
+    fn get_value(&self, value_id: u32) -> N {
+        N::decode(self.values.get(value_id as usize).unwrap_or_default())
+    }
+
+    pub fn get_values(&self, idx: PointOffsetType) -> Option<impl Iterator<Item = N> + '_> {
+        let value_ids = self.point_to_values.get(idx as usize)?;
+        Some(value_ids.iter().map(|&value_id| self.get_value(value_id)))
+    }
+
+    pub fn values_count(&self, idx: PointOffsetType) -> usize {
+        self.point_to_values
+            .get(idx as usize)
+            .map_or(0, <[u32]>::len)
+    }
+
+    pub fn get_indexed_points(&self) -> usize {
+        self.indexed_points
+    }
+
+    pub fn get_values_count(&self) -> usize {
+        self.values_count
+    }
+
+    pub fn get_unique_values_count(&self) -> usize {
+        self.unique_values_count
+    }
+
+    pub fn get_points_with_value_count(&self, value: &N::Referenced) -> Option<usize> {
+        let value_id = self.find_value(value)?;
+        self.value_to_points.get(value_id).map(<[_]>::len)
+    }
+
+    pub fn get_iterator(
+        &self,
+        value: &N::Referenced,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        match self
+            .find_value(value)
+            .and_then(|value_id| self.value_to_points.get(value_id))
+        {
+            Some(points) => Box::new(points.iter().copied()),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    pub fn get_values_iterator(&self) -> Box<dyn Iterator<Item = N> + '_> {
+        Box::new(
+            self.values
+                .iter()
+                .zip(self.value_to_points.iter())
+                .filter(|(_value, points)| !points.is_empty())
+                .map(|(value, _points)| N::decode(value)),
+        )
+    }
+
+    /// Values within the bounds, which have at least one point, in ascending order
+    ///
+    /// Values are compared by their binary representation, which keeps the order of strings only.
+    pub fn get_values_range<'a>(
+        &'a self,
+        lower: Bound<&[u8]>,
+        upper: Bound<&'a [u8]>,
+    ) -> impl Iterator<Item = N> + 'a {
+        let start = match lower {
+            Bound::Included(lower) => self.values.partition_point(|value| value < lower),
+            Bound::Excluded(lower) => self.values.partition_point(|value| value <= lower),
+            Bound::Unbounded => 0,
+        };
+        self.values
+            .iter_from(start)
+            .zip(self.value_to_points.iter_from(start))
+            .take_while(move |(value, _points)| match upper {
+                Bound::Included(upper) => *value <= upper,
+                Bound::Excluded(upper) => *value < upper,
+                Bound::Unbounded => true,
+            })
+            .filter(|(_value, points)| !points.is_empty())
+            .map(|(value, _points)| N::decode(value))
+    }
+
+    pub fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
+        let Some(value_ids) = self.point_to_values.take(idx as usize) else {
+            return Ok(());
+        };
+
+        if !value_ids.is_empty() {
+            self.indexed_points -= 1;
+        }
+        self.values_count -= value_ids.len();
+
+        for &value_id in value_ids {
+            let Some(points) = self.value_to_points.get(value_id as usize) else {
+                continue;
+            };
+            let points_count = points.len();
+            // A point may have the same value several times, it is removed on the first occurrence
+            if let Ok(position) = points.binary_search(&idx) {
+                self.value_to_points.remove_at(value_id as usize, position);
+                if points_count == 1 {
+                    self.unique_values_count -= 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        let value_to_points_flusher = self.value_to_points.flusher();
+        let point_to_values_flusher = self.point_to_values.flusher();
+        Box::new(move || {
+            value_to_points_flusher()?;
+            point_to_values_flusher()
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.values.files();
+        files.extend(self.value_to_points.files());
+        files.extend(self.point_to_values.files());
+        files
+    }
+
+    pub fn clear(self) -> OperationResult<()> {
+        let dir = self.dir.clone();
+        drop(self);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
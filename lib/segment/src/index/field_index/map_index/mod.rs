@@ -1,40 +1,115 @@
 pub mod immutable_map_index;
+pub mod mmap_map_index;
 pub mod mutable_map_index;
 
+use std::borrow::{Borrow, Cow};
 use std::fmt::Display;
 use std::hash::Hash;
+use std::iter;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
 use immutable_map_index::ImmutableMapIndex;
-use itertools::Itertools;
+use itertools::{Either, Itertools};
+use mmap_map_index::MmapMapIndex;
 use mutable_map_index::MutableMapIndex;
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde_json::Value;
 use smol_str::SmolStr;
+use uuid::Uuid;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::index::field_index::stat_tools::number_of_selected_points;
+use crate::index::field_index::utils::check_boundaries;
 use crate::index::field_index::{
     CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, PrimaryCondition, ValueIndexer,
 };
 use crate::index::query_estimator::combine_should_estimations;
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept, MatchValue,
-    PayloadKeyType, ValueVariants,
+    AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept, MatchPrefix,
+    MatchValue, PayloadKeyType, RangeInterface, UuidIntType, ValueVariants,
 };
 
-pub enum MapIndex<N: Hash + Eq + Clone + Display + FromStr> {
+/// Integer representation of the UUID string, `None` if the string is not a valid UUID
+pub fn parse_uuid(value: &str) -> Option<UuidIntType> {
+    Uuid::parse_str(value).ok().map(|uuid| uuid.as_u128())
+}
+
+/// Hyphenated string representation of the UUID, as it is stored in the payload
+pub fn uuid_to_string(value: UuidIntType) -> String {
+    Uuid::from_u128(value).to_string()
+}
+
+/// Parse each of the UUID strings, strings which are not valid UUIDs can't match any value
+fn parse_uuids(values: &[String]) -> Vec<UuidIntType> {
+    values
+        .iter()
+        .filter_map(|value| parse_uuid(value))
+        .collect()
+}
+
+/// Value of the map index
+pub trait MapIndexKey:
+    Hash + Ord + Clone + Display + FromStr + Default + Borrow<Self::Referenced>
+{
+    /// Type of the values to look up in the index, e.g. `str` for strings
+    type Referenced: ?Sized + Hash + Ord;
+
+    /// Binary representation of the value, as stored in the on-disk index
+    fn encode_referenced(value: &Self::Referenced) -> Cow<'_, [u8]>;
+
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+impl MapIndexKey for SmolStr {
+    type Referenced = str;
+
+    fn encode_referenced(value: &str) -> Cow<'_, [u8]> {
+        Cow::Borrowed(value.as_bytes())
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        SmolStr::new(String::from_utf8_lossy(bytes))
+    }
+}
+
+impl MapIndexKey for IntPayloadType {
+    type Referenced = IntPayloadType;
+
+    fn encode_referenced(value: &IntPayloadType) -> Cow<'_, [u8]> {
+        Cow::Owned(value.to_be_bytes().to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        IntPayloadType::from_be_bytes(bytes.try_into().unwrap_or_default())
+    }
+}
+
+impl MapIndexKey for UuidIntType {
+    type Referenced = UuidIntType;
+
+    fn encode_referenced(value: &UuidIntType) -> Cow<'_, [u8]> {
+        Cow::Owned(value.to_be_bytes().to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        UuidIntType::from_be_bytes(bytes.try_into().unwrap_or_default())
+    }
+}
+
+pub enum MapIndex<N: MapIndexKey> {
     Mutable(MutableMapIndex<N>),
     Immutable(ImmutableMapIndex<N>),
+    Mmap(MmapMapIndex<N>),
 }
 
-impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
+impl<N: MapIndexKey> MapIndex<N> {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str, is_appendable: bool) -> Self {
         if is_appendable {
             MapIndex::Mutable(MutableMapIndex::new(db, field_name))
@@ -43,24 +118,21 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
         }
     }
 
-    fn get_db_wrapper(&self) -> &DatabaseColumnWrapper {
-        match self {
-            MapIndex::Mutable(index) => index.get_db_wrapper(),
-            MapIndex::Immutable(index) => index.get_db_wrapper(),
-        }
-    }
-
     fn load_from_db(&mut self) -> OperationResult<bool> {
         match self {
             MapIndex::Mutable(index) => index.load_from_db(),
             MapIndex::Immutable(index) => index.load_from_db(),
+            MapIndex::Mmap(_) => Ok(true),
         }
     }
 
-    pub fn get_values(&self, idx: PointOffsetType) -> Option<&[N]> {
+    pub fn get_values(&self, idx: PointOffsetType) -> Option<impl Iterator<Item = N> + '_> {
         match self {
-            MapIndex::Mutable(index) => index.get_values(idx),
-            MapIndex::Immutable(index) => index.get_values(idx),
+            MapIndex::Mutable(index) => Some(Either::Left(index.get_values(idx)?.iter().cloned())),
+            MapIndex::Immutable(index) => {
+                Some(Either::Left(index.get_values(idx)?.iter().cloned()))
+            }
+            MapIndex::Mmap(index) => Some(Either::Right(index.get_values(idx)?)),
         }
     }
 
@@ -68,6 +140,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
         match self {
             MapIndex::Mutable(index) => index.get_indexed_points(),
             MapIndex::Immutable(index) => index.get_indexed_points(),
+            MapIndex::Mmap(index) => index.get_indexed_points(),
         }
     }
 
@@ -75,6 +148,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
         match self {
             MapIndex::Mutable(index) => index.get_values_count(),
             MapIndex::Immutable(index) => index.get_values_count(),
+            MapIndex::Mmap(index) => index.get_values_count(),
         }
     }
 
@@ -82,58 +156,103 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
         match self {
             MapIndex::Mutable(index) => index.get_unique_values_count(),
             MapIndex::Immutable(index) => index.get_unique_values_count(),
+            MapIndex::Mmap(index) => index.get_unique_values_count(),
         }
     }
 
-    fn get_points_with_value_count<Q>(&self, value: &Q) -> Option<usize>
-    where
-        Q: ?Sized,
-        N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
-    {
+    fn get_points_with_value_count(&self, value: &N::Referenced) -> Option<usize> {
         match self {
             MapIndex::Mutable(index) => index.get_points_with_value_count(value),
             MapIndex::Immutable(index) => index.get_points_with_value_count(value),
+            MapIndex::Mmap(index) => index.get_points_with_value_count(value),
         }
     }
 
-    fn get_iterator<Q>(&self, value: &Q) -> Box<dyn Iterator<Item = PointOffsetType> + '_>
-    where
-        Q: ?Sized,
-        N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
-    {
+    fn get_iterator(
+        &self,
+        value: &N::Referenced,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         match self {
             MapIndex::Mutable(index) => index.get_iterator(value),
             MapIndex::Immutable(index) => index.get_iterator(value),
+            MapIndex::Mmap(index) => index.get_iterator(value),
         }
     }
 
-    fn get_values_iterator(&self) -> Box<dyn Iterator<Item = &N> + '_> {
+    fn get_values_iterator(&self) -> Box<dyn Iterator<Item = N> + '_> {
         match self {
-            MapIndex::Mutable(index) => index.get_values_iterator(),
-            MapIndex::Immutable(index) => index.get_values_iterator(),
+            MapIndex::Mutable(index) => Box::new(index.get_values_iterator().cloned()),
+            MapIndex::Immutable(index) => Box::new(index.get_values_iterator().cloned()),
+            MapIndex::Mmap(index) => index.get_values_iterator(),
         }
     }
 
+    /// Iterate over unique values, along with the number of points having each of them
+    pub fn iter_counts_per_value(&self) -> impl Iterator<Item = (N, usize)> + '_ {
+        self.get_values_iterator().map(|value| {
+            let count = self
+                .get_points_with_value_count(value.borrow())
+                .unwrap_or_default();
+            (value, count)
+        })
+    }
+
     pub fn storage_cf_name(field: &str) -> String {
         format!("{field}_map")
     }
 
     pub fn recreate(&self) -> OperationResult<()> {
-        self.get_db_wrapper().recreate_column_family()
+        match self {
+            MapIndex::Mutable(index) => index.get_db_wrapper().recreate_column_family(),
+            MapIndex::Immutable(index) => index.get_db_wrapper().recreate_column_family(),
+            MapIndex::Mmap(_) => Ok(()),
+        }
     }
 
     fn flusher(&self) -> Flusher {
-        self.get_db_wrapper().flusher()
+        match self {
+            MapIndex::Mutable(index) => index.get_db_wrapper().flusher(),
+            MapIndex::Immutable(index) => index.get_db_wrapper().flusher(),
+            MapIndex::Mmap(index) => index.flusher(),
+        }
     }
 
-    fn match_cardinality<Q>(&self, value: &Q) -> CardinalityEstimation
-    where
-        Q: ?Sized,
-        N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
-    {
+    fn wipe(self) -> OperationResult<()> {
+        match self {
+            MapIndex::Mutable(index) => index.get_db_wrapper().recreate_column_family(),
+            MapIndex::Immutable(index) => index.get_db_wrapper().recreate_column_family(),
+            MapIndex::Mmap(index) => index.clear(),
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        match self {
+            MapIndex::Mutable(index) => MmapMapIndex::create(dir, index),
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => Err(OperationError::service_error(
+                "Only mutable map index can be saved on disk",
+            )),
+        }
+    }
+
+    /// Replace the index with the memory mapped one, saved in the directory with [`Self::save_mmap`]
+    pub fn into_mmap(self, dir: &Path) -> OperationResult<Self> {
+        match &self {
+            MapIndex::Mutable(index) => index.get_db_wrapper().remove_column_family()?,
+            MapIndex::Immutable(index) => index.get_db_wrapper().remove_column_family()?,
+            MapIndex::Mmap(_) => {}
+        }
+        Ok(MapIndex::Mmap(MmapMapIndex::open(dir)?))
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            MapIndex::Mutable(_) | MapIndex::Immutable(_) => vec![],
+            MapIndex::Mmap(index) => index.files(),
+        }
+    }
+
+    fn match_cardinality(&self, value: &N::Referenced) -> CardinalityEstimation {
         let values_count = self.get_points_with_value_count(value).unwrap_or(0);
 
         CardinalityEstimation::exact(values_count)
@@ -170,13 +289,15 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
     }
 
     pub fn values_count(&self, point_id: PointOffsetType) -> usize {
-        self.get_values(point_id).map(|x| x.len()).unwrap_or(0)
+        match self {
+            MapIndex::Mutable(index) => index.get_values(point_id).map_or(0, <[N]>::len),
+            MapIndex::Immutable(index) => index.get_values(point_id).map_or(0, <[N]>::len),
+            MapIndex::Mmap(index) => index.values_count(point_id),
+        }
     }
 
     pub fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
-        self.get_values(point_id)
-            .map(|x| x.is_empty())
-            .unwrap_or(true)
+        self.values_count(point_id) == 0
     }
 
     /// Estimates cardinality for `except` clause
@@ -188,12 +309,12 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
     /// # Returns
     ///
     /// * `CardinalityEstimation` - estimation of cardinality
-    fn except_cardinality<Q, I>(&self, excluded: impl Iterator<Item = I>) -> CardinalityEstimation
+    fn except_cardinality<'a>(
+        &self,
+        excluded: impl Iterator<Item = &'a N::Referenced>,
+    ) -> CardinalityEstimation
     where
-        I: std::borrow::Borrow<Q>,
-        Q: ?Sized,
-        N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
+        N::Referenced: 'a,
     {
         // Minimal case: we exclude as many points as possible.
         // In this case, excluded points do not have any other values except excluded ones.
@@ -237,7 +358,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
         // max = min(60, 20) = 20
 
         let excluded_value_counts: Vec<_> = excluded
-            .map(|val| self.get_points_with_value_count(val.borrow()).unwrap_or(0))
+            .map(|val| self.get_points_with_value_count(val).unwrap_or(0))
             .collect();
         let total_excluded_value_count: usize = excluded_value_counts.iter().sum();
 
@@ -300,11 +421,124 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MapIndex<N> {
     {
         Box::new(
             self.get_values_iterator()
-                .filter(|key| !excluded.iter().any(|e| e.eq(*key)))
-                .flat_map(|key| self.get_iterator(key))
+                .filter(|key| !excluded.iter().any(|e| e.eq(key)))
+                .flat_map(|key| self.get_iterator(key.borrow()))
+                .unique(),
+        )
+    }
+}
+
+/// Maximal number of keywords, matching a prefix or a range, which are counted to estimate
+/// cardinality of the condition
+const KEYWORDS_CARDINALITY_LIMIT: usize = 1000;
+
+impl MapIndex<SmolStr> {
+    /// Unique keywords within the bounds, in lexicographic order
+    fn get_keywords_range<'a>(
+        &'a self,
+        lower: Bound<&'a str>,
+        upper: Bound<&'a str>,
+    ) -> Box<dyn Iterator<Item = SmolStr> + 'a> {
+        // BTreeMap::range panics on inverted or empty excluded bounds
+        if !check_boundaries(&lower, &upper) {
+            return Box::new(iter::empty());
+        }
+        match self {
+            MapIndex::Mutable(index) => Box::new(index.get_values_range((lower, upper)).cloned()),
+            MapIndex::Immutable(index) => Box::new(index.get_values_range((lower, upper)).cloned()),
+            MapIndex::Mmap(index) => {
+                Box::new(index.get_values_range(lower.map(str::as_bytes), upper.map(str::as_bytes)))
+            }
+        }
+    }
+
+    /// Unique keywords, which start with the prefix, in lexicographic order
+    fn get_keywords_with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = SmolStr> + 'a {
+        self.get_keywords_range(Bound::Included(prefix), Bound::Unbounded)
+            .take_while(move |keyword| keyword.starts_with(prefix))
+    }
+
+    /// Keywords, matching the prefix or range condition
+    fn get_matching_keywords<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+    ) -> Option<Box<dyn Iterator<Item = SmolStr> + 'a>> {
+        match (&condition.r#match, &condition.range) {
+            (Some(Match::Prefix(MatchPrefix { prefix })), _) => {
+                Some(Box::new(self.get_keywords_with_prefix(prefix)))
+            }
+            (_, Some(RangeInterface::Keyword { keyword: range })) => {
+                let (lower, upper) = range.bounds();
+                Some(self.get_keywords_range(lower, upper))
+            }
+            _ => None,
+        }
+    }
+
+    fn keywords_iterator<'a>(
+        &'a self,
+        keywords: impl Iterator<Item = SmolStr> + 'a,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        Box::new(
+            keywords
+                .flat_map(|keyword| self.get_iterator(keyword.as_str()))
                 .unique(),
         )
     }
+
+    /// Estimate cardinality of points with any of the keywords
+    ///
+    /// Only the first [`KEYWORDS_CARDINALITY_LIMIT`] keywords are counted. If there are more,
+    /// the estimation is bounded by the counted keywords from below and by all values of the index
+    /// from above.
+    fn keywords_cardinality(
+        &self,
+        mut keywords: impl Iterator<Item = SmolStr>,
+    ) -> CardinalityEstimation {
+        let estimations = keywords
+            .by_ref()
+            .take(KEYWORDS_CARDINALITY_LIMIT)
+            .map(|keyword| self.match_cardinality(keyword.as_str()))
+            .collect::<Vec<_>>();
+        if estimations.is_empty() {
+            return CardinalityEstimation::exact(0);
+        }
+        let points_count = self.get_indexed_points();
+        if keywords.next().is_none() {
+            return combine_should_estimations(&estimations, points_count);
+        }
+
+        // Example: points_count = 1000, total_values = 1200, counted_values = 500
+        // min = max(max_counted_value, 500 - (1200 - 1000)) = 300
+        // exp = selected by (500 + 1200) / 2 = 850 values
+        // max = min(1000, 1200) = 1000
+        let total_values = self.get_values_count();
+        let counted_values: usize = estimations.iter().map(|estimation| estimation.exp).sum();
+        let max_counted_value = estimations
+            .iter()
+            .map(|estimation| estimation.min)
+            .max()
+            .unwrap_or(0);
+
+        let min = max_counted_value
+            .max(counted_values.saturating_sub(total_values.saturating_sub(points_count)));
+        let max = points_count.min(total_values);
+        // Walk stopped within the matching keywords, so half of the rest is assumed to match
+        let expected_values = counted_values + total_values.saturating_sub(counted_values) / 2;
+        let exp = number_of_selected_points(points_count, expected_values)
+            .max(min)
+            .min(max);
+
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min,
+            exp,
+            max,
+        }
+    }
 }
 
 impl PayloadFieldIndex for MapIndex<SmolStr> {
@@ -317,7 +551,7 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.get_db_wrapper().recreate_column_family()
+        self.wipe()
     }
 
     fn flusher(&self) -> Flusher {
@@ -352,7 +586,10 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
             Some(Match::Except(MatchExcept {
                 except: AnyVariants::Keywords(keywords),
             })) => Ok(self.except_iterator(keywords)),
-            _ => Err(OperationError::service_error("failed to filter")),
+            _ => match self.get_matching_keywords(condition) {
+                Some(keywords) => Ok(self.keywords_iterator(keywords)),
+                None => Err(OperationError::service_error("failed to filter")),
+            },
         }
     }
 
@@ -397,10 +634,15 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
             },
             Some(Match::Except(MatchExcept {
                 except: AnyVariants::Keywords(keywords),
-            })) => Ok(self.except_cardinality::<str, &str>(keywords.iter().map(|k| k.as_str()))),
-            _ => Err(OperationError::service_error(
-                "failed to estimate cardinality",
-            )),
+            })) => Ok(self.except_cardinality(keywords.iter().map(|k| k.as_str()))),
+            _ => match self.get_matching_keywords(condition) {
+                Some(keywords) => Ok(self
+                    .keywords_cardinality(keywords)
+                    .with_primary_clause(PrimaryCondition::Condition(condition.clone()))),
+                None => Err(OperationError::service_error(
+                    "failed to estimate cardinality",
+                )),
+            },
         }
     }
 
@@ -411,10 +653,13 @@ impl PayloadFieldIndex for MapIndex<SmolStr> {
     ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
         Box::new(
             self.get_values_iterator()
-                .map(|value| (value, self.get_points_with_value_count(value).unwrap_or(0)))
+                .map(|value| {
+                    let count = self.get_points_with_value_count(value.borrow());
+                    (value, count.unwrap_or(0))
+                })
                 .filter(move |(_value, count)| *count > threshold)
                 .map(move |(value, count)| PayloadBlockCondition {
-                    condition: FieldCondition::new_match(key.clone(), value.to_owned().into()),
+                    condition: FieldCondition::new_match(key.clone(), value.into()),
                     cardinality: count,
                 }),
         )
@@ -431,7 +676,7 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.get_db_wrapper().recreate_column_family()
+        self.wipe()
     }
 
     fn flusher(&self) -> Flusher {
@@ -511,9 +756,140 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
             },
             Some(Match::Except(MatchExcept {
                 except: AnyVariants::Integers(integers),
+            })) => Ok(self.except_cardinality(integers.iter())),
+            _ => Err(OperationError::service_error(
+                "failed to estimate cardinality",
+            )),
+        }
+    }
+
+    fn payload_blocks(
+        &self,
+        threshold: usize,
+        key: PayloadKeyType,
+    ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
+        Box::new(
+            self.get_values_iterator()
+                .map(|value| {
+                    let count = self.get_points_with_value_count(value.borrow());
+                    (value, count.unwrap_or(0))
+                })
+                .filter(move |(_value, count)| *count >= threshold)
+                .map(move |(value, count)| PayloadBlockCondition {
+                    condition: FieldCondition::new_match(key.clone(), value.into()),
+                    cardinality: count,
+                }),
+        )
+    }
+}
+
+impl PayloadFieldIndex for MapIndex<UuidIntType> {
+    fn count_indexed_points(&self) -> usize {
+        self.get_indexed_points()
+    }
+
+    fn load(&mut self) -> OperationResult<bool> {
+        self.load_from_db()
+    }
+
+    fn clear(self) -> OperationResult<()> {
+        self.wipe()
+    }
+
+    fn flusher(&self) -> Flusher {
+        MapIndex::flusher(self)
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+    ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        match &condition.r#match {
+            Some(Match::Value(MatchValue {
+                value: ValueVariants::Keyword(keyword),
+            })) => match parse_uuid(keyword) {
+                Some(uuid) => Ok(self.get_iterator(&uuid)),
+                None => Ok(Box::new(std::iter::empty())),
+            },
+            Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
+                AnyVariants::Keywords(keywords) => Ok(Box::new(
+                    parse_uuids(keywords)
+                        .into_iter()
+                        .flat_map(|uuid| self.get_iterator(&uuid))
+                        .unique(),
+                )),
+                AnyVariants::Integers(integers) => {
+                    if integers.is_empty() {
+                        Ok(Box::new(vec![].into_iter()))
+                    } else {
+                        Err(OperationError::service_error(
+                            "failed to estimate cardinality",
+                        ))
+                    }
+                }
+            },
+            Some(Match::Except(MatchExcept {
+                except: AnyVariants::Keywords(keywords),
             })) => {
-                Ok(self
-                    .except_cardinality::<IntPayloadType, IntPayloadType>(integers.iter().cloned()))
+                let excluded = parse_uuids(keywords);
+                Ok(Box::new(
+                    self.get_values_iterator()
+                        .filter(move |uuid| !excluded.contains(uuid))
+                        .flat_map(|uuid| self.get_iterator(&uuid))
+                        .unique(),
+                ))
+            }
+            _ => Err(OperationError::service_error("failed to filter")),
+        }
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+    ) -> OperationResult<CardinalityEstimation> {
+        match &condition.r#match {
+            Some(Match::Value(MatchValue {
+                value: ValueVariants::Keyword(keyword),
+            })) => {
+                let mut estimation = match parse_uuid(keyword) {
+                    Some(uuid) => self.match_cardinality(&uuid),
+                    None => CardinalityEstimation::exact(0),
+                };
+                estimation
+                    .primary_clauses
+                    .push(PrimaryCondition::Condition(condition.clone()));
+                Ok(estimation)
+            }
+            Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
+                AnyVariants::Keywords(keywords) => {
+                    let estimations = parse_uuids(keywords)
+                        .iter()
+                        .map(|uuid| self.match_cardinality(uuid))
+                        .collect::<Vec<_>>();
+                    let estimation = if estimations.is_empty() {
+                        CardinalityEstimation::exact(0)
+                    } else {
+                        combine_should_estimations(&estimations, self.get_indexed_points())
+                    };
+                    Ok(estimation
+                        .with_primary_clause(PrimaryCondition::Condition(condition.clone())))
+                }
+                AnyVariants::Integers(integers) => {
+                    if integers.is_empty() {
+                        Ok(CardinalityEstimation::exact(0)
+                            .with_primary_clause(PrimaryCondition::Condition(condition.clone())))
+                    } else {
+                        Err(OperationError::service_error(
+                            "failed to estimate cardinality",
+                        ))
+                    }
+                }
+            },
+            Some(Match::Except(MatchExcept {
+                except: AnyVariants::Keywords(keywords),
+            })) => {
+                let excluded = parse_uuids(keywords);
+                Ok(self.except_cardinality(excluded.iter()))
             }
             _ => Err(OperationError::service_error(
                 "failed to estimate cardinality",
@@ -528,10 +904,13 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
     ) -> Box<dyn Iterator<Item = PayloadBlockCondition> + '_> {
         Box::new(
             self.get_values_iterator()
-                .map(|value| (value, self.get_points_with_value_count(value).unwrap_or(0)))
-                .filter(move |(_value, count)| *count >= threshold)
+                .map(|value| {
+                    let count = self.get_points_with_value_count(value.borrow());
+                    (value, count.unwrap_or(0))
+                })
+                .filter(move |(_value, count)| *count > threshold)
                 .map(move |(value, count)| PayloadBlockCondition {
-                    condition: FieldCondition::new_match(key.clone(), value.to_owned().into()),
+                    condition: FieldCondition::new_match(key.clone(), uuid_to_string(value).into()),
                     cardinality: count,
                 }),
         )
@@ -542,7 +921,7 @@ impl ValueIndexer<String> for MapIndex<SmolStr> {
     fn add_many(&mut self, id: PointOffsetType, values: Vec<String>) -> OperationResult<()> {
         match self {
             MapIndex::Mutable(index) => index.add_many_to_map(id, values),
-            MapIndex::Immutable(_) => Err(OperationError::service_error(
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => Err(OperationError::service_error(
                 "Can't add values to immutable map index",
             )),
         }
@@ -559,6 +938,7 @@ impl ValueIndexer<String> for MapIndex<SmolStr> {
         match self {
             MapIndex::Mutable(index) => index.remove_point(id),
             MapIndex::Immutable(index) => index.remove_point(id),
+            MapIndex::Mmap(index) => index.remove_point(id),
         }
     }
 }
@@ -571,7 +951,7 @@ impl ValueIndexer<IntPayloadType> for MapIndex<IntPayloadType> {
     ) -> OperationResult<()> {
         match self {
             MapIndex::Mutable(index) => index.add_many_to_map(id, values),
-            MapIndex::Immutable(_) => Err(OperationError::service_error(
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => Err(OperationError::service_error(
                 "Can't add values to immutable map index",
             )),
         }
@@ -588,6 +968,33 @@ impl ValueIndexer<IntPayloadType> for MapIndex<IntPayloadType> {
         match self {
             MapIndex::Mutable(index) => index.remove_point(id),
             MapIndex::Immutable(index) => index.remove_point(id),
+            MapIndex::Mmap(index) => index.remove_point(id),
+        }
+    }
+}
+
+impl ValueIndexer<UuidIntType> for MapIndex<UuidIntType> {
+    fn add_many(&mut self, id: PointOffsetType, values: Vec<UuidIntType>) -> OperationResult<()> {
+        match self {
+            MapIndex::Mutable(index) => index.add_many_to_map(id, values),
+            MapIndex::Immutable(_) | MapIndex::Mmap(_) => Err(OperationError::service_error(
+                "Can't add values to immutable map index",
+            )),
+        }
+    }
+
+    fn get_value(&self, value: &Value) -> Option<UuidIntType> {
+        if let Value::String(keyword) = value {
+            return parse_uuid(keyword);
+        }
+        None
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        match self {
+            MapIndex::Mutable(index) => index.remove_point(id),
+            MapIndex::Immutable(index) => index.remove_point(id),
+            MapIndex::Mmap(index) => index.remove_point(id),
         }
     }
 }
@@ -603,13 +1010,11 @@ mod tests {
 
     use super::*;
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
+    use crate::types::KeywordRange;
 
     const FIELD_NAME: &str = "test";
 
-    fn save_map_index<N: Hash + Eq + Clone + Display + FromStr + Debug + Default>(
-        data: &[Vec<N>],
-        path: &Path,
-    ) {
+    fn save_map_index<N: MapIndexKey + Debug>(data: &[Vec<N>], path: &Path) {
         let mut index =
             MapIndex::<N>::new(open_db_with_existing_cf(path).unwrap(), FIELD_NAME, true);
         index.recreate().unwrap();
@@ -624,21 +1029,13 @@ mod tests {
         index.flusher()().unwrap();
     }
 
-    fn load_map_index<N: Hash + Eq + Clone + Display + FromStr + Debug + Default>(
-        data: &[Vec<N>],
-        path: &Path,
-    ) -> MapIndex<N> {
+    fn load_map_index<N: MapIndexKey + Debug>(data: &[Vec<N>], path: &Path) -> MapIndex<N> {
         let mut index =
             MapIndex::<N>::new(open_db_with_existing_cf(path).unwrap(), FIELD_NAME, true);
         index.load_from_db().unwrap();
         for (idx, values) in data.iter().enumerate() {
-            let index_values: HashSet<N> = HashSet::from_iter(
-                index
-                    .get_values(idx as PointOffsetType)
-                    .unwrap()
-                    .iter()
-                    .cloned(),
-            );
+            let index_values: HashSet<N> =
+                HashSet::from_iter(index.get_values(idx as PointOffsetType).unwrap());
             let check_values: HashSet<N> = HashSet::from_iter(values.iter().cloned());
             assert_eq!(index_values, check_values);
         }
@@ -648,7 +1045,7 @@ mod tests {
 
     #[test]
     fn test_int_disk_map_index() {
-        let data = vec![
+        let data: Vec<Vec<IntPayloadType>> = vec![
             vec![1, 2, 3, 4, 5, 6],
             vec![1, 2, 3, 4, 5, 6],
             vec![13, 14, 15, 16, 17, 18],
@@ -662,7 +1059,7 @@ mod tests {
 
         // Ensure cardinality is non zero
         assert!(!index
-            .except_cardinality::<_, &_>(vec![].into_iter())
+            .except_cardinality(vec![].into_iter())
             .equals_min_exp_max(&CardinalityEstimation::exact(0)));
     }
 
@@ -670,26 +1067,26 @@ mod tests {
     fn test_string_disk_map_index() {
         let data = vec![
             vec![
-                String::from("AABB"),
-                String::from("UUFF"),
-                String::from("IIBB"),
+                SmolStr::from("AABB"),
+                SmolStr::from("UUFF"),
+                SmolStr::from("IIBB"),
             ],
             vec![
-                String::from("PPMM"),
-                String::from("QQXX"),
-                String::from("YYBB"),
+                SmolStr::from("PPMM"),
+                SmolStr::from("QQXX"),
+                SmolStr::from("YYBB"),
             ],
             vec![
-                String::from("FFMM"),
-                String::from("IICC"),
-                String::from("IIBB"),
+                SmolStr::from("FFMM"),
+                SmolStr::from("IICC"),
+                SmolStr::from("IIBB"),
             ],
             vec![
-                String::from("AABB"),
-                String::from("UUFF"),
-                String::from("IIBB"),
+                SmolStr::from("AABB"),
+                SmolStr::from("UUFF"),
+                SmolStr::from("IIBB"),
             ],
-            vec![String::from("PPGG")],
+            vec![SmolStr::from("PPGG")],
         ];
 
         let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
@@ -698,13 +1095,40 @@ mod tests {
 
         // Ensure cardinality is non zero
         assert!(!index
-            .except_cardinality::<str, &str>(vec![].into_iter())
+            .except_cardinality(vec![].into_iter())
             .equals_min_exp_max(&CardinalityEstimation::exact(0)));
     }
 
+    #[test]
+    fn test_uuid_disk_map_index() {
+        let uuids = [
+            "1ab4ba4c-7c2e-4e3c-9d2a-59a6b4d8c1f0",
+            "f3d1c7e2-0a4b-4c8d-8e6f-2b9a7c5d3e10",
+            "00000000-0000-0000-0000-000000000001",
+        ]
+        .map(|uuid| parse_uuid(uuid).unwrap());
+        let data = vec![
+            vec![uuids[0]],
+            vec![uuids[1], uuids[2]],
+            vec![],
+            vec![uuids[0], uuids[1], uuids[2]],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index(&data, temp_dir.path());
+        let index = load_map_index(&data, temp_dir.path());
+
+        assert_eq!(index.get_points_with_value_count(&uuids[0]), Some(2));
+        assert_eq!(
+            uuid_to_string(uuids[1]),
+            "f3d1c7e2-0a4b-4c8d-8e6f-2b9a7c5d3e10"
+        );
+        assert_eq!(parse_uuid("not a uuid"), None);
+    }
+
     #[test]
     fn test_empty_index() {
-        let data: Vec<Vec<String>> = vec![];
+        let data: Vec<Vec<SmolStr>> = vec![];
 
         let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
         save_map_index(&data, temp_dir.path());
@@ -712,7 +1136,181 @@ mod tests {
 
         // Ensure cardinality is zero
         assert!(index
-            .except_cardinality::<str, &str>(vec![].into_iter())
+            .except_cardinality(vec![].into_iter())
             .equals_min_exp_max(&CardinalityEstimation::exact(0)));
     }
+
+    #[test]
+    fn test_mmap_map_index() {
+        let data = vec![
+            vec![SmolStr::from("a"), SmolStr::from("b")],
+            vec![SmolStr::from("b")],
+            vec![],
+            vec![SmolStr::from("c"), SmolStr::from("a"), SmolStr::from("a")],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index(&data, temp_dir.path());
+        let mutable_index = load_map_index(&data, temp_dir.path());
+
+        let mmap_dir = temp_dir.path().join("mmap");
+        mutable_index.save_mmap(&mmap_dir).unwrap();
+        let mut mmap_index = load_map_index::<SmolStr>(&[], temp_dir.path())
+            .into_mmap(&mmap_dir)
+            .unwrap();
+        assert!(matches!(mmap_index, MapIndex::Mmap(_)));
+
+        for idx in 0..data.len() as PointOffsetType {
+            assert_eq!(
+                mmap_index.get_values(idx).unwrap().collect::<HashSet<_>>(),
+                mutable_index
+                    .get_values(idx)
+                    .unwrap()
+                    .collect::<HashSet<_>>(),
+            );
+            assert_eq!(
+                mmap_index.values_count(idx),
+                mutable_index.values_count(idx)
+            );
+        }
+        for value in ["a", "b", "c", "d"] {
+            assert_eq!(
+                mmap_index.get_iterator(value).collect::<Vec<_>>(),
+                mutable_index.get_iterator(value).collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(mmap_index.get_indexed_points(), 3);
+        assert_eq!(
+            mmap_index.get_values_count(),
+            mutable_index.get_values_count()
+        );
+        assert_eq!(mmap_index.get_unique_values_count(), 3);
+        assert_eq!(
+            mmap_index.iter_counts_per_value().collect::<HashSet<_>>(),
+            mutable_index
+                .iter_counts_per_value()
+                .collect::<HashSet<_>>(),
+        );
+
+        mmap_index.remove_point(3).unwrap();
+        assert_eq!(mmap_index.get_iterator("a").collect::<Vec<_>>(), vec![0]);
+        assert_eq!(mmap_index.get_points_with_value_count("c"), Some(0));
+        assert_eq!(mmap_index.get_indexed_points(), 2);
+        assert_eq!(mmap_index.get_values_count(), 3);
+        assert_eq!(mmap_index.get_unique_values_count(), 2);
+        assert!(mmap_index.values_is_empty(3));
+    }
+
+    #[test]
+    fn test_keyword_prefix_and_range_filter() {
+        let data: Vec<Vec<SmolStr>> = [
+            vec!["A100", "eu-west"],
+            vec!["A150"],
+            vec!["A200", "eu-north"],
+            vec![],
+            vec!["B100", "us-east"],
+            vec!["A1", "eu"],
+        ]
+        .into_iter()
+        .map(|values| values.into_iter().map(SmolStr::from).collect())
+        .collect();
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index(&data, temp_dir.path());
+        let mutable_index = load_map_index(&data, temp_dir.path());
+
+        let mut immutable_index = MapIndex::<SmolStr>::new(
+            open_db_with_existing_cf(temp_dir.path()).unwrap(),
+            FIELD_NAME,
+            false,
+        );
+        immutable_index.load_from_db().unwrap();
+
+        let mmap_dir = temp_dir.path().join("mmap");
+        mutable_index.save_mmap(&mmap_dir).unwrap();
+        let mmap_index = load_map_index::<SmolStr>(&[], temp_dir.path())
+            .into_mmap(&mmap_dir)
+            .unwrap();
+
+        let prefix =
+            |prefix: &str| FieldCondition::new_match(FIELD_NAME, Match::new_prefix(prefix));
+        let range = |range: KeywordRange| FieldCondition::new_keyword_range(FIELD_NAME, range);
+
+        let cases = [
+            (prefix("eu-"), vec![0, 2]),
+            (prefix("eu"), vec![0, 2, 5]),
+            (prefix("A1"), vec![0, 1, 5]),
+            (prefix("x"), vec![]),
+            (
+                range(KeywordRange {
+                    gte: Some("A100".to_string()),
+                    lt: Some("A200".to_string()),
+                    ..Default::default()
+                }),
+                vec![0, 1],
+            ),
+            (
+                range(KeywordRange {
+                    gt: Some("A100".to_string()),
+                    lte: Some("A200".to_string()),
+                    ..Default::default()
+                }),
+                vec![1, 2],
+            ),
+            (
+                range(KeywordRange {
+                    gte: Some("B".to_string()),
+                    ..Default::default()
+                }),
+                vec![0, 2, 4, 5],
+            ),
+            // inverted bounds select nothing
+            (
+                range(KeywordRange {
+                    gt: Some("B".to_string()),
+                    lt: Some("A".to_string()),
+                    ..Default::default()
+                }),
+                vec![],
+            ),
+        ];
+
+        for index in [&mutable_index, &immutable_index, &mmap_index] {
+            for (condition, expected) in &cases {
+                let points: HashSet<_> = index.filter(condition).unwrap().collect();
+                assert_eq!(points, expected.iter().copied().collect(), "{condition:?}");
+
+                let estimation = index.estimate_cardinality(condition).unwrap();
+                assert!(estimation.min <= expected.len(), "{condition:?}");
+                assert!(estimation.max >= expected.len(), "{condition:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_keywords_cardinality_limit() {
+        let num_points = 3 * KEYWORDS_CARDINALITY_LIMIT;
+        let data: Vec<Vec<SmolStr>> = (0..num_points)
+            .map(|idx| vec![SmolStr::from(format!("key{idx:05}"))])
+            .collect();
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index(&data, temp_dir.path());
+        let index = load_map_index(&data, temp_dir.path());
+
+        let prefix =
+            |prefix: &str| FieldCondition::new_match(FIELD_NAME, Match::new_prefix(prefix));
+
+        // Fewer keywords than the limit are all counted
+        let estimation = index.estimate_cardinality(&prefix("key0001")).unwrap();
+        assert_eq!(estimation.min, 1);
+        assert_eq!(estimation.max, 10);
+
+        // Walk over the keywords stops at the limit, the rest is bounded by the index size
+        let estimation = index.estimate_cardinality(&prefix("key")).unwrap();
+        assert_eq!(estimation.min, KEYWORDS_CARDINALITY_LIMIT);
+        assert_eq!(estimation.max, num_points);
+        assert!(estimation.min <= estimation.exp && estimation.exp <= estimation.max);
+        assert!(!estimation.primary_clauses.is_empty());
+    }
 }
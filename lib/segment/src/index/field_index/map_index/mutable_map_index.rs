@@ -1,7 +1,8 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use std::hash::Hash;
 use std::iter;
+use std::ops::Bound;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -9,12 +10,12 @@ use common::types::PointOffsetType;
 use parking_lot::RwLock;
 use rocksdb::DB;
 
-use super::MapIndex;
+use super::{MapIndex, MapIndexKey};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 
 pub struct MutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
-    pub(super) map: HashMap<N, BTreeSet<PointOffsetType>>,
+    pub(super) map: BTreeMap<N, BTreeSet<PointOffsetType>>,
     pub(super) point_to_values: Vec<Vec<N>>,
     /// Amount of point which have at least one indexed payload value
     pub(super) indexed_points: usize,
@@ -22,7 +23,7 @@ pub struct MutableMapIndex<N: Hash + Eq + Clone + Display + FromStr> {
     pub(super) db_wrapper: DatabaseColumnWrapper,
 }
 
-impl<N: Hash + Eq + Clone + Display + FromStr + Default> MutableMapIndex<N> {
+impl<N: MapIndexKey> MutableMapIndex<N> {
     pub fn new(db: Arc<RwLock<DB>>, field_name: &str) -> Self {
         let store_cf_name = MapIndex::<N>::storage_cf_name(field_name);
         let db_wrapper = DatabaseColumnWrapper::new(db, &store_cf_name);
@@ -77,7 +78,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MutableMapIndex<N> {
         self.values_count -= removed_values.len();
 
         for value in &removed_values {
-            if let Some(vals) = self.map.get_mut(value) {
+            if let Some(vals) = self.map.get_mut(value.borrow()) {
                 vals.remove(&idx);
             }
             let key = MapIndex::encode_db_record(value, idx);
@@ -136,7 +137,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MutableMapIndex<N> {
     where
         Q: ?Sized,
         N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Ord,
     {
         self.map.get(value).map(|p| p.len())
     }
@@ -145,7 +146,7 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MutableMapIndex<N> {
     where
         Q: ?Sized,
         N: std::borrow::Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Ord,
     {
         self.map
             .get(value)
@@ -156,4 +157,15 @@ impl<N: Hash + Eq + Clone + Display + FromStr + Default> MutableMapIndex<N> {
     pub fn get_values_iterator(&self) -> Box<dyn Iterator<Item = &N> + '_> {
         Box::new(self.map.keys())
     }
+
+    /// Values within the bounds, which have at least one point, in ascending order
+    pub fn get_values_range<'a>(
+        &'a self,
+        range: (Bound<&'a N::Referenced>, Bound<&'a N::Referenced>),
+    ) -> impl Iterator<Item = &'a N> + 'a {
+        self.map
+            .range::<N::Referenced, _>(range)
+            .filter(|(_value, points)| !points.is_empty())
+            .map(|(value, _points)| value)
+    }
 }
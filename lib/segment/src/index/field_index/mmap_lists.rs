@@ -0,0 +1,184 @@
+//! Memory mapped storage of the on-disk field indexes
+//!
+//! On-disk indexes are written once, from a fully built in-memory index, and are never extended.
+//! Points can only be removed from them, which is done in place.
+
+use std::path::{Path, PathBuf};
+
+use bitvec::prelude::{BitSlice, BitVec};
+use memory::mmap_ops::{open_write_mmap, transmute_to_u8_slice};
+
+use crate::common::error_logging::LogError;
+use crate::common::mmap_type::{MmapBitSlice, MmapSlice};
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+
+/// Write the values into a file, which can be opened with [`open_mmap_slice`]
+pub fn create_mmap_slice<T: Copy>(path: &Path, values: &[T]) -> OperationResult<()> {
+    std::fs::write(path, transmute_to_u8_slice(values))?;
+    Ok(())
+}
+
+pub fn open_mmap_slice<T: Copy>(path: &Path) -> OperationResult<MmapSlice<T>> {
+    let mmap = open_write_mmap(path).describe("Open mmap index file")?;
+    // Safety: the file is written by `create_mmap_slice` with values of the same type
+    let slice = unsafe { MmapSlice::try_from(mmap)? };
+    Ok(slice)
+}
+
+/// Write the flags into a file, which can be opened with [`open_mmap_bitslice`]
+pub fn create_mmap_bitslice(path: &Path, flags: &BitSlice) -> OperationResult<()> {
+    let flags: BitVec = flags.to_bitvec();
+    create_mmap_slice(path, flags.as_raw_slice())
+}
+
+pub fn open_mmap_bitslice(path: &Path) -> OperationResult<MmapBitSlice> {
+    let mmap = open_write_mmap(path).describe("Open mmap index flags")?;
+    Ok(MmapBitSlice::try_from(mmap, 0)?)
+}
+
+/// Lists of values of variable length, stored in two memory mapped files
+///
+/// Lists can be shrunk or emptied in place, but never extended.
+pub struct MmapLists<T: Copy + 'static> {
+    /// `[start, end)` range of each list in `values`
+    ranges: MmapSlice<[u32; 2]>,
+    values: MmapSlice<T>,
+    ranges_path: PathBuf,
+    values_path: PathBuf,
+}
+
+impl<T: Copy + 'static> MmapLists<T> {
+    fn ranges_path(dir: &Path, name: &str) -> PathBuf {
+        dir.join(format!("{name}_ranges.dat"))
+    }
+
+    fn values_path(dir: &Path, name: &str) -> PathBuf {
+        dir.join(format!("{name}_values.dat"))
+    }
+
+    pub fn create<L: AsRef<[T]>>(
+        dir: &Path,
+        name: &str,
+        lists: impl IntoIterator<Item = L>,
+    ) -> OperationResult<()> {
+        let mut ranges = vec![];
+        let mut values = vec![];
+        for list in lists {
+            let start = values.len() as u32;
+            values.extend_from_slice(list.as_ref());
+            ranges.push([start, values.len() as u32]);
+        }
+        create_mmap_slice(&Self::ranges_path(dir, name), &ranges)?;
+        create_mmap_slice(&Self::values_path(dir, name), &values)
+    }
+
+    pub fn open(dir: &Path, name: &str) -> OperationResult<Self> {
+        let ranges_path = Self::ranges_path(dir, name);
+        let values_path = Self::values_path(dir, name);
+        Ok(Self {
+            ranges: open_mmap_slice(&ranges_path)?,
+            values: open_mmap_slice(&values_path)?,
+            ranges_path,
+            values_path,
+        })
+    }
+
+    /// Number of lists, including the empty ones
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&[T]> {
+        let [start, end] = *self.ranges.get(idx)?;
+        Some(&self.values[start as usize..end as usize])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.iter_from(0)
+    }
+
+    /// Iterate over the lists, starting from the `start` one
+    pub fn iter_from(&self, start: usize) -> impl Iterator<Item = &[T]> + '_ {
+        self.ranges[start.min(self.len())..]
+            .iter()
+            .map(|&[start, end]| &self.values[start as usize..end as usize])
+    }
+
+    /// Index of the first list, for which the predicate is false
+    ///
+    /// Lists must be partitioned by the predicate, e.g. sorted for a "less than" one.
+    pub fn partition_point(&self, pred: impl Fn(&[T]) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.get(mid).map_or(false, &pred) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Empty the list, returns the values it contained
+    pub fn take(&mut self, idx: usize) -> Option<&[T]> {
+        let range = self.ranges.get_mut(idx)?;
+        let [start, end] = *range;
+        *range = [start, start];
+        Some(&self.values[start as usize..end as usize])
+    }
+
+    /// Remove the value at the position within the list, keeping the order of the other values
+    pub fn remove_at(&mut self, idx: usize, position: usize) {
+        let [start, end] = self.ranges[idx];
+        let list = &mut self.values[start as usize..end as usize];
+        list.copy_within(position + 1.., position);
+        self.ranges[idx] = [start, end - 1];
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        let ranges_flusher = self.ranges.flusher();
+        let values_flusher = self.values.flusher();
+        Box::new(move || {
+            ranges_flusher()?;
+            values_flusher()
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        vec![self.ranges_path.clone(), self.values_path.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    #[test]
+    fn test_mmap_lists() {
+        let dir = Builder::new().prefix("mmap_lists").tempdir().unwrap();
+        let lists = vec![vec![1, 2, 3], vec![], vec![4, 5]];
+        MmapLists::<u32>::create(dir.path(), "test", &lists).unwrap();
+
+        let mut mmap_lists = MmapLists::<u32>::open(dir.path(), "test").unwrap();
+        assert_eq!(mmap_lists.len(), 3);
+        assert_eq!(mmap_lists.iter().collect::<Vec<_>>(), lists);
+        assert_eq!(mmap_lists.iter_from(2).collect::<Vec<_>>(), lists[2..]);
+        assert_eq!(mmap_lists.partition_point(|list| list.len() > 2), 1);
+
+        mmap_lists.remove_at(0, 1);
+        assert_eq!(mmap_lists.get(0), Some([1, 3].as_slice()));
+        assert_eq!(mmap_lists.take(2), Some([4, 5].as_slice()));
+        assert_eq!(mmap_lists.get(2), Some([].as_slice()));
+        assert_eq!(mmap_lists.get(3), None);
+        mmap_lists.flusher()().unwrap();
+
+        // Changes are persisted
+        let mmap_lists = MmapLists::<u32>::open(dir.path(), "test").unwrap();
+        assert_eq!(mmap_lists.get(0), Some([1, 3].as_slice()));
+        assert!(mmap_lists.get(2).unwrap().is_empty());
+    }
+}
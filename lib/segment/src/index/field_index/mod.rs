@@ -11,6 +11,7 @@ pub mod geo_index;
 mod histogram;
 pub mod index_selector;
 pub mod map_index;
+pub(crate) mod mmap_lists;
 pub mod numeric_index;
 mod stat_tools;
 
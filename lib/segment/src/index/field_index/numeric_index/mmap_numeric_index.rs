@@ -0,0 +1,258 @@
+use std::fs::create_dir_all;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use bitvec::vec::BitVec;
+use common::types::PointOffsetType;
+
+use super::immutable_numeric_index::NumericIndexKey;
+use super::mutable_numeric_index::MutableNumericIndex;
+use super::{Encodable, HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION};
+use crate::common::mmap_type::{MmapBitSlice, MmapSlice};
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::index::field_index::histogram::{Histogram, Numericable, Point};
+use crate::index::field_index::mmap_lists::{
+    create_mmap_bitslice, create_mmap_slice, open_mmap_bitslice, open_mmap_slice, MmapLists,
+};
+
+const PAIRS_VALUES_PATH: &str = "pairs_values.dat";
+const PAIRS_POINTS_PATH: &str = "pairs_points.dat";
+const DELETED_PATH: &str = "deleted.dat";
+const POINT_TO_VALUES: &str = "point_to_values";
+
+/// Sorted `(value, point)` pairs of the index
+struct MmapPairs<T: Encodable + Numericable> {
+    values: MmapSlice<T>,
+    points: MmapSlice<PointOffsetType>,
+    /// Pairs of the removed points
+    deleted: MmapBitSlice,
+    deleted_count: usize,
+}
+
+impl<T: Encodable + Numericable> MmapPairs<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn key(&self, position: usize) -> NumericIndexKey<T> {
+        NumericIndexKey::new(self.values[position], self.points[position])
+    }
+
+    fn is_deleted(&self, position: usize) -> bool {
+        self.deleted.get(position).map_or(false, |deleted| *deleted)
+    }
+
+    /// Number of leading pairs among the first `end` ones, which satisfy the predicate
+    fn partition_point(&self, end: usize, pred: impl Fn(&NumericIndexKey<T>) -> bool) -> usize {
+        let (mut low, mut high) = (0, end);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&self.key(mid)) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    fn values_range(
+        &self,
+        start_bound: Bound<NumericIndexKey<T>>,
+        end_bound: Bound<NumericIndexKey<T>>,
+    ) -> impl DoubleEndedIterator<Item = NumericIndexKey<T>> + '_ {
+        let len = self.len();
+        let start = match start_bound {
+            Bound::Included(key) => self.partition_point(len, |pair| pair < &key),
+            Bound::Excluded(key) => self.partition_point(len, |pair| pair <= &key),
+            Bound::Unbounded => 0,
+        };
+        let end = match end_bound {
+            Bound::Included(key) => self.partition_point(len, |pair| pair <= &key),
+            Bound::Excluded(key) => self.partition_point(len, |pair| pair < &key),
+            Bound::Unbounded => len,
+        };
+        (start..end.max(start))
+            .filter(|&position| !self.is_deleted(position))
+            .map(|position| self.key(position))
+    }
+
+    /// Closest pair before the point, among the first `end` pairs
+    fn left_neighbor(&self, point: &Point<T>, end: usize) -> Option<Point<T>> {
+        let key: NumericIndexKey<T> = point.clone().into();
+        let position = self.partition_point(end, |pair| pair < &key);
+        (0..position)
+            .rev()
+            .find(|&position| !self.is_deleted(position))
+            .map(|position| self.key(position).into())
+    }
+
+    /// Closest pair after the point, among the first `end` pairs
+    fn right_neighbor(&self, point: &Point<T>, end: usize) -> Option<Point<T>> {
+        let key: NumericIndexKey<T> = point.clone().into();
+        let position = self.partition_point(end, |pair| pair <= &key);
+        (position..end)
+            .find(|&position| !self.is_deleted(position))
+            .map(|position| self.key(position).into())
+    }
+}
+
+pub struct MmapNumericIndex<T: Encodable + Numericable> {
+    dir: PathBuf,
+    pairs: MmapPairs<T>,
+    point_to_values: MmapLists<T>,
+    pub(super) histogram: Histogram<T>,
+    pub(super) points_count: usize,
+    pub(super) max_values_per_point: usize,
+}
+
+impl<T: Encodable + Numericable> MmapNumericIndex<T> {
+    pub fn create(dir: &Path, index: &MutableNumericIndex<T>) -> OperationResult<()> {
+        create_dir_all(dir)?;
+
+        // Keys are sorted by the encoded value first, and by the point then
+        let (points, values): (Vec<_>, Vec<_>) =
+            index.map.keys().map(|key| T::decode_key(key)).unzip();
+        create_mmap_slice(&dir.join(PAIRS_VALUES_PATH), &values)?;
+        create_mmap_slice(&dir.join(PAIRS_POINTS_PATH), &points)?;
+        create_mmap_bitslice(
+            &dir.join(DELETED_PATH),
+            &BitVec::repeat(false, values.len()),
+        )?;
+        MmapLists::<T>::create(dir, POINT_TO_VALUES, &index.point_to_values)
+    }
+
+    pub fn open(dir: &Path) -> OperationResult<Self> {
+        let deleted = open_mmap_bitslice(&dir.join(DELETED_PATH))?;
+        let deleted_count = deleted.count_ones();
+        let point_to_values = MmapLists::<T>::open(dir, POINT_TO_VALUES)?;
+
+        let points_count = point_to_values
+            .iter()
+            .filter(|values| !values.is_empty())
+            .count();
+        let max_values_per_point = point_to_values.iter().map(<[T]>::len).max();
+
+        let mut index = Self {
+            dir: dir.to_owned(),
+            pairs: MmapPairs {
+                values: open_mmap_slice(&dir.join(PAIRS_VALUES_PATH))?,
+                points: open_mmap_slice(&dir.join(PAIRS_POINTS_PATH))?,
+                deleted,
+                deleted_count,
+            },
+            point_to_values,
+            histogram: Histogram::new(HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION),
+            points_count,
+            max_values_per_point: max_values_per_point.unwrap_or_default(),
+        };
+        index.build_histogram();
+        Ok(index)
+    }
+
+    /// Histogram is not stored, it is built the same way as the in-memory index does on load:
+    /// by inserting pairs in sorted order.
+    fn build_histogram(&mut self) {
+        let pairs = &self.pairs;
+        for position in 0..pairs.len() {
+            if pairs.is_deleted(position) {
+                continue;
+            }
+            // Only the pairs which are already inserted can be neighbors
+            let end = position + 1;
+            self.histogram.insert(
+                pairs.key(position).into(),
+                |point| pairs.left_neighbor(point, end),
+                |point| pairs.right_neighbor(point, end),
+            );
+        }
+    }
+
+    pub(super) fn get_values(&self, idx: PointOffsetType) -> Option<&[T]> {
+        self.point_to_values.get(idx as usize)
+    }
+
+    pub(super) fn get_values_count(&self) -> usize {
+        self.pairs.len() - self.pairs.deleted_count
+    }
+
+    pub(super) fn values_range(
+        &self,
+        start_bound: Bound<NumericIndexKey<T>>,
+        end_bound: Bound<NumericIndexKey<T>>,
+    ) -> impl Iterator<Item = PointOffsetType> + '_ {
+        self.pairs
+            .values_range(start_bound, end_bound)
+            .map(|key| Point::from(key).idx as PointOffsetType)
+    }
+
+    pub(super) fn orderable_values_range(
+        &self,
+        start_bound: Bound<NumericIndexKey<T>>,
+        end_bound: Bound<NumericIndexKey<T>>,
+    ) -> impl DoubleEndedIterator<Item = (T, PointOffsetType)> + '_ {
+        self.pairs.values_range(start_bound, end_bound).map(|key| {
+            let Point { val, idx } = key.into();
+            (val, idx as PointOffsetType)
+        })
+    }
+
+    pub(super) fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
+        let Some(removed_values) = self.point_to_values.take(idx as usize) else {
+            return Ok(());
+        };
+
+        if !removed_values.is_empty() {
+            self.points_count -= 1;
+        }
+
+        let pairs = &mut self.pairs;
+        for &value in removed_values {
+            let key = NumericIndexKey::new(value, idx);
+            let position = pairs.partition_point(pairs.len(), |pair| pair < &key);
+            // Same value may occur several times, the pair is removed on the first occurrence
+            if position == pairs.len() || pairs.key(position) != key || pairs.is_deleted(position) {
+                continue;
+            }
+            pairs.deleted.set(position, true);
+            pairs.deleted_count += 1;
+
+            let end = pairs.len();
+            let pairs = &*pairs;
+            self.histogram.remove(
+                &Point::from(key),
+                |point| pairs.left_neighbor(point, end),
+                |point| pairs.right_neighbor(point, end),
+            );
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn flusher(&self) -> Flusher {
+        let deleted_flusher = self.pairs.deleted.flusher();
+        let point_to_values_flusher = self.point_to_values.flusher();
+        Box::new(move || {
+            deleted_flusher()?;
+            point_to_values_flusher()
+        })
+    }
+
+    pub(super) fn files(&self) -> Vec<PathBuf> {
+        let mut files = vec![
+            self.dir.join(PAIRS_VALUES_PATH),
+            self.dir.join(PAIRS_POINTS_PATH),
+            self.dir.join(DELETED_PATH),
+        ];
+        files.extend(self.point_to_values.files());
+        files
+    }
+
+    pub(super) fn clear(self) -> OperationResult<()> {
+        let dir = self.dir.clone();
+        drop(self);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
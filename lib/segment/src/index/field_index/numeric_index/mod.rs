@@ -1,4 +1,5 @@
 mod immutable_numeric_index;
+mod mmap_numeric_index;
 mod mutable_numeric_index;
 
 #[cfg(test)]
@@ -7,9 +8,11 @@ mod tests;
 use std::cmp::{max, min};
 use std::ops::Bound;
 use std::ops::Bound::{Excluded, Included, Unbounded};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
+use mmap_numeric_index::MmapNumericIndex;
 use mutable_numeric_index::MutableNumericIndex;
 use parking_lot::RwLock;
 use rocksdb::DB;
@@ -18,7 +21,6 @@ use serde_json::Value;
 use self::immutable_numeric_index::{ImmutableNumericIndex, NumericIndexKey};
 use super::utils::check_boundaries;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::index::field_index::histogram::{Histogram, Numericable};
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
@@ -30,12 +32,15 @@ use crate::index::key_encoding::{
     encode_i64_key_ascending,
 };
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, FloatPayloadType, IntPayloadType, PayloadKeyType, Range};
+use crate::types::{
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, PayloadKeyType, Range,
+    RangeInterface,
+};
 
 const HISTOGRAM_MAX_BUCKET_SIZE: usize = 10_000;
 const HISTOGRAM_PRECISION: f64 = 0.01;
 
-pub trait Encodable: Copy {
+pub trait Encodable: Copy + 'static {
     fn encode_key(&self, id: PointOffsetType) -> Vec<u8>;
 
     fn decode_key(key: &[u8]) -> (PointOffsetType, Self);
@@ -83,6 +88,7 @@ impl Encodable for FloatPayloadType {
 pub enum NumericIndex<T: Encodable + Numericable> {
     Mutable(MutableNumericIndex<T>),
     Immutable(ImmutableNumericIndex<T>),
+    Mmap(MmapNumericIndex<T>),
 }
 
 impl<T: Encodable + Numericable> NumericIndex<T> {
@@ -94,17 +100,11 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         }
     }
 
-    fn get_db_wrapper(&self) -> &DatabaseColumnWrapper {
-        match self {
-            NumericIndex::Mutable(index) => index.get_db_wrapper(),
-            NumericIndex::Immutable(index) => index.get_db_wrapper(),
-        }
-    }
-
     fn get_histogram(&self) -> &Histogram<T> {
         match self {
             NumericIndex::Mutable(index) => &index.histogram,
             NumericIndex::Immutable(index) => &index.histogram,
+            NumericIndex::Mmap(index) => &index.histogram,
         }
     }
 
@@ -112,6 +112,7 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         match self {
             NumericIndex::Mutable(index) => index.points_count,
             NumericIndex::Immutable(index) => index.points_count,
+            NumericIndex::Mmap(index) => index.points_count,
         }
     }
 
@@ -119,6 +120,7 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         match self {
             NumericIndex::Mutable(index) => index.get_values_count(),
             NumericIndex::Immutable(index) => index.get_values_count(),
+            NumericIndex::Mmap(index) => index.get_values_count(),
         }
     }
 
@@ -127,24 +129,61 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
     }
 
     pub fn recreate(&self) -> OperationResult<()> {
-        self.get_db_wrapper().recreate_column_family()
+        match self {
+            NumericIndex::Mutable(index) => index.get_db_wrapper().recreate_column_family(),
+            NumericIndex::Immutable(index) => index.get_db_wrapper().recreate_column_family(),
+            NumericIndex::Mmap(_) => Ok(()),
+        }
     }
 
     pub fn load(&mut self) -> OperationResult<bool> {
         match self {
             NumericIndex::Mutable(index) => index.load(),
             NumericIndex::Immutable(index) => index.load(),
+            NumericIndex::Mmap(_) => Ok(true),
         }
     }
 
     pub fn flusher(&self) -> Flusher {
-        self.get_db_wrapper().flusher()
+        match self {
+            NumericIndex::Mutable(index) => index.get_db_wrapper().flusher(),
+            NumericIndex::Immutable(index) => index.get_db_wrapper().flusher(),
+            NumericIndex::Mmap(index) => index.flusher(),
+        }
+    }
+
+    /// Write the index into memory mapped files in the directory, only a mutable index can be written
+    pub fn save_mmap(&self, dir: &Path) -> OperationResult<()> {
+        match self {
+            NumericIndex::Mutable(index) => MmapNumericIndex::create(dir, index),
+            NumericIndex::Immutable(_) | NumericIndex::Mmap(_) => Err(
+                OperationError::service_error("Only mutable numeric index can be saved on disk"),
+            ),
+        }
+    }
+
+    /// Replace the index with the memory mapped one, saved in the directory with [`Self::save_mmap`]
+    pub fn into_mmap(self, dir: &Path) -> OperationResult<Self> {
+        match &self {
+            NumericIndex::Mutable(index) => index.get_db_wrapper().remove_column_family()?,
+            NumericIndex::Immutable(index) => index.get_db_wrapper().remove_column_family()?,
+            NumericIndex::Mmap(_) => {}
+        }
+        Ok(NumericIndex::Mmap(MmapNumericIndex::open(dir)?))
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            NumericIndex::Mutable(_) | NumericIndex::Immutable(_) => vec![],
+            NumericIndex::Mmap(index) => index.files(),
+        }
     }
 
     pub fn remove_point(&mut self, idx: PointOffsetType) -> OperationResult<()> {
         match self {
             NumericIndex::Mutable(index) => index.remove_point(idx),
             NumericIndex::Immutable(index) => index.remove_point(idx),
+            NumericIndex::Mmap(index) => index.remove_point(idx),
         }
     }
 
@@ -152,6 +191,7 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         match self {
             NumericIndex::Mutable(index) => index.get_values(idx),
             NumericIndex::Immutable(index) => index.get_values(idx),
+            NumericIndex::Mmap(index) => index.get_values(idx),
         }
     }
 
@@ -164,6 +204,7 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         match self {
             NumericIndex::Mutable(index) => index.max_values_per_point,
             NumericIndex::Immutable(index) => index.max_values_per_point,
+            NumericIndex::Mmap(index) => index.max_values_per_point,
         }
     }
 
@@ -228,6 +269,96 @@ impl<T: Encodable + Numericable> NumericIndex<T> {
         }
     }
 
+    fn range_bounds(range: &Range) -> (Bound<NumericIndexKey<T>>, Bound<NumericIndexKey<T>>) {
+        let start_bound = match range {
+            Range { gt: Some(gt), .. } => {
+                let v: T = T::from_f64(*gt);
+                Excluded(NumericIndexKey::new(v, PointOffsetType::MAX))
+            }
+            Range { gte: Some(gte), .. } => {
+                let v: T = T::from_f64(*gte);
+                Included(NumericIndexKey::new(v, PointOffsetType::MIN))
+            }
+            _ => Unbounded,
+        };
+
+        let end_bound = match range {
+            Range { lt: Some(lt), .. } => {
+                let v: T = T::from_f64(*lt);
+                Excluded(NumericIndexKey::new(v, PointOffsetType::MIN))
+            }
+            Range { lte: Some(lte), .. } => {
+                let v: T = T::from_f64(*lte);
+                Included(NumericIndexKey::new(v, PointOffsetType::MAX))
+            }
+            _ => Unbounded,
+        };
+
+        (start_bound, end_bound)
+    }
+
+    /// Iterate over `(value, point)` pairs within the range, ordered by value.
+    /// Points with multiple values are yielded once per value.
+    pub fn stream_range(
+        &self,
+        range: &Range,
+    ) -> Box<dyn DoubleEndedIterator<Item = (T, PointOffsetType)> + '_> {
+        let (start_bound, end_bound) = Self::range_bounds(range);
+        self.stream_keys_range(start_bound, end_bound)
+    }
+
+    /// Iterate over `(value, point)` pairs with values within the bounds, ordered by value.
+    /// Unlike [`NumericIndex::stream_range`], bounds are not converted from floats.
+    pub fn stream_values_range(
+        &self,
+        start: Bound<T>,
+        end: Bound<T>,
+    ) -> Box<dyn DoubleEndedIterator<Item = (T, PointOffsetType)> + '_> {
+        let start_bound = match start {
+            Included(v) => Included(NumericIndexKey::new(v, PointOffsetType::MIN)),
+            Excluded(v) => Excluded(NumericIndexKey::new(v, PointOffsetType::MAX)),
+            Unbounded => Unbounded,
+        };
+        let end_bound = match end {
+            Included(v) => Included(NumericIndexKey::new(v, PointOffsetType::MAX)),
+            Excluded(v) => Excluded(NumericIndexKey::new(v, PointOffsetType::MIN)),
+            Unbounded => Unbounded,
+        };
+        self.stream_keys_range(start_bound, end_bound)
+    }
+
+    fn stream_keys_range(
+        &self,
+        start_bound: Bound<NumericIndexKey<T>>,
+        end_bound: Bound<NumericIndexKey<T>>,
+    ) -> Box<dyn DoubleEndedIterator<Item = (T, PointOffsetType)> + '_> {
+        if !check_boundaries(&start_bound, &end_bound) {
+            return Box::new(std::iter::empty());
+        }
+
+        match self {
+            NumericIndex::Mutable(index) => {
+                let start_bound = match start_bound {
+                    Included(k) => Included(k.encode()),
+                    Excluded(k) => Excluded(k.encode()),
+                    Unbounded => Unbounded,
+                };
+                let end_bound = match end_bound {
+                    Included(k) => Included(k.encode()),
+                    Excluded(k) => Excluded(k.encode()),
+                    Unbounded => Unbounded,
+                };
+                Box::new(index.orderable_values_range(start_bound, end_bound))
+            }
+            NumericIndex::Immutable(index) => {
+                Box::new(index.orderable_values_range(start_bound, end_bound))
+            }
+            NumericIndex::Mmap(index) => {
+                Box::new(index.orderable_values_range(start_bound, end_bound))
+            }
+        }
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
@@ -258,7 +389,11 @@ impl<T: Encodable + Numericable> PayloadFieldIndex for NumericIndex<T> {
     }
 
     fn clear(self) -> OperationResult<()> {
-        self.get_db_wrapper().recreate_column_family()
+        match self {
+            NumericIndex::Mutable(index) => index.get_db_wrapper().recreate_column_family(),
+            NumericIndex::Immutable(index) => index.get_db_wrapper().recreate_column_family(),
+            NumericIndex::Mmap(index) => index.clear(),
+        }
     }
 
     fn flusher(&self) -> Flusher {
@@ -272,31 +407,10 @@ impl<T: Encodable + Numericable> PayloadFieldIndex for NumericIndex<T> {
         let cond_range = condition
             .range
             .as_ref()
+            .and_then(RangeInterface::as_numeric_range)
             .ok_or_else(|| OperationError::service_error("failed to get condition range"))?;
 
-        let start_bound = match cond_range {
-            Range { gt: Some(gt), .. } => {
-                let v: T = T::from_f64(*gt);
-                Excluded(NumericIndexKey::new(v, PointOffsetType::MAX))
-            }
-            Range { gte: Some(gte), .. } => {
-                let v: T = T::from_f64(*gte);
-                Included(NumericIndexKey::new(v, PointOffsetType::MIN))
-            }
-            _ => Unbounded,
-        };
-
-        let end_bound = match cond_range {
-            Range { lt: Some(lt), .. } => {
-                let v: T = T::from_f64(*lt);
-                Excluded(NumericIndexKey::new(v, PointOffsetType::MIN))
-            }
-            Range { lte: Some(lte), .. } => {
-                let v: T = T::from_f64(*lte);
-                Included(NumericIndexKey::new(v, PointOffsetType::MAX))
-            }
-            _ => Unbounded,
-        };
+        let (start_bound, end_bound) = Self::range_bounds(&cond_range);
 
         // map.range
         // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
@@ -319,6 +433,7 @@ impl<T: Encodable + Numericable> PayloadFieldIndex for NumericIndex<T> {
                 Box::new(index.values_range(start_bound, end_bound))
             }
             NumericIndex::Immutable(index) => Box::new(index.values_range(start_bound, end_bound)),
+            NumericIndex::Mmap(index) => Box::new(index.values_range(start_bound, end_bound)),
         })
     }
 
@@ -329,8 +444,9 @@ impl<T: Encodable + Numericable> PayloadFieldIndex for NumericIndex<T> {
         condition
             .range
             .as_ref()
+            .and_then(RangeInterface::as_numeric_range)
             .map(|range| {
-                let mut cardinality = self.range_cardinality(range);
+                let mut cardinality = self.range_cardinality(&range);
                 cardinality
                     .primary_clauses
                     .push(PrimaryCondition::Condition(condition.clone()));
@@ -418,9 +534,9 @@ impl ValueIndexer<IntPayloadType> for NumericIndex<IntPayloadType> {
     ) -> OperationResult<()> {
         match self {
             NumericIndex::Mutable(index) => index.add_many_to_list(id, values),
-            NumericIndex::Immutable(_) => Err(OperationError::service_error(
-                "Can't add values to immutable numeric index",
-            )),
+            NumericIndex::Immutable(_) | NumericIndex::Mmap(_) => Err(
+                OperationError::service_error("Can't add values to immutable numeric index"),
+            ),
         }
     }
 
@@ -444,9 +560,9 @@ impl ValueIndexer<FloatPayloadType> for NumericIndex<FloatPayloadType> {
     ) -> OperationResult<()> {
         match self {
             NumericIndex::Mutable(index) => index.add_many_to_list(id, values),
-            NumericIndex::Immutable(_) => Err(OperationError::service_error(
-                "Can't add values to immutable numeric index",
-            )),
+            NumericIndex::Immutable(_) | NumericIndex::Mmap(_) => Err(
+                OperationError::service_error("Can't add values to immutable numeric index"),
+            ),
         }
     }
 
@@ -461,3 +577,25 @@ impl ValueIndexer<FloatPayloadType> for NumericIndex<FloatPayloadType> {
         NumericIndex::remove_point(self, id)
     }
 }
+
+impl ValueIndexer<DateTimePayloadType> for NumericIndex<IntPayloadType> {
+    fn add_many(
+        &mut self,
+        id: PointOffsetType,
+        values: Vec<DateTimePayloadType>,
+    ) -> OperationResult<()> {
+        let timestamps = values.iter().map(DateTimePayloadType::timestamp).collect();
+        ValueIndexer::<IntPayloadType>::add_many(self, id, timestamps)
+    }
+
+    fn get_value(&self, value: &Value) -> Option<DateTimePayloadType> {
+        if let Value::String(datetime) = value {
+            return datetime.parse().ok();
+        }
+        None
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        NumericIndex::remove_point(self, id)
+    }
+}
@@ -1,6 +1,8 @@
 use itertools::Itertools;
+use parking_lot::RwLock;
 use rand::prelude::StdRng;
 use rand::{Rng, SeedableRng};
+use rocksdb::DB;
 use rstest::rstest;
 use tempfile::{Builder, TempDir};
 
@@ -21,6 +23,14 @@ fn get_index() -> (TempDir, NumericIndex<f64>) {
     (temp_dir, index)
 }
 
+fn get_db(index: &NumericIndex<f64>) -> Arc<RwLock<DB>> {
+    match index {
+        NumericIndex::Mutable(index) => index.get_db_wrapper().database.clone(),
+        NumericIndex::Immutable(index) => index.get_db_wrapper().database.clone(),
+        NumericIndex::Mmap(_) => unreachable!("mmap index is not stored in RocksDB"),
+    }
+}
+
 fn random_index(
     num_points: usize,
     values_per_point: usize,
@@ -35,7 +45,7 @@ fn random_index(
             NumericIndex::Mutable(index) => index
                 .add_many_to_list(i as PointOffsetType, values)
                 .unwrap(),
-            NumericIndex::Immutable(_) => unreachable!("index is mutable"),
+            _ => unreachable!("index is mutable"),
         }
     }
 
@@ -43,7 +53,7 @@ fn random_index(
 
     // if immutable, we have to reload the index
     if immutable {
-        let db_ref = index.get_db_wrapper().database.clone();
+        let db_ref = get_db(&index);
         let mut new_index: NumericIndex<f64> = NumericIndex::new(db_ref, COLUMN_NAME, false);
         new_index.load().unwrap();
         (temp_dir, new_index)
@@ -213,14 +223,14 @@ fn test_payload_blocks_small(#[case] immutable: bool) {
             NumericIndex::Mutable(index) => index
                 .add_many_to_list(idx as PointOffsetType + 1, values)
                 .unwrap(),
-            NumericIndex::Immutable(_) => unreachable!("index is mutable"),
+            _ => unreachable!("index is mutable"),
         });
 
     index.flusher()().unwrap();
 
     // if immutable, we have to reload the index
     let index = if immutable {
-        let db_ref = index.get_db_wrapper().database.clone();
+        let db_ref = get_db(&index);
         let mut new_index: NumericIndex<f64> = NumericIndex::new(db_ref, COLUMN_NAME, false);
         new_index.load().unwrap();
         new_index
@@ -259,12 +269,12 @@ fn test_numeric_index_load_from_disk(#[case] immutable: bool) {
             NumericIndex::Mutable(index) => index
                 .add_many_to_list(idx as PointOffsetType + 1, values)
                 .unwrap(),
-            NumericIndex::Immutable(_) => unreachable!("index is mutable"),
+            _ => unreachable!("index is mutable"),
         });
 
     index.flusher()().unwrap();
 
-    let db_ref = index.get_db_wrapper().database.clone();
+    let db_ref = get_db(&index);
     let mut new_index: NumericIndex<f64> = NumericIndex::new(db_ref, COLUMN_NAME, !immutable);
     new_index.load().unwrap();
 
@@ -305,14 +315,14 @@ fn test_numeric_index(#[case] immutable: bool) {
             NumericIndex::Mutable(index) => index
                 .add_many_to_list(idx as PointOffsetType + 1, values)
                 .unwrap(),
-            NumericIndex::Immutable(_) => unreachable!("index is mutable"),
+            _ => unreachable!("index is mutable"),
         });
 
     index.flusher()().unwrap();
 
     // if immutable, we have to reload the index
     let index = if immutable {
-        let db_ref = index.get_db_wrapper().database.clone();
+        let db_ref = get_db(&index);
         let mut new_index: NumericIndex<f64> = NumericIndex::new(db_ref, COLUMN_NAME, false);
         new_index.load().unwrap();
         new_index
@@ -384,11 +394,13 @@ fn test_cond<T: Encodable + Numericable + PartialOrd + Clone>(
     let condition = FieldCondition {
         key: "".to_string(),
         r#match: None,
-        range: Some(rng),
+        range: Some(rng.into()),
         geo_bounding_box: None,
         geo_radius: None,
         values_count: None,
         geo_polygon: None,
+        geo_intersects: None,
+        geo_within: None,
     };
 
     let offsets = index.filter(&condition).unwrap().collect_vec();
@@ -423,3 +435,73 @@ fn test_empty_cardinality(#[case] immutable: bool) {
         },
     );
 }
+
+#[test]
+fn test_mmap_numeric_index() {
+    let (temp_dir, index) = random_index(1000, 2, false);
+
+    let mmap_dir = temp_dir.path().join("mmap");
+    index.save_mmap(&mmap_dir).unwrap();
+    let db_ref = get_db(&index);
+    let mut mmap_index: NumericIndex<f64> = NumericIndex::new(db_ref, COLUMN_NAME, true)
+        .into_mmap(&mmap_dir)
+        .unwrap();
+    assert!(matches!(mmap_index, NumericIndex::Mmap(_)));
+
+    assert_eq!(mmap_index.get_points_count(), index.get_points_count());
+    assert_eq!(mmap_index.get_values_count(), index.get_values_count());
+    assert_eq!(
+        mmap_index.max_values_per_point(),
+        index.max_values_per_point()
+    );
+    for idx in 0..1000 {
+        assert_eq!(mmap_index.get_values(idx), index.get_values(idx));
+    }
+
+    let queries = [
+        Range {
+            lt: Some(20.0),
+            gt: None,
+            gte: Some(10.0),
+            lte: None,
+        },
+        Range {
+            lt: None,
+            gt: Some(99.0),
+            gte: None,
+            lte: None,
+        },
+    ];
+    for query in queries {
+        let condition = FieldCondition::new_range("".to_string(), query.clone());
+        assert_eq!(
+            mmap_index.filter(&condition).unwrap().collect_vec(),
+            index.filter(&condition).unwrap().collect_vec(),
+        );
+        // Histogram is rebuilt on load, estimations must stay consistent with the filtered points
+        cardinality_request(&mmap_index, query);
+    }
+
+    let removed_values = mmap_index.get_values(10).unwrap().to_vec();
+    mmap_index.remove_point(10).unwrap();
+    mmap_index.flusher()().unwrap();
+    for value in removed_values {
+        let condition = FieldCondition::new_range(
+            "".to_string(),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(value),
+                lte: Some(value),
+            },
+        );
+        assert!(!mmap_index.filter(&condition).unwrap().contains(&10));
+    }
+    assert_eq!(mmap_index.get_points_count(), 999);
+    assert_eq!(mmap_index.get_values_count(), 1998);
+
+    // Removal is persisted
+    let mmap_index = NumericIndex::<f64>::Mmap(MmapNumericIndex::open(&mmap_dir).unwrap());
+    assert!(mmap_index.get_values(10).unwrap().is_empty());
+    assert_eq!(mmap_index.get_values_count(), 1998);
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use common::types::PointOffsetType;
 
 use crate::index::visited_pool::VisitedListHandle;
@@ -16,3 +18,23 @@ impl<'a> FilterContext for BuildConditionChecker<'a> {
         self.filter_list.check(point_id)
     }
 }
+
+/// Matches points of a window of adjacent range buckets.
+///
+/// Points of each bucket are marked in `filter_list` during its own iteration,
+/// so the window is a range of iterations and buckets are not marked again for every window.
+pub struct RangeBucketConditionChecker<'a> {
+    pub filter_list: &'a VisitedListHandle<'a>,
+    pub iterations: RangeInclusive<usize>,
+    pub current_point: PointOffsetType,
+}
+
+impl<'a> FilterContext for RangeBucketConditionChecker<'a> {
+    fn check(&self, point_id: PointOffsetType) -> bool {
+        if point_id == self.current_point {
+            return false; // Do not match current point while inserting it (second time)
+        }
+        self.iterations
+            .contains(&self.filter_list.get_visit_iteration(point_id))
+    }
+}
@@ -27,6 +27,9 @@ pub struct HnswGraphConfig {
     pub payload_m: Option<usize>,
     #[serde(default)]
     pub payload_m0: Option<usize>,
+    /// Build additional links within wide ranges of numeric payload fields
+    #[serde(default)]
+    pub range_links: bool,
     #[serde(default)]
     pub indexed_vector_count: Option<usize>,
 }
@@ -38,6 +41,7 @@ impl HnswGraphConfig {
         full_scan_threshold: usize,
         max_indexing_threads: usize,
         payload_m: Option<usize>,
+        range_links: bool,
         indexed_vector_count: usize,
     ) -> Self {
         HnswGraphConfig {
@@ -49,6 +53,7 @@ impl HnswGraphConfig {
             max_indexing_threads,
             payload_m,
             payload_m0: payload_m.map(|v| v * 2),
+            range_links,
             indexed_vector_count: Some(indexed_vector_count),
         }
     }
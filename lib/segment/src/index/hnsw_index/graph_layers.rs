@@ -24,6 +24,7 @@ pub type LayersContainer = Vec<LinkContainer>;
 
 pub const HNSW_GRAPH_FILE: &str = "graph.bin";
 pub const HNSW_LINKS_FILE: &str = "links.bin";
+pub const HNSW_MAIN_LINKS_FILE: &str = "main_links.bin";
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GraphLayersBackwardCompatibility {
@@ -230,6 +231,10 @@ impl<TGraphLinks: GraphLinks> GraphLayers<TGraphLinks> {
         path.join(HNSW_LINKS_FILE)
     }
 
+    pub fn get_main_links_path(path: &Path) -> PathBuf {
+        path.join(HNSW_MAIN_LINKS_FILE)
+    }
+
     pub fn num_points(&self) -> usize {
         self.links.num_points()
     }
@@ -451,7 +456,7 @@ mod tests {
 
         let top = 5;
         let query = random_vector(&mut rng, dim);
-        let processed_query = M::preprocess(query.clone());
+        let processed_query = <M as Metric>::preprocess(query.clone());
         let mut reference_top = FixedLengthPriorityQueue::new(top);
         for idx in 0..vector_holder.vectors.len() as PointOffsetType {
             let vec = &vector_holder.vectors.get(idx);
@@ -195,6 +195,86 @@ impl GraphLayersBuilder {
         self.links_layers.len()
     }
 
+    /// Number of links of each point on each of its levels, ordered by points and then by levels
+    pub fn links_counts(&self) -> Vec<u32> {
+        self.links_layers
+            .iter()
+            .flat_map(|layers| layers.iter().map(|links| links.read().len() as u32))
+            .collect()
+    }
+
+    /// Whether the point is already linked into the graph
+    pub fn is_ready(&self, point_id: PointOffsetType) -> bool {
+        self.ready_list
+            .read()
+            .get(point_id as usize)
+            .map_or(false, |ready| *ready)
+    }
+
+    /// Seed the graph with points and links of an already built graph
+    ///
+    /// `main_links_counts` holds the number of main graph links of every point of `graph` on each
+    /// of its levels, as returned by [`GraphLayersBuilder::links_counts`] before links of the
+    /// payload blocks were merged. Only main links are seeded.
+    ///
+    /// `old_to_new` maps points of `graph` to points of this graph, unmapped points are dropped
+    /// together with all links to them. Seeded points keep their levels, and the ones which kept
+    /// all of their links are ready right away. Points which lost some links must be linked again
+    /// with [`GraphLayersBuilder::link_new_point`], which repairs them starting from the
+    /// remaining links.
+    ///
+    /// Returns flags of the seeded points.
+    pub fn seed_from_graph<TGraphLinks: GraphLinks>(
+        &mut self,
+        graph: &GraphLayers<TGraphLinks>,
+        main_links_counts: &[u32],
+        old_to_new: &[Option<PointOffsetType>],
+    ) -> BitVec {
+        let mut seeded = BitVec::repeat(false, self.num_points());
+        let mut counts_offset = 0;
+        for (old_id, new_id) in old_to_new.iter().enumerate() {
+            let old_id = old_id as PointOffsetType;
+            let level = graph.point_level(old_id);
+            let point_main_links = &main_links_counts[counts_offset..=counts_offset + level];
+            counts_offset += level + 1;
+
+            let Some(new_id) = *new_id else {
+                continue;
+            };
+            self.set_levels(new_id, level);
+
+            let mut lost_links = false;
+            for (curr_level, &main_count) in point_main_links.iter().enumerate() {
+                // Links of the payload blocks are appended after the main ones, skip them
+                let links: LinkContainer = graph
+                    .links
+                    .links(old_id, curr_level)
+                    .iter()
+                    .take(main_count as usize)
+                    .filter_map(|&old_link| {
+                        let new_link = old_to_new.get(old_link as usize).copied().flatten();
+                        lost_links |= new_link.is_none();
+                        new_link
+                    })
+                    .collect();
+                *self.links_layers[new_id as usize][curr_level].get_mut() = links;
+            }
+
+            if seeded.len() <= new_id as usize {
+                seeded.resize(new_id as usize + 1, false);
+            }
+            seeded.set(new_id as usize, true);
+
+            if !lost_links {
+                self.ready_list.get_mut().set(new_id as usize, true);
+                self.entry_points
+                    .get_mut()
+                    .new_point(new_id, level, |_| true);
+            }
+        }
+        seeded
+    }
+
     /// Generate random level for a new point, according to geometric distribution
     pub fn get_random_layer<R>(&self, rng: &mut R) -> usize
     where
@@ -389,6 +469,10 @@ impl GraphLayersBuilder {
                         for &other_point in &selected_nearest {
                             let mut other_point_links =
                                 self.links_layers[other_point as usize][curr_level].write();
+                            if other_point_links.contains(&point_id) {
+                                // Already linked, e.g. when repairing a seeded point
+                                continue;
+                            }
                             if other_point_links.len() < level_m {
                                 // If linked point is lack of neighbours
                                 other_point_links.push(point_id);
@@ -632,7 +716,7 @@ mod tests {
 
         let top = 5;
         let query = random_vector(&mut rng, dim);
-        let processed_query = M::preprocess(query.clone());
+        let processed_query = <M as Metric>::preprocess(query.clone());
         let mut reference_top = FixedLengthPriorityQueue::new(top);
         for idx in 0..vector_holder.vectors.len() as PointOffsetType {
             let vec = &vector_holder.vectors.get(idx);
@@ -655,6 +739,108 @@ mod tests {
         assert_eq!(reference_top.into_vec(), graph_search);
     }
 
+    #[test]
+    fn test_seed_from_graph() {
+        let num_vectors = 1000;
+        let dim = 8;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        type M = CosineMetric;
+
+        let (vector_holder, old_graph) =
+            create_graph_layer_fixture::<M, _>(num_vectors, M, dim, true, &mut rng, None);
+
+        // Every 10th point is removed, other points keep their offsets
+        let is_removed = |idx: PointOffsetType| idx % 10 == 0;
+        let old_to_new = (0..num_vectors as PointOffsetType)
+            .map(|idx| (!is_removed(idx)).then_some(idx))
+            .collect_vec();
+
+        // Fixture graph has no links of payload blocks, all links are main
+        let main_links_counts = (0..num_vectors as PointOffsetType)
+            .flat_map(|idx| {
+                (0..=old_graph.point_level(idx))
+                    .map(|level| old_graph.links.links(idx, level).len() as u32)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let mut graph_layers_builder = GraphLayersBuilder::new(num_vectors, M, M * 2, 16, 10, true);
+        let seeded =
+            graph_layers_builder.seed_from_graph(&old_graph, &main_links_counts, &old_to_new);
+
+        let mut to_repair = vec![];
+        for idx in 0..num_vectors as PointOffsetType {
+            assert_eq!(seeded[idx as usize], !is_removed(idx));
+            if is_removed(idx) {
+                assert!(!graph_layers_builder.is_ready(idx));
+                continue;
+            }
+            assert_eq!(
+                graph_layers_builder.get_point_level(idx),
+                old_graph.point_level(idx),
+            );
+            let mut lost_links = false;
+            for level in 0..=old_graph.point_level(idx) {
+                let old_links = old_graph.links.links(idx, level);
+                let new_links = graph_layers_builder.links_layers[idx as usize][level].read();
+                let kept_links = old_links
+                    .iter()
+                    .copied()
+                    .filter(|&link| !is_removed(link))
+                    .collect_vec();
+                assert_eq!(new_links.as_slice(), kept_links.as_slice());
+                lost_links |= kept_links.len() != old_links.len();
+            }
+            assert_eq!(graph_layers_builder.is_ready(idx), !lost_links);
+            if lost_links {
+                to_repair.push(idx);
+            }
+        }
+        assert!(!to_repair.is_empty());
+
+        for idx in to_repair {
+            let fake_filter_context = FakeFilterContext {};
+            let added_vector = vector_holder.vectors.get(idx).to_vec();
+            let raw_scorer = vector_holder.get_raw_scorer(added_vector).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            graph_layers_builder.link_new_point(idx, scorer);
+        }
+
+        for idx in 0..num_vectors as PointOffsetType {
+            assert_eq!(graph_layers_builder.is_ready(idx), !is_removed(idx));
+            let links = graph_layers_builder.links_layers[idx as usize][0].read();
+            assert!(links.iter().all(|&link| !is_removed(link)));
+            assert!(links.iter().all_unique());
+        }
+
+        let top = 5;
+        let query = random_vector(&mut rng, dim);
+        let processed_query = <M as Metric>::preprocess(query.clone());
+        let mut reference_top = FixedLengthPriorityQueue::new(top);
+        for idx in 0..num_vectors as PointOffsetType {
+            if is_removed(idx) {
+                continue;
+            }
+            reference_top.push(ScoredPointOffset {
+                idx,
+                score: M::similarity(vector_holder.vectors.get(idx), &processed_query),
+            });
+        }
+
+        let graph = graph_layers_builder
+            .into_graph_layers::<GraphLinksRam>(None)
+            .unwrap();
+
+        let fake_filter_context = FakeFilterContext {};
+        let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
+        let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+        let graph_search = graph.search(top, 16, scorer, None);
+
+        assert_eq!(reference_top.into_vec(), graph_search);
+    }
+
     #[test]
     fn test_add_points() {
         let num_vectors = 1000;
@@ -715,7 +901,7 @@ mod tests {
 
         let top = 5;
         let query = random_vector(&mut rng, dim);
-        let processed_query = M::preprocess(query.clone());
+        let processed_query = <M as Metric>::preprocess(query.clone());
         let mut reference_top = FixedLengthPriorityQueue::new(top);
         for idx in 0..vector_holder.vectors.len() as PointOffsetType {
             let vec = &vector_holder.vectors.get(idx);
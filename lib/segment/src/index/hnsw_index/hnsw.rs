@@ -5,7 +5,10 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
+use bitvec::prelude::BitVec;
 use common::types::{PointOffsetType, ScoredPointOffset};
+use io::file_operations::{atomic_save_bin, read_bin};
+use itertools::Itertools;
 use log::debug;
 use memory::mmap_ops;
 use parking_lot::Mutex;
@@ -21,7 +24,10 @@ use crate::common::operation_time_statistics::{
 use crate::common::BYTES_IN_KB;
 use crate::data_types::vectors::{QueryVector, Vector, VectorRef};
 use crate::id_tracker::{IdTracker, IdTrackerSS};
-use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
+use crate::index::field_index::CardinalityEstimation;
+use crate::index::hnsw_index::build_condition_checker::{
+    BuildConditionChecker, RangeBucketConditionChecker,
+};
 use crate::index::hnsw_index::config::HnswGraphConfig;
 use crate::index::hnsw_index::graph_layers::GraphLayers;
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
@@ -31,12 +37,14 @@ use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
+use crate::payload_storage::FilterContext;
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::Condition::Field;
 use crate::types::{
     default_quantization_ignore_value, default_quantization_oversampling_value, FieldCondition,
-    Filter, HnswConfig, QuantizationSearchParams, SearchParams, VECTOR_ELEMENT_SIZE,
+    Filter, HnswConfig, PayloadKeyTypeRef, PayloadSchemaType, QuantizationSearchParams,
+    SearchParams, VECTOR_ELEMENT_SIZE,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
@@ -46,6 +54,34 @@ use crate::vector_storage::{
 
 const HNSW_USE_HEURISTIC: bool = true;
 
+/// Each scale of additional links for numeric range buckets is this many times coarser
+/// than the previous one.
+const RANGE_BUCKET_SCALE_FACTOR: usize = 4;
+
+/// Filtered graph search widens `ef` once the estimated share of points, accepted by the filter,
+/// drops below this rate.
+const ADAPTIVE_EF_ACCEPTANCE_RATE: f64 = 0.5;
+
+/// Maximal widening of `ef` by the filtered graph search.
+const ADAPTIVE_EF_MAX_MULTIPLIER: usize = 8;
+
+/// Widen `ef`, if the filter rejects most of the points
+///
+/// Graph search only expands points accepted by the filter, so with a low acceptance rate
+/// candidates run out before the search reaches the nearest points.
+/// `ef` grows inversely to the estimated acceptance rate, up to [`ADAPTIVE_EF_MAX_MULTIPLIER`].
+fn adaptive_ef(ef: usize, acceptance_rate: f64) -> usize {
+    if acceptance_rate >= ADAPTIVE_EF_ACCEPTANCE_RATE {
+        return ef;
+    }
+    let max_ef = ef.saturating_mul(ADAPTIVE_EF_MAX_MULTIPLIER);
+    if acceptance_rate > 0.0 {
+        ((ef as f64 * ADAPTIVE_EF_ACCEPTANCE_RATE / acceptance_rate).ceil() as usize).min(max_ef)
+    } else {
+        max_ef
+    }
+}
+
 /// Build first N points in HNSW graph using only a single thread, to avoid
 /// disconnected components in the graph.
 #[cfg(debug_assertions)]
@@ -99,6 +135,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                 full_scan_threshold,
                 hnsw_config.max_indexing_threads,
                 hnsw_config.payload_m,
+                hnsw_config.range_links.unwrap_or_default(),
                 available_vectors,
             )
         };
@@ -158,6 +195,330 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         Ok(())
     }
 
+    /// Number of main graph links of every point on each of its levels, saved at build time
+    ///
+    /// Returns `None` if the counts were not saved or do not match the graph.
+    pub(super) fn main_links_counts(&self) -> Option<Vec<u32>> {
+        let graph = self.graph.as_ref()?;
+        let main_links_path = GraphLayers::<TGraphLinks>::get_main_links_path(&self.path);
+        if !main_links_path.exists() {
+            return None;
+        }
+        let main_links_counts: Vec<u32> = read_bin(&main_links_path).ok()?;
+        let expected_len: usize = (0..graph.num_points() as PointOffsetType)
+            .map(|point_id| graph.point_level(point_id) + 1)
+            .sum();
+        (main_links_counts.len() == expected_len).then_some(main_links_counts)
+    }
+
+    /// Map points of the `old_index` graph to points of this index, so the graph can be reused
+    ///
+    /// Only points with the same version, which are present in both indexes, are mapped, as
+    /// their vectors are the same. Returns `None` if the old graph is not compatible.
+    fn map_old_points<TOldGraphLinks: GraphLinks>(
+        &self,
+        old_index: &HNSWIndex<TOldGraphLinks>,
+    ) -> Option<Vec<Option<PointOffsetType>>> {
+        let old_graph = old_index.graph.as_ref()?;
+        if old_index.config.m != self.config.m
+            || old_index.config.m0 != self.config.m0
+            || old_index.config.ef_construct != self.config.ef_construct
+        {
+            return None;
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+        let old_id_tracker = old_index.id_tracker.borrow();
+        let old_vector_storage = old_index.vector_storage.borrow();
+
+        let mut old_to_new = vec![None; old_graph.num_points()];
+        for old_id in
+            old_id_tracker.iter_ids_excluding(old_vector_storage.deleted_vector_bitslice())
+        {
+            if old_id as usize >= old_to_new.len() {
+                continue;
+            }
+            let Some(new_id) = old_id_tracker
+                .external_id(old_id)
+                .and_then(|external_id| id_tracker.internal_id(external_id))
+            else {
+                continue;
+            };
+            let is_deleted = deleted_bitslice
+                .get(new_id as usize)
+                .map_or(false, |deleted| *deleted);
+            if is_deleted
+                || old_id_tracker.internal_version(old_id) != id_tracker.internal_version(new_id)
+            {
+                continue;
+            }
+            old_to_new[old_id as usize] = Some(new_id);
+        }
+        Some(old_to_new)
+    }
+
+    /// Seed the graph builder with the graph of the old index, which shares most points with
+    /// this one. Returns flags of the seeded points.
+    fn seed_from_old_indices(
+        &self,
+        old_indices: &[Arc<AtomicRefCell<VectorIndexEnum>>],
+        graph_layers_builder: &mut GraphLayersBuilder,
+    ) -> BitVec {
+        let old_indices = old_indices.iter().map(|index| index.borrow()).collect_vec();
+        let best_old_index = old_indices
+            .iter()
+            .filter_map(|old_index| {
+                let old_to_new = match old_index.deref() {
+                    VectorIndexEnum::HnswRam(index) => self.map_old_points(index),
+                    VectorIndexEnum::HnswMmap(index) => self.map_old_points(index),
+                    VectorIndexEnum::Plain(_)
+                    | VectorIndexEnum::SparseRam(_)
+                    | VectorIndexEnum::SparseMmap(_) => None,
+                }?;
+                // Without main link counts, links of the main graph can't be told apart from
+                // links of the payload blocks
+                let main_links_counts = match old_index.deref() {
+                    VectorIndexEnum::HnswRam(index) => index.main_links_counts(),
+                    VectorIndexEnum::HnswMmap(index) => index.main_links_counts(),
+                    _ => None,
+                }?;
+                let reused_count = old_to_new.iter().flatten().count();
+                Some((old_index, old_to_new, main_links_counts, reused_count))
+            })
+            .max_by_key(|(_, _, _, reused_count)| *reused_count);
+
+        let Some((old_index, old_to_new, main_links_counts, reused_count)) = best_old_index else {
+            return BitVec::new();
+        };
+        debug!("reusing HNSW graph of {reused_count} points");
+        match old_index.deref() {
+            VectorIndexEnum::HnswRam(HNSWIndex {
+                graph: Some(graph), ..
+            }) => graph_layers_builder.seed_from_graph(graph, &main_links_counts, &old_to_new),
+            VectorIndexEnum::HnswMmap(HNSWIndex {
+                graph: Some(graph), ..
+            }) => graph_layers_builder.seed_from_graph(graph, &main_links_counts, &old_to_new),
+            _ => BitVec::new(),
+        }
+    }
+
+    /// Build the index, reusing the graph of one of the `old_indices` of the source segments
+    ///
+    /// Links of the points, which are the same in the old index, are copied from its graph.
+    /// Only the other points are inserted, and the copied points, which lost links to deleted
+    /// ones, are linked again.
+    pub fn build_index_with_old_indices(
+        &mut self,
+        old_indices: &[Arc<AtomicRefCell<VectorIndexEnum>>],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        // Build main index graph
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+        let mut rng = thread_rng();
+
+        let total_vector_count = vector_storage.total_vector_count();
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+
+        debug!("building HNSW for {} vectors", total_vector_count);
+        let indexing_threshold = self.config.full_scan_threshold;
+        let mut graph_layers_builder = GraphLayersBuilder::new(
+            total_vector_count,
+            self.config.m,
+            self.config.m0,
+            self.config.ef_construct,
+            (total_vector_count
+                .checked_div(indexing_threshold)
+                .unwrap_or(0)
+                * 10)
+                .max(1),
+            HNSW_USE_HEURISTIC,
+        );
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("hnsw-build-{idx}"))
+            .num_threads(max_rayon_threads(self.config.max_indexing_threads))
+            .build()?;
+
+        let seeded = if self.config.m > 0 {
+            self.seed_from_old_indices(old_indices, &mut graph_layers_builder)
+        } else {
+            BitVec::new()
+        };
+
+        for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
+            check_process_stopped(stopped)?;
+            if seeded
+                .get(vector_id as usize)
+                .map_or(false, |seeded| *seeded)
+            {
+                // Keep the level of the old graph
+                continue;
+            }
+            let level = graph_layers_builder.get_random_layer(&mut rng);
+            graph_layers_builder.set_levels(vector_id, level);
+        }
+
+        let mut indexed_vectors = 0;
+
+        if self.config.m > 0 {
+            indexed_vectors = id_tracker.iter_ids_excluding(deleted_bitslice).count();
+
+            let mut ids_iterator = id_tracker
+                .iter_ids_excluding(deleted_bitslice)
+                .filter(|&vector_id| !graph_layers_builder.is_ready(vector_id));
+
+            let first_few_ids: Vec<_> = ids_iterator
+                .by_ref()
+                .take(SINGLE_THREADED_HNSW_BUILD_THRESHOLD)
+                .collect();
+            let ids: Vec<_> = ids_iterator.collect();
+
+            let insert_point = |vector_id| {
+                check_process_stopped(stopped)?;
+                let vector = vector_storage.get_vector(vector_id);
+                let vector = vector.as_vec_ref().into();
+                let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
+                    quantized_storage.raw_scorer(
+                        vector,
+                        id_tracker.deleted_point_bitslice(),
+                        vector_storage.deleted_vector_bitslice(),
+                        stopped,
+                    )
+                } else {
+                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
+                }?;
+                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+
+                graph_layers_builder.link_new_point(vector_id, points_scorer);
+                Ok::<_, OperationError>(())
+            };
+
+            for vector_id in first_few_ids {
+                insert_point(vector_id)?;
+            }
+
+            if !ids.is_empty() {
+                pool.install(|| ids.into_par_iter().try_for_each(insert_point))?;
+            }
+
+            debug!("finish main graph");
+        } else {
+            debug!("skip building main HNSW graph");
+        }
+
+        // Links of the payload blocks are appended to the main ones, remember where they start
+        let main_links_counts = graph_layers_builder.links_counts();
+
+        let visited_pool = VisitedPool::new();
+        let mut block_filter_list = visited_pool.get(total_vector_count);
+        let visits_iteration = block_filter_list.get_current_iteration_id();
+
+        let payload_index = self.payload_index.borrow();
+        let payload_m = self.config.payload_m.unwrap_or(self.config.m);
+
+        if payload_m > 0 {
+            // Calculate true average number of links per vertex in the HNSW graph
+            // to better estimate percolation threshold
+            let average_links_per_0_level =
+                graph_layers_builder.get_average_connectivity_on_level(0);
+            let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
+
+            for (field, field_schema) in payload_index.indexed_fields() {
+                debug!("building additional index for field {}", &field);
+
+                // It is expected, that graph will become disconnected less than
+                // $1/m$ points left.
+                // So blocks larger than $1/m$ are not needed.
+                // We add multiplier for the extra safety.
+                let percolation_multiplier = 4;
+                let max_block_size = if self.config.m > 0 {
+                    total_vector_count / average_links_per_0_level_int * percolation_multiplier
+                } else {
+                    usize::MAX
+                };
+                let min_block_size = indexing_threshold;
+
+                for payload_block in payload_index.payload_blocks(&field, min_block_size) {
+                    check_process_stopped(stopped)?;
+                    if payload_block.cardinality > max_block_size {
+                        continue;
+                    }
+                    // ToDo: reuse graph layer for same payload
+                    let mut additional_graph = GraphLayersBuilder::new_with_params(
+                        total_vector_count,
+                        payload_m,
+                        self.config.payload_m0.unwrap_or(self.config.m0),
+                        self.config.ef_construct,
+                        1,
+                        HNSW_USE_HEURISTIC,
+                        false,
+                    );
+                    self.build_filtered_graph(
+                        &pool,
+                        stopped,
+                        &mut additional_graph,
+                        payload_block.condition,
+                        &mut block_filter_list,
+                    )?;
+                    graph_layers_builder.merge_from_other(additional_graph);
+                }
+
+                if self.config.range_links
+                    && matches!(
+                        field_schema.kind(),
+                        PayloadSchemaType::Integer
+                            | PayloadSchemaType::Float
+                            | PayloadSchemaType::Datetime
+                    )
+                {
+                    self.build_range_bucket_graphs(
+                        &pool,
+                        stopped,
+                        &mut graph_layers_builder,
+                        &field,
+                        min_block_size,
+                        max_block_size,
+                        &mut block_filter_list,
+                    )?;
+                }
+            }
+
+            let indexed_payload_vectors = block_filter_list.count_visits_since(visits_iteration);
+
+            debug_assert!(indexed_vectors >= indexed_payload_vectors || self.config.m == 0);
+            indexed_vectors = indexed_vectors.max(indexed_payload_vectors);
+            debug_assert!(indexed_payload_vectors <= total_vector_count);
+        } else {
+            debug!("skip building additional HNSW links");
+        }
+
+        self.config.indexed_vector_count.replace(indexed_vectors);
+
+        let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(&self.path);
+        self.graph = Some(graph_layers_builder.into_graph_layers(Some(&graph_links_path))?);
+        atomic_save_bin(
+            &GraphLayers::<TGraphLinks>::get_main_links_path(&self.path),
+            &main_links_counts,
+        )?;
+
+        #[cfg(debug_assertions)]
+        {
+            let graph = self.graph.as_ref().unwrap();
+            for (idx, deleted) in deleted_bitslice.iter().enumerate() {
+                if *deleted {
+                    debug_assert!(graph.links.links(idx as PointOffsetType, 0).is_empty());
+                }
+            }
+        }
+
+        debug!("finish additional payload field indexing");
+        self.save()
+    }
+
     pub fn build_filtered_graph(
         &self,
         pool: &ThreadPool,
@@ -168,16 +529,138 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
     ) -> OperationResult<()> {
         block_filter_list.next_iteration();
 
+        let points_to_index = self.query_block_points(condition);
+
+        for block_point_id in points_to_index.iter().copied() {
+            block_filter_list.check_and_update_visited(block_point_id);
+        }
+
+        let block_filter_list = &*block_filter_list;
+        self.link_block_points(
+            pool,
+            stopped,
+            graph_layers_builder,
+            points_to_index,
+            |block_point_id| BuildConditionChecker {
+                filter_list: block_filter_list,
+                current_point: block_point_id,
+            },
+        )
+    }
+
+    /// Build additional links within wide ranges of a numeric field.
+    ///
+    /// Payload blocks of a numeric field are narrow ranges of adjacent histogram buckets,
+    /// so a range filter of medium selectivity is only covered by a chain of overlapping blocks.
+    /// Buckets are marked once, and on each coarser scale windows of consecutive buckets are linked.
+    /// Windows overlap by half, so any range not wider than half of a window lies within one window.
+    #[allow(clippy::too_many_arguments)]
+    fn build_range_bucket_graphs(
+        &self,
+        pool: &ThreadPool,
+        stopped: &AtomicBool,
+        graph_layers_builder: &mut GraphLayersBuilder,
+        field: PayloadKeyTypeRef,
+        min_block_size: usize,
+        max_block_size: usize,
+        block_filter_list: &mut VisitedListHandle,
+    ) -> OperationResult<()> {
+        let payload_index = self.payload_index.borrow();
+        let total_vector_count = self.vector_storage.borrow().total_vector_count();
+        let payload_m = self.config.payload_m.unwrap_or(self.config.m);
+        let payload_m0 = self.config.payload_m0.unwrap_or(self.config.m0);
+
+        // Each block spans two buckets and the next block starts from the second one,
+        // so a point is marked in the iteration of the last block, which is its own bucket
+        let blocks = payload_index
+            .payload_blocks(field, min_block_size)
+            .collect_vec();
+        let mut block_points = Vec::with_capacity(blocks.len());
+        let mut bucket_iterations = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            check_process_stopped(stopped)?;
+            block_filter_list.next_iteration();
+            let points = self.query_block_points(block.condition);
+            for point_id in points.iter().copied() {
+                block_filter_list.check_and_update_visited(point_id);
+            }
+            block_points.push(points);
+            bucket_iterations.push(block_filter_list.get_current_iteration_id());
+        }
+
+        // Points with multiple values belong only to the last bucket they are in
+        let block_filter_list = &*block_filter_list;
+        let bucket_points = block_points
+            .into_iter()
+            .zip(&bucket_iterations)
+            .map(|(points, &iteration)| {
+                points
+                    .into_iter()
+                    .filter(|&point_id| {
+                        block_filter_list.get_visit_iteration(point_id) == iteration
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let mut window_buckets = 2 * RANGE_BUCKET_SCALE_FACTOR;
+        while window_buckets < bucket_points.len() {
+            let window_stride = window_buckets / 2;
+            for window_start in (0..bucket_points.len()).step_by(window_stride) {
+                check_process_stopped(stopped)?;
+                let window_end = (window_start + window_buckets).min(bucket_points.len()) - 1;
+
+                let points_to_index = bucket_points[window_start..=window_end]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect_vec();
+                if points_to_index.len() > max_block_size {
+                    continue;
+                }
+
+                let iterations = bucket_iterations[window_start]..=bucket_iterations[window_end];
+                let mut additional_graph = GraphLayersBuilder::new_with_params(
+                    total_vector_count,
+                    payload_m,
+                    payload_m0,
+                    self.config.ef_construct,
+                    1,
+                    HNSW_USE_HEURISTIC,
+                    false,
+                );
+                self.link_block_points(
+                    pool,
+                    stopped,
+                    &mut additional_graph,
+                    points_to_index,
+                    |block_point_id| RangeBucketConditionChecker {
+                        filter_list: block_filter_list,
+                        iterations: iterations.clone(),
+                        current_point: block_point_id,
+                    },
+                )?;
+                graph_layers_builder.merge_from_other(additional_graph);
+
+                if window_end + 1 == bucket_points.len() {
+                    break;
+                }
+            }
+
+            window_buckets *= RANGE_BUCKET_SCALE_FACTOR;
+        }
+        Ok(())
+    }
+
+    /// Points matching the payload block condition, excluding deleted vectors
+    fn query_block_points(&self, condition: FieldCondition) -> Vec<PointOffsetType> {
         let filter = Filter::new_must(Field(condition));
 
-        let id_tracker = self.id_tracker.borrow();
         let payload_index = self.payload_index.borrow();
         let vector_storage = self.vector_storage.borrow();
-        let quantized_vectors = self.quantized_vectors.borrow();
-
         let deleted_bitslice = vector_storage.deleted_vector_bitslice();
 
-        let points_to_index: Vec<_> = payload_index
+        payload_index
             .query_points(&filter)
             .into_iter()
             .filter(|&point_id| {
@@ -186,11 +669,27 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                     .map(|x| *x)
                     .unwrap_or(false)
             })
-            .collect();
+            .collect()
+    }
 
-        for block_point_id in points_to_index.iter().copied() {
-            block_filter_list.check_and_update_visited(block_point_id);
-        }
+    /// Link block points with each other, considering only points accepted by the condition checker
+    fn link_block_points<F, C>(
+        &self,
+        pool: &ThreadPool,
+        stopped: &AtomicBool,
+        graph_layers_builder: &mut GraphLayersBuilder,
+        points_to_index: Vec<PointOffsetType>,
+        condition_checker: F,
+    ) -> OperationResult<()>
+    where
+        F: Fn(PointOffsetType) -> C + Sync,
+        C: FilterContext,
+    {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
 
         if let Some(graph) = &self.graph {
             for &block_point_id in &points_to_index {
@@ -216,10 +715,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                     new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
                 }
             }?;
-            let block_condition_checker = BuildConditionChecker {
-                filter_list: block_filter_list,
-                current_point: block_point_id,
-            };
+            let block_condition_checker = condition_checker(block_point_id);
             let points_scorer =
                 FilteredScorer::new(raw_scorer.as_ref(), Some(&block_condition_checker));
 
@@ -451,6 +947,26 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         }
     }
 
+    /// Widen `ef` of a filtered graph search, if requested by `adaptive_ef` search param
+    ///
+    /// Explicitly set `hnsw_ef` is always respected.
+    fn adaptive_search_params(
+        &self,
+        params: Option<&SearchParams>,
+        query_cardinality: &CardinalityEstimation,
+        available_vector_count: usize,
+    ) -> Option<SearchParams> {
+        let params = params.copied()?;
+        if !params.adaptive_ef || params.hnsw_ef.is_some() || available_vector_count == 0 {
+            return Some(params);
+        }
+        let acceptance_rate = query_cardinality.exp as f64 / available_vector_count as f64;
+        Some(SearchParams {
+            hnsw_ef: Some(adaptive_ef(self.config.ef, acceptance_rate)),
+            ..params
+        })
+    }
+
     fn postprocess_search_result(
         &self,
         search_result: Vec<ScoredPointOffset>,
@@ -601,12 +1117,20 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     );
                 }
 
+                let graph_params =
+                    self.adaptive_search_params(params, &query_cardinality, available_vector_count);
+
                 if query_cardinality.min > self.config.full_scan_threshold {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    return self
-                        .search_vectors_with_graph(vectors, filter, top, params, is_stopped);
+                    return self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        graph_params.as_ref(),
+                        is_stopped,
+                    );
                 }
 
                 let filter_context = payload_index.filter_context(query_filter);
@@ -622,7 +1146,13 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    self.search_vectors_with_graph(vectors, filter, top, params, is_stopped)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        graph_params.as_ref(),
+                        is_stopped,
+                    )
                 } else {
                     // if cardinality is small - use plain index
                     let _timer =
@@ -634,168 +1164,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
     }
 
     fn build_index(&mut self, stopped: &AtomicBool) -> OperationResult<()> {
-        // Build main index graph
-        let id_tracker = self.id_tracker.borrow();
-        let vector_storage = self.vector_storage.borrow();
-        let quantized_vectors = self.quantized_vectors.borrow();
-        let mut rng = thread_rng();
-
-        let total_vector_count = vector_storage.total_vector_count();
-        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
-
-        debug!("building HNSW for {} vectors", total_vector_count);
-        let indexing_threshold = self.config.full_scan_threshold;
-        let mut graph_layers_builder = GraphLayersBuilder::new(
-            total_vector_count,
-            self.config.m,
-            self.config.m0,
-            self.config.ef_construct,
-            (total_vector_count
-                .checked_div(indexing_threshold)
-                .unwrap_or(0)
-                * 10)
-                .max(1),
-            HNSW_USE_HEURISTIC,
-        );
-
-        let pool = rayon::ThreadPoolBuilder::new()
-            .thread_name(|idx| format!("hnsw-build-{idx}"))
-            .num_threads(max_rayon_threads(self.config.max_indexing_threads))
-            .build()?;
-
-        for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
-            check_process_stopped(stopped)?;
-            let level = graph_layers_builder.get_random_layer(&mut rng);
-            graph_layers_builder.set_levels(vector_id, level);
-        }
-
-        let mut indexed_vectors = 0;
-
-        if self.config.m > 0 {
-            let mut ids_iterator = id_tracker.iter_ids_excluding(deleted_bitslice);
-
-            let first_few_ids: Vec<_> = ids_iterator
-                .by_ref()
-                .take(SINGLE_THREADED_HNSW_BUILD_THRESHOLD)
-                .collect();
-            let ids: Vec<_> = ids_iterator.collect();
-
-            indexed_vectors = ids.len() + first_few_ids.len();
-
-            let insert_point = |vector_id| {
-                check_process_stopped(stopped)?;
-                let vector = vector_storage.get_vector(vector_id);
-                let vector = vector.as_vec_ref().into();
-                let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
-                    quantized_storage.raw_scorer(
-                        vector,
-                        id_tracker.deleted_point_bitslice(),
-                        vector_storage.deleted_vector_bitslice(),
-                        stopped,
-                    )
-                } else {
-                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
-                }?;
-                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
-
-                graph_layers_builder.link_new_point(vector_id, points_scorer);
-                Ok::<_, OperationError>(())
-            };
-
-            for vector_id in first_few_ids {
-                insert_point(vector_id)?;
-            }
-
-            if !ids.is_empty() {
-                pool.install(|| ids.into_par_iter().try_for_each(insert_point))?;
-            }
-
-            debug!("finish main graph");
-        } else {
-            debug!("skip building main HNSW graph");
-        }
-
-        let visited_pool = VisitedPool::new();
-        let mut block_filter_list = visited_pool.get(total_vector_count);
-        let visits_iteration = block_filter_list.get_current_iteration_id();
-
-        let payload_index = self.payload_index.borrow();
-        let payload_m = self.config.payload_m.unwrap_or(self.config.m);
-
-        if payload_m > 0 {
-            // Calculate true average number of links per vertex in the HNSW graph
-            // to better estimate percolation threshold
-            let average_links_per_0_level =
-                graph_layers_builder.get_average_connectivity_on_level(0);
-            let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
-
-            for (field, _) in payload_index.indexed_fields() {
-                debug!("building additional index for field {}", &field);
-
-                // It is expected, that graph will become disconnected less than
-                // $1/m$ points left.
-                // So blocks larger than $1/m$ are not needed.
-                // We add multiplier for the extra safety.
-                let percolation_multiplier = 4;
-                let max_block_size = if self.config.m > 0 {
-                    total_vector_count / average_links_per_0_level_int * percolation_multiplier
-                } else {
-                    usize::MAX
-                };
-                let min_block_size = indexing_threshold;
-
-                for payload_block in payload_index.payload_blocks(&field, min_block_size) {
-                    check_process_stopped(stopped)?;
-                    if payload_block.cardinality > max_block_size {
-                        continue;
-                    }
-                    // ToDo: reuse graph layer for same payload
-                    let mut additional_graph = GraphLayersBuilder::new_with_params(
-                        total_vector_count,
-                        payload_m,
-                        self.config.payload_m0.unwrap_or(self.config.m0),
-                        self.config.ef_construct,
-                        1,
-                        HNSW_USE_HEURISTIC,
-                        false,
-                    );
-                    self.build_filtered_graph(
-                        &pool,
-                        stopped,
-                        &mut additional_graph,
-                        payload_block.condition,
-                        &mut block_filter_list,
-                    )?;
-                    graph_layers_builder.merge_from_other(additional_graph);
-                }
-            }
-
-            let indexed_payload_vectors = block_filter_list.count_visits_since(visits_iteration);
-
-            debug_assert!(indexed_vectors >= indexed_payload_vectors || self.config.m == 0);
-            indexed_vectors = indexed_vectors.max(indexed_payload_vectors);
-            debug_assert!(indexed_payload_vectors <= total_vector_count);
-        } else {
-            debug!("skip building additional HNSW links");
-        }
-
-        self.config.indexed_vector_count.replace(indexed_vectors);
-
-        let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(&self.path);
-        self.graph = Some(graph_layers_builder.into_graph_layers(Some(&graph_links_path))?);
-
-        #[cfg(debug_assertions)]
-        {
-            let graph = self.graph.as_ref().unwrap();
-            for (idx, deleted) in deleted_bitslice.iter().enumerate() {
-                if *deleted {
-                    debug_assert!(graph.links.links(idx as PointOffsetType, 0).is_empty());
-                }
-            }
-        }
-
-        debug!("finish additional payload field indexing");
-        self.save()
+        self.build_index_with_old_indices(&[], stopped)
     }
 
     fn get_telemetry_data(&self) -> VectorIndexSearchesTelemetry {
@@ -816,10 +1185,15 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
 
     fn files(&self) -> Vec<PathBuf> {
         if self.graph.is_some() {
-            vec![
+            let mut files = vec![
                 GraphLayers::<TGraphLinks>::get_path(&self.path),
                 GraphLayers::<TGraphLinks>::get_links_path(&self.path),
-            ]
+            ];
+            let main_links_path = GraphLayers::<TGraphLinks>::get_main_links_path(&self.path);
+            if main_links_path.exists() {
+                files.push(main_links_path);
+            }
+            files
         } else {
             vec![]
         }
@@ -837,3 +1211,24 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
         Err(OperationError::service_error("Cannot update HNSW index"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_ef() {
+        // Permissive filters keep the configured `ef`
+        assert_eq!(adaptive_ef(100, 1.0), 100);
+        assert_eq!(adaptive_ef(100, ADAPTIVE_EF_ACCEPTANCE_RATE), 100);
+
+        // `ef` grows inversely to the acceptance rate
+        assert_eq!(adaptive_ef(100, 0.25), 200);
+        assert_eq!(adaptive_ef(100, 0.1), 500);
+        assert_eq!(adaptive_ef(7, 0.3), 12);
+
+        // Up to the maximal multiplier
+        assert_eq!(adaptive_ef(100, 0.01), 100 * ADAPTIVE_EF_MAX_MULTIPLIER);
+        assert_eq!(adaptive_ef(100, 0.0), 100 * ADAPTIVE_EF_MAX_MULTIPLIER);
+    }
+}
@@ -1,5 +1,7 @@
 mod test_compact_graph_layer;
 mod test_graph_connectivity;
+mod test_range_links;
+mod test_seed_from_graph;
 
 use std::path::Path;
 
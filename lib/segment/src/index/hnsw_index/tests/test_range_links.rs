@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use common::types::PointOffsetType;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
+use crate::entry::entry_point::SegmentEntry;
+use crate::fixtures::index_fixtures::random_vector;
+use crate::fixtures::payload_fixtures::random_int_payload;
+use crate::index::hnsw_index::graph_links::{GraphLinks, GraphLinksRam};
+use crate::index::hnsw_index::hnsw::HNSWIndex;
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::segment_constructor::build_segment;
+use crate::types::{
+    Distance, HnswConfig, Indexes, Payload, PayloadSchemaType, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType,
+};
+
+/// Links within wide ranges of numeric fields are only built, if enabled in the config
+#[test]
+fn test_range_links_disabled() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let num_vectors: u64 = 2_000;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 2; // KB
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let disabled_hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+    let enabled_hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+        sparse_vector_data: Default::default(),
+    };
+
+    let int_key = "int";
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rnd, dim);
+        let payload: Payload = json!({ int_key: random_int_payload(&mut rnd, 1..=1) }).into();
+
+        segment
+            .upsert_point(n as SeqNumberType, idx, only_default_vector(&vector))
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload)
+            .unwrap();
+    }
+
+    segment
+        .payload_index
+        .borrow_mut()
+        .set_indexed(int_key, PayloadSchemaType::Integer.into())
+        .unwrap();
+
+    // No main graph, so points are connected only by the additional payload links
+    let hnsw_config = HnswConfig {
+        m: 0,
+        ef_construct: 16,
+        full_scan_threshold,
+        max_indexing_threads: 1,
+        on_disk: Some(false),
+        payload_m: Some(8),
+        range_links: None,
+    };
+
+    let build_index = |path, hnsw_config| {
+        let mut hnsw_index = HNSWIndex::<GraphLinksRam>::open(
+            path,
+            segment.id_tracker.clone(),
+            segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            Default::default(),
+            segment.payload_index.clone(),
+            hnsw_config,
+        )
+        .unwrap();
+        hnsw_index.build_index(&stopped).unwrap();
+        hnsw_index
+    };
+
+    let disabled_index = build_index(disabled_hnsw_dir.path(), hnsw_config.clone());
+    let enabled_index = build_index(
+        enabled_hnsw_dir.path(),
+        HnswConfig {
+            range_links: Some(true),
+            ..hnsw_config
+        },
+    );
+
+    let disabled_graph = disabled_index.graph().unwrap();
+    let enabled_graph = enabled_index.graph().unwrap();
+
+    // Range links are appended after links of the payload blocks, which are the same
+    let mut disabled_links_count = 0;
+    let mut enabled_links_count = 0;
+    for point_id in 0..num_vectors as PointOffsetType {
+        let disabled_links = disabled_graph.links.links(point_id, 0);
+        let enabled_links = enabled_graph.links.links(point_id, 0);
+        assert_eq!(disabled_links, &enabled_links[..disabled_links.len()]);
+
+        disabled_links_count += disabled_links.len();
+        enabled_links_count += enabled_links.len();
+    }
+    assert!(disabled_links_count > 0);
+    assert!(enabled_links_count > disabled_links_count);
+}
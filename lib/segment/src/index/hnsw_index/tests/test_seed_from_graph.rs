@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::types::PointOffsetType;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
+use crate::entry::entry_point::SegmentEntry;
+use crate::fixtures::index_fixtures::random_vector;
+use crate::fixtures::payload_fixtures::random_adj;
+use crate::index::hnsw_index::graph_links::{GraphLinks, GraphLinksRam};
+use crate::index::hnsw_index::hnsw::HNSWIndex;
+use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
+use crate::segment_constructor::build_segment;
+use crate::types::{
+    Distance, HnswConfig, Indexes, Payload, PayloadSchemaType, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType,
+};
+
+/// Graph seeded from an index with payload blocks must only reuse the main graph links
+#[test]
+fn test_seeded_graph_keeps_main_links() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let m = 8;
+    let num_vectors: u64 = 2_000;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 2; // KB
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let fresh_hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+    let seeded_hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+        sparse_vector_data: Default::default(),
+    };
+
+    let keyword_key = "keyword";
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rnd, dim);
+        let payload: Payload = json!({ keyword_key: random_adj(&mut rnd) }).into();
+
+        segment
+            .upsert_point(n as SeqNumberType, idx, only_default_vector(&vector))
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload)
+            .unwrap();
+    }
+
+    segment
+        .payload_index
+        .borrow_mut()
+        .set_indexed(keyword_key, PayloadSchemaType::Keyword.into())
+        .unwrap();
+
+    let hnsw_config = HnswConfig {
+        m,
+        ef_construct: 16,
+        full_scan_threshold,
+        max_indexing_threads: 1,
+        on_disk: Some(false),
+        payload_m: Some(m),
+        range_links: None,
+    };
+
+    let open_index = |path| {
+        HNSWIndex::<GraphLinksRam>::open(
+            path,
+            segment.id_tracker.clone(),
+            segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            Default::default(),
+            segment.payload_index.clone(),
+            hnsw_config.clone(),
+        )
+        .unwrap()
+    };
+
+    let mut fresh_index = open_index(fresh_hnsw_dir.path());
+    fresh_index.build_index(&stopped).unwrap();
+
+    let old_indices = [Arc::new(AtomicRefCell::new(VectorIndexEnum::HnswRam(
+        fresh_index,
+    )))];
+    let mut seeded_index = open_index(seeded_hnsw_dir.path());
+    seeded_index
+        .build_index_with_old_indices(&old_indices, &stopped)
+        .unwrap();
+
+    let old_index = old_indices[0].borrow();
+    let VectorIndexEnum::HnswRam(fresh_index) = &*old_index else {
+        unreachable!();
+    };
+
+    let fresh_graph = fresh_index.graph().unwrap();
+    let seeded_graph = seeded_index.graph().unwrap();
+    let fresh_counts = fresh_index.main_links_counts().unwrap();
+    let seeded_counts = seeded_index.main_links_counts().unwrap();
+
+    // Every point is shared, so the main graph is copied as is
+    assert_eq!(fresh_counts, seeded_counts);
+
+    let mut counts = fresh_counts.iter();
+    let mut sparse_points = 0;
+    for point_id in 0..num_vectors as PointOffsetType {
+        let level = fresh_graph.point_level(point_id);
+        assert_eq!(seeded_graph.point_level(point_id), level);
+        for curr_level in 0..=level {
+            let main_count = *counts.next().unwrap() as usize;
+            let fresh_links = fresh_graph.links.links(point_id, curr_level);
+            let seeded_links = seeded_graph.links.links(point_id, curr_level);
+            assert_eq!(fresh_links[..main_count], seeded_links[..main_count]);
+
+            // Point has less main links than the graph allows, followed by payload block links
+            let level_m = if curr_level == 0 { m * 2 } else { m };
+            if main_count < level_m && fresh_links.len() > main_count {
+                sparse_points += 1;
+            }
+        }
+    }
+    assert!(sparse_points > 0);
+}
@@ -8,7 +8,7 @@ use std::cmp::{max, min};
 use itertools::Itertools;
 
 use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
-use crate::types::{Condition, Filter};
+use crate::types::{Condition, Filter, MinShould};
 
 /// Re-estimate cardinality based on number of available vectors
 /// Assuming that deleted vectors are not correlated with the filter
@@ -125,6 +125,75 @@ pub fn combine_should_estimations(
     }
 }
 
+/// Estimate the number of points matching at least `min_count` of the conditions
+///
+/// Expected number is computed assuming that conditions are independent.
+pub fn combine_min_should_estimations(
+    estimations: &[CardinalityEstimation],
+    min_count: usize,
+    total: usize,
+) -> CardinalityEstimation {
+    if min_count == 0 {
+        return CardinalityEstimation::exact(total);
+    }
+    if min_count > estimations.len() {
+        return CardinalityEstimation::exact(0);
+    }
+    if min_count == 1 {
+        return combine_should_estimations(estimations, total);
+    }
+
+    // Any point matching `min_count` conditions matches at least one of them,
+    // so primary clauses are the same as for `should`
+    let mut clauses: Vec<PrimaryCondition> = vec![];
+    for estimation in estimations {
+        if estimation.primary_clauses.is_empty() {
+            clauses = vec![];
+            break;
+        }
+        clauses.append(&mut estimation.primary_clauses.clone());
+    }
+
+    // Probabilities of a point to match exactly `i` conditions,
+    // with the last element accumulating `min_count` matches or more
+    let mut hit_probs = vec![0.0; min_count + 1];
+    hit_probs[0] = 1.0;
+    for estimation in estimations {
+        let hit_prob = estimation.exp as f64 / total as f64;
+        hit_probs[min_count] += hit_probs[min_count - 1] * hit_prob;
+        for hits in (1..min_count).rev() {
+            hit_probs[hits] = hit_probs[hits] * (1.0 - hit_prob) + hit_probs[hits - 1] * hit_prob;
+        }
+        hit_probs[0] *= 1.0 - hit_prob;
+    }
+    let expected_count = (hit_probs[min_count] * total as f64).round() as usize;
+
+    // Each matched point is counted in at least `min_count` conditions,
+    // and each non-matched point in at most `min_count - 1` of them
+    let conditions_count = estimations.len();
+    let min_sum: usize = estimations.iter().map(|x| x.min).sum();
+    let min_estimation = min_sum
+        .saturating_sub((min_count - 1) * total)
+        .div_ceil(conditions_count - min_count + 1);
+
+    // Each matched point matches at least one of any `len - min_count + 1` conditions
+    let max_sum: usize = estimations.iter().map(|x| x.max).sum();
+    let smallest_max_sum: usize = estimations
+        .iter()
+        .map(|x| x.max)
+        .sorted()
+        .take(conditions_count - min_count + 1)
+        .sum();
+    let max_estimation = (max_sum / min_count).min(smallest_max_sum).min(total);
+
+    CardinalityEstimation {
+        primary_clauses: clauses,
+        min: min_estimation,
+        exp: expected_count.clamp(min_estimation, max_estimation),
+        max: max_estimation,
+    }
+}
+
 pub fn combine_must_estimations(
     estimations: &[CardinalityEstimation],
     total: usize,
@@ -196,6 +265,15 @@ where
             }
         }
     }
+    match &filter.min_should {
+        None => {}
+        Some(MinShould {
+            conditions,
+            min_count,
+        }) => filter_estimations.push(estimate_min_should(
+            estimator, conditions, *min_count, total,
+        )),
+    }
     match &filter.must_not {
         None => {}
         Some(conditions) => {
@@ -221,6 +299,20 @@ where
     combine_should_estimations(&should_estimations, total)
 }
 
+fn estimate_min_should<F>(
+    estimator: &F,
+    conditions: &[Condition],
+    min_count: usize,
+    total: usize,
+) -> CardinalityEstimation
+where
+    F: Fn(&Condition) -> CardinalityEstimation,
+{
+    let estimate = |x| estimate_condition(estimator, x, total);
+    let min_should_estimations = conditions.iter().map(estimate).collect_vec();
+    combine_min_should_estimations(&min_should_estimations, min_count, total)
+}
+
 fn estimate_must<F>(estimator: &F, conditions: &[Condition], total: usize) -> CardinalityEstimation
 where
     F: Fn(&Condition) -> CardinalityEstimation,
@@ -275,6 +367,8 @@ mod tests {
             geo_radius: None,
             values_count: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
         })
     }
 
@@ -342,6 +436,7 @@ mod tests {
     fn must_estimation_query_test() {
         let query = Filter {
             should: None,
+            min_should: None,
             must: Some(vec![
                 test_condition("color".to_owned()),
                 test_condition("size".to_owned()),
@@ -368,6 +463,7 @@ mod tests {
                 test_condition("color".to_owned()),
                 test_condition("size".to_owned()),
             ]),
+            min_should: None,
             must: None,
             must_not: None,
         };
@@ -387,6 +483,7 @@ mod tests {
                 test_condition("size".to_owned()),
                 test_condition("un-indexed".to_owned()),
             ]),
+            min_should: None,
             must: None,
             must_not: None,
         };
@@ -399,12 +496,76 @@ mod tests {
         assert!(estimation.min <= estimation.exp);
     }
 
+    #[test]
+    fn min_should_estimation_query_test() {
+        let query = Filter {
+            should: None,
+            min_should: Some(MinShould {
+                conditions: vec![
+                    test_condition("color".to_owned()),
+                    test_condition("size".to_owned()),
+                    test_condition("price".to_owned()),
+                ],
+                min_count: 2,
+            }),
+            must: None,
+            must_not: None,
+        };
+
+        let estimation = estimate_filter(&test_estimator, &query, TOTAL);
+        assert_eq!(estimation.primary_clauses.len(), 3);
+        assert_eq!(estimation.min, 0);
+        assert_eq!(estimation.exp, 24);
+        // Matched points must match either "size" or "price"
+        assert_eq!(estimation.max, 120);
+
+        let query = Filter {
+            should: None,
+            min_should: Some(MinShould {
+                conditions: vec![
+                    test_condition("color".to_owned()),
+                    test_condition("size".to_owned()),
+                    test_condition("un-indexed".to_owned()),
+                ],
+                min_count: 2,
+            }),
+            must: None,
+            must_not: None,
+        };
+
+        let estimation = estimate_filter(&test_estimator, &query, TOTAL);
+        assert_eq!(estimation.primary_clauses.len(), 0);
+        assert!(estimation.max <= TOTAL);
+        assert!(estimation.exp <= estimation.max);
+        assert!(estimation.min <= estimation.exp);
+    }
+
+    #[test]
+    fn test_combine_min_should_estimations() {
+        let estimations = vec![
+            CardinalityEstimation::exact(800),
+            CardinalityEstimation::exact(700),
+            CardinalityEstimation::exact(600),
+        ];
+
+        let estimation = combine_min_should_estimations(&estimations, 3, TOTAL);
+        assert_eq!(estimation.min, 100);
+        assert_eq!(estimation.max, 600);
+
+        let estimation = combine_min_should_estimations(&estimations, 0, TOTAL);
+        assert!(estimation.equals_min_exp_max(&CardinalityEstimation::exact(TOTAL)));
+
+        let estimation = combine_min_should_estimations(&estimations, 4, TOTAL);
+        assert!(estimation.equals_min_exp_max(&CardinalityEstimation::exact(0)));
+    }
+
     #[test]
     fn complex_estimation_query_test() {
         let query = Filter {
             should: Some(vec![
                 Condition::Filter(Filter {
                     should: None,
+                    min_should: None,
                     must: Some(vec![
                         test_condition("color".to_owned()),
                         test_condition("size".to_owned()),
@@ -413,6 +574,7 @@ mod tests {
                 }),
                 Condition::Filter(Filter {
                     should: None,
+                    min_should: None,
                     must: Some(vec![
                         test_condition("price".to_owned()),
                         test_condition("size".to_owned()),
@@ -420,6 +582,7 @@ mod tests {
                     must_not: None,
                 }),
             ]),
+            min_should: None,
             must: None,
             must_not: Some(vec![Condition::HasId(HasIdCondition {
                 has_id: HashSet::from_iter([1, 2, 3, 4, 5].into_iter().map(|x| x.into())),
@@ -437,6 +600,7 @@ mod tests {
     fn another_complex_estimation_query_test() {
         let query = Filter {
             should: None,
+            min_should: None,
             must: Some(vec![
                 Condition::Filter(Filter {
                     must: None,
@@ -444,6 +608,7 @@ mod tests {
                         test_condition("color".to_owned()),
                         test_condition("size".to_owned()),
                     ]),
+                    min_should: None,
                     must_not: None,
                 }),
                 Condition::Filter(Filter {
@@ -452,6 +617,7 @@ mod tests {
                         test_condition("price".to_owned()),
                         test_condition("size".to_owned()),
                     ]),
+                    min_should: None,
                     must_not: None,
                 }),
             ]),
@@ -5,6 +5,7 @@ use serde_json::Value;
 
 use crate::common::utils::IndexesMap;
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::map_index::parse_uuid;
 use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
@@ -14,8 +15,8 @@ use crate::payload_storage::query_checker::{
 };
 use crate::types::{
     AnyVariants, Condition, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPolygon,
-    GeoRadius, Match, MatchAny, MatchExcept, MatchText, MatchValue, OwnedPayloadRef,
-    PayloadContainer, Range, ValueVariants,
+    GeoRadius, GeoShape, Match, MatchAny, MatchExcept, MatchPhrase, MatchPrefix, MatchText,
+    MatchValue, OwnedPayloadRef, PayloadContainer, RangeInterface, ValueVariants,
 };
 
 pub fn condition_converter<'a>(
@@ -168,6 +169,22 @@ pub fn field_condition_index<'a>(
         return Some(checker);
     }
 
+    if let Some(checker) = field_condition
+        .geo_intersects
+        .clone()
+        .and_then(|cond| get_geo_intersects_checkers(index, cond))
+    {
+        return Some(checker);
+    }
+
+    if let Some(checker) = field_condition
+        .geo_within
+        .clone()
+        .and_then(|cond| get_geo_within_checkers(index, cond))
+    {
+        return Some(checker);
+    }
+
     None
 }
 
@@ -178,26 +195,48 @@ pub fn get_geo_polygon_checkers(
     let polygon_wrapper = geo_polygon.convert();
     match index {
         FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
-            geo_index.get_values(point_id).map_or(false, |values| {
-                values
-                    .iter()
-                    .any(|geo_point| polygon_wrapper.check_point(geo_point))
+            geo_index.get_values(point_id).map_or(false, |mut values| {
+                values.any(|geo_point| polygon_wrapper.check_point(&geo_point))
             })
         })),
         _ => None,
     }
 }
 
+pub fn get_geo_intersects_checkers(
+    index: &FieldIndex,
+    geo_shape: GeoShape,
+) -> Option<ConditionCheckerFn> {
+    let shape_wrapper = geo_shape.convert();
+    match index {
+        FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            geo_index.check_geo_shape(point_id, |shape| shape_wrapper.check_intersects(shape))
+        })),
+        _ => None,
+    }
+}
+
+pub fn get_geo_within_checkers(
+    index: &FieldIndex,
+    geo_shape: GeoShape,
+) -> Option<ConditionCheckerFn> {
+    let shape_wrapper = geo_shape.convert();
+    match index {
+        FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            geo_index.check_geo_shape(point_id, |shape| shape_wrapper.check_within(shape))
+        })),
+        _ => None,
+    }
+}
+
 pub fn get_geo_radius_checkers(
     index: &FieldIndex,
     geo_radius: GeoRadius,
 ) -> Option<ConditionCheckerFn> {
     match index {
         FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
-            geo_index.get_values(point_id).map_or(false, |values| {
-                values
-                    .iter()
-                    .any(|geo_point| geo_radius.check_point(geo_point))
+            geo_index.get_values(point_id).map_or(false, |mut values| {
+                values.any(|geo_point| geo_radius.check_point(&geo_point))
             })
         })),
         _ => None,
@@ -212,25 +251,38 @@ pub fn get_geo_bounding_box_checkers(
         FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
             match geo_index.get_values(point_id) {
                 None => false,
-                Some(values) => values
-                    .iter()
-                    .any(|geo_point| geo_bounding_box.check_point(geo_point)),
+                Some(mut values) => {
+                    values.any(|geo_point| geo_bounding_box.check_point(&geo_point))
+                }
             }
         })),
         _ => None,
     }
 }
 
-pub fn get_range_checkers(index: &FieldIndex, range: Range) -> Option<ConditionCheckerFn> {
+pub fn get_range_checkers(index: &FieldIndex, range: RangeInterface) -> Option<ConditionCheckerFn> {
+    if let RangeInterface::Keyword { keyword: range } = range {
+        return match index {
+            FieldIndex::KeywordIndex(index) => Some(Box::new(move |point_id: PointOffsetType| {
+                index
+                    .get_values(point_id)
+                    .map_or(false, |mut values| values.any(|k| range.check_range(&k)))
+            })),
+            _ => None,
+        };
+    }
+    let range = range.as_numeric_range()?;
     match index {
-        FieldIndex::IntIndex(num_index) => Some(Box::new(move |point_id: PointOffsetType| {
-            num_index.get_values(point_id).map_or(false, |values| {
-                values
-                    .iter()
-                    .copied()
-                    .any(|i| range.check_range(i as FloatPayloadType))
-            })
-        })),
+        FieldIndex::IntIndex(num_index) | FieldIndex::DatetimeIndex(num_index) => {
+            Some(Box::new(move |point_id: PointOffsetType| {
+                num_index.get_values(point_id).map_or(false, |values| {
+                    values
+                        .iter()
+                        .copied()
+                        .any(|i| range.check_range(i as FloatPayloadType))
+                })
+            }))
+        }
         FieldIndex::FloatIndex(num_index) => Some(Box::new(move |point_id: PointOffsetType| {
             num_index.get_values(point_id).map_or(false, |values| {
                 values.iter().copied().any(|i| range.check_range(i))
@@ -249,14 +301,22 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
                 Some(Box::new(move |point_id: PointOffsetType| {
                     index
                         .get_values(point_id)
-                        .map_or(false, |values| values.iter().any(|k| k == &keyword))
+                        .map_or(false, |mut values| values.any(|k| k == keyword))
                 }))
             }
             (ValueVariants::Integer(value), FieldIndex::IntMapIndex(index)) => {
                 Some(Box::new(move |point_id: PointOffsetType| {
                     index
                         .get_values(point_id)
-                        .map_or(false, |values| values.iter().any(|i| i == &value))
+                        .map_or(false, |mut values| values.any(|i| i == value))
+                }))
+            }
+            (ValueVariants::Keyword(keyword), FieldIndex::UuidMapIndex(index)) => {
+                let uuid = parse_uuid(&keyword);
+                Some(Box::new(move |point_id: PointOffsetType| {
+                    index
+                        .get_values(point_id)
+                        .map_or(false, |mut values| values.any(|value| Some(value) == uuid))
                 }))
             }
             (ValueVariants::Bool(is_true), FieldIndex::BinaryIndex(index)) => {
@@ -274,20 +334,33 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
             FieldIndex::FullTextIndex(full_text_index) => {
                 let parsed_query = full_text_index.parse_query(&text);
                 Some(Box::new(move |point_id: PointOffsetType| {
-                    full_text_index
-                        .get_doc(point_id)
-                        .map_or(false, |doc| parsed_query.check_match(doc))
+                    full_text_index.check_match(&parsed_query, point_id)
                 }))
             }
             _ => None,
         },
+        Match::Phrase(MatchPhrase { phrase }) => match index {
+            FieldIndex::FullTextIndex(full_text_index) => {
+                let parsed_phrase = full_text_index.parse_phrase(&phrase)?;
+                Some(Box::new(move |point_id: PointOffsetType| {
+                    full_text_index.check_phrase(&parsed_phrase, point_id)
+                }))
+            }
+            _ => None,
+        },
+        Match::Prefix(MatchPrefix { prefix }) => match index {
+            FieldIndex::KeywordIndex(index) => Some(Box::new(move |point_id: PointOffsetType| {
+                index.get_values(point_id).map_or(false, |mut values| {
+                    values.any(|k| k.starts_with(prefix.as_str()))
+                })
+            })),
+            _ => None,
+        },
         Match::Any(MatchAny { any }) => match (any, index) {
             (AnyVariants::Keywords(list), FieldIndex::KeywordIndex(index)) => {
                 Some(Box::new(move |point_id: PointOffsetType| {
-                    index.get_values(point_id).map_or(false, |values| {
-                        values
-                            .iter()
-                            .any(|k| list.iter().any(|s| s.as_str() == k.as_ref()))
+                    index.get_values(point_id).map_or(false, |mut values| {
+                        values.any(|k| list.iter().any(|s| s.as_str() == k.as_str()))
                     })
                 }))
             }
@@ -295,7 +368,15 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
                 Some(Box::new(move |point_id: PointOffsetType| {
                     index
                         .get_values(point_id)
-                        .map_or(false, |values| values.iter().any(|i| list.contains(i)))
+                        .map_or(false, |mut values| values.any(|i| list.contains(&i)))
+                }))
+            }
+            (AnyVariants::Keywords(list), FieldIndex::UuidMapIndex(index)) => {
+                let uuids: HashSet<_> = list.iter().filter_map(|s| parse_uuid(s)).collect();
+                Some(Box::new(move |point_id: PointOffsetType| {
+                    index
+                        .get_values(point_id)
+                        .map_or(false, |mut values| values.any(|u| uuids.contains(&u)))
                 }))
             }
             _ => None,
@@ -303,10 +384,8 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
         Match::Except(MatchExcept { except }) => match (except, index) {
             (AnyVariants::Keywords(list), FieldIndex::KeywordIndex(index)) => {
                 Some(Box::new(move |point_id: PointOffsetType| {
-                    index.get_values(point_id).map_or(false, |values| {
-                        values
-                            .iter()
-                            .any(|k| !list.iter().any(|s| s.as_str() == k.as_ref()))
+                    index.get_values(point_id).map_or(false, |mut values| {
+                        values.any(|k| !list.iter().any(|s| s.as_str() == k.as_str()))
                     })
                 }))
             }
@@ -314,7 +393,15 @@ pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<Condi
                 Some(Box::new(move |point_id: PointOffsetType| {
                     index
                         .get_values(point_id)
-                        .map_or(false, |values| values.iter().any(|i| !list.contains(i)))
+                        .map_or(false, |mut values| values.any(|i| !list.contains(&i)))
+                }))
+            }
+            (AnyVariants::Keywords(list), FieldIndex::UuidMapIndex(index)) => {
+                let uuids: HashSet<_> = list.iter().filter_map(|s| parse_uuid(s)).collect();
+                Some(Box::new(move |point_id: PointOffsetType| {
+                    index
+                        .get_values(point_id)
+                        .map_or(false, |mut values| values.any(|u| !uuids.contains(&u)))
                 }))
             }
             (_, index) => Some(Box::new(|point_id: PointOffsetType| {
@@ -8,6 +8,7 @@ use atomic_refcell::AtomicRefCell;
 use common::types::{PointOffsetType, ScoredPointOffset};
 use itertools::Itertools;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimId;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use sparse::index::inverted_index::InvertedIndex;
 use sparse::index::search_context::SearchContext;
@@ -16,6 +17,7 @@ use super::indices_tracker::IndicesTracker;
 use super::sparse_index_config::SparseIndexType;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
+use crate::data_types::sparse_statistics::SparseVectorStatistics;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::CardinalityEstimation;
@@ -161,6 +163,25 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         unique_record_ids.len()
     }
 
+    /// Collect document frequencies of the given dimensions, along with the number of vectors in the index
+    pub fn statistics(&self, indices: &[DimId]) -> SparseVectorStatistics {
+        let document_frequencies = indices
+            .iter()
+            .map(|dim_id| {
+                let frequency = self
+                    .indices_tracker
+                    .remap_index(*dim_id)
+                    .and_then(|remapped| self.inverted_index.posting_list_len(&remapped))
+                    .unwrap_or_default();
+                (*dim_id, frequency)
+            })
+            .collect();
+        SparseVectorStatistics {
+            vector_count: self.vector_storage.borrow().available_vector_count(),
+            document_frequencies,
+        }
+    }
+
     fn get_query_cardinality(&self, filter: &Filter) -> CardinalityEstimation {
         let vector_storage = self.vector_storage.borrow();
         let id_tracker = self.id_tracker.borrow();
@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::create_dir_all;
+use std::fs::{create_dir_all, remove_dir_all, rename};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -32,8 +32,8 @@ use crate::payload_storage::{FilterContext, PayloadStorage};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
     infer_collection_value_type, infer_value_type, Condition, FieldCondition, Filter,
-    IsEmptyCondition, IsNullCondition, Payload, PayloadContainer, PayloadField, PayloadFieldSchema,
-    PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
+    IsEmptyCondition, IsNullCondition, Match, Payload, PayloadContainer, PayloadField,
+    PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
 };
 
 pub const PAYLOAD_FIELD_INDEX_PATH: &str = "fields";
@@ -52,6 +52,8 @@ pub struct StructPayloadIndex {
     /// Used to select unique point ids
     visited_pool: VisitedPool,
     db: Arc<RwLock<DB>>,
+    /// Indexes of non-appendable segments may be stored on disk, see `on_disk` schema parameter
+    is_appendable: bool,
 }
 
 impl StructPayloadIndex {
@@ -89,6 +91,27 @@ impl StructPayloadIndex {
         indexes
     }
 
+    /// Condition of the filter, which selects points of a single tenant, if there is any
+    fn tenant_condition<'a>(&self, query: &'a Filter) -> Option<&'a FieldCondition> {
+        query
+            .must
+            .iter()
+            .flatten()
+            .find_map(|condition| match condition {
+                Condition::Field(field_condition)
+                    if matches!(field_condition.r#match, Some(Match::Value(_)))
+                        && self
+                            .config
+                            .indexed_fields
+                            .get(&field_condition.key)
+                            .map_or(false, |schema| schema.is_tenant()) =>
+                {
+                    Some(field_condition)
+                }
+                _ => None,
+            })
+    }
+
     fn config_path(&self) -> PathBuf {
         PayloadConfig::get_config_path(&self.path)
     }
@@ -98,11 +121,55 @@ impl StructPayloadIndex {
         self.config.save(&config_path)
     }
 
-    fn load_all_fields(&mut self, is_appendable: bool) -> OperationResult<()> {
+    /// Directory of the on-disk indexes of the field
+    fn field_index_dir(&self, field: PayloadKeyTypeRef) -> PathBuf {
+        // Field names may contain any characters, so only the safe ones are kept as is
+        let mut dir_name = String::with_capacity(field.len());
+        for byte in field.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' {
+                dir_name.push(byte as char);
+            } else {
+                dir_name.push_str(&format!("_{byte:02x}"));
+            }
+        }
+        self.path.join(PAYLOAD_FIELD_INDEX_PATH).join(dir_name)
+    }
+
+    fn is_on_disk(&self, payload_schema: &PayloadFieldSchema) -> bool {
+        !self.is_appendable && payload_schema.is_on_disk()
+    }
+
+    /// Replace freshly built or loaded indexes of the field with on-disk ones
+    fn convert_to_mmap(
+        &self,
+        field: PayloadKeyTypeRef,
+        indexes: Vec<FieldIndex>,
+    ) -> OperationResult<Vec<FieldIndex>> {
+        let dir = self.field_index_dir(field);
+        // Write into a temporary directory first, so an incomplete index is never opened
+        let tmp_dir = dir.with_extension("tmp");
+        if tmp_dir.exists() {
+            remove_dir_all(&tmp_dir)?;
+        }
+        for index in &indexes {
+            index.save_mmap(&tmp_dir)?;
+        }
+        if dir.exists() {
+            remove_dir_all(&dir)?;
+        }
+        rename(&tmp_dir, &dir)?;
+
+        indexes
+            .into_iter()
+            .map(|index| index.into_mmap(&dir))
+            .collect()
+    }
+
+    fn load_all_fields(&mut self) -> OperationResult<()> {
         let mut field_indexes: IndexesMap = Default::default();
 
         for (field, payload_schema) in &self.config.indexed_fields {
-            let field_index = self.load_from_db(field, payload_schema.to_owned(), is_appendable)?;
+            let field_index = self.load_from_db(field, payload_schema.to_owned())?;
             field_indexes.insert(field.clone(), field_index);
         }
         self.field_indexes = field_indexes;
@@ -113,9 +180,23 @@ impl StructPayloadIndex {
         &self,
         field: PayloadKeyTypeRef,
         payload_schema: PayloadFieldSchema,
-        is_appendable: bool,
     ) -> OperationResult<Vec<FieldIndex>> {
-        let mut indexes = index_selector(field, &payload_schema, self.db.clone(), is_appendable);
+        let is_on_disk = self.is_on_disk(&payload_schema);
+        let field_index_dir = self.field_index_dir(field);
+        if is_on_disk && field_index_dir.exists() {
+            return index_selector(field, &payload_schema, self.db.clone(), true)
+                .into_iter()
+                .map(|index| index.into_mmap(&field_index_dir))
+                .collect();
+        }
+
+        // On-disk indexes are written from the mutable ones
+        let mut indexes = index_selector(
+            field,
+            &payload_schema,
+            self.db.clone(),
+            self.is_appendable || is_on_disk,
+        );
 
         let mut is_loaded = true;
         for ref mut index in indexes.iter_mut() {
@@ -130,6 +211,10 @@ impl StructPayloadIndex {
             indexes = self.build_field_indexes(field, payload_schema)?;
         }
 
+        if is_on_disk {
+            indexes = self.convert_to_mmap(field, indexes)?;
+        }
+
         Ok(indexes)
     }
 
@@ -158,6 +243,7 @@ impl StructPayloadIndex {
             path: path.to_owned(),
             visited_pool: Default::default(),
             db,
+            is_appendable,
         };
 
         if !index.config_path().exists() {
@@ -165,7 +251,7 @@ impl StructPayloadIndex {
             index.save_config()?;
         }
 
-        index.load_all_fields(is_appendable)?;
+        index.load_all_fields()?;
 
         Ok(index)
     }
@@ -196,7 +282,11 @@ impl StructPayloadIndex {
         field: PayloadKeyTypeRef,
         payload_schema: PayloadFieldSchema,
     ) -> OperationResult<()> {
-        let field_indexes = self.build_field_indexes(field, payload_schema)?;
+        let is_on_disk = self.is_on_disk(&payload_schema);
+        let mut field_indexes = self.build_field_indexes(field, payload_schema)?;
+        if is_on_disk {
+            field_indexes = self.convert_to_mmap(field, field_indexes)?;
+        }
         self.field_indexes.insert(field.into(), field_indexes);
         Ok(())
     }
@@ -369,6 +459,10 @@ impl PayloadIndex for StructPayloadIndex {
                 index.clear()?;
             }
         }
+        let field_index_dir = self.field_index_dir(field);
+        if field_index_dir.exists() {
+            remove_dir_all(field_index_dir)?;
+        }
 
         self.save_config()?;
         Ok(())
@@ -394,6 +488,17 @@ impl PayloadIndex for StructPayloadIndex {
     fn query_points(&self, query: &Filter) -> Vec<PointOffsetType> {
         // Assume query is already estimated to be small enough so we can iterate over all matched ids
 
+        // Fast path: points of a single tenant are stored together, take them from its postings
+        if let Some(tenant_points) = self
+            .tenant_condition(query)
+            .and_then(|condition| self.query_field(condition))
+        {
+            let struct_filtered_context = self.struct_filtered_context(query);
+            return tenant_points
+                .filter(|&id| struct_filtered_context.check(id))
+                .collect();
+        }
+
         let query_cardinality = self.estimate_cardinality(query);
 
         if query_cardinality.primary_clauses.is_empty() {
@@ -550,6 +655,12 @@ impl PayloadIndex for StructPayloadIndex {
     }
 
     fn files(&self) -> Vec<PathBuf> {
-        vec![self.config_path()]
+        let mut files = vec![self.config_path()];
+        for indexes in self.field_indexes.values() {
+            for index in indexes {
+                files.extend(index.files());
+            }
+        }
+        files
     }
 }
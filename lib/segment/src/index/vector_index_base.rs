@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
+use atomic_refcell::AtomicRefCell;
 use common::types::{PointOffsetType, ScoredPointOffset};
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
@@ -49,6 +51,20 @@ pub enum VectorIndexEnum {
 }
 
 impl VectorIndexEnum {
+    /// Build the index, reusing the graph of one of the `old_indices` of the source segments
+    /// where possible
+    pub fn build_index_with_old_indices(
+        &mut self,
+        old_indices: &[Arc<AtomicRefCell<VectorIndexEnum>>],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        match self {
+            Self::HnswRam(index) => index.build_index_with_old_indices(old_indices, stopped),
+            Self::HnswMmap(index) => index.build_index_with_old_indices(old_indices, stopped),
+            Self::Plain(_) | Self::SparseRam(_) | Self::SparseMmap(_) => self.build_index(stopped),
+        }
+    }
+
     pub fn is_index(&self) -> bool {
         match self {
             Self::Plain(_) => false,
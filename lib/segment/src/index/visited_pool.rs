@@ -85,6 +85,15 @@ impl<'a> VisitedListHandle<'a> {
             .map_or(false, |x| *x >= self.visited_list.current_iter)
     }
 
+    /// Return the iteration in which the point was visited last, `0` if it was never visited
+    pub fn get_visit_iteration(&self, point_id: PointOffsetType) -> usize {
+        self.visited_list
+            .visit_counters
+            .get(point_id as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Updates visited list
     /// return `true` if point was visited before
     pub fn check_and_update_visited(&mut self, point_id: PointOffsetType) -> bool {
@@ -1,10 +1,13 @@
 //! Contains functions for interpreting filter queries and defining if given points pass the conditions
 
+use std::str::FromStr;
+
 use serde_json::Value;
 
 use crate::types::{
-    AnyVariants, FieldCondition, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, Match, MatchAny,
-    MatchExcept, MatchText, MatchValue, Range, ValueVariants, ValuesCount,
+    AnyVariants, DateTimePayloadType, DatetimeRange, FieldCondition, GeoBoundingBox, GeoPoint,
+    GeoPolygon, GeoRadius, GeoShape, KeywordRange, Match, MatchAny, MatchExcept, MatchPhrase,
+    MatchPrefix, MatchText, MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
 };
 
 pub trait ValueChecker {
@@ -52,6 +55,16 @@ impl ValueChecker for FieldCondition {
                 .geo_polygon
                 .as_ref()
                 .map_or(false, |condition| condition.check_match(payload));
+        res = res
+            || self.geo_intersects.as_ref().map_or(false, |condition| {
+                GeoShape::from_payload(payload)
+                    .map_or(false, |shape| condition.convert().check_intersects(&shape))
+            });
+        res = res
+            || self.geo_within.as_ref().map_or(false, |condition| {
+                GeoShape::from_payload(payload)
+                    .map_or(false, |shape| condition.convert().check_within(&shape))
+            });
         res = res
             || self
                 .values_count
@@ -84,6 +97,14 @@ impl ValueChecker for Match {
                 Value::String(stored) => stored.contains(text),
                 _ => false,
             },
+            Match::Phrase(MatchPhrase { phrase }) => match payload {
+                Value::String(stored) => stored.contains(phrase),
+                _ => false,
+            },
+            Match::Prefix(MatchPrefix { prefix }) => match payload {
+                Value::String(stored) => stored.starts_with(prefix.as_str()),
+                _ => false,
+            },
             Match::Any(MatchAny { any }) => match (payload, any) {
                 (Value::String(stored), AnyVariants::Keywords(list)) => list.contains(stored),
                 (Value::Number(stored), AnyVariants::Integers(list)) => stored
@@ -109,6 +130,16 @@ impl ValueChecker for Match {
     }
 }
 
+impl ValueChecker for RangeInterface {
+    fn check_match(&self, payload: &Value) -> bool {
+        match self {
+            RangeInterface::Float(range) => range.check_match(payload),
+            RangeInterface::DateTime(range) => range.check_match(payload),
+            RangeInterface::Keyword { keyword: range } => range.check_match(payload),
+        }
+    }
+}
+
 impl ValueChecker for Range {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -121,6 +152,26 @@ impl ValueChecker for Range {
     }
 }
 
+impl ValueChecker for DatetimeRange {
+    fn check_match(&self, payload: &Value) -> bool {
+        match payload {
+            Value::String(stored) => DateTimePayloadType::from_str(stored)
+                .map(|datetime| self.check_range(datetime))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
+impl ValueChecker for KeywordRange {
+    fn check_match(&self, payload: &Value) -> bool {
+        match payload {
+            Value::String(stored) => self.check_range(stored),
+            _ => false,
+        }
+    }
+}
+
 impl ValueChecker for GeoBoundingBox {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -256,4 +307,70 @@ mod tests {
         };
         assert!(gte_two_countries_query.check(&countries));
     }
+
+    #[test]
+    fn test_keyword_prefix_and_range() {
+        let regions = json!(["eu-west", "us-east"]);
+
+        assert!(Match::new_prefix("eu-").check(&regions));
+        assert!(!Match::new_prefix("ap-").check(&regions));
+        assert!(!Match::new_prefix("eu-").check(&json!(42)));
+
+        let sku_range = KeywordRange {
+            gte: Some("A100".to_string()),
+            lt: Some("A200".to_string()),
+            ..Default::default()
+        };
+        assert!(sku_range.check(&json!("A100")));
+        assert!(sku_range.check(&json!("A150-X")));
+        assert!(!sku_range.check(&json!("A200")));
+        assert!(!sku_range.check(&json!("B100")));
+        assert!(sku_range.check(&json!(["B100", "A199"])));
+    }
+
+    #[test]
+    fn test_geo_shape_matching() {
+        let delivery_zones = json!([
+            {
+                "type": "Polygon",
+                "coordinates": [
+                    [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]],
+                    [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0], [4.0, 4.0]]
+                ]
+            },
+            { "lon": 20.0, "lat": 20.0 }
+        ]);
+
+        let point = |lon, lat| {
+            serde_json::from_value::<GeoShape>(
+                json!({ "type": "Point", "coordinates": [lon, lat] }),
+            )
+            .unwrap()
+        };
+
+        let check_intersects = |shape: GeoShape| {
+            FieldCondition::new_geo_intersects("zone", shape).check(&delivery_zones)
+        };
+        assert!(check_intersects(point(2.0, 2.0)));
+        assert!(check_intersects(point(20.0, 20.0)));
+        // Inside of the hole
+        assert!(!check_intersects(point(5.0, 5.0)));
+        assert!(!check_intersects(point(15.0, 15.0)));
+
+        let line: GeoShape = serde_json::from_value(json!({
+            "type": "LineString",
+            "coordinates": [[-5.0, 5.0], [1.0, 5.0]]
+        }))
+        .unwrap();
+        assert!(check_intersects(line));
+
+        let region: GeoShape = serde_json::from_value(json!({
+            "type": "Polygon",
+            "coordinates": [[[-1.0, -1.0], [11.0, -1.0], [11.0, 11.0], [-1.0, 11.0], [-1.0, -1.0]]]
+        }))
+        .unwrap();
+        let within_region = FieldCondition::new_geo_within("zone", region);
+        assert!(within_region.check(&delivery_zones));
+        assert!(!within_region.check(&json!({ "lon": 20.0, "lat": 20.0 })));
+    }
 }
@@ -0,0 +1,498 @@
+mod page;
+
+use std::fs::{create_dir_all, remove_dir_all, File};
+use std::io::Write;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use common::types::PointOffsetType;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use self::page::{page_path, Page};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::payload_storage::PayloadStorage;
+use crate::types::{Payload, PayloadKeyTypeRef};
+use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
+
+#[cfg(debug_assertions)]
+const DEFAULT_PAGE_SIZE_BYTES: usize = 1024 * 1024; // 1Mb
+#[cfg(not(debug_assertions))]
+const DEFAULT_PAGE_SIZE_BYTES: usize = 32 * 1024 * 1024; // 32Mb
+
+const DEFAULT_BLOCK_SIZE_BYTES: usize = 128;
+
+const CONFIG_FILE_NAME: &str = "config.json";
+const TRACKER_PATH: &str = "tracker";
+
+#[derive(Serialize, Deserialize)]
+struct MmapPayloadStorageConfig {
+    block_size_bytes: usize,
+    page_size_blocks: usize,
+}
+
+/// Location of the compressed payload of a point
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+struct ValuePointer {
+    page_id: u32,
+    block_offset: u32,
+    /// Length of the compressed value in bytes, zero if the point has no payload
+    length: u32,
+}
+
+/// Mmap implementation of `PayloadStorage`, does not use RocksDB and does not keep payload in memory.
+///
+/// Payloads are serialized, compressed with LZ4 and written into runs of fixed size blocks of
+/// memory mapped pages. Occupied blocks are tracked by a free-space bitmap in each page.
+///
+/// Values are never overwritten in place: an update appends a new copy and switches the pointer.
+/// Blocks of the old copy are reused only after the switched pointer is flushed, so persisted
+/// pointers never reference overwritten data.
+pub struct MmapPayloadStorage {
+    config: MmapPayloadStorageConfig,
+    directory: PathBuf,
+    /// Pointer to the payload of each point
+    tracker: ChunkedMmapVectors<ValuePointer>,
+    /// Shared with flushers, so they also persist pages created after the flusher itself
+    pages: Arc<RwLock<Vec<Page>>>,
+    /// Replaced values, which are still referenced by pointers on disk
+    pending_free: Arc<Mutex<Vec<ValuePointer>>>,
+    /// Replaced values, which are not referenced anymore. Their blocks can be reused
+    released: Arc<Mutex<Vec<ValuePointer>>>,
+}
+
+impl MmapPayloadStorage {
+    fn config_file(directory: &Path) -> PathBuf {
+        directory.join(CONFIG_FILE_NAME)
+    }
+
+    fn ensure_config(directory: &Path) -> OperationResult<MmapPayloadStorageConfig> {
+        let config_file = Self::config_file(directory);
+        if !config_file.exists() {
+            let config = MmapPayloadStorageConfig {
+                block_size_bytes: DEFAULT_BLOCK_SIZE_BYTES,
+                page_size_blocks: DEFAULT_PAGE_SIZE_BYTES / DEFAULT_BLOCK_SIZE_BYTES,
+            };
+            let mut file = File::create(&config_file)?;
+            serde_json::to_writer(&mut file, &config)?;
+            file.flush()?;
+            Ok(config)
+        } else {
+            let file = File::open(&config_file)?;
+            Ok(serde_json::from_reader(file)?)
+        }
+    }
+
+    pub fn open(directory: &Path) -> OperationResult<Self> {
+        create_dir_all(directory)?;
+        let config = Self::ensure_config(directory)?;
+        let tracker = ChunkedMmapVectors::open(&directory.join(TRACKER_PATH), 1)?;
+
+        let mut pages = Vec::new();
+        loop {
+            let path = page_path(directory, pages.len() as u32);
+            if !path.exists() {
+                break;
+            }
+            pages.push(Page::open(&path, config.block_size_bytes)?);
+        }
+
+        // Blocks of replaced values are released in memory only, so the persisted bitmap may
+        // still have them occupied. Occupied blocks are restored from the persisted pointers instead
+        pages.iter_mut().for_each(Page::clear);
+        for point_id in 0..tracker.len() as PointOffsetType {
+            let pointer: ValuePointer = tracker.get(point_id)[0];
+            if pointer.length == 0 {
+                continue;
+            }
+            let page = pages.get_mut(pointer.page_id as usize).ok_or_else(|| {
+                OperationError::service_error(format!(
+                    "Payload page {} is missing",
+                    pointer.page_id
+                ))
+            })?;
+            let blocks = (pointer.length as usize).div_ceil(config.block_size_bytes);
+            page.mark(pointer.block_offset as usize, blocks, true);
+        }
+
+        Ok(Self {
+            config,
+            directory: directory.to_owned(),
+            tracker,
+            pages: Arc::new(RwLock::new(pages)),
+            pending_free: Default::default(),
+            released: Default::default(),
+        })
+    }
+
+    fn blocks_count(&self, length: usize) -> usize {
+        length.div_ceil(self.config.block_size_bytes)
+    }
+
+    fn get_pointer(&self, point_id: PointOffsetType) -> Option<ValuePointer> {
+        if point_id as usize >= self.tracker.len() {
+            return None;
+        }
+        let pointer = self.tracker.get(point_id)[0];
+        (pointer.length > 0).then_some(pointer)
+    }
+
+    /// Replace pointer of the point, blocks of the previous value are released on flush
+    fn set_pointer(
+        &mut self,
+        point_id: PointOffsetType,
+        pointer: ValuePointer,
+    ) -> OperationResult<()> {
+        if let Some(old_pointer) = self.get_pointer(point_id) {
+            self.pending_free.lock().push(old_pointer);
+        }
+        self.tracker.insert(point_id, &[pointer])
+    }
+
+    /// Mark blocks of values, which are not referenced on disk anymore, as free
+    fn reuse_released(&mut self) {
+        let released = mem::take(&mut *self.released.lock());
+        let mut pages = self.pages.write();
+        for pointer in released {
+            let blocks = self.blocks_count(pointer.length as usize);
+            pages[pointer.page_id as usize].mark(pointer.block_offset as usize, blocks, false);
+        }
+    }
+
+    /// Find free blocks for a value of `length` bytes, adds a new page if none of the pages fit
+    fn allocate(&mut self, length: usize) -> OperationResult<ValuePointer> {
+        let length = u32::try_from(length).map_err(|_| {
+            OperationError::service_error(format!("Payload of {length} bytes is too large"))
+        })?;
+
+        self.reuse_released();
+
+        let blocks = self.blocks_count(length as usize);
+        let mut pages = self.pages.write();
+        let free_space = pages
+            .iter()
+            .enumerate()
+            .find_map(|(page_id, page)| Some((page_id, page.find_free(blocks)?)));
+
+        let (page_id, block_offset) = match free_space {
+            Some(free_space) => free_space,
+            None => {
+                // Values larger than a page get a dedicated page of a sufficient size
+                let num_blocks = self.config.page_size_blocks.max(blocks);
+                let page_id = pages.len();
+                let path = page_path(&self.directory, page_id as u32);
+                pages.push(Page::create(
+                    &path,
+                    num_blocks,
+                    self.config.block_size_bytes,
+                )?);
+                (page_id, 0)
+            }
+        };
+
+        pages[page_id].mark(block_offset, blocks, true);
+        Ok(ValuePointer {
+            page_id: page_id as u32,
+            block_offset: block_offset as u32,
+            length,
+        })
+    }
+
+    fn read_value(&self, pointer: ValuePointer) -> OperationResult<Payload> {
+        let pages = self.pages.read();
+        let page = pages.get(pointer.page_id as usize).ok_or_else(|| {
+            OperationError::service_error(format!("Payload page {} is missing", pointer.page_id))
+        })?;
+        let compressed = page.read(pointer.block_offset as usize, pointer.length as usize)?;
+        let serialized = lz4_flex::decompress_size_prepended(compressed).map_err(|err| {
+            OperationError::service_error(format!("Failed to decompress payload: {err}"))
+        })?;
+        Ok(serde_cbor::from_slice(&serialized)?)
+    }
+
+    pub fn read_payload(&self, point_id: PointOffsetType) -> OperationResult<Option<Payload>> {
+        self.get_pointer(point_id)
+            .map(|pointer| self.read_value(pointer))
+            .transpose()
+    }
+
+    pub fn update_storage(
+        &mut self,
+        point_id: PointOffsetType,
+        payload: &Payload,
+    ) -> OperationResult<()> {
+        let serialized = serde_cbor::to_vec(payload)?;
+        let compressed = lz4_flex::compress_prepend_size(&serialized);
+
+        let pointer = self.allocate(compressed.len())?;
+        self.pages.write()[pointer.page_id as usize]
+            .write(pointer.block_offset as usize, &compressed);
+        self.set_pointer(point_id, pointer)
+    }
+
+    pub fn remove_from_storage(&mut self, point_id: PointOffsetType) -> OperationResult<()> {
+        if self.get_pointer(point_id).is_some() {
+            self.set_pointer(point_id, ValuePointer::default())?;
+        }
+        Ok(())
+    }
+
+    pub fn iter<F>(&self, mut callback: F) -> OperationResult<()>
+    where
+        F: FnMut(PointOffsetType, &Payload) -> OperationResult<bool>,
+    {
+        for point_id in 0..self.tracker.len() as PointOffsetType {
+            let Some(pointer) = self.get_pointer(point_id) else {
+                continue;
+            };
+            let do_continue = callback(point_id, &self.read_value(pointer)?)?;
+            if !do_continue {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files = vec![Self::config_file(&self.directory)];
+        files.extend(self.tracker.files());
+        files.extend(
+            (0..self.pages.read().len()).map(|page_id| page_path(&self.directory, page_id as u32)),
+        );
+        files
+    }
+}
+
+impl PayloadStorage for MmapPayloadStorage {
+    fn assign_all(&mut self, point_id: PointOffsetType, payload: &Payload) -> OperationResult<()> {
+        self.update_storage(point_id, payload)
+    }
+
+    fn assign(&mut self, point_id: PointOffsetType, payload: &Payload) -> OperationResult<()> {
+        match self.read_payload(point_id)? {
+            Some(mut point_payload) => {
+                point_payload.merge(payload);
+                self.update_storage(point_id, &point_payload)
+            }
+            None => self.update_storage(point_id, payload),
+        }
+    }
+
+    fn payload(&self, point_id: PointOffsetType) -> OperationResult<Payload> {
+        Ok(self.read_payload(point_id)?.unwrap_or_default())
+    }
+
+    fn delete(
+        &mut self,
+        point_id: PointOffsetType,
+        key: PayloadKeyTypeRef,
+    ) -> OperationResult<Vec<Value>> {
+        match self.read_payload(point_id)? {
+            Some(mut payload) => {
+                let res = payload.remove(key);
+                if !res.is_empty() {
+                    self.update_storage(point_id, &payload)?;
+                }
+                Ok(res)
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    fn drop(&mut self, point_id: PointOffsetType) -> OperationResult<Option<Payload>> {
+        let payload = self.read_payload(point_id)?;
+        self.remove_from_storage(point_id)?;
+        Ok(payload)
+    }
+
+    fn wipe(&mut self) -> OperationResult<()> {
+        self.pages.write().clear();
+        remove_dir_all(&self.directory)?;
+        // Replaces shared lists too, so flushers created before the wipe can't release new blocks
+        *self = Self::open(&self.directory)?;
+        Ok(())
+    }
+
+    fn flusher(&self) -> Flusher {
+        let pages = self.pages.clone();
+        let tracker_flusher = self.tracker.flusher();
+        let to_release = mem::take(&mut *self.pending_free.lock());
+        let pending_free = self.pending_free.clone();
+        let released = self.released.clone();
+        Box::new(move || {
+            // Values must be persisted before the pointers referencing them.
+            // Pages are listed at flush time, as the tracker may already point into new pages
+            let pages_flushers: Vec<_> = pages.read().iter().map(Page::flusher).collect();
+            let flushed = pages_flushers
+                .into_iter()
+                .try_for_each(|flusher| flusher())
+                .and_then(|()| tracker_flusher());
+            // Replaced values can be overwritten only when no persisted pointer references them
+            let target = if flushed.is_ok() {
+                released
+            } else {
+                pending_free
+            };
+            target.lock().extend(to_release);
+            flushed
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::StdRng;
+    use rand::{Rng, SeedableRng};
+    use serde_json::json;
+    use tempfile::Builder;
+
+    use super::*;
+
+    fn random_payload(rng: &mut StdRng, size: usize) -> Payload {
+        let text: String = (0..size).map(|_| rng.gen_range('a'..='z')).collect();
+        json!({ "text": text, "number": rng.gen_range(0..1000) }).into()
+    }
+
+    #[test]
+    fn test_mmap_payload_storage() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_points = 2000;
+
+        let mut payloads: Vec<_> = (0..num_points)
+            .map(|_| random_payload(&mut rng, 1000))
+            .collect();
+
+        // Small pages, so payloads are spread over many of them
+        let config = MmapPayloadStorageConfig {
+            block_size_bytes: DEFAULT_BLOCK_SIZE_BYTES,
+            page_size_blocks: 1024,
+        };
+        let config_file = File::create(MmapPayloadStorage::config_file(dir.path())).unwrap();
+        serde_json::to_writer(config_file, &config).unwrap();
+
+        {
+            let mut storage = MmapPayloadStorage::open(dir.path()).unwrap();
+            for (point_id, payload) in payloads.iter().enumerate() {
+                storage
+                    .assign(point_id as PointOffsetType, payload)
+                    .unwrap();
+            }
+            assert!(
+                storage.pages.read().len() > 1,
+                "must have multiple pages to test"
+            );
+
+            payloads[10] = random_payload(&mut rng, 10);
+            storage.assign_all(10, &payloads[10]).unwrap();
+
+            let partial: Payload = json!({ "color": "red" }).into();
+            storage.assign(20, &partial).unwrap();
+            payloads[20].merge(&partial);
+
+            assert_eq!(storage.delete(30, "text").unwrap().len(), 1);
+            payloads[30].remove("text");
+
+            assert_eq!(storage.drop(40).unwrap(), Some(payloads[40].clone()));
+            payloads[40] = Payload::default();
+
+            // Payload larger than a page
+            let page_size = storage.config.page_size_blocks * storage.config.block_size_bytes;
+            payloads[50] = random_payload(&mut rng, 2 * page_size);
+            storage.assign_all(50, &payloads[50]).unwrap();
+
+            storage.flusher()().unwrap();
+        }
+
+        {
+            let storage = MmapPayloadStorage::open(dir.path()).unwrap();
+            for (point_id, payload) in payloads.iter().enumerate() {
+                assert_eq!(
+                    &storage.payload(point_id as PointOffsetType).unwrap(),
+                    payload
+                );
+            }
+            assert_eq!(
+                storage.payload(num_points as PointOffsetType).unwrap(),
+                Payload::default()
+            );
+
+            let mut count = 0;
+            storage
+                .iter(|point_id, payload| {
+                    assert_eq!(payload, &payloads[point_id as usize]);
+                    count += 1;
+                    Ok(true)
+                })
+                .unwrap();
+            assert_eq!(count, num_points - 1);
+        }
+    }
+
+    #[test]
+    fn test_mmap_payload_storage_reuse_blocks_after_reopen() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let payload = random_payload(&mut rng, 100);
+
+        let replaced_pointer = {
+            let mut storage = MmapPayloadStorage::open(dir.path()).unwrap();
+            storage.assign_all(0, &payload).unwrap();
+            storage.assign_all(1, &payload).unwrap();
+            let replaced_pointer = storage.get_pointer(0).unwrap();
+
+            storage.assign_all(0, &payload).unwrap();
+            storage.remove_from_storage(1).unwrap();
+            storage.flusher()().unwrap();
+            replaced_pointer
+        };
+
+        // Released blocks are not persisted, they must be recovered from the live pointers
+        let mut storage = MmapPayloadStorage::open(dir.path()).unwrap();
+        storage.assign_all(2, &payload).unwrap();
+        assert_eq!(storage.get_pointer(2), Some(replaced_pointer));
+
+        storage.assign_all(3, &payload).unwrap();
+        assert_ne!(storage.get_pointer(3), storage.get_pointer(0));
+
+        for point_id in [0, 2, 3] {
+            assert_eq!(storage.payload(point_id).unwrap(), payload);
+        }
+        assert_eq!(storage.payload(1).unwrap(), Payload::default());
+    }
+
+    #[test]
+    fn test_mmap_payload_storage_reuse_blocks() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut storage = MmapPayloadStorage::open(dir.path()).unwrap();
+
+        let payload = random_payload(&mut rng, 100);
+        storage.assign_all(0, &payload).unwrap();
+        let first_pointer = storage.get_pointer(0).unwrap();
+
+        // Replaced value is still referenced on disk, its blocks must not be reused yet
+        storage.assign_all(0, &payload).unwrap();
+        storage.assign_all(1, &payload).unwrap();
+        assert_ne!(storage.get_pointer(0), Some(first_pointer));
+        assert_ne!(storage.get_pointer(1), Some(first_pointer));
+
+        storage.flusher()().unwrap();
+
+        storage.assign_all(2, &payload).unwrap();
+        assert_eq!(storage.get_pointer(2), Some(first_pointer));
+
+        for point_id in 0..3 {
+            assert_eq!(storage.payload(point_id).unwrap(), payload);
+        }
+
+        storage.wipe().unwrap();
+        assert_eq!(storage.payload(0).unwrap(), Payload::default());
+        storage.assign_all(0, &payload).unwrap();
+        assert_eq!(storage.payload(0).unwrap(), payload);
+    }
+}
@@ -0,0 +1,154 @@
+use std::path::{Path, PathBuf};
+
+use bitvec::prelude::{BitSlice, Lsb0};
+use memory::mmap_ops::{create_and_ensure_length, open_write_mmap};
+
+use crate::common::mmap_type::MmapSlice;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::Flusher;
+
+const PAGE_FILE_PREFIX: &str = "page_";
+const PAGE_FILE_SUFFIX: &str = ".dat";
+
+/// Size of the page header, which holds the number of blocks in the page
+const HEADER_SIZE: usize = std::mem::size_of::<u64>();
+
+pub fn page_path(directory: &Path, page_id: u32) -> PathBuf {
+    directory.join(format!("{PAGE_FILE_PREFIX}{page_id}{PAGE_FILE_SUFFIX}"))
+}
+
+/// Memory mapped page of fixed size blocks
+///
+/// Layout of the page file:
+///
+/// ```text
+/// | num_blocks: u64 | free-space bitmap: 1 bit per block | blocks: num_blocks * block_size |
+/// ```
+///
+/// Bit in the bitmap is set if the corresponding block is occupied by a value.
+pub struct Page {
+    data: MmapSlice<u8>,
+    num_blocks: usize,
+    block_size: usize,
+    /// Number of unoccupied blocks, kept in memory to skip full pages quickly
+    free_blocks: usize,
+}
+
+fn bitmap_size_bytes(num_blocks: usize) -> usize {
+    num_blocks.div_ceil(u64::BITS as usize) * std::mem::size_of::<u64>()
+}
+
+impl Page {
+    pub fn create(path: &Path, num_blocks: usize, block_size: usize) -> OperationResult<Self> {
+        let file_size = HEADER_SIZE + bitmap_size_bytes(num_blocks) + num_blocks * block_size;
+        create_and_ensure_length(path, file_size)?;
+        let mmap = open_write_mmap(path)?;
+        let mut data = unsafe { MmapSlice::try_from(mmap)? };
+        data[..HEADER_SIZE].copy_from_slice(&(num_blocks as u64).to_le_bytes());
+        Ok(Self {
+            data,
+            num_blocks,
+            block_size,
+            free_blocks: num_blocks,
+        })
+    }
+
+    pub fn open(path: &Path, block_size: usize) -> OperationResult<Self> {
+        let mmap = open_write_mmap(path)?;
+        let data: MmapSlice<u8> = unsafe { MmapSlice::try_from(mmap)? };
+
+        let header = data.get(..HEADER_SIZE).ok_or_else(|| {
+            OperationError::service_error(format!("Page {} is truncated", path.display()))
+        })?;
+        let num_blocks = u64::from_le_bytes(header.try_into().unwrap()) as usize;
+        let expected_size = HEADER_SIZE + bitmap_size_bytes(num_blocks) + num_blocks * block_size;
+        if data.len() < expected_size {
+            return Err(OperationError::service_error(format!(
+                "Page {} is truncated: expected {expected_size} bytes, found {}",
+                path.display(),
+                data.len(),
+            )));
+        }
+
+        let mut page = Self {
+            data,
+            num_blocks,
+            block_size,
+            free_blocks: 0,
+        };
+        page.free_blocks = page.bitmap().count_zeros();
+        Ok(page)
+    }
+
+    fn bitmap(&self) -> &BitSlice<u8, Lsb0> {
+        let bytes = &self.data[HEADER_SIZE..HEADER_SIZE + bitmap_size_bytes(self.num_blocks)];
+        &BitSlice::from_slice(bytes)[..self.num_blocks]
+    }
+
+    fn bitmap_mut(&mut self) -> &mut BitSlice<u8, Lsb0> {
+        let range = HEADER_SIZE..HEADER_SIZE + bitmap_size_bytes(self.num_blocks);
+        let num_blocks = self.num_blocks;
+        &mut BitSlice::from_slice_mut(&mut self.data[range])[..num_blocks]
+    }
+
+    fn blocks_offset(&self) -> usize {
+        HEADER_SIZE + bitmap_size_bytes(self.num_blocks)
+    }
+
+    /// Find the first run of `blocks` consecutive free blocks
+    pub fn find_free(&self, blocks: usize) -> Option<usize> {
+        if blocks > self.free_blocks {
+            return None;
+        }
+        let bitmap = self.bitmap();
+        let mut start = 0;
+        while start + blocks <= self.num_blocks {
+            match bitmap[start..start + blocks].last_one() {
+                None => return Some(start),
+                // Any run containing an occupied block can't be used, skip past it
+                Some(occupied) => start += occupied + 1,
+            }
+        }
+        None
+    }
+
+    /// Mark `blocks` blocks starting at `block_offset` as occupied or free
+    pub fn mark(&mut self, block_offset: usize, blocks: usize, occupied: bool) {
+        let bitmap = self.bitmap_mut();
+        let range = &mut bitmap[block_offset..block_offset + blocks];
+        let changed = if occupied {
+            range.count_zeros()
+        } else {
+            range.count_ones()
+        };
+        range.fill(occupied);
+        if occupied {
+            self.free_blocks -= changed;
+        } else {
+            self.free_blocks += changed;
+        }
+    }
+
+    /// Mark all blocks of the page as free
+    pub fn clear(&mut self) {
+        self.mark(0, self.num_blocks, false);
+    }
+
+    pub fn write(&mut self, block_offset: usize, value: &[u8]) {
+        let start = self.blocks_offset() + block_offset * self.block_size;
+        self.data[start..start + value.len()].copy_from_slice(value);
+    }
+
+    pub fn read(&self, block_offset: usize, length: usize) -> OperationResult<&[u8]> {
+        let start = self.blocks_offset() + block_offset * self.block_size;
+        self.data.get(start..start + length).ok_or_else(|| {
+            OperationError::service_error(format!(
+                "Value at block {block_offset} with length {length} is out of page bounds",
+            ))
+        })
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        self.data.flusher()
+    }
+}
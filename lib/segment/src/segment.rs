@@ -11,8 +11,10 @@ use common::types::{PointOffsetType, ScoredPointOffset};
 use io::file_operations::{atomic_save_json, read_json};
 use memory::mmap_ops;
 use parking_lot::{Mutex, RwLock};
+use rand::seq::IteratorRandom;
 use rocksdb::DB;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimId;
 use tar::Builder;
 use uuid::Uuid;
 
@@ -24,13 +26,19 @@ use crate::common::version::{StorageVersion, VERSION_FILE};
 use crate::common::{
     check_named_vectors, check_query_vectors, check_stopped, check_vector, check_vector_name,
 };
+use crate::data_types::facets::{top_facet_hits, FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::vectors::{QueryVector, Vector};
+use crate::data_types::order_by::{Direction, OrderBy, OrderValue};
+use crate::data_types::sparse_statistics::SparseVectorStatistics;
+use crate::data_types::text_statistics::TextStatistics;
+use crate::data_types::vectors::{MultiDenseVector, QueryVector, Vector};
 use crate::entry::entry_point::SegmentEntry;
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::CardinalityEstimation;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
+use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::spaces::tools::peek_top_smallest_iterable;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
@@ -78,6 +86,8 @@ pub struct Segment {
     pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
     pub vector_data: HashMap<String, VectorData>,
     pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    /// Storage of point payloads, shared with the payload index
+    pub payload_storage: Arc<AtomicRefCell<PayloadStorageEnum>>,
     /// Shows if it is possible to insert more points into this segment
     pub appendable_flag: bool,
     /// Shows what kind of indexes and storages are used in this segment
@@ -105,6 +115,8 @@ impl VectorData {
 
         let storage_task = match &*self.vector_storage.borrow() {
             VectorStorageEnum::Memmap(storage) => storage.prefault_mmap_pages(),
+            VectorStorageEnum::MemmapByte(storage) => storage.prefault_mmap_pages(),
+            VectorStorageEnum::MemmapHalf(storage) => storage.prefault_mmap_pages(),
             _ => None,
         };
 
@@ -209,9 +221,19 @@ impl Segment {
                     let dim = vector_storage.vector_dim();
                     let vector: Vector = match *vector_storage {
                         VectorStorageEnum::DenseSimple(_)
+                        | VectorStorageEnum::DenseSimpleByte(_)
+                        | VectorStorageEnum::DenseSimpleHalf(_)
                         | VectorStorageEnum::Memmap(_)
-                        | VectorStorageEnum::AppendableMemmap(_) => vec![1.0; dim].into(),
+                        | VectorStorageEnum::MemmapByte(_)
+                        | VectorStorageEnum::MemmapHalf(_)
+                        | VectorStorageEnum::AppendableMemmap(_)
+                        | VectorStorageEnum::AppendableMemmapByte(_)
+                        | VectorStorageEnum::AppendableMemmapHalf(_) => vec![1.0; dim].into(),
                         VectorStorageEnum::SparseSimple(_) => SparseVector::default().into(),
+                        VectorStorageEnum::MultiDenseSimple(_)
+                        | VectorStorageEnum::AppendableMemmapMultiDense(_) => {
+                            MultiDenseVector::new(vec![1.0; dim], dim).into()
+                        }
                     };
                     vector_storage.insert_vector(new_index, vector.to_vec_ref())?;
                     vector_storage.delete_vector(new_index)?;
@@ -546,6 +568,20 @@ impl Segment {
         }
     }
 
+    /// Full-text index of the field, which is required to resolve text queries
+    fn full_text_index<'a>(
+        payload_index: &'a StructPayloadIndex,
+        key: &PayloadKeyType,
+    ) -> OperationResult<&'a FullTextIndex> {
+        payload_index
+            .field_indexes
+            .get(key)
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_full_text()))
+            .ok_or_else(|| OperationError::MissingFullTextIndexForTextQuery {
+                key: key.to_string(),
+            })
+    }
+
     /// Converts raw ScoredPointOffset search result into ScoredPoint result
     fn process_search_result(
         &self,
@@ -815,7 +851,7 @@ impl SegmentEntry for Segment {
     ) -> OperationResult<bool> {
         debug_assert!(self.is_appendable());
         check_named_vectors(&vectors, &self.segment_config)?;
-        vectors.preprocess(|name| self.segment_config.distance(name).unwrap());
+        vectors.preprocess(|name| self.segment_config.vector_data.get(name).unwrap());
         let stored_internal_point = self.id_tracker.borrow().internal_id(point_id);
         self.handle_version_and_failure(op_num, stored_internal_point, |segment| {
             if let Some(existing_internal_id) = stored_internal_point {
@@ -868,7 +904,7 @@ impl SegmentEntry for Segment {
         mut vectors: NamedVectors,
     ) -> OperationResult<bool> {
         check_named_vectors(&vectors, &self.segment_config)?;
-        vectors.preprocess(|name| self.segment_config.distance(name).unwrap());
+        vectors.preprocess(|name| self.segment_config.vector_data.get(name).unwrap());
         let internal_id = self.id_tracker.borrow().internal_id(point_id);
         match internal_id {
             None => Err(OperationError::PointIdError {
@@ -1078,6 +1114,176 @@ impl SegmentEntry for Segment {
         }
     }
 
+    fn read_random_filtered(&self, limit: usize, filter: Option<&Filter>) -> Vec<PointIdType> {
+        let mut rng = rand::thread_rng();
+        match filter {
+            None => self
+                .id_tracker
+                .borrow()
+                .iter_from(None)
+                .map(|x| x.0)
+                .choose_multiple(&mut rng, limit),
+            Some(condition) => {
+                let payload_index = self.payload_index.borrow();
+                let id_tracker = self.id_tracker.borrow();
+                payload_index
+                    .query_points(condition)
+                    .into_iter()
+                    .filter_map(|internal_id| id_tracker.external_id(internal_id))
+                    .choose_multiple(&mut rng, limit)
+            }
+        }
+    }
+
+    fn read_ordered_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        order_by: &'a OrderBy,
+        offset: Option<PointIdType>,
+    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let numeric_index = payload_index
+            .field_indexes
+            .get(&order_by.key)
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+            .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
+                key: order_by.key.to_string(),
+            })?;
+
+        let filter_context = filter.map(|f| payload_index.filter_context(f));
+
+        let direction = order_by.direction();
+        let range_iter = numeric_index.stream_range(&order_by.as_range());
+        let values_iter: Box<dyn Iterator<Item = (OrderValue, PointOffsetType)>> = match direction {
+            Direction::Asc => Box::new(range_iter),
+            Direction::Desc => Box::new(range_iter.rev()),
+        };
+
+        let start_from = order_by.start_from.map(OrderValue::from);
+        let limit = limit.unwrap_or(usize::MAX);
+
+        let mut seen = HashSet::new();
+        let mut page: Vec<(OrderValue, PointIdType)> = Vec::new();
+
+        for (value, internal_id) in values_iter {
+            // All points with the same value as the last one on the page are required
+            // to keep the order stable, as ties are sorted by id
+            if page.len() >= limit && page.last().map(|(last, _)| *last) != Some(value) {
+                break;
+            }
+            if id_tracker.is_deleted_point(internal_id) {
+                continue;
+            }
+            if let Some(filter_context) = &filter_context {
+                if !filter_context.check(internal_id) {
+                    continue;
+                }
+            }
+            let Some(external_id) = id_tracker.external_id(internal_id) else {
+                continue;
+            };
+            if let (Some(start_from), Some(offset)) = (start_from, offset) {
+                if value == start_from && external_id < offset {
+                    continue;
+                }
+            }
+            // Points with multiple values are returned once, with the first value in the order
+            if !seen.insert(internal_id) {
+                continue;
+            }
+            page.push((value, external_id));
+        }
+
+        page.sort_unstable_by(|(value_a, id_a), (value_b, id_b)| {
+            value_a
+                .cmp_directed(value_b, direction)
+                .then_with(|| id_a.cmp(id_b))
+        });
+        page.truncate(limit);
+
+        Ok(page)
+    }
+
+    fn facet(
+        &self,
+        request: &FacetParams,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<HashMap<FacetValue, usize>> {
+        let payload_index = self.payload_index.borrow();
+
+        let facet_index = payload_index
+            .field_indexes
+            .get(&request.key)
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_facet_index()))
+            .ok_or_else(|| OperationError::MissingMapIndexForFacet {
+                key: request.key.to_string(),
+            })?;
+
+        let counts = match &request.filter {
+            // Deleted points are removed from the index, so its postings can be counted as is
+            None => facet_index.counts_per_value(),
+            Some(filter) => {
+                let mut counts = HashMap::new();
+                for internal_id in payload_index.query_points(filter) {
+                    check_stopped(is_stopped)?;
+                    for value in facet_index.get_values(internal_id) {
+                        *counts.entry(value).or_default() += 1;
+                    }
+                }
+                counts
+            }
+        };
+
+        if request.exact {
+            return Ok(counts);
+        }
+        Ok(top_facet_hits(counts, request.limit)
+            .into_iter()
+            .map(|hit| (hit.value, hit.count))
+            .collect())
+    }
+
+    fn text_statistics(&self, key: &PayloadKeyType, text: &str) -> OperationResult<TextStatistics> {
+        let payload_index = self.payload_index.borrow();
+        let full_text_index = Self::full_text_index(&payload_index, key)?;
+        Ok(full_text_index.text_statistics(text))
+    }
+
+    fn text_search(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        statistics: &TextStatistics,
+        filter: Option<&Filter>,
+        top: usize,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let internal_result = {
+            let payload_index = self.payload_index.borrow();
+            let id_tracker = self.id_tracker.borrow();
+            let full_text_index = Self::full_text_index(&payload_index, key)?;
+            let filter_context = filter.map(|f| payload_index.filter_context(f));
+            full_text_index.search_bm25(
+                text,
+                statistics,
+                |internal_id| {
+                    !id_tracker.is_deleted_point(internal_id)
+                        && filter_context
+                            .as_ref()
+                            .map_or(true, |filter_context| filter_context.check(internal_id))
+                },
+                top,
+            )
+        };
+        self.process_search_result(
+            &internal_result,
+            &WithPayload::default(),
+            &WithVector::Bool(false),
+        )
+    }
+
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let id_tracker = self.id_tracker.borrow();
         let iterator = id_tracker.iter_from(from).map(|x| x.0);
@@ -1410,6 +1616,21 @@ impl SegmentEntry for Segment {
             .collect()
     }
 
+    fn sparse_vector_statistics(
+        &self,
+        vector_name: &str,
+        indices: &[DimId],
+    ) -> OperationResult<SparseVectorStatistics> {
+        check_vector_name(vector_name, &self.segment_config)?;
+        match &*self.vector_data[vector_name].vector_index.borrow() {
+            VectorIndexEnum::SparseRam(index) => Ok(index.statistics(indices)),
+            VectorIndexEnum::SparseMmap(index) => Ok(index.statistics(indices)),
+            VectorIndexEnum::Plain(_)
+            | VectorIndexEnum::HnswRam(_)
+            | VectorIndexEnum::HnswMmap(_) => Err(OperationError::WrongSparse),
+        }
+    }
+
     fn take_snapshot(
         &self,
         temp_path: &Path,
@@ -1504,6 +1725,15 @@ impl SegmentEntry for Segment {
             }
         }
 
+        for file in self.id_tracker.borrow().files() {
+            utils::tar::append_file_relative_to_base(
+                &mut builder,
+                &self.current_path,
+                &file,
+                &files,
+            )?;
+        }
+
         for file in self.payload_index.borrow().files() {
             utils::tar::append_file_relative_to_base(
                 &mut builder,
@@ -1513,6 +1743,15 @@ impl SegmentEntry for Segment {
             )?;
         }
 
+        for file in self.payload_storage.borrow().files() {
+            utils::tar::append_file_relative_to_base(
+                &mut builder,
+                &self.current_path,
+                &file,
+                &files,
+            )?;
+        }
+
         utils::tar::append_file(
             &mut builder,
             &self.current_path.join(SEGMENT_STATE_FILE),
@@ -1570,13 +1809,16 @@ impl Drop for Segment {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
     use tempfile::Builder;
 
     use super::*;
     use crate::common::operation_error::OperationError::PointIdError;
     use crate::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
     use crate::segment_constructor::{build_segment, load_segment};
-    use crate::types::{Distance, Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+    use crate::types::{
+        Distance, Indexes, PayloadStorageType, SegmentConfig, VectorDataConfig, VectorStorageType,
+    };
 
     // no longer valid since users are now allowed to store arbitrary json objects.
     // TODO(gvelo): add tests for invalid payload types on indexed fields.
@@ -1625,6 +1867,8 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -1698,6 +1942,8 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -1768,8 +2014,11 @@ mod tests {
         assert!(results_with_invalid_filter.is_empty());
     }
 
-    #[test]
-    fn test_snapshot() {
+    #[rstest]
+    fn test_snapshot(
+        #[values(PayloadStorageType::InMemory, PayloadStorageType::Mmap)]
+        payload_storage_type: PayloadStorageType,
+    ) {
         let data = r#"
         {
             "name": "John Doe",
@@ -1790,10 +2039,12 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
-            payload_storage_type: Default::default(),
+            payload_storage_type,
         };
 
         let mut segment = build_segment(segment_base_dir.path(), &config, true).unwrap();
@@ -1882,6 +2133,8 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -1914,6 +2167,8 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2009,6 +2264,8 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    multivector_config: None,
+                    datatype: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2062,6 +2319,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
                 (
@@ -2072,6 +2331,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
             ]),
@@ -2168,6 +2429,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
                 (
@@ -2178,6 +2441,8 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        multivector_config: None,
+                        datatype: None,
                     },
                 ),
             ]),
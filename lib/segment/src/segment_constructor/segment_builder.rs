@@ -2,16 +2,24 @@ use std::cmp;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
-use super::get_vector_storage_path;
+use atomic_refcell::AtomicRefCell;
+use common::types::PointOffsetType;
+use serde_json::Value;
+
+use super::{get_vector_storage_path, ID_TRACKER_PATH};
 use crate::common::error_logging::LogError;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::rocksdb_wrapper::{DatabaseColumnWrapper, DB_MAPPING_CF, DB_VERSIONS_CF};
+use crate::data_types::facets::FacetValue;
 use crate::entry::entry_point::SegmentEntry;
+use crate::id_tracker::immutable_id_tracker::ImmutableIdTracker;
 use crate::index::hnsw_index::max_rayon_threads;
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::{PayloadIndex, VectorIndexEnum};
 use crate::segment::Segment;
 use crate::segment_constructor::{build_segment, load_segment};
-use crate::types::{Indexes, PayloadFieldSchema, PayloadKeyType, SegmentConfig};
+use crate::types::{Indexes, PayloadContainer, PayloadFieldSchema, PayloadKeyType, SegmentConfig};
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::VectorStorage;
 
@@ -21,6 +29,8 @@ pub struct SegmentBuilder {
     pub destination_path: PathBuf,
     pub temp_path: PathBuf,
     pub indexed_fields: HashMap<PayloadKeyType, PayloadFieldSchema>,
+    /// Vector indexes of the source segments, which may be reused to build the new indexes
+    old_indices: HashMap<String, Vec<Arc<AtomicRefCell<VectorIndexEnum>>>>,
 }
 
 impl SegmentBuilder {
@@ -39,6 +49,7 @@ impl SegmentBuilder {
             destination_path,
             temp_path,
             indexed_fields: Default::default(),
+            old_indices: Default::default(),
         })
     }
 
@@ -54,15 +65,97 @@ impl SegmentBuilder {
     /// * `bool` - if `true` - data successfully added, if `false` - process was interrupted
     ///
     pub fn update_from(&mut self, other: &Segment, stopped: &AtomicBool) -> OperationResult<bool> {
-        let self_segment = match &mut self.segment {
-            Some(segment) => segment,
-            None => {
-                return Err(OperationError::service_error(
-                    "Segment building error: created segment not found",
-                ))
+        self.update(&[other], stopped)
+    }
+
+    /// Update current segment builder with all (not deleted) vectors and payload from `segments`
+    ///
+    /// If some of the indexed fields is a tenant field, points of all segments are placed in the
+    /// order of its values, so points of a single tenant are stored contiguously.
+    ///
+    /// # Result
+    ///
+    /// * `bool` - if `true` - data successfully added, if `false` - process was interrupted
+    ///
+    pub fn update(&mut self, segments: &[&Segment], stopped: &AtomicBool) -> OperationResult<bool> {
+        for segment in segments {
+            let self_segment = self.segment_mut()?;
+            self_segment.version = Some(cmp::max(self_segment.version(), segment.version()));
+            for (field, payload_schema) in segment.payload_index.borrow().indexed_fields() {
+                self.indexed_fields.insert(field, payload_schema);
+            }
+            for (vector_name, vector_data) in &segment.vector_data {
+                self.old_indices
+                    .entry(vector_name.clone())
+                    .or_default()
+                    .push(vector_data.vector_index.clone());
+            }
+        }
+
+        let tenant_key = self.tenant_key();
+
+        // Points in the order they are placed into the new segment, grouped by source segment
+        let mut points: Vec<(Option<FacetValue>, usize, PointOffsetType)> = vec![];
+        for (segment_index, segment) in segments.iter().enumerate() {
+            let id_tracker = segment.id_tracker.borrow();
+            let payload_index = segment.payload_index.borrow();
+            for internal_id in id_tracker.iter_ids() {
+                check_process_stopped(stopped)?;
+                let tenant = match &tenant_key {
+                    Some(key) => payload_index
+                        .payload(internal_id)?
+                        .get_value(key)
+                        .into_iter()
+                        .find_map(tenant_value),
+                    None => None,
+                };
+                points.push((tenant, segment_index, internal_id));
+            }
+        }
+        if tenant_key.is_some() {
+            // Stable sort keeps the order of points within each tenant
+            points.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        }
+
+        let mut runs: Vec<(usize, Vec<PointOffsetType>)> = vec![];
+        for (_, segment_index, internal_id) in points {
+            match runs.last_mut() {
+                Some((last_index, ids)) if *last_index == segment_index => ids.push(internal_id),
+                _ => runs.push((segment_index, vec![internal_id])),
             }
-        };
-        self_segment.version = Some(cmp::max(self_segment.version(), other.version()));
+        }
+
+        for (segment_index, ids) in runs {
+            self.update_from_ids(segments[segment_index], &ids, stopped)?;
+        }
+
+        Ok(true)
+    }
+
+    fn segment_mut(&mut self) -> OperationResult<&mut Segment> {
+        self.segment.as_mut().ok_or_else(|| {
+            OperationError::service_error("Segment building error: created segment not found")
+        })
+    }
+
+    /// Field to group points by, the first of the indexed fields marked as tenant
+    fn tenant_key(&self) -> Option<PayloadKeyType> {
+        self.indexed_fields
+            .iter()
+            .filter(|(_, payload_schema)| payload_schema.is_tenant())
+            .map(|(field, _)| field)
+            .min()
+            .cloned()
+    }
+
+    /// Append points with the given internal ids of `other` segment
+    fn update_from_ids(
+        &mut self,
+        other: &Segment,
+        other_ids: &[PointOffsetType],
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let self_segment = self.segment_mut()?;
 
         let other_id_tracker = other.id_tracker.borrow();
         let other_vector_storages: HashMap<_, _> = other
@@ -103,7 +196,7 @@ impl SegmentBuilder {
             })?;
             let internal_range = vector_storage.update_from(
                 other_vector_storage,
-                &mut other_id_tracker.iter_ids(),
+                &mut other_ids.iter().copied(),
                 stopped,
             )?;
             match new_internal_range.clone() {
@@ -119,7 +212,7 @@ impl SegmentBuilder {
         }
 
         if let Some(new_internal_range) = new_internal_range {
-            let internal_id_iter = new_internal_range.zip(other_id_tracker.iter_ids());
+            let internal_id_iter = new_internal_range.zip(other_ids.iter().copied());
 
             for (new_internal_id, old_internal_id) in internal_id_iter {
                 check_process_stopped(stopped)?;
@@ -174,11 +267,7 @@ impl SegmentBuilder {
             }
         }
 
-        for (field, payload_schema) in other.payload_index.borrow().indexed_fields() {
-            self.indexed_fields.insert(field, payload_schema);
-        }
-
-        Ok(true)
+        Ok(())
     }
 
     pub fn build(mut self, stopped: &AtomicBool) -> Result<Segment, OperationError> {
@@ -194,11 +283,24 @@ impl SegmentBuilder {
 
             Self::update_quantization(&mut segment, stopped)?;
 
-            for vector_data in segment.vector_data.values_mut() {
-                vector_data.vector_index.borrow_mut().build_index(stopped)?;
+            for (vector_name, vector_data) in &segment.vector_data {
+                let old_indices = self
+                    .old_indices
+                    .get(vector_name)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                vector_data
+                    .vector_index
+                    .borrow_mut()
+                    .build_index_with_old_indices(old_indices, stopped)?;
             }
 
             segment.flush(true)?;
+
+            if !segment.appendable_flag {
+                Self::build_immutable_id_tracker(&segment)?;
+            }
+
             drop(segment);
             // Now segment is evicted from RAM
         }
@@ -216,6 +318,20 @@ impl SegmentBuilder {
         Ok(loaded_segment)
     }
 
+    /// Move id mappings of a non-appendable segment from RocksDB into memory mapped files,
+    /// which are opened instead of the RocksDB mappings on segment load
+    fn build_immutable_id_tracker(segment: &Segment) -> OperationResult<()> {
+        ImmutableIdTracker::create(
+            &segment.current_path.join(ID_TRACKER_PATH),
+            &*segment.id_tracker.borrow(),
+        )?;
+        for column_name in [DB_MAPPING_CF, DB_VERSIONS_CF] {
+            DatabaseColumnWrapper::new(segment.database.clone(), column_name)
+                .recreate_column_family()?;
+        }
+        Ok(())
+    }
+
     fn update_quantization(segment: &mut Segment, stopped: &AtomicBool) -> OperationResult<()> {
         let config = segment.config().clone();
 
@@ -252,3 +368,13 @@ impl SegmentBuilder {
         Ok(())
     }
 }
+
+/// Value of the tenant field to group points by, the first one if there are several
+fn tenant_value(value: &Value) -> Option<FacetValue> {
+    match value {
+        Value::String(keyword) => Some(FacetValue::Keyword(keyword.clone())),
+        Value::Number(number) => number.as_i64().map(FacetValue::Integer),
+        Value::Array(values) => values.iter().find_map(tenant_value),
+        _ => None,
+    }
+}
@@ -15,8 +15,9 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
 use crate::common::version::StorageVersion;
 use crate::data_types::vectors::DEFAULT_VECTOR_NAME;
+use crate::id_tracker::immutable_id_tracker::ImmutableIdTracker;
 use crate::id_tracker::simple_id_tracker::SimpleIdTracker;
-use crate::id_tracker::IdTracker;
+use crate::id_tracker::IdTrackerSS;
 use crate::index::hnsw_index::graph_links::{GraphLinksMmap, GraphLinksRam};
 use crate::index::hnsw_index::hnsw::HNSWIndex;
 use crate::index::plain_payload_index::PlainIndex;
@@ -24,21 +25,33 @@ use crate::index::sparse_index::sparse_index_config::SparseIndexType;
 use crate::index::sparse_index::sparse_vector_index::SparseVectorIndex;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::VectorIndexEnum;
+use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 use crate::payload_storage::on_disk_payload_storage::OnDiskPayloadStorage;
 use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
 use crate::segment::{Segment, SegmentVersion, VectorData, SEGMENT_STATE_FILE};
 use crate::types::{
     Distance, Indexes, PayloadStorageType, SegmentConfig, SegmentState, SegmentType, SeqNumberType,
-    VectorStorageType,
+    VectorStorageDatatype, VectorStorageType,
+};
+use crate::vector_storage::appendable_mmap_multi_dense_vector_storage::open_appendable_memmap_multi_vector_storage;
+use crate::vector_storage::appendable_mmap_vector_storage::{
+    open_appendable_memmap_byte_vector_storage, open_appendable_memmap_half_vector_storage,
+    open_appendable_memmap_vector_storage,
+};
+use crate::vector_storage::memmap_vector_storage::{
+    open_memmap_byte_vector_storage, open_memmap_half_vector_storage, open_memmap_vector_storage,
 };
-use crate::vector_storage::appendable_mmap_vector_storage::open_appendable_memmap_vector_storage;
-use crate::vector_storage::memmap_vector_storage::open_memmap_vector_storage;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
-use crate::vector_storage::simple_dense_vector_storage::open_simple_vector_storage;
+use crate::vector_storage::simple_dense_vector_storage::{
+    open_simple_byte_vector_storage, open_simple_half_vector_storage, open_simple_vector_storage,
+};
+use crate::vector_storage::simple_multi_dense_vector_storage::open_simple_multi_dense_vector_storage;
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use crate::vector_storage::VectorStorage;
 
+pub const ID_TRACKER_PATH: &str = "id_tracker";
 pub const PAYLOAD_INDEX_PATH: &str = "payload_index";
+pub const PAYLOAD_STORAGE_PATH: &str = "payload_storage";
 pub const VECTOR_STORAGE_PATH: &str = "vector_storage";
 pub const VECTOR_INDEX_PATH: &str = "vector_index";
 
@@ -87,9 +100,18 @@ fn create_segment(
     let payload_storage = match config.payload_storage_type {
         PayloadStorageType::InMemory => sp(SimplePayloadStorage::open(database.clone())?.into()),
         PayloadStorageType::OnDisk => sp(OnDiskPayloadStorage::open(database.clone())?.into()),
+        PayloadStorageType::Mmap => {
+            sp(MmapPayloadStorage::open(&segment_path.join(PAYLOAD_STORAGE_PATH))?.into())
+        }
     };
 
-    let id_tracker = sp(SimpleIdTracker::open(database.clone())?);
+    let id_tracker_path = segment_path.join(ID_TRACKER_PATH);
+    let id_tracker: Arc<AtomicRefCell<IdTrackerSS>> =
+        if ImmutableIdTracker::exists(&id_tracker_path) {
+            sp(ImmutableIdTracker::open(&id_tracker_path)?)
+        } else {
+            sp(SimpleIdTracker::open(database.clone())?)
+        };
 
     let appendable_flag = config
         .vector_data
@@ -105,7 +127,7 @@ fn create_segment(
 
     let payload_index_path = segment_path.join(PAYLOAD_INDEX_PATH);
     let payload_index: Arc<AtomicRefCell<StructPayloadIndex>> = sp(StructPayloadIndex::open(
-        payload_storage,
+        payload_storage.clone(),
         id_tracker.clone(),
         &payload_index_path,
         appendable_flag,
@@ -116,30 +138,86 @@ fn create_segment(
         let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
         let vector_index_path = get_vector_index_path(segment_path, vector_name);
 
+        let datatype = vector_config.datatype.unwrap_or_default();
+        if vector_config.multivector_config.is_some() && datatype != VectorStorageDatatype::Float32
+        {
+            return Err(OperationError::ValidationError {
+                description: format!(
+                    "Multi vectors only support the float32 datatype, got {datatype:?}",
+                ),
+            });
+        }
+        if vector_config.multivector_config.is_some() && vector_config.quantization_config.is_some()
+        {
+            return Err(OperationError::ValidationError {
+                description: format!("Multi vectors {vector_name} can't be quantized"),
+            });
+        }
+
         // Select suitable vector storage type based on configuration
-        let vector_storage = match vector_config.storage_type {
-            // In memory
-            VectorStorageType::Memory => {
+        let vector_storage = match (vector_config.storage_type, vector_config.multivector_config) {
+            // In memory multi vectors
+            (VectorStorageType::Memory, Some(multi_vector_config)) => {
                 let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
-                open_simple_vector_storage(
+                open_simple_multi_dense_vector_storage(
                     database.clone(),
                     &db_column_name,
                     vector_config.size,
                     vector_config.distance,
+                    multi_vector_config,
                 )?
             }
-            // Mmap on disk, not appendable
-            VectorStorageType::Mmap => open_memmap_vector_storage(
+            // Multi vectors on disk, always appendable
+            (
+                VectorStorageType::Mmap | VectorStorageType::ChunkedMmap,
+                Some(multi_vector_config),
+            ) => open_appendable_memmap_multi_vector_storage(
                 &vector_storage_path,
                 vector_config.size,
                 vector_config.distance,
+                multi_vector_config,
             )?,
+            // In memory
+            (VectorStorageType::Memory, None) => {
+                let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+                let open_storage = match datatype {
+                    VectorStorageDatatype::Float32 => open_simple_vector_storage,
+                    VectorStorageDatatype::Float16 => open_simple_half_vector_storage,
+                    VectorStorageDatatype::Uint8 => open_simple_byte_vector_storage,
+                };
+                open_storage(
+                    database.clone(),
+                    &db_column_name,
+                    vector_config.size,
+                    vector_config.distance,
+                )?
+            }
+            // Mmap on disk, not appendable
+            (VectorStorageType::Mmap, None) => {
+                let open_storage = match datatype {
+                    VectorStorageDatatype::Float32 => open_memmap_vector_storage,
+                    VectorStorageDatatype::Float16 => open_memmap_half_vector_storage,
+                    VectorStorageDatatype::Uint8 => open_memmap_byte_vector_storage,
+                };
+                open_storage(
+                    &vector_storage_path,
+                    vector_config.size,
+                    vector_config.distance,
+                )?
+            }
             // Chunked mmap on disk, appendable
-            VectorStorageType::ChunkedMmap => open_appendable_memmap_vector_storage(
-                &vector_storage_path,
-                vector_config.size,
-                vector_config.distance,
-            )?,
+            (VectorStorageType::ChunkedMmap, None) => {
+                let open_storage = match datatype {
+                    VectorStorageDatatype::Float32 => open_appendable_memmap_vector_storage,
+                    VectorStorageDatatype::Float16 => open_appendable_memmap_half_vector_storage,
+                    VectorStorageDatatype::Uint8 => open_appendable_memmap_byte_vector_storage,
+                };
+                open_storage(
+                    &vector_storage_path,
+                    vector_config.size,
+                    vector_config.distance,
+                )?
+            }
         };
 
         // Warn when number of points between ID tracker and storage differs
@@ -263,6 +341,7 @@ fn create_segment(
         segment_type,
         appendable_flag,
         payload_index,
+        payload_storage,
         segment_config: config.clone(),
         error_status: None,
         database,
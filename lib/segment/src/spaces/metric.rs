@@ -4,11 +4,14 @@ use crate::data_types::vectors::{DenseVector, VectorElementType};
 use crate::types::Distance;
 
 /// Defines how to compare vectors
-pub trait Metric {
+///
+/// Vectors are compared in the element type `T` of the storage, preprocessing and
+/// postprocessing are done in `f32`.
+pub trait Metric<T = VectorElementType> {
     fn distance() -> Distance;
 
     /// Greater the value - closer the vectors
-    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType;
+    fn similarity(v1: &[T], v2: &[T]) -> ScoreType;
 
     /// Necessary vector transformations performed before adding it to the collection (like normalization)
     /// If no transformation is needed - returns the same vector
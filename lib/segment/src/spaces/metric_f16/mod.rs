@@ -0,0 +1,4 @@
+pub mod simple;
+
+#[cfg(target_arch = "x86_64")]
+pub mod simple_avx;
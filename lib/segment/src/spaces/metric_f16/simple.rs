@@ -0,0 +1,176 @@
+use common::types::ScoreType;
+
+#[cfg(target_arch = "x86_64")]
+use super::simple_avx::*;
+use crate::data_types::vectors::{DenseVector, VectorElementType, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::types::Distance;
+
+#[cfg(target_arch = "x86_64")]
+const MIN_DIM_SIZE_AVX: usize = 32;
+
+#[cfg(target_arch = "x86_64")]
+fn is_avx_half_supported() -> bool {
+    is_x86_feature_detected!("avx")
+        && is_x86_feature_detected!("fma")
+        && is_x86_feature_detected!("f16c")
+}
+
+impl Metric<VectorElementTypeHalf> for EuclidMetric {
+    fn distance() -> Distance {
+        Distance::Euclid
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_avx_half_supported() && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { euclid_similarity_avx_half(v1, v2) };
+            }
+        }
+
+        euclid_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        <EuclidMetric as Metric<VectorElementType>>::preprocess(vector)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <EuclidMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+impl Metric<VectorElementTypeHalf> for ManhattanMetric {
+    fn distance() -> Distance {
+        Distance::Manhattan
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_avx_half_supported() && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { manhattan_similarity_avx_half(v1, v2) };
+            }
+        }
+
+        manhattan_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <ManhattanMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+impl Metric<VectorElementTypeHalf> for DotProductMetric {
+    fn distance() -> Distance {
+        Distance::Dot
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_avx_half_supported() && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { dot_similarity_avx_half(v1, v2) };
+            }
+        }
+
+        dot_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        <DotProductMetric as Metric<VectorElementType>>::preprocess(vector)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <DotProductMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+/// Vectors are normalized before they are converted to half precision,
+/// so cosine similarity is a dot product, same as for `f32` vectors
+impl Metric<VectorElementTypeHalf> for CosineMetric {
+    fn distance() -> Distance {
+        Distance::Cosine
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        <DotProductMetric as Metric<VectorElementTypeHalf>>::similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        <CosineMetric as Metric<VectorElementType>>::preprocess(vector)
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <CosineMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+pub fn euclid_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a.to_f32() - b.to_f32()).powi(2))
+        .sum::<ScoreType>()
+}
+
+pub fn manhattan_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a.to_f32() - b.to_f32()).abs())
+        .sum::<ScoreType>()
+}
+
+pub fn dot_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    v1.iter()
+        .zip(v2)
+        .map(|(a, b)| a.to_f32() * b.to_f32())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_similarity_matches_float() {
+        let v1: Vec<VectorElementType> = vec![0.5, -1.25, 2.0, 0.0, 3.5];
+        let v2: Vec<VectorElementType> = vec![1.0, 0.75, -0.5, 4.0, 0.25];
+        let h1: Vec<VectorElementTypeHalf> = v1
+            .iter()
+            .map(|&x| VectorElementTypeHalf::from_f32(x))
+            .collect();
+        let h2: Vec<VectorElementTypeHalf> = v2
+            .iter()
+            .map(|&x| VectorElementTypeHalf::from_f32(x))
+            .collect();
+
+        // All values are exactly representable in half precision
+        assert_eq!(
+            <DotProductMetric as Metric<VectorElementTypeHalf>>::similarity(&h1, &h2),
+            <DotProductMetric as Metric<VectorElementType>>::similarity(&v1, &v2),
+        );
+        assert_eq!(
+            <EuclidMetric as Metric<VectorElementTypeHalf>>::similarity(&h1, &h2),
+            <EuclidMetric as Metric<VectorElementType>>::similarity(&v1, &v2),
+        );
+        assert_eq!(
+            <ManhattanMetric as Metric<VectorElementTypeHalf>>::similarity(&h1, &h2),
+            <ManhattanMetric as Metric<VectorElementType>>::similarity(&v1, &v2),
+        );
+    }
+}
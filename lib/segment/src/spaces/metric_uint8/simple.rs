@@ -0,0 +1,186 @@
+use common::types::ScoreType;
+
+#[cfg(target_arch = "x86_64")]
+use super::simple_avx2::*;
+use crate::data_types::vectors::{DenseVector, VectorElementType, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::types::Distance;
+
+#[cfg(target_arch = "x86_64")]
+const MIN_DIM_SIZE_AVX: usize = 32;
+
+impl Metric<VectorElementTypeByte> for EuclidMetric {
+    fn distance() -> Distance {
+        Distance::Euclid
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { euclid_similarity_avx2_bytes(v1, v2) };
+            }
+        }
+
+        euclid_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <EuclidMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+impl Metric<VectorElementTypeByte> for ManhattanMetric {
+    fn distance() -> Distance {
+        Distance::Manhattan
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { manhattan_similarity_avx2_bytes(v1, v2) };
+            }
+        }
+
+        manhattan_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <ManhattanMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+impl Metric<VectorElementTypeByte> for DotProductMetric {
+    fn distance() -> Distance {
+        Distance::Dot
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { dot_similarity_avx2_bytes(v1, v2) };
+            }
+        }
+
+        dot_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <DotProductMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+/// Normalized vectors can't be represented with bytes,
+/// so vectors are stored as is and normalized while scoring
+impl Metric<VectorElementTypeByte> for CosineMetric {
+    fn distance() -> Distance {
+        Distance::Cosine
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { cosine_similarity_avx2_bytes(v1, v2) };
+            }
+        }
+
+        cosine_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+
+    fn postprocess(score: ScoreType) -> ScoreType {
+        <CosineMetric as Metric<VectorElementType>>::postprocess(score)
+    }
+}
+
+pub fn euclid_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| (i64::from(a) - i64::from(b)).pow(2))
+        .sum::<i64>() as ScoreType
+}
+
+pub fn manhattan_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| i64::from(a.abs_diff(b)))
+        .sum::<i64>() as ScoreType
+}
+
+pub fn dot_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| i64::from(a) * i64::from(b))
+        .sum::<i64>() as ScoreType
+}
+
+pub fn cosine_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let (mut dot, mut norm1, mut norm2) = (0i64, 0i64, 0i64);
+    for (&a, &b) in v1.iter().zip(v2) {
+        let (a, b) = (i64::from(a), i64::from(b));
+        dot += a * b;
+        norm1 += a * a;
+        norm2 += b * b;
+    }
+    if norm1 == 0 || norm2 == 0 {
+        return 0.0;
+    }
+    (dot as f64 / ((norm1 as f64) * (norm2 as f64)).sqrt()) as ScoreType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_similarity() {
+        let v1: Vec<VectorElementTypeByte> = vec![1, 2, 3, 0];
+        let v2: Vec<VectorElementTypeByte> = vec![4, 0, 6, 255];
+
+        assert_eq!(dot_similarity_bytes(&v1, &v2), 22.0);
+        assert_eq!(
+            euclid_similarity_bytes(&v1, &v2),
+            -(9.0 + 4.0 + 9.0 + 65025.0)
+        );
+        assert_eq!(
+            manhattan_similarity_bytes(&v1, &v2),
+            -(3.0 + 2.0 + 3.0 + 255.0)
+        );
+
+        // Cosine similarity doesn't depend on the length of the vectors
+        let v3: Vec<VectorElementTypeByte> = vec![2, 4, 6, 0];
+        assert!((cosine_similarity_bytes(&v1, &v3) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity_bytes(&v1, &[0, 0, 0, 0]), 0.0);
+    }
+}
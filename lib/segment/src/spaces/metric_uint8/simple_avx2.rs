@@ -0,0 +1,204 @@
+use std::arch::x86_64::*;
+
+use common::types::ScoreType;
+
+use crate::data_types::vectors::VectorElementTypeByte;
+
+/// Sum 8 lanes of 32-bit integers
+///
+/// Lanes are summed as 64-bit integers, as the total may not fit into 32 bits.
+#[target_feature(enable = "avx2")]
+unsafe fn hsum256_epi32_avx2(x: __m256i) -> i64 {
+    let mut lanes = [0i32; 8];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, x);
+    lanes.iter().map(|&lane| i64::from(lane)).sum()
+}
+
+/// Sum 4 lanes of 64-bit integers
+#[target_feature(enable = "avx2")]
+unsafe fn hsum256_epi64_avx2(x: __m256i) -> i64 {
+    let mut lanes = [0i64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, x);
+    lanes.iter().sum()
+}
+
+/// Load 16 bytes and widen them to 16-bit integers
+#[target_feature(enable = "avx2")]
+unsafe fn load_epu8_epi16_avx2(ptr: *const VectorElementTypeByte) -> __m256i {
+    _mm256_cvtepu8_epi16(_mm_loadu_si128(ptr as *const __m128i))
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn euclid_similarity_avx2_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 32);
+    let mut ptr1: *const VectorElementTypeByte = v1.as_ptr();
+    let mut ptr2: *const VectorElementTypeByte = v2.as_ptr();
+    let mut sum256_1: __m256i = _mm256_setzero_si256();
+    let mut sum256_2: __m256i = _mm256_setzero_si256();
+    let mut i: usize = 0;
+    while i < m {
+        let sub256_1 = _mm256_sub_epi16(load_epu8_epi16_avx2(ptr1), load_epu8_epi16_avx2(ptr2));
+        sum256_1 = _mm256_add_epi32(_mm256_madd_epi16(sub256_1, sub256_1), sum256_1);
+
+        let sub256_2 = _mm256_sub_epi16(
+            load_epu8_epi16_avx2(ptr1.add(16)),
+            load_epu8_epi16_avx2(ptr2.add(16)),
+        );
+        sum256_2 = _mm256_add_epi32(_mm256_madd_epi16(sub256_2, sub256_2), sum256_2);
+
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+        i += 32;
+    }
+
+    let mut result = hsum256_epi32_avx2(sum256_1) + hsum256_epi32_avx2(sum256_2);
+    for i in 0..n - m {
+        let diff = i64::from(*ptr1.add(i)) - i64::from(*ptr2.add(i));
+        result += diff * diff;
+    }
+    -(result as ScoreType)
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn manhattan_similarity_avx2_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 32);
+    let mut ptr1: *const VectorElementTypeByte = v1.as_ptr();
+    let mut ptr2: *const VectorElementTypeByte = v2.as_ptr();
+    let mut sum256: __m256i = _mm256_setzero_si256();
+    let mut i: usize = 0;
+    while i < m {
+        // Sum of absolute differences of every 8 bytes into 64-bit lanes
+        let sad256 = _mm256_sad_epu8(
+            _mm256_loadu_si256(ptr1 as *const __m256i),
+            _mm256_loadu_si256(ptr2 as *const __m256i),
+        );
+        sum256 = _mm256_add_epi64(sad256, sum256);
+
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+        i += 32;
+    }
+
+    let mut result = hsum256_epi64_avx2(sum256);
+    for i in 0..n - m {
+        result += i64::from((*ptr1.add(i)).abs_diff(*ptr2.add(i)));
+    }
+    -(result as ScoreType)
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn dot_similarity_avx2_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 32);
+    let mut ptr1: *const VectorElementTypeByte = v1.as_ptr();
+    let mut ptr2: *const VectorElementTypeByte = v2.as_ptr();
+    let mut sum256_1: __m256i = _mm256_setzero_si256();
+    let mut sum256_2: __m256i = _mm256_setzero_si256();
+    let mut i: usize = 0;
+    while i < m {
+        sum256_1 = _mm256_add_epi32(
+            _mm256_madd_epi16(load_epu8_epi16_avx2(ptr1), load_epu8_epi16_avx2(ptr2)),
+            sum256_1,
+        );
+        sum256_2 = _mm256_add_epi32(
+            _mm256_madd_epi16(
+                load_epu8_epi16_avx2(ptr1.add(16)),
+                load_epu8_epi16_avx2(ptr2.add(16)),
+            ),
+            sum256_2,
+        );
+
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+        i += 32;
+    }
+
+    let mut result = hsum256_epi32_avx2(sum256_1) + hsum256_epi32_avx2(sum256_2);
+    for i in 0..n - m {
+        result += i64::from(*ptr1.add(i)) * i64::from(*ptr2.add(i));
+    }
+    result as ScoreType
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn cosine_similarity_avx2_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 16);
+    let mut ptr1: *const VectorElementTypeByte = v1.as_ptr();
+    let mut ptr2: *const VectorElementTypeByte = v2.as_ptr();
+    let mut dot256: __m256i = _mm256_setzero_si256();
+    let mut norm256_1: __m256i = _mm256_setzero_si256();
+    let mut norm256_2: __m256i = _mm256_setzero_si256();
+    let mut i: usize = 0;
+    while i < m {
+        let m256_1 = load_epu8_epi16_avx2(ptr1);
+        let m256_2 = load_epu8_epi16_avx2(ptr2);
+        dot256 = _mm256_add_epi32(_mm256_madd_epi16(m256_1, m256_2), dot256);
+        norm256_1 = _mm256_add_epi32(_mm256_madd_epi16(m256_1, m256_1), norm256_1);
+        norm256_2 = _mm256_add_epi32(_mm256_madd_epi16(m256_2, m256_2), norm256_2);
+
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+        i += 16;
+    }
+
+    let mut dot = hsum256_epi32_avx2(dot256);
+    let mut norm1 = hsum256_epi32_avx2(norm256_1);
+    let mut norm2 = hsum256_epi32_avx2(norm256_2);
+    for i in 0..n - m {
+        let (a, b) = (i64::from(*ptr1.add(i)), i64::from(*ptr2.add(i)));
+        dot += a * b;
+        norm1 += a * a;
+        norm2 += b * b;
+    }
+    if norm1 == 0 || norm2 == 0 {
+        return 0.0;
+    }
+    (dot as f64 / ((norm1 as f64) * (norm2 as f64)).sqrt()) as ScoreType
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_spaces_avx2_bytes() {
+        use super::*;
+        use crate::spaces::metric_uint8::simple::*;
+
+        if is_x86_feature_detected!("avx2") {
+            let v1: Vec<VectorElementTypeByte> = (0..70).map(|i| (i * 3) as u8).collect();
+            let v2: Vec<VectorElementTypeByte> = (0..70).map(|i| 255 - (i * 2) as u8).collect();
+
+            let euclid_simd = unsafe { euclid_similarity_avx2_bytes(&v1, &v2) };
+            let euclid = euclid_similarity_bytes(&v1, &v2);
+            assert_eq!(euclid_simd, euclid);
+
+            let manhattan_simd = unsafe { manhattan_similarity_avx2_bytes(&v1, &v2) };
+            let manhattan = manhattan_similarity_bytes(&v1, &v2);
+            assert_eq!(manhattan_simd, manhattan);
+
+            let dot_simd = unsafe { dot_similarity_avx2_bytes(&v1, &v2) };
+            let dot = dot_similarity_bytes(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+
+            let cosine_simd = unsafe { cosine_similarity_avx2_bytes(&v1, &v2) };
+            let cosine = cosine_similarity_bytes(&v1, &v2);
+            assert_eq!(cosine_simd, cosine);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
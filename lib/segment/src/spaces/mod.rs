@@ -1,4 +1,6 @@
 pub mod metric;
+pub mod metric_f16;
+pub mod metric_uint8;
 pub mod simple;
 pub mod tools;
 
@@ -3,13 +3,13 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 use std::mem::size_of;
-use std::ops::Deref;
+use std::ops::{Bound, Deref};
 use std::rc::Rc;
 use std::str::FromStr;
 
 use common::types::ScoreType;
 use geo::prelude::HaversineDistance;
-use geo::{Contains, Coord, LineString, Point, Polygon};
+use geo::{Contains, Coord, Geometry, Intersects, LineString, Point, Polygon};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
@@ -25,6 +25,11 @@ use crate::common::utils::{
     check_exclude_pattern, check_include_pattern, filter_json_values, get_value_from_json_map,
     get_value_from_json_map_opt, MultiValue,
 };
+use crate::data_types::index::{
+    BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
+    KeywordIndexParams, UuidIndexParams,
+};
+use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::text_index::TextIndexParams;
 use crate::data_types::vectors::{DenseVector, VectorElementType, VectorStruct};
 use crate::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
@@ -41,6 +46,32 @@ pub type TagType = u64;
 pub type FloatPayloadType = f64;
 /// Type of integer point payload
 pub type IntPayloadType = i64;
+/// Type of UUID point payload, stored as 128-bit integer
+pub type UuidIntType = u128;
+
+/// Type of datetime point payload
+///
+/// Serialized as RFC 3339 string, stored as UTC
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(transparent)]
+pub struct DateTimePayloadType(pub chrono::DateTime<chrono::Utc>);
+
+impl DateTimePayloadType {
+    /// Number of microseconds since UNIX epoch, used as a key in numeric index
+    pub fn timestamp(&self) -> IntPayloadType {
+        self.0.timestamp_micros()
+    }
+}
+
+impl FromStr for DateTimePayloadType {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        chrono::DateTime::from_str(s).map(Self)
+    }
+}
 
 pub const VECTOR_ELEMENT_SIZE: usize = size_of::<VectorElementType>();
 
@@ -129,21 +160,30 @@ pub enum Distance {
 }
 
 impl Distance {
-    pub fn preprocess_vector(&self, vector: DenseVector) -> DenseVector {
+    /// Preprocess a vector before storing it with elements of type `T`
+    pub fn preprocess_vector<T: PrimitiveVectorElement>(&self, vector: DenseVector) -> DenseVector
+    where
+        CosineMetric: Metric<T>,
+        EuclidMetric: Metric<T>,
+        DotProductMetric: Metric<T>,
+        ManhattanMetric: Metric<T>,
+    {
         match self {
-            Distance::Cosine => CosineMetric::preprocess(vector),
-            Distance::Euclid => EuclidMetric::preprocess(vector),
-            Distance::Dot => DotProductMetric::preprocess(vector),
-            Distance::Manhattan => ManhattanMetric::preprocess(vector),
+            Distance::Cosine => <CosineMetric as Metric<T>>::preprocess(vector),
+            Distance::Euclid => <EuclidMetric as Metric<T>>::preprocess(vector),
+            Distance::Dot => <DotProductMetric as Metric<T>>::preprocess(vector),
+            Distance::Manhattan => <ManhattanMetric as Metric<T>>::preprocess(vector),
         }
     }
 
     pub fn postprocess_score(&self, score: ScoreType) -> ScoreType {
         match self {
-            Distance::Cosine => CosineMetric::postprocess(score),
-            Distance::Euclid => EuclidMetric::postprocess(score),
-            Distance::Dot => DotProductMetric::postprocess(score),
-            Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::postprocess(score),
+            Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::postprocess(score),
+            Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::postprocess(score),
+            Distance::Manhattan => {
+                <ManhattanMetric as Metric<VectorElementType>>::postprocess(score)
+            }
         }
     }
 
@@ -167,14 +207,17 @@ impl Distance {
     /// Warn: prefer compile-time generics with `Metric` trait
     pub fn similarity(&self, v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
         match self {
-            Distance::Cosine => CosineMetric::similarity(v1, v2),
-            Distance::Euclid => EuclidMetric::similarity(v1, v2),
-            Distance::Dot => DotProductMetric::similarity(v1, v2),
-            Distance::Manhattan => ManhattanMetric::similarity(v1, v2),
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::similarity(v1, v2),
+            Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::similarity(v1, v2),
+            Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::similarity(v1, v2),
+            Distance::Manhattan => {
+                <ManhattanMetric as Metric<VectorElementType>>::similarity(v1, v2)
+            }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Order {
     LargeBetter,
     SmallBetter,
@@ -249,12 +292,10 @@ impl PayloadIndexInfo {
                 params: None,
                 points: points_count,
             },
-            PayloadFieldSchema::FieldParams(schema_params) => match schema_params {
-                PayloadSchemaParams::Text(_) => PayloadIndexInfo {
-                    data_type: PayloadSchemaType::Text,
-                    params: Some(schema_params),
-                    points: points_count,
-                },
+            PayloadFieldSchema::FieldParams(schema_params) => PayloadIndexInfo {
+                data_type: schema_params.kind(),
+                params: Some(schema_params),
+                points: points_count,
             },
         }
     }
@@ -340,6 +381,11 @@ pub struct SearchParams {
     /// guarantee that all uploaded vectors will be included in search results
     #[serde(default)]
     pub indexed_only: bool,
+
+    /// If enabled, filtered HNSW search widens the beam, when the filter accepts only a small share
+    /// of points. Ignored if `hnsw_ef` is set explicitly.
+    #[serde(default)]
+    pub adaptive_ef: bool,
 }
 
 /// Vector index configuration
@@ -388,6 +434,10 @@ pub struct HnswConfig {
     /// Custom M param for hnsw graph built for payload index. If not set, default M will be used.
     #[serde(default, skip_serializing_if = "Option::is_none")] // Better backward compatibility
     pub payload_m: Option<usize>,
+    /// Build additional links within wide ranges of indexed numeric payload fields.
+    /// Improves accuracy of range filters of medium selectivity at the cost of longer indexing. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")] // Better backward compatibility
+    pub range_links: Option<bool>,
 }
 
 impl HnswConfig {
@@ -404,6 +454,7 @@ impl HnswConfig {
             || self.ef_construct != other.ef_construct
             || self.full_scan_threshold != other.full_scan_threshold
             || self.payload_m != other.payload_m
+            || self.range_links.unwrap_or_default() != other.range_links.unwrap_or_default()
             // Data on disk is the same, we have a unit test for that. We can eventually optimize
             // this to just reload the collection rather than optimizing it again as a whole just
             // to flip this flag
@@ -570,6 +621,7 @@ impl Default for HnswConfig {
             max_indexing_threads: 0,
             on_disk: Some(false),
             payload_m: None,
+            range_links: None,
         }
     }
 }
@@ -606,11 +658,13 @@ pub enum PayloadStorageType {
     InMemory,
     // Store payload on disk only, read each time it is requested
     OnDisk,
+    // Store payload in memory mapped pages without RocksDB, read each time it is requested
+    Mmap,
 }
 
 impl PayloadStorageType {
     pub fn is_on_disk(&self) -> bool {
-        matches!(self, PayloadStorageType::OnDisk)
+        matches!(self, PayloadStorageType::OnDisk | PayloadStorageType::Mmap)
     }
 }
 
@@ -714,6 +768,21 @@ impl VectorStorageType {
     }
 }
 
+/// Storage types for vector elements
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Hash, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStorageDatatype {
+    /// Single-precision floating point, 4 bytes per element
+    #[default]
+    Float32,
+    /// Half-precision floating point, 2 bytes per element
+    Float16,
+    /// Unsigned 8-bit integer, 1 byte per element
+    ///
+    /// Values are expected to be in the range 0..=255, fractional parts are dropped on insertion.
+    Uint8,
+}
+
 /// Config of single vector data storage
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -728,6 +797,12 @@ pub struct VectorDataConfig {
     pub index: Indexes,
     /// Vector specific quantization config that overrides collection config
     pub quantization_config: Option<QuantizationConfig>,
+    /// If set, each point holds a matrix of vectors of this size instead of a single vector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multivector_config: Option<MultiVectorConfig>,
+    /// Type of the vector elements in the storage, `float32` if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<VectorStorageDatatype>,
 }
 
 impl VectorDataConfig {
@@ -748,6 +823,24 @@ impl VectorDataConfig {
     }
 }
 
+/// How to compare a query multi vector with a stored one
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiVectorComparator {
+    /// Sum of the best similarity of every query vector against the stored vectors,
+    /// as used by late interaction models like ColBERT
+    #[default]
+    MaxSim,
+}
+
+/// Config of multi vectors, i.e. vectors consisting of multiple dense vectors of the same size
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct MultiVectorConfig {
+    /// How to compare multi vectors
+    pub comparator: MultiVectorComparator,
+}
+
 /// Config of single sparse vector data storage
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Validate)]
 #[serde(rename_all = "snake_case")]
@@ -1059,6 +1152,8 @@ pub enum PayloadSchemaType {
     Geo,
     Text,
     Bool,
+    Datetime,
+    Uuid,
 }
 
 /// Payload type with parameters
@@ -1066,6 +1161,56 @@ pub enum PayloadSchemaType {
 #[serde(untagged, rename_all = "snake_case")]
 pub enum PayloadSchemaParams {
     Text(TextIndexParams),
+    Keyword(KeywordIndexParams),
+    Integer(IntegerIndexParams),
+    Float(FloatIndexParams),
+    Datetime(DatetimeIndexParams),
+    Uuid(UuidIndexParams),
+    Geo(GeoIndexParams),
+    Bool(BoolIndexParams),
+}
+
+impl PayloadSchemaParams {
+    /// Type of the payload field these parameters are applicable to
+    pub fn kind(&self) -> PayloadSchemaType {
+        match self {
+            PayloadSchemaParams::Text(_) => PayloadSchemaType::Text,
+            PayloadSchemaParams::Keyword(_) => PayloadSchemaType::Keyword,
+            PayloadSchemaParams::Integer(_) => PayloadSchemaType::Integer,
+            PayloadSchemaParams::Float(_) => PayloadSchemaType::Float,
+            PayloadSchemaParams::Datetime(_) => PayloadSchemaType::Datetime,
+            PayloadSchemaParams::Uuid(_) => PayloadSchemaType::Uuid,
+            PayloadSchemaParams::Geo(_) => PayloadSchemaType::Geo,
+            PayloadSchemaParams::Bool(_) => PayloadSchemaType::Bool,
+        }
+    }
+
+    pub fn is_tenant(&self) -> bool {
+        match self {
+            PayloadSchemaParams::Keyword(params) => params.is_tenant.unwrap_or(false),
+            PayloadSchemaParams::Integer(params) => params.is_tenant.unwrap_or(false),
+            PayloadSchemaParams::Text(_)
+            | PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::Uuid(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Bool(_) => false,
+        }
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        let on_disk = match self {
+            PayloadSchemaParams::Text(params) => params.on_disk,
+            PayloadSchemaParams::Keyword(params) => params.on_disk,
+            PayloadSchemaParams::Integer(params) => params.on_disk,
+            PayloadSchemaParams::Float(params) => params.on_disk,
+            PayloadSchemaParams::Datetime(params) => params.on_disk,
+            PayloadSchemaParams::Uuid(params) => params.on_disk,
+            PayloadSchemaParams::Geo(params) => params.on_disk,
+            PayloadSchemaParams::Bool(params) => params.on_disk,
+        };
+        on_disk.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
@@ -1075,6 +1220,36 @@ pub enum PayloadFieldSchema {
     FieldParams(PayloadSchemaParams),
 }
 
+impl PayloadFieldSchema {
+    /// Type of the indexed payload field
+    pub fn kind(&self) -> PayloadSchemaType {
+        match self {
+            PayloadFieldSchema::FieldType(payload_schema_type) => *payload_schema_type,
+            PayloadFieldSchema::FieldParams(payload_schema_params) => payload_schema_params.kind(),
+        }
+    }
+
+    /// Whether points should be grouped by the values of this field, see `is_tenant` parameter
+    pub fn is_tenant(&self) -> bool {
+        match self {
+            PayloadFieldSchema::FieldType(_) => false,
+            PayloadFieldSchema::FieldParams(payload_schema_params) => {
+                payload_schema_params.is_tenant()
+            }
+        }
+    }
+
+    /// Whether the index should be memory mapped in non-appendable segments, see `on_disk` parameter
+    pub fn is_on_disk(&self) -> bool {
+        match self {
+            PayloadFieldSchema::FieldType(_) => false,
+            PayloadFieldSchema::FieldParams(payload_schema_params) => {
+                payload_schema_params.is_on_disk()
+            }
+        }
+    }
+}
+
 impl From<PayloadSchemaType> for PayloadFieldSchema {
     fn from(payload_schema_type: PayloadSchemaType) -> Self {
         PayloadFieldSchema::FieldType(payload_schema_type)
@@ -1086,9 +1261,9 @@ impl TryFrom<PayloadIndexInfo> for PayloadFieldSchema {
 
     fn try_from(index_info: PayloadIndexInfo) -> Result<Self, Self::Error> {
         match (index_info.data_type, index_info.params) {
-            (PayloadSchemaType::Text, Some(PayloadSchemaParams::Text(params))) => Ok(
-                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(params)),
-            ),
+            (data_type, Some(params)) if params.kind() == data_type => {
+                Ok(PayloadFieldSchema::FieldParams(params))
+            }
             (data_type, Some(_)) => Err(format!(
                 "Payload field with type {data_type:?} has unexpected params"
             )),
@@ -1178,6 +1353,33 @@ impl From<String> for MatchText {
     }
 }
 
+/// Full-text match of the phrase: tokens must appear next to each other and in the same order.
+/// Requires a full-text index with `phrase_matching` enabled, otherwise a substring match is used.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPhrase {
+    pub phrase: String,
+}
+
+impl From<String> for MatchPhrase {
+    fn from(phrase: String) -> Self {
+        MatchPhrase { phrase }
+    }
+}
+
+/// Match of the keywords, which start with the given prefix
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPrefix {
+    pub prefix: String,
+}
+
+impl From<String> for MatchPrefix {
+    fn from(prefix: String) -> Self {
+        MatchPrefix { prefix }
+    }
+}
+
 /// Exact match on any of the given values
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -1198,6 +1400,8 @@ pub struct MatchExcept {
 pub enum MatchInterface {
     Value(MatchValue),
     Text(MatchText),
+    Phrase(MatchPhrase),
+    Prefix(MatchPrefix),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -1208,6 +1412,8 @@ pub enum MatchInterface {
 pub enum Match {
     Value(MatchValue),
     Text(MatchText),
+    Phrase(MatchPhrase),
+    Prefix(MatchPrefix),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -1222,6 +1428,19 @@ impl Match {
         Self::Text(MatchText { text: text.into() })
     }
 
+    #[cfg(test)]
+    fn new_phrase(phrase: &str) -> Self {
+        Self::Phrase(MatchPhrase {
+            phrase: phrase.into(),
+        })
+    }
+
+    pub fn new_prefix(prefix: &str) -> Self {
+        Self::Prefix(MatchPrefix {
+            prefix: prefix.into(),
+        })
+    }
+
     pub fn new_any(any: AnyVariants) -> Self {
         Self::Any(MatchAny { any })
     }
@@ -1242,6 +1461,12 @@ impl From<MatchInterface> for Match {
         match value {
             MatchInterface::Value(value) => Self::Value(MatchValue { value: value.value }),
             MatchInterface::Text(text) => Self::Text(MatchText { text: text.text }),
+            MatchInterface::Phrase(phrase) => Self::Phrase(MatchPhrase {
+                phrase: phrase.phrase,
+            }),
+            MatchInterface::Prefix(prefix) => Self::Prefix(MatchPrefix {
+                prefix: prefix.prefix,
+            }),
             MatchInterface::Any(any) => Self::Any(MatchAny { any: any.any }),
             MatchInterface::Except(except) => Self::Except(MatchExcept {
                 except: except.except,
@@ -1337,6 +1562,126 @@ impl Range {
     }
 }
 
+/// Range filter request for datetime values, bounds are RFC 3339 strings
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct DatetimeRange {
+    /// point.key < range.lt
+    pub lt: Option<DateTimePayloadType>,
+    /// point.key > range.gt
+    pub gt: Option<DateTimePayloadType>,
+    /// point.key >= range.gte
+    pub gte: Option<DateTimePayloadType>,
+    /// point.key <= range.lte
+    pub lte: Option<DateTimePayloadType>,
+}
+
+impl DatetimeRange {
+    pub fn check_range(&self, datetime: DateTimePayloadType) -> bool {
+        self.lt.map_or(true, |x| datetime < x)
+            && self.gt.map_or(true, |x| datetime > x)
+            && self.lte.map_or(true, |x| datetime <= x)
+            && self.gte.map_or(true, |x| datetime >= x)
+    }
+
+    /// Convert into a range over timestamps, as they are stored in the numeric index
+    pub fn as_timestamp_range(&self) -> Range {
+        let timestamp = |datetime: DateTimePayloadType| datetime.timestamp() as FloatPayloadType;
+        Range {
+            lt: self.lt.map(timestamp),
+            gt: self.gt.map(timestamp),
+            gte: self.gte.map(timestamp),
+            lte: self.lte.map(timestamp),
+        }
+    }
+}
+
+/// Lexicographic range filter request for keyword values
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct KeywordRange {
+    /// point.key < range.lt
+    pub lt: Option<String>,
+    /// point.key > range.gt
+    pub gt: Option<String>,
+    /// point.key >= range.gte
+    pub gte: Option<String>,
+    /// point.key <= range.lte
+    pub lte: Option<String>,
+}
+
+impl KeywordRange {
+    pub fn check_range(&self, keyword: &str) -> bool {
+        self.lt.as_deref().map_or(true, |x| keyword < x)
+            && self.gt.as_deref().map_or(true, |x| keyword > x)
+            && self.lte.as_deref().map_or(true, |x| keyword <= x)
+            && self.gte.as_deref().map_or(true, |x| keyword >= x)
+    }
+
+    /// The tightest lower and upper bounds of the range
+    pub fn bounds(&self) -> (Bound<&str>, Bound<&str>) {
+        let lower = match (self.gt.as_deref(), self.gte.as_deref()) {
+            (Some(gt), Some(gte)) if gte > gt => Bound::Included(gte),
+            (Some(gt), _) => Bound::Excluded(gt),
+            (None, Some(gte)) => Bound::Included(gte),
+            (None, None) => Bound::Unbounded,
+        };
+        let upper = match (self.lt.as_deref(), self.lte.as_deref()) {
+            (Some(lt), Some(lte)) if lte < lt => Bound::Included(lte),
+            (Some(lt), _) => Bound::Excluded(lt),
+            (None, Some(lte)) => Bound::Included(lte),
+            (None, None) => Bound::Unbounded,
+        };
+        (lower, upper)
+    }
+}
+
+/// Range filter, either numeric, datetime or lexicographic over keywords
+///
+/// String bounds are parsed as datetimes, keyword ranges are explicitly wrapped into `keyword`.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RangeInterface {
+    // Goes first, otherwise it is parsed as an unbounded numeric range with an unknown field
+    Keyword {
+        /// Lexicographic range over keyword values
+        keyword: KeywordRange,
+    },
+    Float(Range),
+    DateTime(DatetimeRange),
+}
+
+impl RangeInterface {
+    /// Range over values, as they are stored in the numeric index
+    ///
+    /// Datetime bounds are converted into timestamps, keyword ranges have no numeric equivalent
+    pub fn as_numeric_range(&self) -> Option<Range> {
+        match self {
+            RangeInterface::Float(range) => Some(range.clone()),
+            RangeInterface::DateTime(range) => Some(range.as_timestamp_range()),
+            RangeInterface::Keyword { .. } => None,
+        }
+    }
+}
+
+impl From<Range> for RangeInterface {
+    fn from(range: Range) -> Self {
+        RangeInterface::Float(range)
+    }
+}
+
+impl From<DatetimeRange> for RangeInterface {
+    fn from(range: DatetimeRange) -> Self {
+        RangeInterface::DateTime(range)
+    }
+}
+
+impl From<KeywordRange> for RangeInterface {
+    fn from(range: KeywordRange) -> Self {
+        RangeInterface::Keyword { keyword: range }
+    }
+}
+
 /// Values count filter request
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -1526,6 +1871,186 @@ impl TryFrom<GeoPolygonShadow> for GeoPolygon {
     }
 }
 
+/// Geometry in GeoJSON format, coordinates are `[lon, lat]` pairs
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(tag = "type", content = "coordinates")]
+enum GeoJsonGeometry {
+    /// Single position
+    Point([f64; 2]),
+    /// Line through at least 2 positions
+    LineString(Vec<[f64; 2]>),
+    /// Exterior ring followed by optional interior rings (holes).
+    /// Each ring must consist of a minimum of 4 positions, and the first and last positions
+    /// must be the same.
+    Polygon(Vec<Vec<[f64; 2]>>),
+}
+
+/// Geo point, line or polygon stored in payload or used in a filter, in GeoJSON format
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(try_from = "GeoJsonGeometry", into = "GeoJsonGeometry")]
+pub enum GeoShape {
+    Point(GeoPoint),
+    LineString(GeoLineString),
+    Polygon(GeoPolygon),
+}
+
+impl JsonSchema for GeoShape {
+    fn schema_name() -> String {
+        "GeoShape".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        GeoJsonGeometry::json_schema(gen)
+    }
+}
+
+pub struct GeoShapeWrapper {
+    pub geometry: Geometry,
+}
+
+impl GeoShapeWrapper {
+    /// Check if the shape shares at least one point with this one
+    pub fn check_intersects(&self, shape: &GeoShape) -> bool {
+        self.geometry.intersects(&shape.to_geometry())
+    }
+
+    /// Check if the shape lies completely within this one
+    pub fn check_within(&self, shape: &GeoShape) -> bool {
+        self.geometry.contains(&shape.to_geometry())
+    }
+}
+
+impl GeoShape {
+    pub fn validate_line_string(line: &GeoLineString) -> OperationResult<()> {
+        if line.points.len() < 2 {
+            return Err(OperationError::ValidationError {
+                description: format!(
+                    "line string invalid, the size must be at least 2, got {}",
+                    line.points.len()
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check coordinates range and the size of every line in the shape
+    pub fn validate(&self) -> OperationResult<()> {
+        let validate_points = |points: &[GeoPoint]| {
+            points.iter().try_for_each(|point| {
+                GeoPoint::validate(point.lon, point.lat).map_err(|err| {
+                    OperationError::ValidationError {
+                        description: err.to_string(),
+                    }
+                })
+            })
+        };
+        match self {
+            GeoShape::Point(point) => validate_points(std::slice::from_ref(point)),
+            GeoShape::LineString(line) => {
+                validate_points(&line.points)?;
+                Self::validate_line_string(line)
+            }
+            GeoShape::Polygon(polygon) => {
+                let interiors = polygon.interiors.iter().flatten();
+                for line in std::iter::once(&polygon.exterior).chain(interiors) {
+                    validate_points(&line.points)?;
+                    GeoPolygon::validate_line_string(line)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Extract geo point in `{"lon": .., "lat": ..}` or GeoJSON format from payload value
+    pub fn from_payload(value: &Value) -> Option<Self> {
+        let Value::Object(obj) = value else {
+            return None;
+        };
+        let lon = obj.get("lon").and_then(Value::as_f64);
+        let lat = obj.get("lat").and_then(Value::as_f64);
+        if let (Some(lon), Some(lat)) = (lon, lat) {
+            return GeoPoint::new(lon, lat).ok().map(GeoShape::Point);
+        }
+        GeoShape::deserialize(value).ok()
+    }
+
+    fn to_geometry(&self) -> Geometry {
+        match self {
+            GeoShape::Point(point) => Geometry::Point(Point::new(point.lon, point.lat)),
+            GeoShape::LineString(line) => Geometry::LineString(LineString(
+                line.points
+                    .iter()
+                    .map(|p| Coord { x: p.lon, y: p.lat })
+                    .collect(),
+            )),
+            GeoShape::Polygon(polygon) => Geometry::Polygon(polygon.convert().polygon),
+        }
+    }
+
+    // convert GeoShape to Geo crate Geometry for checking intersections
+    pub fn convert(&self) -> GeoShapeWrapper {
+        GeoShapeWrapper {
+            geometry: self.to_geometry(),
+        }
+    }
+}
+
+impl TryFrom<GeoJsonGeometry> for GeoShape {
+    type Error = OperationError;
+
+    fn try_from(value: GeoJsonGeometry) -> OperationResult<Self> {
+        fn to_point([lon, lat]: [f64; 2]) -> OperationResult<GeoPoint> {
+            GeoPoint::new(lon, lat).map_err(|err| OperationError::ValidationError {
+                description: err.to_string(),
+            })
+        }
+
+        fn to_line(positions: Vec<[f64; 2]>) -> OperationResult<GeoLineString> {
+            let points = positions.into_iter().map(to_point).try_collect()?;
+            Ok(GeoLineString { points })
+        }
+
+        match value {
+            GeoJsonGeometry::Point(position) => Ok(GeoShape::Point(to_point(position)?)),
+            GeoJsonGeometry::LineString(positions) => {
+                let line = to_line(positions)?;
+                Self::validate_line_string(&line)?;
+                Ok(GeoShape::LineString(line))
+            }
+            GeoJsonGeometry::Polygon(rings) => {
+                let mut rings = rings.into_iter().map(to_line);
+                let exterior = rings
+                    .next()
+                    .ok_or_else(|| OperationError::ValidationError {
+                        description: "polygon invalid, the exterior ring is missing".to_string(),
+                    })??;
+                let interiors: Vec<_> = rings.try_collect()?;
+                let polygon = GeoPolygon::new(&exterior, &interiors)?;
+                Ok(GeoShape::Polygon(polygon))
+            }
+        }
+    }
+}
+
+impl From<GeoShape> for GeoJsonGeometry {
+    fn from(value: GeoShape) -> Self {
+        fn to_positions(line: GeoLineString) -> Vec<[f64; 2]> {
+            line.points.into_iter().map(|p| [p.lon, p.lat]).collect()
+        }
+
+        match value {
+            GeoShape::Point(point) => GeoJsonGeometry::Point([point.lon, point.lat]),
+            GeoShape::LineString(line) => GeoJsonGeometry::LineString(to_positions(line)),
+            GeoShape::Polygon(polygon) => GeoJsonGeometry::Polygon(
+                std::iter::once(polygon.exterior)
+                    .chain(polygon.interiors.unwrap_or_default())
+                    .map(to_positions)
+                    .collect(),
+            ),
+        }
+    }
+}
+
 /// All possible payload filtering conditions
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
 #[validate(schema(function = "validate_field_condition"))]
@@ -1536,13 +2061,17 @@ pub struct FieldCondition {
     /// Check if point has field with a given value
     pub r#match: Option<Match>,
     /// Check if points value lies in a given range
-    pub range: Option<Range>,
+    pub range: Option<RangeInterface>,
     /// Check if points geo location lies in a given area
     pub geo_bounding_box: Option<GeoBoundingBox>,
     /// Check if geo point is within a given radius
     pub geo_radius: Option<GeoRadius>,
     /// Check if geo point is within a given polygon
     pub geo_polygon: Option<GeoPolygon>,
+    /// Check if geo point or geo shape intersects a given GeoJSON geometry
+    pub geo_intersects: Option<GeoShape>,
+    /// Check if geo point or geo shape lies completely within a given GeoJSON geometry
+    pub geo_within: Option<GeoShape>,
     /// Check number of values of the field
     pub values_count: Option<ValuesCount>,
 }
@@ -1556,6 +2085,8 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
             values_count: None,
         }
     }
@@ -1564,10 +2095,40 @@ impl FieldCondition {
         Self {
             key: key.into(),
             r#match: None,
-            range: Some(range),
+            range: Some(RangeInterface::Float(range)),
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
+            values_count: None,
+        }
+    }
+
+    pub fn new_datetime_range(key: impl Into<PayloadKeyType>, range: DatetimeRange) -> Self {
+        Self {
+            key: key.into(),
+            r#match: None,
+            range: Some(RangeInterface::DateTime(range)),
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
+            values_count: None,
+        }
+    }
+
+    pub fn new_keyword_range(key: impl Into<PayloadKeyType>, range: KeywordRange) -> Self {
+        Self {
+            key: key.into(),
+            r#match: None,
+            range: Some(RangeInterface::Keyword { keyword: range }),
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
             values_count: None,
         }
     }
@@ -1583,6 +2144,8 @@ impl FieldCondition {
             geo_bounding_box: Some(geo_bounding_box),
             geo_radius: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
             values_count: None,
         }
     }
@@ -1595,6 +2158,8 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: Some(geo_radius),
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
             values_count: None,
         }
     }
@@ -1607,6 +2172,36 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: Some(geo_polygon),
+            geo_intersects: None,
+            geo_within: None,
+            values_count: None,
+        }
+    }
+
+    pub fn new_geo_intersects(key: impl Into<PayloadKeyType>, geo_shape: GeoShape) -> Self {
+        Self {
+            key: key.into(),
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_intersects: Some(geo_shape),
+            geo_within: None,
+            values_count: None,
+        }
+    }
+
+    pub fn new_geo_within(key: impl Into<PayloadKeyType>, geo_shape: GeoShape) -> Self {
+        Self {
+            key: key.into(),
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_intersects: None,
+            geo_within: Some(geo_shape),
             values_count: None,
         }
     }
@@ -1619,6 +2214,8 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_intersects: None,
+            geo_within: None,
             values_count: Some(values_count),
         }
     }
@@ -1629,6 +2226,8 @@ impl FieldCondition {
             && self.geo_bounding_box.is_none()
             && self.geo_radius.is_none()
             && self.geo_polygon.is_none()
+            && self.geo_intersects.is_none()
+            && self.geo_within.is_none()
             && self.values_count.is_none()
     }
 }
@@ -1950,12 +2549,25 @@ pub struct WithPayload {
     pub payload_selector: Option<PayloadSelector>,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct MinShould {
+    /// Conditions to count the matches of
+    #[validate]
+    pub conditions: Vec<Condition>,
+    /// Minimal number of the conditions which should match
+    pub min_count: usize,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct Filter {
     /// At least one of those conditions should match
     #[validate]
     pub should: Option<Vec<Condition>>,
+    /// At least minimum amount of given conditions should match
+    #[validate]
+    pub min_should: Option<MinShould>,
     /// All conditions must match
     #[validate]
     pub must: Option<Vec<Condition>>,
@@ -1968,6 +2580,7 @@ impl Filter {
     pub fn new_should(condition: Condition) -> Self {
         Filter {
             should: Some(vec![condition]),
+            min_should: None,
             must: None,
             must_not: None,
         }
@@ -1976,6 +2589,7 @@ impl Filter {
     pub fn new_must(condition: Condition) -> Self {
         Filter {
             should: None,
+            min_should: None,
             must: Some(vec![condition]),
             must_not: None,
         }
@@ -1984,6 +2598,7 @@ impl Filter {
     pub fn new_must_not(condition: Condition) -> Self {
         Filter {
             should: None,
+            min_should: None,
             must: None,
             must_not: Some(vec![condition]),
         }
@@ -2001,9 +2616,29 @@ impl Filter {
                 }
             }
         };
+        // Two `min_should` clauses can't be combined into one, so the other one is nested in `must`
+        let (min_should, other_must) = match (&self.min_should, &other.min_should) {
+            (Some(this), Some(other_min_should)) => (
+                Some(this.clone()),
+                merge_component(
+                    other.must.clone(),
+                    Some(vec![Condition::Filter(Filter {
+                        should: None,
+                        min_should: Some(other_min_should.clone()),
+                        must: None,
+                        must_not: None,
+                    })]),
+                ),
+            ),
+            (this, other_min_should) => (
+                this.clone().or_else(|| other_min_should.clone()),
+                other.must.clone(),
+            ),
+        };
         Filter {
             should: merge_component(self.should.clone(), other.should.clone()),
-            must: merge_component(self.must.clone(), other.must.clone()),
+            min_should,
+            must: merge_component(self.must.clone(), other_must),
             must_not: merge_component(self.must_not.clone(), other.must_not.clone()),
         }
     }
@@ -2201,6 +2836,7 @@ mod tests {
             ))]),
             must_not: None,
             should: None,
+            min_should: None,
         };
         let json = serde_json::to_string_pretty(&filter).unwrap();
         eprintln!("{json}")
@@ -2262,6 +2898,92 @@ mod tests {
                 value: ValueVariants::Keyword("world".to_owned())
             })
         );
+
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "phrase": "new york" }
+        }
+        "#;
+
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_phrase("new york"));
+
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "prefix": "eu-" }
+        }
+        "#;
+
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_prefix("eu-"));
+    }
+
+    #[test]
+    fn test_parse_range_query() {
+        let query = r#"
+        {
+            "key": "price",
+            "range": { "gte": 10, "lt": 20.5 }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(
+            condition.range.unwrap(),
+            RangeInterface::Float(Range {
+                gte: Some(10.0),
+                lt: Some(20.5),
+                ..Default::default()
+            })
+        );
+
+        let query = r#"
+        {
+            "key": "created_at",
+            "range": { "gt": "2024-01-01T00:00:00+02:00", "lte": "2024-01-31T12:30:00.123Z" }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        let expected_gt = chrono::DateTime::parse_from_rfc3339("2023-12-31T22:00:00Z").unwrap();
+        let expected_lte =
+            chrono::DateTime::parse_from_rfc3339("2024-01-31T12:30:00.123Z").unwrap();
+        assert_eq!(
+            condition.range.unwrap(),
+            RangeInterface::DateTime(DatetimeRange {
+                gt: Some(DateTimePayloadType(expected_gt.into())),
+                lte: Some(DateTimePayloadType(expected_lte.into())),
+                ..Default::default()
+            })
+        );
+
+        // keyword ranges are explicitly wrapped
+        let query = r#"
+        {
+            "key": "sku",
+            "range": { "keyword": { "gte": "A100", "lt": "A200" } }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(
+            condition.range.unwrap(),
+            RangeInterface::Keyword {
+                keyword: KeywordRange {
+                    gte: Some("A100".to_string()),
+                    lt: Some("A200".to_string()),
+                    ..Default::default()
+                }
+            }
+        );
+
+        let query = r#"
+        {
+            "key": "created_at",
+            "range": { "gt": "yesterday" }
+        }
+        "#;
+        let result: Result<FieldCondition, _> = serde_json::from_str(query);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -2564,6 +3286,50 @@ mod tests {
         assert_eq!(must.len(), 2);
     }
 
+    #[test]
+    fn test_geo_shape_serialization() {
+        let polygon = json!({
+            "type": "Polygon",
+            "coordinates": [
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]],
+                [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0], [4.0, 4.0]]
+            ]
+        });
+        let shape: GeoShape = serde_json::from_value(polygon.clone()).unwrap();
+        let GeoShape::Polygon(geo_polygon) = &shape else {
+            panic!("polygon expected, got {shape:?}");
+        };
+        assert_eq!(geo_polygon.interiors.as_ref().unwrap().len(), 1);
+        assert_eq!(serde_json::to_value(&shape).unwrap(), polygon);
+
+        let condition: FieldCondition = serde_json::from_value(json!({
+            "key": "zone",
+            "geo_intersects": { "type": "Point", "coordinates": [13.4, 52.5] }
+        }))
+        .unwrap();
+        assert_eq!(
+            condition.geo_intersects,
+            Some(GeoShape::Point(GeoPoint {
+                lon: 13.4,
+                lat: 52.5
+            }))
+        );
+
+        let invalid_shapes = [
+            json!({ "type": "Point", "coordinates": [200.0, 52.5] }),
+            json!({ "type": "LineString", "coordinates": [[13.4, 52.5]] }),
+            json!({ "type": "Polygon", "coordinates": [] }),
+            json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]] }),
+            json!({ "type": "MultiPoint", "coordinates": [[13.4, 52.5]] }),
+        ];
+        for invalid_shape in invalid_shapes {
+            assert!(
+                serde_json::from_value::<GeoShape>(invalid_shape.clone()).is_err(),
+                "{invalid_shape} should be rejected",
+            );
+        }
+    }
+
     #[test]
     fn test_geo_validation() {
         let query1 = r#"
@@ -2860,6 +3626,36 @@ mod tests {
         assert!(merged.should.as_ref().unwrap().contains(&condition1));
     }
 
+    #[test]
+    fn merge_min_should_filters() {
+        let min_should = |key: &str| MinShould {
+            conditions: vec![
+                Condition::Field(FieldCondition::new_match(key, Match::new_text("a"))),
+                Condition::Field(FieldCondition::new_match(key, Match::new_text("b"))),
+            ],
+            min_count: 2,
+        };
+        let this = Filter {
+            min_should: Some(min_should("summary")),
+            ..Default::default()
+        };
+        let other = Filter {
+            min_should: Some(min_should("title")),
+            ..Default::default()
+        };
+
+        let merged = this.merge(&other);
+
+        assert_eq!(merged.min_should, Some(min_should("summary")));
+        assert_eq!(
+            merged.must,
+            Some(vec![Condition::Filter(Filter {
+                min_should: Some(min_should("title")),
+                ..Default::default()
+            })]),
+        );
+    }
+
     #[test]
     fn test_payload_selector_include() {
         let payload = json!({
@@ -2979,6 +3775,19 @@ mod tests {
         });
         assert_eq!(selected_payload, expected.into());
 
+        // with array index and object wildcard
+        let selector = PayloadSelector::new_include(vec!["b.*[1].e".to_string()]);
+        let selected_payload = selector.process(payload.clone().into());
+
+        let expected = json!({
+            "b": {
+                "c": [
+                    {"e": 4}
+                ]
+            }
+        });
+        assert_eq!(selected_payload, expected.into());
+
         // shortcuts implicit array traversal
         let selector = PayloadSelector::new_include(vec!["b.c.d".to_string()]);
         let selected_payload = selector.process(payload.into());
@@ -3067,15 +3876,15 @@ mod tests {
         });
         assert_eq!(payload, expected.into());
 
-        // ignore path that points to array
+        // path that points to array element
         let selector = PayloadSelector::new_exclude(vec!["b.f[0]".to_string()]);
         let payload = selector.process(payload);
 
-        // no removal
+        // element removal
         let expected = json!({
             "b": {
                 "c": 123,
-                "f": [1,2,3,4,5],
+                "f": [2,3,4,5],
             }
         });
         assert_eq!(payload, expected.into());
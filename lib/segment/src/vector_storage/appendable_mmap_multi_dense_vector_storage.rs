@@ -0,0 +1,259 @@
+use std::fs::create_dir_all;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use bitvec::prelude::BitSlice;
+use common::types::PointOffsetType;
+
+use super::MultiVectorStorage;
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::vectors::{MultiDenseVectorRef, VectorElementType, VectorRef};
+use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
+use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
+use crate::vector_storage::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+const VECTORS_DIR_PATH: &str = "vectors";
+const OFFSETS_DIR_PATH: &str = "offsets";
+const DELETED_DIR_PATH: &str = "deleted";
+
+/// Location of the matrix of a point in the inner vectors storage
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+struct MultivectorMmapOffset {
+    offset: PointOffsetType,
+    count: PointOffsetType,
+}
+
+/// Appendable on-disk storage of multi vectors
+///
+/// All vectors of every point are stored one after another in `vectors`, `offsets` maps a point
+/// to its range of vectors. Updated points get a new range, the old one is left unused until the
+/// segment is optimized.
+pub struct AppendableMmapMultiDenseVectorStorage {
+    vectors: ChunkedMmapVectors<VectorElementType>,
+    offsets: ChunkedMmapVectors<MultivectorMmapOffset>,
+    deleted: DynamicMmapFlags,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    deleted_count: usize,
+}
+
+pub fn open_appendable_memmap_multi_vector_storage(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    create_dir_all(path)?;
+
+    let vectors_path = path.join(VECTORS_DIR_PATH);
+    let offsets_path = path.join(OFFSETS_DIR_PATH);
+    let deleted_path = path.join(DELETED_DIR_PATH);
+
+    let vectors = ChunkedMmapVectors::open(&vectors_path, dim)?;
+    let offsets = ChunkedMmapVectors::open(&offsets_path, 1)?;
+
+    let num_vectors = offsets.len();
+
+    let deleted: DynamicMmapFlags = DynamicMmapFlags::open(&deleted_path)?;
+
+    let mut deleted_count = 0;
+
+    for i in 0..num_vectors {
+        if deleted.get(i) {
+            deleted_count += 1;
+        }
+    }
+
+    let storage = AppendableMmapMultiDenseVectorStorage {
+        vectors,
+        offsets,
+        deleted,
+        distance,
+        multi_vector_config,
+        deleted_count,
+    };
+
+    Ok(Arc::new(AtomicRefCell::new(
+        VectorStorageEnum::AppendableMemmapMultiDense(Box::new(storage)),
+    )))
+}
+
+impl AppendableMmapMultiDenseVectorStorage {
+    /// Set deleted flag for given key. Returns previous deleted state.
+    #[inline]
+    fn set_deleted(&mut self, key: PointOffsetType, deleted: bool) -> OperationResult<bool> {
+        if self.offsets.len() <= key as usize {
+            return Ok(false);
+        }
+
+        if self.deleted.len() <= key as usize {
+            self.deleted.set_len(key as usize + 1)?;
+        }
+        let previous = self.deleted.set(key, deleted);
+        if !previous && deleted {
+            self.deleted_count += 1;
+        } else if previous && !deleted {
+            self.deleted_count -= 1;
+        }
+        Ok(previous)
+    }
+
+    fn insert_multi(
+        &mut self,
+        key: PointOffsetType,
+        vector: MultiDenseVectorRef,
+    ) -> OperationResult<()> {
+        let dim = self.vectors.dim();
+        if vector.dim != dim {
+            return Err(OperationError::WrongVector {
+                expected_dim: dim,
+                received_dim: vector.dim,
+            });
+        }
+
+        // All vectors of a point must be placed in the same chunk to be read as a single slice
+        let count = vector.vectors_count();
+        if count == 0 {
+            return Err(OperationError::ValidationError {
+                description: "multi vector must contain at least one vector".to_string(),
+            });
+        }
+        let chunk_capacity = self.vectors.chunk_capacity();
+        if count > chunk_capacity {
+            return Err(OperationError::ValidationError {
+                description: format!(
+                    "multi vector of {count} vectors exceeds the maximum of {chunk_capacity}",
+                ),
+            });
+        }
+        let mut offset = self.vectors.len();
+        if offset / chunk_capacity != (offset + count - 1) / chunk_capacity {
+            offset = (offset / chunk_capacity + 1) * chunk_capacity;
+        }
+
+        self.vectors
+            .insert_many(offset as PointOffsetType, vector.flattened_vectors, count)?;
+        self.offsets.insert(
+            key,
+            &[MultivectorMmapOffset {
+                offset: offset as PointOffsetType,
+                count: count as PointOffsetType,
+            }],
+        )?;
+        Ok(())
+    }
+}
+
+impl MultiVectorStorage for AppendableMmapMultiDenseVectorStorage {
+    fn get_multi(&self, key: PointOffsetType) -> MultiDenseVectorRef {
+        let MultivectorMmapOffset { offset, count } = self.offsets.get(key)[0];
+        MultiDenseVectorRef {
+            flattened_vectors: self
+                .vectors
+                .get_many(offset, count as usize)
+                .expect("multi vector must be stored in a single chunk"),
+            dim: self.vectors.dim(),
+        }
+    }
+
+    fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+}
+
+impl VectorStorage for AppendableMmapMultiDenseVectorStorage {
+    fn vector_dim(&self) -> usize {
+        self.vectors.dim()
+    }
+
+    fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        VectorStorageDatatype::Float32
+    }
+
+    fn is_on_disk(&self) -> bool {
+        true
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn get_vector(&self, key: PointOffsetType) -> CowVector {
+        CowVector::from(self.get_multi(key).to_owned())
+    }
+
+    fn insert_vector(&mut self, key: PointOffsetType, vector: VectorRef) -> OperationResult<()> {
+        self.insert_multi(key, vector.try_into()?)?;
+        self.set_deleted(key, false)?;
+        Ok(())
+    }
+
+    fn update_from(
+        &mut self,
+        other: &VectorStorageEnum,
+        other_ids: &mut dyn Iterator<Item = PointOffsetType>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let start_index = self.offsets.len() as PointOffsetType;
+        for point_id in other_ids {
+            check_process_stopped(stopped)?;
+            // Do not perform preprocessing - vectors should be already processed
+            let other_deleted = other.is_deleted_vector(point_id);
+            let other_vector = other.get_vector(point_id);
+            let other_vector = other_vector.as_vec_ref().try_into()?;
+            let new_id = self.offsets.len() as PointOffsetType;
+            self.insert_multi(new_id, other_vector)?;
+            self.set_deleted(new_id, other_deleted)?;
+        }
+        let end_index = self.offsets.len() as PointOffsetType;
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        Box::new({
+            let vectors_flusher = self.vectors.flusher();
+            let offsets_flusher = self.offsets.flusher();
+            let deleted_flusher = self.deleted.flusher();
+            move || {
+                vectors_flusher()?;
+                offsets_flusher()?;
+                deleted_flusher()?;
+                Ok(())
+            }
+        })
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.vectors.files();
+        files.extend(self.offsets.files());
+        files.extend(self.deleted.files());
+        files
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        self.set_deleted(key, true)
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.deleted_count
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.deleted.get_bitslice()
+    }
+}
@@ -6,7 +6,6 @@ use io_uring::{opcode, types, IoUring};
 use memory::mmap_ops::transmute_from_u8_to_slice;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::vectors::VectorElementType;
 
 const DISK_PARALLELISM: usize = 16; // TODO: benchmark it better, or make it configurable
 
@@ -70,10 +69,10 @@ impl UringReader {
     }
 
     /// Takes in iterator of point offsets, reads it, and yields a callback with the read data.
-    pub fn read_stream(
+    pub fn read_stream<T>(
         &mut self,
         points: impl IntoIterator<Item = PointOffsetType>,
-        mut callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+        mut callback: impl FnMut(usize, PointOffsetType, &[T]),
     ) -> OperationResult<()> {
         // Take `UringReader::io_uring`, so that if we return an error or panic during `read_stream`,
         // `IoUring` would be transparently dropped.
@@ -149,11 +148,11 @@ impl UringReader {
     }
 }
 
-fn submit_and_read(
+fn submit_and_read<T>(
     io_uring: &mut IoUring,
     buffers: &mut BufferStore,
     unused_buffer_ids: &mut Vec<usize>,
-    mut callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+    mut callback: impl FnMut(usize, PointOffsetType, &[T]),
     raw_size: usize,
 ) -> OperationResult<()> {
     let buffers_count = buffers.buffers.len();
@@ -22,7 +22,7 @@ use crate::vector_storage::{RawScorer, VectorStorage as _, DEFAULT_STOPPED};
 
 pub fn new<'a>(
     query: QueryVector,
-    storage: &'a MemmapVectorStorage,
+    storage: &'a MemmapVectorStorage<VectorElementType>,
     point_deleted: &'a BitSlice,
     is_stopped: &'a AtomicBool,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
@@ -34,7 +34,7 @@ pub fn new<'a>(
 pub struct AsyncRawScorerImpl<'a, TQueryScorer: QueryScorer<[VectorElementType]>> {
     points_count: PointOffsetType,
     query_scorer: TQueryScorer,
-    storage: &'a MmapVectors,
+    storage: &'a MmapVectors<VectorElementType>,
     point_deleted: &'a BitSlice,
     vec_deleted: &'a BitSlice,
     /// This flag indicates that the search process is stopped externally,
@@ -49,7 +49,7 @@ where
     fn new(
         points_count: PointOffsetType,
         query_scorer: TQueryScorer,
-        storage: &'a MmapVectors,
+        storage: &'a MmapVectors<VectorElementType>,
         point_deleted: &'a BitSlice,
         vec_deleted: &'a BitSlice,
         is_stopped: &'a AtomicBool,
@@ -209,7 +209,7 @@ where
 struct AsyncRawScorerBuilder<'a> {
     points_count: PointOffsetType,
     query: QueryVector,
-    storage: &'a MemmapVectorStorage,
+    storage: &'a MemmapVectorStorage<VectorElementType>,
     point_deleted: &'a BitSlice,
     vec_deleted: &'a BitSlice,
     distance: Distance,
@@ -219,7 +219,7 @@ struct AsyncRawScorerBuilder<'a> {
 impl<'a> AsyncRawScorerBuilder<'a> {
     pub fn new(
         query: QueryVector,
-        storage: &'a MemmapVectorStorage,
+        storage: &'a MemmapVectorStorage<VectorElementType>,
         point_deleted: &'a BitSlice,
     ) -> OperationResult<Self> {
         let points_count = storage.total_vector_count() as _;
@@ -270,7 +270,7 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                 match vector {
                     Vector::Dense(dense_vector) => {
                         let query_scorer =
-                            MetricQueryScorer::<TMetric, _>::new(dense_vector, storage);
+                            MetricQueryScorer::<_, TMetric, _>::new(dense_vector, storage);
                         Ok(Box::new(AsyncRawScorerImpl::new(
                             points_count,
                             query_scorer,
@@ -283,11 +283,12 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                     Vector::Sparse(_sparse_vector) => Err(OperationError::service_error(
                         "sparse vectors are not supported for async scorer",
                     )), // TODO(sparse) add support?
+                    Vector::MultiDense(_multi_dense_vector) => Err(OperationError::WrongMulti),
                 }
             }
             QueryVector::Recommend(reco_query) => {
                 let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
-                let query_scorer = CustomQueryScorer::<TMetric, _, _>::new(reco_query, storage);
+                let query_scorer = CustomQueryScorer::<_, TMetric, _, _>::new(reco_query, storage);
                 Ok(Box::new(AsyncRawScorerImpl::new(
                     points_count,
                     query_scorer,
@@ -301,7 +302,7 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                 let discovery_query: DiscoveryQuery<DenseVector> =
                     discovery_query.transform_into()?;
                 let query_scorer =
-                    CustomQueryScorer::<TMetric, _, _>::new(discovery_query, storage);
+                    CustomQueryScorer::<_, TMetric, _, _>::new(discovery_query, storage);
                 Ok(Box::new(AsyncRawScorerImpl::new(
                     points_count,
                     query_scorer,
@@ -313,7 +314,8 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             }
             QueryVector::Context(context_query) => {
                 let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
-                let query_scorer = CustomQueryScorer::<TMetric, _, _>::new(context_query, storage);
+                let query_scorer =
+                    CustomQueryScorer::<_, TMetric, _, _>::new(context_query, storage);
                 Ok(Box::new(AsyncRawScorerImpl::new(
                     points_count,
                     query_scorer,
@@ -11,7 +11,6 @@ use serde::{Deserialize, Serialize};
 use crate::common::mmap_type::MmapType;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::Flusher;
-use crate::data_types::vectors::VectorElementType;
 use crate::vector_storage::chunked_utils::{chunk_name, create_chunk, read_mmaps, MmapChunk};
 
 #[cfg(debug_assertions)]
@@ -34,14 +33,17 @@ struct ChunkedMmapConfig {
     dim: usize,
 }
 
-pub struct ChunkedMmapVectors {
+/// Appendable storage of fixed size vectors in memory mapped chunks
+///
+/// `T` is the type of vector element, vectors of `dim` elements each are stored continuously.
+pub struct ChunkedMmapVectors<T: Sized + 'static> {
     config: ChunkedMmapConfig,
     status: MmapType<Status>,
-    chunks: Vec<MmapChunk>,
+    chunks: Vec<MmapChunk<T>>,
     directory: PathBuf,
 }
 
-impl ChunkedMmapVectors {
+impl<T: Sized + Copy + 'static> ChunkedMmapVectors<T> {
     fn config_file(directory: &Path) -> PathBuf {
         directory.join(CONFIG_FILE_NAME)
     }
@@ -69,7 +71,7 @@ impl ChunkedMmapVectors {
         let config_file = Self::config_file(directory);
         if !config_file.exists() {
             let chunk_size_bytes = DEFAULT_CHUNK_SIZE;
-            let vector_size_bytes = dim * std::mem::size_of::<VectorElementType>();
+            let vector_size_bytes = dim * std::mem::size_of::<T>();
             let chunk_size_vectors = chunk_size_bytes / vector_size_bytes;
             let corrected_chunk_size_bytes = chunk_size_vectors * vector_size_bytes;
 
@@ -149,15 +151,35 @@ impl ChunkedMmapVectors {
         Ok(())
     }
 
-    pub fn insert(
+    /// Number of vectors that fit into a single chunk
+    pub fn chunk_capacity(&self) -> usize {
+        self.config.chunk_size_vectors
+    }
+
+    pub fn insert(&mut self, key: PointOffsetType, vector: &[T]) -> OperationResult<()> {
+        self.insert_many(key, vector, 1)
+    }
+
+    /// Insert `count` consecutive vectors starting at `start_key`
+    ///
+    /// All vectors must fit into the same chunk, see [`Self::chunk_capacity`].
+    pub fn insert_many(
         &mut self,
-        key: PointOffsetType,
-        vector: &[VectorElementType],
+        start_key: PointOffsetType,
+        vectors: &[T],
+        count: usize,
     ) -> OperationResult<()> {
-        let key = key as usize;
+        debug_assert_eq!(vectors.len(), count * self.config.dim);
+        let key = start_key as usize;
         let chunk_idx = self.get_chunk_index(key);
         let chunk_offset = self.get_chunk_offset(key);
 
+        if count > 0 && self.get_chunk_index(key + count - 1) != chunk_idx {
+            return Err(OperationError::service_error(format!(
+                "{count} vectors starting at {key} do not fit into a single chunk",
+            )));
+        }
+
         // Ensure capacity
         while chunk_idx >= self.chunks.len() {
             self.add_chunk()?;
@@ -165,9 +187,9 @@ impl ChunkedMmapVectors {
 
         let chunk = &mut self.chunks[chunk_idx];
 
-        chunk[chunk_offset..chunk_offset + vector.len()].copy_from_slice(vector);
+        chunk[chunk_offset..chunk_offset + vectors.len()].copy_from_slice(vectors);
 
-        let new_len = max(self.status.len, key + 1);
+        let new_len = max(self.status.len, key + count);
 
         if new_len > self.status.len {
             self.status.len = new_len;
@@ -175,13 +197,13 @@ impl ChunkedMmapVectors {
         Ok(())
     }
 
-    pub fn push(&mut self, vector: &[VectorElementType]) -> OperationResult<PointOffsetType> {
+    pub fn push(&mut self, vector: &[T]) -> OperationResult<PointOffsetType> {
         let new_id = self.status.len as PointOffsetType;
         self.insert(new_id, vector)?;
         Ok(new_id)
     }
 
-    pub fn get<TKey>(&self, key: TKey) -> &[VectorElementType]
+    pub fn get<TKey>(&self, key: TKey) -> &[T]
     where
         TKey: num_traits::cast::AsPrimitive<usize>,
     {
@@ -192,6 +214,23 @@ impl ChunkedMmapVectors {
         &chunk[chunk_offset..chunk_offset + self.config.dim]
     }
 
+    /// Get `count` consecutive vectors starting at `start_key` as a single slice
+    ///
+    /// Returns `None` if the vectors are not placed in the same chunk.
+    pub fn get_many<TKey>(&self, start_key: TKey, count: usize) -> Option<&[T]>
+    where
+        TKey: num_traits::cast::AsPrimitive<usize>,
+    {
+        let key: usize = start_key.as_();
+        let chunk_idx = self.get_chunk_index(key);
+        if count > 0 && self.get_chunk_index(key + count - 1) != chunk_idx {
+            return None;
+        }
+        let chunk_offset = self.get_chunk_offset(key);
+        let chunk = self.chunks.get(chunk_idx)?;
+        chunk.get(chunk_offset..chunk_offset + count * self.config.dim)
+    }
+
     pub fn flusher(&self) -> Flusher {
         Box::new({
             let status_flusher = self.status.flusher();
@@ -224,6 +263,7 @@ mod tests {
     use tempfile::Builder;
 
     use super::*;
+    use crate::data_types::vectors::VectorElementType;
     use crate::fixtures::index_fixtures::random_vector;
 
     #[test]
@@ -238,7 +278,7 @@ mod tests {
             .collect();
 
         {
-            let mut chunked_mmap: ChunkedMmapVectors =
+            let mut chunked_mmap: ChunkedMmapVectors<VectorElementType> =
                 ChunkedMmapVectors::open(dir.path(), dim).unwrap();
 
             for vec in &vectors {
@@ -264,7 +304,7 @@ mod tests {
         }
 
         {
-            let chunked_mmap: ChunkedMmapVectors =
+            let chunked_mmap: ChunkedMmapVectors<VectorElementType> =
                 ChunkedMmapVectors::open(dir.path(), dim).unwrap();
 
             assert!(
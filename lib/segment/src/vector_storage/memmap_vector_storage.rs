@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fs::{create_dir_all, File, OpenOptions};
 use std::io::{self, Write};
 use std::ops::Range;
@@ -14,8 +15,11 @@ use super::{DenseVectorStorage, VectorStorageEnum};
 use crate::common::operation_error::{check_process_stopped, OperationResult};
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
-use crate::data_types::vectors::{DenseVector, VectorElementType, VectorRef};
-use crate::types::Distance;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    DenseVector, VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+};
+use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::common::get_async_scorer;
 use crate::vector_storage::mmap_vectors::MmapVectors;
 use crate::vector_storage::VectorStorage;
@@ -29,10 +33,10 @@ const DELETED_PATH: &str = "deleted.dat";
 /// but possible to mark some vectors as removed
 ///
 /// Mem-mapped storage can only be constructed from another storage
-pub struct MemmapVectorStorage {
+pub struct MemmapVectorStorage<T: PrimitiveVectorElement> {
     vectors_path: PathBuf,
     deleted_path: PathBuf,
-    mmap_store: Option<MmapVectors>,
+    mmap_store: Option<MmapVectors<T>>,
     distance: Distance,
 }
 
@@ -44,29 +48,72 @@ pub fn open_memmap_vector_storage(
     open_memmap_vector_storage_with_async_io(path, dim, distance, get_async_scorer())
 }
 
+pub fn open_memmap_byte_vector_storage(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage = open_memmap_vector_storage_impl::<VectorElementTypeByte>(
+        path,
+        dim,
+        distance,
+        get_async_scorer(),
+    )?;
+    Ok(Arc::new(AtomicRefCell::new(VectorStorageEnum::MemmapByte(
+        Box::new(storage),
+    ))))
+}
+
+pub fn open_memmap_half_vector_storage(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage = open_memmap_vector_storage_impl::<VectorElementTypeHalf>(
+        path,
+        dim,
+        distance,
+        get_async_scorer(),
+    )?;
+    Ok(Arc::new(AtomicRefCell::new(VectorStorageEnum::MemmapHalf(
+        Box::new(storage),
+    ))))
+}
+
 pub fn open_memmap_vector_storage_with_async_io(
     path: &Path,
     dim: usize,
     distance: Distance,
     with_async_io: bool,
 ) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage =
+        open_memmap_vector_storage_impl::<VectorElementType>(path, dim, distance, with_async_io)?;
+    Ok(Arc::new(AtomicRefCell::new(VectorStorageEnum::Memmap(
+        Box::new(storage),
+    ))))
+}
+
+fn open_memmap_vector_storage_impl<T: PrimitiveVectorElement>(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    with_async_io: bool,
+) -> OperationResult<MemmapVectorStorage<T>> {
     create_dir_all(path)?;
 
     let vectors_path = path.join(VECTORS_PATH);
     let deleted_path = path.join(DELETED_PATH);
     let mmap_store = MmapVectors::open(&vectors_path, &deleted_path, dim, with_async_io)?;
 
-    Ok(Arc::new(AtomicRefCell::new(VectorStorageEnum::Memmap(
-        Box::new(MemmapVectorStorage {
-            vectors_path,
-            deleted_path,
-            mmap_store: Some(mmap_store),
-            distance,
-        }),
-    ))))
+    Ok(MemmapVectorStorage {
+        vectors_path,
+        deleted_path,
+        mmap_store: Some(mmap_store),
+        distance,
+    })
 }
 
-impl MemmapVectorStorage {
+impl<T: PrimitiveVectorElement> MemmapVectorStorage<T> {
     pub fn prefault_mmap_pages(&self) -> Option<mmap_ops::PrefaultMmapPages> {
         Some(
             self.mmap_store
@@ -75,7 +122,7 @@ impl MemmapVectorStorage {
         )
     }
 
-    pub fn get_mmap_vectors(&self) -> &MmapVectors {
+    pub fn get_mmap_vectors(&self) -> &MmapVectors<T> {
         self.mmap_store.as_ref().unwrap()
     }
 
@@ -87,13 +134,13 @@ impl MemmapVectorStorage {
     }
 }
 
-impl DenseVectorStorage for MemmapVectorStorage {
-    fn get_dense(&self, key: PointOffsetType) -> &[VectorElementType] {
+impl<T: PrimitiveVectorElement> DenseVectorStorage<T> for MemmapVectorStorage<T> {
+    fn get_dense(&self, key: PointOffsetType) -> &[T] {
         self.mmap_store.as_ref().unwrap().get_vector(key)
     }
 }
 
-impl VectorStorage for MemmapVectorStorage {
+impl<T: PrimitiveVectorElement> VectorStorage for MemmapVectorStorage<T> {
     fn vector_dim(&self) -> usize {
         self.mmap_store.as_ref().unwrap().dim
     }
@@ -102,6 +149,10 @@ impl VectorStorage for MemmapVectorStorage {
         self.distance
     }
 
+    fn datatype(&self) -> VectorStorageDatatype {
+        T::datatype()
+    }
+
     fn is_on_disk(&self) -> bool {
         true
     }
@@ -111,7 +162,8 @@ impl VectorStorage for MemmapVectorStorage {
     }
 
     fn get_vector(&self, key: PointOffsetType) -> CowVector {
-        self.get_dense(key).into()
+        let vector = self.get_dense(key);
+        CowVector::from(T::slice_to_float_cow(Cow::Borrowed(vector)).into_owned())
     }
 
     fn insert_vector(&mut self, _key: PointOffsetType, _vector: VectorRef) -> OperationResult<()> {
@@ -140,7 +192,8 @@ impl VectorStorage for MemmapVectorStorage {
         for id in other_ids {
             check_process_stopped(stopped)?;
             let vector: DenseVector = other.get_vector(id).try_into()?;
-            let raw_bites = mmap_ops::transmute_to_u8_slice(&vector);
+            let vector = T::slice_from_float_cow(Cow::Owned(vector));
+            let raw_bites = mmap_ops::transmute_to_u8_slice(vector.as_ref());
             vectors_file.write_all(raw_bites)?;
             end_index += 1;
 
@@ -1,6 +1,7 @@
 use std::fs::{File, OpenOptions};
 use std::io::Write;
-use std::mem::{self, size_of, transmute};
+use std::marker::PhantomData;
+use std::mem::{self, size_of};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -14,7 +15,7 @@ use crate::common::error_logging::LogError;
 use crate::common::mmap_type::MmapBitSlice;
 use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
-use crate::data_types::vectors::VectorElementType;
+use crate::data_types::primitive::PrimitiveVectorElement;
 #[cfg(target_os = "linux")]
 use crate::vector_storage::async_io::UringReader;
 #[cfg(not(target_os = "linux"))]
@@ -25,7 +26,7 @@ const VECTORS_HEADER: &[u8; HEADER_SIZE] = b"data";
 const DELETED_HEADER: &[u8; HEADER_SIZE] = b"drop";
 
 /// Mem-mapped file
-pub struct MmapVectors {
+pub struct MmapVectors<T: PrimitiveVectorElement> {
     pub dim: usize,
     pub num_vectors: usize,
     /// Memory mapped file for vector data
@@ -39,9 +40,10 @@ pub struct MmapVectors {
     deleted: MmapBitSlice,
     /// Current number of deleted vectors.
     pub deleted_count: usize,
+    _phantom: PhantomData<T>,
 }
 
-impl MmapVectors {
+impl<T: PrimitiveVectorElement> MmapVectors<T> {
     pub fn open(
         vectors_path: &Path,
         deleted_path: &Path,
@@ -52,7 +54,7 @@ impl MmapVectors {
         ensure_mmap_file_size(vectors_path, VECTORS_HEADER, None)
             .describe("Create mmap data file")?;
         let mmap = mmap_ops::open_read_mmap(vectors_path).describe("Open mmap for reading")?;
-        let num_vectors = (mmap.len() - HEADER_SIZE) / dim / size_of::<VectorElementType>();
+        let num_vectors = (mmap.len() - HEADER_SIZE) / dim / size_of::<T>();
 
         // Allocate/open deleted mmap
         let deleted_mmap_size = deleted_mmap_size(num_vectors);
@@ -74,7 +76,7 @@ impl MmapVectors {
         let uring_reader = if with_async_io {
             // Keep file handle open for async IO
             let vectors_file = File::open(vectors_path)?;
-            let raw_size = dim * size_of::<VectorElementType>();
+            let raw_size = dim * size_of::<T>();
             Some(UringReader::new(vectors_file, raw_size, HEADER_SIZE)?)
         } else {
             None
@@ -87,6 +89,7 @@ impl MmapVectors {
             uring_reader: Mutex::new(uring_reader),
             deleted,
             deleted_count,
+            _phantom: PhantomData,
         })
     }
 
@@ -99,7 +102,7 @@ impl MmapVectors {
     }
 
     pub fn data_offset(&self, key: PointOffsetType) -> Option<usize> {
-        let vector_data_length = self.dim * size_of::<VectorElementType>();
+        let vector_data_length = self.dim * size_of::<T>();
         let offset = (key as usize) * vector_data_length + HEADER_SIZE;
         if key >= (self.num_vectors as PointOffsetType) {
             return None;
@@ -108,17 +111,17 @@ impl MmapVectors {
     }
 
     pub fn raw_size(&self) -> usize {
-        self.dim * size_of::<VectorElementType>()
+        self.dim * size_of::<T>()
     }
 
-    pub fn raw_vector_offset(&self, offset: usize) -> &[VectorElementType] {
+    pub fn raw_vector_offset(&self, offset: usize) -> &[T] {
         let byte_slice = &self.mmap[offset..(offset + self.raw_size())];
-        let arr: &[VectorElementType] = unsafe { transmute(byte_slice) };
+        let arr: &[T] = mmap_ops::transmute_from_u8_to_slice(byte_slice);
         &arr[0..self.dim]
     }
 
     /// Returns reference to vector data by key
-    pub fn get_vector(&self, key: PointOffsetType) -> &[VectorElementType] {
+    pub fn get_vector(&self, key: PointOffsetType) -> &[T] {
         let offset = self.data_offset(key).unwrap();
         self.raw_vector_offset(offset)
     }
@@ -155,7 +158,7 @@ impl MmapVectors {
     fn process_points_uring(
         &self,
         points: impl Iterator<Item = PointOffsetType>,
-        callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+        callback: impl FnMut(usize, PointOffsetType, &[T]),
     ) -> OperationResult<()> {
         self.uring_reader
             .lock()
@@ -168,7 +171,7 @@ impl MmapVectors {
     fn process_points_simple(
         &self,
         points: impl Iterator<Item = PointOffsetType>,
-        mut callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+        mut callback: impl FnMut(usize, PointOffsetType, &[T]),
     ) -> OperationResult<()> {
         for (idx, point) in points.enumerate() {
             let vector = self.get_vector(point);
@@ -183,7 +186,7 @@ impl MmapVectors {
     pub fn read_vectors_async(
         &self,
         points: impl Iterator<Item = PointOffsetType>,
-        callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+        callback: impl FnMut(usize, PointOffsetType, &[T]),
     ) -> OperationResult<()> {
         #[cfg(target_os = "linux")]
         {
@@ -1,7 +1,8 @@
+pub mod appendable_mmap_multi_dense_vector_storage;
 pub mod appendable_mmap_vector_storage;
 #[cfg(target_os = "linux")]
 pub mod async_raw_scorer;
-mod chunked_mmap_vectors;
+pub(crate) mod chunked_mmap_vectors;
 mod chunked_utils;
 pub mod chunked_vectors;
 mod dynamic_mmap_flags;
@@ -10,6 +11,7 @@ mod mmap_vectors;
 pub mod quantized;
 pub mod raw_scorer;
 pub mod simple_dense_vector_storage;
+pub mod simple_multi_dense_vector_storage;
 mod vector_storage_base;
 
 #[cfg(test)]
@@ -23,7 +23,7 @@ where
         quantized_data: &'a TEncodedVectors,
         distance: Distance,
     ) -> Self {
-        let original_query = distance.preprocess_vector(raw_query);
+        let original_query = distance.preprocess_vector::<VectorElementType>(raw_query);
         let query = quantized_data.encode_query(&original_query);
 
         Self {
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -9,8 +10,9 @@ use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 use serde::{Deserialize, Serialize};
 
 use super::quantized_scorer_builder::QuantizedScorerBuilder;
-use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::common::vector_utils::TrySetCapacityExact;
+use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType};
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, CompressionRatio, Distance, ProductQuantization,
@@ -110,13 +112,49 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseSimple(v) => {
                 Self::create_impl(v, quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseSimpleByte(v) => {
+                Self::create_impl_converted(v, quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::DenseSimpleHalf(v) => {
+                Self::create_impl_converted(v, quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::Memmap(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MemmapByte(v) => Self::create_impl_converted(
+                v.as_ref(),
+                quantization_config,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::MemmapHalf(v) => Self::create_impl_converted(
+                v.as_ref(),
+                quantization_config,
+                path,
+                max_threads,
+                stopped,
+            ),
             VectorStorageEnum::AppendableMemmap(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::AppendableMemmapByte(v) => Self::create_impl_converted(
+                v.as_ref(),
+                quantization_config,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::AppendableMemmapHalf(v) => Self::create_impl_converted(
+                v.as_ref(),
+                quantization_config,
+                path,
+                max_threads,
+                stopped,
+            ),
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
+            VectorStorageEnum::MultiDenseSimple(_)
+            | VectorStorageEnum::AppendableMemmapMultiDense(_) => Err(OperationError::WrongMulti),
         }
     }
 
@@ -129,6 +167,58 @@ impl QuantizedVectors {
     ) -> OperationResult<Self> {
         let count = vector_storage.total_vector_count();
         let vectors = (0..count as PointOffsetType).map(|i| vector_storage.get_dense(i));
+        Self::create_from_vectors(
+            vectors,
+            vector_storage,
+            quantization_config,
+            path,
+            max_threads,
+            stopped,
+        )
+    }
+
+    /// Quantization encodes `f32` vectors, so vectors of other datatypes are converted first
+    fn create_impl_converted<
+        TElement: PrimitiveVectorElement,
+        TVectorStorage: DenseVectorStorage<TElement> + Send + Sync,
+    >(
+        vector_storage: &TVectorStorage,
+        quantization_config: &QuantizationConfig,
+        path: &Path,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let count = vector_storage.total_vector_count();
+        let distance = vector_storage.distance();
+        let mut converted_vectors = ChunkedVectors::new(vector_storage.vector_dim());
+        converted_vectors.try_set_capacity_exact(count)?;
+        for i in 0..count as PointOffsetType {
+            check_process_stopped(stopped)?;
+            let vector = TElement::slice_to_float_cow(Cow::Borrowed(vector_storage.get_dense(i)));
+            // Byte vectors are stored as is, quantization expects them to be preprocessed
+            let vector = distance.preprocess_vector::<VectorElementType>(vector.into_owned());
+            converted_vectors.push(&vector)?;
+        }
+        let vectors = (0..count as PointOffsetType).map(|i| converted_vectors.get(i));
+        Self::create_from_vectors(
+            vectors,
+            vector_storage,
+            quantization_config,
+            path,
+            max_threads,
+            stopped,
+        )
+    }
+
+    fn create_from_vectors<'a>(
+        vectors: impl Iterator<Item = &'a [VectorElementType]> + Clone + Send,
+        vector_storage: &impl VectorStorage,
+        quantization_config: &QuantizationConfig,
+        path: &Path,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let count = vector_storage.total_vector_count();
         let on_disk_vector_storage = vector_storage.is_on_disk();
         let distance = vector_storage.distance();
         let dim = vector_storage.vector_dim();
@@ -1,32 +1,48 @@
+use std::borrow::Cow;
 use std::marker::PhantomData;
 
 use common::types::{PointOffsetType, ScoreType};
 
-use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{DenseVector, TypedDenseVector};
 use crate::spaces::metric::Metric;
 use crate::vector_storage::query_scorer::QueryScorer;
 use crate::vector_storage::DenseVectorStorage;
 
-pub struct MetricQueryScorer<'a, TMetric: Metric, TVectorStorage: DenseVectorStorage> {
+pub struct MetricQueryScorer<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> {
     vector_storage: &'a TVectorStorage,
-    query: Vec<VectorElementType>,
+    query: TypedDenseVector<TElement>,
     metric: PhantomData<TMetric>,
 }
 
-impl<'a, TMetric: Metric, TVectorStorage: DenseVectorStorage>
-    MetricQueryScorer<'a, TMetric, TVectorStorage>
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: DenseVectorStorage<TElement>,
+    > MetricQueryScorer<'a, TElement, TMetric, TVectorStorage>
 {
     pub fn new(query: DenseVector, vector_storage: &'a TVectorStorage) -> Self {
+        let preprocessed_query = TMetric::preprocess(query);
         Self {
-            query: TMetric::preprocess(query),
+            query: TElement::slice_from_float_cow(Cow::Owned(preprocessed_query)).into_owned(),
             vector_storage,
             metric: PhantomData,
         }
     }
 }
 
-impl<'a, TMetric: Metric, TVectorStorage: DenseVectorStorage> QueryScorer<[VectorElementType]>
-    for MetricQueryScorer<'a, TMetric, TVectorStorage>
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: DenseVectorStorage<TElement>,
+    > QueryScorer<[TElement]> for MetricQueryScorer<'a, TElement, TMetric, TVectorStorage>
 {
     #[inline]
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
@@ -34,7 +50,7 @@ impl<'a, TMetric: Metric, TVectorStorage: DenseVectorStorage> QueryScorer<[Vecto
     }
 
     #[inline]
-    fn score(&self, v2: &[VectorElementType]) -> ScoreType {
+    fn score(&self, v2: &[TElement]) -> ScoreType {
         TMetric::similarity(&self.query, v2)
     }
 
@@ -2,6 +2,8 @@ use common::types::{PointOffsetType, ScoreType};
 
 pub mod custom_query_scorer;
 pub mod metric_query_scorer;
+pub mod multi_custom_query_scorer;
+pub mod multi_metric_query_scorer;
 pub mod sparse_custom_query_scorer;
 
 pub trait QueryScorer<TVector: ?Sized> {
@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::MultiDenseVector;
+use crate::spaces::metric::Metric;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::multi_metric_query_scorer::{
+    preprocess_multi_vector, score_multi,
+};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::MultiVectorStorage;
+
+pub struct MultiCustomQueryScorer<
+    'a,
+    TMetric: Metric,
+    TVectorStorage: MultiVectorStorage,
+    TQuery: Query<MultiDenseVector>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TQuery,
+    metric: PhantomData<TMetric>,
+}
+
+impl<
+        'a,
+        TMetric: Metric,
+        TVectorStorage: MultiVectorStorage,
+        TQuery: Query<MultiDenseVector> + TransformInto<TQuery, MultiDenseVector, MultiDenseVector>,
+    > MultiCustomQueryScorer<'a, TMetric, TVectorStorage, TQuery>
+{
+    pub fn new(query: TQuery, vector_storage: &'a TVectorStorage) -> Self {
+        let query: TQuery = TransformInto::transform(query, |vector| {
+            Ok(preprocess_multi_vector::<TMetric>(vector))
+        })
+        .unwrap();
+
+        Self {
+            query,
+            vector_storage,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<'a, TMetric: Metric, TVectorStorage: MultiVectorStorage, TQuery: Query<MultiDenseVector>>
+    QueryScorer<MultiDenseVector> for MultiCustomQueryScorer<'a, TMetric, TVectorStorage, TQuery>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_multi(idx);
+        let multi_vector_config = self.vector_storage.multi_vector_config();
+        self.query
+            .score_by(|example| score_multi::<TMetric>(multi_vector_config, example.into(), stored))
+    }
+
+    #[inline]
+    fn score(&self, against: &MultiDenseVector) -> ScoreType {
+        let multi_vector_config = self.vector_storage.multi_vector_config();
+        self.query.score_by(|example| {
+            score_multi::<TMetric>(multi_vector_config, example.into(), against.into())
+        })
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+}
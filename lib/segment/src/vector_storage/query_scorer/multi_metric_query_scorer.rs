@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::{MultiDenseVector, MultiDenseVectorRef};
+use crate::spaces::metric::Metric;
+use crate::types::{MultiVectorComparator, MultiVectorConfig};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::MultiVectorStorage;
+
+pub struct MultiMetricQueryScorer<'a, TMetric: Metric, TVectorStorage: MultiVectorStorage> {
+    vector_storage: &'a TVectorStorage,
+    query: MultiDenseVector,
+    metric: PhantomData<TMetric>,
+}
+
+impl<'a, TMetric: Metric, TVectorStorage: MultiVectorStorage>
+    MultiMetricQueryScorer<'a, TMetric, TVectorStorage>
+{
+    pub fn new(query: MultiDenseVector, vector_storage: &'a TVectorStorage) -> Self {
+        Self {
+            query: preprocess_multi_vector::<TMetric>(query),
+            vector_storage,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<'a, TMetric: Metric, TVectorStorage: MultiVectorStorage> QueryScorer<MultiDenseVector>
+    for MultiMetricQueryScorer<'a, TMetric, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        score_multi::<TMetric>(
+            self.vector_storage.multi_vector_config(),
+            (&self.query).into(),
+            self.vector_storage.get_multi(idx),
+        )
+    }
+
+    #[inline]
+    fn score(&self, v2: &MultiDenseVector) -> ScoreType {
+        score_multi::<TMetric>(
+            self.vector_storage.multi_vector_config(),
+            (&self.query).into(),
+            v2.into(),
+        )
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        score_multi::<TMetric>(
+            self.vector_storage.multi_vector_config(),
+            self.vector_storage.get_multi(point_a),
+            self.vector_storage.get_multi(point_b),
+        )
+    }
+}
+
+/// Apply the metric preprocessing to every vector of the matrix
+pub fn preprocess_multi_vector<TMetric: Metric>(
+    multi_vector: MultiDenseVector,
+) -> MultiDenseVector {
+    let dim = multi_vector.dim;
+    let flattened_vectors = multi_vector
+        .multi_vectors()
+        .flat_map(|vector| TMetric::preprocess(vector.to_vec()))
+        .collect();
+    MultiDenseVector::new(flattened_vectors, dim)
+}
+
+/// Compare two multi vectors with the comparator of the config
+///
+/// MaxSim: for every vector of `query`, take the best similarity against all vectors of
+/// `stored`, and sum them up.
+pub fn score_multi<TMetric: Metric>(
+    multi_vector_config: &MultiVectorConfig,
+    query: MultiDenseVectorRef,
+    stored: MultiDenseVectorRef,
+) -> ScoreType {
+    match multi_vector_config.comparator {
+        MultiVectorComparator::MaxSim => query
+            .multi_vectors()
+            .map(|query_vector| {
+                stored
+                    .multi_vectors()
+                    .map(|stored_vector| TMetric::similarity(query_vector, stored_vector))
+                    .fold(ScoreType::NEG_INFINITY, ScoreType::max)
+            })
+            .sum(),
+    }
+}
@@ -16,7 +16,7 @@ use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::vectors::VectorRef;
-use crate::types::Distance;
+use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::bitvec::bitvec_set_deleted;
 use crate::vector_storage::{VectorStorage, VectorStorageEnum};
 
@@ -165,6 +165,10 @@ impl VectorStorage for SimpleSparseVectorStorage {
         SPARSE_VECTOR_DISTANCE
     }
 
+    fn datatype(&self) -> VectorStorageDatatype {
+        VectorStorageDatatype::Float32
+    }
+
     fn is_on_disk(&self) -> bool {
         true
     }
@@ -11,9 +11,14 @@ use super::simple_dense_vector_storage::SimpleDenseVectorStorage;
 use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
-use crate::data_types::vectors::{VectorElementType, VectorRef};
-use crate::types::Distance;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    MultiDenseVectorRef, VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+};
+use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
+use crate::vector_storage::appendable_mmap_multi_dense_vector_storage::AppendableMmapMultiDenseVectorStorage;
 use crate::vector_storage::appendable_mmap_vector_storage::AppendableMmapVectorStorage;
+use crate::vector_storage::simple_multi_dense_vector_storage::SimpleMultiDenseVectorStorage;
 use crate::vector_storage::simple_sparse_vector_storage::SimpleSparseVectorStorage;
 
 /// Trait for vector storage
@@ -24,6 +29,9 @@ pub trait VectorStorage {
 
     fn distance(&self) -> Distance;
 
+    /// Type of the vector elements held by this storage
+    fn datatype(&self) -> VectorStorageDatatype;
+
     fn is_on_disk(&self) -> bool;
 
     /// Number of vectors
@@ -96,82 +104,169 @@ pub trait VectorStorage {
     fn deleted_vector_bitslice(&self) -> &BitSlice;
 }
 
-pub trait DenseVectorStorage: VectorStorage {
-    fn get_dense(&self, key: PointOffsetType) -> &[VectorElementType];
+pub trait DenseVectorStorage<T: PrimitiveVectorElement = VectorElementType>: VectorStorage {
+    fn get_dense(&self, key: PointOffsetType) -> &[T];
 }
 
 pub trait SparseVectorStorage: VectorStorage {
     fn get_sparse(&self, key: PointOffsetType) -> OperationResult<SparseVector>;
 }
 
+pub trait MultiVectorStorage: VectorStorage {
+    fn get_multi(&self, key: PointOffsetType) -> MultiDenseVectorRef;
+
+    fn multi_vector_config(&self) -> &MultiVectorConfig;
+}
+
 pub enum VectorStorageEnum {
-    DenseSimple(SimpleDenseVectorStorage),
-    Memmap(Box<MemmapVectorStorage>),
-    AppendableMemmap(Box<AppendableMmapVectorStorage>),
+    DenseSimple(SimpleDenseVectorStorage<VectorElementType>),
+    DenseSimpleByte(SimpleDenseVectorStorage<VectorElementTypeByte>),
+    DenseSimpleHalf(SimpleDenseVectorStorage<VectorElementTypeHalf>),
+    Memmap(Box<MemmapVectorStorage<VectorElementType>>),
+    MemmapByte(Box<MemmapVectorStorage<VectorElementTypeByte>>),
+    MemmapHalf(Box<MemmapVectorStorage<VectorElementTypeHalf>>),
+    AppendableMemmap(Box<AppendableMmapVectorStorage<VectorElementType>>),
+    AppendableMemmapByte(Box<AppendableMmapVectorStorage<VectorElementTypeByte>>),
+    AppendableMemmapHalf(Box<AppendableMmapVectorStorage<VectorElementTypeHalf>>),
     SparseSimple(SimpleSparseVectorStorage),
+    MultiDenseSimple(SimpleMultiDenseVectorStorage),
+    AppendableMemmapMultiDense(Box<AppendableMmapMultiDenseVectorStorage>),
 }
 
 impl VectorStorage for VectorStorageEnum {
     fn vector_dim(&self) -> usize {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.vector_dim(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.vector_dim(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.vector_dim(),
             VectorStorageEnum::Memmap(v) => v.vector_dim(),
+            VectorStorageEnum::MemmapByte(v) => v.vector_dim(),
+            VectorStorageEnum::MemmapHalf(v) => v.vector_dim(),
             VectorStorageEnum::AppendableMemmap(v) => v.vector_dim(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.vector_dim(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.vector_dim(),
             VectorStorageEnum::SparseSimple(v) => v.vector_dim(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.vector_dim(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.vector_dim(),
         }
     }
 
     fn distance(&self) -> Distance {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.distance(),
             VectorStorageEnum::Memmap(v) => v.distance(),
+            VectorStorageEnum::MemmapByte(v) => v.distance(),
+            VectorStorageEnum::MemmapHalf(v) => v.distance(),
             VectorStorageEnum::AppendableMemmap(v) => v.distance(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.distance(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.distance(),
             VectorStorageEnum::SparseSimple(v) => v.distance(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.distance(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.distance(),
+        }
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        match self {
+            VectorStorageEnum::DenseSimple(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.datatype(),
+            VectorStorageEnum::Memmap(v) => v.datatype(),
+            VectorStorageEnum::MemmapByte(v) => v.datatype(),
+            VectorStorageEnum::MemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::AppendableMemmap(v) => v.datatype(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::SparseSimple(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.datatype(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.datatype(),
         }
     }
 
     fn is_on_disk(&self) -> bool {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.is_on_disk(),
             VectorStorageEnum::Memmap(v) => v.is_on_disk(),
+            VectorStorageEnum::MemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MemmapHalf(v) => v.is_on_disk(),
             VectorStorageEnum::AppendableMemmap(v) => v.is_on_disk(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.is_on_disk(),
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.is_on_disk(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.is_on_disk(),
         }
     }
 
     fn total_vector_count(&self) -> usize {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.total_vector_count(),
             VectorStorageEnum::Memmap(v) => v.total_vector_count(),
+            VectorStorageEnum::MemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MemmapHalf(v) => v.total_vector_count(),
             VectorStorageEnum::AppendableMemmap(v) => v.total_vector_count(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.total_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.total_vector_count(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.total_vector_count(),
         }
     }
 
     fn get_vector(&self, key: PointOffsetType) -> CowVector {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleByte(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector(key),
             VectorStorageEnum::Memmap(v) => v.get_vector(key),
+            VectorStorageEnum::MemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::MemmapHalf(v) => v.get_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.get_vector(key),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.get_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseSimple(v) => v.get_vector(key),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.get_vector(key),
         }
     }
 
     fn get_vector_opt(&self, key: PointOffsetType) -> Option<CowVector> {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector_opt(key),
             VectorStorageEnum::Memmap(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MemmapHalf(v) => v.get_vector_opt(key),
             VectorStorageEnum::AppendableMemmap(v) => v.get_vector_opt(key),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.get_vector_opt(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseSimple(v) => v.get_vector_opt(key),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.get_vector_opt(key),
         }
     }
 
     fn insert_vector(&mut self, key: PointOffsetType, vector: VectorRef) -> OperationResult<()> {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.insert_vector(key, vector),
             VectorStorageEnum::Memmap(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MemmapHalf(v) => v.insert_vector(key, vector),
             VectorStorageEnum::AppendableMemmap(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.insert_vector(key, vector),
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseSimple(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.insert_vector(key, vector),
         }
     }
 
@@ -183,63 +278,121 @@ impl VectorStorage for VectorStorageEnum {
     ) -> OperationResult<Range<PointOffsetType>> {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseSimpleByte(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::Memmap(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MemmapByte(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MemmapHalf(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::AppendableMemmap(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::SparseSimple(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MultiDenseSimple(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
         }
     }
 
     fn flusher(&self) -> Flusher {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.flusher(),
             VectorStorageEnum::Memmap(v) => v.flusher(),
+            VectorStorageEnum::MemmapByte(v) => v.flusher(),
+            VectorStorageEnum::MemmapHalf(v) => v.flusher(),
             VectorStorageEnum::AppendableMemmap(v) => v.flusher(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.flusher(),
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.flusher(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.flusher(),
         }
     }
 
     fn files(&self) -> Vec<PathBuf> {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.files(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.files(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.files(),
             VectorStorageEnum::Memmap(v) => v.files(),
+            VectorStorageEnum::MemmapByte(v) => v.files(),
+            VectorStorageEnum::MemmapHalf(v) => v.files(),
             VectorStorageEnum::AppendableMemmap(v) => v.files(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.files(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.files(),
             VectorStorageEnum::SparseSimple(v) => v.files(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.files(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.files(),
         }
     }
 
     fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.delete_vector(key),
             VectorStorageEnum::Memmap(v) => v.delete_vector(key),
+            VectorStorageEnum::MemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MemmapHalf(v) => v.delete_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.delete_vector(key),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.delete_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseSimple(v) => v.delete_vector(key),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.delete_vector(key),
         }
     }
 
     fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.is_deleted_vector(key),
             VectorStorageEnum::Memmap(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MemmapHalf(v) => v.is_deleted_vector(key),
             VectorStorageEnum::AppendableMemmap(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseSimple(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.is_deleted_vector(key),
         }
     }
 
     fn deleted_vector_count(&self) -> usize {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_count(),
             VectorStorageEnum::Memmap(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MemmapHalf(v) => v.deleted_vector_count(),
             VectorStorageEnum::AppendableMemmap(v) => v.deleted_vector_count(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_count(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.deleted_vector_count(),
         }
     }
 
     fn deleted_vector_bitslice(&self) -> &BitSlice {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::Memmap(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MemmapHalf(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::AppendableMemmap(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::AppendableMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::AppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::AppendableMemmapMultiDense(v) => v.deleted_vector_bitslice(),
         }
     }
 }
@@ -110,6 +110,8 @@ fn _test_filterable_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                multivector_config: None,
+                datatype: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -143,6 +145,7 @@ fn _test_filterable_hnsw(
         max_indexing_threads: 2,
         on_disk: Some(false),
         payload_m: None,
+        range_links: None,
     };
 
     let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
@@ -259,3 +262,149 @@ fn _test_filterable_hnsw(
     ); // Not more than X% failures
     eprintln!("hits = {hits:#?} out of {attempts}");
 }
+
+/// Range filters wider than a single payload block should be searchable with only payload links
+#[test]
+fn test_filterable_hnsw_range_buckets() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let num_vectors: u64 = 5_000;
+    let ef = 8;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 16; // KB
+    let max_failures = 5; // out of 100
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let int_key = "int";
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rnd, dim);
+
+        let int_payload = random_int_payload(&mut rnd, 1..=1);
+        let payload: Payload = json!({int_key:int_payload,}).into();
+
+        segment
+            .upsert_point(n as SeqNumberType, idx, only_default_vector(&vector))
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload)
+            .unwrap();
+    }
+
+    let payload_index_ptr = segment.payload_index.clone();
+    payload_index_ptr
+        .borrow_mut()
+        .set_indexed(int_key, PayloadSchemaType::Integer.into())
+        .unwrap();
+
+    // No main graph, so points are connected only by the additional payload links
+    let hnsw_config = HnswConfig {
+        m: 0,
+        ef_construct: 16,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: Some(8),
+        range_links: Some(true),
+    };
+
+    let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
+    let quantized_vectors = &segment.vector_data[DEFAULT_VECTOR_NAME].quantized_vectors;
+    let mut hnsw_index = HNSWIndex::<GraphLinksRam>::open(
+        hnsw_dir.path(),
+        segment.id_tracker.clone(),
+        vector_storage.clone(),
+        quantized_vectors.clone(),
+        payload_index_ptr.clone(),
+        hnsw_config,
+    )
+    .unwrap();
+
+    hnsw_index.build_index(&stopped).unwrap();
+
+    let top = 3;
+    let mut hits = 0;
+    let attempts = 100;
+    for i in 0..attempts {
+        let query = random_vector(&mut rnd, dim).into();
+
+        // About 1500 points, which span several payload blocks of about 500 points
+        let range_size = 150;
+        let left_range = rnd.gen_range(0..350);
+        let right_range = left_range + range_size;
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+            int_key.to_owned(),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(left_range as f64),
+                lte: Some(right_range as f64),
+            },
+        )));
+
+        let filter_query = Some(&filter);
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                filter_query,
+                top,
+                // Explicit `ef` is never widened, so only range bucket links are tested
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &false.into(),
+            )
+            .unwrap();
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data()
+                .filtered_large_cardinality
+                .count,
+            i + 1
+        );
+
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], filter_query, top, None, &false.into())
+            .unwrap();
+
+        if plain_result == index_result {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits} of {attempts}"
+    ); // Not more than X% failures
+    eprintln!("hits = {hits:#?} out of {attempts}");
+}
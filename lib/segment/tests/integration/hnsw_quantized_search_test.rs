@@ -68,6 +68,8 @@ fn hnsw_quantized_search_test(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                multivector_config: None,
+                datatype: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -119,6 +121,7 @@ fn hnsw_quantized_search_test(
         max_indexing_threads: 2,
         on_disk: Some(false),
         payload_m: None,
+        range_links: None,
     };
 
     let mut hnsw_index = HNSWIndex::<GraphLinksRam>::open(
@@ -412,6 +415,7 @@ fn test_build_hnsw_using_quantization() {
         max_indexing_threads: 2,
         on_disk: Some(false),
         payload_m: None,
+        range_links: None,
     });
 
     let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
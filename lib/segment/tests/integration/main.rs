@@ -9,6 +9,7 @@ pub mod filtrable_hnsw_test;
 pub mod fixtures;
 pub mod hnsw_discover_test;
 pub mod hnsw_quantized_search_test;
+mod multivector_test;
 pub mod nested_filtering_test;
 pub mod payload_index_test;
 pub mod scroll_filtering_test;
@@ -17,3 +18,4 @@ pub mod segment_tests;
 mod sparse_discover_test;
 mod sparse_vector_index_search_tests;
 pub mod utils;
+mod vector_datatype_test;
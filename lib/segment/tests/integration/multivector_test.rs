@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::{MultiDenseVector, QueryVector, Vector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::segment::Segment;
+use segment::segment_constructor::{build_segment, load_segment};
+use segment::types::{
+    Distance, Indexes, MultiVectorConfig, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+const DIM: usize = 4;
+const NUM_POINTS: u64 = 100;
+
+fn random_multi_vector<R: Rng + ?Sized>(rnd: &mut R, max_count: usize) -> MultiDenseVector {
+    let count = rnd.gen_range(1..=max_count);
+    let vectors = (0..count).map(|_| random_vector(rnd, DIM)).collect_vec();
+    MultiDenseVector::try_from(vectors).unwrap()
+}
+
+/// Reference implementation of the MaxSim score with the dot product
+fn max_sim(query: &MultiDenseVector, stored: &MultiDenseVector) -> f32 {
+    query
+        .multi_vectors()
+        .map(|query_row| {
+            stored
+                .multi_vectors()
+                .map(|stored_row| query_row.iter().zip(stored_row).map(|(a, b)| a * b).sum())
+                .fold(f32::NEG_INFINITY, f32::max)
+        })
+        .sum()
+}
+
+fn multivector_segment(path: &std::path::Path, storage_type: VectorStorageType) -> Segment {
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance: Distance::Dot,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig::default()),
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    build_segment(path, &config, true).unwrap()
+}
+
+#[rstest]
+#[case::memory(VectorStorageType::Memory)]
+#[case::mmap(VectorStorageType::ChunkedMmap)]
+fn test_multivector_max_sim_search(#[case] storage_type: VectorStorageType) {
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let mut segment = multivector_segment(dir.path(), storage_type);
+
+    let mut stored = HashMap::new();
+    for n in 0..NUM_POINTS {
+        let vector = random_multi_vector(&mut rnd, 5);
+        let named_vectors = NamedVectors::from_ref(DEFAULT_VECTOR_NAME, (&vector).into());
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), named_vectors)
+            .unwrap();
+        stored.insert(n, vector);
+    }
+
+    let query = random_multi_vector(&mut rnd, 3);
+    let expected = stored
+        .iter()
+        .map(|(id, vector)| (*id, max_sim(&query, vector)))
+        .sorted_by(|(_, a), (_, b)| b.total_cmp(a))
+        .take(10)
+        .collect_vec();
+
+    let result = segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &QueryVector::from(query.clone()),
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            10,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+    assert_eq!(result.len(), expected.len());
+    for (point, (expected_id, expected_score)) in result.iter().zip(&expected) {
+        assert_eq!(point.id, (*expected_id).into());
+        assert!((point.score - expected_score).abs() < 1e-4);
+    }
+
+    // Vectors are returned as they were inserted
+    let retrieved = segment.vector(DEFAULT_VECTOR_NAME, 7.into()).unwrap();
+    assert_eq!(retrieved, Some(Vector::MultiDense(stored[&7].clone())));
+
+    // Vectors survive the reload of the segment
+    segment.flush(true).unwrap();
+    let path = segment.current_path.clone();
+    drop(segment);
+    let segment = load_segment(&path).unwrap().unwrap();
+    for (id, vector) in &stored {
+        let retrieved = segment.vector(DEFAULT_VECTOR_NAME, (*id).into()).unwrap();
+        assert_eq!(retrieved, Some(Vector::MultiDense(vector.clone())));
+    }
+}
+
+#[test]
+fn test_multivector_rejects_dense_vector() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = multivector_segment(dir.path(), VectorStorageType::Memory);
+
+    let dense = vec![1.0; DIM];
+    let result = segment.upsert_point(
+        1,
+        1.into(),
+        NamedVectors::from_ref(DEFAULT_VECTOR_NAME, dense.as_slice().into()),
+    );
+    assert!(result.is_err());
+
+    // Every vector of a multi vector must have the configured dimension
+    let wrong_dim = MultiDenseVector::try_from(vec![vec![1.0; DIM + 1]]).unwrap();
+    let result = segment.upsert_point(
+        2,
+        2.into(),
+        NamedVectors::from_ref(DEFAULT_VECTOR_NAME, (&wrong_dim).into()),
+    );
+    assert!(result.is_err());
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
@@ -7,13 +8,17 @@ use common::types::PointOffsetType;
 use itertools::Itertools;
 use rand::prelude::StdRng;
 use rand::{Rng, SeedableRng};
+use segment::data_types::index::{
+    BoolIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams, KeywordIndexParams,
+};
+use segment::data_types::text_index::TextIndexParams;
 use segment::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_context_fixture::FixtureIdTracker;
 use segment::fixtures::payload_fixtures::{
     generate_diverse_nested_payload, generate_diverse_payload, random_filter, random_nested_filter,
-    random_vector, FLICKING_KEY, GEO_KEY, INT_KEY, INT_KEY_2, LAT_RANGE, LON_RANGE, STR_KEY,
-    STR_PROJ_KEY, STR_ROOT_PROJ_KEY, TEXT_KEY,
+    random_vector, BOOL_KEY, FLICKING_KEY, FLT_KEY, GEO_KEY, INT_KEY, INT_KEY_2, LAT_RANGE,
+    LON_RANGE, STR_KEY, STR_PROJ_KEY, STR_ROOT_PROJ_KEY, TEXT_KEY,
 };
 use segment::index::field_index::PrimaryCondition;
 use segment::index::struct_payload_index::StructPayloadIndex;
@@ -21,13 +26,17 @@ use segment::index::PayloadIndex;
 use segment::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
 use segment::payload_storage::PayloadStorage;
 use segment::segment::Segment;
-use segment::segment_constructor::build_segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::segment_constructor::{build_segment, load_segment};
 use segment::types::PayloadFieldSchema::FieldType;
 use segment::types::PayloadSchemaType::{Integer, Keyword};
 use segment::types::{
-    AnyVariants, Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoLineString,
-    GeoPoint, GeoPolygon, GeoRadius, Indexes, IsEmptyCondition, Match, Payload, PayloadField,
-    PayloadSchemaType, Range, SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload,
+    AnyVariants, Condition, DatetimeRange, Distance, FieldCondition, Filter, GeoBoundingBox,
+    GeoLineString, GeoPoint, GeoPolygon, GeoRadius, GeoShape, Indexes, IsEmptyCondition,
+    KeywordRange, Match, MinShould, Payload, PayloadField, PayloadFieldSchema, PayloadSchemaParams,
+    PayloadSchemaType, PayloadStorageType, PointIdType, Range, SegmentConfig, VectorDataConfig,
+    VectorStorageType, WithPayload,
 };
 use serde_json::json;
 use tempfile::Builder;
@@ -49,6 +58,8 @@ fn build_test_segments(path_struct: &Path, path_plain: &Path) -> (Segment, Segme
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                multivector_config: None,
+                datatype: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -154,6 +165,8 @@ fn build_test_segments_nested_payload(path_struct: &Path, path_plain: &Path) ->
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                multivector_config: None,
+                datatype: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -360,6 +373,57 @@ fn test_is_empty_conditions() {
     );
 }
 
+#[test]
+fn test_min_should_conditions() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let (struct_segment, plain_segment) = build_test_segments(dir1.path(), dir2.path());
+
+    let int_range = |key: &str, gte: f64, lte: f64| {
+        Condition::Field(FieldCondition::new_range(
+            key.to_owned(),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(gte),
+                lte: Some(lte),
+            },
+        ))
+    };
+    let conditions = vec![
+        int_range(INT_KEY, 0., 250.),
+        int_range(INT_KEY, 150., 400.),
+        int_range(INT_KEY_2, 0., 250.),
+    ];
+
+    for min_count in 0..=conditions.len() + 1 {
+        let filter = Filter {
+            should: None,
+            min_should: Some(MinShould {
+                conditions: conditions.clone(),
+                min_count,
+            }),
+            must: None,
+            must_not: None,
+        };
+
+        let plain_result = plain_segment.payload_index.borrow().query_points(&filter);
+        let mut struct_result = struct_segment.payload_index.borrow().query_points(&filter);
+        // Points selected by multiple primary clauses are not ordered
+        struct_result.sort_unstable();
+        assert_eq!(plain_result, struct_result, "min_count = {min_count}");
+
+        let real_number = plain_result.len();
+        let estimation = struct_segment
+            .payload_index
+            .borrow()
+            .estimate_cardinality(&filter);
+        assert!(estimation.min <= real_number, "{estimation:#?}");
+        assert!(estimation.max >= real_number, "{estimation:#?}");
+    }
+}
+
 #[test]
 fn test_cardinality_estimation() {
     let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
@@ -850,3 +914,760 @@ fn test_any_matcher_cardinality_estimation() {
     assert!(exact <= estimation.max);
     assert!(exact >= estimation.min);
 }
+
+#[test]
+fn test_datetime_range_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let key = "created_at";
+    struct_segment
+        .create_field_index(0, key, Some(&PayloadSchemaType::Datetime.into()))
+        .unwrap();
+
+    let datetimes = [
+        "2023-12-31T23:30:00+01:00",
+        "2024-01-01T00:00:00Z",
+        "2024-01-01T10:00:00.5Z",
+        "2024-01-02T04:59:59-03:00",
+        "2024-01-02T05:00:00-03:00",
+        "2024-02-29T12:00:00+00:00",
+        "not a datetime",
+    ];
+
+    let mut rnd = rand::thread_rng();
+    for (idx, datetime) in datetimes.into_iter().enumerate() {
+        let point_id = (idx as u64).into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = json!({ key: datetime }).into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    let range = DatetimeRange {
+        gte: Some("2024-01-01T00:00:00Z".parse().unwrap()),
+        lt: Some("2024-01-02T08:00:00Z".parse().unwrap()),
+        ..Default::default()
+    };
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_datetime_range(
+        key, range,
+    )));
+
+    let expected: Vec<PointIdType> = vec![1.into(), 2.into(), 3.into()];
+    assert_eq!(
+        plain_segment.read_filtered(None, None, Some(&filter)),
+        expected
+    );
+    assert_eq!(
+        struct_segment.read_filtered(None, None, Some(&filter)),
+        expected
+    );
+
+    let estimation = struct_segment
+        .payload_index
+        .borrow()
+        .estimate_cardinality(&filter);
+    assert_eq!(estimation.primary_clauses.len(), 1);
+    assert!(estimation.min <= expected.len() && expected.len() <= estimation.max);
+}
+
+#[test]
+fn test_uuid_match_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let key = "tenant";
+    struct_segment
+        .create_field_index(0, key, Some(&PayloadSchemaType::Uuid.into()))
+        .unwrap();
+
+    let tenant_a = "1ab4ba4c-7c2e-4e3c-9d2a-59a6b4d8c1f0";
+    let tenant_b = "f3d1c7e2-0a4b-4c8d-8e6f-2b9a7c5d3e10";
+    let tenant_c = "00000000-0000-0000-0000-000000000001";
+    let payloads = [
+        json!({ key: tenant_a }),
+        json!({ key: tenant_b }),
+        json!({ key: [tenant_a, tenant_c] }),
+        json!({ key: [tenant_b, tenant_c] }),
+        json!({ key: tenant_c }),
+    ];
+
+    let mut rnd = rand::thread_rng();
+    for (idx, payload) in payloads.into_iter().enumerate() {
+        let point_id = (idx as u64).into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = payload.into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    let check = |r#match: Match, expected: Vec<u64>| {
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(key, r#match)));
+        let expected: Vec<PointIdType> = expected.into_iter().map(PointIdType::from).collect();
+        assert_eq!(
+            plain_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+    };
+
+    check(Match::from(tenant_a.to_string()), vec![0, 2]);
+    check(
+        Match::new_any(AnyVariants::Keywords(vec![
+            tenant_b.to_string(),
+            tenant_c.to_string(),
+        ])),
+        vec![1, 2, 3, 4],
+    );
+    check(
+        Match::new_except(AnyVariants::Keywords(vec![
+            tenant_a.to_string(),
+            tenant_c.to_string(),
+        ])),
+        vec![1, 3],
+    );
+    check(Match::from("not a uuid".to_string()), vec![]);
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        key,
+        tenant_c.to_string().into(),
+    )));
+    let estimation = struct_segment
+        .payload_index
+        .borrow()
+        .estimate_cardinality(&filter);
+    assert_eq!(estimation.primary_clauses.len(), 1);
+    assert_eq!(estimation.exp, 3);
+}
+
+#[test]
+fn test_keyword_prefix_and_range_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let key = "sku";
+    struct_segment
+        .create_field_index(0, key, Some(&Keyword.into()))
+        .unwrap();
+
+    let payloads = [
+        json!({ key: "A100" }),
+        json!({ key: "A150" }),
+        json!({ key: ["A200", "eu-west"] }),
+        json!({ key: "eu-north" }),
+        json!({ key: 150 }),
+    ];
+
+    let mut rnd = rand::thread_rng();
+    for (idx, payload) in payloads.into_iter().enumerate() {
+        let point_id = (idx as u64).into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = payload.into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    let check = |condition: FieldCondition, expected: Vec<u64>| {
+        let filter = Filter::new_must(Condition::Field(condition));
+        let expected: Vec<PointIdType> = expected.into_iter().map(PointIdType::from).collect();
+        assert_eq!(
+            plain_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+
+        let estimation = struct_segment
+            .payload_index
+            .borrow()
+            .estimate_cardinality(&filter);
+        assert_eq!(estimation.primary_clauses.len(), 1);
+        assert!(estimation.min <= expected.len());
+        assert!(estimation.max >= expected.len());
+    };
+
+    check(
+        FieldCondition::new_match(key, Match::new_prefix("eu-")),
+        vec![2, 3],
+    );
+    check(
+        FieldCondition::new_keyword_range(
+            key,
+            KeywordRange {
+                gte: Some("A100".to_string()),
+                lt: Some("A200".to_string()),
+                ..Default::default()
+            },
+        ),
+        vec![0, 1],
+    );
+    check(
+        FieldCondition::new_keyword_range(
+            key,
+            KeywordRange {
+                gt: Some("A150".to_string()),
+                ..Default::default()
+            },
+        ),
+        vec![2, 3],
+    );
+}
+
+#[test]
+fn test_array_index_and_wildcard_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let first_item_key = "items[0].name";
+    let price_key = "prices.*.amount";
+    struct_segment
+        .create_field_index(0, first_item_key, Some(&Keyword.into()))
+        .unwrap();
+    struct_segment
+        .create_field_index(0, price_key, Some(&PayloadSchemaType::Float.into()))
+        .unwrap();
+
+    let payloads = [
+        json!({
+            "items": [{ "name": "apple" }, { "name": "pear" }],
+            "prices": { "eur": { "amount": 1.5 }, "usd": { "amount": 1.7 } },
+        }),
+        json!({
+            "items": [{ "name": "pear" }, { "name": "apple" }],
+            "prices": { "eur": { "amount": 12.0 } },
+        }),
+        json!({
+            "items": [{ "name": "plum" }],
+            "prices": { "usd": { "amount": 25.0 }, "gbp": { "amount": 0.5 } },
+        }),
+    ];
+
+    let mut rnd = rand::thread_rng();
+    for (idx, payload) in payloads.into_iter().enumerate() {
+        let point_id = (idx as u64).into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = payload.into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    let check = |condition: FieldCondition, expected: Vec<u64>| {
+        let filter = Filter::new_must(Condition::Field(condition));
+        let expected: Vec<PointIdType> = expected.into_iter().map(PointIdType::from).collect();
+        assert_eq!(
+            plain_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+    };
+
+    check(
+        FieldCondition::new_match(first_item_key, Match::from("apple".to_string())),
+        vec![0],
+    );
+    check(
+        FieldCondition::new_match("items[].name", Match::from("apple".to_string())),
+        vec![0, 1],
+    );
+    check(
+        FieldCondition::new_range(
+            price_key,
+            Range {
+                lt: None,
+                gt: Some(10.0),
+                gte: None,
+                lte: None,
+            },
+        ),
+        vec![1, 2],
+    );
+    check(
+        FieldCondition::new_range(
+            price_key,
+            Range {
+                lt: Some(1.0),
+                gt: None,
+                gte: None,
+                lte: None,
+            },
+        ),
+        vec![2],
+    );
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        first_item_key,
+        Match::from("pear".to_string()),
+    )));
+    let estimation = struct_segment
+        .payload_index
+        .borrow()
+        .estimate_cardinality(&filter);
+    assert_eq!(estimation.primary_clauses.len(), 1);
+    assert_eq!(estimation.exp, 1);
+}
+
+#[test]
+fn test_tenant_index_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let tenant_schema =
+        PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+            is_tenant: Some(true),
+            ..Default::default()
+        }));
+    struct_segment
+        .create_field_index(0, "tenant", Some(&tenant_schema))
+        .unwrap();
+    struct_segment
+        .create_field_index(0, "count", Some(&Integer.into()))
+        .unwrap();
+
+    let tenants = ["a", "b", "c", "d"];
+    let num_points = 200u64;
+
+    let mut rnd = rand::thread_rng();
+    for idx in 0..num_points {
+        let point_id = idx.into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = json!({
+            "tenant": tenants[idx as usize % tenants.len()],
+            "count": idx,
+        })
+        .into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    // Deleted points must not be returned by the tenant postings
+    for idx in (0..num_points).step_by(7) {
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment.delete_point(2, idx.into()).unwrap();
+        }
+    }
+
+    for tenant in tenants {
+        let filter = Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![
+                Condition::Field(FieldCondition::new_range(
+                    "count",
+                    Range {
+                        lt: None,
+                        gt: None,
+                        gte: Some(50.0),
+                        lte: None,
+                    },
+                )),
+                Condition::Field(FieldCondition::new_match(
+                    "tenant",
+                    tenant.to_string().into(),
+                )),
+            ]),
+            must_not: None,
+        };
+
+        let expected: Vec<PointIdType> = (50..num_points)
+            .filter(|idx| idx % 7 != 0 && tenants[*idx as usize % tenants.len()] == tenant)
+            .map(PointIdType::from)
+            .collect();
+
+        assert_eq!(
+            plain_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment
+                .payload_index
+                .borrow()
+                .query_points(&filter)
+                .len(),
+            expected.len()
+        );
+    }
+}
+
+#[test]
+fn test_on_disk_payload_index() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+    let plain_dir = Builder::new()
+        .prefix("plain_segment_dir")
+        .tempdir()
+        .unwrap();
+
+    let mut rnd = StdRng::seed_from_u64(42);
+    let stopped = AtomicBool::new(false);
+
+    let mut appendable_segment = build_simple_segment(dir.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(plain_dir.path(), DIM, Distance::Dot).unwrap();
+
+    let on_disk_schemas = [
+        (
+            STR_KEY,
+            PayloadSchemaParams::Keyword(KeywordIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+        (
+            INT_KEY,
+            PayloadSchemaParams::Integer(IntegerIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+        (
+            FLT_KEY,
+            PayloadSchemaParams::Float(FloatIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+        (
+            GEO_KEY,
+            PayloadSchemaParams::Geo(GeoIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+        (
+            TEXT_KEY,
+            PayloadSchemaParams::Text(TextIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+        (
+            BOOL_KEY,
+            PayloadSchemaParams::Bool(BoolIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }),
+        ),
+    ];
+    for (field, params) in on_disk_schemas {
+        appendable_segment
+            .create_field_index(0, field, Some(&PayloadFieldSchema::FieldParams(params)))
+            .unwrap();
+    }
+
+    let num_points = 1000u64;
+    for idx in 0..num_points {
+        let vector = random_vector(&mut rnd, DIM);
+        let payload = generate_diverse_payload(&mut rnd);
+        for segment in [&mut appendable_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, idx.into(), only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, idx.into(), &payload).unwrap();
+        }
+    }
+
+    // Indexes of the appendable segment stay in RocksDB
+    assert_eq!(appendable_segment.payload_index.borrow().files().len(), 1);
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::Mmap,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
+    builder.update_from(&appendable_segment, &stopped).unwrap();
+    let mut segment = builder.build(&stopped).unwrap();
+
+    let index_files = segment.payload_index.borrow().files();
+    assert!(index_files.len() > 1);
+    assert!(index_files.iter().all(|file| file.exists()));
+
+    // Removal of points from on-disk indexes is persisted
+    for idx in (0..num_points).step_by(7) {
+        for segment in [&mut segment, &mut plain_segment] {
+            segment.delete_point(2, idx.into()).unwrap();
+        }
+    }
+    segment.flush(true).unwrap();
+    let segment_path = segment.current_path.clone();
+    drop(segment);
+    let segment = load_segment(&segment_path).unwrap().unwrap();
+
+    let mut filters = (0..ATTEMPTS)
+        .map(|_| random_filter(&mut rnd, 3))
+        .collect_vec();
+    filters.extend(
+        [
+            FieldCondition::new_range(
+                FLT_KEY,
+                Range {
+                    lt: Some(5.0),
+                    gt: None,
+                    gte: Some(2.5),
+                    lte: None,
+                },
+            ),
+            FieldCondition::new_geo_radius(
+                GEO_KEY,
+                GeoRadius {
+                    center: GeoPoint { lon: 0.0, lat: 0.0 },
+                    radius: 5_000_000.0,
+                },
+            ),
+            FieldCondition::new_match(BOOL_KEY, true.into()),
+        ]
+        .into_iter()
+        .map(|condition| Filter::new_must(Condition::Field(condition))),
+    );
+
+    for filter in &filters {
+        assert_eq!(
+            segment.read_filtered(None, None, Some(filter)),
+            plain_segment.read_filtered(None, None, Some(filter)),
+            "filter: {filter:?}",
+        );
+
+        let estimation = segment.payload_index.borrow().estimate_cardinality(filter);
+        assert!(estimation.min <= estimation.exp, "{estimation:#?}");
+        assert!(estimation.exp <= estimation.max, "{estimation:#?}");
+        assert!(
+            estimation.max <= segment.available_point_count(),
+            "{estimation:#?}",
+        );
+    }
+}
+
+#[test]
+fn test_geo_shape_filter() {
+    let dir1 = Builder::new().prefix("segment1_dir").tempdir().unwrap();
+    let dir2 = Builder::new().prefix("segment2_dir").tempdir().unwrap();
+
+    let mut struct_segment = build_simple_segment(dir1.path(), DIM, Distance::Dot).unwrap();
+    let mut plain_segment = build_simple_segment(dir2.path(), DIM, Distance::Dot).unwrap();
+
+    let key = "location";
+    struct_segment
+        .create_field_index(0, key, Some(&PayloadSchemaType::Geo.into()))
+        .unwrap();
+
+    let payloads = [
+        json!({ key: { "lon": 5.0, "lat": 5.0 } }),
+        json!({ key: { "type": "LineString", "coordinates": [[-5.0, 5.0], [15.0, 5.0]] } }),
+        json!({ key: {
+            "type": "Polygon",
+            "coordinates": [[[2.0, 2.0], [4.0, 2.0], [4.0, 4.0], [2.0, 4.0], [2.0, 2.0]]],
+        } }),
+        json!({ key: { "type": "Point", "coordinates": [50.0, 50.0] } }),
+        json!({ key: [
+            { "lon": 60.0, "lat": 60.0 },
+            { "type": "LineString", "coordinates": [[8.0, 8.0], [12.0, 12.0]] },
+        ] }),
+    ];
+
+    let mut rnd = rand::thread_rng();
+    for (idx, payload) in payloads.into_iter().enumerate() {
+        let point_id = (idx as u64).into();
+        let vector = random_vector(&mut rnd, DIM);
+        let payload: Payload = payload.into();
+        for segment in [&mut struct_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, point_id, only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, point_id, &payload).unwrap();
+        }
+    }
+
+    let check = |condition: FieldCondition, expected: Vec<u64>| {
+        let filter = Filter::new_must(Condition::Field(condition));
+        let expected: Vec<PointIdType> = expected.into_iter().map(PointIdType::from).collect();
+        assert_eq!(
+            plain_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+        assert_eq!(
+            struct_segment.read_filtered(None, None, Some(&filter)),
+            expected
+        );
+
+        let estimation = struct_segment
+            .payload_index
+            .borrow()
+            .estimate_cardinality(&filter);
+        assert_eq!(estimation.primary_clauses.len(), 1);
+        assert!(estimation.min <= expected.len());
+        assert!(estimation.max >= expected.len());
+    };
+
+    let square = serde_json::from_value::<GeoShape>(json!({
+        "type": "Polygon",
+        "coordinates": [
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]],
+            [[4.5, 4.5], [5.5, 4.5], [5.5, 5.5], [4.5, 5.5], [4.5, 4.5]],
+        ],
+    }))
+    .unwrap();
+
+    check(
+        FieldCondition::new_geo_intersects(key, square.clone()),
+        vec![1, 2, 4],
+    );
+    check(FieldCondition::new_geo_within(key, square), vec![2]);
+    check(
+        FieldCondition::new_geo_within(
+            key,
+            GeoShape::Point(GeoPoint {
+                lon: 50.0,
+                lat: 50.0,
+            }),
+        ),
+        vec![3],
+    );
+}
+
+#[test]
+fn test_mmap_payload_storage() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let plain_dir = Builder::new()
+        .prefix("plain_segment_dir")
+        .tempdir()
+        .unwrap();
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: PayloadStorageType::Mmap,
+    };
+    let mut mmap_segment = build_segment(dir.path(), &config, true).unwrap();
+    let mut plain_segment = build_simple_segment(plain_dir.path(), DIM, Distance::Dot).unwrap();
+
+    mmap_segment
+        .create_field_index(0, INT_KEY, Some(&Integer.into()))
+        .unwrap();
+
+    let num_points = 1000u64;
+    for idx in 0..num_points {
+        let vector = random_vector(&mut rnd, DIM);
+        let payload = generate_diverse_payload(&mut rnd);
+        for segment in [&mut mmap_segment, &mut plain_segment] {
+            segment
+                .upsert_point(1, idx.into(), only_default_vector(&vector))
+                .unwrap();
+            segment.set_full_payload(1, idx.into(), &payload).unwrap();
+        }
+    }
+
+    let updated: Payload = json!({ STR_KEY: "updated" }).into();
+    for segment in [&mut mmap_segment, &mut plain_segment] {
+        for idx in (0..num_points).step_by(5) {
+            segment.set_payload(2, idx.into(), &updated).unwrap();
+        }
+        for idx in (1..num_points).step_by(11) {
+            segment.delete_payload(2, idx.into(), FLT_KEY).unwrap();
+        }
+        for idx in (0..num_points).step_by(7) {
+            segment.delete_point(2, idx.into()).unwrap();
+        }
+    }
+
+    // Payload storage is persisted without RocksDB
+    mmap_segment.flush(true).unwrap();
+    let segment_path = mmap_segment.current_path.clone();
+    drop(mmap_segment);
+    let mmap_segment = load_segment(&segment_path).unwrap().unwrap();
+
+    assert_eq!(
+        mmap_segment.iter_points().collect_vec(),
+        plain_segment.iter_points().collect_vec(),
+    );
+    for point_id in plain_segment.iter_points() {
+        assert_eq!(
+            mmap_segment.payload(point_id).unwrap(),
+            plain_segment.payload(point_id).unwrap(),
+        );
+    }
+
+    let mut filters = (0..ATTEMPTS)
+        .map(|_| random_filter(&mut rnd, 3))
+        .collect_vec();
+    filters.push(Filter::new_must(Condition::Field(
+        FieldCondition::new_match(STR_KEY, "updated".to_owned().into()),
+    )));
+
+    for filter in &filters {
+        assert_eq!(
+            mmap_segment.read_filtered(None, None, Some(filter)),
+            plain_segment.read_filtered(None, None, Some(filter)),
+            "filter: {filter:?}",
+        );
+    }
+}
@@ -1,7 +1,19 @@
+use std::collections::HashSet;
+
 use rand::prelude::StdRng;
 use rand::{Rng, SeedableRng};
+use segment::common::operation_error::OperationError;
+use segment::data_types::order_by::{Direction, OrderBy, OrderValue, StartFrom};
+use segment::data_types::vectors::only_default_vector;
+use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_fixtures::random_filter;
 use segment::fixtures::segment_fixtures::random_segment;
+use segment::segment::Segment;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{
+    Condition, Distance, Filter, HasIdCondition, Payload, PayloadSchemaType, PointIdType,
+};
+use serde_json::json;
 use tempfile::Builder;
 
 const NUM_POINTS: usize = 2000;
@@ -29,3 +41,187 @@ fn test_filtering_context_consistency() {
         assert_eq!(read_by_index_res, read_by_stream_res, "filter: {filter:#?}");
     }
 }
+
+#[test]
+fn test_order_by_pagination() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+
+    let key = "price";
+    segment
+        .create_field_index(0, key, Some(&PayloadSchemaType::Float.into()))
+        .unwrap();
+
+    let num_points = 500u64;
+    let mut expected: Vec<(OrderValue, PointIdType)> = Vec::new();
+    for id in 0..num_points {
+        // Low cardinality, so there are plenty of ties between pages
+        let price = rng.gen_range(0..50) as f64 / 2.0;
+        let point_id = id.into();
+        segment
+            .upsert_point(1, point_id, only_default_vector(&[1.0, 0.0, 1.0, 1.0]))
+            .unwrap();
+        let payload: Payload = json!({ key: price }).into();
+        segment.set_full_payload(1, point_id, &payload).unwrap();
+        expected.push((OrderValue::Float(price), point_id));
+    }
+
+    for direction in [Direction::Asc, Direction::Desc] {
+        expected.sort_by(|(value_a, id_a), (value_b, id_b)| {
+            value_a
+                .cmp_directed(value_b, direction)
+                .then_with(|| id_a.cmp(id_b))
+        });
+
+        let page_size = 7;
+        let mut start_from: Option<StartFrom> = None;
+        let mut offset = None;
+        let mut scrolled = Vec::new();
+        loop {
+            let order_by = OrderBy {
+                key: key.to_string(),
+                direction: Some(direction),
+                start_from,
+            };
+            let mut page = segment
+                .read_ordered_filtered(Some(page_size + 1), None, &order_by, offset)
+                .unwrap();
+            if page.len() <= page_size {
+                scrolled.extend(page);
+                break;
+            }
+            let (next_value, next_id) = page.pop().unwrap();
+            scrolled.extend(page);
+            start_from = Some(next_value.into());
+            offset = Some(next_id);
+        }
+
+        assert_eq!(scrolled, expected, "direction: {direction:?}");
+    }
+
+    let missing_index = OrderBy {
+        key: "not_indexed".to_string(),
+        direction: None,
+        start_from: None,
+    };
+    assert!(matches!(
+        segment.read_ordered_filtered(Some(10), None, &missing_index, None),
+        Err(OperationError::MissingRangeIndexForOrderBy { .. }),
+    ));
+}
+
+#[test]
+fn test_order_by_start_from_large_integer() {
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+
+    let key = "counter";
+    segment
+        .create_field_index(0, key, Some(&PayloadSchemaType::Integer.into()))
+        .unwrap();
+
+    // Adjacent values near `i64::MAX` are the same, if converted to floats
+    let values = (0..10).map(|i| i64::MAX - i).collect::<Vec<_>>();
+    for (id, value) in values.iter().enumerate() {
+        let point_id = (id as u64).into();
+        segment
+            .upsert_point(1, point_id, only_default_vector(&[1.0, 0.0, 1.0, 1.0]))
+            .unwrap();
+        let payload: Payload = json!({ key: value }).into();
+        segment.set_full_payload(1, point_id, &payload).unwrap();
+    }
+
+    let start_from = i64::MAX - 5;
+    for direction in [Direction::Asc, Direction::Desc] {
+        let order_by = OrderBy {
+            key: key.to_string(),
+            direction: Some(direction),
+            start_from: Some(StartFrom::Integer(start_from)),
+        };
+        let read_values = segment
+            .read_ordered_filtered(None, None, &order_by, None)
+            .unwrap()
+            .into_iter()
+            .map(|(value, _)| value)
+            .collect::<Vec<_>>();
+
+        let mut expected = values
+            .iter()
+            .copied()
+            .filter(|&value| match direction {
+                Direction::Asc => value >= start_from,
+                Direction::Desc => value <= start_from,
+            })
+            .map(OrderValue::Int)
+            .collect::<Vec<_>>();
+        expected.sort_by(|a, b| a.cmp_directed(b, direction));
+
+        assert_eq!(read_values, expected, "direction: {direction:?}");
+    }
+}
+
+#[test]
+fn test_text_search_ranking() {
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+
+    let key = "title".to_string();
+    segment
+        .create_field_index(0, &key, Some(&PayloadSchemaType::Text.into()))
+        .unwrap();
+
+    let titles = [
+        "The quick brown fox",
+        "The lazy dog",
+        "Quick quick quick fox jumps",
+        "A fox",
+    ];
+    for (id, title) in titles.iter().enumerate() {
+        let point_id = (id as u64).into();
+        segment
+            .upsert_point(1, point_id, only_default_vector(&[1.0, 0.0, 1.0, 1.0]))
+            .unwrap();
+        let payload: Payload = json!({ "title": title }).into();
+        segment.set_full_payload(1, point_id, &payload).unwrap();
+    }
+
+    let statistics = segment.text_statistics(&key, "quick fox").unwrap();
+    assert_eq!(statistics.documents_count, 4);
+    assert_eq!(statistics.total_length, 14);
+    assert_eq!(statistics.document_frequencies["quick"], 2);
+    assert_eq!(statistics.document_frequencies["fox"], 3);
+
+    let ranked_ids = |segment: &Segment, filter: Option<&Filter>, top: usize| -> Vec<PointIdType> {
+        segment
+            .text_search(&key, "quick fox", &statistics, filter, top)
+            .unwrap()
+            .into_iter()
+            .map(|point| point.id)
+            .collect()
+    };
+
+    // More occurrences of rare tokens rank higher, the dog doesn't match at all
+    assert_eq!(
+        ranked_ids(&segment, None, 10),
+        vec![2.into(), 0.into(), 3.into()]
+    );
+    assert_eq!(ranked_ids(&segment, None, 1), vec![2.into()]);
+
+    let filter = Filter::new_must_not(Condition::HasId(HasIdCondition::from(HashSet::from([
+        2.into()
+    ]))));
+    assert_eq!(
+        ranked_ids(&segment, Some(&filter), 10),
+        vec![0.into(), 3.into()]
+    );
+
+    segment.delete_point(2, 0.into()).unwrap();
+    assert_eq!(ranked_ids(&segment, None, 10), vec![2.into(), 3.into()]);
+
+    assert!(matches!(
+        segment.text_search(&"not_indexed".to_string(), "fox", &statistics, None, 10),
+        Err(OperationError::MissingFullTextIndexForTextQuery { .. }),
+    ));
+}
@@ -4,12 +4,23 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use segment::common::operation_error::OperationError;
+use segment::data_types::index::KeywordIndexParams;
 use segment::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
 use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::id_tracker::immutable_id_tracker::ImmutableIdTracker;
+use segment::index::PayloadIndex;
 use segment::segment::Segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
-use segment::types::{Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+use segment::segment_constructor::{load_segment, ID_TRACKER_PATH};
+use segment::types::{
+    HnswConfig, Indexes, Payload, PayloadFieldSchema, PayloadSchemaParams, SearchParams,
+    SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload,
+};
+use serde_json::json;
 use tempfile::Builder;
 
 use crate::fixtures::segment::{build_segment_1, build_segment_2, empty_segment};
@@ -70,6 +81,227 @@ fn test_building_new_segment() {
     assert_eq!(merged_segment.point_version(3.into()), Some(100));
 }
 
+#[test]
+fn test_building_segment_grouped_by_tenant() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    let tenant_schema =
+        PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+            is_tenant: Some(true),
+            ..Default::default()
+        }));
+
+    let tenants = ["b", "a", "c"];
+
+    let mut segment1 = empty_segment(dir.path());
+    let mut segment2 = empty_segment(dir.path());
+    segment1
+        .create_field_index(1, "tenant", Some(&tenant_schema))
+        .unwrap();
+
+    // Interleave tenants across and within source segments
+    for idx in 0..30u64 {
+        let segment = if idx % 2 == 0 {
+            &mut segment1
+        } else {
+            &mut segment2
+        };
+        let payload: Payload = json!({ "tenant": tenants[idx as usize % tenants.len()] }).into();
+        segment
+            .upsert_point(idx + 2, idx.into(), only_default_vector(&[0., 1., 0., 1.]))
+            .unwrap();
+        segment.set_payload(idx + 2, idx.into(), &payload).unwrap();
+    }
+
+    let mut builder =
+        SegmentBuilder::new(dir.path(), temp_dir.path(), &segment1.segment_config).unwrap();
+    builder.update(&[&segment1, &segment2], &stopped).unwrap();
+    let merged_segment: Segment = builder.build(&stopped).unwrap();
+
+    assert_eq!(merged_segment.available_point_count(), 30);
+    assert_eq!(
+        merged_segment.payload_index.borrow().indexed_fields()["tenant"],
+        tenant_schema,
+    );
+
+    // Internal ids of each tenant must form a contiguous range, ordered by tenant
+    let id_tracker = merged_segment.id_tracker.borrow();
+    let tenants_by_offset = (0..30u64)
+        .map(|idx| {
+            let internal_id = id_tracker.internal_id(idx.into()).unwrap();
+            (internal_id, tenants[idx as usize % tenants.len()])
+        })
+        .sorted()
+        .map(|(_, tenant)| tenant)
+        .collect_vec();
+    assert_eq!(
+        tenants_by_offset.iter().dedup().collect_vec(),
+        vec![&"a", &"b", &"c"],
+    );
+}
+
+#[test]
+fn test_building_immutable_id_tracker() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    let segment1 = build_segment_1(dir.path());
+    let segment2 = build_segment_2(dir.path());
+
+    let mut config = segment1.segment_config.clone();
+    for vector_config in config.vector_data.values_mut() {
+        vector_config.storage_type = VectorStorageType::Mmap;
+    }
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
+    builder.update(&[&segment1, &segment2], &stopped).unwrap();
+    let mut merged_segment: Segment = builder.build(&stopped).unwrap();
+    assert!(!merged_segment.appendable_flag);
+
+    let id_tracker_path = merged_segment.current_path.join(ID_TRACKER_PATH);
+    assert!(ImmutableIdTracker::exists(&id_tracker_path));
+
+    let expected_points = segment1
+        .iter_points()
+        .chain(segment2.iter_points())
+        .sorted()
+        .dedup()
+        .collect_vec();
+    assert_eq!(merged_segment.iter_points().collect_vec(), expected_points);
+    for &point_id in &expected_points {
+        let source = if segment1.has_point(point_id) {
+            &segment1
+        } else {
+            &segment2
+        };
+        assert_eq!(
+            merged_segment.point_version(point_id),
+            source.point_version(point_id),
+        );
+    }
+
+    // Points can still be removed from the immutable segment, and stay removed after reload
+    let removed_id = expected_points[0];
+    merged_segment.delete_point(100, removed_id).unwrap();
+    assert!(!merged_segment.has_point(removed_id));
+    merged_segment.flush(true).unwrap();
+
+    let segment_path = merged_segment.current_path.clone();
+    drop(merged_segment);
+    let reloaded_segment = load_segment(&segment_path).unwrap().unwrap();
+
+    assert!(!reloaded_segment.has_point(removed_id));
+    assert_eq!(
+        reloaded_segment.iter_points().collect_vec(),
+        expected_points[1..].to_vec(),
+    );
+    assert_eq!(
+        reloaded_segment.read_range(Some(expected_points[2]), None),
+        expected_points[2..].to_vec(),
+    );
+}
+
+#[test]
+fn test_building_hnsw_from_indexed_segment() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+    let mut rng = StdRng::seed_from_u64(42);
+    let dim = 4;
+
+    let mut plain_segment = empty_segment(dir.path());
+    for idx in 0..1000u64 {
+        let vector = random_vector(&mut rng, dim);
+        plain_segment
+            .upsert_point(1, idx.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let mut config = plain_segment.segment_config.clone();
+    for vector_config in config.vector_data.values_mut() {
+        vector_config.index = Indexes::Hnsw(HnswConfig {
+            m: 16,
+            ef_construct: 100,
+            full_scan_threshold: 10,
+            max_indexing_threads: 2,
+            on_disk: None,
+            payload_m: None,
+            range_links: None,
+        });
+    }
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
+    builder.update_from(&plain_segment, &stopped).unwrap();
+    let mut indexed_segment = builder.build(&stopped).unwrap();
+
+    // Points of the indexed segment are removed or updated in another segment
+    let mut appendable_segment = empty_segment(dir.path());
+    for idx in 0..50u64 {
+        indexed_segment.delete_point(2, idx.into()).unwrap();
+    }
+    for idx in (50..100u64).chain(1000..1100) {
+        let vector = random_vector(&mut rng, dim);
+        appendable_segment
+            .upsert_point(3, idx.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    // Graph of the indexed segment is reused for most of the points
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
+    builder
+        .update(&[&indexed_segment, &appendable_segment], &stopped)
+        .unwrap();
+    let merged_segment = builder.build(&stopped).unwrap();
+
+    assert_eq!(merged_segment.available_point_count(), 1050);
+    assert!(!merged_segment.has_point(10.into()));
+    assert_eq!(merged_segment.point_version(60.into()), Some(3));
+    assert_eq!(merged_segment.point_version(500.into()), Some(1));
+
+    let top = 10;
+    let mut hits = 0;
+    let attempts = 20;
+    for _ in 0..attempts {
+        let query = random_vector(&mut rng, dim).into();
+        let search = |params: SearchParams| {
+            merged_segment
+                .search(
+                    DEFAULT_VECTOR_NAME,
+                    &query,
+                    &WithPayload::default(),
+                    &false.into(),
+                    None,
+                    top,
+                    Some(&params),
+                    &stopped,
+                )
+                .unwrap()
+                .into_iter()
+                .map(|scored_point| scored_point.id)
+                .collect_vec()
+        };
+        let exact = search(SearchParams {
+            exact: true,
+            ..Default::default()
+        });
+        let approximate = search(SearchParams {
+            hnsw_ef: Some(64),
+            ..Default::default()
+        });
+        assert!(exact.iter().all(|id| merged_segment.has_point(*id)));
+        hits += approximate.iter().filter(|id| exact.contains(id)).count();
+    }
+
+    let recall = hits as f64 / (attempts * top) as f64;
+    assert!(recall > 0.95, "recall: {recall}");
+}
+
 fn estimate_build_time(segment: &Segment, stop_delay_millis: u64) -> (u64, bool) {
     let stopped = Arc::new(AtomicBool::new(false));
 
@@ -85,6 +317,8 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: u64) -> (u64, bool)
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Hnsw(Default::default()),
                 quantization_config: None,
+                multivector_config: None,
+                datatype: None,
             },
         )]),
         sparse_vector_data: Default::default(),
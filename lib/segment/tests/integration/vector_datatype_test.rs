@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::{
+    DenseVector, QueryVector, Vector, VectorElementType, DEFAULT_VECTOR_NAME,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment::Segment;
+use segment::segment_constructor::{build_segment, load_segment};
+use segment::types::{
+    Distance, Indexes, MultiVectorConfig, PointIdType, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageDatatype, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+/// Large enough to use SIMD implementations and have a remainder
+const DIM: usize = 40;
+const NUM_POINTS: u64 = 100;
+
+/// Random vector, exactly representable with the given datatype
+fn random_vector<R: Rng + ?Sized>(rnd: &mut R, datatype: VectorStorageDatatype) -> DenseVector {
+    (0..DIM)
+        .map(|_| match datatype {
+            VectorStorageDatatype::Float32 => rnd.gen_range(-1.0..1.0),
+            VectorStorageDatatype::Float16 => rnd.gen_range(-32..32) as f32 / 8.0,
+            VectorStorageDatatype::Uint8 => rnd.gen_range(0..=255) as f32,
+        })
+        .collect()
+}
+
+fn segment_config(
+    distance: Distance,
+    storage_type: VectorStorageType,
+    datatype: VectorStorageDatatype,
+    multivector_config: Option<MultiVectorConfig>,
+) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config,
+                datatype: Some(datatype),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+fn search_all(segment: &Segment, query: &DenseVector) -> HashMap<PointIdType, f32> {
+    segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &QueryVector::from(query.clone()),
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            NUM_POINTS as usize,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|point| (point.id, point.score))
+        .collect()
+}
+
+#[rstest]
+fn test_vector_datatype_search(
+    #[values(VectorStorageDatatype::Uint8, VectorStorageDatatype::Float16)]
+    datatype: VectorStorageDatatype,
+    #[values(VectorStorageType::Memory, VectorStorageType::ChunkedMmap)]
+    storage_type: VectorStorageType,
+    #[values(Distance::Dot, Distance::Cosine, Distance::Euclid, Distance::Manhattan)]
+    distance: Distance,
+) {
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = segment_config(distance, storage_type, datatype, None);
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let mut stored = HashMap::new();
+    for n in 0..NUM_POINTS {
+        let vector = random_vector(&mut rnd, datatype);
+        let named_vectors = NamedVectors::from_ref(DEFAULT_VECTOR_NAME, vector.as_slice().into());
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), named_vectors)
+            .unwrap();
+        stored.insert(n, vector);
+    }
+
+    // Scores must match the ones computed on `float32` vectors
+    let query = random_vector(&mut rnd, datatype);
+    let processed_query = distance.preprocess_vector::<VectorElementType>(query.clone());
+    let tolerance = match datatype {
+        VectorStorageDatatype::Float16 if distance == Distance::Cosine => 1e-2,
+        _ => 1e-4,
+    };
+    let result = search_all(&segment, &query);
+    assert_eq!(result.len(), stored.len());
+    for (id, vector) in &stored {
+        let expected = distance.similarity(
+            &processed_query,
+            &distance.preprocess_vector::<VectorElementType>(vector.clone()),
+        );
+        let score = result[&(*id).into()];
+        assert!(
+            (score - expected).abs() <= tolerance * expected.abs().max(1.0),
+            "point {id}: {score} != {expected}",
+        );
+    }
+
+    // Vectors are returned as they were inserted, unless normalized
+    if distance != Distance::Cosine || datatype == VectorStorageDatatype::Uint8 {
+        let retrieved = segment.vector(DEFAULT_VECTOR_NAME, 7.into()).unwrap();
+        assert_eq!(retrieved, Some(Vector::Dense(stored[&7].clone())));
+    }
+
+    // Vectors and scores survive the reload of the segment
+    segment.flush(true).unwrap();
+    let path = segment.current_path.clone();
+    drop(segment);
+    let segment = load_segment(&path).unwrap().unwrap();
+    assert_eq!(search_all(&segment, &query), result);
+}
+
+#[test]
+fn test_uint8_datatype_truncates_values() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let config = segment_config(
+        Distance::Dot,
+        VectorStorageType::Memory,
+        VectorStorageDatatype::Uint8,
+        None,
+    );
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let vector: DenseVector = (0..DIM).map(|i| i as f32 * 10.0 - 0.5).collect();
+    segment
+        .upsert_point(
+            1,
+            1.into(),
+            NamedVectors::from_ref(DEFAULT_VECTOR_NAME, vector.as_slice().into()),
+        )
+        .unwrap();
+
+    let expected: DenseVector = vector.iter().map(|x| x.clamp(0.0, 255.0).trunc()).collect();
+    let retrieved = segment.vector(DEFAULT_VECTOR_NAME, 1.into()).unwrap();
+    assert_eq!(retrieved, Some(Vector::Dense(expected)));
+}
+
+#[test]
+fn test_multivector_rejects_datatype() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let config = segment_config(
+        Distance::Dot,
+        VectorStorageType::Memory,
+        VectorStorageDatatype::Uint8,
+        Some(MultiVectorConfig::default()),
+    );
+    assert!(build_segment(dir.path(), &config, true).is_err());
+}
@@ -55,6 +55,10 @@ impl InvertedIndex for InvertedIndexMmap {
         self.get(id).map(PostingListIterator::new)
     }
 
+    fn posting_list_len(&self, id: &DimId) -> Option<usize> {
+        self.get(id).map(|posting_list| posting_list.len())
+    }
+
     fn files(path: &Path) -> Vec<PathBuf> {
         vec![
             Self::index_file_path(path),
@@ -283,9 +287,12 @@ mod tests {
         assert_eq!(inverted_index_mmap.get(&3).unwrap().len(), 3);
         assert!(inverted_index_mmap.get(&4).unwrap().is_empty()); // return empty posting list info for intermediary empty ids
         assert_eq!(inverted_index_mmap.get(&5).unwrap().len(), 2);
+        assert_eq!(inverted_index_mmap.posting_list_len(&1), Some(9));
+        assert_eq!(inverted_index_mmap.posting_list_len(&4), Some(0));
         // index after the last values are None
         assert!(inverted_index_mmap.get(&6).is_none());
         assert!(inverted_index_mmap.get(&7).is_none());
         assert!(inverted_index_mmap.get(&100).is_none());
+        assert!(inverted_index_mmap.posting_list_len(&100).is_none());
     }
 }
@@ -54,6 +54,10 @@ impl InvertedIndex for InvertedIndexRam {
             .map(|posting_list| PostingListIterator::new(&posting_list.elements))
     }
 
+    fn posting_list_len(&self, id: &DimId) -> Option<usize> {
+        self.get(id).map(|posting_list| posting_list.elements.len())
+    }
+
     fn files(path: &Path) -> Vec<PathBuf> {
         [
             InvertedIndexMmap::index_file_path(path),
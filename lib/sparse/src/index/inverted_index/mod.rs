@@ -20,6 +20,9 @@ pub trait InvertedIndex: Sized {
     /// Get posting list for dimension id
     fn get(&self, id: &DimId) -> Option<PostingListIterator>;
 
+    /// Number of vectors in the posting list of dimension id, i.e. its document frequency
+    fn posting_list_len(&self, id: &DimId) -> Option<usize>;
+
     /// Files used by this index
     fn files(path: &Path) -> Vec<PathBuf>;
 
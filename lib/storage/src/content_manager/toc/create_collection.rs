@@ -38,6 +38,7 @@ impl TableOfContent {
             shard_number,
             sharding_method,
             on_disk_payload,
+            mmap_payload,
             hnsw_config: hnsw_config_diff,
             wal_config: wal_config_diff,
             optimizers_config: optimizers_config_diff,
@@ -46,6 +47,7 @@ impl TableOfContent {
             init_from,
             quantization_config,
             sparse_vectors,
+            strict_mode_config,
         } = operation;
 
         self.collections
@@ -114,6 +116,7 @@ impl TableOfContent {
             })?,
             sharding_method,
             on_disk_payload: on_disk_payload.unwrap_or(self.storage_config.on_disk_payload),
+            mmap_payload,
             replication_factor: NonZeroU32::new(replication_factor).ok_or(
                 StorageError::BadInput {
                     description: "`replication_factor` cannot be 0".to_string(),
@@ -157,6 +160,7 @@ impl TableOfContent {
             optimizer_config: optimizers_config,
             hnsw_config,
             quantization_config,
+            strict_mode_config,
         };
         let collection = Collection::new(
             collection_name.to_string(),
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use collection::collection::Collection;
+use collection::distance_matrix::CollectionSearchMatrixResponse;
 use collection::grouping::group_by::GroupRequest;
 use collection::grouping::GroupBy;
 use collection::operations::consistency_params::ReadConsistency;
@@ -8,9 +9,9 @@ use collection::operations::point_ops::WriteOrdering;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::CollectionUpdateOperations;
-use collection::{discovery, recommendations};
+use collection::{discovery, distance_matrix, hybrid, recommendations, universal_query};
 use futures::future::try_join_all;
-use segment::types::{ScoredPoint, ShardKey};
+use segment::types::{ExtendedPointId, Filter, ScoredPoint, ShardKey};
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageError;
@@ -35,6 +36,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<ScoredPoint>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
         recommendations::recommend_by(
             request,
             &collection,
@@ -65,6 +69,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection
+            .check_strict_mode_batch(strict_mode_requests(&requests).into_iter(), timeout)
+            .await?;
         recommendations::recommend_batch_by(
             requests,
             &collection,
@@ -99,6 +106,11 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection
+                .check_strict_mode_batch(request.searches.iter(), timeout)
+                .await?;
+        }
         collection
             .core_search_batch(request, read_consistency, shard_selection, timeout)
             .await
@@ -125,12 +137,91 @@ impl TableOfContent {
         shard_selection: ShardSelectorInternal,
     ) -> Result<CountResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request, None).await?;
+        }
         collection
             .count(request, read_consistency, &shard_selection)
             .await
             .map_err(|err| err.into())
     }
 
+    /// Count points per value of a payload key
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we count
+    /// * `request` - [`FacetRequestInternal`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// The most frequent values of the key with their number of points
+    pub async fn facet(
+        &self,
+        collection_name: &str,
+        request: FacetRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<FacetResponse, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request, None).await?;
+        }
+        collection
+            .facet(request, read_consistency, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Collect statistics of sparse vector dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we collect statistics
+    /// * `request` - [`SparseVectorStatisticsRequest`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Number of vectors and document frequencies of the requested dimensions, per vector name
+    pub async fn sparse_vector_statistics(
+        &self,
+        collection_name: &str,
+        request: SparseVectorStatisticsRequest,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<SparseVectorStatisticsResult, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        collection
+            .sparse_vector_statistics(request, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Rank points by the BM25 score of the text in a full-text indexed payload field
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`TextSearchRequest`]
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// Points with BM25 score, best first
+    pub async fn text_search(
+        &self,
+        collection_name: &str,
+        request: TextSearchRequest,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        collection
+            .text_search(request, None, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Return specific points by IDs
     ///
     /// # Arguments
@@ -165,6 +256,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<GroupsResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
 
         let collection_by_name = |name| self.get_collection_opt(name);
 
@@ -189,6 +283,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<ScoredPoint>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
         discovery::discover(
             request,
             &collection,
@@ -201,6 +298,100 @@ impl TableOfContent {
         .map_err(|err| err.into())
     }
 
+    /// Run the prefetch searches of the hybrid request and fuse their results
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`HybridSearchRequestInternal`]
+    /// * `shard_selector` - which local shards to use
+    ///
+    /// # Result
+    ///
+    /// Points with fused score
+    pub async fn search_hybrid(
+        &self,
+        collection_name: &str,
+        request: HybridSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selector: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
+        hybrid::search_hybrid(
+            request,
+            &collection,
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
+    /// Search the nearest neighbours of each point of a random sample within that sample
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`SearchMatrixRequestInternal`]
+    /// * `shard_selector` - which local shards to use
+    ///
+    /// # Result
+    ///
+    /// Sampled points with their nearest neighbours
+    pub async fn search_points_matrix(
+        &self,
+        collection_name: &str,
+        request: SearchMatrixRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selector: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> Result<CollectionSearchMatrixResponse, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
+        distance_matrix::search_points_matrix(
+            request,
+            &collection,
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
+    /// Select a random sample of points which satisfy the filter
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we sample
+    /// * `limit` - maximal number of sampled points
+    /// * `filter` - condition sampled points should satisfy
+    /// * `shard_selection` - which local shard to use
+    ///
+    /// # Result
+    ///
+    /// IDs of sampled points
+    pub async fn sample_points(
+        &self,
+        collection_name: &str,
+        limit: usize,
+        filter: Option<&Filter>,
+        shard_selection: ShardSelectorInternal,
+    ) -> Result<Vec<ExtendedPointId>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        collection
+            .sample_points(limit, filter, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
     pub async fn discover_batch(
         &self,
         collection_name: &str,
@@ -209,6 +400,9 @@ impl TableOfContent {
         timeout: Option<Duration>,
     ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        collection
+            .check_strict_mode_batch(strict_mode_requests(&requests).into_iter(), timeout)
+            .await?;
 
         discovery::discover_batch(
             requests,
@@ -221,6 +415,74 @@ impl TableOfContent {
         .map_err(|err| err.into())
     }
 
+    /// Run a universal query: search, recommend, discover or context search, optionally
+    /// rescoring or fusing the candidates of nested prefetches
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we query
+    /// * `request` - [`QueryRequestInternal`]
+    /// * `shard_selector` - which local shards to use
+    ///
+    /// # Result
+    ///
+    /// Points with the score of the last query stage
+    pub async fn query(
+        &self,
+        collection_name: &str,
+        request: QueryRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selector: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode(&request, timeout).await?;
+        }
+        universal_query::query(
+            request,
+            &collection,
+            |name| self.get_collection_opt(name),
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
+    /// Run universal queries in a batching fashion
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we query
+    /// * `requests` - [`QueryRequestInternal`] with the shards to query in
+    ///
+    /// # Result
+    ///
+    /// Points with the score of the last query stage, for each request
+    pub async fn query_batch(
+        &self,
+        collection_name: &str,
+        requests: Vec<(QueryRequestInternal, ShardSelectorInternal)>,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
+        let collection = self.get_collection(collection_name).await?;
+        collection
+            .check_strict_mode_batch(strict_mode_requests(&requests).into_iter(), timeout)
+            .await?;
+        universal_query::query_batch(
+            requests,
+            &collection,
+            |name| self.get_collection_opt(name),
+            read_consistency,
+            timeout,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
     /// Paginate over all stored points with given filtering conditions
     ///
     /// # Arguments
@@ -240,6 +502,9 @@ impl TableOfContent {
         shard_selection: ShardSelectorInternal,
     ) -> Result<ScrollResult, StorageError> {
         let collection = self.get_collection(collection_name).await?;
+        if !shard_selection.is_shard_id() {
+            collection.check_strict_mode(&request, None).await?;
+        }
         collection
             .scroll_by(request, read_consistency, &shard_selection)
             .await
@@ -317,6 +582,10 @@ impl TableOfContent {
         if operation.is_write_operation() {
             self.check_write_lock()?;
         }
+        // Like the rate limit, strict mode is only checked on the first node in the chain
+        if !shard_selector.is_shard_id() {
+            collection.check_strict_mode_update(&operation).await?;
+        }
         let res = match shard_selector {
             ShardSelectorInternal::Empty => {
                 collection
@@ -351,3 +620,14 @@ impl TableOfContent {
         Ok(res)
     }
 }
+
+/// Requests of the batch, which are checked in strict mode
+///
+/// Requests to a specific shard id are internal, they are checked by the original request.
+fn strict_mode_requests<R>(requests: &[(R, ShardSelectorInternal)]) -> Vec<&R> {
+    requests
+        .iter()
+        .filter(|(_, shard_selection)| !shard_selection.is_shard_id())
+        .map(|(request, _)| request)
+        .collect()
+}
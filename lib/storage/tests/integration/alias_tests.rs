@@ -95,6 +95,8 @@ fn test_alias_operation() {
                             hnsw_config: None,
                             quantization_config: None,
                             on_disk: None,
+                            multivector_config: None,
+                            datatype: None,
                         }
                         .into(),
                         sparse_vectors: None,
@@ -103,11 +105,13 @@ fn test_alias_operation() {
                         optimizers_config: None,
                         shard_number: Some(1),
                         on_disk_payload: None,
+                        mmap_payload: None,
                         replication_factor: None,
                         write_consistency_factor: None,
                         init_from: None,
                         quantization_config: None,
                         sharding_method: None,
+                        strict_mode_config: None,
                     },
                 )),
                 None,
@@ -0,0 +1,111 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_datetime'
+
+DATETIMES = [
+    "2023-12-31T23:30:00+01:00",
+    "2024-01-01T00:00:00Z",
+    "2024-01-01T10:00:00.5Z",
+    "2024-01-02T04:59:59-03:00",
+    "2024-01-02T05:00:00-03:00",
+    "2024-02-29T12:00:00+00:00",
+]
+
+
+def create_collection(on_disk_vectors, with_index):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    if with_index:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": "created_at",
+                "field_schema": "datetime"
+            }
+        )
+        assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": idx,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"created_at": datetime}
+                }
+                for idx, datetime in enumerate(DATETIMES)
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+@pytest.mark.parametrize("with_index", [True, False])
+def test_datetime_range(on_disk_vectors, with_index):
+    create_collection(on_disk_vectors, with_index)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {
+                        "key": "created_at",
+                        "range": {
+                            "gte": "2024-01-01T00:00:00Z",
+                            "lt": "2024-01-02T09:00:00+01:00",
+                        }
+                    }
+                ]
+            }
+        },
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']['points']] == [1, 2, 3]
+
+
+def test_datetime_index_schema(on_disk_vectors):
+    create_collection(on_disk_vectors, with_index=True)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    payload_schema = response.json()['result']['payload_schema']
+    assert payload_schema['created_at']['data_type'] == "datetime"
+    assert payload_schema['created_at']['points'] == len(DATETIMES)
+
@@ -0,0 +1,121 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_facet'
+
+NUM_POINTS = 30
+BRANDS = ["a", "b", "c"]
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup():
+    facet_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def facet_collection_setup(collection_name):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {"size": 4, "distance": "Dot"},
+            "shard_number": 2,
+        }
+    )
+    assert response.ok
+
+    for field_name, field_schema in [("brand", "keyword"), ("size", "integer")]:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={"field_name": field_name, "field_schema": field_schema}
+        )
+        assert response.ok
+
+    # Brand "a" is in 15 points, "b" in 10 and "c" in 5
+    points = []
+    for point_id in range(NUM_POINTS):
+        if point_id % 2 == 0:
+            brand = "a"
+        elif point_id % 3 != 0:
+            brand = "b"
+        else:
+            brand = "c"
+        points.append({
+            "id": point_id,
+            "vector": [1.0, 0.0, 0.0, 0.0],
+            "payload": {"brand": brand, "size": point_id % 2, "title": f"point {point_id}"},
+        })
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points}
+    )
+    assert response.ok
+
+
+def facet(body):
+    response = request_with_validation(
+        api='/collections/{collection_name}/facet',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']['hits']
+
+
+@pytest.mark.parametrize("exact", [True, False])
+def test_facet_keyword(exact):
+    hits = facet({"key": "brand", "exact": exact})
+    assert hits == [
+        {"value": "a", "count": 15},
+        {"value": "b", "count": 10},
+        {"value": "c", "count": 5},
+    ]
+
+
+def test_facet_limit():
+    hits = facet({"key": "brand", "limit": 1, "exact": True})
+    assert hits == [{"value": "a", "count": 15}]
+
+
+def test_facet_integer():
+    hits = facet({"key": "size", "exact": True})
+    assert hits == [
+        {"value": 0, "count": 15},
+        {"value": 1, "count": 15},
+    ]
+
+
+def test_facet_filter():
+    hits = facet({
+        "key": "brand",
+        "exact": True,
+        "filter": {"must": [{"key": "size", "match": {"value": 1}}]},
+    })
+    assert hits == [
+        {"value": "b", "count": 10},
+        {"value": "c", "count": 5},
+    ]
+
+
+def test_facet_without_index():
+    response = request_with_validation(
+        api='/collections/{collection_name}/facet',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"key": "title"},
+    )
+    assert response.status_code == 400
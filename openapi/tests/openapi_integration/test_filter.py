@@ -67,3 +67,31 @@ def test_just_key():
     error = response.json()["status"]["error"]
     assert "Validation error in JSON body" in error
     assert "At least one field condition must be specified" in error
+
+
+def test_min_should():
+    def scroll_ids(min_count):
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "limit": 10,
+                "filter": {
+                    "min_should": {
+                        "conditions": [
+                            {"key": "city", "match": {"value": "Berlin"}},
+                            {"key": "city", "match": {"value": "London"}},
+                            {"key": "city", "match": {"value": "Moscow"}},
+                        ],
+                        "min_count": min_count,
+                    }
+                }
+            }
+        )
+        assert response.ok
+        return [x['id'] for x in response.json()['result']['points']]
+
+    assert scroll_ids(1) == [1, 2, 3, 4]
+    assert scroll_ids(2) == [2, 3, 4]
+    assert scroll_ids(3) == []
@@ -187,6 +187,7 @@ def basic_collection_setup(
             "field_schema": {
                 "type": "text",
                 "tokenizer": "prefix",
+                "phrase_matching": True,
             }
         }
     )
@@ -274,3 +275,32 @@ def test_scroll_with_prefix():
 
     assert response.ok
     assert len(response.json()['result']) == 3
+
+
+def test_scroll_with_phrase():
+    def scroll_phrase(phrase):
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "limit": 10,
+                "with_payload": True,
+                "filter": {
+                    "must": [
+                        {
+                            "key": "title",
+                            "match": {
+                                "phrase": phrase,
+                            }
+                        }
+                    ]
+                }
+            }
+        )
+        assert response.ok
+        return sorted(point['payload']['title'] for point in response.json()['result']['points'])
+
+    assert scroll_phrase("the weap") == ["The Weapon", "The Weapon Too Dreadful to Use"]
+    assert scroll_phrase("weapon too dread") == ["The Weapon Too Dreadful to Use"]
+    assert scroll_phrase("weapon the") == []
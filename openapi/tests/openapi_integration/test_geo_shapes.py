@@ -0,0 +1,153 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_geo_shapes'
+
+square = {
+    "type": "Polygon",
+    "coordinates": [[[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]]],
+}
+
+square_with_hole = {
+    "type": "Polygon",
+    "coordinates": [
+        [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]],
+        [[4.5, 4.5], [5.5, 4.5], [5.5, 5.5], [4.5, 5.5], [4.5, 4.5]],
+    ],
+}
+
+
+def geo_shapes_collection_setup(on_disk_vectors, with_index):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    if with_index:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": "location",
+                "field_schema": "geo",
+            }
+        )
+        assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"location": {"lon": 5.0, "lat": 5.0}},
+                },
+                {
+                    "id": 2,
+                    "vector": [0.19, 0.81, 0.75, 0.11],
+                    "payload": {"location": {"type": "LineString", "coordinates": [[-5.0, 5.0], [15.0, 5.0]]}},
+                },
+                {
+                    "id": 3,
+                    "vector": [0.36, 0.55, 0.47, 0.94],
+                    "payload": {"location": {
+                        "type": "Polygon",
+                        "coordinates": [[[2.0, 2.0], [4.0, 2.0], [4.0, 4.0], [2.0, 4.0], [2.0, 2.0]]],
+                    }},
+                },
+                {
+                    "id": 4,
+                    "vector": [0.18, 0.01, 0.85, 0.80],
+                    "payload": {"location": {"type": "Point", "coordinates": [50.0, 50.0]}},
+                },
+                {
+                    "id": 5,
+                    "vector": [0.24, 0.18, 0.22, 0.44],
+                    "payload": {"location": {
+                        "type": "Polygon",
+                        "coordinates": [[[8.0, 8.0], [12.0, 8.0], [12.0, 12.0], [8.0, 12.0], [8.0, 8.0]]],
+                    }},
+                },
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ids(condition):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [condition]
+            },
+        },
+    )
+    assert response.ok
+    return [point['id'] for point in response.json()['result']['points']]
+
+
+@pytest.mark.parametrize("with_index", [False, True])
+def test_geo_shapes(on_disk_vectors, with_index):
+    geo_shapes_collection_setup(on_disk_vectors, with_index)
+
+    assert scroll_ids({"key": "location", "geo_intersects": square}) == [1, 2, 3, 5]
+    assert scroll_ids({"key": "location", "geo_within": square}) == [1, 3]
+
+    assert scroll_ids({"key": "location", "geo_intersects": square_with_hole}) == [2, 3, 5]
+    assert scroll_ids({"key": "location", "geo_within": square_with_hole}) == [3]
+
+    line = {"type": "LineString", "coordinates": [[3.0, -1.0], [3.0, 1.0]]}
+    assert scroll_ids({"key": "location", "geo_intersects": line}) == []
+
+    # Classic geo filters keep matching only points
+    assert scroll_ids({"key": "location", "geo_radius": {
+        "center": {"lon": 5.0, "lat": 5.0},
+        "radius": 1000.0,
+    }}) == [1]
+
+
+def test_invalid_geo_shape(on_disk_vectors):
+    geo_shapes_collection_setup(on_disk_vectors, False)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [{
+                    "key": "location",
+                    "geo_intersects": {"type": "LineString", "coordinates": [[200.0, 5.0], [3.0, 5.0]]},
+                }]
+            },
+        },
+    )
+    assert response.status_code == 400
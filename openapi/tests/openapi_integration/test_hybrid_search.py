@@ -0,0 +1,131 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_hybrid'
+
+DENSE_QUERY = [0.2, 0.1, 0.9, 0.7]
+SPARSE_QUERY = {
+    "name": "sparse-text",
+    "vector": {
+        "indices": [1, 3, 12],
+        "values": [1.0, 0.5, 1.0],
+    }
+}
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def search(vector, limit, filter=None):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": vector,
+            "filter": filter,
+            "limit": limit,
+        }
+    )
+    assert response.ok
+    return response.json()['result']
+
+
+def search_hybrid(body):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/hybrid',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def rrf(results_with_weights, k=60):
+    scores = {}
+    for results, weight in results_with_weights:
+        for rank, point in enumerate(results, start=1):
+            scores[point['id']] = scores.get(point['id'], 0.0) + weight / (k + rank)
+    return sorted(scores.items(), key=lambda item: (-item[1], item[0]))
+
+
+def test_hybrid_search_rrf():
+    dense = search(DENSE_QUERY, limit=5)
+    sparse = search(SPARSE_QUERY, limit=5)
+    expected = rrf([(dense, 1.0), (sparse, 1.0)])
+
+    result = search_hybrid({
+        "prefetch": [
+            {"vector": DENSE_QUERY, "limit": 5},
+            {"vector": SPARSE_QUERY, "limit": 5},
+        ],
+        "limit": 10,
+    })
+
+    assert [point['id'] for point in result] == [point_id for point_id, _ in expected]
+    for point, (_, score) in zip(result, expected):
+        assert point['score'] == pytest.approx(score, rel=1e-5)
+
+
+def test_hybrid_search_weights_and_pagination():
+    dense = search(DENSE_QUERY, limit=5)
+    sparse = search(SPARSE_QUERY, limit=5)
+    expected = rrf([(dense, 0.5), (sparse, 2.0)])
+
+    result = search_hybrid({
+        "prefetch": [
+            {"vector": DENSE_QUERY, "limit": 5, "weight": 0.5},
+            {"vector": SPARSE_QUERY, "limit": 5, "weight": 2.0},
+        ],
+        "fusion": "rrf",
+        "limit": 3,
+        "offset": 2,
+        "with_payload": True,
+    })
+
+    assert [point['id'] for point in result] == [point_id for point_id, _ in expected[2:5]]
+    assert all(point['payload'] is not None for point in result)
+
+
+def test_hybrid_search_filter():
+    berlin = {"must": [{"key": "city", "match": {"value": "Berlin"}}]}
+    dense = search(DENSE_QUERY, limit=5, filter=berlin)
+
+    result = search_hybrid({
+        "prefetch": [
+            {"vector": DENSE_QUERY, "limit": 5},
+            {"vector": SPARSE_QUERY, "limit": 5},
+        ],
+        "filter": berlin,
+        "limit": 10,
+    })
+
+    # None of the sparse vectors belong to Berlin, so only the dense candidates are left
+    assert [point['id'] for point in result] == [point['id'] for point in dense]
+
+
+def test_hybrid_search_normalized():
+    result = search_hybrid({
+        "prefetch": [
+            {"vector": DENSE_QUERY, "limit": 3},
+            {"vector": SPARSE_QUERY, "limit": 3},
+        ],
+        "fusion": "normalized",
+        "limit": 10,
+    })
+
+    # The best match of every prefetch gets the normalized score of 1.0
+    dense_top = search(DENSE_QUERY, limit=1)[0]['id']
+    sparse_top = search(SPARSE_QUERY, limit=1)[0]['id']
+    scores = {point['id']: point['score'] for point in result}
+    assert scores[dense_top] == pytest.approx(1.0)
+    assert scores[sparse_top] == pytest.approx(1.0)
+    assert all(0.0 <= score <= 1.0 for score in scores.values())
+
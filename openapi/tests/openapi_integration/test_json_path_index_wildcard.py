@@ -0,0 +1,143 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_json_path_index_wildcard'
+
+
+def json_path_collection_setup(on_disk_vectors, with_index):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    if with_index:
+        for field_name, field_schema in [("items[0].name", "keyword"), ("prices.*.amount", "float")]:
+            response = request_with_validation(
+                api='/collections/{collection_name}/index',
+                method="PUT",
+                path_params={'collection_name': collection_name},
+                query_params={'wait': 'true'},
+                body={
+                    "field_name": field_name,
+                    "field_schema": field_schema,
+                }
+            )
+            assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": 1,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {
+                        "items": [{"name": "apple"}, {"name": "pear"}],
+                        "prices": {"eur": {"amount": 1.5}, "usd": {"amount": 1.7}},
+                    }
+                },
+                {
+                    "id": 2,
+                    "vector": [0.19, 0.81, 0.75, 0.11],
+                    "payload": {
+                        "items": [{"name": "pear"}, {"name": "apple"}],
+                        "prices": {"eur": {"amount": 12.0}},
+                    }
+                },
+                {
+                    "id": 3,
+                    "vector": [0.36, 0.55, 0.47, 0.94],
+                    "payload": {
+                        "items": [{"name": "plum"}],
+                        "prices": {"usd": {"amount": 25.0}, "gbp": {"amount": 0.5}},
+                    }
+                },
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ids(condition):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [condition]
+            },
+        },
+    )
+    assert response.ok
+    return [point['id'] for point in response.json()['result']['points']]
+
+
+@pytest.mark.parametrize("with_index", [False, True])
+def test_filter_by_array_index_and_wildcard(on_disk_vectors, with_index):
+    json_path_collection_setup(on_disk_vectors, with_index)
+
+    assert scroll_ids({"key": "items[0].name", "match": {"value": "apple"}}) == [1]
+    assert scroll_ids({"key": "items[].name", "match": {"value": "apple"}}) == [1, 2]
+    assert scroll_ids({"key": "prices.*.amount", "range": {"gt": 10.0}}) == [2, 3]
+    assert scroll_ids({"key": "prices.*.amount", "range": {"lt": 1.0}}) == [3]
+
+
+def test_payload_selector_with_array_index_and_wildcard(on_disk_vectors):
+    json_path_collection_setup(on_disk_vectors, with_index=False)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [{"has_id": [1]}]
+            },
+            "with_payload": {"include": ["items[1].name", "prices.*.amount"]},
+        },
+    )
+    assert response.ok
+    assert response.json()['result']['points'][0]['payload'] == {
+        "items": [{"name": "pear"}],
+        "prices": {"eur": {"amount": 1.5}, "usd": {"amount": 1.7}},
+    }
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [{"has_id": [1]}]
+            },
+            "with_payload": {"exclude": ["items[0]", "prices.*"]},
+        },
+    )
+    assert response.ok
+    assert response.json()['result']['points'][0]['payload'] == {
+        "items": [{"name": "pear"}],
+        "prices": {},
+    }
@@ -0,0 +1,84 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_keyword_prefix_range'
+
+
+def keyword_collection_setup(on_disk_vectors, with_index):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    if with_index:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": "sku",
+                "field_schema": "keyword",
+            }
+        )
+        assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 1, "vector": [0.05, 0.61, 0.76, 0.74], "payload": {"sku": "A100"}},
+                {"id": 2, "vector": [0.19, 0.81, 0.75, 0.11], "payload": {"sku": "A150"}},
+                {"id": 3, "vector": [0.36, 0.55, 0.47, 0.94], "payload": {"sku": ["A200", "eu-west"]}},
+                {"id": 4, "vector": [0.18, 0.01, 0.85, 0.80], "payload": {"sku": "eu-north"}},
+                {"id": 5, "vector": [0.24, 0.18, 0.22, 0.44], "payload": {"sku": 150}},
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ids(condition):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [condition]
+            },
+        },
+    )
+    assert response.ok
+    return [point['id'] for point in response.json()['result']['points']]
+
+
+@pytest.mark.parametrize("with_index", [False, True])
+def test_keyword_prefix_and_range(on_disk_vectors, with_index):
+    keyword_collection_setup(on_disk_vectors, with_index)
+
+    assert scroll_ids({"key": "sku", "match": {"prefix": "eu-"}}) == [3, 4]
+    assert scroll_ids({"key": "sku", "range": {"keyword": {"gte": "A100", "lt": "A200"}}}) == [1, 2]
+    assert scroll_ids({"key": "sku", "range": {"keyword": {"gt": "A150"}}}) == [3, 4]
@@ -0,0 +1,189 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_multivector'
+
+POINTS = {
+    1: [[0.1, 0.2, 0.3, 0.4], [0.5, 0.1, 0.1, 0.1]],
+    2: [[0.9, 0.1, 0.0, 0.2]],
+    3: [[0.0, 0.3, 0.8, 0.1], [0.2, 0.2, 0.2, 0.2], [0.7, 0.0, 0.1, 0.6]],
+}
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors):
+    multivector_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def multivector_collection_setup(collection_name, on_disk_vectors):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "colbert": {
+                    "size": 4,
+                    "distance": "Dot",
+                    "on_disk": on_disk_vectors,
+                    "multivector_config": {"comparator": "max_sim"},
+                }
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": point_id, "vector": {"colbert": vectors}}
+                for point_id, vectors in POINTS.items()
+            ]
+        }
+    )
+    assert response.ok
+
+
+def max_sim(query, vectors):
+    return sum(
+        max(sum(q * v for q, v in zip(query_row, row)) for row in vectors)
+        for query_row in query
+    )
+
+
+def test_multivector_config():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    params = response.json()['result']['config']['params']['vectors']['colbert']
+    assert params['multivector_config'] == {"comparator": "max_sim"}
+
+
+def test_multivector_retrieve():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 3},
+    )
+    assert response.ok
+    vectors = response.json()['result']['vector']['colbert']
+    assert len(vectors) == 3
+    for row, expected in zip(vectors, POINTS[3]):
+        assert row == pytest.approx(expected)
+
+
+def test_multivector_search():
+    query = [[0.3, 0.1, 0.5, 0.2], [0.1, 0.9, 0.0, 0.1]]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {"name": "colbert", "vector": query},
+            "limit": 3,
+        }
+    )
+    assert response.ok, response.text
+    result = response.json()['result']
+
+    expected = sorted(
+        ((point_id, max_sim(query, vectors)) for point_id, vectors in POINTS.items()),
+        key=lambda item: -item[1],
+    )
+    assert [point['id'] for point in result] == [point_id for point_id, _ in expected]
+    for point, (_, score) in zip(result, expected):
+        assert point['score'] == pytest.approx(score, rel=1e-5)
+
+
+def test_multivector_wrong_dimension():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 4, "vector": {"colbert": [[0.1, 0.2, 0.3]]}}
+            ]
+        }
+    )
+    assert response.status_code == 400
+
+
+def test_multivector_average_recommend_rejected():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/recommend',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "positive": [1],
+            "using": "colbert",
+            "limit": 3,
+        }
+    )
+    assert response.status_code == 400
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/recommend',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "positive": [1],
+            "using": "colbert",
+            "strategy": "best_score",
+            "limit": 3,
+        }
+    )
+    assert response.ok, response.text
+    assert all(point['id'] != 1 for point in response.json()['result'])
+
+
+def test_multivector_quantization_rejected():
+    quantization_config = {"scalar": {"type": "int8"}}
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name + '_quantized'},
+        body={
+            "vectors": {
+                "colbert": {
+                    "size": 4,
+                    "distance": "Dot",
+                    "multivector_config": {"comparator": "max_sim"},
+                    "quantization_config": quantization_config,
+                }
+            },
+        }
+    )
+    assert response.status_code == 422
+    error = response.json()["status"]["error"]
+    assert "quantization_config is not supported for multi vectors" in error
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PATCH",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "colbert": {
+                    "quantization_config": quantization_config,
+                }
+            },
+        }
+    )
+    assert response.status_code == 400
@@ -0,0 +1,130 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_on_disk_payload_index'
+
+def create_collection(on_disk_vectors, field_schema, values):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "field",
+            "field_schema": field_schema
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": idx,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"field": values[idx % len(values)]}
+                }
+                for idx in range(6)
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+@pytest.mark.parametrize("field_schema, values, condition", [
+    (
+        {"type": "keyword", "on_disk": True},
+        ["alice", "bob"],
+        {"match": {"value": "bob"}},
+    ),
+    (
+        {"type": "integer", "on_disk": True},
+        [10, 20],
+        {"match": {"value": 20}},
+    ),
+    (
+        {"type": "float", "on_disk": True},
+        [1.5, 2.5],
+        {"range": {"gt": 2.0}},
+    ),
+    (
+        {"type": "datetime", "on_disk": True},
+        ["2024-01-01T00:00:00Z", "2024-06-01T00:00:00Z"],
+        {"range": {"gt": "2024-03-01T00:00:00Z"}},
+    ),
+    (
+        {"type": "uuid", "on_disk": True},
+        ["d0b8e5b4-7d3c-4f4a-9a47-2a6f1c3e9b10", "5f2a9c7e-1b4d-4e8a-b6c3-9d0e7f1a2b34"],
+        {"match": {"value": "5f2a9c7e-1b4d-4e8a-b6c3-9d0e7f1a2b34"}},
+    ),
+    (
+        {"type": "geo", "on_disk": True},
+        [{"lon": 13.4, "lat": 52.5}, {"lon": -74.0, "lat": 40.7}],
+        {"geo_radius": {"center": {"lon": -74.0, "lat": 40.7}, "radius": 1000.0}},
+    ),
+    (
+        {"type": "text", "on_disk": True},
+        ["hello world", "good bye"],
+        {"match": {"text": "bye"}},
+    ),
+    (
+        {"type": "bool", "on_disk": True},
+        [False, True],
+        {"match": {"value": True}},
+    ),
+])
+def test_on_disk_payload_index(on_disk_vectors, field_schema, values, condition):
+    create_collection(on_disk_vectors, field_schema, values)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    payload_schema = response.json()['result']['payload_schema']
+    assert payload_schema['field']['data_type'] == field_schema["type"]
+    assert payload_schema['field']['params']['on_disk'] is True
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {"key": "field", **condition}
+                ]
+            }
+        },
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']['points']] == [1, 3, 5]
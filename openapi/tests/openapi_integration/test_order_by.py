@@ -0,0 +1,135 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_order_by'
+
+PRICES = [5.0, 1.5, 3.0, 3.0, 8.0, 1.5, 3.0, 0.5, 9.0, 3.0]
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "price",
+            "field_schema": "float"
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": idx,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"price": price}
+                }
+                for idx, price in enumerate(PRICES)
+            ]
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def scroll_ordered(direction, limit):
+    points = []
+    start_from = None
+    offset = None
+    while True:
+        order_by = {"key": "price", "direction": direction}
+        if start_from is not None:
+            order_by["start_from"] = start_from
+
+        body = {"limit": limit, "order_by": order_by}
+        if offset is not None:
+            body["offset"] = offset
+
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body=body,
+        )
+        assert response.ok
+        result = response.json()['result']
+        points.extend(result['points'])
+
+        offset = result['next_page_offset']
+        if offset is None:
+            assert 'next_page_start_from' not in result
+            return points
+        start_from = result['next_page_start_from']
+
+
+@pytest.mark.parametrize("direction", ["asc", "desc"])
+def test_order_by_pagination(direction):
+    expected = sorted(
+        enumerate(PRICES),
+        key=lambda item: (item[1] if direction == "asc" else -item[1], item[0]),
+    )
+
+    points = scroll_ordered(direction, limit=3)
+
+    assert [(point['id'], point['order_value']) for point in points] == expected
+
+
+def test_order_by_key_shorthand():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"limit": 3, "order_by": "price"},
+    )
+    assert response.ok
+    result = response.json()['result']
+    assert [point['id'] for point in result['points']] == [7, 1, 5]
+    assert result['next_page_offset'] == 2
+    assert result['next_page_start_from'] == 3.0
+
+
+def test_order_by_without_index():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"order_by": "not_indexed"},
+    )
+    assert response.status_code == 400
+
+
+def test_order_by_offset_without_start_from():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"order_by": "price", "offset": 1},
+    )
+    assert response.status_code == 400
@@ -0,0 +1,145 @@
+import math
+
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_query'
+
+NUM_POINTS = 20
+
+
+def point_vector(point_id):
+    angle = point_id / NUM_POINTS * math.pi / 2
+    return [math.cos(angle), math.sin(angle), 0.0, 0.0]
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup():
+    query_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def query_collection_setup(collection_name):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {"size": 4, "distance": "Dot"},
+            "shard_number": 2,
+        }
+    )
+    assert response.ok
+
+    points = [
+        {
+            "id": point_id,
+            "vector": point_vector(point_id),
+            "payload": {"parity": point_id % 2},
+        }
+        for point_id in range(NUM_POINTS)
+    ]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points}
+    )
+    assert response.ok
+
+
+def query(body):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def ids(points):
+    return [point['id'] for point in points]
+
+
+def test_query_nearest_vector():
+    result = query({"query": point_vector(5), "limit": 3, "with_payload": True})
+    assert len(result) == 3
+    assert result[0]['id'] == 5
+    assert all('payload' in point and point['payload'] is not None for point in result)
+
+
+def test_query_nearest_point_id():
+    result = query({"query": {"nearest": 5}, "limit": 2})
+    assert len(result) == 2
+    assert 5 not in ids(result)
+    assert set(ids(result)) == {4, 6}
+
+
+def test_query_recommend():
+    result = query({
+        "query": {"recommend": {"positive": [NUM_POINTS - 1], "negative": [0], "strategy": "best_score"}},
+        "limit": 3,
+    })
+    assert len(result) == 3
+    assert all(point_id >= NUM_POINTS - 4 for point_id in ids(result))
+
+
+def test_query_prefetch_rescore():
+    result = query({
+        "prefetch": [{
+            "query": point_vector(0),
+            "filter": {"must": [{"key": "parity", "match": {"value": 0}}]},
+            "limit": 4,
+        }],
+        "query": point_vector(NUM_POINTS - 1),
+    })
+    assert ids(result) == [6, 4, 2, 0]
+
+
+def test_query_fusion():
+    result = query({
+        "prefetch": [
+            {"query": point_vector(0), "limit": 2},
+            {"query": point_vector(NUM_POINTS - 1), "limit": 2},
+        ],
+        "query": {"fusion": "rrf"},
+    })
+    assert set(ids(result)) == {0, 1, NUM_POINTS - 1, NUM_POINTS - 2}
+
+
+def test_query_fusion_without_prefetch():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"query": {"fusion": "rrf"}},
+    )
+    assert response.status_code == 400
+
+
+def test_query_batch():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query/batch',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "searches": [
+                {"query": point_vector(3), "limit": 1},
+                {"query": {"nearest": 3}, "limit": 1, "filter": {"must": [{"key": "parity", "match": {"value": 0}}]}},
+            ]
+        },
+    )
+    assert response.ok, response.text
+    result = response.json()['result']
+    assert len(result) == 2
+    assert ids(result[0]) == [3]
+    assert ids(result[1])[0] in (2, 4)
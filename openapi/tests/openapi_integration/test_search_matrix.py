@@ -0,0 +1,88 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_search_matrix'
+
+NUM_POINTS = 20
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup():
+    search_matrix_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def search_matrix_collection_setup(collection_name):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {"size": 4, "distance": "Dot"},
+            "shard_number": 2,
+        }
+    )
+    assert response.ok
+
+    points = [
+        {
+            "id": point_id,
+            "vector": [point_id / NUM_POINTS, 1.0, 0.5, 0.0],
+            "payload": {"parity": point_id % 2},
+        }
+        for point_id in range(NUM_POINTS)
+    ]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points}
+    )
+    assert response.ok
+
+
+def search_matrix(kind, body):
+    response = request_with_validation(
+        api=f'/collections/{{collection_name}}/points/search/matrix/{kind}',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()['result']
+
+
+def test_search_matrix_pairs():
+    result = search_matrix("pairs", {
+        "sample": 6,
+        "limit": 2,
+        "filter": {"must": [{"key": "parity", "match": {"value": 0}}]},
+    })
+
+    pairs = result['pairs']
+    assert len(pairs) == 6 * 2
+    for pair in pairs:
+        assert pair['a'] % 2 == 0
+        assert pair['b'] % 2 == 0
+        assert pair['a'] != pair['b']
+
+
+def test_search_matrix_offsets():
+    result = search_matrix("offsets", {"sample": 5, "limit": 3})
+
+    assert len(result['ids']) == 5
+    assert len(set(result['ids'])) == 5
+    assert len(result['offsets_row']) == 5 * 3
+    assert len(result['offsets_col']) == 5 * 3
+    assert len(result['scores']) == 5 * 3
+    for row, col in zip(result['offsets_row'], result['offsets_col']):
+        assert row != col
+        assert 0 <= col < 5
+
@@ -0,0 +1,107 @@
+import math
+
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_sparse_idf'
+
+NUM_POINTS = 20
+
+
+def idf(document_frequency, vector_count=NUM_POINTS):
+    return math.log1p((vector_count - document_frequency + 0.5) / (document_frequency + 0.5))
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup():
+    idf_collection_setup(collection_name=collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def idf_collection_setup(collection_name):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "sparse_vectors": {
+                "text": {},
+                "text-idf": {
+                    "modifier": "idf",
+                },
+            },
+            "shard_number": 2,
+        }
+    )
+    assert response.ok
+
+    # Dimension 1 is in every point, dimension 2 only in every fifth
+    points = []
+    for point_id in range(NUM_POINTS):
+        if point_id % 5 == 0:
+            vector = {"indices": [1, 2], "values": [1.0, 1.0]}
+        else:
+            vector = {"indices": [1, 3], "values": [1.0, 1.0]}
+        points.append({"id": point_id, "vector": {"text": vector, "text-idf": vector}})
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={"points": points}
+    )
+    assert response.ok
+
+
+def search(vector_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {
+                "name": vector_name,
+                "vector": {"indices": [1, 2], "values": [1.0, 1.0]},
+            },
+            "limit": NUM_POINTS,
+        }
+    )
+    assert response.ok
+    return response.json()['result']
+
+
+def test_sparse_idf_config():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    sparse_vectors = response.json()['result']['config']['params']['sparse_vectors']
+    assert sparse_vectors['text-idf']['modifier'] == "idf"
+    assert 'modifier' not in sparse_vectors['text']
+
+
+def test_sparse_idf_search():
+    result = search("text-idf")
+    assert len(result) == NUM_POINTS
+    for point in result:
+        if point['id'] % 5 == 0:
+            expected = idf(NUM_POINTS) + idf(NUM_POINTS // 5)
+        else:
+            expected = idf(NUM_POINTS)
+        assert point['score'] == pytest.approx(expected, rel=1e-5)
+
+
+def test_sparse_without_modifier_search():
+    result = search("text")
+    assert len(result) == NUM_POINTS
+    for point in result:
+        expected = 2.0 if point['id'] % 5 == 0 else 1.0
+        assert point['score'] == pytest.approx(expected)
@@ -0,0 +1,95 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_tenant_index'
+
+def create_collection(on_disk_vectors, field_schema, tenants):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "tenant",
+            "field_schema": field_schema
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": idx,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"tenant": tenants[idx % len(tenants)]}
+                }
+                for idx in range(12)
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+@pytest.mark.parametrize("field_schema, tenants", [
+    ({"type": "keyword", "is_tenant": True}, ["alice", "bob", "carol"]),
+    ({"type": "integer", "is_tenant": True}, [10, 20, 30]),
+])
+def test_tenant_index(on_disk_vectors, field_schema, tenants):
+    create_collection(on_disk_vectors, field_schema, tenants)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    payload_schema = response.json()['result']['payload_schema']
+    assert payload_schema['tenant']['data_type'] == field_schema["type"]
+    assert payload_schema['tenant']['params'] == field_schema
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {
+                        "key": "tenant",
+                        "match": {"value": tenants[1]}
+                    }
+                ]
+            }
+        },
+    )
+    assert response.ok
+    assert [point['id'] for point in response.json()['result']['points']] == [1, 4, 7, 10]
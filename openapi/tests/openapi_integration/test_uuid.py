@@ -0,0 +1,117 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_uuid'
+
+TENANT_A = "1ab4ba4c-7c2e-4e3c-9d2a-59a6b4d8c1f0"
+TENANT_B = "f3d1c7e2-0a4b-4c8d-8e6f-2b9a7c5d3e10"
+TENANT_C = "00000000-0000-0000-0000-000000000001"
+
+TENANTS = [
+    TENANT_A,
+    TENANT_B,
+    [TENANT_A, TENANT_C],
+    [TENANT_B, TENANT_C],
+    TENANT_C,
+]
+
+
+def create_collection(on_disk_vectors, with_index):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Dot",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+
+    if with_index:
+        response = request_with_validation(
+            api='/collections/{collection_name}/index',
+            method="PUT",
+            path_params={'collection_name': collection_name},
+            query_params={'wait': 'true'},
+            body={
+                "field_name": "tenant",
+                "field_schema": "uuid"
+            }
+        )
+        assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": idx,
+                    "vector": [0.05, 0.61, 0.76, 0.74],
+                    "payload": {"tenant": tenant}
+                }
+                for idx, tenant in enumerate(TENANTS)
+            ]
+        }
+    )
+    assert response.ok
+
+
+def scroll_ids(condition):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {
+                        "key": "tenant",
+                        "match": condition
+                    }
+                ]
+            }
+        },
+    )
+    assert response.ok
+    return [point['id'] for point in response.json()['result']['points']]
+
+
+@pytest.fixture(autouse=True)
+def setup():
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+@pytest.mark.parametrize("with_index", [True, False])
+def test_uuid_match(on_disk_vectors, with_index):
+    create_collection(on_disk_vectors, with_index)
+
+    assert scroll_ids({"value": TENANT_A}) == [0, 2]
+    assert scroll_ids({"any": [TENANT_B, TENANT_C]}) == [1, 2, 3, 4]
+    assert scroll_ids({"except": [TENANT_A, TENANT_C]}) == [1, 3]
+    assert scroll_ids({"value": "not a uuid"}) == []
+
+
+def test_uuid_index_schema(on_disk_vectors):
+    create_collection(on_disk_vectors, with_index=True)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    payload_schema = response.json()['result']['payload_schema']
+    assert payload_schema['tenant']['data_type'] == "uuid"
+    assert payload_schema['tenant']['points'] == len(TENANTS)
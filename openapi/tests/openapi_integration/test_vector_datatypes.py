@@ -0,0 +1,132 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+collection_name = 'test_collection_vector_datatypes'
+
+POINTS = {
+    1: [0.0, 1.0, 2.0, 3.0],
+    2: [10.0, 20.0, 30.0, 40.0],
+    3: [255.0, 0.0, 127.0, 1.0],
+}
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors):
+    datatype_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def datatype_collection_setup(collection_name, on_disk_vectors):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "byte": {
+                    "size": 4,
+                    "distance": "Dot",
+                    "on_disk": on_disk_vectors,
+                    "datatype": "uint8",
+                },
+                "half": {
+                    "size": 4,
+                    "distance": "Dot",
+                    "on_disk": on_disk_vectors,
+                    "datatype": "float16",
+                },
+            },
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": point_id, "vector": {"byte": vector, "half": vector}}
+                for point_id, vector in POINTS.items()
+            ]
+        }
+    )
+    assert response.ok
+
+
+def test_datatype_config():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="GET",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+    params = response.json()['result']['config']['params']['vectors']
+    assert params['byte']['datatype'] == "uint8"
+    assert params['half']['datatype'] == "float16"
+
+
+def test_datatype_retrieve():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 3},
+    )
+    assert response.ok
+    vectors = response.json()['result']['vector']
+    # All values are exactly representable with both datatypes
+    assert vectors['byte'] == POINTS[3]
+    assert vectors['half'] == POINTS[3]
+
+
+@pytest.mark.parametrize("vector_name", ["byte", "half"])
+def test_datatype_search(vector_name):
+    query = [1.0, 2.0, 3.0, 4.0]
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": {"name": vector_name, "vector": query},
+            "limit": 3,
+        }
+    )
+    assert response.ok
+    result = response.json()['result']
+
+    expected = sorted(
+        ((point_id, sum(q * v for q, v in zip(query, vector))) for point_id, vector in POINTS.items()),
+        key=lambda x: -x[1],
+    )
+    assert [point['id'] for point in result] == [point_id for point_id, _ in expected]
+    for point, (_, score) in zip(result, expected):
+        assert point['score'] == pytest.approx(score)
+
+
+def test_datatype_uint8_truncates_values():
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": 100, "vector": {"byte": [-1.0, 1.7, 254.9, 300.0], "half": [0.0, 0.0, 0.0, 0.0]}}
+            ]
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 100},
+    )
+    assert response.ok
+    assert response.json()['result']['vector']['byte'] == [0.0, 1.0, 254.0, 255.0]
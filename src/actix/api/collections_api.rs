@@ -16,6 +16,7 @@ use validator::Validate;
 use super::CollectionPath;
 use crate::actix::api::StrictCollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::auth::Access;
 use crate::common::collections::*;
 
 #[derive(Debug, Deserialize, Validate)]
@@ -31,9 +32,9 @@ impl WaitTimeout {
 }
 
 #[get("/collections")]
-async fn get_collections(toc: web::Data<TableOfContent>) -> impl Responder {
+async fn get_collections(toc: web::Data<TableOfContent>, access: Access) -> impl Responder {
     let timing = Instant::now();
-    let response = Ok(do_list_collections(toc.get_ref()).await);
+    let response = Ok(do_list_collections(toc.get_ref(), &access).await);
     process_response(response, timing)
 }
 
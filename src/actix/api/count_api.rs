@@ -8,6 +8,7 @@ use storage::content_manager::toc::TableOfContent;
 use super::CollectionPath;
 use crate::actix::api::read_params::ReadParams;
 use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
 use crate::common::points::do_count_points;
 
 #[post("/collections/{name}/points/count")]
@@ -16,13 +17,19 @@ async fn count_points(
     collection: Path<CollectionPath>,
     request: Json<CountRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let CountRequest {
         count_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selector = match shard_key {
         None => ShardSelectorInternal::All,
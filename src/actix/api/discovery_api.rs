@@ -8,6 +8,7 @@ use tokio::time::Instant;
 use crate::actix::api::read_params::ReadParams;
 use crate::actix::api::CollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
 use crate::common::points::do_discover_batch_points;
 
 #[post("/collections/{name}/points/discover")]
@@ -16,13 +17,19 @@ async fn discover_points(
     collection: Path<CollectionPath>,
     request: Json<DiscoverRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let DiscoverRequest {
         discover_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
@@ -48,13 +55,19 @@ async fn discover_batch_points(
     collection: Path<CollectionPath>,
     request: Json<DiscoverRequestBatch>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let response = do_discover_batch_points(
         toc.get_ref(),
         &collection.name,
-        request.into_inner(),
+        request,
         params.consistency,
         params.timeout(),
     )
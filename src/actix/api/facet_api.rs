@@ -0,0 +1,49 @@
+use actix_web::rt::time::Instant;
+use actix_web::{post, web, Responder};
+use actix_web_validator::{Json, Path, Query};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::FacetRequest;
+use storage::content_manager::toc::TableOfContent;
+
+use super::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
+use crate::common::points::do_facet_points;
+
+#[post("/collections/{name}/facet")]
+async fn facet(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<FacetRequest>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let FacetRequest {
+        facet_request,
+        shard_key,
+    } = request;
+
+    let shard_selector = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
+    };
+
+    let response = do_facet_points(
+        toc.get_ref(),
+        &collection.name,
+        facet_request,
+        params.consistency,
+        shard_selector,
+    )
+    .await;
+
+    process_response(response, timing)
+}
@@ -2,6 +2,8 @@ pub mod cluster_api;
 pub mod collections_api;
 pub mod count_api;
 pub mod discovery_api;
+pub mod facet_api;
+pub mod query_api;
 pub mod read_params;
 pub mod recommend_api;
 pub mod retrieve_api;
@@ -0,0 +1,82 @@
+use actix_web::{post, web, Responder};
+use actix_web_validator::{Json, Path, Query};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{QueryRequest, QueryRequestBatch};
+use storage::content_manager::toc::TableOfContent;
+use tokio::time::Instant;
+
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::api::CollectionPath;
+use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
+use crate::common::points::{do_query_batch_points, do_query_points};
+
+#[post("/collections/{name}/points/query")]
+async fn query_points(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<QueryRequest>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let QueryRequest {
+        query_request,
+        shard_key,
+    } = request;
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = do_query_points(
+        toc.get_ref(),
+        &collection.name,
+        query_request,
+        params.consistency,
+        shard_selection,
+        params.timeout(),
+    )
+    .await;
+
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/query/batch")]
+async fn query_points_batch(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<QueryRequestBatch>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let response = do_query_batch_points(
+        toc.get_ref(),
+        &collection.name,
+        request,
+        params.consistency,
+        params.timeout(),
+    )
+    .await;
+
+    process_response(response, timing)
+}
+
+pub fn config_query_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(query_points);
+    cfg.service(query_points_batch);
+}
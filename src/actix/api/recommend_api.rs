@@ -15,6 +15,7 @@ use storage::content_manager::toc::TableOfContent;
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
 
 #[post("/collections/{name}/points/recommend")]
 async fn recommend_points(
@@ -22,13 +23,19 @@ async fn recommend_points(
     collection: Path<CollectionPath>,
     request: Json<RecommendRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let RecommendRequest {
         recommend_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
@@ -78,13 +85,19 @@ async fn recommend_batch_points(
     collection: Path<CollectionPath>,
     request: Json<RecommendRequestBatch>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let response = do_recommend_batch_points(
         toc.get_ref(),
         &collection.name,
-        request.into_inner(),
+        request,
         params.consistency,
         params.timeout(),
     )
@@ -99,13 +112,19 @@ async fn recommend_point_groups(
     collection: Path<CollectionPath>,
     request: Json<RecommendGroupsRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let RecommendGroupsRequest {
         recommend_group_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
@@ -13,6 +13,7 @@ use validator::Validate;
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
 use crate::common::points::do_get_points;
 
 #[derive(Deserialize, Validate)]
@@ -119,13 +120,19 @@ async fn scroll_points(
     collection: Path<CollectionPath>,
     request: Json<ScrollRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let ScrollRequest {
         scroll_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
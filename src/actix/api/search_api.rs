@@ -3,15 +3,18 @@ use actix_web::{post, web, Responder};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
+    CoreSearchRequest, HybridSearchRequest, SearchGroupsRequest, SearchMatrixOffsetsResponse,
+    SearchMatrixPairsResponse, SearchMatrixRequest, SearchRequest, SearchRequestBatch,
 };
 use storage::content_manager::toc::TableOfContent;
 
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::helpers::process_response;
+use crate::common::auth::{Access, Restrict};
 use crate::common::points::{
-    do_core_search_points, do_search_batch_points, do_search_point_groups,
+    do_core_search_points, do_search_batch_points, do_search_hybrid_points, do_search_point_groups,
+    do_search_points_matrix,
 };
 
 #[post("/collections/{name}/points/search")]
@@ -20,13 +23,19 @@ async fn search_points(
     collection: Path<CollectionPath>,
     request: Json<SearchRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let SearchRequest {
         search_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
@@ -52,10 +61,15 @@ async fn batch_search_points(
     collection: Path<CollectionPath>,
     request: Json<SearchRequestBatch>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
-    let request = request.into_inner();
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let requests = request
         .searches
         .into_iter()
@@ -92,13 +106,19 @@ async fn search_point_groups(
     collection: Path<CollectionPath>,
     request: Json<SearchGroupsRequest>,
     params: Query<ReadParams>,
+    access: Access,
 ) -> impl Responder {
     let timing = Instant::now();
 
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
     let SearchGroupsRequest {
         search_group_request,
         shard_key,
-    } = request.into_inner();
+    } = request;
 
     let shard_selection = match shard_key {
         None => ShardSelectorInternal::All,
@@ -118,9 +138,128 @@ async fn search_point_groups(
     process_response(response, timing)
 }
 
+#[post("/collections/{name}/points/search/hybrid")]
+async fn search_hybrid_points(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<HybridSearchRequest>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let HybridSearchRequest {
+        search_request,
+        shard_key,
+    } = request;
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = do_search_hybrid_points(
+        toc.get_ref(),
+        &collection.name,
+        search_request,
+        params.consistency,
+        shard_selection,
+        params.timeout(),
+    )
+    .await;
+
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/search/matrix/pairs")]
+async fn search_points_matrix_pairs(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<SearchMatrixRequest>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let SearchMatrixRequest {
+        search_matrix_request,
+        shard_key,
+    } = request;
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = do_search_points_matrix(
+        toc.get_ref(),
+        &collection.name,
+        search_matrix_request,
+        params.consistency,
+        shard_selection,
+        params.timeout(),
+    )
+    .await
+    .map(SearchMatrixPairsResponse::from);
+
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/search/matrix/offsets")]
+async fn search_points_matrix_offsets(
+    toc: web::Data<TableOfContent>,
+    collection: Path<CollectionPath>,
+    request: Json<SearchMatrixRequest>,
+    params: Query<ReadParams>,
+    access: Access,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let mut request = request.into_inner();
+    if let Err(err) = request.restrict(&access) {
+        return process_response::<()>(Err(err.into()), timing);
+    }
+
+    let SearchMatrixRequest {
+        search_matrix_request,
+        shard_key,
+    } = request;
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = do_search_points_matrix(
+        toc.get_ref(),
+        &collection.name,
+        search_matrix_request,
+        params.consistency,
+        shard_selection,
+        params.timeout(),
+    )
+    .await
+    .map(SearchMatrixOffsetsResponse::from);
+
+    process_response(response, timing)
+}
+
 // Configure services
 pub fn config_search_api(cfg: &mut web::ServiceConfig) {
     cfg.service(search_points)
         .service(batch_search_points)
-        .service(search_point_groups);
+        .service(search_point_groups)
+        .service(search_hybrid_points)
+        .service(search_points_matrix_pairs)
+        .service(search_points_matrix_offsets);
 }
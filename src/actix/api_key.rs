@@ -4,26 +4,55 @@ use actix_web::body::{BoxBody, EitherBody};
 use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::http::header::Header;
 use actix_web::http::Method;
-use actix_web::{Error, HttpResponse};
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest, HttpResponse};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
 use futures_util::future::LocalBoxFuture;
 
-use crate::common::auth::AuthKeys;
+use crate::common::auth::{Access, AuthError, AuthKeys};
 
-const READ_ONLY_POST_PATTERNS: [&str; 11] = [
+const READ_ONLY_POST_PATTERNS: [&str; 17] = [
     "/collections/{name}/points",
     "/collections/{name}/points/count",
     "/collections/{name}/points/search",
     "/collections/{name}/points/scroll",
     "/collections/{name}/points/search/groups",
     "/collections/{name}/points/search/batch",
+    "/collections/{name}/points/search/hybrid",
+    "/collections/{name}/points/search/matrix/pairs",
+    "/collections/{name}/points/search/matrix/offsets",
     "/collections/{name}/points/recommend",
     "/collections/{name}/points/recommend/groups",
     "/collections/{name}/points/recommend/batch",
     "/collections/{name}/points/discover",
     "/collections/{name}/points/discover/batch",
+    "/collections/{name}/points/query",
+    "/collections/{name}/points/query/batch",
+    "/collections/{name}/facet",
 ];
 
+/// Read requests which apply the payload restriction of the access to their filter
+const PAYLOAD_RESTRICTED_POST_PATTERNS: [&str; 16] = [
+    "/collections/{name}/points/count",
+    "/collections/{name}/points/search",
+    "/collections/{name}/points/scroll",
+    "/collections/{name}/points/search/groups",
+    "/collections/{name}/points/search/batch",
+    "/collections/{name}/points/search/hybrid",
+    "/collections/{name}/points/search/matrix/pairs",
+    "/collections/{name}/points/search/matrix/offsets",
+    "/collections/{name}/points/recommend",
+    "/collections/{name}/points/recommend/groups",
+    "/collections/{name}/points/recommend/batch",
+    "/collections/{name}/points/discover",
+    "/collections/{name}/points/discover/batch",
+    "/collections/{name}/points/query",
+    "/collections/{name}/points/query/batch",
+    "/collections/{name}/facet",
+];
+
+/// Path parameters holding the name of the collection a request is made to
+const COLLECTION_PATH_PARAMS: [&str; 3] = ["{name}", "{collection_name}", "{collection}"];
+
 pub struct ApiKey {
     auth_keys: Option<AuthKeys>,
     whitelist: Vec<WhitelistItem>,
@@ -134,25 +163,80 @@ where
                     Authorization::<Bearer>::parse(&req).ok().map(|auth| auth.as_ref().token().into())
                 });
 
-        if let Some(key) = key {
-            let is_allowed = if let Some(ref auth_keys) = self.auth_keys {
-                auth_keys.can_write(&key) || (is_read_only(&req) && auth_keys.can_read(&key))
-            } else {
+        let access = match (key, &self.auth_keys) {
+            (Some(key), Some(auth_keys)) => auth_keys
+                .validate(&key)
+                .and_then(|access| check_access(&access, &req).map(|()| access)),
+            (Some(_), None) => {
                 // This code path should not be reached
                 log::warn!("Auth for REST API is set up incorrectly. Denying access by default.");
-                false
-            };
-            if is_allowed {
-                return Box::pin(self.service.call(req));
+                Err(AuthError::Unauthorized("Invalid api-key".to_string()))
+            }
+            (None, _) => Err(AuthError::Unauthorized("Invalid api-key".to_string())),
+        };
+
+        match access {
+            Ok(access) => {
+                req.extensions_mut().insert(access);
+                Box::pin(self.service.call(req))
             }
+            Err(err) => Box::pin(async move {
+                Ok(req
+                    .into_response(HttpResponse::Forbidden().body(err.to_string()))
+                    .map_into_right_body())
+            }),
         }
+    }
+}
+
+/// Access granted to the request by the auth middleware
+///
+/// Full access is assumed if auth is disabled.
+impl FromRequest for Access {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        let access = req
+            .extensions()
+            .get::<Access>()
+            .cloned()
+            .unwrap_or_else(Access::full);
+        ready(Ok(access))
+    }
+}
 
-        Box::pin(async {
-            Ok(req
-                .into_response(HttpResponse::Forbidden().body("Invalid api-key"))
-                .map_into_right_body())
-        })
+/// Check the access allows the request, given its method and the collection in its path
+fn check_access(access: &Access, req: &ServiceRequest) -> Result<(), AuthError> {
+    access.check_level(is_read_only(req))?;
+
+    let pattern = req.match_pattern().unwrap_or_default();
+    let is_list_collections = req.method() == Method::GET && pattern == "/collections";
+
+    match collection_name(req, &pattern) {
+        Some(collection_name) => access.check_collection(&collection_name)?,
+        // The list of collections is filtered by the access instead
+        None if is_list_collections => {}
+        None => access.check_global()?,
+    }
+
+    let applies_payload_restriction = is_list_collections
+        || (req.method() == Method::POST
+            && PAYLOAD_RESTRICTED_POST_PATTERNS.contains(&pattern.as_str()));
+    if !applies_payload_restriction {
+        access.check_payload_unrestricted()?;
     }
+
+    Ok(())
+}
+
+/// Name of the collection the request is made to, matched from its path by the route pattern
+fn collection_name(req: &ServiceRequest, pattern: &str) -> Option<String> {
+    pattern
+        .split('/')
+        .zip(req.match_info().as_str().split('/'))
+        .find(|(param, _)| COLLECTION_PATH_PARAMS.contains(param))
+        .map(|(_, collection_name)| collection_name.to_string())
 }
 
 fn is_read_only(req: &ServiceRequest) -> bool {
@@ -23,6 +23,8 @@ use crate::actix::api::cluster_api::config_cluster_api;
 use crate::actix::api::collections_api::config_collections_api;
 use crate::actix::api::count_api::count_points;
 use crate::actix::api::discovery_api::config_discovery_api;
+use crate::actix::api::facet_api::facet;
+use crate::actix::api::query_api::config_query_api;
 use crate::actix::api::recommend_api::config_recommend_api;
 use crate::actix::api::retrieve_api::{get_point, get_points, scroll_points};
 use crate::actix::api::search_api::config_search_api;
@@ -147,11 +149,13 @@ pub fn init(
                 .configure(config_search_api)
                 .configure(config_recommend_api)
                 .configure(config_discovery_api)
+                .configure(config_query_api)
                 .configure(config_shards_api)
                 .service(get_point)
                 .service(get_points)
                 .service(scroll_points)
-                .service(count_points);
+                .service(count_points)
+                .service(facet);
 
             if web_ui_available {
                 app = app.service(
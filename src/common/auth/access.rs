@@ -0,0 +1,229 @@
+use api::grpc::qdrant as grpc;
+use segment::types::{Condition, Filter};
+
+use super::claims::{AccessLevel, Claims};
+use super::AuthError;
+
+/// Access granted to a request, either by an api-key or by the claims of a JWT token
+#[derive(Clone, Debug, PartialEq)]
+pub struct Access {
+    level: AccessLevel,
+    /// Collections which can be accessed, all if `None`
+    collections: Option<Vec<String>>,
+    /// Filter restricting the points which can be read
+    payload: Option<Filter>,
+}
+
+impl Access {
+    /// Unrestricted access, as granted by the read-write api-key
+    pub fn full() -> Self {
+        Self {
+            level: AccessLevel::Manage,
+            collections: None,
+            payload: None,
+        }
+    }
+
+    /// Read access to everything, as granted by the read-only api-key
+    pub fn read_only() -> Self {
+        Self {
+            level: AccessLevel::Read,
+            collections: None,
+            payload: None,
+        }
+    }
+
+    /// Check the access allows an operation, given whether it only reads the data
+    ///
+    /// Access with a payload restriction is always read-only, as the restriction can't be
+    /// applied to the updates.
+    pub fn check_level(&self, read_only: bool) -> Result<(), AuthError> {
+        if read_only {
+            return Ok(());
+        }
+        if self.level != AccessLevel::Manage {
+            return Err(AuthError::Forbidden(
+                "Write access is required for this operation".to_string(),
+            ));
+        }
+        if self.payload.is_some() {
+            return Err(AuthError::Forbidden(
+                "Access with a payload restriction only allows reading".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_collection_allowed(&self, collection_name: &str) -> bool {
+        self.collections.as_ref().map_or(true, |collections| {
+            collections.iter().any(|name| name == collection_name)
+        })
+    }
+
+    pub fn check_collection(&self, collection_name: &str) -> Result<(), AuthError> {
+        if self.is_collection_allowed(collection_name) {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden(format!(
+                "Access to collection {collection_name} is not allowed",
+            )))
+        }
+    }
+
+    /// Check the access is not restricted in any way, as required by the operations which are
+    /// not bound to a single collection
+    pub fn check_global(&self) -> Result<(), AuthError> {
+        if self.collections.is_some() {
+            return Err(AuthError::Forbidden(
+                "Access to all collections is required for this operation".to_string(),
+            ));
+        }
+        self.check_payload_unrestricted()
+    }
+
+    /// Check the access allows a request to read from another collection than its own, in which
+    /// case the payload restriction can't be applied
+    pub fn check_lookup_collection(&self, collection_name: &str) -> Result<(), AuthError> {
+        self.check_collection(collection_name)?;
+        self.check_payload_unrestricted()
+    }
+
+    /// Check the access has no payload restriction, as required by the operations which can't
+    /// apply it
+    pub fn check_payload_unrestricted(&self) -> Result<(), AuthError> {
+        if self.payload.is_some() {
+            return Err(AuthError::Forbidden(
+                "This operation is not allowed with a payload restriction".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Add the payload restriction of the access, if any, to the filter of a read request
+    pub fn restrict_filter(&self, filter: &mut Option<Filter>) {
+        let Some(restriction) = &self.payload else {
+            return;
+        };
+
+        let restriction = Filter::new_must(Condition::Filter(restriction.clone()));
+        *filter = Some(match filter.take() {
+            Some(filter) => filter.merge(&restriction),
+            None => restriction,
+        });
+    }
+
+    /// Same as [`Access::restrict_filter`], for the filter of a gRPC request
+    pub fn restrict_grpc_filter(&self, filter: &mut Option<grpc::Filter>) {
+        let Some(restriction) = &self.payload else {
+            return;
+        };
+
+        let restriction = grpc::Condition {
+            condition_one_of: Some(grpc::condition::ConditionOneOf::Filter(
+                restriction.clone().into(),
+            )),
+        };
+        filter
+            .get_or_insert_with(Default::default)
+            .must
+            .push(restriction);
+    }
+}
+
+impl From<Claims> for Access {
+    fn from(claims: Claims) -> Self {
+        let Claims {
+            exp: _,
+            access,
+            collections,
+            payload,
+        } = claims;
+
+        Self {
+            level: access,
+            collections,
+            payload,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{FieldCondition, Match};
+
+    use super::*;
+
+    fn restricted_access(payload: Option<Filter>) -> Access {
+        Access::from(Claims {
+            exp: None,
+            access: AccessLevel::Manage,
+            collections: Some(vec!["allowed".to_string()]),
+            payload,
+        })
+    }
+
+    fn match_filter(key: &str, value: &str) -> Filter {
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key,
+            Match::from(value.to_string()),
+        )))
+    }
+
+    #[test]
+    fn test_check_level() {
+        assert!(Access::full().check_level(false).is_ok());
+        assert!(Access::read_only().check_level(true).is_ok());
+        assert!(Access::read_only().check_level(false).is_err());
+
+        let access = restricted_access(Some(match_filter("user", "alice")));
+        assert!(access.check_level(true).is_ok());
+        assert!(access.check_level(false).is_err());
+    }
+
+    #[test]
+    fn test_check_collection() {
+        assert!(Access::full().check_collection("any").is_ok());
+        assert!(Access::full().check_global().is_ok());
+
+        let access = restricted_access(None);
+        assert!(access.check_collection("allowed").is_ok());
+        assert!(access.check_collection("other").is_err());
+        assert!(access.check_global().is_err());
+    }
+
+    #[test]
+    fn test_restrict_filter() {
+        let mut filter = None;
+        Access::full().restrict_filter(&mut filter);
+        assert_eq!(filter, None);
+
+        let restriction = match_filter("user", "alice");
+        let access = restricted_access(Some(restriction.clone()));
+
+        let mut filter = None;
+        access.restrict_filter(&mut filter);
+        assert_eq!(
+            filter,
+            Some(Filter::new_must(Condition::Filter(restriction.clone()))),
+        );
+
+        // The restriction is added to the existing conditions, and can't be bypassed by `should`
+        let mut filter = Some(Filter {
+            should: Some(vec![Condition::Filter(match_filter("user", "bob"))]),
+            min_should: None,
+            must: None,
+            must_not: None,
+        });
+        access.restrict_filter(&mut filter);
+        let filter = filter.unwrap();
+        assert_eq!(filter.should.unwrap().len(), 1);
+        assert_eq!(
+            filter.must,
+            Some(vec![Condition::Filter(restriction.clone())])
+        );
+
+        let mut grpc_filter = None;
+        access.restrict_grpc_filter(&mut grpc_filter);
+        assert_eq!(grpc_filter.unwrap().must.len(), 1);
+    }
+}
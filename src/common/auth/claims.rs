@@ -0,0 +1,33 @@
+use segment::types::Filter;
+use serde::{Deserialize, Serialize};
+
+/// Claims of a JWT token, restricting the access it grants
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Claims {
+    /// Expiration time of the token, as a unix timestamp in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u64>,
+
+    /// Operations allowed with the token
+    #[serde(default)]
+    pub access: AccessLevel,
+
+    /// Collections the token can access, all of them if not specified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collections: Option<Vec<String>>,
+
+    /// Filter applied to every read made with the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Filter>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AccessLevel {
+    /// Only read the data
+    #[default]
+    #[serde(rename = "r")]
+    Read,
+    /// Read and manage the data
+    #[serde(rename = "m")]
+    Manage,
+}
@@ -0,0 +1,91 @@
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+
+use super::claims::Claims;
+use super::AuthError;
+
+/// Decoder of JWT tokens signed with the api-key
+#[derive(Clone)]
+pub struct JwtParser {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtParser {
+    pub fn new(secret: &str) -> Self {
+        let key = DecodingKey::from_secret(secret.as_bytes());
+
+        // Tokens may omit the expiration time, it is still checked when present
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.required_spec_claims.clear();
+
+        Self { key, validation }
+    }
+
+    /// Decode the claims of a token, checking its signature and expiration
+    pub fn decode(&self, token: &str) -> Result<Claims, AuthError> {
+        jsonwebtoken::decode::<Claims>(token, &self.key, &self.validation)
+            .map(|data| data.claims)
+            .map_err(|err| AuthError::Unauthorized(format!("Invalid JWT token: {err}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{EncodingKey, Header};
+
+    use super::*;
+    use crate::common::auth::claims::AccessLevel;
+
+    const SECRET: &str = "secret";
+
+    fn encode(claims: &Claims, secret: &str) -> String {
+        jsonwebtoken::encode(
+            &Header::default(),
+            claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decode_claims() {
+        let parser = JwtParser::new(SECRET);
+
+        let claims = Claims {
+            exp: None,
+            access: AccessLevel::Manage,
+            collections: Some(vec!["my_collection".to_string()]),
+            payload: None,
+        };
+        let decoded = parser.decode(&encode(&claims, SECRET)).unwrap();
+        assert_eq!(decoded, claims);
+
+        // Signed with another key
+        assert!(parser.decode(&encode(&claims, "other secret")).is_err());
+
+        // Expired
+        let expired = Claims {
+            exp: Some(1),
+            ..claims
+        };
+        assert!(parser.decode(&encode(&expired, SECRET)).is_err());
+
+        // Not a token
+        assert!(parser.decode(SECRET).is_err());
+    }
+
+    #[test]
+    fn test_default_access_is_read() {
+        let parser = JwtParser::new(SECRET);
+
+        let token = jsonwebtoken::encode(
+            &Header::default(),
+            &serde_json::json!({ "collections": ["my_collection"] }),
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        let decoded = parser.decode(&token).unwrap();
+        assert_eq!(decoded.access, AccessLevel::Read);
+    }
+}
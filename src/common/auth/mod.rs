@@ -0,0 +1,108 @@
+mod access;
+mod claims;
+mod jwt_parser;
+mod restrict;
+
+pub use access::Access;
+pub use restrict::Restrict;
+use storage::content_manager::errors::StorageError;
+
+use self::jwt_parser::JwtParser;
+use super::strings::ct_eq;
+use crate::settings::ServiceConfig;
+
+/// The API keys used for auth
+#[derive(Clone)]
+pub struct AuthKeys {
+    /// A key allowing Read or Write operations
+    read_write: Option<String>,
+
+    /// A key allowing Read operations
+    read_only: Option<String>,
+
+    /// Parser of the JWT tokens signed with the read-write key, if enabled
+    jwt_parser: Option<JwtParser>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("{0}")]
+    Forbidden(String),
+}
+
+impl AuthKeys {
+    /// Defines the auth scheme given the service config
+    ///
+    /// Returns None if no scheme is specified.
+    pub fn try_create(service_config: &ServiceConfig) -> Option<Self> {
+        let jwt_parser = if service_config.jwt_rbac.unwrap_or_default() {
+            if service_config.api_key.is_none() {
+                log::warn!("JWT RBAC requires an api-key to verify the tokens, ignoring it");
+            }
+            service_config.api_key.as_deref().map(JwtParser::new)
+        } else {
+            None
+        };
+
+        match (
+            service_config.api_key.clone(),
+            service_config.read_only_api_key.clone(),
+        ) {
+            (None, None) => None,
+            (read_write, read_only) => Some(Self {
+                read_write,
+                read_only,
+                jwt_parser,
+            }),
+        }
+    }
+
+    /// Resolve the access granted by a key
+    ///
+    /// The key is either one of the api-keys, or a JWT token signed with the read-write key if
+    /// JWT RBAC is enabled.
+    pub fn validate(&self, key: &str) -> Result<Access, AuthError> {
+        if self.can_write(key) {
+            return Ok(Access::full());
+        }
+        if self.can_read(key) {
+            return Ok(Access::read_only());
+        }
+        if let Some(jwt_parser) = &self.jwt_parser {
+            return jwt_parser.decode(key).map(Access::from);
+        }
+        Err(AuthError::Unauthorized("Invalid api-key".to_string()))
+    }
+
+    /// Check if a key is allowed to read
+    #[inline]
+    fn can_read(&self, key: &str) -> bool {
+        self.read_only
+            .as_ref()
+            .map(|ro_key| ct_eq(ro_key, key))
+            .unwrap_or_else(|| self.can_write(key))
+    }
+
+    /// Check if a key is allowed to write
+    #[inline]
+    fn can_write(&self, key: &str) -> bool {
+        self.read_write
+            .as_ref()
+            .map(|rw_key| ct_eq(rw_key, key))
+            .unwrap_or_default()
+    }
+}
+
+impl From<AuthError> for StorageError {
+    fn from(err: AuthError) -> Self {
+        StorageError::forbidden(err.to_string())
+    }
+}
+
+impl From<AuthError> for tonic::Status {
+    fn from(err: AuthError) -> Self {
+        tonic::Status::permission_denied(err.to_string())
+    }
+}
@@ -0,0 +1,270 @@
+use api::grpc::qdrant as grpc;
+use collection::operations::types::{
+    CountRequest, DiscoverRequest, DiscoverRequestBatch, FacetRequest, HybridSearchRequest,
+    QueryRequest, QueryRequestBatch, RecommendGroupsRequest, RecommendRequest,
+    RecommendRequestBatch, ScrollRequest, SearchGroupsRequest, SearchMatrixRequest, SearchRequest,
+    SearchRequestBatch,
+};
+
+use super::{Access, AuthError};
+
+/// Read requests which can be restricted to the access granted to them
+///
+/// The access to the collection of the request itself is checked separately.
+pub trait Restrict {
+    /// Check the access to the other collections read by the request, and add the payload
+    /// restriction of the access to its filter
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError>;
+}
+
+impl<T: Restrict> Restrict for Vec<T> {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.iter_mut()
+            .try_for_each(|request| request.restrict(access))
+    }
+}
+
+impl Restrict for SearchRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_filter(&mut self.search_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for SearchRequestBatch {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.searches.restrict(access)
+    }
+}
+
+impl Restrict for SearchGroupsRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        let request = &mut self.search_group_request;
+        if let Some(with_lookup) = &request.group_request.with_lookup {
+            access.check_lookup_collection(with_lookup.collection_name())?;
+        }
+        access.restrict_filter(&mut request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for HybridSearchRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        // Filters of the prefetches are combined with this one
+        access.restrict_filter(&mut self.search_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for SearchMatrixRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_filter(&mut self.search_matrix_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for RecommendRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        let request = &mut self.recommend_request;
+        if let Some(lookup_from) = &request.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection)?;
+        }
+        access.restrict_filter(&mut request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for RecommendRequestBatch {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.searches.restrict(access)
+    }
+}
+
+impl Restrict for RecommendGroupsRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        let request = &mut self.recommend_group_request;
+        if let Some(lookup_from) = &request.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection)?;
+        }
+        if let Some(with_lookup) = &request.group_request.with_lookup {
+            access.check_lookup_collection(with_lookup.collection_name())?;
+        }
+        access.restrict_filter(&mut request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for DiscoverRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        let request = &mut self.discover_request;
+        if let Some(lookup_from) = &request.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection)?;
+        }
+        access.restrict_filter(&mut request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for DiscoverRequestBatch {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.searches.restrict(access)
+    }
+}
+
+impl Restrict for CountRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_filter(&mut self.count_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for ScrollRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_filter(&mut self.scroll_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for FacetRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_filter(&mut self.facet_request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for QueryRequest {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        let request = &mut self.query_request;
+        if let Some(lookup_from) = &request.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection)?;
+        }
+        // Filters of the prefetches are combined with this one
+        access.restrict_filter(&mut request.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for QueryRequestBatch {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.searches.restrict(access)
+    }
+}
+
+impl Restrict for grpc::SearchPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::SearchBatchPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.search_points.restrict(access)
+    }
+}
+
+impl Restrict for grpc::SearchPointGroups {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        if let Some(with_lookup) = &self.with_lookup {
+            access.check_lookup_collection(&with_lookup.collection)?;
+        }
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::SearchHybridPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::SearchMatrixPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::RecommendPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        if let Some(lookup_from) = &self.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection_name)?;
+        }
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::RecommendBatchPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.recommend_points.restrict(access)
+    }
+}
+
+impl Restrict for grpc::RecommendPointGroups {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        if let Some(lookup_from) = &self.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection_name)?;
+        }
+        if let Some(with_lookup) = &self.with_lookup {
+            access.check_lookup_collection(&with_lookup.collection)?;
+        }
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::DiscoverPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        if let Some(lookup_from) = &self.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection_name)?;
+        }
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::DiscoverBatchPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.discover_points.restrict(access)
+    }
+}
+
+impl Restrict for grpc::CountPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::ScrollPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::FacetCounts {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::QueryPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        if let Some(lookup_from) = &self.lookup_from {
+            access.check_lookup_collection(&lookup_from.collection_name)?;
+        }
+        access.restrict_grpc_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
+impl Restrict for grpc::QueryBatchPoints {
+    fn restrict(&mut self, access: &Access) -> Result<(), AuthError> {
+        self.query_points.restrict(access)
+    }
+}
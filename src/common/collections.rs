@@ -24,6 +24,8 @@ use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 
+use crate::common::auth::Access;
+
 pub async fn do_get_collection(
     toc: &TableOfContent,
     name: &str,
@@ -39,11 +41,13 @@ pub async fn do_get_collection(
     Ok(collection.info(&shard_selection).await?)
 }
 
-pub async fn do_list_collections(toc: &TableOfContent) -> CollectionsResponse {
+/// List the collections, only those allowed by the access
+pub async fn do_list_collections(toc: &TableOfContent, access: &Access) -> CollectionsResponse {
     let collections = toc
         .all_collections()
         .await
         .into_iter()
+        .filter(|name| access.is_collection_allowed(name))
         .map(|name| CollectionDescription { name })
         .collect_vec();
 
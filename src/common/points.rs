@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use collection::common::batching::batch_requests;
+use collection::distance_matrix::CollectionSearchMatrixResponse;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::payload_ops::{
     DeletePayload, DeletePayloadOp, PayloadOps, SetPayload, SetPayloadOp,
@@ -13,9 +14,10 @@ use collection::operations::shard_key_selector::ShardKeySelector;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
     CoreSearchRequest, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    DiscoverRequestBatch, DiscoverRequestInternal, GroupsResult, PointRequestInternal,
-    RecommendGroupsRequestInternal, Record, ScrollRequestInternal, ScrollResult,
-    SearchGroupsRequestInternal, UpdateResult,
+    DiscoverRequestBatch, DiscoverRequestInternal, FacetRequestInternal, FacetResponse,
+    GroupsResult, HybridSearchRequestInternal, PointRequestInternal, QueryRequestBatch,
+    QueryRequestInternal, RecommendGroupsRequestInternal, Record, ScrollRequestInternal,
+    ScrollResult, SearchGroupsRequestInternal, SearchMatrixRequestInternal, UpdateResult,
 };
 use collection::operations::vector_ops::{
     DeleteVectors, UpdateVectors, UpdateVectorsOp, VectorOperations,
@@ -801,6 +803,42 @@ pub async fn do_discover_points(
     .await
 }
 
+pub async fn do_search_hybrid_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: HybridSearchRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> Result<Vec<ScoredPoint>, StorageError> {
+    toc.search_hybrid(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+}
+
+pub async fn do_search_points_matrix(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: SearchMatrixRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> Result<CollectionSearchMatrixResponse, StorageError> {
+    toc.search_points_matrix(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+}
+
 pub async fn do_discover_batch_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -825,6 +863,48 @@ pub async fn do_discover_batch_points(
         .await
 }
 
+pub async fn do_query_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: QueryRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> Result<Vec<ScoredPoint>, StorageError> {
+    toc.query(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+}
+
+pub async fn do_query_batch_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: QueryRequestBatch,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
+    let requests = request
+        .searches
+        .into_iter()
+        .map(|req| {
+            let shard_selector = match req.shard_key {
+                None => ShardSelectorInternal::All,
+                Some(shard_key) => ShardSelectorInternal::from(shard_key),
+            };
+
+            (req.query_request, shard_selector)
+        })
+        .collect();
+
+    toc.query_batch(collection_name, requests, read_consistency, timeout)
+        .await
+}
+
 pub async fn do_count_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -836,6 +916,17 @@ pub async fn do_count_points(
         .await
 }
 
+pub async fn do_facet_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: FacetRequestInternal,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+) -> Result<FacetResponse, StorageError> {
+    toc.facet(collection_name, request, read_consistency, shard_selection)
+        .await
+}
+
 pub async fn do_get_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -1224,6 +1224,8 @@ mod tests {
                                 hnsw_config: None,
                                 quantization_config: None,
                                 on_disk: None,
+                                multivector_config: None,
+                                datatype: None,
                             }
                             .into(),
                             sparse_vectors: None,
@@ -1232,11 +1234,13 @@ mod tests {
                             optimizers_config: None,
                             shard_number: Some(2),
                             on_disk_payload: None,
+                            mmap_payload: None,
                             replication_factor: None,
                             write_consistency_factor: None,
                             init_from: None,
                             quantization_config: None,
                             sharding_method: None,
+                            strict_mode_config: None,
                         },
                     )),
                     None,
@@ -8,10 +8,11 @@ use collection::operations::snapshot_ops::{
 };
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
-    CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch, GroupsResult, PointGroup,
-    PointRequest, RecommendGroupsRequest, RecommendRequest, RecommendRequestBatch, Record,
-    ScrollRequest, ScrollResult, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
-    UpdateResult,
+    CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch, FacetRequest, FacetResponse,
+    GroupsResult, HybridSearchRequest, PointGroup, PointRequest, QueryRequest, QueryRequestBatch,
+    RecommendGroupsRequest, RecommendRequest, RecommendRequestBatch, Record, ScrollRequest,
+    ScrollResult, SearchGroupsRequest, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
+    SearchMatrixRequest, SearchRequest, SearchRequestBatch, UpdateResult,
 };
 use collection::operations::vector_ops::{DeleteVectors, UpdateVectors};
 use schemars::gen::SchemaSettings;
@@ -78,6 +79,14 @@ struct AllDefinitions {
     b9: ShardSnapshotRecover,
     ba: DiscoverRequest,
     bb: DiscoverRequestBatch,
+    bc: HybridSearchRequest,
+    bd: FacetRequest,
+    be: FacetResponse,
+    bf: SearchMatrixRequest,
+    bg: SearchMatrixPairsResponse,
+    bh: SearchMatrixOffsetsResponse,
+    bi: QueryRequest,
+    bj: QueryRequestBatch,
 }
 
 fn save_schema<T: JsonSchema>() {
@@ -20,6 +20,7 @@ use tonic::{Request, Response, Status};
 use super::validate;
 use crate::common::collections::*;
 use crate::tonic::api::collections_common::get;
+use crate::tonic::api_key::extract_access;
 
 pub struct CollectionsService {
     dispatcher: Arc<Dispatcher>,
@@ -106,19 +107,22 @@ impl CollectionsService {
 impl Collections for CollectionsService {
     async fn get(
         &self,
-        request: Request<GetCollectionInfoRequest>,
+        mut request: Request<GetCollectionInfoRequest>,
     ) -> Result<Response<GetCollectionInfoResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         get(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn list(
         &self,
-        request: Request<ListCollectionsRequest>,
+        mut request: Request<ListCollectionsRequest>,
     ) -> Result<Response<ListCollectionsResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
         let timing = Instant::now();
-        let result = do_list_collections(&self.dispatcher).await;
+        let result = do_list_collections(&self.dispatcher, &access).await;
 
         let response = ListCollectionsResponse::from((timing, result));
         Ok(Response::new(response))
@@ -126,25 +130,31 @@ impl Collections for CollectionsService {
 
     async fn create(
         &self,
-        request: Request<CreateCollection>,
+        mut request: Request<CreateCollection>,
     ) -> Result<Response<CollectionOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         self.perform_operation(request).await
     }
 
     async fn update(
         &self,
-        request: Request<UpdateCollection>,
+        mut request: Request<UpdateCollection>,
     ) -> Result<Response<CollectionOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         self.perform_operation(request).await
     }
 
     async fn delete(
         &self,
-        request: Request<DeleteCollection>,
+        mut request: Request<DeleteCollection>,
     ) -> Result<Response<CollectionOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         self.perform_operation(request).await
     }
 
@@ -158,9 +168,11 @@ impl Collections for CollectionsService {
 
     async fn list_collection_aliases(
         &self,
-        request: Request<ListCollectionAliasesRequest>,
+        mut request: Request<ListCollectionAliasesRequest>,
     ) -> Result<Response<ListAliasesResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         self.list_collection_aliases(request).await
     }
 
@@ -174,9 +186,11 @@ impl Collections for CollectionsService {
 
     async fn collection_cluster_info(
         &self,
-        request: Request<CollectionClusterInfoRequest>,
+        mut request: Request<CollectionClusterInfoRequest>,
     ) -> Result<Response<CollectionClusterInfoResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let response = do_get_collection_cluster(
             self.dispatcher.toc(),
             request.into_inner().collection_name.as_str(),
@@ -190,9 +204,11 @@ impl Collections for CollectionsService {
 
     async fn update_collection_cluster_setup(
         &self,
-        request: Request<UpdateCollectionClusterSetupRequest>,
+        mut request: Request<UpdateCollectionClusterSetupRequest>,
     ) -> Result<Response<UpdateCollectionClusterSetupResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let UpdateCollectionClusterSetupRequest {
             collection_name,
             operation,
@@ -216,8 +232,10 @@ impl Collections for CollectionsService {
 
     async fn create_shard_key(
         &self,
-        request: Request<CreateShardKeyRequest>,
+        mut request: Request<CreateShardKeyRequest>,
     ) -> Result<Response<CreateShardKeyResponse>, Status> {
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let CreateShardKeyRequest {
             collection_name,
             request,
@@ -248,8 +266,10 @@ impl Collections for CollectionsService {
 
     async fn delete_shard_key(
         &self,
-        request: Request<DeleteShardKeyRequest>,
+        mut request: Request<DeleteShardKeyRequest>,
     ) -> Result<Response<DeleteShardKeyResponse>, Status> {
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let DeleteShardKeyRequest {
             collection_name,
             request,
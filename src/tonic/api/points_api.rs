@@ -5,10 +5,12 @@ use api::grpc::qdrant::points_server::Points;
 use api::grpc::qdrant::{
     ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
     DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints,
-    DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, GetPoints,
-    GetResponse, PointsOperationResponse, RecommendBatchPoints, RecommendBatchResponse,
+    DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, FacetCounts,
+    FacetResponse, GetPoints, GetResponse, PointsOperationResponse, QueryBatchPoints,
+    QueryBatchResponse, QueryPoints, QueryResponse, RecommendBatchPoints, RecommendBatchResponse,
     RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
     ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
+    SearchHybridPoints, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixPoints,
     SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints,
     UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
 };
@@ -17,15 +19,17 @@ use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
 
 use super::points_common::{
-    delete_vectors, discover, discover_batch, recommend_groups, search_groups, update_batch,
-    update_vectors,
+    delete_vectors, discover, discover_batch, query, query_batch, recommend_groups, search_groups,
+    search_hybrid, search_matrix_offsets, search_matrix_pairs, update_batch, update_vectors,
 };
 use super::validate;
+use crate::common::auth::Restrict;
 use crate::tonic::api::points_common::{
     clear_payload, convert_shard_selector_for_read, core_search_batch, count, create_field_index,
-    delete, delete_field_index, delete_payload, get, overwrite_payload, recommend, recommend_batch,
-    scroll, search, set_payload, upsert,
+    delete, delete_field_index, delete_payload, facet, get, overwrite_payload, recommend,
+    recommend_batch, scroll, search, set_payload, upsert,
 };
+use crate::tonic::api_key::extract_access;
 
 pub struct PointsService {
     dispatcher: Arc<Dispatcher>,
@@ -41,110 +45,140 @@ impl PointsService {
 impl Points for PointsService {
     async fn upsert(
         &self,
-        request: Request<UpsertPoints>,
+        mut request: Request<UpsertPoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         upsert(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn delete(
         &self,
-        request: Request<DeletePoints>,
+        mut request: Request<DeletePoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         delete(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
-    async fn get(&self, request: Request<GetPoints>) -> Result<Response<GetResponse>, Status> {
+    async fn get(&self, mut request: Request<GetPoints>) -> Result<Response<GetResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         get(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn update_vectors(
         &self,
-        request: Request<UpdatePointVectors>,
+        mut request: Request<UpdatePointVectors>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         update_vectors(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn delete_vectors(
         &self,
-        request: Request<DeletePointVectors>,
+        mut request: Request<DeletePointVectors>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         delete_vectors(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn set_payload(
         &self,
-        request: Request<SetPayloadPoints>,
+        mut request: Request<SetPayloadPoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         set_payload(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn overwrite_payload(
         &self,
-        request: Request<SetPayloadPoints>,
+        mut request: Request<SetPayloadPoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         overwrite_payload(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn delete_payload(
         &self,
-        request: Request<DeletePayloadPoints>,
+        mut request: Request<DeletePayloadPoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         delete_payload(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn clear_payload(
         &self,
-        request: Request<ClearPayloadPoints>,
+        mut request: Request<ClearPayloadPoints>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         clear_payload(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn update_batch(
         &self,
-        request: Request<UpdateBatchPoints>,
+        mut request: Request<UpdateBatchPoints>,
     ) -> Result<Response<UpdateBatchResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         update_batch(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn create_field_index(
         &self,
-        request: Request<CreateFieldIndexCollection>,
+        mut request: Request<CreateFieldIndexCollection>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         create_field_index(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn delete_field_index(
         &self,
-        request: Request<DeleteFieldIndexCollection>,
+        mut request: Request<DeleteFieldIndexCollection>,
     ) -> Result<Response<PointsOperationResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         delete_field_index(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn search(
         &self,
-        request: Request<SearchPoints>,
+        mut request: Request<SearchPoints>,
     ) -> Result<Response<SearchResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         search(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn search_batch(
         &self,
-        request: Request<SearchBatchPoints>,
+        mut request: Request<SearchBatchPoints>,
     ) -> Result<Response<SearchBatchResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         let SearchBatchPoints {
             collection_name,
             search_points,
@@ -177,33 +211,56 @@ impl Points for PointsService {
 
     async fn search_groups(
         &self,
-        request: Request<SearchPointGroups>,
+        mut request: Request<SearchPointGroups>,
     ) -> Result<Response<SearchGroupsResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         search_groups(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
+    async fn search_hybrid(
+        &self,
+        mut request: Request<SearchHybridPoints>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        search_hybrid(self.dispatcher.as_ref(), request.into_inner()).await
+    }
+
     async fn scroll(
         &self,
-        request: Request<ScrollPoints>,
+        mut request: Request<ScrollPoints>,
     ) -> Result<Response<ScrollResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         scroll(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
 
     async fn recommend(
         &self,
-        request: Request<RecommendPoints>,
+        mut request: Request<RecommendPoints>,
     ) -> Result<Response<RecommendResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         recommend(self.dispatcher.as_ref(), request.into_inner()).await
     }
 
     async fn recommend_batch(
         &self,
-        request: Request<RecommendBatchPoints>,
+        mut request: Request<RecommendBatchPoints>,
     ) -> Result<Response<RecommendBatchResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         let RecommendBatchPoints {
             collection_name,
             recommend_points,
@@ -222,25 +279,34 @@ impl Points for PointsService {
 
     async fn recommend_groups(
         &self,
-        request: Request<RecommendPointGroups>,
+        mut request: Request<RecommendPointGroups>,
     ) -> Result<Response<RecommendGroupsResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         recommend_groups(self.dispatcher.as_ref(), request.into_inner()).await
     }
 
     async fn discover(
         &self,
-        request: Request<DiscoverPoints>,
+        mut request: Request<DiscoverPoints>,
     ) -> Result<Response<DiscoverResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         discover(self.dispatcher.as_ref(), request.into_inner()).await
     }
 
     async fn discover_batch(
         &self,
-        request: Request<DiscoverBatchPoints>,
+        mut request: Request<DiscoverBatchPoints>,
     ) -> Result<Response<DiscoverBatchResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         let DiscoverBatchPoints {
             collection_name,
             discover_points,
@@ -259,9 +325,80 @@ impl Points for PointsService {
 
     async fn count(
         &self,
-        request: Request<CountPoints>,
+        mut request: Request<CountPoints>,
     ) -> Result<Response<CountResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
         count(self.dispatcher.as_ref(), request.into_inner(), None).await
     }
+
+    async fn facet(
+        &self,
+        mut request: Request<FacetCounts>,
+    ) -> Result<Response<FacetResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        facet(self.dispatcher.as_ref(), request.into_inner(), None).await
+    }
+
+    async fn search_matrix_pairs(
+        &self,
+        mut request: Request<SearchMatrixPoints>,
+    ) -> Result<Response<SearchMatrixPairsResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        search_matrix_pairs(self.dispatcher.as_ref(), request.into_inner()).await
+    }
+
+    async fn search_matrix_offsets(
+        &self,
+        mut request: Request<SearchMatrixPoints>,
+    ) -> Result<Response<SearchMatrixOffsetsResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        search_matrix_offsets(self.dispatcher.as_ref(), request.into_inner()).await
+    }
+
+    async fn query(
+        &self,
+        mut request: Request<QueryPoints>,
+    ) -> Result<Response<QueryResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        query(self.dispatcher.as_ref(), request.into_inner()).await
+    }
+
+    async fn query_batch(
+        &self,
+        mut request: Request<QueryBatchPoints>,
+    ) -> Result<Response<QueryBatchResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
+        request.get_mut().restrict(&access)?;
+        let QueryBatchPoints {
+            collection_name,
+            query_points,
+            read_consistency,
+            timeout,
+        } = request.into_inner();
+        query_batch(
+            self.dispatcher.as_ref(),
+            collection_name,
+            query_points,
+            read_consistency,
+            timeout.map(Duration::from_secs),
+        )
+        .await
+    }
 }
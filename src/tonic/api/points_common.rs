@@ -7,13 +7,17 @@ use api::grpc::qdrant::{
     points_update_operation, BatchResult, ClearPayloadPoints, CoreSearchPoints, CountPoints,
     CountResponse, CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
     DeletePointVectors, DeletePoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse,
-    FieldType, GetPoints, GetResponse, PayloadIndexParams, PointsOperationResponse, PointsSelector,
+    FacetCounts, FacetResponse, FieldType, GetPoints, GetResponse, PayloadIndexParams,
+    PointsOperationResponse, PointsSelector, QueryBatchResponse, QueryPoints, QueryResponse,
     ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
     RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
-    SearchBatchResponse, SearchGroupsResponse, SearchPointGroups, SearchPoints, SearchResponse,
-    SetPayloadPoints, SyncPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors,
-    UpsertPoints,
+    SearchBatchResponse, SearchGroupsResponse, SearchHybridPoints,
+    SearchMatrixOffsetsResponse as SearchMatrixOffsetsResponseGrpc,
+    SearchMatrixPairsResponse as SearchMatrixPairsResponseGrpc, SearchMatrixPoints,
+    SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, SyncPoints,
+    UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
 };
+use collection::distance_matrix::CollectionSearchMatrixResponse;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::{
     try_discover_request_from_grpc, try_points_selector_from_grpc, write_ordering_from_proto,
@@ -25,12 +29,15 @@ use collection::operations::point_ops::{
 use collection::operations::shard_key_selector::ShardKeySelector;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    default_exact_count, CoreSearchRequest, CoreSearchRequestBatch, PointRequestInternal,
-    QueryEnum, RecommendExample, ScrollRequestInternal,
+    default_exact_count, default_facet_limit, CoreSearchRequest, CoreSearchRequestBatch,
+    FacetRequestInternal, HybridSearchRequestInternal, OrderByInterface, PointRequestInternal,
+    QueryEnum, QueryRequestInternal, RecommendExample, ScrollRequestInternal,
+    SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixRequestInternal,
 };
 use collection::operations::vector_ops::{DeleteVectors, PointVectors, UpdateVectors};
 use collection::operations::CollectionUpdateOperations;
 use collection::shards::shard::ShardId;
+use segment::data_types::order_by::OrderBy;
 use segment::types::{
     ExtendedPointId, Filter, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
 };
@@ -42,8 +49,9 @@ use tonic::{Response, Status};
 use crate::common::points::{
     do_clear_payload, do_core_search_points, do_count_points, do_create_index,
     do_create_index_internal, do_delete_index, do_delete_index_internal, do_delete_payload,
-    do_delete_points, do_delete_vectors, do_get_points, do_overwrite_payload, do_scroll_points,
-    do_search_batch_points, do_set_payload, do_update_vectors, do_upsert_points, CreateFieldIndex,
+    do_delete_points, do_delete_vectors, do_facet_points, do_get_points, do_overwrite_payload,
+    do_query_points, do_scroll_points, do_search_batch_points, do_search_hybrid_points,
+    do_search_points_matrix, do_set_payload, do_update_vectors, do_upsert_points, CreateFieldIndex,
 };
 
 fn extract_points_selector(
@@ -676,12 +684,47 @@ fn convert_field_type(
         (
             Some(v),
             Some(PayloadIndexParams {
-                index_params: Some(IndexParams::TextIndexParams(text_index_params)),
+                index_params: Some(index_params),
             }),
-        ) => match v {
-            FieldType::Text => Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(
-                text_index_params.try_into()?,
-            ))),
+        ) => match (v, index_params) {
+            (FieldType::Text, IndexParams::TextIndexParams(text_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(
+                    text_index_params.try_into()?,
+                )))
+            }
+            (FieldType::Keyword, IndexParams::KeywordIndexParams(keyword_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(
+                    PayloadSchemaParams::Keyword(keyword_index_params.into()),
+                ))
+            }
+            (FieldType::Integer, IndexParams::IntegerIndexParams(integer_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(
+                    PayloadSchemaParams::Integer(integer_index_params.into()),
+                ))
+            }
+            (FieldType::Float, IndexParams::FloatIndexParams(float_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Float(
+                    float_index_params.into(),
+                )))
+            }
+            (FieldType::Datetime, IndexParams::DatetimeIndexParams(datetime_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(
+                    PayloadSchemaParams::Datetime(datetime_index_params.into()),
+                ))
+            }
+            (FieldType::Uuid, IndexParams::UuidIndexParams(uuid_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Uuid(
+                    uuid_index_params.into(),
+                )))
+            }
+            (FieldType::Geo, IndexParams::GeoIndexParams(geo_index_params)) => Some(
+                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Geo(geo_index_params.into())),
+            ),
+            (FieldType::Bool, IndexParams::BoolIndexParams(bool_index_params)) => {
+                Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(
+                    bool_index_params.into(),
+                )))
+            }
             _ => {
                 return Err(Status::invalid_argument(
                     "field_type and field_index_params do not match",
@@ -695,6 +738,8 @@ fn convert_field_type(
             FieldType::Geo => Some(PayloadSchemaType::Geo.into()),
             FieldType::Text => Some(PayloadSchemaType::Text.into()),
             FieldType::Bool => Some(PayloadSchemaType::Bool.into()),
+            FieldType::Datetime => Some(PayloadSchemaType::Datetime.into()),
+            FieldType::Uuid => Some(PayloadSchemaType::Uuid.into()),
         },
         (None, Some(_)) => return Err(Status::invalid_argument("field type is missing")),
         (None, None) => None,
@@ -849,10 +894,15 @@ pub async fn search(
         timeout,
         shard_key_selector,
         sparse_indices,
+        vectors_count,
     } = search_points;
 
-    let vector_struct =
-        api::grpc::conversions::into_named_vector_struct(vector_name, vector, sparse_indices)?;
+    let vector_struct = api::grpc::conversions::into_named_vector_struct(
+        vector_name,
+        vector,
+        sparse_indices,
+        vectors_count,
+    )?;
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
 
@@ -896,6 +946,172 @@ pub async fn search(
     Ok(Response::new(response))
 }
 
+pub async fn search_hybrid(
+    toc: &TableOfContent,
+    mut search_points: SearchHybridPoints,
+) -> Result<Response<SearchResponse>, Status> {
+    let collection_name = search_points.collection_name.clone();
+    let read_consistency =
+        ReadConsistency::try_from_optional(search_points.read_consistency.take())?;
+    let timeout = search_points.timeout.map(Duration::from_secs);
+    let shard_selector =
+        convert_shard_selector_for_read(None, search_points.shard_key_selector.take());
+
+    let search_request = HybridSearchRequestInternal::try_from(search_points)?;
+
+    let timing = Instant::now();
+    let scored_points = do_search_hybrid_points(
+        toc,
+        &collection_name,
+        search_request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = SearchResponse {
+        result: scored_points
+            .into_iter()
+            .map(|point| point.into())
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+async fn search_points_matrix(
+    toc: &TableOfContent,
+    mut search_matrix_points: SearchMatrixPoints,
+) -> Result<CollectionSearchMatrixResponse, Status> {
+    let collection_name = search_matrix_points.collection_name.clone();
+    let read_consistency =
+        ReadConsistency::try_from_optional(search_matrix_points.read_consistency.take())?;
+    let timeout = search_matrix_points.timeout.map(Duration::from_secs);
+    let shard_selector =
+        convert_shard_selector_for_read(None, search_matrix_points.shard_key_selector.take());
+
+    let search_matrix_request = SearchMatrixRequestInternal::try_from(search_matrix_points)?;
+
+    do_search_points_matrix(
+        toc,
+        &collection_name,
+        search_matrix_request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+    .map_err(error_to_status)
+}
+
+pub async fn search_matrix_pairs(
+    toc: &TableOfContent,
+    search_matrix_points: SearchMatrixPoints,
+) -> Result<Response<SearchMatrixPairsResponseGrpc>, Status> {
+    let timing = Instant::now();
+    let search_matrix_response = search_points_matrix(toc, search_matrix_points).await?;
+
+    let response = SearchMatrixPairsResponseGrpc {
+        result: Some(SearchMatrixPairsResponse::from(search_matrix_response).into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn search_matrix_offsets(
+    toc: &TableOfContent,
+    search_matrix_points: SearchMatrixPoints,
+) -> Result<Response<SearchMatrixOffsetsResponseGrpc>, Status> {
+    let timing = Instant::now();
+    let search_matrix_response = search_points_matrix(toc, search_matrix_points).await?;
+
+    let response = SearchMatrixOffsetsResponseGrpc {
+        result: Some(SearchMatrixOffsetsResponse::from(search_matrix_response).into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn query(
+    toc: &TableOfContent,
+    mut query_points: QueryPoints,
+) -> Result<Response<QueryResponse>, Status> {
+    let collection_name = query_points.collection_name.clone();
+    let read_consistency =
+        ReadConsistency::try_from_optional(query_points.read_consistency.take())?;
+    let timeout = query_points.timeout.map(Duration::from_secs);
+    let shard_selector =
+        convert_shard_selector_for_read(None, query_points.shard_key_selector.take());
+
+    let query_request = QueryRequestInternal::try_from(query_points)?;
+
+    let timing = Instant::now();
+    let scored_points = do_query_points(
+        toc,
+        &collection_name,
+        query_request,
+        read_consistency,
+        shard_selector,
+        timeout,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = QueryResponse {
+        result: scored_points
+            .into_iter()
+            .map(|point| point.into())
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn query_batch(
+    toc: &TableOfContent,
+    collection_name: String,
+    query_points: Vec<QueryPoints>,
+    read_consistency: Option<ReadConsistencyGrpc>,
+    timeout: Option<Duration>,
+) -> Result<Response<QueryBatchResponse>, Status> {
+    let mut requests = Vec::with_capacity(query_points.len());
+
+    for mut query_request in query_points {
+        let shard_selector =
+            convert_shard_selector_for_read(None, query_request.shard_key_selector.take());
+        requests.push((
+            QueryRequestInternal::try_from(query_request)?,
+            shard_selector,
+        ));
+    }
+
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let timing = Instant::now();
+    let scored_points = toc
+        .query_batch(&collection_name, requests, read_consistency, timeout)
+        .await
+        .map_err(error_to_status)?;
+
+    let response = QueryBatchResponse {
+        result: scored_points
+            .into_iter()
+            .map(|points| BatchResult {
+                result: points.into_iter().map(|p| p.into()).collect(),
+            })
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn core_search_batch(
     toc: &TableOfContent,
     collection_name: String,
@@ -1049,7 +1265,10 @@ pub async fn recommend(
         .into_iter()
         .map(TryInto::try_into)
         .collect::<Result<Vec<RecommendExample>, Status>>()?;
-    let positive_vectors = positive_vectors.into_iter().map(Into::into).collect();
+    let positive_vectors = positive_vectors
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<RecommendExample>, Status>>()?;
     let positive = [positive_ids, positive_vectors].concat();
 
     let negative_ids = negative
@@ -1267,6 +1486,7 @@ pub async fn scroll(
         with_vectors,
         read_consistency,
         shard_key_selector,
+        order_by,
     } = scroll_points;
 
     let scroll_request = ScrollRequestInternal {
@@ -1277,6 +1497,10 @@ pub async fn scroll(
         with_vector: with_vectors
             .map(|selector| selector.into())
             .unwrap_or_default(),
+        order_by: order_by
+            .map(OrderBy::try_from)
+            .transpose()?
+            .map(OrderByInterface::Struct),
     };
 
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
@@ -1302,6 +1526,7 @@ pub async fn scroll(
             .map(|point| point.into())
             .collect(),
         time: timing.elapsed().as_secs_f64(),
+        next_page_start_from: scrolled_points.next_page_start_from.map(|s| s.into()),
     };
 
     Ok(Response::new(response))
@@ -1348,6 +1573,51 @@ pub async fn count(
     Ok(Response::new(response))
 }
 
+pub async fn facet(
+    toc: &TableOfContent,
+    facet_counts: FacetCounts,
+    shard_selection: Option<ShardId>,
+) -> Result<Response<FacetResponse>, Status> {
+    let FacetCounts {
+        collection_name,
+        key,
+        filter,
+        limit,
+        exact,
+        read_consistency,
+        shard_key_selector,
+    } = facet_counts;
+
+    let facet_request = FacetRequestInternal {
+        key,
+        limit: limit.map_or_else(default_facet_limit, |limit| limit as usize),
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        exact: exact.unwrap_or_default(),
+    };
+
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let timing = Instant::now();
+    let facet_response = do_facet_points(
+        toc,
+        &collection_name,
+        facet_request,
+        read_consistency,
+        shard_selector,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = FacetResponse {
+        hits: facet_response.hits.into_iter().map(From::from).collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn get(
     toc: &TableOfContent,
     get_points: GetPoints,
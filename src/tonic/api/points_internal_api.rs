@@ -1,25 +1,29 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use api::grpc::qdrant::points_internal_server::PointsInternal;
 use api::grpc::qdrant::{
     ClearPayloadPointsInternal, CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollectionInternal,
-    DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, GetPointsInternal,
-    GetResponse, PointsOperationResponse, RecommendPointsInternal, RecommendResponse,
+    DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, FacetCountsInternal,
+    FacetResponse, GetPointsInternal, GetResponse, PointsOperationResponse,
+    RecommendPointsInternal, RecommendResponse, SamplePointsInternal, SamplePointsResponse,
     ScrollPointsInternal, ScrollResponse, SearchBatchPointsInternal, SearchBatchResponse,
-    SearchPointsInternal, SearchResponse, SetPayloadPointsInternal, SyncPointsInternal,
-    UpdateVectorsInternal, UpsertPointsInternal,
+    SearchPointsInternal, SearchResponse, SetPayloadPointsInternal, SparseVectorStatisticsInternal,
+    SparseVectorStatisticsResponse, SyncPointsInternal, TextSearchInternal, UpdateVectorsInternal,
+    UpsertPointsInternal,
 };
+use collection::operations::types::{SparseVectorStatisticsRequest, TextSearchRequest};
+use storage::content_manager::conversions::error_to_status;
 use storage::content_manager::toc::TableOfContent;
 use tonic::{Request, Response, Status};
 
 use super::points_common::core_search_list;
 use super::validate_and_log;
 use crate::tonic::api::points_common::{
-    clear_payload, count, create_field_index_internal, delete, delete_field_index_internal,
-    delete_payload, delete_vectors, get, overwrite_payload, recommend, scroll, set_payload, sync,
-    update_vectors, upsert,
+    clear_payload, convert_shard_selector_for_read, count, create_field_index_internal, delete,
+    delete_field_index_internal, delete_payload, delete_vectors, facet, get, overwrite_payload,
+    recommend, scroll, set_payload, sync, update_vectors, upsert,
 };
 
 /// This API is intended for P2P communication within a distributed deployment.
@@ -300,6 +304,130 @@ impl PointsInternal for PointsInternalService {
         count(self.toc.as_ref(), count_points, shard_id).await
     }
 
+    async fn facet(
+        &self,
+        request: Request<FacetCountsInternal>,
+    ) -> Result<Response<FacetResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let FacetCountsInternal {
+            facet_counts,
+            shard_id,
+        } = request.into_inner();
+
+        let facet_counts =
+            facet_counts.ok_or_else(|| Status::invalid_argument("FacetCounts is missing"))?;
+        facet(self.toc.as_ref(), facet_counts, shard_id).await
+    }
+
+    async fn sample_points(
+        &self,
+        request: Request<SamplePointsInternal>,
+    ) -> Result<Response<SamplePointsResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let SamplePointsInternal {
+            collection_name,
+            filter,
+            limit,
+            shard_id,
+        } = request.into_inner();
+
+        let filter = filter.map(TryInto::try_into).transpose()?;
+        let shard_selector = convert_shard_selector_for_read(shard_id, None);
+
+        let timing = Instant::now();
+        let sample = self
+            .toc
+            .sample_points(
+                &collection_name,
+                limit as usize,
+                filter.as_ref(),
+                shard_selector,
+            )
+            .await
+            .map_err(error_to_status)?;
+
+        let response = SamplePointsResponse {
+            result: sample.into_iter().map(Into::into).collect(),
+            time: timing.elapsed().as_secs_f64(),
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn sparse_vector_statistics(
+        &self,
+        request: Request<SparseVectorStatisticsInternal>,
+    ) -> Result<Response<SparseVectorStatisticsResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let SparseVectorStatisticsInternal {
+            collection_name,
+            vectors,
+            shard_id,
+        } = request.into_inner();
+
+        let statistics_request = SparseVectorStatisticsRequest {
+            vectors: vectors
+                .into_iter()
+                .map(|(vector_name, indices)| (vector_name, indices.data))
+                .collect(),
+        };
+        let shard_selector = convert_shard_selector_for_read(shard_id, None);
+
+        let timing = Instant::now();
+        let statistics = self
+            .toc
+            .sparse_vector_statistics(&collection_name, statistics_request, shard_selector)
+            .await
+            .map_err(error_to_status)?;
+
+        let response = SparseVectorStatisticsResponse {
+            result: statistics
+                .into_iter()
+                .map(|(vector_name, statistics)| (vector_name, statistics.into()))
+                .collect(),
+            time: timing.elapsed().as_secs_f64(),
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn text_search(
+        &self,
+        request: Request<TextSearchInternal>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate_and_log(request.get_ref());
+        let TextSearchInternal {
+            collection_name,
+            key,
+            text,
+            filter,
+            limit,
+            shard_id,
+        } = request.into_inner();
+
+        let text_search_request = TextSearchRequest {
+            key,
+            text,
+            filter: filter.map(TryInto::try_into).transpose()?,
+            limit: limit as usize,
+        };
+        let shard_selector = convert_shard_selector_for_read(shard_id, None);
+
+        let timing = Instant::now();
+        let scored_points = self
+            .toc
+            .text_search(&collection_name, text_search_request, shard_selector)
+            .await
+            .map_err(error_to_status)?;
+
+        let response = SearchResponse {
+            result: scored_points
+                .into_iter()
+                .map(|point| point.into())
+                .collect(),
+            time: timing.elapsed().as_secs_f64(),
+        };
+        Ok(Response::new(response))
+    }
+
     async fn sync(
         &self,
         request: Request<SyncPointsInternal>,
@@ -23,6 +23,7 @@ use super::{validate, validate_and_log};
 use crate::common;
 use crate::common::collections::{do_create_snapshot, do_list_snapshots};
 use crate::common::http_client::HttpClient;
+use crate::tonic::api_key::extract_access;
 
 pub struct SnapshotsService {
     dispatcher: Arc<Dispatcher>,
@@ -38,9 +39,11 @@ impl SnapshotsService {
 impl Snapshots for SnapshotsService {
     async fn create(
         &self,
-        request: Request<CreateSnapshotRequest>,
+        mut request: Request<CreateSnapshotRequest>,
     ) -> Result<Response<CreateSnapshotResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let collection_name = request.into_inner().collection_name;
         let timing = Instant::now();
         let dispatcher = self.dispatcher.clone();
@@ -55,9 +58,11 @@ impl Snapshots for SnapshotsService {
 
     async fn list(
         &self,
-        request: Request<ListSnapshotsRequest>,
+        mut request: Request<ListSnapshotsRequest>,
     ) -> Result<Response<ListSnapshotsResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let collection_name = request.into_inner().collection_name;
 
         let timing = Instant::now();
@@ -72,9 +77,11 @@ impl Snapshots for SnapshotsService {
 
     async fn delete(
         &self,
-        request: Request<DeleteSnapshotRequest>,
+        mut request: Request<DeleteSnapshotRequest>,
     ) -> Result<Response<DeleteSnapshotResponse>, Status> {
         validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        access.check_collection(&request.get_ref().collection_name)?;
         let DeleteSnapshotRequest {
             collection_name,
             snapshot_name,
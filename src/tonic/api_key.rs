@@ -9,10 +9,10 @@ use tonic::Code;
 use tower::Service;
 use tower_layer::Layer;
 
-use crate::common::auth::AuthKeys;
+use crate::common::auth::{Access, AuthError, AuthKeys};
 use crate::common::strings::ct_eq;
 
-const READ_ONLY_RPC_PATHS: [&str; 13] = [
+const READ_ONLY_RPC_PATHS: [&str; 19] = [
     "/qdrant.Collections/List",
     "/qdrant.Collections/Get",
     "/qdrant.Points/Scroll",
@@ -26,8 +26,54 @@ const READ_ONLY_RPC_PATHS: [&str; 13] = [
     "/qdrant.Points/RecommendBatch",
     "/qdrant.Points/Discover",
     "/qdrant.Points/DiscoverBatch",
+    "/qdrant.Points/SearchHybrid",
+    "/qdrant.Points/SearchMatrixPairs",
+    "/qdrant.Points/SearchMatrixOffsets",
+    "/qdrant.Points/Facet",
+    "/qdrant.Points/Query",
+    "/qdrant.Points/QueryBatch",
 ];
 
+/// RPCs made to the collection named in their request, in addition to all of the points service
+///
+/// Access to the collection is checked by their handlers.
+const COLLECTION_RPC_PATHS: [&str; 12] = [
+    "/qdrant.Collections/Get",
+    "/qdrant.Collections/Create",
+    "/qdrant.Collections/Update",
+    "/qdrant.Collections/Delete",
+    "/qdrant.Collections/ListCollectionAliases",
+    "/qdrant.Collections/CollectionClusterInfo",
+    "/qdrant.Collections/UpdateCollectionClusterSetup",
+    "/qdrant.Collections/CreateShardKey",
+    "/qdrant.Collections/DeleteShardKey",
+    "/qdrant.Snapshots/Create",
+    "/qdrant.Snapshots/List",
+    "/qdrant.Snapshots/Delete",
+];
+
+/// Read RPCs which apply the payload restriction of the access to their filter
+const PAYLOAD_RESTRICTED_RPC_PATHS: [&str; 16] = [
+    "/qdrant.Points/Scroll",
+    "/qdrant.Points/Count",
+    "/qdrant.Points/Search",
+    "/qdrant.Points/SearchGroups",
+    "/qdrant.Points/SearchBatch",
+    "/qdrant.Points/SearchHybrid",
+    "/qdrant.Points/SearchMatrixPairs",
+    "/qdrant.Points/SearchMatrixOffsets",
+    "/qdrant.Points/Recommend",
+    "/qdrant.Points/RecommendGroups",
+    "/qdrant.Points/RecommendBatch",
+    "/qdrant.Points/Discover",
+    "/qdrant.Points/DiscoverBatch",
+    "/qdrant.Points/Facet",
+    "/qdrant.Points/Query",
+    "/qdrant.Points/QueryBatch",
+];
+
+const LIST_COLLECTIONS_RPC_PATH: &str = "/qdrant.Collections/List";
+
 #[derive(Clone)]
 pub struct ApiKeyMiddleware<T> {
     service: T,
@@ -57,7 +103,7 @@ where
 
     fn call(
         &mut self,
-        request: tonic::codegen::http::Request<tonic::transport::Body>,
+        mut request: tonic::codegen::http::Request<tonic::transport::Body>,
     ) -> Self::Future {
         // Grab API key from request
         let key =
@@ -71,13 +117,21 @@ where
                         })
                 });
 
-        if let Some(key) = key {
-            let is_allowed = self.auth_keys.can_write(&key)
-                || (is_read_only(&request) && self.auth_keys.can_read(&key));
-            if is_allowed {
+        let access = match key {
+            Some(key) => self
+                .auth_keys
+                .validate(&key)
+                .and_then(|access| check_access(&access, &request).map(|()| access)),
+            None => Err(AuthError::Unauthorized("Invalid api-key".to_string())),
+        };
+
+        let err = match access {
+            Ok(access) => {
+                request.extensions_mut().insert(access);
                 return Box::pin(self.service.call(request));
             }
-        }
+            Err(err) => err,
+        };
 
         let mut response = Self::Response::new(BoxBody::default());
         *response.status_mut() = StatusCode::FORBIDDEN;
@@ -85,9 +139,11 @@ where
             "grpc-status",
             HeaderValue::from(Code::PermissionDenied as i32),
         );
-        response
-            .headers_mut()
-            .append("grpc-message", HeaderValue::from_static("Invalid api-key"));
+        response.headers_mut().append(
+            "grpc-message",
+            HeaderValue::from_str(&err.to_string())
+                .unwrap_or_else(|_| HeaderValue::from_static("Permission denied")),
+        );
 
         Box::pin(async move { Ok(response) })
     }
@@ -110,6 +166,40 @@ impl<S> Layer<S> for ApiKeyMiddlewareLayer {
     }
 }
 
+/// Take the access granted to a request by the auth middleware
+///
+/// Full access is assumed if auth is disabled.
+pub fn extract_access<R>(request: &mut tonic::Request<R>) -> Access {
+    request
+        .extensions_mut()
+        .remove::<Access>()
+        .unwrap_or_else(Access::full)
+}
+
+/// Check the access allows the RPC, except for the collection it is made to
+fn check_access<R>(
+    access: &Access,
+    req: &tonic::codegen::http::Request<R>,
+) -> Result<(), AuthError> {
+    access.check_level(is_read_only(req))?;
+
+    let uri_path = req.uri().path();
+    let is_list_collections = uri_path == LIST_COLLECTIONS_RPC_PATH;
+    let is_collection_rpc =
+        uri_path.starts_with("/qdrant.Points/") || COLLECTION_RPC_PATHS.contains(&uri_path);
+
+    // The list of collections is filtered by the access instead
+    if !is_collection_rpc && !is_list_collections {
+        access.check_global()?;
+    }
+
+    if !is_list_collections && !PAYLOAD_RESTRICTED_RPC_PATHS.contains(&uri_path) {
+        access.check_payload_unrestricted()?;
+    }
+
+    Ok(())
+}
+
 fn is_read_only<R>(req: &tonic::codegen::http::Request<R>) -> bool {
     let uri_path = req.uri().path();
     READ_ONLY_RPC_PATHS